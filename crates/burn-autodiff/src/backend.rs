@@ -1,10 +1,13 @@
 use crate::{
-    checkpoint::strategy::{CheckpointStrategy, NoCheckpointing},
+    checkpoint::{
+        segment::checkpoint_segment,
+        strategy::{CheckpointStrategy, NoCheckpointing},
+    },
     grads::Gradients,
     runtime::AutodiffClient,
     tensor::AutodiffTensor,
 };
-use alloc::{format, string::String};
+use alloc::{format, string::String, vec::Vec};
 use burn_tensor::{
     backend::{AutodiffBackend, Backend},
     ops::{BoolTensor, IntTensor, QuantizedTensor},
@@ -15,6 +18,11 @@ use core::marker::PhantomData;
 ///
 /// This works as a backend decorator, extending the functionality of any backend with
 /// backpropagation.
+///
+/// The decorator can be nested to compute higher-order gradients: with
+/// `Autodiff<Autodiff<B>>`, the gradients are tensors of `Autodiff<B>` that record their own
+/// graph, so they can be used in a loss and differentiated again (e.g. gradient penalties,
+/// meta-learning or Hessian-vector products).
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Autodiff<B, C = NoCheckpointing> {
     _b: PhantomData<B>,
@@ -102,6 +110,31 @@ impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
         grads.register::<B>(tensor.node.id, grad);
     }
 
+    fn register_hook<F>(tensor: AutodiffTensor<B>, hook: F) -> AutodiffTensor<B>
+    where
+        F: Fn(B::FloatTensorPrimitive) -> B::FloatTensorPrimitive + Send + 'static,
+    {
+        tensor.register_hook(hook)
+    }
+
+    fn retain_grad(tensor: AutodiffTensor<B>) -> AutodiffTensor<B> {
+        tensor.retain_grad()
+    }
+
+    fn checkpoint<Func>(
+        inputs: Vec<AutodiffTensor<B>>,
+        parameters: Vec<AutodiffTensor<B>>,
+        func: Func,
+    ) -> AutodiffTensor<B>
+    where
+        Func: Fn(Vec<AutodiffTensor<B>>, Vec<AutodiffTensor<B>>) -> AutodiffTensor<B>
+            + Send
+            + Sync
+            + 'static,
+    {
+        checkpoint_segment::<B, C, Func>(inputs, parameters, func)
+    }
+
     fn int_inner(tensor: IntTensor<Self>) -> IntTensor<Self::InnerBackend> {
         tensor
     }
@@ -9,6 +9,7 @@ use core::any::Any;
 
 use super::{
     base::{Checkpointer, NodeTree},
+    report::{BackwardReport, KeptMemory, num_bytes},
     retro_forward::{RetroForward, RetroForwards},
     state::{BackwardStates, State},
 };
@@ -58,6 +59,12 @@ impl CheckpointingAction {
 pub struct CheckpointerBuilder {
     explicit_actions: Vec<CheckpointingAction>,
     backup_actions: Vec<CheckpointingAction>,
+    /// The outputs kept in memory until the checkpointer is built.
+    #[new(default)]
+    kept: Vec<KeptMemory>,
+    /// The estimated size in bytes of the output of each checkpointed node.
+    #[new(default)]
+    sizes: HashMap<NodeID, usize>,
 }
 
 /// Determines if a checkpoint should impact the n_required values (Main)
@@ -83,8 +90,12 @@ impl CheckpointerBuilder {
             ActionType::Explicit => &mut self.explicit_actions,
             ActionType::Backup => &mut self.backup_actions,
         };
+        let bytes = num_bytes(&tensor.primitive);
+        self.sizes.insert(tensor.node.id, bytes);
+
         match &tensor.node.properties {
             ComputingProperty::ComputeBound | ComputingProperty::Ambiguous => {
+                self.kept.push(KeptMemory::new(tensor.node.clone(), bytes));
                 action_list.push(CheckpointingAction::Computed {
                     node_id: tensor.node.id,
                     state_content: Box::new(tensor.primitive.clone()),
@@ -106,9 +117,11 @@ impl CheckpointerBuilder {
         for other_unsure in other.backup_actions {
             self.backup_actions.push(other_unsure)
         }
+        self.kept.extend(other.kept);
+        self.sizes.extend(other.sizes);
     }
 
-    pub(crate) fn build(self, node_tree: NodeTree) -> Checkpointer {
+    pub(crate) fn build(self, node_tree: NodeTree) -> (Checkpointer, BackwardReport) {
         let mut backward_states_map = HashMap::new();
         let mut retro_forwards_map = HashMap::new();
 
@@ -119,17 +132,19 @@ impl CheckpointerBuilder {
         let n_required_map = self.build_n_required_map(&node_tree, stop_nodes);
 
         // Then we checkpoint the nodes with the corresponding n_required value
-        self.insert_checkpoints(
+        let report = self.insert_checkpoints(
             &mut backward_states_map,
             &mut retro_forwards_map,
             n_required_map,
         );
 
-        Checkpointer::new(
+        let checkpointer = Checkpointer::new(
             BackwardStates::new(backward_states_map),
             RetroForwards::new(retro_forwards_map),
             node_tree,
-        )
+        );
+
+        (checkpointer, report)
     }
 
     fn find_stop_nodes(&self) -> Vec<NodeID> {
@@ -199,7 +214,9 @@ impl CheckpointerBuilder {
         backward_states_map: &mut HashMap<NodeID, State>,
         retro_forward_map: &mut HashMap<NodeID, Arc<dyn RetroForward>>,
         n_required_map: HashMap<NodeID, usize>,
-    ) {
+    ) -> BackwardReport {
+        let mut report = BackwardReport::default();
+
         // We do not loop over checkpointing actions anymore because they can contain
         // duplicates or miss some that are in backup. We loop over the n_required_map
         // from which we use the ids to find them again in the checkpointing actions
@@ -225,25 +242,34 @@ impl CheckpointerBuilder {
                 }
             };
 
+            let bytes = self.sizes.get(&node_id).copied().unwrap_or_default();
+
             match action {
                 CheckpointingAction::Computed {
                     node_id: _,
                     state_content,
                 } => {
+                    report.kept_bytes += bytes;
                     self.checkpoint_compute(backward_states_map, node_id, state_content, n_required)
                 }
                 CheckpointingAction::Recompute {
                     node_id: _,
                     retro_forward,
-                } => self.checkpoint_lazy(
-                    backward_states_map,
-                    retro_forward_map,
-                    node_id,
-                    retro_forward,
-                    n_required,
-                ),
+                } => {
+                    report.recomputed_bytes += bytes;
+                    report.recomputed_ops += 1;
+                    self.checkpoint_lazy(
+                        backward_states_map,
+                        retro_forward_map,
+                        node_id,
+                        retro_forward,
+                        n_required,
+                    )
+                }
             };
         }
+
+        report
     }
 
     fn update_n_required_of_parents(
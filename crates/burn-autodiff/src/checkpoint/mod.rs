@@ -1,8 +1,11 @@
 /// Checkpointer module
 pub mod base;
 pub(crate) mod builder;
+/// Checkpointing report module
+pub mod report;
 /// RetroForward module
 pub mod retro_forward;
+pub(crate) mod segment;
 /// BackwardStates module
 pub mod state;
 /// CheckpointStrategy module
@@ -0,0 +1,234 @@
+use crate::{Autodiff, checkpoint::strategy::CheckpointStrategy, graph::NodeRef};
+use alloc::{sync::Arc, vec::Vec};
+use burn_tensor::{Tensor, TensorMetadata, backend::Backend};
+use core::fmt::Display;
+
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic::{AtomicUsize, Ordering};
+
+/// Statistics of the activation checkpointing of the autodiff graph of a tensor, accumulated over
+/// the backward passes from its roots.
+///
+/// The memory saved by recomputing activations can be compared with the extra compute it costs
+/// to tune the [checkpoint strategy](super::strategy::CheckpointStrategy).
+///
+/// # Notes
+///
+/// The sizes are estimated from the shapes and data types of the tensors, and a tensor is counted
+/// once even when it's needed by many operations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CheckpointReport {
+    /// The number of backward passes.
+    pub backward_passes: usize,
+    /// The number of bytes of the activations kept in memory for the backward passes.
+    pub kept_bytes: usize,
+    /// The number of bytes of the activations recomputed during the backward passes instead of
+    /// being kept in memory, which is the memory saved by checkpointing.
+    pub recomputed_bytes: usize,
+    /// The number of memory bound operations recomputed during the backward passes.
+    pub recomputed_ops: usize,
+    /// The number of checkpointed segments recomputed during the backward passes.
+    pub recomputed_segments: usize,
+    /// The highest number of bytes of activations kept in memory at once by the graph.
+    pub peak_bytes: usize,
+}
+
+impl CheckpointReport {
+    /// Returns the statistics of the graph of the tensor, including the graphs it joins.
+    ///
+    /// A graph starts with the first operation on leaves, so each forward pass has its own report,
+    /// e.g. the report of a loss covers the backward passes from that loss. A leaf doesn't belong
+    /// to any graph, so its report is empty.
+    pub fn of<B: Backend, C: CheckpointStrategy, const D: usize>(
+        tensor: &Tensor<Autodiff<B, C>, D>,
+    ) -> Self {
+        let tensor = tensor.clone().into_primitive().tensor();
+        let graphs = GraphMemory::reachable(tensor.node.memory.iter().cloned().collect());
+
+        graphs.iter().fold(Self::default(), |report, graph| {
+            let recorded = *graph.report.lock();
+
+            Self {
+                backward_passes: report.backward_passes + recorded.backward_passes,
+                kept_bytes: report.kept_bytes + recorded.kept_bytes,
+                recomputed_bytes: report.recomputed_bytes + recorded.recomputed_bytes,
+                recomputed_ops: report.recomputed_ops + recorded.recomputed_ops,
+                recomputed_segments: report.recomputed_segments + recorded.recomputed_segments,
+                peak_bytes: report.peak_bytes.max(graph.peak.load(Ordering::Relaxed)),
+            }
+        })
+    }
+
+    /// The fraction of the activations that were recomputed instead of being kept in memory.
+    pub fn saved_ratio(&self) -> f64 {
+        let total = self.kept_bytes + self.recomputed_bytes;
+
+        match total {
+            0 => 0.0,
+            _ => self.recomputed_bytes as f64 / total as f64,
+        }
+    }
+}
+
+impl Display for CheckpointReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mib = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+
+        write!(
+            f,
+            "Checkpointing over {} backward passes: kept {:.2} MiB, saved {:.2} MiB ({:.1}%) by \
+             recomputing {} operations and {} segments, peak {:.2} MiB",
+            self.backward_passes,
+            mib(self.kept_bytes),
+            mib(self.recomputed_bytes),
+            self.saved_ratio() * 100.0,
+            self.recomputed_ops,
+            self.recomputed_segments,
+            mib(self.peak_bytes),
+        )
+    }
+}
+
+/// The statistics of the checkpointer of one backward pass.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct BackwardReport {
+    pub(crate) kept_bytes: usize,
+    pub(crate) recomputed_bytes: usize,
+    pub(crate) recomputed_ops: usize,
+}
+
+impl BackwardReport {
+    /// Adds the statistics of a backward pass from a root of the graph to its report.
+    pub(crate) fn record(&self, graph: &GraphMemory) {
+        let mut report = graph.report.lock();
+
+        report.backward_passes += 1;
+        report.kept_bytes += self.kept_bytes;
+        report.recomputed_bytes += self.recomputed_bytes;
+        report.recomputed_ops += self.recomputed_ops;
+    }
+
+    /// Adds the statistics of the backward pass recomputing a checkpointed segment of the graph to
+    /// its report.
+    ///
+    /// The activations kept by the backward pass of a checkpointed segment only exist because the
+    /// segment is recomputed, so they are counted as recomputed.
+    pub(crate) fn record_segment(&self, graph: &GraphMemory) {
+        let mut report = graph.report.lock();
+
+        report.recomputed_segments += 1;
+        report.recomputed_bytes += self.kept_bytes + self.recomputed_bytes;
+        report.recomputed_ops += self.recomputed_ops;
+    }
+}
+
+/// Returns the estimated number of bytes of a tensor.
+pub(crate) fn num_bytes<T: TensorMetadata>(tensor: &T) -> usize {
+    tensor.shape().num_elements() * tensor.dtype().size()
+}
+
+/// Counts the output of a node as kept in memory for as long as it lives.
+///
+/// The output of a node can be kept by many checkpointing actions, so it's only counted by the
+/// first one, using the count of the node.
+#[derive(Debug)]
+pub(crate) struct KeptMemory {
+    node: NodeRef,
+    bytes: usize,
+}
+
+impl KeptMemory {
+    pub(crate) fn new(node: NodeRef, bytes: usize) -> Self {
+        if node.checkpoints.fetch_add(1, Ordering::Relaxed) == 0 {
+            if let Some(memory) = &node.memory {
+                memory.kept.fetch_add(bytes, Ordering::Relaxed);
+
+                let kept = GraphMemory::reachable(alloc::vec![memory.clone()])
+                    .iter()
+                    .map(|graph| graph.kept.load(Ordering::Relaxed))
+                    .sum();
+                memory.peak.fetch_max(kept, Ordering::Relaxed);
+            }
+        }
+
+        Self { node, bytes }
+    }
+}
+
+impl Drop for KeptMemory {
+    fn drop(&mut self) {
+        if self.node.checkpoints.fetch_sub(1, Ordering::Relaxed) == 1 {
+            if let Some(memory) = &self.node.memory {
+                memory.kept.fetch_sub(self.bytes, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// The bytes of the activations kept in memory by the nodes of a graph, along with the
+/// [report](CheckpointReport) of its backward passes.
+///
+/// A graph starts with the first operation on leaves, so each forward pass has its own graph even
+/// when it uses the same parameters. An operation joining independent graphs starts a new graph
+/// that includes them, so the memory of a graph is the one of its nodes and of the graphs it joins.
+#[derive(Debug, Default)]
+pub struct GraphMemory {
+    kept: AtomicUsize,
+    peak: AtomicUsize,
+    report: spin::Mutex<CheckpointReport>,
+    joined: Vec<Arc<GraphMemory>>,
+}
+
+impl GraphMemory {
+    /// Returns the graph of an operation with the given parents.
+    pub(crate) fn of(parents: &[NodeRef]) -> Arc<Self> {
+        let mut graphs = Self::graphs(parents);
+
+        match graphs.len() {
+            1 => graphs.remove(0),
+            _ => Arc::new(Self {
+                joined: graphs,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Returns the number of bytes kept by the graph of an operation with the given parents.
+    pub(crate) fn kept_bytes(parents: &[NodeRef]) -> usize {
+        Self::reachable(Self::graphs(parents))
+            .iter()
+            .map(|graph| graph.kept.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Returns the distinct graphs of the given nodes.
+    fn graphs(nodes: &[NodeRef]) -> Vec<Arc<Self>> {
+        let mut graphs = Vec::<Arc<Self>>::new();
+
+        for memory in nodes.iter().filter_map(|node| node.memory.as_ref()) {
+            if !graphs.iter().any(|graph| Arc::ptr_eq(graph, memory)) {
+                graphs.push(memory.clone());
+            }
+        }
+
+        graphs
+    }
+
+    /// Returns the distinct graphs among the given graphs and the graphs they join.
+    fn reachable(mut graphs: Vec<Arc<Self>>) -> Vec<Arc<Self>> {
+        let mut visited = Vec::<Arc<Self>>::new();
+
+        while let Some(graph) = graphs.pop() {
+            if visited.iter().any(|visited| Arc::ptr_eq(visited, &graph)) {
+                continue;
+            }
+
+            graphs.extend(graph.joined.iter().cloned());
+            visited.push(graph);
+        }
+
+        visited
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    Autodiff,
+    checkpoint::{
+        base::Checkpointer,
+        builder::{ActionType, CheckpointerBuilder},
+        strategy::CheckpointStrategy,
+    },
+    grads::Gradients,
+    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step},
+    runtime::{GraphClient, LocalGraph},
+    tensor::AutodiffTensor,
+};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use burn_tensor::{
+    backend::Backend,
+    ops::{FloatTensor, FloatTensorOps},
+};
+use core::marker::PhantomData;
+
+/// The function of a checkpointed segment, called with its inputs and parameters.
+type SegmentFn<B> =
+    dyn Fn(Vec<FloatTensor<B>>, Vec<FloatTensor<B>>) -> FloatTensor<B> + Send + Sync;
+
+/// Computes a function as a single operation of the graph, keeping only its inputs and parameters
+/// for the backward pass.
+///
+/// The function is computed without tracking during the forward pass. During the backward pass,
+/// it's computed again with tracking, and its own backward pass gives the gradients of the inputs
+/// and parameters.
+pub(crate) fn checkpoint_segment<B, C, F>(
+    inputs: Vec<AutodiffTensor<B>>,
+    parameters: Vec<AutodiffTensor<B>>,
+    func: F,
+) -> AutodiffTensor<B>
+where
+    B: Backend,
+    C: CheckpointStrategy,
+    F: Fn(
+            Vec<FloatTensor<Autodiff<B, C>>>,
+            Vec<FloatTensor<Autodiff<B, C>>>,
+        ) -> FloatTensor<Autodiff<B, C>>
+        + Send
+        + Sync
+        + 'static,
+{
+    let num_inputs = inputs.len();
+    let tensors = inputs.into_iter().chain(parameters).collect::<Vec<_>>();
+    let nodes = tensors
+        .iter()
+        .map(|tensor| tensor.node.clone())
+        .collect::<Vec<_>>();
+    let requirement = Requirement::from_nodes(&nodes);
+
+    // Nothing is tracked inside the segment, so none of its intermediate tensors are kept.
+    let (inputs, parameters) = split(
+        tensors
+            .iter()
+            .map(|tensor| AutodiffTensor::new(tensor.primitive.clone()))
+            .collect(),
+        num_inputs,
+    );
+    let output = func(inputs, parameters).primitive;
+
+    if requirement.is_none() {
+        return AutodiffTensor::from_parents(
+            output,
+            &nodes,
+            requirement,
+            ComputingProperty::ComputeBound,
+        );
+    }
+
+    let mut checkpointer_builder = CheckpointerBuilder::default();
+    let saved = tensors
+        .iter()
+        .map(|tensor| {
+            checkpointer_builder.checkpoint(tensor, ActionType::Explicit);
+            (tensor.node.clone_if_require_grad(), tensor.node.id)
+        })
+        .collect::<Vec<_>>();
+
+    let output =
+        AutodiffTensor::from_parents(output, &nodes, requirement, ComputingProperty::ComputeBound);
+    let step = SegmentStep::<B, C> {
+        output: output.node.clone(),
+        saved,
+        num_inputs,
+        func: Arc::new(func),
+        phantom: PhantomData,
+    };
+
+    output.register_step(step, checkpointer_builder)
+}
+
+/// Recomputes a checkpointed segment to compute the gradients of its inputs and parameters.
+struct SegmentStep<B: Backend, C: CheckpointStrategy> {
+    output: NodeRef,
+    /// The node of each input followed by each parameter if it's tracked, and its checkpoint.
+    saved: Vec<(Option<NodeRef>, NodeID)>,
+    num_inputs: usize,
+    func: Arc<SegmentFn<Autodiff<B, C>>>,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend, C: CheckpointStrategy> Step for SegmentStep<B, C> {
+    fn step(self: Box<Self>, grads: &mut Gradients, checkpointer: &mut Checkpointer) {
+        let grad = grads.consume::<B>(&self.output);
+
+        // The segment is recomputed in a graph of its own, since the step is executed by the
+        // server of the current graph.
+        let graph = LocalGraph::default();
+        let leaves = self
+            .saved
+            .iter()
+            .map(|(node, id)| {
+                let tensor = AutodiffTensor::<B>::with_client(
+                    checkpointer.retrieve_node_output(*id),
+                    GraphClient::Local(graph.client()),
+                );
+                match node {
+                    Some(_) => tensor.require_grad(),
+                    None => tensor,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let (inputs, parameters) = split(leaves.clone(), self.num_inputs);
+        let output = (self.func)(inputs, parameters);
+
+        // Backpropagating the sum of the output weighted by its gradient gives the gradients of
+        // the leaves.
+        let weighted = Autodiff::<B, C>::float_sum(Autodiff::<B, C>::float_mul(
+            output,
+            AutodiffTensor::new(grad),
+        ));
+        if !weighted.is_tracked() {
+            return;
+        }
+
+        let mut local = graph.backward_segment(weighted, self.output.memory.as_deref());
+
+        for ((node, _), leaf) in self.saved.iter().zip(leaves.iter()) {
+            let grad = node
+                .as_ref()
+                .and_then(|node| local.remove::<B>(leaf).map(|grad| (node.id, grad)));
+
+            if let Some((id, grad)) = grad {
+                grads.register::<B>(id, grad);
+            }
+        }
+    }
+
+    fn node(&self) -> NodeID {
+        self.output.id
+    }
+
+    fn parents(&self) -> Vec<NodeID> {
+        self.saved
+            .iter()
+            .filter_map(|(node, _)| node.as_ref().map(|node| node.id))
+            .collect()
+    }
+
+    fn depth(&self) -> usize {
+        self.output.order
+    }
+}
+
+impl<B: Backend, C: CheckpointStrategy> core::fmt::Debug for SegmentStep<B, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SegmentStep")
+            .field("output", &self.output)
+            .field("saved", &self.saved)
+            .field("num_inputs", &self.num_inputs)
+            .finish()
+    }
+}
+
+/// Splits the tensors into the inputs and the parameters of a segment.
+fn split<T>(mut tensors: Vec<T>, num_inputs: usize) -> (Vec<T>, Vec<T>) {
+    let parameters = tensors.split_off(num_inputs);
+
+    (tensors, parameters)
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use burn_ndarray::NdArray;
+    use burn_tensor::{Tensor, TensorPrimitive, Tolerance, backend::AutodiffBackend};
+
+    type TestBackend = Autodiff<NdArray>;
+
+    fn block(x: Tensor<TestBackend, 2>, weights: Tensor<TestBackend, 2>) -> Tensor<TestBackend, 2> {
+        let hidden = x.matmul(weights).tanh();
+        hidden.clone().exp() * hidden
+    }
+
+    #[test]
+    fn should_recompute_segment_on_the_server_thread() {
+        let device = Default::default();
+        let x =
+            Tensor::<TestBackend, 2>::from_data([[1.0, -0.5], [2.0, 0.25]], &device).require_grad();
+        let weights =
+            Tensor::<TestBackend, 2>::from_data([[0.3, -0.2], [0.5, 0.4]], &device).require_grad();
+
+        // The step of the segment is executed by the thread of the server, which would wait on
+        // itself if the segment was differentiated through the client.
+        let output = TestBackend::checkpoint(
+            vec![x.clone().into_primitive().tensor()],
+            vec![weights.clone().into_primitive().tensor()],
+            |inputs, parameters| {
+                let x = Tensor::from_primitive(TensorPrimitive::Float(inputs[0].clone()));
+                let weights = Tensor::from_primitive(TensorPrimitive::Float(parameters[0].clone()));
+
+                block(x, weights).into_primitive().tensor()
+            },
+        );
+        let output = Tensor::<TestBackend, 2>::from_primitive(TensorPrimitive::Float(output));
+        let grads = output.sum().backward();
+
+        let grads_ref = block(x.clone(), weights.clone()).sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_approx_eq::<f32>(
+            &x.grad(&grads_ref).unwrap().into_data(),
+            Tolerance::default(),
+        );
+        weights
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<f32>(
+                &weights.grad(&grads_ref).unwrap().into_data(),
+                Tolerance::default(),
+            );
+    }
+}
@@ -2,18 +2,26 @@ use core::fmt::Debug;
 
 use burn_tensor::backend::Backend;
 
-use crate::{graph::ComputingProperty, tensor::AutodiffTensor};
+use crate::{
+    graph::{ComputingProperty, NodeRef},
+    tensor::AutodiffTensor,
+};
 use alloc::sync::Arc;
 
 use super::{
     builder::{ActionType, CheckpointerBuilder},
+    report::GraphMemory,
     retro_forward::RetroForward,
 };
 
 /// Strategy for the amount of checkpointing to do during autodiff
 pub trait CheckpointStrategy: Clone + Copy + Debug + Default + Send + Sync + 'static {
-    /// May modify the compute property depending on the strategy
-    fn compute_property<R: RetroForward>(retro_forward: R) -> ComputingProperty;
+    /// May modify the compute property depending on the strategy, given the nodes of the parents
+    /// of the operation
+    fn compute_property<R: RetroForward>(
+        retro_forward: R,
+        parents: &[NodeRef],
+    ) -> ComputingProperty;
 
     /// Checkpoints parents if necessary in the strategy
     fn checkpoint_parents<'a, B2, A>(
@@ -39,7 +47,10 @@ pub struct NoCheckpointing {}
 
 impl CheckpointStrategy for NoCheckpointing {
     /// An operation marked as memory bound is actually compute bound.
-    fn compute_property<R: RetroForward>(_retro_forward: R) -> ComputingProperty {
+    fn compute_property<R: RetroForward>(
+        _retro_forward: R,
+        _parents: &[NodeRef],
+    ) -> ComputingProperty {
         ComputingProperty::ComputeBound
     }
 
@@ -65,7 +76,10 @@ pub struct BalancedCheckpointing {}
 impl CheckpointStrategy for BalancedCheckpointing {
     /// An operation marked as memory bound is memory bound.
     /// When memory bound, an operation needs to save its RetroForward
-    fn compute_property<R: RetroForward>(retro_forward: R) -> ComputingProperty {
+    fn compute_property<R: RetroForward>(
+        retro_forward: R,
+        _parents: &[NodeRef],
+    ) -> ComputingProperty {
         ComputingProperty::MemoryBound {
             retro_forward: Arc::new(retro_forward),
         }
@@ -100,3 +114,43 @@ impl CheckpointStrategy for BalancedCheckpointing {
         Ok(())
     }
 }
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Operations marked as memory bound are recomputed once the activations kept in memory by their
+/// autodiff graph exceed a budget of `BYTES` bytes, and kept in memory otherwise.
+///
+/// Each graph has its own budget, so the activations kept by other forward passes, e.g. on other
+/// threads, don't count. Compute bound operations are always kept in memory, so the budget can be
+/// exceeded; the
+/// [checkpoint report](super::report::CheckpointReport) shows the memory saved and the operations
+/// recomputed for a given budget.
+pub struct BudgetCheckpointing<const BYTES: usize> {}
+
+impl<const BYTES: usize> CheckpointStrategy for BudgetCheckpointing<BYTES> {
+    /// An operation marked as memory bound is memory bound when the budget is spent.
+    fn compute_property<R: RetroForward>(
+        retro_forward: R,
+        parents: &[NodeRef],
+    ) -> ComputingProperty {
+        if GraphMemory::kept_bytes(parents) >= BYTES {
+            ComputingProperty::MemoryBound {
+                retro_forward: Arc::new(retro_forward),
+            }
+        } else {
+            ComputingProperty::ComputeBound
+        }
+    }
+
+    /// The parents of a memory bound operation are checkpointed like with
+    /// [balanced checkpointing](BalancedCheckpointing).
+    fn checkpoint_parents<'a, B2, A>(
+        parents: A,
+        builder: &mut CheckpointerBuilder,
+    ) -> Result<(), CheckpointingError>
+    where
+        B2: Backend,
+        A: IntoIterator<Item = &'a AutodiffTensor<B2>>,
+    {
+        BalancedCheckpointing::checkpoint_parents(parents, builder)
+    }
+}
@@ -0,0 +1,64 @@
+use super::DualTensor;
+use alloc::{format, string::String};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// Enable forward-mode auto-differentiation on a backend.
+///
+/// This works as a backend decorator, where each float tensor is a dual number carrying its value
+/// (the primal) along with its derivative in a chosen direction of the inputs (the tangent). Every
+/// operation computes the tangent of its output from the tangents of its inputs, so a single
+/// forward pass yields a Jacobian-vector product without recording a graph.
+///
+/// The tangents are set with [make_dual](super::make_dual) and read with
+/// [unpack_dual](super::unpack_dual), or through the [jvp](crate::functional::jvp) helper.
+/// Combined with reverse mode as `Autodiff<Dual<B>>`, the gradients carry tangents as well, which
+/// computes Hessian-vector products (forward-over-reverse).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dual<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Dual<B> {
+    type Device = B::Device;
+
+    type FloatTensorPrimitive = DualTensor<B>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive = B::IntTensorPrimitive;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive = B::BoolTensorPrimitive;
+    type BoolElem = B::BoolElem;
+
+    type QuantizedTensorPrimitive = B::QuantizedTensorPrimitive;
+    type QuantizedEncoding = B::QuantizedEncoding;
+
+    fn ad_enabled() -> bool {
+        B::ad_enabled()
+    }
+
+    fn name(device: &Self::Device) -> String {
+        format!("dual<{}>", B::name(device))
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device) {
+        B::sync(device)
+    }
+
+    fn memory_static_allocations<Output, Input, Func: Fn(Input) -> Output>(
+        device: &Self::Device,
+        input: Input,
+        func: Func,
+    ) -> Output {
+        B::memory_static_allocations(device, input, func)
+    }
+
+    fn memory_cleanup(device: &Self::Device) {
+        B::memory_cleanup(device)
+    }
+}
@@ -0,0 +1,42 @@
+mod backend;
+mod ops;
+mod tensor;
+
+pub use backend::Dual;
+pub use tensor::DualTensor;
+
+use burn_tensor::{Tensor, TensorPrimitive, backend::Backend};
+
+/// Create a tensor of the [dual](Dual) backend from its value and its tangent, which is the
+/// direction in which the derivatives of the operations using it are computed.
+///
+/// # Panics
+///
+/// If the tangent doesn't have the shape of the value.
+pub fn make_dual<B: Backend, const D: usize>(
+    primal: Tensor<B, D>,
+    tangent: Tensor<B, D>,
+) -> Tensor<Dual<B>, D> {
+    let tensor = DualTensor::dual(
+        primal.into_primitive().tensor(),
+        Some(tangent.into_primitive().tensor()),
+    );
+
+    Tensor::from_primitive(TensorPrimitive::Float(tensor))
+}
+
+/// Split a tensor of the [dual](Dual) backend into its value and its tangent.
+///
+/// The tangent is `None` when the tensor doesn't depend on any tensor created with
+/// [make_dual], in which case it is zero.
+pub fn unpack_dual<B: Backend, const D: usize>(
+    tensor: Tensor<Dual<B>, D>,
+) -> (Tensor<B, D>, Option<Tensor<B, D>>) {
+    let tensor = tensor.into_primitive().tensor();
+    let primal = Tensor::from_primitive(TensorPrimitive::Float(tensor.primal));
+    let tangent = tensor
+        .tangent
+        .map(|tangent| Tensor::from_primitive(TensorPrimitive::Float(tangent)));
+
+    (primal, tangent)
+}
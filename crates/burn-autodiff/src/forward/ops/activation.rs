@@ -0,0 +1,43 @@
+use crate::forward::{Dual, DualTensor};
+use burn_tensor::{
+    backend::Backend,
+    ops::{ActivationOps, FloatTensor},
+};
+
+/// The activations without a dedicated implementation use the default ones, which are written
+/// with float operations, so their tangents follow from the chain rule.
+impl<B: Backend> ActivationOps<Self> for Dual<B> {
+    fn relu(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let primal = B::relu(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::relu_backward(primal.clone(), tangent));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn relu_backward(output: FloatTensor<Self>, grad: FloatTensor<Self>) -> FloatTensor<Self> {
+        grad.linear(|grad| B::relu_backward(output.primal.clone(), grad))
+    }
+
+    fn sigmoid(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // dσ(x) = σ(x) (1 - σ(x)) dx
+        let primal = B::sigmoid(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::sigmoid_backward(primal.clone(), tangent));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn gelu(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::gelu_backward(tensor.primal.clone(), tangent));
+
+        DualTensor {
+            primal: B::gelu(tensor.primal),
+            tangent,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::forward::{Dual, DualTensor, tensor::add_tangents};
+use burn_tensor::{
+    ElementConversion, TensorMetadata,
+    backend::Backend,
+    ops::{ComplexOps, ComplexTensor, FloatTensor},
+};
+
+/// The arithmetic operations use the default implementations, which are written with float
+/// operations on the real and imaginary parts, so their tangents follow from the chain rule.
+///
+/// The modulus and the argument aren't differentiable at zero, where their tangent is defined to
+/// be zero like the gradients of the reverse mode.
+impl<B: Backend> ComplexOps<Self> for Dual<B> {
+    fn complex_abs(tensor: ComplexTensor<Self>) -> FloatTensor<Self> {
+        let (real, imag) = (tensor.real, tensor.imag);
+        let primal = B::complex_abs(ComplexTensor::<B>::new(
+            real.primal.clone(),
+            imag.primal.clone(),
+        ));
+
+        // d|z| = (x dx + y dy) / |z|
+        let modulus = safe_modulus::<B>(primal.clone());
+        let tangent_real = real
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_div(real.primal, modulus.clone())));
+        let tangent_imag = imag
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_div(imag.primal, modulus)));
+        let tangent = add_tangents::<B>(tangent_real, tangent_imag, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+
+    fn complex_angle(tensor: ComplexTensor<Self>) -> FloatTensor<Self> {
+        let (real, imag) = (tensor.real, tensor.imag);
+        let primal = B::complex_angle(ComplexTensor::<B>::new(
+            real.primal.clone(),
+            imag.primal.clone(),
+        ));
+
+        if real.tangent.is_none() && imag.tangent.is_none() {
+            return DualTensor::new(primal);
+        }
+
+        // d atan2(y, x) = (x dy - y dx) / |z|²
+        let modulus = safe_modulus::<B>(B::complex_abs(ComplexTensor::<B>::new(
+            real.primal.clone(),
+            imag.primal.clone(),
+        )));
+        let modulus_sq = B::float_mul(modulus.clone(), modulus);
+        let tangent_real = real.tangent.map(|tangent| {
+            let partial = B::float_div(B::float_neg(imag.primal.clone()), modulus_sq.clone());
+            B::float_mul(tangent, partial)
+        });
+        let tangent_imag = imag
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_div(real.primal, modulus_sq)));
+        let tangent = add_tangents::<B>(tangent_real, tangent_imag, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+}
+
+/// Replaces a zero modulus by one, so that the tangents at zero, where both parts are zero, are
+/// zero instead of undefined.
+fn safe_modulus<B: Backend>(modulus: FloatTensor<B>) -> FloatTensor<B> {
+    let zero = B::float_equal_elem(modulus.clone(), 0.elem());
+    B::float_mask_fill(modulus, zero, 1.elem())
+}
@@ -0,0 +1,41 @@
+use crate::forward::{Dual, DualTensor};
+use burn_tensor::{
+    backend::Backend,
+    ops::{FftOps, FloatTensor},
+};
+
+/// The transform is linear, so the tangents of the real and imaginary parts are transformed along
+/// with the values.
+impl<B: Backend> FftOps<Self> for Dual<B> {
+    fn fft(
+        real: FloatTensor<Self>,
+        imag: FloatTensor<Self>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let (tangent_real, tangent_imag) = match (&real.tangent, &imag.tangent) {
+            (None, None) => (None, None),
+            _ => {
+                let (real, imag) = B::fft(
+                    real.tangent_or_zeros(),
+                    imag.tangent_or_zeros(),
+                    dim,
+                    inverse,
+                );
+                (Some(real), Some(imag))
+            }
+        };
+        let (output_real, output_imag) = B::fft(real.primal, imag.primal, dim, inverse);
+
+        (
+            DualTensor {
+                primal: output_real,
+                tangent: tangent_real,
+            },
+            DualTensor {
+                primal: output_imag,
+                tangent: tangent_imag,
+            },
+        )
+    }
+}
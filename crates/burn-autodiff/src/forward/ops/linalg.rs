@@ -0,0 +1,328 @@
+use crate::{
+    forward::{Dual, DualTensor, tensor::add_tangents},
+    ops::linalg::{eye, matrix_dims, slice_cols, tril, triu},
+};
+use burn_tensor::{
+    ElementConversion, TensorMetadata,
+    backend::Backend,
+    ops::{FloatTensor, LinalgOps},
+};
+
+/// The decompositions of symmetric matrices only read one triangle of the input, so their tangent
+/// is computed for the symmetric part of the input tangent, which matches the symmetric gradients
+/// of the reverse mode.
+impl<B: Backend> LinalgOps<Self> for Dual<B> {
+    fn cholesky(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let primal = B::cholesky(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| cholesky_tangent::<B>(symmetrize::<B>(tangent), primal.clone()));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn qr(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let (q, r) = B::qr(tensor.primal);
+
+        match tensor.tangent {
+            Some(tangent) => {
+                let (tangent_q, tangent_r) = qr_tangent::<B>(tangent, q.clone(), r.clone());
+                (
+                    DualTensor::dual(q, Some(tangent_q)),
+                    DualTensor::dual(r, Some(tangent_r)),
+                )
+            }
+            None => (DualTensor::new(q), DualTensor::new(r)),
+        }
+    }
+
+    fn svd(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>, FloatTensor<Self>) {
+        let (u, s, vh) = B::svd(tensor.primal);
+
+        match tensor.tangent {
+            Some(tangent) => {
+                let (tangent_u, tangent_s, tangent_vh) =
+                    svd_tangent::<B>(tangent, u.clone(), s.clone(), vh.clone());
+                (
+                    DualTensor::dual(u, Some(tangent_u)),
+                    DualTensor::dual(s, Some(tangent_s)),
+                    DualTensor::dual(vh, Some(tangent_vh)),
+                )
+            }
+            None => (DualTensor::new(u), DualTensor::new(s), DualTensor::new(vh)),
+        }
+    }
+
+    fn eigh(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let (values, vectors) = B::eigh(tensor.primal);
+
+        match tensor.tangent {
+            Some(tangent) => {
+                let (tangent_values, tangent_vectors) =
+                    eigh_tangent::<B>(symmetrize::<B>(tangent), values.clone(), vectors.clone());
+                (
+                    DualTensor::dual(values, Some(tangent_values)),
+                    DualTensor::dual(vectors, Some(tangent_vectors)),
+                )
+            }
+            None => (DualTensor::new(values), DualTensor::new(vectors)),
+        }
+    }
+
+    fn solve_triangular(
+        a: FloatTensor<Self>,
+        b: FloatTensor<Self>,
+        upper: bool,
+    ) -> FloatTensor<Self> {
+        // X = T⁻¹B, so dX = T⁻¹(dB - dT X), where only the triangle T of A is read.
+        let primal = B::solve_triangular(a.primal.clone(), b.primal, upper);
+        let tangent_a = a.tangent.map(|tangent| {
+            let tangent = match upper {
+                true => triu::<B>(tangent, 0),
+                false => tril::<B>(tangent, 0),
+            };
+            B::float_neg(B::float_matmul(tangent, primal.clone()))
+        });
+        let tangent = add_tangents::<B>(b.tangent, tangent_a, &primal.shape())
+            .map(|tangent| B::solve_triangular(a.primal, tangent, upper));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn solve(a: FloatTensor<Self>, b: FloatTensor<Self>) -> FloatTensor<Self> {
+        // X = A⁻¹B, so dX = A⁻¹(dB - dA X).
+        let primal = B::solve(a.primal.clone(), b.primal);
+        let tangent_a = a
+            .tangent
+            .map(|tangent| B::float_neg(B::float_matmul(tangent, primal.clone())));
+        let tangent = add_tangents::<B>(b.tangent, tangent_a, &primal.shape())
+            .map(|tangent| B::solve(a.primal, tangent));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn inverse(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // dY = -Y dA Y
+        let primal = B::inverse(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let tangent = B::float_matmul(B::float_matmul(primal.clone(), tangent), primal.clone());
+            B::float_neg(tangent)
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn det(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d det(A) = det(A) tr(A⁻¹ dA)
+        let primal = B::det(tensor.primal.clone());
+        let tangent = tensor.tangent.map(|tangent| {
+            let trace = inverse_trace::<B>(tensor.primal, tangent);
+            B::float_mul(trace, primal.clone())
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn slogdet(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        // d log|det(A)| = tr(A⁻¹ dA), while the sign is piecewise constant.
+        let (sign, logabsdet) = B::slogdet(tensor.primal.clone());
+        let tangent = tensor
+            .tangent
+            .map(|tangent| inverse_trace::<B>(tensor.primal, tangent));
+
+        (
+            DualTensor::new(sign),
+            DualTensor {
+                primal: logabsdet,
+                tangent,
+            },
+        )
+    }
+}
+
+/// Returns `(X + Xᵀ) / 2`.
+fn symmetrize<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let tensor = B::float_add(tensor.clone(), B::float_transpose(tensor));
+    B::float_mul_scalar(tensor, 0.5.elem())
+}
+
+/// Returns the diagonal of a batch of square matrices as rows of shape `[..., 1, n]`.
+fn diagonal_row<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let (n, _) = matrix_dims::<B>(&tensor);
+    let rank = tensor.shape().num_dims();
+    let diagonal = B::float_mul(tensor.clone(), eye::<B>(&tensor, n));
+
+    B::float_sum_dim(diagonal, rank - 2)
+}
+
+/// Returns `1 / E` outside of the diagonal and zero on the diagonal, where `E[i, j]` is
+/// `values[j] - values[i]` for the given row of values.
+fn inverse_gaps<B: Backend>(values: FloatTensor<B>) -> FloatTensor<B> {
+    let (_, n) = matrix_dims::<B>(&values);
+    let eye = eye::<B>(&values, n);
+    let gaps = B::float_sub(values.clone(), B::float_transpose(values));
+    let gaps = B::float_recip(B::float_add(gaps, eye.clone()));
+
+    B::float_sub(gaps, eye)
+}
+
+/// Computes `tr(A⁻¹ dA)` as a tensor of shape `[..., 1, 1]`.
+fn inverse_trace<B: Backend>(a: FloatTensor<B>, tangent: FloatTensor<B>) -> FloatTensor<B> {
+    let rank = tangent.shape().num_dims();
+    let product = B::float_mul(B::float_transpose(B::inverse(a)), tangent);
+
+    B::float_sum_dim(B::float_sum_dim(product, rank - 1), rank - 2)
+}
+
+/// Computes `dA R⁻¹` for an upper triangular `R`.
+fn right_solve_upper<B: Backend>(tangent: FloatTensor<B>, r: FloatTensor<B>) -> FloatTensor<B> {
+    // dA R⁻¹ = (R⁻ᵀ dAᵀ)ᵀ
+    let solved = B::solve_triangular(B::float_transpose(r), B::float_transpose(tangent), false);
+    B::float_transpose(solved)
+}
+
+/// Tangent of the Cholesky decomposition `A = L Lᵀ`.
+///
+/// `dL = L Φ(L⁻¹ dA L⁻ᵀ)`, where `Φ` takes the lower triangle and halves the diagonal.
+fn cholesky_tangent<B: Backend>(tangent: FloatTensor<B>, l: FloatTensor<B>) -> FloatTensor<B> {
+    let (n, _) = matrix_dims::<B>(&l);
+    let inner = B::solve_triangular(l.clone(), tangent, false);
+    let inner = B::float_transpose(B::solve_triangular(
+        l.clone(),
+        B::float_transpose(inner),
+        false,
+    ));
+
+    let diagonal = B::float_mul(inner.clone(), eye::<B>(&inner, n));
+    let phi = B::float_sub(
+        tril::<B>(inner, 0),
+        B::float_mul_scalar(diagonal, 0.5.elem()),
+    );
+
+    B::float_matmul(l, phi)
+}
+
+/// Tangent of the reduced QR decomposition `A = QR`.
+///
+/// When `A` has more columns than rows, it is split as `A = [X | Y]` with `R = [U | V]`, such that
+/// `X = QU` is a square decomposition and `V = QᵀY`.
+fn qr_tangent<B: Backend>(
+    tangent: FloatTensor<B>,
+    q: FloatTensor<B>,
+    r: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let (m, n) = (matrix_dims::<B>(&q).0, matrix_dims::<B>(&r).1);
+
+    if m >= n {
+        return qr_tangent_tall::<B>(tangent, q, r);
+    }
+
+    let u = slice_cols::<B>(r.clone(), 0..m);
+    let v = slice_cols::<B>(r, m..n);
+    let tangent_x = slice_cols::<B>(tangent.clone(), 0..m);
+    let tangent_y = slice_cols::<B>(tangent, m..n);
+
+    let (tangent_q, tangent_u) = qr_tangent_tall::<B>(tangent_x, q.clone(), u);
+
+    // dV = Qᵀ (dY - dQ V)
+    let tangent_y = B::float_sub(tangent_y, B::float_matmul(tangent_q.clone(), v));
+    let tangent_v = B::float_matmul(B::float_transpose(q), tangent_y);
+
+    let rank = tangent_u.shape().num_dims();
+    let tangent_r = B::float_cat(alloc::vec![tangent_u, tangent_v], rank - 1);
+
+    (tangent_q, tangent_r)
+}
+
+/// Tangent of the reduced QR decomposition of a matrix with at least as many rows as columns.
+///
+/// With `C = Qᵀ dA R⁻¹`, `QᵀdQ` is the skew-symmetric matrix `Ω` matching the strictly lower
+/// triangle of `C`, such that `dR = (C - Ω) R` and `dQ = dA R⁻¹ - Q (C - Ω)`.
+fn qr_tangent_tall<B: Backend>(
+    tangent: FloatTensor<B>,
+    q: FloatTensor<B>,
+    r: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let tangent = right_solve_upper::<B>(tangent, r.clone());
+    let c = B::float_matmul(B::float_transpose(q.clone()), tangent.clone());
+
+    let lower = tril::<B>(c.clone(), -1);
+    let omega = B::float_sub(lower.clone(), B::float_transpose(lower));
+    let upper = B::float_sub(c, omega);
+
+    let tangent_r = B::float_matmul(upper.clone(), r);
+    let tangent_q = B::float_sub(tangent, B::float_matmul(q, upper));
+
+    (tangent_q, tangent_r)
+}
+
+/// Tangent of the reduced singular value decomposition `A = U diag(S) Vh`.
+///
+/// With `dP = Uᵀ dA V` and `F[i, j] = 1 / (S[j]² - S[i]²)` outside of the diagonal, `dS` is the
+/// diagonal of `dP`, `dU = U [F ∘ (dP S + S dPᵀ)]` and `dV = V [F ∘ (S dP + dPᵀ S)]`, and
+/// projection terms are added for the singular vectors that don't span the whole space when the
+/// matrix isn't square.
+fn svd_tangent<B: Backend>(
+    tangent: FloatTensor<B>,
+    u: FloatTensor<B>,
+    s: FloatTensor<B>,
+    vh: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>) {
+    let (m, k) = matrix_dims::<B>(&u);
+    let n = matrix_dims::<B>(&vh).1;
+
+    let v = B::float_transpose(vh);
+    let u_t = B::float_transpose(u.clone());
+    let s_row = s;
+    let s_col = B::float_transpose(s_row.clone());
+    let f = inverse_gaps::<B>(B::float_mul(s_row.clone(), s_row.clone()));
+
+    let tangent_v_proj = B::float_matmul(tangent.clone(), v.clone());
+    let p = B::float_matmul(u_t.clone(), tangent_v_proj.clone());
+    let tangent_s = diagonal_row::<B>(p.clone());
+
+    let ps = B::float_mul(p.clone(), s_row.clone());
+    let ps = B::float_add(ps.clone(), B::float_transpose(ps));
+    let mut tangent_u = B::float_matmul(u.clone(), B::float_mul(f.clone(), ps));
+
+    let sp = B::float_mul(s_col, p);
+    let sp = B::float_add(sp.clone(), B::float_transpose(sp));
+    let mut tangent_v = B::float_matmul(v.clone(), B::float_mul(f, sp));
+
+    if m > k {
+        // (I - U Uᵀ) dA V S⁻¹
+        let projected = B::float_matmul(u.clone(), B::float_matmul(u_t, tangent_v_proj.clone()));
+        let term = B::float_sub(tangent_v_proj, projected);
+        tangent_u = B::float_add(tangent_u, B::float_div(term, s_row.clone()));
+    }
+    if n > k {
+        // (I - V Vᵀ) dAᵀ U S⁻¹
+        let tangent_u_proj = B::float_matmul(B::float_transpose(tangent), u);
+        let projected = B::float_matmul(
+            v.clone(),
+            B::float_matmul(B::float_transpose(v), tangent_u_proj.clone()),
+        );
+        let term = B::float_sub(tangent_u_proj, projected);
+        tangent_v = B::float_add(tangent_v, B::float_div(term, s_row));
+    }
+
+    (tangent_u, tangent_s, B::float_transpose(tangent_v))
+}
+
+/// Tangent of the symmetric eigendecomposition `A = V diag(L) Vᵀ`.
+///
+/// With `dP = Vᵀ dA V` and `F[i, j] = 1 / (L[j] - L[i])` outside of the diagonal, `dL` is the
+/// diagonal of `dP` and `dV = V (F ∘ dP)`.
+fn eigh_tangent<B: Backend>(
+    tangent: FloatTensor<B>,
+    values: FloatTensor<B>,
+    vectors: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let p = B::float_matmul(B::float_transpose(vectors.clone()), tangent);
+    let p = B::float_matmul(p, vectors.clone());
+
+    let tangent_values = diagonal_row::<B>(p.clone());
+    let tangent_vectors = B::float_matmul(vectors, B::float_mul(inverse_gaps::<B>(values), p));
+
+    (tangent_values, tangent_vectors)
+}
@@ -0,0 +1,10 @@
+mod activation;
+mod bool_tensor;
+mod complex;
+mod fft;
+mod int_tensor;
+mod linalg;
+mod module;
+mod qtensor;
+mod tensor;
+mod transaction;
@@ -0,0 +1,459 @@
+use crate::forward::{
+    Dual, DualTensor,
+    tensor::{add_tangents, broadcast},
+};
+use alloc::vec;
+use burn_tensor::{
+    ElementConversion, Shape, TensorMetadata,
+    backend::Backend,
+    ops::{
+        ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, FloatTensor,
+        IntTensor, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    },
+};
+
+/// The convolutions are linear in both the input and the weight, so their tangent is the sum of
+/// the convolutions with one tangent each, while the tangent of deform conv 2d with respect to the
+/// offset follows from the derivative of the bilinear interpolation. The pooling and interpolation
+/// operations, along with their backward passes, are linear in the tensor they transform and are
+/// applied on the tangent directly, while max pooling selects the tangents at the positions of the
+/// maximums.
+impl<B: Backend> ModuleOps<Self> for Dual<B> {
+    fn conv2d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self> {
+        let primal = B::conv2d(
+            x.primal.clone(),
+            weight.primal.clone(),
+            bias.clone().map(|bias| bias.primal),
+            options.clone(),
+        );
+        let tangent = conv_tangent::<B>(x, weight, bias, &primal.shape(), |x, weight| {
+            B::conv2d(x, weight, None, options.clone())
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn deform_conv2d(
+        x: FloatTensor<Self>,
+        offset: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        mask: Option<FloatTensor<Self>>,
+        bias: Option<FloatTensor<Self>>,
+        options: DeformConvOptions<2>,
+    ) -> FloatTensor<Self> {
+        let offset_tangent = offset.tangent;
+        let offset = offset.primal;
+        let mask_primal = mask.as_ref().map(|mask| mask.primal.clone());
+        let primal = B::deform_conv2d(
+            x.primal.clone(),
+            offset.clone(),
+            weight.primal.clone(),
+            mask_primal.clone(),
+            bias.clone().map(|bias| bias.primal),
+            options.clone(),
+        );
+
+        // The output is linear in each of the input, the weight and the mask.
+        let deform_conv = |x, weight, mask| {
+            B::deform_conv2d(x, offset.clone(), weight, mask, None, options.clone())
+        };
+        let tangent_x = x
+            .tangent
+            .map(|tangent| deform_conv(tangent, weight.primal.clone(), mask_primal.clone()));
+        let tangent_weight = weight
+            .tangent
+            .map(|tangent| deform_conv(x.primal.clone(), tangent, mask_primal.clone()));
+        let tangent_mask = mask
+            .and_then(|mask| mask.tangent)
+            .map(|tangent| deform_conv(x.primal.clone(), weight.primal.clone(), Some(tangent)));
+        let tangent_offset = offset_tangent.map(|tangent| {
+            deform_conv2d_offset_tangent::<B>(
+                x.primal,
+                offset.clone(),
+                tangent,
+                weight.primal,
+                mask_primal,
+                options.clone(),
+            )
+        });
+
+        let shape = primal.shape();
+        let tangent = add_tangents::<B>(tangent_x, tangent_weight, &shape);
+        let tangent = add_tangents::<B>(tangent, tangent_mask, &shape);
+        let tangent = add_tangents::<B>(tangent, tangent_offset, &shape);
+        let tangent = add_tangents::<B>(tangent, bias_tangent::<B>(bias, &shape), &shape);
+
+        DualTensor { primal, tangent }
+    }
+
+    fn deform_conv2d_backward(
+        x: FloatTensor<Self>,
+        offset: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        mask: Option<FloatTensor<Self>>,
+        bias: Option<FloatTensor<Self>>,
+        output_grad: FloatTensor<Self>,
+        options: DeformConvOptions<2>,
+    ) -> DeformConv2dBackward<Self> {
+        let has_tangent = [&x, &offset, &weight, &output_grad]
+            .into_iter()
+            .chain(mask.iter())
+            .chain(bias.iter())
+            .any(|tensor| tensor.tangent.is_some());
+        assert!(
+            !has_tangent,
+            "Can't compute the tangent of deform conv 2d backward."
+        );
+
+        let backward = B::deform_conv2d_backward(
+            x.primal,
+            offset.primal,
+            weight.primal,
+            mask.map(|mask| mask.primal),
+            bias.map(|bias| bias.primal),
+            output_grad.primal,
+            options,
+        );
+
+        DeformConv2dBackward::new(
+            DualTensor::new(backward.x_grad),
+            DualTensor::new(backward.offset_grad),
+            DualTensor::new(backward.weight_grad),
+            backward.mask_grad.map(DualTensor::new),
+            backward.bias_grad.map(DualTensor::new),
+        )
+    }
+
+    fn conv3d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<3>,
+    ) -> FloatTensor<Self> {
+        let primal = B::conv3d(
+            x.primal.clone(),
+            weight.primal.clone(),
+            bias.clone().map(|bias| bias.primal),
+            options.clone(),
+        );
+        let tangent = conv_tangent::<B>(x, weight, bias, &primal.shape(), |x, weight| {
+            B::conv3d(x, weight, None, options.clone())
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self> {
+        let primal = B::conv_transpose2d(
+            x.primal.clone(),
+            weight.primal.clone(),
+            bias.clone().map(|bias| bias.primal),
+            options.clone(),
+        );
+        let tangent = conv_tangent::<B>(x, weight, bias, &primal.shape(), |x, weight| {
+            B::conv_transpose2d(x, weight, None, options.clone())
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn conv_transpose3d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvTransposeOptions<3>,
+    ) -> FloatTensor<Self> {
+        let primal = B::conv_transpose3d(
+            x.primal.clone(),
+            weight.primal.clone(),
+            bias.clone().map(|bias| bias.primal),
+            options.clone(),
+        );
+        let tangent = conv_tangent::<B>(x, weight, bias, &primal.shape(), |x, weight| {
+            B::conv_transpose3d(x, weight, None, options.clone())
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        x.linear(|x| B::avg_pool2d(x, kernel_size, stride, padding, count_include_pad))
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        grad.linear(|grad| {
+            B::avg_pool2d_backward(
+                x.primal.clone(),
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )
+        })
+    }
+
+    fn adaptive_avg_pool2d(x: FloatTensor<Self>, output_size: [usize; 2]) -> FloatTensor<Self> {
+        x.linear(|x| B::adaptive_avg_pool2d(x, output_size))
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        grad.linear(|grad| B::adaptive_avg_pool2d_backward(x.primal.clone(), grad))
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self> {
+        match x.tangent {
+            Some(_) => {
+                Self::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation).output
+            }
+            None => DualTensor::new(B::max_pool2d(
+                x.primal,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let output = B::max_pool2d_with_indices(x.primal, kernel_size, stride, padding, dilation);
+        let tangent = x
+            .tangent
+            .map(|tangent| max_pool2d_tangent::<B>(tangent, output.indices.clone()));
+
+        MaxPool2dWithIndices::new(
+            DualTensor {
+                primal: output.output,
+                tangent,
+            },
+            output.indices,
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool2dBackward<Self> {
+        // The gradients are routed to the positions of the maximums, which are constant around
+        // the input, so only the tangent of the output gradient contributes.
+        let x_grad = output_grad.linear(|grad| {
+            B::max_pool2d_with_indices_backward(
+                x.primal.clone(),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices.clone(),
+            )
+            .x_grad
+        });
+
+        MaxPool2dBackward::new(x_grad)
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self> {
+        x.linear(|x| B::interpolate(x, output_size, options.clone()))
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self> {
+        grad.linear(|grad| {
+            B::interpolate_backward(x.primal.clone(), grad, output_size, options.clone())
+        })
+    }
+}
+
+/// Computes the tangent of a convolution, which is bilinear in the input and the weight.
+fn conv_tangent<B: Backend>(
+    x: DualTensor<B>,
+    weight: DualTensor<B>,
+    bias: Option<DualTensor<B>>,
+    shape: &Shape,
+    conv: impl Fn(FloatTensor<B>, FloatTensor<B>) -> FloatTensor<B>,
+) -> Option<FloatTensor<B>> {
+    let tangent_x = x
+        .tangent
+        .map(|tangent| conv(tangent, weight.primal.clone()));
+    let tangent_weight = weight.tangent.map(|tangent| conv(x.primal, tangent));
+    let tangent = add_tangents::<B>(tangent_x, tangent_weight, shape);
+
+    add_tangents::<B>(tangent, bias_tangent::<B>(bias, shape), shape)
+}
+
+/// Tangent of deform conv 2d with respect to the offset.
+///
+/// The sampled values are bilinear interpolations of the input. Along one axis, their derivative
+/// is the difference between the values sampled at the positions rounded down and rounded up
+/// along that axis, which are sampled with integer offsets, with zeros outside of the input. The
+/// tangent of the offset along the axis then scales each sampled value like the mask.
+fn deform_conv2d_offset_tangent<B: Backend>(
+    x: FloatTensor<B>,
+    offset: FloatTensor<B>,
+    tangent: FloatTensor<B>,
+    weight: FloatTensor<B>,
+    mask: Option<FloatTensor<B>>,
+    options: DeformConvOptions<2>,
+) -> FloatTensor<B> {
+    let [batch_size, offset_channels, height_out, width_out] = offset.shape().dims();
+    let num_samples = offset_channels / 2;
+
+    // The offsets are stored as pairs `(y, x)` for each kernel position of each offset group.
+    let split = |offset: FloatTensor<B>, axis: usize| {
+        let offset = B::float_reshape(
+            offset,
+            Shape::new([batch_size, num_samples, 2, height_out, width_out]),
+        );
+        let offset = B::float_slice(
+            offset,
+            &[
+                0..batch_size,
+                0..num_samples,
+                axis..axis + 1,
+                0..height_out,
+                0..width_out,
+            ],
+        );
+        B::float_reshape(
+            offset,
+            Shape::new([batch_size, num_samples, height_out, width_out]),
+        )
+    };
+    let merge = |offset_y, offset_x| {
+        let shape = Shape::new([batch_size, num_samples, 1, height_out, width_out]);
+        let offset = B::float_cat(
+            vec![
+                B::float_reshape(offset_y, shape.clone()),
+                B::float_reshape(offset_x, shape),
+            ],
+            2,
+        );
+        B::float_reshape(
+            offset,
+            Shape::new([batch_size, offset_channels, height_out, width_out]),
+        )
+    };
+    let scale = |tangent| match &mask {
+        Some(mask) => B::float_mul(mask.clone(), tangent),
+        None => tangent,
+    };
+    let deform_conv = |offset, mask| {
+        B::deform_conv2d(
+            x.clone(),
+            offset,
+            weight.clone(),
+            Some(mask),
+            None,
+            options.clone(),
+        )
+    };
+    // Difference between the values sampled at the offsets rounded up and rounded down.
+    let derivative = |rounded_up, rounded_down, mask: FloatTensor<B>| {
+        B::float_sub(
+            deform_conv(rounded_up, mask.clone()),
+            deform_conv(rounded_down, mask),
+        )
+    };
+
+    let offset_y = split(offset.clone(), 0);
+    let offset_x = split(offset, 1);
+    let floor_y = B::float_floor(offset_y.clone());
+    let floor_x = B::float_floor(offset_x.clone());
+    let ceil_y = B::float_add_scalar(floor_y.clone(), 1.elem());
+    let ceil_x = B::float_add_scalar(floor_x.clone(), 1.elem());
+
+    let tangent_y = derivative(
+        merge(ceil_y, offset_x.clone()),
+        merge(floor_y, offset_x),
+        scale(split(tangent.clone(), 0)),
+    );
+    let tangent_x = derivative(
+        merge(offset_y.clone(), ceil_x),
+        merge(offset_y, floor_x),
+        scale(split(tangent, 1)),
+    );
+
+    B::float_add(tangent_y, tangent_x)
+}
+
+/// Broadcasts the tangent of a bias of shape `[channels]` along the channel dimension of the
+/// output.
+fn bias_tangent<B: Backend>(bias: Option<DualTensor<B>>, shape: &Shape) -> Option<FloatTensor<B>> {
+    let tangent = bias.and_then(|bias| bias.tangent)?;
+    let mut dims = vec![1; shape.num_dims()];
+    dims[1] = shape.dims[1];
+    let tangent = B::float_reshape(tangent, Shape::from(dims));
+
+    Some(broadcast::<B>(tangent, shape))
+}
+
+/// Selects the tangents at the positions of the maximums, given as flat indices over the spatial
+/// dimensions of the input.
+fn max_pool2d_tangent<B: Backend>(
+    tangent: FloatTensor<B>,
+    indices: IntTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = tangent.shape().dims();
+    let shape_out = indices.shape();
+    let [_, _, height_out, width_out] = shape_out.dims();
+
+    let tangent = B::float_reshape(tangent, Shape::new([batch_size, channels, height * width]));
+    let indices = B::int_reshape(
+        indices,
+        Shape::new([batch_size, channels, height_out * width_out]),
+    );
+
+    B::float_reshape(B::float_gather(2, tangent, indices), shape_out)
+}
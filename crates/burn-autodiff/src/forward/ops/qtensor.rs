@@ -0,0 +1,189 @@
+use core::ops::Range;
+
+use burn_tensor::{
+    Device, ElementConversion, Shape, TensorData, TensorMetadata,
+    backend::Backend,
+    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
+    quantization::{QuantScheme, QuantizationParametersPrimitive},
+};
+
+use crate::forward::{Dual, DualTensor, tensor::add_tangents};
+
+/// Quantized tensors don't carry tangents, so the quantization is a constant. The fake
+/// quantization follows the straight-through estimator of the reverse mode, which makes the
+/// tangents agree with the gradients of [Autodiff](crate::Autodiff).
+impl<B: Backend> QTensorOps<Self> for Dual<B> {
+    fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
+        B::q_from_data(data, device)
+    }
+
+    fn quantize(
+        tensor: FloatTensor<Self>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self> {
+        B::quantize(
+            tensor.primal,
+            scheme,
+            QuantizationParametersPrimitive {
+                scales: qparams.scales.primal,
+                offsets: qparams.offsets.map(|offsets| offsets.primal),
+            },
+        )
+    }
+
+    fn quantize_dynamic(tensor: FloatTensor<Self>, scheme: &QuantScheme) -> QuantizedTensor<Self> {
+        B::quantize_dynamic(tensor.primal, scheme)
+    }
+
+    fn dequantize(tensor: QuantizedTensor<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::dequantize(tensor))
+    }
+
+    fn fake_quantize(
+        tensor: FloatTensor<Self>,
+        scheme: &QuantScheme,
+        scales: FloatTensor<Self>,
+        offsets: Option<FloatTensor<Self>>,
+    ) -> FloatTensor<Self> {
+        let primal = B::fake_quantize(
+            tensor.primal.clone(),
+            scheme,
+            scales.primal.clone(),
+            offsets.as_ref().map(|offsets| offsets.primal.clone()),
+        );
+        let tangent =
+            fake_quantize_tangent::<B>(tensor, scales, offsets, scheme.q_type.range(scheme.mode));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn q_device(tensor: &QuantizedTensor<Self>) -> Device<Self> {
+        B::q_device(tensor)
+    }
+
+    fn q_to_device(tensor: QuantizedTensor<Self>, device: &Device<Self>) -> QuantizedTensor<Self> {
+        B::q_to_device(tensor, device)
+    }
+
+    fn q_reshape(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        B::q_reshape(tensor, shape)
+    }
+
+    async fn q_into_data(tensor: QuantizedTensor<Self>) -> TensorData {
+        B::q_into_data(tensor).await
+    }
+
+    fn q_swap_dims(
+        tensor: QuantizedTensor<Self>,
+        dim1: usize,
+        dim2: usize,
+    ) -> QuantizedTensor<Self> {
+        B::q_swap_dims(tensor, dim1, dim2)
+    }
+
+    fn q_permute(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        B::q_permute(tensor, axes)
+    }
+
+    fn q_flip(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        B::q_flip(tensor, axes)
+    }
+
+    fn q_gather(
+        dim: usize,
+        tensor: QuantizedTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> QuantizedTensor<Self> {
+        B::q_gather(dim, tensor, indices)
+    }
+
+    fn q_select(
+        tensor: QuantizedTensor<Self>,
+        dim: usize,
+        indices: IntTensor<Self>,
+    ) -> QuantizedTensor<Self> {
+        B::q_select(tensor, dim, indices)
+    }
+
+    fn q_slice(tensor: QuantizedTensor<Self>, ranges: &[Range<usize>]) -> QuantizedTensor<Self> {
+        B::q_slice(tensor, ranges)
+    }
+
+    fn q_argmax(tensor: QuantizedTensor<Self>, dim: usize) -> IntTensor<Self> {
+        B::q_argmax(tensor, dim)
+    }
+
+    fn q_argmin(tensor: QuantizedTensor<Self>, dim: usize) -> IntTensor<Self> {
+        B::q_argmin(tensor, dim)
+    }
+
+    fn q_expand(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        B::q_expand(tensor, shape)
+    }
+}
+
+/// Straight-through estimator of the fake quantization tangent.
+///
+/// The rounding is treated as the identity, so the tangent of the input is passed through for
+/// values within the quantized range and is zero for clamped values, while the scales and offsets
+/// contribute with the same partial derivatives as in the reverse mode.
+fn fake_quantize_tangent<B: Backend>(
+    x: DualTensor<B>,
+    scales: DualTensor<B>,
+    offsets: Option<DualTensor<B>>,
+    (a, b): (i32, i32),
+) -> Option<FloatTensor<B>> {
+    let has_tangent = x.tangent.is_some()
+        || scales.tangent.is_some()
+        || offsets
+            .as_ref()
+            .is_some_and(|offsets| offsets.tangent.is_some());
+    if !has_tangent {
+        return None;
+    }
+
+    let shape = x.shape();
+    let values = B::float_div(x.primal, scales.primal.clone());
+    let values = match &offsets {
+        Some(offsets) => B::float_add(values, offsets.primal.clone()),
+        None => values,
+    };
+    let rounded = B::float_round(values.clone());
+    let clamped = B::bool_or(
+        B::float_lower_elem(rounded.clone(), a.elem()),
+        B::float_greater_elem(rounded.clone(), b.elem()),
+    );
+    let quantized = B::float_clamp(rounded, a.elem(), b.elem());
+
+    let tangent_x = x
+        .tangent
+        .map(|tangent| B::float_mask_fill(tangent, clamped.clone(), 0.elem()));
+
+    // d(out)/d(scale) is `round(v) - v` within the range, and `q - offset` for clamped values.
+    let tangent_scales = scales.tangent.map(|tangent| {
+        let dequantized = match &offsets {
+            Some(offsets) => B::float_sub(quantized.clone(), offsets.primal.clone()),
+            None => quantized.clone(),
+        };
+        let partial = B::float_mask_where(
+            B::float_sub(quantized, values),
+            clamped.clone(),
+            dequantized,
+        );
+        B::float_mul(tangent, partial)
+    });
+
+    // d(out)/d(offset) is zero within the range and `-scale` for clamped values.
+    let tangent_offsets = offsets.and_then(|offsets| offsets.tangent).map(|tangent| {
+        let tangent = B::float_mul(tangent, B::float_neg(scales.primal));
+        B::float_mask_fill(
+            B::float_expand(tangent, shape.clone()),
+            B::bool_not(clamped),
+            0.elem(),
+        )
+    });
+
+    let tangent = add_tangents::<B>(tangent_x, tangent_scales, &shape);
+    add_tangents::<B>(tangent, tangent_offsets, &shape)
+}
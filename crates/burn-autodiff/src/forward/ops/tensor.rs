@@ -0,0 +1,624 @@
+use core::ops::Range;
+
+use crate::forward::{
+    Dual, DualTensor,
+    tensor::{add_tangents, broadcast, zeros_like},
+};
+use alloc::vec::Vec;
+use burn_tensor::{
+    Device, Distribution, ElementConversion, FloatDType, Shape, TensorData, TensorMetadata,
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
+};
+
+/// The tangent of each operation is its derivative applied to the tangents of the inputs. Missing
+/// tangents are zero, so they are skipped instead of being materialized, and the operations
+/// without a dedicated implementation fall back to the default ones, which are written with the
+/// operations below so that their tangents follow from the chain rule.
+impl<B: Backend> FloatTensorOps<Self> for Dual<B> {
+    fn float_from_data(data: TensorData, device: &Device<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::float_from_data(data, device))
+    }
+
+    fn float_random(
+        shape: Shape,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self> {
+        DualTensor::new(B::float_random(shape, distribution, device))
+    }
+
+    fn float_zeros(shape: Shape, device: &Device<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::float_zeros(shape, device))
+    }
+
+    fn float_ones(shape: Shape, device: &Device<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::float_ones(shape, device))
+    }
+
+    fn float_full(
+        shape: Shape,
+        fill_value: FloatElem<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self> {
+        DualTensor::new(B::float_full(shape, fill_value, device))
+    }
+
+    async fn float_into_data(tensor: FloatTensor<Self>) -> TensorData {
+        B::float_into_data(tensor.primal).await
+    }
+
+    fn float_device(tensor: &FloatTensor<Self>) -> Device<Self> {
+        B::float_device(&tensor.primal)
+    }
+
+    fn float_to_device(tensor: FloatTensor<Self>, device: &Device<Self>) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_to_device(tensor, device))
+    }
+
+    fn float_into_int(tensor: FloatTensor<Self>) -> IntTensor<Self> {
+        B::float_into_int(tensor.primal)
+    }
+
+    fn float_empty(shape: Shape, device: &Device<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::float_empty(shape, device))
+    }
+
+    fn float_repeat_dim(tensor: FloatTensor<Self>, dim: usize, times: usize) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_repeat_dim(tensor, dim, times))
+    }
+
+    fn float_add(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        let primal = B::float_add(lhs.primal, rhs.primal);
+        let tangent = add_tangents::<B>(lhs.tangent, rhs.tangent, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_add_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> FloatTensor<Self> {
+        DualTensor {
+            primal: B::float_add_scalar(lhs.primal, rhs),
+            tangent: lhs.tangent,
+        }
+    }
+
+    fn float_sub(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        let primal = B::float_sub(lhs.primal, rhs.primal);
+        let tangent = match (lhs.tangent, rhs.tangent) {
+            (Some(lhs), Some(rhs)) => Some(B::float_sub(lhs, rhs)),
+            (Some(lhs), None) => Some(lhs),
+            (None, Some(rhs)) => Some(B::float_neg(rhs)),
+            (None, None) => None,
+        };
+        let tangent = tangent.map(|tangent| broadcast::<B>(tangent, &primal.shape()));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_sub_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> FloatTensor<Self> {
+        DualTensor {
+            primal: B::float_sub_scalar(lhs.primal, rhs),
+            tangent: lhs.tangent,
+        }
+    }
+
+    fn float_mul(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d(xy) = dx y + x dy
+        let tangent_lhs = lhs
+            .tangent
+            .map(|tangent| B::float_mul(tangent, rhs.primal.clone()));
+        let tangent_rhs = rhs
+            .tangent
+            .map(|tangent| B::float_mul(lhs.primal.clone(), tangent));
+        let primal = B::float_mul(lhs.primal, rhs.primal);
+        let tangent = add_tangents::<B>(tangent_lhs, tangent_rhs, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_mul_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> FloatTensor<Self> {
+        lhs.linear(|tensor| B::float_mul_scalar(tensor, rhs))
+    }
+
+    fn float_div(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d(x / y) = dx / y - (x / y) dy / y
+        let primal = B::float_div(lhs.primal, rhs.primal.clone());
+        let tangent_lhs = lhs
+            .tangent
+            .map(|tangent| B::float_div(tangent, rhs.primal.clone()));
+        let tangent_rhs = rhs.tangent.map(|tangent| {
+            let tangent = B::float_mul(tangent, primal.clone());
+            B::float_neg(B::float_div(tangent, rhs.primal))
+        });
+        let tangent = add_tangents::<B>(tangent_lhs, tangent_rhs, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_div_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> FloatTensor<Self> {
+        lhs.linear(|tensor| B::float_div_scalar(tensor, rhs))
+    }
+
+    fn float_remainder(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // x % y = x - floor(x / y) y, where the quotient is piecewise constant.
+        let tangent_rhs = rhs.tangent.map(|tangent| {
+            let quotient = B::float_floor(B::float_div(lhs.primal.clone(), rhs.primal.clone()));
+            B::float_neg(B::float_mul(tangent, quotient))
+        });
+        let primal = B::float_remainder(lhs.primal, rhs.primal);
+        let tangent = add_tangents::<B>(lhs.tangent, tangent_rhs, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_remainder_scalar(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> FloatTensor<Self> {
+        DualTensor {
+            primal: B::float_remainder_scalar(lhs.primal, rhs),
+            tangent: lhs.tangent,
+        }
+    }
+
+    fn float_matmul(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        let tangent_lhs = lhs
+            .tangent
+            .map(|tangent| B::float_matmul(tangent, rhs.primal.clone()));
+        let tangent_rhs = rhs
+            .tangent
+            .map(|tangent| B::float_matmul(lhs.primal.clone(), tangent));
+        let primal = B::float_matmul(lhs.primal, rhs.primal);
+        let tangent = add_tangents::<B>(tangent_lhs, tangent_rhs, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_neg(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        tensor.linear(B::float_neg)
+    }
+
+    fn float_recip(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d(1 / x) = -dx / x²
+        let primal = B::float_recip(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let square = B::float_mul(primal.clone(), primal.clone());
+            B::float_neg(B::float_mul(tangent, square))
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_swap_dims(tensor: FloatTensor<Self>, dim1: usize, dim2: usize) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_swap_dims(tensor, dim1, dim2))
+    }
+
+    fn float_permute(tensor: FloatTensor<Self>, axes: &[usize]) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_permute(tensor, axes))
+    }
+
+    fn float_flip(tensor: FloatTensor<Self>, axes: &[usize]) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_flip(tensor, axes))
+    }
+
+    fn float_reshape(tensor: FloatTensor<Self>, shape: Shape) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_reshape(tensor, shape.clone()))
+    }
+
+    fn float_gather(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_gather(dim, tensor, indices.clone()))
+    }
+
+    fn float_scatter(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        // The values are added to the tensor, so the tangents are scattered the same way.
+        let tangent = match (tensor.tangent, value.tangent) {
+            (tangent, None) => tangent,
+            (tangent, Some(tangent_value)) => {
+                let tangent = tangent.unwrap_or_else(|| zeros_like::<B>(&tensor.primal));
+                Some(B::float_scatter(
+                    dim,
+                    tangent,
+                    indices.clone(),
+                    tangent_value,
+                ))
+            }
+        };
+        let primal = B::float_scatter(dim, tensor.primal, indices, value.primal);
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_select(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        indices: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_select(tensor, dim, indices.clone()))
+    }
+
+    fn float_select_assign(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        // The values are added to the selected elements, so the tangents are added the same way.
+        let tangent = match (tensor.tangent, value.tangent) {
+            (tangent, None) => tangent,
+            (tangent, Some(tangent_value)) => {
+                let tangent = tangent.unwrap_or_else(|| zeros_like::<B>(&tensor.primal));
+                Some(B::float_select_assign(
+                    tangent,
+                    dim,
+                    indices.clone(),
+                    tangent_value,
+                ))
+            }
+        };
+        let primal = B::float_select_assign(tensor.primal, dim, indices, value.primal);
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_slice(tensor: FloatTensor<Self>, ranges: &[Range<usize>]) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_slice(tensor, ranges))
+    }
+
+    fn float_slice_assign(
+        tensor: FloatTensor<Self>,
+        ranges: &[Range<usize>],
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let tangent = match (&tensor.tangent, &value.tangent) {
+            (None, None) => None,
+            _ => Some(B::float_slice_assign(
+                tensor.tangent_or_zeros(),
+                ranges,
+                value.tangent_or_zeros(),
+            )),
+        };
+        let primal = B::float_slice_assign(tensor.primal, ranges, value.primal);
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_mask_where(
+        tensor: FloatTensor<Self>,
+        mask: BoolTensor<Self>,
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let tangent = match (&tensor.tangent, &value.tangent) {
+            (None, None) => None,
+            _ => Some(B::float_mask_where(
+                tensor.tangent_or_zeros(),
+                mask.clone(),
+                value.tangent_or_zeros(),
+            )),
+        };
+        let primal = B::float_mask_where(tensor.primal, mask, value.primal);
+        let tangent = tangent.map(|tangent| broadcast::<B>(tangent, &primal.shape()));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_mask_fill(
+        tensor: FloatTensor<Self>,
+        mask: BoolTensor<Self>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mask_fill(tangent, mask.clone(), 0.elem()));
+        let primal = B::float_mask_fill(tensor.primal, mask, value);
+        let tangent = tangent.map(|tangent| broadcast::<B>(tangent, &primal.shape()));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_equal(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> BoolTensor<Self> {
+        B::float_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_equal_elem(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> BoolTensor<Self> {
+        B::float_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_greater(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> BoolTensor<Self> {
+        B::float_greater(lhs.primal, rhs.primal)
+    }
+
+    fn float_greater_elem(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> BoolTensor<Self> {
+        B::float_greater_elem(lhs.primal, rhs)
+    }
+
+    fn float_greater_equal(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> BoolTensor<Self> {
+        B::float_greater_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_greater_equal_elem(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> BoolTensor<Self> {
+        B::float_greater_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_lower(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> BoolTensor<Self> {
+        B::float_lower(lhs.primal, rhs.primal)
+    }
+
+    fn float_lower_elem(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> BoolTensor<Self> {
+        B::float_lower_elem(lhs.primal, rhs)
+    }
+
+    fn float_lower_equal(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> BoolTensor<Self> {
+        B::float_lower_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_lower_equal_elem(lhs: FloatTensor<Self>, rhs: FloatElem<Self>) -> BoolTensor<Self> {
+        B::float_lower_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_detach(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // A detached tensor is a constant, so its tangent is removed.
+        DualTensor::new(B::float_detach(tensor.primal))
+    }
+
+    fn float_set_require_grad(tensor: FloatTensor<Self>, require_grad: bool) -> FloatTensor<Self> {
+        DualTensor {
+            primal: B::float_set_require_grad(tensor.primal, require_grad),
+            tangent: tensor.tangent,
+        }
+    }
+
+    fn float_is_require_grad(tensor: &FloatTensor<Self>) -> bool {
+        B::float_is_require_grad(&tensor.primal)
+    }
+
+    fn float_sum(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        tensor.linear(B::float_sum)
+    }
+
+    fn float_sum_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_sum_dim(tensor, dim))
+    }
+
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_cumsum(tensor, dim))
+    }
+
+    fn float_mean(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        tensor.linear(B::float_mean)
+    }
+
+    fn float_mean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_mean_dim(tensor, dim))
+    }
+
+    fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_cast(tensor, dtype))
+    }
+
+    fn float_exp(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let primal = B::float_exp(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, primal.clone()));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_log(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_div(tangent, tensor.primal.clone()));
+
+        DualTensor {
+            primal: B::float_log(tensor.primal),
+            tangent,
+        }
+    }
+
+    fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let tangent = tensor.tangent.map(|tangent| {
+            B::float_div(
+                tangent,
+                B::float_add_scalar(tensor.primal.clone(), 1.elem()),
+            )
+        });
+
+        DualTensor {
+            primal: B::float_log1p(tensor.primal),
+            tangent,
+        }
+    }
+
+    fn float_powf(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d(x^y) = y x^(y - 1) dx + x^y ln(x) dy
+        let tangent_lhs = lhs.tangent.map(|tangent| {
+            let exponent = B::float_sub_scalar(rhs.primal.clone(), 1.elem());
+            let derivative = B::float_powf(lhs.primal.clone(), exponent);
+            let derivative = B::float_mul(rhs.primal.clone(), derivative);
+            B::float_mul(tangent, derivative)
+        });
+        let log = rhs
+            .tangent
+            .as_ref()
+            .map(|_| B::float_log(lhs.primal.clone()));
+        let primal = B::float_powf(lhs.primal, rhs.primal);
+        let tangent_rhs = rhs
+            .tangent
+            .zip(log)
+            .map(|(tangent, log)| B::float_mul(tangent, B::float_mul(primal.clone(), log)));
+        let tangent = add_tangents::<B>(tangent_lhs, tangent_rhs, &primal.shape());
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_powf_scalar(tensor: FloatTensor<Self>, value: f32) -> FloatTensor<Self> {
+        // d(x^a) = a x^(a - 1) dx
+        let tangent = tensor.tangent.map(|tangent| {
+            let derivative = B::float_powf_scalar(tensor.primal.clone(), value - 1.0);
+            let derivative = B::float_mul_scalar(derivative, value.elem());
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor {
+            primal: B::float_powf_scalar(tensor.primal, value),
+            tangent,
+        }
+    }
+
+    fn float_sqrt(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d(√x) = dx / 2√x
+        let primal = B::float_sqrt(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let tangent = B::float_div(tangent, primal.clone());
+            B::float_mul_scalar(tangent, 0.5.elem())
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_abs(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_sign(tensor.primal.clone())));
+
+        DualTensor {
+            primal: B::float_abs(tensor.primal),
+            tangent,
+        }
+    }
+
+    fn float_cos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let tangent = tensor.tangent.map(|tangent| {
+            let derivative = B::float_neg(B::float_sin(tensor.primal.clone()));
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor {
+            primal: B::float_cos(tensor.primal),
+            tangent,
+        }
+    }
+
+    fn float_sin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_mul(tangent, B::float_cos(tensor.primal.clone())));
+
+        DualTensor {
+            primal: B::float_sin(tensor.primal),
+            tangent,
+        }
+    }
+
+    fn float_tanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d(tanh(x)) = (1 - tanh²(x)) dx
+        let primal = B::float_tanh(tensor.primal);
+        let tangent = tensor.tangent.map(|tangent| {
+            let square = B::float_mul(primal.clone(), primal.clone());
+            let derivative = B::float_add_scalar(B::float_neg(square), 1.elem());
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_round(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // Piecewise constant, so the tangent is zero.
+        DualTensor::new(B::float_round(tensor.primal))
+    }
+
+    fn float_floor(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::float_floor(tensor.primal))
+    }
+
+    fn float_ceil(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::float_ceil(tensor.primal))
+    }
+
+    fn float_erf(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // d(erf(x)) = 2 / √π exp(-x²) dx
+        let tangent = tensor.tangent.map(|tangent| {
+            let square = B::float_mul(tensor.primal.clone(), tensor.primal.clone());
+            let derivative = B::float_exp(B::float_neg(square));
+            let derivative =
+                B::float_mul_scalar(derivative, (2.0 / core::f64::consts::PI.sqrt()).elem());
+            B::float_mul(tangent, derivative)
+        });
+
+        DualTensor {
+            primal: B::float_erf(tensor.primal),
+            tangent,
+        }
+    }
+
+    fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
+        let tangent = match tensors.iter().any(|tensor| tensor.tangent.is_some()) {
+            true => Some(B::float_cat(
+                tensors.iter().map(DualTensor::tangent_or_zeros).collect(),
+                dim,
+            )),
+            false => None,
+        };
+        let primal = B::float_cat(
+            tensors.into_iter().map(|tensor| tensor.primal).collect(),
+            dim,
+        );
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_argmax(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<Self> {
+        B::float_argmax(tensor.primal, dim)
+    }
+
+    fn float_argmin(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<Self> {
+        B::float_argmin(tensor.primal, dim)
+    }
+
+    fn float_sign(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        DualTensor::new(B::float_sign(tensor.primal))
+    }
+
+    fn float_expand(tensor: FloatTensor<Self>, shape: Shape) -> FloatTensor<Self> {
+        tensor.linear(|tensor| B::float_expand(tensor, shape.clone()))
+    }
+
+    fn float_sort(tensor: FloatTensor<Self>, dim: usize, descending: bool) -> FloatTensor<Self> {
+        match tensor.tangent {
+            Some(_) => Self::float_sort_with_indices(tensor, dim, descending).0,
+            None => DualTensor::new(B::float_sort(tensor.primal, dim, descending)),
+        }
+    }
+
+    fn float_sort_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        // The tangents follow the permutation of the values.
+        let (primal, indices) = B::float_sort_with_indices(tensor.primal, dim, descending);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (DualTensor { primal, tangent }, indices)
+    }
+
+    fn float_argsort(tensor: FloatTensor<Self>, dim: usize, descending: bool) -> IntTensor<Self> {
+        B::float_argsort(tensor.primal, dim, descending)
+    }
+
+    fn float_multinomial(
+        tensor: FloatTensor<Self>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<Self> {
+        B::float_multinomial(tensor.primal, num_samples, replacement)
+    }
+}
@@ -0,0 +1,90 @@
+use burn_tensor::{Shape, TensorMetadata, backend::Backend, ops::FloatTensor};
+
+/// A float tensor of the [dual](super::Dual) backend, holding its value and its directional
+/// derivative.
+#[derive(Debug, Clone)]
+pub struct DualTensor<B: Backend> {
+    /// The value of the tensor.
+    pub primal: FloatTensor<B>,
+    /// The derivative of the tensor along the tangent direction of the inputs, where `None` stands
+    /// for zero.
+    pub tangent: Option<FloatTensor<B>>,
+}
+
+impl<B: Backend> TensorMetadata for DualTensor<B> {
+    fn dtype(&self) -> burn_tensor::DType {
+        self.primal.dtype()
+    }
+
+    fn shape(&self) -> Shape {
+        self.primal.shape()
+    }
+}
+
+impl<B: Backend> DualTensor<B> {
+    /// Create a constant tensor, which has a zero tangent.
+    pub fn new(primal: FloatTensor<B>) -> Self {
+        Self {
+            primal,
+            tangent: None,
+        }
+    }
+
+    /// Create a tensor from its value and its tangent.
+    ///
+    /// # Panics
+    ///
+    /// If the tangent doesn't have the shape of the value.
+    pub fn dual(primal: FloatTensor<B>, tangent: Option<FloatTensor<B>>) -> Self {
+        if let Some(tangent) = &tangent {
+            assert_eq!(
+                primal.shape(),
+                tangent.shape(),
+                "The tangent should have the same shape as the primal"
+            );
+        }
+
+        Self { primal, tangent }
+    }
+
+    /// Applies an operation that is linear in the tensor on both the value and the tangent.
+    pub(crate) fn linear(self, op: impl Fn(FloatTensor<B>) -> FloatTensor<B>) -> Self {
+        Self {
+            tangent: self.tangent.map(&op),
+            primal: op(self.primal),
+        }
+    }
+
+    /// Returns the tangent, materializing zeros when it isn't set.
+    pub(crate) fn tangent_or_zeros(&self) -> FloatTensor<B> {
+        match &self.tangent {
+            Some(tangent) => tangent.clone(),
+            None => zeros_like::<B>(&self.primal),
+        }
+    }
+}
+
+pub(crate) fn zeros_like<B: Backend>(tensor: &FloatTensor<B>) -> FloatTensor<B> {
+    B::float_zeros(tensor.shape(), &B::float_device(tensor))
+}
+
+/// Expands a tangent to the shape of the output of a broadcasting operation.
+pub(crate) fn broadcast<B: Backend>(tangent: FloatTensor<B>, shape: &Shape) -> FloatTensor<B> {
+    match tangent.shape() == *shape {
+        true => tangent,
+        false => B::float_expand(tangent, shape.clone()),
+    }
+}
+
+/// Sums the tangent contributions of the inputs of an operation, expanded to the output shape.
+pub(crate) fn add_tangents<B: Backend>(
+    lhs: Option<FloatTensor<B>>,
+    rhs: Option<FloatTensor<B>>,
+    shape: &Shape,
+) -> Option<FloatTensor<B>> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(broadcast::<B>(B::float_add(lhs, rhs), shape)),
+        (Some(tangent), None) | (None, Some(tangent)) => Some(broadcast::<B>(tangent, shape)),
+        (None, None) => None,
+    }
+}
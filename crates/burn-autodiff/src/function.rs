@@ -0,0 +1,412 @@
+use crate::{
+    Autodiff,
+    checkpoint::{
+        base::Checkpointer, retro_forward::RetroForward, state::BackwardStates,
+        strategy::CheckpointStrategy,
+    },
+    grads::Gradients,
+    graph::NodeID,
+    ops::{Backward, Ops, OpsKind},
+    tensor::AutodiffTensor,
+};
+use alloc::{sync::Arc, vec::Vec};
+use burn_tensor::{
+    Shape, Tensor, TensorMetadata, TensorPrimitive, backend::Backend, ops::FloatTensor,
+};
+use core::{fmt::Debug, marker::PhantomData};
+
+/// A differentiable operation defined by its forward and backward passes on plain tensors.
+///
+/// The forward pass is computed on the inner backend, so it can call any operation, including
+/// custom kernels, and the backward pass receives the gradient of the output along with the
+/// tensors saved during the forward pass. Use [apply] to call the function on tensors of the
+/// [Autodiff] backend, which registers it in the graph.
+///
+/// Only the tensors declared with [save_inputs](Function::save_inputs) and
+/// [save_output](Function::save_output) are available during the backward pass. The inputs are
+/// saved with the [checkpointer](Checkpointer), so they follow the [CheckpointStrategy] of the
+/// backend: an input computed by a memory bound operation may be recomputed instead of kept in
+/// memory.
+///
+/// # Restrictions
+///
+/// All the inputs have the same rank `D`, and the function has a single output, since it is
+/// registered in the graph as a single operation. Inputs of lower rank can be unsqueezed to `D`
+/// before calling [apply], their gradients then having the unsqueezed shape, and several outputs
+/// can be concatenated into one and sliced afterwards.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Debug, Clone)]
+/// struct Square;
+///
+/// impl<B: Backend> Function<B, 1, 2, 2> for Square {
+///     fn forward(&self, [x]: [Tensor<B, 2>; 1]) -> Tensor<B, 2> {
+///         x.clone() * x
+///     }
+///
+///     fn backward(&self, saved: Saved<B, 1, 2, 2>, grad: Tensor<B, 2>) -> [Option<Tensor<B, 2>>; 1] {
+///         [Some(grad * saved.input(0).mul_scalar(2.0))]
+///     }
+/// }
+///
+/// let y = apply(Square, [x]);
+/// ```
+pub trait Function<B: Backend, const N: usize, const D: usize, const D2: usize>:
+    Clone + Send + Debug + 'static
+{
+    /// Computes the output of the function from its inputs.
+    fn forward(&self, inputs: [Tensor<B, D>; N]) -> Tensor<B, D2>;
+
+    /// Computes the gradients of the inputs from the gradient of the output.
+    ///
+    /// The gradients of the inputs that don't [require grad](Saved::requires_grad) are ignored,
+    /// and can be `None`. A `None` gradient is considered to be zero.
+    fn backward(&self, saved: Saved<B, N, D, D2>, grad: Tensor<B, D2>)
+    -> [Option<Tensor<B, D>>; N];
+
+    /// The inputs needed by the backward pass, all of them by default.
+    fn save_inputs(&self) -> [bool; N] {
+        [true; N]
+    }
+
+    /// Whether the output is needed by the backward pass.
+    fn save_output(&self) -> bool {
+        false
+    }
+
+    /// Whether the function is cheap to compute compared to the memory its output takes.
+    ///
+    /// A memory bound function recomputes its [saved output](Function::save_output) from the
+    /// inputs during its backward pass instead of keeping it. When the checkpoint strategy allows
+    /// it, the operations using its output also recompute it rather than keeping it in memory.
+    fn memory_bound(&self) -> bool {
+        false
+    }
+}
+
+/// The tensors saved during the forward pass of a [function](Function) for its backward pass.
+pub struct Saved<B: Backend, const N: usize, const D: usize, const D2: usize> {
+    inputs: [Option<Tensor<B, D>>; N],
+    output: Option<Tensor<B, D2>>,
+    requires_grad: [bool; N],
+}
+
+impl<B: Backend, const N: usize, const D: usize, const D2: usize> Saved<B, N, D, D2> {
+    /// Returns the input at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If the input wasn't [saved](Function::save_inputs).
+    pub fn input(&self, index: usize) -> Tensor<B, D> {
+        self.inputs[index]
+            .clone()
+            .unwrap_or_else(|| panic!("Input {index} should be saved for the backward pass."))
+    }
+
+    /// Returns the output.
+    ///
+    /// # Panics
+    ///
+    /// If the output wasn't [saved](Function::save_output).
+    pub fn output(&self) -> Tensor<B, D2> {
+        self.output
+            .clone()
+            .expect("The output should be saved for the backward pass.")
+    }
+
+    /// Whether the gradient of the input at the given index is needed.
+    pub fn requires_grad(&self, index: usize) -> bool {
+        self.requires_grad[index]
+    }
+}
+
+/// Applies a [function](Function) on tensors of the [Autodiff] backend.
+///
+/// The function is computed on the inner backend, and its backward pass is registered in the
+/// graph when at least one of the inputs is tracked.
+pub fn apply<B, C, F, const N: usize, const D: usize, const D2: usize>(
+    function: F,
+    inputs: [Tensor<Autodiff<B, C>, D>; N],
+) -> Tensor<Autodiff<B, C>, D2>
+where
+    B: Backend,
+    C: CheckpointStrategy,
+    F: Function<B, N, D, D2>,
+{
+    let inputs: [AutodiffTensor<B>; N] = inputs.map(|input| input.into_primitive().tensor());
+    let nodes = inputs.each_ref().map(|input| input.node.clone());
+    let backward = FunctionBackward::<B, F, D, D2>::new(function.clone());
+    let save_inputs = function.save_inputs();
+
+    let prep = backward.prepare::<C>(nodes);
+    let kind = match function.memory_bound() {
+        true => {
+            let ids = inputs.each_ref().map(|input| input.node.id);
+            prep.memory_bound()
+                .retro_forward(RetroFunction::<B, F, N, D, D2>::new(function.clone(), ids))
+                .parents(&inputs)
+                .stateful()
+        }
+        false => prep.compute_bound().stateful(),
+    };
+
+    let output = match kind {
+        OpsKind::Tracked(mut prep) => {
+            // A memory bound function recomputes its output during the backward pass instead of
+            // keeping it, which needs all of its inputs.
+            let recompute_output = function.save_output() && function.memory_bound();
+            let saved = core::array::from_fn(|i| {
+                (save_inputs[i] || recompute_output).then(|| prep.checkpoint(&inputs[i]))
+            });
+            let shapes = inputs.each_ref().map(|input| input.primitive.shape());
+
+            let output =
+                forward_primitive::<B, F, N, D, D2>(&function, inputs.map(|input| input.primitive));
+            let state = FunctionState {
+                inputs: saved,
+                output: (function.save_output() && !recompute_output).then(|| output.clone()),
+                recompute_output,
+                shapes,
+            };
+
+            prep.finish(state, output)
+        }
+        OpsKind::UnTracked(prep) => prep.finish(forward_primitive::<B, F, N, D, D2>(
+            &function,
+            inputs.map(|input| input.primitive),
+        )),
+    };
+
+    Tensor::from_primitive(TensorPrimitive::Float(output))
+}
+
+/// Creates a [function](Function) from its forward and backward closures.
+///
+/// All the inputs are saved by default, which can be changed with
+/// [save_inputs](FnFunction::save_inputs).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let exp = from_fn(
+///     |[x]: [Tensor<B, 1>; 1]| x.exp(),
+///     |saved, grad| [Some(grad * saved.output())],
+/// )
+/// .save_inputs([false])
+/// .save_output();
+///
+/// let y = apply(exp, [x]);
+/// ```
+pub fn from_fn<B, FW, BW, const N: usize, const D: usize, const D2: usize>(
+    forward: FW,
+    backward: BW,
+) -> FnFunction<FW, BW>
+where
+    B: Backend,
+    FW: Fn([Tensor<B, D>; N]) -> Tensor<B, D2> + Send + Sync + 'static,
+    BW: Fn(Saved<B, N, D, D2>, Tensor<B, D2>) -> [Option<Tensor<B, D>>; N] + Send + Sync + 'static,
+{
+    FnFunction {
+        forward: Arc::new(forward),
+        backward: Arc::new(backward),
+        save_inputs: None,
+        save_output: false,
+        memory_bound: false,
+    }
+}
+
+/// A [function](Function) defined by closures, created with [from_fn].
+pub struct FnFunction<FW, BW> {
+    forward: Arc<FW>,
+    backward: Arc<BW>,
+    save_inputs: Option<Vec<bool>>,
+    save_output: bool,
+    memory_bound: bool,
+}
+
+impl<FW, BW> FnFunction<FW, BW> {
+    /// Sets the inputs needed by the backward pass.
+    pub fn save_inputs<const N: usize>(mut self, save_inputs: [bool; N]) -> Self {
+        self.save_inputs = Some(save_inputs.into());
+        self
+    }
+
+    /// Saves the output for the backward pass.
+    pub fn save_output(mut self) -> Self {
+        self.save_output = true;
+        self
+    }
+
+    /// Marks the function as [memory bound](Function::memory_bound).
+    pub fn memory_bound(mut self) -> Self {
+        self.memory_bound = true;
+        self
+    }
+}
+
+impl<FW, BW> Clone for FnFunction<FW, BW> {
+    fn clone(&self) -> Self {
+        Self {
+            forward: self.forward.clone(),
+            backward: self.backward.clone(),
+            save_inputs: self.save_inputs.clone(),
+            save_output: self.save_output,
+            memory_bound: self.memory_bound,
+        }
+    }
+}
+
+impl<FW, BW> Debug for FnFunction<FW, BW> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FnFunction")
+            .field("save_inputs", &self.save_inputs)
+            .field("save_output", &self.save_output)
+            .field("memory_bound", &self.memory_bound)
+            .finish()
+    }
+}
+
+impl<B, FW, BW, const N: usize, const D: usize, const D2: usize> Function<B, N, D, D2>
+    for FnFunction<FW, BW>
+where
+    B: Backend,
+    FW: Fn([Tensor<B, D>; N]) -> Tensor<B, D2> + Send + Sync + 'static,
+    BW: Fn(Saved<B, N, D, D2>, Tensor<B, D2>) -> [Option<Tensor<B, D>>; N] + Send + Sync + 'static,
+{
+    fn forward(&self, inputs: [Tensor<B, D>; N]) -> Tensor<B, D2> {
+        (self.forward)(inputs)
+    }
+
+    fn backward(
+        &self,
+        saved: Saved<B, N, D, D2>,
+        grad: Tensor<B, D2>,
+    ) -> [Option<Tensor<B, D>>; N] {
+        (self.backward)(saved, grad)
+    }
+
+    fn save_inputs(&self) -> [bool; N] {
+        match &self.save_inputs {
+            Some(save_inputs) => save_inputs.as_slice().try_into().unwrap_or_else(|_| {
+                panic!(
+                    "Expected {N} values to save the inputs, got {}.",
+                    save_inputs.len()
+                )
+            }),
+            None => [true; N],
+        }
+    }
+
+    fn save_output(&self) -> bool {
+        self.save_output
+    }
+
+    fn memory_bound(&self) -> bool {
+        self.memory_bound
+    }
+}
+
+/// Computes the forward pass of the function on primitives of the inner backend.
+fn forward_primitive<B, F, const N: usize, const D: usize, const D2: usize>(
+    function: &F,
+    inputs: [FloatTensor<B>; N],
+) -> FloatTensor<B>
+where
+    B: Backend,
+    F: Function<B, N, D, D2>,
+{
+    let inputs = inputs.map(|input| Tensor::from_primitive(TensorPrimitive::Float(input)));
+
+    function.forward(inputs).into_primitive().tensor()
+}
+
+#[derive(Debug, Clone)]
+struct FunctionState<B: Backend, const N: usize> {
+    inputs: [Option<NodeID>; N],
+    output: Option<FloatTensor<B>>,
+    recompute_output: bool,
+    shapes: [Shape; N],
+}
+
+#[derive(new, Debug)]
+struct FunctionBackward<B: Backend, F, const D: usize, const D2: usize> {
+    function: F,
+    _backend: PhantomData<B>,
+}
+
+impl<B, F, const N: usize, const D: usize, const D2: usize> Backward<B, N>
+    for FunctionBackward<B, F, D, D2>
+where
+    B: Backend,
+    F: Function<B, N, D, D2>,
+{
+    type State = FunctionState<B, N>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, N>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let state = ops.state;
+        let grad = grads.consume::<B>(&ops.node);
+
+        let inputs: [Option<FloatTensor<B>>; N] = state
+            .inputs
+            .map(|id| id.map(|id| checkpointer.retrieve_node_output(id)));
+        let output = match state.recompute_output {
+            true => Some(forward_primitive::<B, F, N, D, D2>(
+                &self.function,
+                inputs.clone().map(|input| input.unwrap()),
+            )),
+            false => state.output,
+        };
+
+        let saved = Saved {
+            inputs: inputs.map(|input| {
+                input.map(|input| Tensor::from_primitive(TensorPrimitive::Float(input)))
+            }),
+            output: output.map(|output| Tensor::from_primitive(TensorPrimitive::Float(output))),
+            requires_grad: ops.parents.each_ref().map(Option::is_some),
+        };
+        let grad = Tensor::from_primitive(TensorPrimitive::Float(grad));
+        let input_grads = self.function.backward(saved, grad);
+
+        for ((parent, grad), shape) in ops.parents.into_iter().zip(input_grads).zip(state.shapes) {
+            if let (Some(node), Some(grad)) = (parent, grad) {
+                assert_eq!(
+                    grad.shape(),
+                    shape,
+                    "The gradient of an input should have the shape of the input."
+                );
+                grads.register::<B>(node.id, grad.into_primitive().tensor());
+            }
+        }
+    }
+}
+
+/// Recomputes the output of a memory bound [function](Function) from its inputs.
+#[derive(new, Debug)]
+struct RetroFunction<B: Backend, F, const N: usize, const D: usize, const D2: usize> {
+    function: F,
+    inputs: [NodeID; N],
+    _backend: PhantomData<B>,
+}
+
+impl<B, F, const N: usize, const D: usize, const D2: usize> RetroForward
+    for RetroFunction<B, F, N, D, D2>
+where
+    B: Backend,
+    F: Function<B, N, D, D2>,
+{
+    fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+        let inputs = self
+            .inputs
+            .map(|id| states.get_state::<FloatTensor<B>>(&id));
+        let output = forward_primitive::<B, F, N, D, D2>(&self.function, inputs);
+
+        states.save(out_node, output)
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    Autodiff,
+    forward::{Dual, make_dual, unpack_dual},
+};
+use alloc::vec::Vec;
+use burn_tensor::{Tensor, backend::Backend};
+
+/// Inputs of a function differentiated with [jvp], given once for the primals and once for the
+/// tangents.
+///
+/// Implemented for arrays of tensors of the same rank and for tuples of up to 6 tensors of
+/// different ranks.
+pub trait JvpInputs {
+    /// The inputs of the function on the [dual](Dual) backend.
+    type Dual;
+
+    /// Combines each primal with the tangent at the same position.
+    fn into_dual(self, tangents: Self) -> Self::Dual;
+}
+
+/// Outputs of a function differentiated with [jvp].
+///
+/// Implemented for tensors and for tuples of up to 6 tensors of different ranks.
+pub trait JvpOutputs {
+    /// The outputs of the function on the inner backend.
+    type Primal;
+
+    /// Splits each output into its value and its tangent.
+    fn unpack(self) -> (Self::Primal, Self::Primal);
+}
+
+impl<B: Backend, const D: usize, const N: usize> JvpInputs for [Tensor<B, D>; N] {
+    type Dual = [Tensor<Dual<B>, D>; N];
+
+    fn into_dual(self, tangents: Self) -> Self::Dual {
+        let mut tangents = tangents.into_iter();
+        self.map(|primal| {
+            let tangent = tangents.next().expect("One tangent per primal");
+            make_dual(primal, tangent)
+        })
+    }
+}
+
+impl<B: Backend, const D: usize> JvpOutputs for Tensor<Dual<B>, D> {
+    type Primal = Tensor<B, D>;
+
+    fn unpack(self) -> (Self::Primal, Self::Primal) {
+        let (output, tangent) = unpack_dual(self);
+        // The output doesn't depend on the inputs.
+        let tangent = tangent.unwrap_or_else(|| output.zeros_like());
+
+        (output, tangent)
+    }
+}
+
+macro_rules! impl_jvp_tuple {
+    ($($index:tt: $rank:ident),+) => {
+        impl<B: Backend, $(const $rank: usize),+> JvpInputs for ($(Tensor<B, $rank>,)+) {
+            type Dual = ($(Tensor<Dual<B>, $rank>,)+);
+
+            fn into_dual(self, tangents: Self) -> Self::Dual {
+                ($(make_dual(self.$index, tangents.$index),)+)
+            }
+        }
+
+        impl<B: Backend, $(const $rank: usize),+> JvpOutputs for ($(Tensor<Dual<B>, $rank>,)+) {
+            type Primal = ($(Tensor<B, $rank>,)+);
+
+            fn unpack(self) -> (Self::Primal, Self::Primal) {
+                let outputs = ($(self.$index.unpack(),)+);
+
+                (($(outputs.$index.0,)+), ($(outputs.$index.1,)+))
+            }
+        }
+    };
+}
+
+impl_jvp_tuple!(0: D1);
+impl_jvp_tuple!(0: D1, 1: D2);
+impl_jvp_tuple!(0: D1, 1: D2, 2: D3);
+impl_jvp_tuple!(0: D1, 1: D2, 2: D3, 3: D4);
+impl_jvp_tuple!(0: D1, 1: D2, 2: D3, 3: D4, 4: D5);
+impl_jvp_tuple!(0: D1, 1: D2, 2: D3, 3: D4, 4: D5, 5: D6);
+
+/// Computes the Jacobian-vector product of a function with forward-mode differentiation.
+///
+/// The function is evaluated once on the [dual](Dual) backend, with each input carrying its
+/// tangent, which yields the derivative of the outputs in the direction of the tangents along
+/// with the outputs themselves.
+///
+/// # Arguments
+///
+/// * `f` - The function to differentiate.
+/// * `primals` - The points at which the function is differentiated, e.g. an array of tensors of
+///   the same rank or a tuple of tensors of different ranks.
+/// * `tangents` - The directions, with the same shapes as the primals.
+///
+/// # Returns
+///
+/// The outputs of the function and their tangents, e.g. a tensor or a tuple of tensors.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Directional derivative of `sin(x) * y` in the direction `(dx, dy)`.
+/// let (output, tangent) = jvp(|[x, y]| x.sin() * y, [x, y], [dx, dy]);
+///
+/// // Inputs and outputs of different ranks.
+/// let ((output, sum), (tangent, sum_tangent)) = jvp(
+///     |(x, bias)| {
+///         let output = x + bias.unsqueeze();
+///         (output.clone(), output.sum())
+///     },
+///     (x, bias),
+///     (dx, dbias),
+/// );
+/// ```
+pub fn jvp<F, I, O>(f: F, primals: I, tangents: I) -> (O::Primal, O::Primal)
+where
+    I: JvpInputs,
+    O: JvpOutputs,
+    F: FnOnce(I::Dual) -> O,
+{
+    f(primals.into_dual(tangents)).unpack()
+}
+
+/// Computes the Jacobian of a function from vectors to vectors with reverse-mode
+/// differentiation.
+///
+/// Each row is the gradient of one output, so the function is evaluated and differentiated once
+/// per output, which is efficient when there are fewer outputs than inputs. Otherwise, prefer
+/// [jacobian_fwd].
+///
+/// # Returns
+///
+/// The Jacobian of shape `[num_outputs, num_inputs]`.
+pub fn jacobian<B, F>(f: F, x: Tensor<B, 1>) -> Tensor<B, 2>
+where
+    B: Backend,
+    F: Fn(Tensor<Autodiff<B>, 1>) -> Tensor<Autodiff<B>, 1>,
+{
+    let num_outputs = f(Tensor::from_inner(x.clone())).dims()[0];
+
+    let rows = (0..num_outputs)
+        .map(|i| {
+            let input = Tensor::<Autodiff<B>, 1>::from_inner(x.clone()).require_grad();
+            let output = f(input.clone()).slice([i..i + 1]).sum();
+            let grads = output.backward();
+
+            input.grad(&grads).unwrap_or_else(|| x.zeros_like())
+        })
+        .collect::<Vec<_>>();
+
+    Tensor::stack(rows, 0)
+}
+
+/// Computes the Jacobian of a function from vectors to vectors with forward-mode
+/// differentiation.
+///
+/// Each column is the tangent of the outputs in the direction of one input, so the function is
+/// evaluated once per input, which is efficient when there are fewer inputs than outputs.
+/// Otherwise, prefer [jacobian].
+///
+/// # Returns
+///
+/// The Jacobian of shape `[num_outputs, num_inputs]`.
+pub fn jacobian_fwd<B, F>(f: F, x: Tensor<B, 1>) -> Tensor<B, 2>
+where
+    B: Backend,
+    F: Fn(Tensor<Dual<B>, 1>) -> Tensor<Dual<B>, 1>,
+{
+    let columns = (0..x.dims()[0])
+        .map(|j| jvp(|[x]| f(x), [x.clone()], [basis(&x, j)]).1)
+        .collect::<Vec<_>>();
+
+    Tensor::stack(columns, 1)
+}
+
+/// Computes the Hessian-vector product of a scalar function with forward-over-reverse
+/// differentiation.
+///
+/// The gradient is computed with reverse mode on top of the [dual](Dual) backend, so that it
+/// carries its derivative in the direction of the vector, without building the Hessian.
+///
+/// # Arguments
+///
+/// * `f` - The function to differentiate, returning a tensor with a single element.
+/// * `x` - The point at which the function is differentiated.
+/// * `v` - The vector, with the same shape as `x`.
+///
+/// # Returns
+///
+/// The gradient of the function and the Hessian-vector product.
+pub fn hvp<B, F>(f: F, x: Tensor<B, 1>, v: Tensor<B, 1>) -> (Tensor<B, 1>, Tensor<B, 1>)
+where
+    B: Backend,
+    F: FnOnce(Tensor<Autodiff<Dual<B>>, 1>) -> Tensor<Autodiff<Dual<B>>, 1>,
+{
+    let zeros = x.zeros_like();
+    let input = Tensor::<Autodiff<Dual<B>>, 1>::from_inner(make_dual(x, v)).require_grad();
+    let grads = f(input.clone()).backward();
+
+    match input.grad(&grads) {
+        Some(grad) => {
+            let (grad, hvp) = unpack_dual(grad);
+            (grad, hvp.unwrap_or(zeros))
+        }
+        None => (zeros.clone(), zeros),
+    }
+}
+
+/// Computes the Hessian of a scalar function with forward-over-reverse differentiation.
+///
+/// Each column is a [Hessian-vector product](hvp) with one basis vector, so the function is
+/// evaluated and differentiated once per input.
+///
+/// # Returns
+///
+/// The Hessian of shape `[num_inputs, num_inputs]`.
+pub fn hessian<B, F>(f: F, x: Tensor<B, 1>) -> Tensor<B, 2>
+where
+    B: Backend,
+    F: Fn(Tensor<Autodiff<Dual<B>>, 1>) -> Tensor<Autodiff<Dual<B>>, 1>,
+{
+    let columns = (0..x.dims()[0])
+        .map(|j| hvp(&f, x.clone(), basis(&x, j)).1)
+        .collect::<Vec<_>>();
+
+    Tensor::stack(columns, 1)
+}
+
+/// Returns the basis vector along the given index, on the device of the given vector.
+fn basis<B: Backend>(like: &Tensor<B, 1>, index: usize) -> Tensor<B, 1> {
+    let device = like.device();
+
+    like.zeros_like()
+        .slice_assign([index..index + 1], Tensor::ones([1], &device))
+}
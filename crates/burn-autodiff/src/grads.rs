@@ -2,6 +2,7 @@ use burn_tensor::{TensorMetadata, backend::Backend, container::TensorContainer,
 
 use crate::{
     NodeID,
+    collections::HashSet,
     graph::{NodeRef, Requirement},
     tensor::AutodiffTensor,
 };
@@ -12,6 +13,7 @@ pub type GradID = u64;
 /// Gradients container used during the backward pass.
 pub struct Gradients {
     container: TensorContainer<GradID>,
+    retained: HashSet<NodeID>,
 }
 
 impl Gradients {
@@ -19,6 +21,7 @@ impl Gradients {
     pub fn new<B: Backend>(root_node: NodeRef, root_tensor: FloatTensor<B>) -> Self {
         let mut gradients = Self {
             container: TensorContainer::new(),
+            retained: HashSet::new(),
         };
         gradients.register::<B>(
             root_node.id,
@@ -38,6 +41,11 @@ impl Gradients {
                 .get::<B>(&node.id.value)
                 .map(|tensor| tensor.tensor())
                 .expect("Can't consume the gradients before they are registered at least once."),
+            Requirement::GradInBackward if self.retained.contains(&node.id) => self
+                .container
+                .get::<B>(&node.id.value)
+                .map(|tensor| tensor.tensor())
+                .expect("Can't consume the gradients before they are registered at least once."),
             Requirement::GradInBackward => self
                 .container
                 .remove::<B>(&node.id.value)
@@ -54,6 +62,18 @@ impl Gradients {
             .map(|tensor| tensor.tensor())
     }
 
+    /// Removes the grad tensor of a node from the container.
+    pub fn remove_node<B: Backend>(&mut self, node_id: NodeID) -> Option<FloatTensor<B>> {
+        self.container
+            .remove::<B>(&node_id.value)
+            .map(|tensor| tensor.tensor())
+    }
+
+    /// Keeps the gradients of a non-leaf node in the container after they are consumed.
+    pub fn retain(&mut self, node_id: NodeID) {
+        self.retained.insert(node_id);
+    }
+
     /// Gets a grad tensor from the container.
     pub fn get<B: Backend>(&self, tensor: &AutodiffTensor<B>) -> Option<FloatTensor<B>> {
         self.container
@@ -0,0 +1,42 @@
+use super::NodeID;
+use crate::grads::Gradients;
+use alloc::boxed::Box;
+use burn_tensor::{backend::Backend, ops::FloatTensor};
+use core::marker::PhantomData;
+
+/// Hook called with the gradient of a node during the backward pass.
+pub trait GradHook: Send {
+    /// Applies the hook on the gradient of the given node and consumes it.
+    ///
+    /// Nothing happens if no gradient was registered for the node.
+    fn apply(self: Box<Self>, node: NodeID, grads: &mut Gradients);
+}
+
+pub type GradHookBoxed = Box<dyn GradHook>;
+
+/// Hook on float tensor gradients, which can inspect or replace the gradient.
+pub struct FloatGradHook<B, F> {
+    func: F,
+    _backend: PhantomData<B>,
+}
+
+impl<B, F> FloatGradHook<B, F> {
+    pub fn new(func: F) -> Self {
+        Self {
+            func,
+            _backend: PhantomData,
+        }
+    }
+}
+
+impl<B, F> GradHook for FloatGradHook<B, F>
+where
+    B: Backend,
+    F: Fn(FloatTensor<B>) -> FloatTensor<B> + Send,
+{
+    fn apply(self: Box<Self>, node: NodeID, grads: &mut Gradients) {
+        if let Some(grad) = grads.remove_node::<B>(node) {
+            grads.register::<B>(node, (self.func)(grad));
+        }
+    }
+}
@@ -5,8 +5,13 @@ use core::sync::atomic::{AtomicU64, Ordering};
 #[cfg(not(target_has_atomic = "64"))]
 use portable_atomic::{AtomicU64, Ordering};
 
-use crate::checkpoint::retro_forward::RetroForward;
-use crate::runtime::AutodiffClientImpl;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicUsize;
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic::AtomicUsize;
+
+use crate::checkpoint::{report::GraphMemory, retro_forward::RetroForward};
+use crate::runtime::GraphClient;
 
 use super::Requirement;
 
@@ -36,7 +41,12 @@ pub struct Node {
     pub id: NodeID,
     pub requirement: Requirement,
     pub properties: ComputingProperty,
-    pub client: AutodiffClientImpl,
+    pub client: GraphClient,
+    /// The memory kept by the graph of the node, which leaves don't belong to.
+    pub memory: Option<Arc<GraphMemory>>,
+    /// Number of checkpointing actions keeping the output of the node in memory.
+    #[new(default)]
+    pub checkpoints: AtomicUsize,
 }
 pub type NodeRef = Arc<Node>;
 
@@ -16,6 +16,12 @@ extern crate alloc;
 
 /// Checkpoint module.
 pub mod checkpoint;
+/// Forward-mode module.
+pub mod forward;
+/// Custom function module.
+pub mod function;
+/// Functional differentiation module.
+pub mod functional;
 /// Gradients module.
 pub mod grads;
 /// Operation module.
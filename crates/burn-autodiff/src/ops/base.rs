@@ -88,7 +88,7 @@ where
             self.nodes,
             self.requirement,
             self.backward,
-            C::compute_property(retro_forward),
+            C::compute_property(retro_forward, &self.nodes),
             self.checkpointer_builder,
         )
     }
@@ -106,10 +106,16 @@ where
         B2: Backend,
         A: IntoIterator<Item = &'a AutodiffTensor<B2>>,
     {
-        let compute_property = match C::checkpoint_parents(parents, &mut self.checkpointer_builder)
-        {
-            Ok(..) => self.compute_property,
-            Err(..) => ComputingProperty::ComputeBound,
+        // The parents are only needed to recompute the operation, which the strategy may have
+        // decided against.
+        let compute_property = match &self.compute_property {
+            ComputingProperty::MemoryBound { .. } => {
+                match C::checkpoint_parents(parents, &mut self.checkpointer_builder) {
+                    Ok(..) => self.compute_property,
+                    Err(..) => ComputingProperty::ComputeBound,
+                }
+            }
+            _ => self.compute_property,
         };
 
         OpsPrep::new(
@@ -0,0 +1,124 @@
+use crate::{
+    Autodiff,
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    ops::{Backward, Ops, OpsKind, binary},
+};
+use burn_tensor::{
+    ElementConversion,
+    backend::Backend,
+    ops::{ComplexOps, ComplexTensor, FloatTensor},
+};
+
+/// The arithmetic operations use the default implementations, which are written with tracked float
+/// operations, so the gradients of the real and imaginary parts follow from the chain rule.
+///
+/// The modulus and the argument have their own backward steps, since they aren't differentiable
+/// at zero, where the gradient is defined to be zero.
+impl<B: Backend, C: CheckpointStrategy> ComplexOps<Autodiff<B, C>> for Autodiff<B, C> {
+    fn complex_abs(tensor: ComplexTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct ComplexAbs;
+
+        impl<B: Backend> Backward<B, 2> for ComplexAbs {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (real, imag) = ops.state;
+                let modulus = safe_modulus::<B>(B::complex_abs(ComplexTensor::<B>::new(
+                    real.clone(),
+                    imag.clone(),
+                )));
+
+                // partial(|z|, x) = x / |z|, partial(|z|, y) = y / |z|
+                let partial_real = B::float_div(real, modulus.clone());
+                let partial_imag = B::float_div(imag, modulus);
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| B::float_mul(grad, partial_real),
+                    |grad| B::float_mul(grad, partial_imag),
+                );
+            }
+        }
+
+        let (real, imag) = (tensor.real, tensor.imag);
+        let output = B::complex_abs(ComplexTensor::<B>::new(
+            real.primitive.clone(),
+            imag.primitive.clone(),
+        ));
+
+        match ComplexAbs
+            .prepare::<C>([real.node.clone(), imag.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((real.primitive, imag.primitive), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn complex_angle(tensor: ComplexTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct ComplexAngle;
+
+        impl<B: Backend> Backward<B, 2> for ComplexAngle {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (real, imag) = ops.state;
+                let modulus = safe_modulus::<B>(B::complex_abs(ComplexTensor::<B>::new(
+                    real.clone(),
+                    imag.clone(),
+                )));
+                let modulus_sq = B::float_mul(modulus.clone(), modulus);
+
+                // partial(atan2(y, x), x) = -y / |z|², partial(atan2(y, x), y) = x / |z|²
+                let partial_real = B::float_div(B::float_neg(imag), modulus_sq.clone());
+                let partial_imag = B::float_div(real, modulus_sq);
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| B::float_mul(grad, partial_real),
+                    |grad| B::float_mul(grad, partial_imag),
+                );
+            }
+        }
+
+        let (real, imag) = (tensor.real, tensor.imag);
+        let output = B::complex_angle(ComplexTensor::<B>::new(
+            real.primitive.clone(),
+            imag.primitive.clone(),
+        ));
+
+        match ComplexAngle
+            .prepare::<C>([real.node.clone(), imag.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((real.primitive, imag.primitive), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+}
+
+/// Replaces a zero modulus by one, so that the gradients at zero, where both parts are zero, are
+/// zero instead of undefined.
+fn safe_modulus<B: Backend>(modulus: FloatTensor<B>) -> FloatTensor<B> {
+    let zero = B::float_equal_elem(modulus.clone(), 0.elem());
+    B::float_mask_fill(modulus, zero, 1.elem())
+}
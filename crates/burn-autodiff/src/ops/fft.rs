@@ -0,0 +1,104 @@
+use crate::{
+    Autodiff,
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    graph::NodeRef,
+    ops::{Backward, Ops, OpsKind},
+};
+use burn_tensor::{
+    TensorMetadata,
+    backend::Backend,
+    ops::{FftOps, FloatTensor},
+};
+
+/// The real and imaginary parts of the output are tracked by two independent backward steps,
+/// each one receiving the gradient of a single part.
+///
+/// Seen as a real linear map on the pair `(real, imag)`, the adjoint of the transform is the
+/// transform with the opposite exponent sign, so both steps transform their gradient in the
+/// other direction and their contributions add up to the gradients of the inputs.
+impl<B: Backend, C: CheckpointStrategy> FftOps<Autodiff<B, C>> for Autodiff<B, C> {
+    fn fft(
+        real: FloatTensor<Self>,
+        imag: FloatTensor<Self>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        #[derive(Debug)]
+        struct FftReal;
+        #[derive(Debug)]
+        struct FftImag;
+
+        impl<B: Backend> Backward<B, 2> for FftReal {
+            type State = (usize, bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let grad = grads.consume::<B>(&ops.node);
+                let zeros = B::float_zeros(grad.shape(), &B::float_device(&grad));
+
+                fft_backward::<B>(ops.parents, grads, grad, zeros, ops.state);
+            }
+        }
+
+        impl<B: Backend> Backward<B, 2> for FftImag {
+            type State = (usize, bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let grad = grads.consume::<B>(&ops.node);
+                let zeros = B::float_zeros(grad.shape(), &B::float_device(&grad));
+
+                fft_backward::<B>(ops.parents, grads, zeros, grad, ops.state);
+            }
+        }
+
+        let (output_real, output_imag) =
+            B::fft(real.primitive.clone(), imag.primitive.clone(), dim, inverse);
+
+        let output_real = match FftReal
+            .prepare::<C>([real.node.clone(), imag.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((dim, inverse), output_real),
+            OpsKind::UnTracked(prep) => prep.finish(output_real),
+        };
+        let output_imag = match FftImag
+            .prepare::<C>([real.node.clone(), imag.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((dim, inverse), output_imag),
+            OpsKind::UnTracked(prep) => prep.finish(output_imag),
+        };
+
+        (output_real, output_imag)
+    }
+}
+
+fn fft_backward<B: Backend>(
+    parents: [Option<NodeRef>; 2],
+    grads: &mut Gradients,
+    grad_real: FloatTensor<B>,
+    grad_imag: FloatTensor<B>,
+    (dim, inverse): (usize, bool),
+) {
+    let [node_real, node_imag] = parents;
+    let (grad_real, grad_imag) = B::fft(grad_real, grad_imag, dim, !inverse);
+
+    if let Some(node) = node_real {
+        grads.register::<B>(node.id, grad_real);
+    }
+    if let Some(node) = node_imag {
+        grads.register::<B>(node.id, grad_imag);
+    }
+}
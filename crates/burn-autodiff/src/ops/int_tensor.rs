@@ -202,6 +202,19 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_select_assign(tensor, dim, indices, value)
     }
 
+    fn int_gather_nd(tensor: IntTensor<B>, indices: IntTensor<B>) -> IntTensor<B> {
+        B::int_gather_nd(tensor, indices)
+    }
+
+    fn int_scatter_nd(
+        tensor: IntTensor<B>,
+        indices: IntTensor<B>,
+        values: IntTensor<B>,
+        accumulate: bool,
+    ) -> IntTensor<B> {
+        B::int_scatter_nd(tensor, indices, values, accumulate)
+    }
+
     fn int_mask_where(
         tensor: IntTensor<B>,
         mask: BoolTensor<B>,
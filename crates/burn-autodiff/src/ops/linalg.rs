@@ -0,0 +1,659 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{
+    Autodiff,
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    ops::{Backward, Ops, OpsKind, unary},
+    tensor::AutodiffTensor,
+};
+use burn_tensor::{
+    ElementConversion, TensorData, TensorMetadata,
+    backend::Backend,
+    ops::{FloatTensor, LinalgOps},
+};
+
+/// The gradients of the outputs of decompositions returning multiple tensors are computed by
+/// independent backward steps, each one receiving the gradient of a single output. Since the
+/// backward formulas are linear in the output gradients, the contributions add up to the
+/// gradient of the input.
+impl<B: Backend, C: CheckpointStrategy> LinalgOps<Autodiff<B, C>> for Autodiff<B, C> {
+    fn cholesky(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Cholesky;
+
+        impl<B: Backend> Backward<B, 1> for Cholesky {
+            type State = FloatTensor<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    cholesky_backward::<B>(grad, ops.state)
+                });
+            }
+        }
+
+        let output = B::cholesky(tensor.primitive.clone());
+        track::<B, C, _>(Cholesky, &tensor, output.clone(), output)
+    }
+
+    fn qr(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        #[derive(Debug)]
+        struct QrQ;
+        #[derive(Debug)]
+        struct QrR;
+
+        impl<B: Backend> Backward<B, 1> for QrQ {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (q, r) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    qr_backward::<B>(Some(grad), None, q, r)
+                });
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for QrR {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (q, r) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    qr_backward::<B>(None, Some(grad), q, r)
+                });
+            }
+        }
+
+        let (q, r) = B::qr(tensor.primitive.clone());
+        let state = (q.clone(), r.clone());
+
+        (
+            track::<B, C, _>(QrQ, &tensor, state.clone(), q),
+            track::<B, C, _>(QrR, &tensor, state, r),
+        )
+    }
+
+    fn svd(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>, FloatTensor<Self>) {
+        #[derive(Debug)]
+        struct SvdU;
+        #[derive(Debug)]
+        struct SvdS;
+        #[derive(Debug)]
+        struct SvdVh;
+
+        impl<B: Backend> Backward<B, 1> for SvdU {
+            type State = (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (u, s, vh) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    svd_backward::<B>(Some(grad), None, None, u, s, vh)
+                });
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for SvdS {
+            type State = (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (u, s, vh) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    svd_backward::<B>(None, Some(grad), None, u, s, vh)
+                });
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for SvdVh {
+            type State = (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (u, s, vh) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    svd_backward::<B>(None, None, Some(grad), u, s, vh)
+                });
+            }
+        }
+
+        let (u, s, vh) = B::svd(tensor.primitive.clone());
+        let state = (u.clone(), s.clone(), vh.clone());
+
+        (
+            track::<B, C, _>(SvdU, &tensor, state.clone(), u),
+            track::<B, C, _>(SvdS, &tensor, state.clone(), s),
+            track::<B, C, _>(SvdVh, &tensor, state, vh),
+        )
+    }
+
+    fn eigh(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        #[derive(Debug)]
+        struct EighValues;
+        #[derive(Debug)]
+        struct EighVectors;
+
+        impl<B: Backend> Backward<B, 1> for EighValues {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (values, vectors) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    eigh_backward::<B>(Some(grad), None, values, vectors)
+                });
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for EighVectors {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (values, vectors) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    eigh_backward::<B>(None, Some(grad), values, vectors)
+                });
+            }
+        }
+
+        let (values, vectors) = B::eigh(tensor.primitive.clone());
+        let state = (values.clone(), vectors.clone());
+
+        (
+            track::<B, C, _>(EighValues, &tensor, state.clone(), values),
+            track::<B, C, _>(EighVectors, &tensor, state, vectors),
+        )
+    }
+
+    fn solve_triangular(
+        a: FloatTensor<Self>,
+        b: FloatTensor<Self>,
+        upper: bool,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct SolveTriangular;
+
+        impl<B: Backend> Backward<B, 2> for SolveTriangular {
+            type State = (FloatTensor<B>, FloatTensor<B>, bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let [node_a, node_b] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (a, x, upper) = ops.state;
+
+                // X = A⁻¹B, so gB = A⁻ᵀ gX and gA = -gB Xᵀ restricted to the triangle of A.
+                let grad_b = B::solve_triangular(B::float_transpose(a), grad, !upper);
+
+                if let Some(node) = node_a {
+                    let grad = B::float_neg(B::float_matmul(grad_b.clone(), B::float_transpose(x)));
+                    let grad = match upper {
+                        true => triu::<B>(grad, 0),
+                        false => tril::<B>(grad, 0),
+                    };
+                    grads.register::<B>(node.id, grad)
+                }
+                if let Some(node) = node_b {
+                    grads.register::<B>(node.id, grad_b)
+                }
+            }
+        }
+
+        let output = B::solve_triangular(a.primitive.clone(), b.primitive, upper);
+
+        match SolveTriangular
+            .prepare::<C>([a.node.clone(), b.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((a.primitive, output.clone(), upper), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn solve(a: FloatTensor<Self>, b: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Solve;
+
+        impl<B: Backend> Backward<B, 2> for Solve {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let [node_a, node_b] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (a, x) = ops.state;
+
+                // X = A⁻¹B, so gB = A⁻ᵀ gX and gA = -gB Xᵀ.
+                let grad_b = B::solve(B::float_transpose(a), grad);
+
+                if let Some(node) = node_a {
+                    let grad = B::float_matmul(grad_b.clone(), B::float_transpose(x));
+                    grads.register::<B>(node.id, B::float_neg(grad))
+                }
+                if let Some(node) = node_b {
+                    grads.register::<B>(node.id, grad_b)
+                }
+            }
+        }
+
+        let output = B::solve(a.primitive.clone(), b.primitive);
+
+        match Solve
+            .prepare::<C>([a.node.clone(), b.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((a.primitive, output.clone()), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn inverse(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Inverse;
+
+        impl<B: Backend> Backward<B, 1> for Inverse {
+            type State = FloatTensor<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // gA = -A⁻ᵀ gY A⁻ᵀ
+                    let inverse_t = B::float_transpose(ops.state);
+                    let grad = B::float_matmul(inverse_t.clone(), grad);
+                    B::float_neg(B::float_matmul(grad, inverse_t))
+                });
+            }
+        }
+
+        let output = B::inverse(tensor.primitive.clone());
+        track::<B, C, _>(Inverse, &tensor, output.clone(), output)
+    }
+
+    fn det(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Det;
+
+        impl<B: Backend> Backward<B, 1> for Det {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (a, det) = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // gA = g det(A) A⁻ᵀ
+                    let inverse_t = B::float_transpose(B::inverse(a));
+                    B::float_mul(inverse_t, B::float_mul(grad, det))
+                });
+            }
+        }
+
+        let output = B::det(tensor.primitive.clone());
+        let state = (tensor.primitive.clone(), output.clone());
+        track::<B, C, _>(Det, &tensor, state, output)
+    }
+
+    fn slogdet(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        #[derive(Debug)]
+        struct LogAbsDet;
+
+        impl<B: Backend> Backward<B, 1> for LogAbsDet {
+            type State = FloatTensor<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // gA = g A⁻ᵀ
+                    let inverse_t = B::float_transpose(B::inverse(ops.state));
+                    B::float_mul(inverse_t, grad)
+                });
+            }
+        }
+
+        let (sign, logabsdet) = B::slogdet(tensor.primitive.clone());
+        let state = tensor.primitive.clone();
+
+        // The sign is piecewise constant, so it doesn't have a gradient.
+        (
+            AutodiffTensor::new(sign),
+            track::<B, C, _>(LogAbsDet, &tensor, state, logabsdet),
+        )
+    }
+}
+
+/// Registers the output of a unary operation, keeping the state only when it is tracked.
+fn track<B, C, O>(
+    op: O,
+    tensor: &AutodiffTensor<B>,
+    state: O::State,
+    output: FloatTensor<B>,
+) -> AutodiffTensor<B>
+where
+    B: Backend,
+    C: CheckpointStrategy,
+    O: Backward<B, 1>,
+{
+    match op
+        .prepare::<C>([tensor.node.clone()])
+        .compute_bound()
+        .stateful()
+    {
+        OpsKind::Tracked(prep) => prep.finish(state, output),
+        OpsKind::UnTracked(prep) => prep.finish(output),
+    }
+}
+
+/// Creates a `[1, ..., 1, rows, cols]` mask with ones where `keep(row, col)` is true, matching the
+/// rank, dtype and device of the given tensor.
+pub(crate) fn mask<B: Backend>(
+    like: &FloatTensor<B>,
+    rows: usize,
+    cols: usize,
+    keep: impl Fn(usize, usize) -> bool,
+) -> FloatTensor<B> {
+    let values = (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| (i, j)))
+        .map(|(i, j)| if keep(i, j) { 1.0 } else { 0.0 })
+        .collect::<Vec<f32>>();
+
+    let mut shape = alloc::vec![1; like.shape().num_dims() - 2];
+    shape.push(rows);
+    shape.push(cols);
+
+    let data = TensorData::new(values, shape).convert_dtype(like.dtype());
+    B::float_from_data(data, &B::float_device(like))
+}
+
+pub(crate) fn matrix_dims<B: Backend>(tensor: &FloatTensor<B>) -> (usize, usize) {
+    let shape = tensor.shape();
+    let rank = shape.num_dims();
+    (shape.dims[rank - 2], shape.dims[rank - 1])
+}
+
+pub(crate) fn eye<B: Backend>(like: &FloatTensor<B>, size: usize) -> FloatTensor<B> {
+    mask::<B>(like, size, size, |i, j| i == j)
+}
+
+/// Keeps the elements on and below the given diagonal.
+pub(crate) fn tril<B: Backend>(tensor: FloatTensor<B>, diagonal: i64) -> FloatTensor<B> {
+    let (rows, cols) = matrix_dims::<B>(&tensor);
+    let mask = mask::<B>(&tensor, rows, cols, |i, j| j as i64 - i as i64 <= diagonal);
+    B::float_mul(tensor, mask)
+}
+
+/// Keeps the elements on and above the given diagonal.
+pub(crate) fn triu<B: Backend>(tensor: FloatTensor<B>, diagonal: i64) -> FloatTensor<B> {
+    let (rows, cols) = matrix_dims::<B>(&tensor);
+    let mask = mask::<B>(&tensor, rows, cols, |i, j| j as i64 - i as i64 >= diagonal);
+    B::float_mul(tensor, mask)
+}
+
+/// Returns `X - Xᵀ`.
+pub(crate) fn skew<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    B::float_sub(tensor.clone(), B::float_transpose(tensor))
+}
+
+/// Slices the columns of a batch of matrices.
+pub(crate) fn slice_cols<B: Backend>(tensor: FloatTensor<B>, cols: Range<usize>) -> FloatTensor<B> {
+    let shape = tensor.shape();
+    let rank = shape.num_dims();
+    let mut ranges = shape.dims.iter().map(|dim| 0..*dim).collect::<Vec<_>>();
+    ranges[rank - 1] = cols;
+
+    B::float_slice(tensor, &ranges)
+}
+
+/// Adds two optional terms of a gradient.
+fn accumulate<B: Backend>(
+    lhs: Option<FloatTensor<B>>,
+    rhs: FloatTensor<B>,
+) -> Option<FloatTensor<B>> {
+    Some(match lhs {
+        Some(lhs) => B::float_add(lhs, rhs),
+        None => rhs,
+    })
+}
+
+/// Backward of the Cholesky decomposition `A = L Lᵀ`.
+///
+/// `gA = L⁻ᵀ Φ(Lᵀ gL) L⁻¹`, where `Φ` takes the lower triangle and halves the diagonal, which is
+/// then symmetrized.
+fn cholesky_backward<B: Backend>(grad: FloatTensor<B>, l: FloatTensor<B>) -> FloatTensor<B> {
+    let l_t = B::float_transpose(l);
+    let phi = tril::<B>(B::float_matmul(l_t.clone(), grad), 0);
+    let phi = B::float_add(phi.clone(), B::float_transpose(tril::<B>(phi, -1)));
+    let phi = B::float_mul_scalar(phi, 0.5.elem());
+
+    let grad = B::solve_triangular(l_t.clone(), phi, true);
+    let grad = B::float_transpose(B::solve_triangular(l_t, B::float_transpose(grad), true));
+
+    let grad = B::float_add(grad.clone(), B::float_transpose(grad));
+    B::float_mul_scalar(grad, 0.5.elem())
+}
+
+/// Backward of the reduced QR decomposition `A = QR`.
+///
+/// When `A` has more columns than rows, it is split as `A = [X | Y]` with `R = [U | V]`, such that
+/// `X = QU` is a square decomposition and `Y = QV`.
+fn qr_backward<B: Backend>(
+    grad_q: Option<FloatTensor<B>>,
+    grad_r: Option<FloatTensor<B>>,
+    q: FloatTensor<B>,
+    r: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let (m, n) = (matrix_dims::<B>(&q).0, matrix_dims::<B>(&r).1);
+
+    if m >= n {
+        return qr_backward_tall::<B>(grad_q, grad_r, q, r);
+    }
+
+    let u = slice_cols::<B>(r.clone(), 0..m);
+    let v = slice_cols::<B>(r, m..n);
+    let y = B::float_matmul(q.clone(), v);
+
+    let (grad_x, grad_y) = match grad_r {
+        Some(grad_r) => {
+            let grad_u = slice_cols::<B>(grad_r.clone(), 0..m);
+            let grad_v = slice_cols::<B>(grad_r, m..n);
+
+            let grad_q = accumulate::<B>(
+                grad_q,
+                B::float_matmul(y, B::float_transpose(grad_v.clone())),
+            );
+            let grad_y = B::float_matmul(q.clone(), grad_v);
+            let grad_x = qr_backward_tall::<B>(grad_q, Some(grad_u), q, u);
+
+            (grad_x, grad_y)
+        }
+        None => {
+            let grad_y = B::float_zeros(y.shape(), &B::float_device(&y));
+            let grad_x = qr_backward_tall::<B>(grad_q, None, q, u);
+
+            (grad_x, grad_y)
+        }
+    };
+
+    let rank = grad_x.shape().num_dims();
+    B::float_cat(alloc::vec![grad_x, grad_y], rank - 1)
+}
+
+/// Backward of the reduced QR decomposition of a matrix with at least as many rows as columns.
+///
+/// `gA = [gQ + Q copyltu(M)] R⁻ᵀ`, where `M = triu(gR Rᵀ - Qᵀ gQ)` and `copyltu(M)` is
+/// `M + Mᵀ` with the diagonal halved.
+fn qr_backward_tall<B: Backend>(
+    grad_q: Option<FloatTensor<B>>,
+    grad_r: Option<FloatTensor<B>>,
+    q: FloatTensor<B>,
+    r: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let k = matrix_dims::<B>(&r).0;
+
+    let mut m = None;
+    if let Some(grad_r) = grad_r {
+        m = accumulate::<B>(m, B::float_matmul(grad_r, B::float_transpose(r.clone())));
+    }
+    if let Some(grad_q) = grad_q.clone() {
+        let term = B::float_matmul(B::float_transpose(q.clone()), grad_q);
+        m = accumulate::<B>(m, B::float_neg(term));
+    }
+
+    let m = triu::<B>(m.expect("At least one gradient is provided"), 0);
+    let m = B::float_add(m.clone(), B::float_transpose(m));
+    let diagonal = B::float_mul(m.clone(), eye::<B>(&m, k));
+    let m = B::float_sub(m, B::float_mul_scalar(diagonal, 0.5.elem()));
+
+    let mut grad = B::float_matmul(q, m);
+    if let Some(grad_q) = grad_q {
+        grad = B::float_add(grad, grad_q);
+    }
+
+    // gA R⁻ᵀ = (R⁻¹ gAᵀ)ᵀ
+    B::float_transpose(B::solve_triangular(r, B::float_transpose(grad), true))
+}
+
+/// Backward of the reduced singular value decomposition `A = U diag(S) Vh`.
+///
+/// The core term is `U [(skew(Uᵀ gU) S + S skew(Vh gVhᵀ)) / E + diag(gS)] Vh` with
+/// `E[j, k] = S[k]² - S[j]²`, and projection terms are added for the singular vectors that don't
+/// span the whole space when the matrix isn't square.
+fn svd_backward<B: Backend>(
+    grad_u: Option<FloatTensor<B>>,
+    grad_s: Option<FloatTensor<B>>,
+    grad_vh: Option<FloatTensor<B>>,
+    u: FloatTensor<B>,
+    s: FloatTensor<B>,
+    vh: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let (m, k) = matrix_dims::<B>(&u);
+    let n = matrix_dims::<B>(&vh).1;
+
+    let s_row = s;
+    let s_col = B::float_transpose(s_row.clone());
+    let s2_row = B::float_mul(s_row.clone(), s_row.clone());
+    let s2_col = B::float_transpose(s2_row.clone());
+    let eye = eye::<B>(&s_row, k);
+    let e = B::float_add(B::float_sub(s2_row, s2_col), eye.clone());
+
+    let mut inner = None;
+    if let Some(grad_u) = grad_u.clone() {
+        let uhgu = B::float_matmul(B::float_transpose(u.clone()), grad_u);
+        let term = B::float_mul(B::float_div(skew::<B>(uhgu), e.clone()), s_row.clone());
+        inner = accumulate::<B>(inner, term);
+    }
+    if let Some(grad_vh) = grad_vh.clone() {
+        let vhgv = B::float_matmul(vh.clone(), B::float_transpose(grad_vh));
+        let term = B::float_mul(s_col.clone(), B::float_div(skew::<B>(vhgv), e));
+        inner = accumulate::<B>(inner, term);
+    }
+    if let Some(grad_s) = grad_s {
+        inner = accumulate::<B>(inner, B::float_mul(eye, grad_s));
+    }
+
+    let inner = inner.expect("At least one gradient is provided");
+    let mut grad = B::float_matmul(B::float_matmul(u.clone(), inner), vh.clone());
+
+    if let Some(grad_u) = grad_u.filter(|_| m > k) {
+        // (I - U Uᵀ) gU S⁻¹ Vh
+        let uhgu = B::float_matmul(B::float_transpose(u.clone()), grad_u.clone());
+        let term = B::float_sub(grad_u, B::float_matmul(u.clone(), uhgu));
+        let term = B::float_matmul(B::float_div(term, s_row), vh.clone());
+        grad = B::float_add(grad, term);
+    }
+    if let Some(grad_vh) = grad_vh.filter(|_| n > k) {
+        // U S⁻¹ gVh (I - Vhᵀ Vh)
+        let gvhv = B::float_matmul(grad_vh.clone(), B::float_transpose(vh.clone()));
+        let term = B::float_sub(grad_vh, B::float_matmul(gvhv, vh));
+        let term = B::float_matmul(u, B::float_div(term, s_col));
+        grad = B::float_add(grad, term);
+    }
+
+    grad
+}
+
+/// Backward of the symmetric eigendecomposition `A = V diag(L) Vᵀ`.
+///
+/// `gA = V [skew(Vᵀ gV) / 2E + diag(gL)] Vᵀ` with `E[j, k] = L[k] - L[j]`.
+fn eigh_backward<B: Backend>(
+    grad_values: Option<FloatTensor<B>>,
+    grad_vectors: Option<FloatTensor<B>>,
+    values: FloatTensor<B>,
+    vectors: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let n = matrix_dims::<B>(&vectors).0;
+    let eye = eye::<B>(&values, n);
+
+    let mut inner = None;
+    if let Some(grad_vectors) = grad_vectors {
+        let vhgv = B::float_matmul(B::float_transpose(vectors.clone()), grad_vectors);
+        let vhgv = B::float_mul_scalar(skew::<B>(vhgv), 0.5.elem());
+        let e = B::float_sub(values.clone(), B::float_transpose(values));
+        let e = B::float_add(e, eye.clone());
+        inner = accumulate::<B>(inner, B::float_div(vhgv, e));
+    }
+    if let Some(grad_values) = grad_values {
+        inner = accumulate::<B>(inner, B::float_mul(eye, grad_values));
+    }
+
+    let inner = inner.expect("At least one gradient is provided");
+    let grad = B::float_matmul(vectors.clone(), inner);
+    B::float_matmul(grad, B::float_transpose(vectors))
+}
@@ -2,12 +2,16 @@ mod activation;
 mod backward;
 mod base;
 mod bool_tensor;
+mod complex;
+mod fft;
 mod int_tensor;
 mod module;
 mod qtensor;
 mod tensor;
 mod transaction;
 
+pub(crate) mod cumulative;
+pub(crate) mod linalg;
 pub(crate) mod maxmin;
 pub(crate) mod sort;
 
@@ -8,6 +8,7 @@ use crate::tensor::AutodiffTensor;
 
 use burn_tensor::backend::Backend;
 use burn_tensor::ops::*;
+use burn_tensor::{Shape, TensorMetadata};
 
 use super::OpsKind;
 
@@ -46,14 +47,6 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         }
     }
 
-    fn embedding_backward(
-        _weights: AutodiffTensor<B>,
-        _output: AutodiffTensor<B>,
-        _indices: IntTensor<B>,
-    ) -> AutodiffTensor<B> {
-        panic!("Can't differentiate embedding backward.");
-    }
-
     fn conv1d(
         x: AutodiffTensor<B>,
         weight: AutodiffTensor<B>,
@@ -1323,14 +1316,54 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     }
 
     fn avg_pool2d_backward(
-        _x: AutodiffTensor<B>,
-        _grad: AutodiffTensor<B>,
-        _kernel_size: [usize; 2],
-        _stride: [usize; 2],
-        _padding: [usize; 2],
-        _count_include_pad: bool,
+        x: AutodiffTensor<B>,
+        grad: AutodiffTensor<B>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
     ) -> AutodiffTensor<B> {
-        panic!("Can't differentiate avg pool 2d backward.");
+        #[derive(Debug)]
+        struct AvgPool2DBackward;
+
+        impl<B: Backend> Backward<B, 1> for AvgPool2DBackward {
+            type State = ([usize; 2], [usize; 2], [usize; 2], bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (kernel_size, stride, padding, count_include_pad) = ops.state;
+
+                // The backward pass is linear in the output gradient, its transpose being the
+                // forward pass.
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::avg_pool2d(grad, kernel_size, stride, padding, count_include_pad)
+                });
+            }
+        }
+
+        let output = B::avg_pool2d_backward(
+            x.primitive,
+            grad.primitive,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+
+        match AvgPool2DBackward
+            .prepare::<C>([grad.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                prep.finish((kernel_size, stride, padding, count_include_pad), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
     }
 
     fn max_pool1d(
@@ -1412,27 +1445,6 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         }
     }
 
-    fn max_pool1d_with_indices_backward(
-        x: AutodiffTensor<B>,
-        kernel_size: usize,
-        stride: usize,
-        padding: usize,
-        dilation: usize,
-        output_grad: AutodiffTensor<B>,
-        indices: IntTensor<B>,
-    ) -> MaxPool1dBackward<Self> {
-        let output = B::max_pool1d_with_indices_backward(
-            x.primitive,
-            kernel_size,
-            stride,
-            padding,
-            dilation,
-            output_grad.primitive,
-            indices,
-        );
-        MaxPool1dBackward::new(AutodiffTensor::new(output.x_grad))
-    }
-
     fn max_pool2d(
         x: AutodiffTensor<B>,
         kernel_size: [usize; 2],
@@ -1514,16 +1526,72 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     }
 
     fn max_pool2d_with_indices_backward(
-        _x: AutodiffTensor<B>,
-        _kernel_size: [usize; 2],
-        _stride: [usize; 2],
-        _padding: [usize; 2],
-        _dilation: [usize; 2],
-        _output_grad: AutodiffTensor<B>,
-        _indices: IntTensor<B>,
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: AutodiffTensor<B>,
+        indices: IntTensor<B>,
     ) -> MaxPool2dBackward<Self> {
-        panic!("Can't differentiate max pool2d with indices backward.");
+        #[derive(Debug)]
+        struct MaxPool2DBackward;
+
+        impl<B: Backend> Backward<B, 1> for MaxPool2DBackward {
+            type State = IntTensor<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let indices = ops.state;
+
+                // The backward pass scatters the output gradient to the selected indices, its
+                // transpose gathers the values at the same indices.
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let [batch_size, channels, height, width] = grad.shape().dims();
+                    let [_, _, height_out, width_out] = indices.shape().dims();
+
+                    let grad =
+                        B::float_reshape(grad, Shape::new([batch_size, channels, height * width]));
+                    let indices = B::int_reshape(
+                        indices,
+                        Shape::new([batch_size, channels, height_out * width_out]),
+                    );
+                    let output = B::float_gather(2, grad, indices);
+
+                    B::float_reshape(
+                        output,
+                        Shape::new([batch_size, channels, height_out, width_out]),
+                    )
+                });
+            }
+        }
+
+        let output = B::max_pool2d_with_indices_backward(
+            x.primitive,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad.primitive,
+            indices.clone(),
+        );
+
+        let x_grad = match MaxPool2DBackward
+            .prepare::<C>([output_grad.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(indices, output.x_grad),
+            OpsKind::UnTracked(prep) => prep.finish(output.x_grad),
+        };
+
+        MaxPool2dBackward::new(x_grad)
     }
+
     fn adaptive_avg_pool1d(x: AutodiffTensor<B>, output_size: usize) -> AutodiffTensor<B> {
         #[derive(Debug)]
         struct AdaptiveAvgPool1D;
@@ -1603,10 +1671,42 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     }
 
     fn adaptive_avg_pool2d_backward(
-        _x: AutodiffTensor<B>,
-        _grad: AutodiffTensor<B>,
+        x: AutodiffTensor<B>,
+        grad: AutodiffTensor<B>,
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive {
-        panic!("Can't differentiate adaptive avg pool2d backward.");
+        #[derive(Debug)]
+        struct AdaptiveAvgPool2DBackward;
+
+        impl<B: Backend> Backward<B, 1> for AdaptiveAvgPool2DBackward {
+            type State = [usize; 2];
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let output_size = ops.state;
+
+                // The backward pass is linear in the output gradient, its transpose being the
+                // forward pass.
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::adaptive_avg_pool2d(grad, output_size)
+                });
+            }
+        }
+
+        let [_, _, height_out, width_out] = grad.primitive.shape().dims();
+        let output = B::adaptive_avg_pool2d_backward(x.primitive, grad.primitive);
+
+        match AdaptiveAvgPool2DBackward
+            .prepare::<C>([grad.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish([height_out, width_out], output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
     }
 
     fn interpolate(
@@ -1655,12 +1755,104 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     }
 
     fn interpolate_backward(
-        _x: FloatTensor<Autodiff<B, C>>,
-        _grad: FloatTensor<Autodiff<B, C>>,
-        _output_size: [usize; 2],
-        _options: InterpolateOptions,
+        x: FloatTensor<Autodiff<B, C>>,
+        grad: FloatTensor<Autodiff<B, C>>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive {
-        panic!("Can't differentiate interpolate backward.");
+        #[derive(Debug)]
+        struct InterpolateBackward;
+
+        impl<B: Backend> Backward<B, 1> for InterpolateBackward {
+            type State = ([usize; 2], InterpolateOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (output_size, options) = ops.state;
+
+                // The backward pass is linear in the output gradient, its transpose being the
+                // forward pass.
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::interpolate(grad, output_size, options)
+                });
+            }
+        }
+
+        let output =
+            B::interpolate_backward(x.primitive, grad.primitive, output_size, options.clone());
+
+        match InterpolateBackward
+            .prepare::<C>([grad.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((output_size, options), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn grid_sample(
+        x: AutodiffTensor<B>,
+        grid: AutodiffTensor<B>,
+        options: GridSampleOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct GridSample;
+        impl<B: Backend> Backward<B, 2> for GridSample {
+            type State = (NodeID, NodeID, GridSampleOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_grid] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, grid_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let grid = checkpointer.retrieve_node_output(grid_state);
+
+                let backward = B::grid_sample_backward(x, grid, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B>(node.id, backward.x_grad);
+                }
+                if let Some(node) = node_grid {
+                    grads.register::<B>(node.id, backward.grid_grad);
+                }
+            }
+        }
+
+        match GridSample
+            .prepare::<C>([x.node.clone(), grid.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let grid_state = prep.checkpoint(&grid);
+                let output = B::grid_sample(x.primitive.clone(), grid.primitive.clone(), options);
+                prep.finish((x_state, grid_state, options), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::grid_sample(x.primitive, grid.primitive, options))
+            }
+        }
+    }
+
+    fn grid_sample_backward(
+        _x: FloatTensor<Autodiff<B, C>>,
+        _grid: FloatTensor<Autodiff<B, C>>,
+        _output_grad: FloatTensor<Autodiff<B, C>>,
+        _options: GridSampleOptions,
+    ) -> GridSampleBackward<Autodiff<B, C>> {
+        panic!("Can't differentiate grid sample backward.");
     }
 }
 
@@ -1,13 +1,19 @@
 use core::ops::Range;
 
 use burn_tensor::{
-    Device, Shape, TensorData,
+    Device, ElementConversion, Shape, TensorData, TensorMetadata,
     backend::Backend,
     ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{QuantScheme, QuantizationParametersPrimitive},
 };
 
-use crate::{Autodiff, checkpoint::strategy::CheckpointStrategy};
+use crate::{
+    Autodiff,
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    graph::NodeID,
+    ops::{Backward, Ops, OpsKind, broadcast_shape},
+};
 
 impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
     fn q_from_data(_data: TensorData, _device: &Device<Self>) -> QuantizedTensor<Self> {
@@ -33,6 +39,133 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
         todo!()
     }
 
+    fn fake_quantize(
+        tensor: FloatTensor<Self>,
+        scheme: &QuantScheme,
+        scales: FloatTensor<Self>,
+        offsets: Option<FloatTensor<Self>>,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct FakeQuantizeWithOffsets;
+        #[derive(Debug)]
+        struct FakeQuantizeNoOffsets;
+
+        impl<B: Backend> Backward<B, 3> for FakeQuantizeWithOffsets {
+            type State = (NodeID, NodeID, NodeID, (i32, i32));
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_scales, node_offsets] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, scales_state, offsets_state, range) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let scales = checkpointer.retrieve_node_output(scales_state);
+                let offsets = checkpointer.retrieve_node_output(offsets_state);
+
+                let (grad_x, grad_scales, grad_offsets) =
+                    fake_quantize_backward::<B>(grad, x, scales, Some(offsets), range);
+
+                if let Some(node) = node_x {
+                    grads.register::<B>(node.id, grad_x)
+                }
+                if let Some(node) = node_scales {
+                    grads.register::<B>(node.id, grad_scales)
+                }
+                if let (Some(node), Some(grad)) = (node_offsets, grad_offsets) {
+                    grads.register::<B>(node.id, grad)
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 2> for FakeQuantizeNoOffsets {
+            type State = (NodeID, NodeID, (i32, i32));
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_scales] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, scales_state, range) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let scales = checkpointer.retrieve_node_output(scales_state);
+
+                let (grad_x, grad_scales, _) =
+                    fake_quantize_backward::<B>(grad, x, scales, None, range);
+
+                if let Some(node) = node_x {
+                    grads.register::<B>(node.id, grad_x)
+                }
+                if let Some(node) = node_scales {
+                    grads.register::<B>(node.id, grad_scales)
+                }
+            }
+        }
+
+        let range = scheme.q_type.range(scheme.mode);
+
+        match offsets {
+            Some(offsets) => match FakeQuantizeWithOffsets
+                .prepare::<C>([
+                    tensor.node.clone(),
+                    scales.node.clone(),
+                    offsets.node.clone(),
+                ])
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&tensor);
+                    let scales_state = prep.checkpoint(&scales);
+                    let offsets_state = prep.checkpoint(&offsets);
+                    prep.finish(
+                        (x_state, scales_state, offsets_state, range),
+                        B::fake_quantize(
+                            tensor.primitive,
+                            scheme,
+                            scales.primitive,
+                            Some(offsets.primitive),
+                        ),
+                    )
+                }
+                OpsKind::UnTracked(prep) => prep.finish(B::fake_quantize(
+                    tensor.primitive,
+                    scheme,
+                    scales.primitive,
+                    Some(offsets.primitive),
+                )),
+            },
+            None => match FakeQuantizeNoOffsets
+                .prepare::<C>([tensor.node.clone(), scales.node.clone()])
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&tensor);
+                    let scales_state = prep.checkpoint(&scales);
+                    prep.finish(
+                        (x_state, scales_state, range),
+                        B::fake_quantize(tensor.primitive, scheme, scales.primitive, None),
+                    )
+                }
+                OpsKind::UnTracked(prep) => prep.finish(B::fake_quantize(
+                    tensor.primitive,
+                    scheme,
+                    scales.primitive,
+                    None,
+                )),
+            },
+        }
+    }
+
     fn q_device(tensor: &QuantizedTensor<Self>) -> Device<Self> {
         B::q_device(tensor)
     }
@@ -100,3 +233,56 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
         unimplemented!()
     }
 }
+
+/// Straight-through estimator of the fake quantization gradients, with learnable scales and
+/// offsets (LSQ).
+///
+/// The rounding is treated as the identity, so the gradient of the input is passed through for
+/// values within the quantized range and is zero for clamped values. The gradients of the scales
+/// and offsets are reduced to their broadcast shapes.
+fn fake_quantize_backward<B: Backend>(
+    grad: FloatTensor<B>,
+    x: FloatTensor<B>,
+    scales: FloatTensor<B>,
+    offsets: Option<FloatTensor<B>>,
+    (a, b): (i32, i32),
+) -> (FloatTensor<B>, FloatTensor<B>, Option<FloatTensor<B>>) {
+    let shape_scales = scales.shape();
+
+    let values = B::float_div(x, scales.clone());
+    let values = match &offsets {
+        Some(offsets) => B::float_add(values, offsets.clone()),
+        None => values,
+    };
+    let rounded = B::float_round(values.clone());
+    let clamped = B::bool_or(
+        B::float_lower_elem(rounded.clone(), a.elem()),
+        B::float_greater_elem(rounded.clone(), b.elem()),
+    );
+    let quantized = B::float_clamp(rounded, a.elem(), b.elem());
+
+    let grad_x = B::float_mask_fill(grad.clone(), clamped.clone(), 0.elem());
+
+    // d(out)/d(scale) is `round(v) - v` within the range, and `q - offset` for clamped values
+    // where `v = x / scale + offset` and `q` is the clamped quantized value.
+    let dequantized = match &offsets {
+        Some(offsets) => B::float_sub(quantized.clone(), offsets.clone()),
+        None => quantized.clone(),
+    };
+    let grad_scales = B::float_mask_where(
+        B::float_sub(quantized, values),
+        clamped.clone(),
+        dequantized,
+    );
+    let grad_scales = B::float_mul(grad.clone(), grad_scales);
+    let grad_scales = broadcast_shape::<B>(grad_scales, &shape_scales);
+
+    // d(out)/d(offset) is zero within the range and `-scale` for clamped values.
+    let grad_offsets = offsets.map(|offsets| {
+        let grad = B::float_mul(grad, B::float_neg(scales));
+        let grad = B::float_mask_fill(grad, B::bool_not(clamped), 0.elem());
+        broadcast_shape::<B>(grad, &offsets.shape())
+    });
+
+    (grad_x, grad_scales, grad_offsets)
+}
@@ -25,6 +25,7 @@ use burn_tensor::{
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
 };
 
+use super::cumulative::CumMaxMin;
 use super::maxmin::MaxMinDim;
 
 // Unsqueeze op on primitive.
@@ -1130,6 +1131,147 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_gather_nd(tensor: FloatTensor<Self>, indices: IntTensor<B>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct GatherNd;
+
+        #[derive(new, Debug)]
+        struct RetroGatherNd<B: Backend> {
+            input_id: NodeID,
+            indices: IntTensor<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroGatherNd<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let input = states.get_state::<B::FloatTensorPrimitive>(&self.input_id);
+                let out = B::float_gather_nd(input, self.indices.clone());
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for GatherNd {
+            type State = (IntTensor<B>, Shape, B::Device);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (indices, shape, device) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let zeros = B::float_zeros(shape, &device);
+                    B::float_scatter_nd(zeros, indices, grad, true)
+                });
+            }
+        }
+
+        match GatherNd
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroGatherNd::<B>::new(tensor.node.id, indices.clone()))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    indices.clone(),
+                    tensor.primitive.shape(),
+                    B::float_device(&tensor.primitive),
+                ),
+                B::float_gather_nd(tensor.primitive, indices),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_gather_nd(tensor.primitive, indices)),
+        }
+    }
+
+    fn float_scatter_nd(
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<B>,
+        values: FloatTensor<Self>,
+        accumulate: bool,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct ScatterNd;
+
+        #[derive(new, Debug)]
+        struct RetroScatterNd<B: Backend> {
+            tensor_id: NodeID,
+            indices: IntTensor<B>,
+            values_id: NodeID,
+            accumulate: bool,
+        }
+
+        impl<B: Backend> RetroForward for RetroScatterNd<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let tensor = states.get_state::<B::FloatTensorPrimitive>(&self.tensor_id);
+                let values = states.get_state::<B::FloatTensorPrimitive>(&self.values_id);
+                let out =
+                    B::float_scatter_nd(tensor, self.indices.clone(), values, self.accumulate);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 2> for ScatterNd {
+            type State = (IntTensor<B>, Shape, B::Device, bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (indices, shape_values, device, accumulate) = ops.state;
+                let indices_values = indices.clone();
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| match accumulate {
+                        true => grad,
+                        // The replaced slices don't contribute to the output.
+                        false => {
+                            let zeros = B::float_zeros(shape_values, &device);
+                            B::float_scatter_nd(grad, indices, zeros, false)
+                        }
+                    },
+                    |grad| B::float_gather_nd(grad, indices_values),
+                );
+            }
+        }
+
+        match ScatterNd
+            .prepare::<C>([tensor.node.clone(), values.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroScatterNd::<B>::new(
+                tensor.node.id,
+                indices.clone(),
+                values.node.id,
+                accumulate,
+            ))
+            .parents([&tensor, &values])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    indices.clone(),
+                    values.primitive.shape(),
+                    B::float_device(&values.primitive),
+                    accumulate,
+                ),
+                B::float_scatter_nd(tensor.primitive, indices, values.primitive, accumulate),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_scatter_nd(
+                tensor.primitive,
+                indices,
+                values.primitive,
+                accumulate,
+            )),
+        }
+    }
+
     fn float_slice(
         tensor: FloatTensor<Self>,
         ranges: &[core::ops::Range<usize>],
@@ -1410,7 +1552,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         // When we detach a tensor, we remove it from the graph, but we still want to keep the
         // `require_grad` setting.
         let is_require_grad = Self::float_is_require_grad(&tensor);
-        let tensor = AutodiffTensor::new(tensor.primitive);
+        let tensor = AutodiffTensor::new(B::float_detach(tensor.primitive));
 
         match is_require_grad {
             true => tensor.require_grad(),
@@ -1569,6 +1711,275 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct CumSum;
+
+        impl<B: Backend> Backward<B, 1> for CumSum {
+            type State = usize;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let dim = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // Each input contributes to every output at or after its position.
+                    let grad = B::float_flip(grad, &[dim]);
+                    let grad = B::float_cumsum(grad, dim);
+                    B::float_flip(grad, &[dim])
+                });
+            }
+        }
+
+        match CumSum
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::float_cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct CumProd;
+
+        impl<B: Backend> Backward<B, 1> for CumProd {
+            type State = (FloatTensor<B>, FloatTensor<B>, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, output, dim) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let reverse_cumsum = |tensor| {
+                        let tensor = B::float_flip(tensor, &[dim]);
+                        let tensor = B::float_cumsum(tensor, dim);
+                        B::float_flip(tensor, &[dim])
+                    };
+
+                    // d(out_i)/d(x_j) = out_i / x_j for i >= j is only defined before the first
+                    // zero of the input, like in PyTorch. The gradient of the first zero skips it
+                    // in the products, and the gradients after it are zero.
+                    let is_zero = B::float_equal_elem(input.clone(), 0.elem());
+                    let num_zeros = B::int_cumsum(B::bool_into_int(is_zero.clone()), dim);
+                    let after_zero = B::bool_not(B::int_equal_elem(num_zeros.clone(), 0.elem()));
+                    let first_zero = B::bool_and(is_zero, B::int_equal_elem(num_zeros, 1.elem()));
+
+                    let divisor = B::float_mask_fill(input.clone(), after_zero.clone(), 1.elem());
+                    let before_zero =
+                        B::float_div(reverse_cumsum(B::float_mul(grad.clone(), output)), divisor);
+
+                    let skipped = B::float_mask_fill(input, first_zero.clone(), 1.elem());
+                    let skipped = B::float_cumprod(skipped, dim);
+                    let at_zero = reverse_cumsum(B::float_mul(grad, skipped));
+
+                    let grad = B::float_mask_fill(before_zero, after_zero, 0.elem());
+                    B::float_mask_where(grad, first_zero, at_zero)
+                });
+            }
+        }
+
+        match CumProd
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_cumprod(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumprod(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize) -> (FloatTensor<Self>, IntTensor<B>) {
+        match CumMaxMin
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = tensor.primitive.shape();
+                let (tensor, index) = B::float_cummax(tensor.primitive, dim);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
+
+                (tensor, index)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, index) = B::float_cummax(tensor.primitive, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, index)
+            }
+        }
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize) -> (FloatTensor<Self>, IntTensor<B>) {
+        match CumMaxMin
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = tensor.primitive.shape();
+                let (tensor, index) = B::float_cummin(tensor.primitive, dim);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
+
+                (tensor, index)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, index) = B::float_cummin(tensor.primitive, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, index)
+            }
+        }
+    }
+
+    fn float_logcumsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct LogCumSumExp;
+
+        impl<B: Backend> Backward<B, 1> for LogCumSumExp {
+            type State = (FloatTensor<B>, FloatTensor<B>, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, output, dim) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // grad_j = sum_{i >= j} grad_i * exp(x_j - out_i), computed in log space with
+                    // the positive and negative parts of the gradient handled separately.
+                    let reverse_logcumsumexp = |tensor| {
+                        let tensor = B::float_flip(tensor, &[dim]);
+                        let tensor = B::float_logcumsumexp(tensor, dim);
+                        B::float_flip(tensor, &[dim])
+                    };
+                    let zero = 0.elem();
+                    let grad_pos = B::float_log(B::float_clamp_min(grad.clone(), zero));
+                    let grad_neg = B::float_log(B::float_clamp_min(B::float_neg(grad), zero));
+
+                    let grad_pos = reverse_logcumsumexp(B::float_sub(grad_pos, output.clone()));
+                    let grad_neg = reverse_logcumsumexp(B::float_sub(grad_neg, output));
+
+                    B::float_sub(
+                        B::float_exp(B::float_add(input.clone(), grad_pos)),
+                        B::float_exp(B::float_add(input, grad_neg)),
+                    )
+                });
+            }
+        }
+
+        match LogCumSumExp
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_logcumsumexp(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_logcumsumexp(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct LogSumExp;
+
+        impl<B: Backend> Backward<B, 1> for LogSumExp {
+            type State = (FloatTensor<B>, FloatTensor<B>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, output) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // grad_x = grad * exp(x - out), the softmax of the input along `dim`. When the
+                    // elements are all -inf, the output is replaced by zero so that the gradient is
+                    // zero instead of NaN.
+                    let empty = B::float_equal_elem(output.clone(), f32::NEG_INFINITY.elem());
+                    let output = B::float_mask_fill(output, empty, 0.elem());
+                    let softmax = B::float_exp(B::float_sub(input, output));
+
+                    B::float_mul(softmax, grad)
+                });
+            }
+        }
+
+        match LogSumExp
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_logsumexp(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_logsumexp(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_sum_masked_dim(
+        tensor: FloatTensor<Self>,
+        mask: BoolTensor<B>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct SumMaskedDim;
+
+        impl<B: Backend> Backward<B, 1> for SumMaskedDim {
+            type State = BoolTensor<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let mask = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad = B::float_expand(grad, mask.shape());
+
+                    B::float_mask_fill(grad, B::bool_not(mask), 0.elem())
+                });
+            }
+        }
+
+        match SumMaskedDim
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                mask.clone(),
+                B::float_sum_masked_dim(tensor.primitive, mask, dim),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_sum_masked_dim(tensor.primitive, mask, dim))
+            }
+        }
+    }
+
     fn float_argmax(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<B> {
         B::float_argmax(tensor.primitive, dim)
     }
@@ -2094,8 +2505,794 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
-    fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
-        #[derive(new, Debug)]
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Asin;
+
+        retro_unary!(RetroAsin, B::float_asin);
+
+        impl<B: Backend> Backward<B, 1> for Asin {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // 1 / sqrt(1 - x^2)
+                    let value = B::float_sqrt(B::float_add_scalar(
+                        B::float_neg(B::float_powi_scalar(input, 2.elem())),
+                        1.elem(),
+                    ));
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Asin
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAsin::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_asin(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_asin(tensor.primitive)),
+        }
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Acos;
+
+        retro_unary!(RetroAcos, B::float_acos);
+
+        impl<B: Backend> Backward<B, 1> for Acos {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // -1 / sqrt(1 - x^2)
+                    let value = B::float_sqrt(B::float_add_scalar(
+                        B::float_neg(B::float_powi_scalar(input, 2.elem())),
+                        1.elem(),
+                    ));
+                    B::float_neg(B::float_div(grad, value))
+                });
+            }
+        }
+
+        match Acos
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAcos::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_acos(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_acos(tensor.primitive)),
+        }
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Atan;
+
+        retro_unary!(RetroAtan, B::float_atan);
+
+        impl<B: Backend> Backward<B, 1> for Atan {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // 1 / (1 + x^2)
+                    let value =
+                        B::float_add_scalar(B::float_powi_scalar(input, 2.elem()), 1.elem());
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Atan
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtan::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_atan(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atan(tensor.primitive)),
+        }
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Atan2;
+
+        retro_binary!(RetroAtan2, B::float_atan2);
+
+        impl<B: Backend> Backward<B, 2> for Atan2 {
+            type State = (NodeID, NodeID, BinaryOpsBroadcast);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs_id, rhs_id, broadcast) = ops.state;
+                let lhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(lhs_id);
+                let rhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(rhs_id);
+                let denominator = B::float_add(
+                    B::float_powi_scalar(lhs.clone(), 2.elem()),
+                    B::float_powi_scalar(rhs.clone(), 2.elem()),
+                );
+
+                // The gradient of y is x and the gradient of x is -y, over x^2 + y^2.
+                let [denominator_4lhs, denominator_4rhs] =
+                    duplicate(&ops.parents, Some(denominator));
+                let [numerator_4lhs, numerator_4rhs] = [Some(rhs), Some(B::float_neg(lhs))];
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // x / (x^2 + y^2)
+                        let value =
+                            B::float_div(numerator_4lhs.unwrap(), denominator_4lhs.unwrap());
+                        broadcast.backward_lhs::<B>(B::float_mul(grad, value))
+                    },
+                    |grad| {
+                        // -y / (x^2 + y^2)
+                        let value =
+                            B::float_div(numerator_4rhs.unwrap(), denominator_4rhs.unwrap());
+                        broadcast.backward_rhs::<B>(B::float_mul(grad, value))
+                    },
+                );
+            }
+        }
+
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Atan2
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtan2::<B>::new(lhs.node.id, rhs.node.id))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let lhs_state = prep.checkpoint(&lhs);
+                let rhs_state = prep.checkpoint(&rhs);
+                prep.finish(
+                    (lhs_state, rhs_state, broadcast),
+                    B::float_atan2(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atan2(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Asinh;
+
+        retro_unary!(RetroAsinh, B::float_asinh);
+
+        impl<B: Backend> Backward<B, 1> for Asinh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // 1 / sqrt(x^2 + 1)
+                    let value = B::float_sqrt(B::float_add_scalar(
+                        B::float_powi_scalar(input, 2.elem()),
+                        1.elem(),
+                    ));
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Asinh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAsinh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_asinh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_asinh(tensor.primitive)),
+        }
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Acosh;
+
+        retro_unary!(RetroAcosh, B::float_acosh);
+
+        impl<B: Backend> Backward<B, 1> for Acosh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // 1 / sqrt(x^2 - 1)
+                    let value = B::float_sqrt(B::float_sub_scalar(
+                        B::float_powi_scalar(input, 2.elem()),
+                        1.elem(),
+                    ));
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Acosh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAcosh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_acosh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_acosh(tensor.primitive)),
+        }
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Atanh;
+
+        retro_unary!(RetroAtanh, B::float_atanh);
+
+        impl<B: Backend> Backward<B, 1> for Atanh {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // 1 / (1 - x^2)
+                    let value = B::float_add_scalar(
+                        B::float_neg(B::float_powi_scalar(input, 2.elem())),
+                        1.elem(),
+                    );
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Atanh
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroAtanh::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_atanh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_atanh(tensor.primitive)),
+        }
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Erfinv;
+
+        retro_unary!(RetroErfinv, B::float_erfinv);
+
+        impl<B: Backend> Backward<B, 1> for Erfinv {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                let output = B::float_erfinv(input);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // sqrt(pi) / 2 * exp(erfinv(x)^2)
+                    let value = B::float_exp(B::float_powi_scalar(output, 2.elem()));
+                    let value =
+                        B::float_mul_scalar(value, (core::f64::consts::PI.sqrt() / 2.0).elem());
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Erfinv
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroErfinv::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_erfinv(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfinv(tensor.primitive)),
+        }
+    }
+
+    fn float_erfc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Erfc;
+
+        retro_unary!(RetroErfc, B::float_erfc);
+
+        impl<B: Backend> Backward<B, 1> for Erfc {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // -2 / sqrt(pi) * exp(-x^2)
+                    let value = B::float_exp(B::float_neg(B::float_powi_scalar(input, 2.elem())));
+                    let value =
+                        B::float_mul_scalar(value, (-core::f64::consts::FRAC_2_SQRT_PI).elem());
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Erfc
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroErfc::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_erfc(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfc(tensor.primitive)),
+        }
+    }
+
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Expm1;
+
+        retro_unary!(RetroExpm1, B::float_expm1);
+
+        impl<B: Backend> Backward<B, 1> for Expm1 {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_exp(input))
+                });
+            }
+        }
+
+        match Expm1
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroExpm1::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_expm1(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_expm1(tensor.primitive)),
+        }
+    }
+
+    fn float_log2(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Log2;
+
+        retro_unary!(RetroLog2, B::float_log2);
+
+        impl<B: Backend> Backward<B, 1> for Log2 {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // 1 / (x ln(2))
+                    let value = B::float_mul_scalar(input, core::f64::consts::LN_2.elem());
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Log2
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroLog2::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_log2(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_log2(tensor.primitive)),
+        }
+    }
+
+    fn float_log10(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Log10;
+
+        retro_unary!(RetroLog10, B::float_log10);
+
+        impl<B: Backend> Backward<B, 1> for Log10 {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // 1 / (x ln(10))
+                    let value = B::float_mul_scalar(input, core::f64::consts::LN_10.elem());
+                    B::float_div(grad, value)
+                });
+            }
+        }
+
+        match Log10
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroLog10::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_log10(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_log10(tensor.primitive)),
+        }
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Hypot;
+
+        retro_binary!(RetroHypot, B::float_hypot);
+
+        impl<B: Backend> Backward<B, 2> for Hypot {
+            type State = (NodeID, NodeID, BinaryOpsBroadcast);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs_id, rhs_id, broadcast) = ops.state;
+                let lhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(lhs_id);
+                let rhs: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(rhs_id);
+                let denominator = B::float_hypot(lhs.clone(), rhs.clone());
+
+                let [denominator_4lhs, denominator_4rhs] =
+                    duplicate(&ops.parents, Some(denominator));
+                let [numerator_4lhs, numerator_4rhs] = [Some(lhs), Some(rhs)];
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // x / hypot(x, y)
+                        let value =
+                            B::float_div(numerator_4lhs.unwrap(), denominator_4lhs.unwrap());
+                        broadcast.backward_lhs::<B>(B::float_mul(grad, value))
+                    },
+                    |grad| {
+                        // y / hypot(x, y)
+                        let value =
+                            B::float_div(numerator_4rhs.unwrap(), denominator_4rhs.unwrap());
+                        broadcast.backward_rhs::<B>(B::float_mul(grad, value))
+                    },
+                );
+            }
+        }
+
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Hypot
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroHypot::<B>::new(lhs.node.id, rhs.node.id))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let lhs_state = prep.checkpoint(&lhs);
+                let rhs_state = prep.checkpoint(&rhs);
+                prep.finish(
+                    (lhs_state, rhs_state, broadcast),
+                    B::float_hypot(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_hypot(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Lgamma;
+
+        retro_unary!(RetroLgamma, B::float_lgamma);
+
+        impl<B: Backend> Backward<B, 1> for Lgamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_digamma(input))
+                });
+            }
+        }
+
+        match Lgamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroLgamma::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_lgamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_lgamma(tensor.primitive)),
+        }
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Digamma;
+
+        retro_unary!(RetroDigamma, B::float_digamma);
+
+        impl<B: Backend> Backward<B, 1> for Digamma {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_polygamma(input, 1))
+                });
+            }
+        }
+
+        match Digamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroDigamma::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_digamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_digamma(tensor.primitive)),
+        }
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Self>, n: u32) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Polygamma;
+
+        #[derive(new, Debug)]
+        struct RetroPolygamma<B: Backend> {
+            input_id: NodeID,
+            n: u32,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroPolygamma<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let input = states.get_state::<B::FloatTensorPrimitive>(&self.input_id);
+                let out = B::float_polygamma(input, self.n);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for Polygamma {
+            type State = (NodeID, u32);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (input_id, n) = ops.state;
+                let input = checkpointer.retrieve_node_output(input_id);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_polygamma(input, n + 1))
+                });
+            }
+        }
+
+        match Polygamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroPolygamma::<B>::new(tensor.node.id, n))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish((state, n), B::float_polygamma(tensor.primitive, n))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_polygamma(tensor.primitive, n)),
+        }
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct I0;
+
+        retro_unary!(RetroI0, B::float_i0);
+
+        impl<B: Backend> Backward<B, 1> for I0 {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_i1(input))
+                });
+            }
+        }
+
+        match I0
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroI0::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_i0(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_i0(tensor.primitive)),
+        }
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct I1;
+
+        retro_unary!(RetroI1, B::float_i1);
+
+        impl<B: Backend> Backward<B, 1> for I1 {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(ops.state);
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // i0(x) - i1(x) / x, which tends to 1/2 at zero
+                    let is_zero = B::float_equal_elem(input.clone(), 0.elem());
+                    let ratio = B::float_div(B::float_i1(input.clone()), input.clone());
+                    let ratio = B::float_mask_fill(ratio, is_zero, 0.5.elem());
+                    let value = B::float_sub(B::float_i0(input), ratio);
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match I1
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroI1::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_i1(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_i1(tensor.primitive)),
+        }
+    }
+
+    fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
+        #[derive(new, Debug)]
         struct CatStep<B: Backend> {
             nodes: Vec<Option<NodeRef>>,
             // The dimension of each tensor along the dim dimension.
@@ -2489,6 +3686,14 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_argsort(tensor.primitive, dim, descending)
     }
 
+    fn float_multinomial(
+        tensor: FloatTensor<Self>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B> {
+        B::float_multinomial(tensor.primitive, num_samples, replacement)
+    }
+
     fn float_repeat_dim(tensor: FloatTensor<Self>, dim: usize, times: usize) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct Repeat;
@@ -1,7 +1,9 @@
+use super::LocalClient;
 use crate::{
+    NodeID,
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{GradHookBoxed, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
 };
 use burn_tensor::backend::Backend;
@@ -10,6 +12,10 @@ use burn_tensor::backend::Backend;
 pub trait AutodiffClient: Send + Clone {
     /// Register a new step.
     fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder);
+    /// Register a hook on the gradient of a node.
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed);
+    /// Keep the gradient of a node after the backward pass.
+    fn retain_grad(&self, node_id: NodeID);
     /// Call backpropagation from the given tensor.
     fn backward<B: Backend>(&self, tensor: AutodiffTensor<B>) -> Gradients;
 }
@@ -21,3 +27,45 @@ pub type AutodiffClientImpl = super::mspc::ChannelClient;
 /// Client implementation in used.
 #[cfg(not(feature = "async"))]
 pub type AutodiffClientImpl = super::mutex::MutexClient;
+
+/// Client of the graph a node belongs to.
+#[derive(Debug, Clone)]
+pub enum GraphClient {
+    /// The graph shared by the tensors of the process.
+    Global(AutodiffClientImpl),
+    /// A graph local to a backward step.
+    Local(LocalClient),
+}
+
+impl Default for GraphClient {
+    fn default() -> Self {
+        Self::Global(AutodiffClientImpl::new())
+    }
+}
+
+impl AutodiffClient for GraphClient {
+    fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder) {
+        match self {
+            Self::Global(client) => client.register(node_id, step, actions),
+            Self::Local(client) => client.register(node_id, step, actions),
+        }
+    }
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed) {
+        match self {
+            Self::Global(client) => client.register_hook(node_id, hook),
+            Self::Local(client) => client.register_hook(node_id, hook),
+        }
+    }
+    fn retain_grad(&self, node_id: NodeID) {
+        match self {
+            Self::Global(client) => client.retain_grad(node_id),
+            Self::Local(client) => client.retain_grad(node_id),
+        }
+    }
+    fn backward<B: Backend>(&self, tensor: AutodiffTensor<B>) -> Gradients {
+        match self {
+            Self::Global(client) => client.backward(tensor),
+            Self::Local(client) => client.backward(tensor),
+        }
+    }
+}
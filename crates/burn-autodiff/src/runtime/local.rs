@@ -0,0 +1,117 @@
+use super::{AutodiffClient, server::AutodiffServer};
+use crate::{
+    NodeID,
+    checkpoint::{
+        builder::CheckpointerBuilder,
+        report::{BackwardReport, GraphMemory},
+    },
+    grads::Gradients,
+    graph::{GradHookBoxed, StepBoxed},
+    tensor::{AutodiffTensor, NodeRefCount},
+};
+use alloc::sync::{Arc, Weak};
+use burn_tensor::backend::Backend;
+
+/// A graph built and differentiated during a backward step, e.g. to recompute a checkpointed
+/// segment.
+///
+/// The step is executed by the server of its own graph, which may run on its own thread, so the
+/// local graph has a server of its own instead of going through the client of that graph.
+#[derive(Default)]
+pub struct LocalGraph {
+    server: Arc<spin::Mutex<AutodiffServer>>,
+}
+
+impl LocalGraph {
+    /// Returns a client registering the nodes in the local graph.
+    pub fn client(&self) -> LocalClient {
+        LocalClient {
+            server: Arc::downgrade(&self.server),
+        }
+    }
+
+    /// Builds and executes the tape of the given root on the server of the local graph.
+    pub fn backward<B: Backend>(&self, root: AutodiffTensor<B>) -> Gradients {
+        let graph = root.node.memory.clone();
+
+        self.execute(root, |report| {
+            if let Some(graph) = graph {
+                report.record(&graph);
+            }
+        })
+    }
+
+    /// Builds and executes the tape of the given root, which recomputes a checkpointed segment of
+    /// the given graph.
+    ///
+    /// The backward pass is recorded in the report of the graph of the segment instead of the
+    /// local graph.
+    pub(crate) fn backward_segment<B: Backend>(
+        &self,
+        root: AutodiffTensor<B>,
+        graph: Option<&GraphMemory>,
+    ) -> Gradients {
+        self.execute(root, |report| {
+            if let Some(graph) = graph {
+                report.record_segment(graph);
+            }
+        })
+    }
+
+    fn execute<B: Backend>(
+        &self,
+        root: AutodiffTensor<B>,
+        record: impl FnOnce(BackwardReport),
+    ) -> Gradients {
+        let node_id = root.node.id;
+        let grads = Gradients::new::<B>(root.node, root.primitive);
+
+        // The lock is released while the steps are executed, since they can register new nodes
+        // in the local graph, e.g. when recomputing a nested segment.
+        let tape = self.server.lock().tape(node_id);
+        record(tape.report());
+        let gradients = AutodiffServer::execute_steps(tape, grads);
+        self.server.lock().cleanup();
+
+        gradients
+    }
+}
+
+/// Client of a [local graph](LocalGraph).
+///
+/// The server keeps the steps of the nodes, so the nodes only keep a weak reference to it. The
+/// nodes registered once the local graph is dropped are ignored.
+#[derive(Clone)]
+pub struct LocalClient {
+    server: Weak<spin::Mutex<AutodiffServer>>,
+}
+
+impl core::fmt::Debug for LocalClient {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("LocalClient")
+    }
+}
+
+impl AutodiffClient for LocalClient {
+    fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder) {
+        if let Some(server) = self.server.upgrade() {
+            server.lock().register(node_id, step, actions);
+        }
+    }
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed) {
+        if let Some(server) = self.server.upgrade() {
+            server.lock().register_hook(node_id, hook);
+        }
+    }
+    fn retain_grad(&self, node_id: NodeID) {
+        if let Some(server) = self.server.upgrade() {
+            server.lock().retain_grad(node_id);
+        }
+    }
+    fn backward<B: Backend>(&self, root: AutodiffTensor<B>) -> Gradients {
+        match self.server.upgrade() {
+            Some(server) => LocalGraph { server }.backward(root),
+            None => panic!("Can't compute the gradients of a local graph that was dropped"),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 mod client;
+mod local;
 mod memory_management;
 mod server;
 
@@ -9,3 +10,4 @@ pub mod mutex;
 pub mod mspc;
 
 pub use client::*;
+pub use local::*;
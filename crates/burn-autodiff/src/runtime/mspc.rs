@@ -1,11 +1,12 @@
 use super::{AutodiffClient, server::AutodiffServer};
 use crate::{
     NodeID,
-    checkpoint::builder::CheckpointerBuilder,
+    checkpoint::{builder::CheckpointerBuilder, report::GraphMemory},
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{GradHookBoxed, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
 };
+use alloc::sync::Arc;
 use burn_tensor::backend::Backend;
 use std::sync::mpsc::Sender;
 
@@ -22,8 +23,16 @@ enum Message {
         step: StepBoxed,
         actions: CheckpointerBuilder,
     },
+    RegisterHook {
+        node_id: NodeID,
+        hook: GradHookBoxed,
+    },
+    RetainGrad {
+        node_id: NodeID,
+    },
     Backward {
         node_id: NodeID,
+        graph: Option<Arc<GraphMemory>>,
         grads: Gradients,
         callback: Sender<Gradients>,
     },
@@ -46,12 +55,15 @@ impl ChannelClient {
                         step,
                         actions,
                     } => server.register(node_id, step, actions),
+                    Message::RegisterHook { node_id, hook } => server.register_hook(node_id, hook),
+                    Message::RetainGrad { node_id } => server.retain_grad(node_id),
                     Message::Backward {
                         node_id,
+                        graph,
                         grads,
                         callback,
                     } => {
-                        let grads = server.backward(grads, node_id);
+                        let grads = server.backward(grads, node_id, graph.as_deref());
                         callback.send(grads).unwrap();
                     }
                 }
@@ -73,14 +85,26 @@ impl AutodiffClient for ChannelClient {
             .unwrap()
     }
 
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed) {
+        self.sender
+            .send(Message::RegisterHook { node_id, hook })
+            .unwrap()
+    }
+
+    fn retain_grad(&self, node_id: NodeID) {
+        self.sender.send(Message::RetainGrad { node_id }).unwrap()
+    }
+
     fn backward<B: Backend>(&self, root: AutodiffTensor<B>) -> Gradients {
         let node_id = root.node.id;
+        let graph = root.node.memory.clone();
         let grads = Gradients::new::<B>(root.node, root.primitive);
         let (callback, receiver) = std::sync::mpsc::channel();
 
         self.sender
             .send(Message::Backward {
                 node_id,
+                graph,
                 grads,
                 callback,
             })
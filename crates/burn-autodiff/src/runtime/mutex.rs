@@ -1,8 +1,9 @@
 use super::{AutodiffClient, server::AutodiffServer};
 use crate::{
+    NodeID,
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{GradHookBoxed, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
 };
 use burn_tensor::backend::Backend;
@@ -31,18 +32,38 @@ impl AutodiffClient for MutexClient {
         server_new.register(node_id, step, actions);
         *server = Some(server_new);
     }
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed) {
+        SERVER
+            .lock()
+            .get_or_insert_with(AutodiffServer::default)
+            .register_hook(node_id, hook);
+    }
+    fn retain_grad(&self, node_id: NodeID) {
+        SERVER
+            .lock()
+            .get_or_insert_with(AutodiffServer::default)
+            .retain_grad(node_id);
+    }
     fn backward<B: Backend>(&self, root: AutodiffTensor<B>) -> Gradients {
-        let mut server = SERVER.lock();
         let node_id = root.node.id;
+        let graph = root.node.memory.clone();
         let grads = Gradients::new::<B>(root.node, root.primitive);
 
-        if let Some(server) = server.as_mut() {
-            return server.backward(grads, node_id);
+        // The lock is released while the steps are executed, since the operations of the backward
+        // pass register new nodes when the inner backend is itself an autodiff backend.
+        let tape = SERVER
+            .lock()
+            .get_or_insert_with(AutodiffServer::default)
+            .tape(node_id);
+        if let Some(graph) = graph {
+            tape.report().record(&graph);
         }
 
-        let mut server_new = AutodiffServer::default();
-        let gradients = server_new.backward(grads, node_id);
-        *server = Some(server_new);
+        let gradients = AutodiffServer::execute_steps(tape, grads);
+
+        if let Some(server) = SERVER.lock().as_mut() {
+            server.cleanup();
+        }
 
         gradients
     }
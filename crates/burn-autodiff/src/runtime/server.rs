@@ -4,10 +4,11 @@ use crate::{
     checkpoint::{
         base::{Checkpointer, NodeTree},
         builder::CheckpointerBuilder,
+        report::{BackwardReport, GraphMemory},
     },
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     grads::Gradients,
-    graph::{StepBoxed, traversal::BreadthFirstSearch},
+    graph::{GradHookBoxed, StepBoxed, traversal::BreadthFirstSearch},
     tensor::NodeRefCount,
 };
 use alloc::vec::Vec;
@@ -16,9 +17,28 @@ use alloc::vec::Vec;
 pub struct AutodiffServer {
     steps: HashMap<NodeID, StepBoxed>,
     actions_builder: HashMap<NodeID, CheckpointerBuilder>,
+    hooks: HashMap<NodeID, Vec<GradHookBoxed>>,
+    retained: HashSet<NodeID>,
     memory_management: GraphMemoryManagement,
 }
 
+/// Everything required to execute a backward pass, removed from the server.
+pub struct Tape {
+    /// The steps, ordered by depth.
+    steps: Vec<Vec<StepBoxed>>,
+    checkpointer: Checkpointer,
+    hooks: HashMap<NodeID, Vec<GradHookBoxed>>,
+    retained: Vec<NodeID>,
+    report: BackwardReport,
+}
+
+impl Tape {
+    /// The statistics of the checkpointer, to be recorded in the graph of the root.
+    pub(crate) fn report(&self) -> BackwardReport {
+        self.report
+    }
+}
+
 impl AutodiffServer {
     pub fn register(&mut self, rc: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder) {
         let parents = step.parents();
@@ -30,25 +50,53 @@ impl AutodiffServer {
         self.actions_builder.insert(node_id, actions);
     }
 
-    pub fn backward(&mut self, grads: Gradients, node_id: NodeID) -> Gradients {
+    /// Registers a hook called with the gradient of the node during the next backward pass.
+    pub fn register_hook(&mut self, node_id: NodeID, hook: GradHookBoxed) {
+        self.hooks.entry(node_id).or_default().push(hook);
+    }
+
+    /// Keeps the gradient of the node in the gradients returned by the next backward pass.
+    pub fn retain_grad(&mut self, node_id: NodeID) {
+        self.retained.insert(node_id);
+    }
+
+    pub fn backward(
+        &mut self,
+        grads: Gradients,
+        node_id: NodeID,
+        graph: Option<&GraphMemory>,
+    ) -> Gradients {
+        let tape = self.tape(node_id);
+        if let Some(graph) = graph {
+            tape.report().record(graph);
+        }
+        let gradients = Self::execute_steps(tape, grads);
+
+        self.cleanup();
+
+        gradients
+    }
+
+    /// Removes the steps required to backpropagate from the given node.
+    pub fn tape(&mut self, node_id: NodeID) -> Tape {
         let step = self.steps.remove(&node_id).expect(
             "Node should have a step registered, did you forget to call \
              `Tensor::register_grad` on the tensor where you need gradients?",
         );
         let builder = self.actions_builder.remove(&node_id).unwrap();
 
-        let (tape, checkpointer) = self.build_tape(node_id, step, builder);
-
-        let gradients = Self::execute_steps(tape, grads, checkpointer);
+        self.build_tape(node_id, step, builder)
+    }
 
-        // Cleanup
+    /// Frees the nodes that can't be used in a backward pass anymore.
+    pub fn cleanup(&mut self) {
         self.memory_management
             .free_unavailable_nodes(|node_id: &NodeID| {
                 self.steps.remove(node_id);
                 self.actions_builder.remove(node_id);
+                self.hooks.remove(node_id);
+                self.retained.remove(node_id);
             });
-
-        gradients
     }
 
     fn build_tape(
@@ -56,16 +104,25 @@ impl AutodiffServer {
         node: NodeID,
         node_step: StepBoxed,
         mut builder: CheckpointerBuilder,
-    ) -> (Vec<Vec<StepBoxed>>, Checkpointer) {
+    ) -> Tape {
         let mut tape = (0..node_step.depth())
             .map(|_| Vec::with_capacity(1))
             .collect::<Vec<_>>();
 
         let mut tree = HashMap::default();
+        let mut hooks = HashMap::default();
+        let mut retained = Vec::new();
 
         BreadthFirstSearch.traverse(node, node_step, &mut self.steps, |id, step| {
             self.memory_management.consume_node(id);
 
+            if let Some(node_hooks) = self.hooks.remove(&id) {
+                hooks.insert(id, node_hooks);
+            }
+            if self.retained.remove(&id) {
+                retained.push(id);
+            }
+
             let depth = step.depth();
 
             if depth == 0 {
@@ -83,22 +140,47 @@ impl AutodiffServer {
             }
         });
 
-        let checkpointer = builder.build(NodeTree::new(tree));
+        let (checkpointer, report) = builder.build(NodeTree::new(tree));
 
-        (tape, checkpointer)
+        Tape {
+            steps: tape,
+            checkpointer,
+            hooks,
+            retained,
+            report,
+        }
     }
 
-    fn execute_steps(
-        tape: Vec<Vec<StepBoxed>>,
-        mut grads: Gradients,
-        mut checkpointer: Checkpointer,
-    ) -> Gradients {
-        tape.into_iter().rev().for_each(|steps| {
-            steps
-                .into_iter()
-                .for_each(|step| step.step(&mut grads, &mut checkpointer))
+    pub fn execute_steps(tape: Tape, mut grads: Gradients) -> Gradients {
+        let Tape {
+            steps,
+            mut checkpointer,
+            mut hooks,
+            retained,
+            report: _,
+        } = tape;
+
+        for node_id in retained {
+            grads.retain(node_id);
+        }
+
+        steps.into_iter().rev().for_each(|steps| {
+            steps.into_iter().for_each(|step| {
+                // All the steps of the children have been executed, so the gradient of the node
+                // is complete when its own step is about to consume it.
+                if let Some(node_hooks) = hooks.remove(&step.node()) {
+                    apply_hooks(step.node(), node_hooks, &mut grads);
+                }
+
+                step.step(&mut grads, &mut checkpointer)
+            })
         });
 
+        // The remaining hooks are on leaves, which don't have a step in the tape.
+        for (node_id, node_hooks) in hooks {
+            apply_hooks(node_id, node_hooks, &mut grads);
+        }
+
         #[cfg(feature = "export_tests")]
         // For checkpointing tests
         assert!(checkpointer.is_empty());
@@ -106,3 +188,9 @@ impl AutodiffServer {
         grads
     }
 }
+
+fn apply_hooks(node_id: NodeID, hooks: Vec<GradHookBoxed>, grads: &mut Gradients) {
+    for hook in hooks {
+        hook.apply(node_id, grads);
+    }
+}
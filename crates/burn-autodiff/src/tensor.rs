@@ -1,8 +1,8 @@
 use crate::{
-    checkpoint::{base::Checkpointer, builder::CheckpointerBuilder},
+    checkpoint::{base::Checkpointer, builder::CheckpointerBuilder, report::GraphMemory},
     grads::Gradients,
-    graph::{ComputingProperty, Node, NodeID, NodeRef, Requirement, Step},
-    runtime::{AutodiffClient, AutodiffClientImpl},
+    graph::{ComputingProperty, FloatGradHook, Node, NodeID, NodeRef, Requirement, Step},
+    runtime::{AutodiffClient, GraphClient},
 };
 use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
 use burn_tensor::{TensorMetadata, backend::Backend};
@@ -52,6 +52,11 @@ impl Step for RootStep {
 impl<B: Backend> AutodiffTensor<B> {
     /// Create a new leaf tensor.
     pub fn new(primitive: B::FloatTensorPrimitive) -> Self {
+        Self::with_client(primitive, GraphClient::default())
+    }
+
+    /// Create a new leaf tensor of the graph of the given client.
+    pub(crate) fn with_client(primitive: B::FloatTensorPrimitive, client: GraphClient) -> Self {
         let id = NodeID::new();
         let node: NodeRef = Node::new(
             vec![],
@@ -59,7 +64,8 @@ impl<B: Backend> AutodiffTensor<B> {
             id,
             Requirement::None,
             ComputingProperty::Ambiguous,
-            AutodiffClientImpl::new(),
+            client,
+            None,
         )
         .into();
 
@@ -86,6 +92,12 @@ impl<B: Backend> AutodiffTensor<B> {
                 panic!("Can't convert a non leaf tensor into a tracked tensor")
             }
             Requirement::None => {
+                // When the inner backend is itself an autodiff backend, the inner tensor is
+                // tracked as well so that the gradients can be differentiated with respect to it.
+                if B::ad_enabled() && !B::float_is_require_grad(&self.primitive) {
+                    self.primitive = B::float_set_require_grad(self.primitive, true);
+                }
+
                 self.node = Node::new(
                     vec![],
                     0,
@@ -93,6 +105,7 @@ impl<B: Backend> AutodiffTensor<B> {
                     Requirement::Grad,
                     self.node.properties.clone(),
                     self.node.client.clone(),
+                    None,
                 )
                 .into();
                 let step = RootStep::new(self.node.clone());
@@ -102,6 +115,43 @@ impl<B: Backend> AutodiffTensor<B> {
         }
     }
 
+    /// Register a hook called with the gradient of the tensor during the next backward pass.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the tensor is not tracked.
+    pub fn register_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(B::FloatTensorPrimitive) -> B::FloatTensorPrimitive + Send + 'static,
+    {
+        if self.node.requirement.is_none() {
+            panic!("Can't register a hook on an untracked tensor")
+        }
+
+        self.node
+            .client
+            .register_hook(self.node.id, Box::new(FloatGradHook::<B, F>::new(hook)));
+        self
+    }
+
+    /// Keep the gradient of the tensor in the gradients returned by the next backward pass, even
+    /// if it isn't a leaf.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the tensor is not tracked.
+    pub fn retain_grad(self) -> Self {
+        match self.node.requirement {
+            // The gradients of leaves are always kept.
+            Requirement::Grad => self,
+            Requirement::GradInBackward => {
+                self.node.client.retain_grad(self.node.id);
+                self
+            }
+            Requirement::None => panic!("Can't retain the gradient of an untracked tensor"),
+        }
+    }
+
     /// Create a tensor from parent infos.
     pub fn from_parents(
         primitive: B::FloatTensorPrimitive,
@@ -116,10 +166,13 @@ impl<B: Backend> AutodiffTensor<B> {
             .unwrap_or(0)
             + 1;
 
+        // Untracked parents, such as constants, may belong to another graph.
         let client = parent_nodes
-            .first()
+            .iter()
+            .find(|node| !node.requirement.is_none())
+            .or(parent_nodes.first())
             .map(|node| node.client.clone())
-            .unwrap_or_else(AutodiffClientImpl::new);
+            .unwrap_or_default();
 
         let node: NodeRef = Node::new(
             parent_nodes
@@ -132,6 +185,7 @@ impl<B: Backend> AutodiffTensor<B> {
             requirement,
             computing_properties,
             client,
+            Some(GraphMemory::of(parent_nodes)),
         )
         .into();
 
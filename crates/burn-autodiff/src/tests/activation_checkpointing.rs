@@ -0,0 +1,155 @@
+#[burn_tensor_testgen::testgen(ad_activation_checkpointing)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{
+        Autodiff,
+        checkpoint::{report::CheckpointReport, strategy::BudgetCheckpointing},
+    };
+    use burn_tensor::{
+        Tensor, TensorData, TensorPrimitive,
+        backend::{AutodiffBackend, Backend},
+    };
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    type BudgetBackend<const BYTES: usize> = Autodiff<TestBackend, BudgetCheckpointing<BYTES>>;
+
+    fn gradients<B: AutodiffBackend>() -> (TensorData, TensorData) {
+        let device = Default::default();
+        let x = Tensor::<B, 2>::from_data([[1.0, -2.0], [0.5, 3.0]], &device).require_grad();
+        let y = Tensor::<B, 2>::from_data([[0.2, 1.5], [-1.0, 0.3]], &device).require_grad();
+
+        let hidden = (x.clone().exp() * y.clone()).sin();
+        let output = hidden.clone().matmul(y.clone()).tanh() * hidden;
+        let grads = output.sum().backward();
+
+        (
+            x.grad(&grads).unwrap().into_data(),
+            y.grad(&grads).unwrap().into_data(),
+        )
+    }
+
+    fn block(x: TestAutodiffTensor<2>, weights: TestAutodiffTensor<2>) -> TestAutodiffTensor<2> {
+        let hidden = x.matmul(weights).tanh();
+        hidden.clone().exp() * hidden
+    }
+
+    fn checkpointed_block(
+        x: TestAutodiffTensor<2>,
+        weights: TestAutodiffTensor<2>,
+    ) -> TestAutodiffTensor<2> {
+        let output = TestAutodiffBackend::checkpoint(
+            vec![x.into_primitive().tensor()],
+            vec![weights.into_primitive().tensor()],
+            |inputs, parameters| {
+                let x = Tensor::from_primitive(TensorPrimitive::Float(inputs[0].clone()));
+                let weights = Tensor::from_primitive(TensorPrimitive::Float(parameters[0].clone()));
+
+                block(x, weights).into_primitive().tensor()
+            },
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(output))
+    }
+
+    #[test]
+    fn should_compute_same_gradients_with_any_budget() {
+        let (x_grad, y_grad) = gradients::<TestAutodiffBackend>();
+
+        for (x_grad_budget, y_grad_budget) in [
+            gradients::<BudgetBackend<0>>(),
+            gradients::<BudgetBackend<{ usize::MAX }>>(),
+        ] {
+            x_grad_budget.assert_approx_eq::<FT>(&x_grad, Tolerance::default());
+            y_grad_budget.assert_approx_eq::<FT>(&y_grad, Tolerance::default());
+        }
+    }
+
+    #[test]
+    fn should_compute_gradients_of_checkpointed_segment() {
+        let device = Default::default();
+        let data_x = TensorData::from([[1.0, -0.5], [2.0, 0.25]]);
+        let data_w = TensorData::from([[0.3, -0.2, 0.1], [0.5, 0.4, -0.6]]);
+
+        let x = TestAutodiffTensor::<2>::from_data(data_x.clone(), &device).require_grad();
+        let w = TestAutodiffTensor::<2>::from_data(data_w.clone(), &device).require_grad();
+        // The input of the segment is not a leaf, and its output is used twice.
+        let output = checkpointed_block(x.clone().sin(), w.clone());
+        let grads = (output.clone() * output).sum().backward();
+
+        let x_ref = TestAutodiffTensor::<2>::from_data(data_x, &device).require_grad();
+        let w_ref = TestAutodiffTensor::<2>::from_data(data_w, &device).require_grad();
+        let output_ref = block(x_ref.clone().sin(), w_ref.clone());
+        let grads_ref = (output_ref.clone() * output_ref).sum().backward();
+
+        x.grad(&grads).unwrap().into_data().assert_approx_eq::<FT>(
+            &x_ref.grad(&grads_ref).unwrap().into_data(),
+            Tolerance::default(),
+        );
+        w.grad(&grads).unwrap().into_data().assert_approx_eq::<FT>(
+            &w_ref.grad(&grads_ref).unwrap().into_data(),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_not_compute_gradient_of_untracked_parameter() {
+        let device = Default::default();
+        let data_x = TensorData::from([[1.0, 2.0], [-1.0, 0.5]]);
+        let data_w = TensorData::from([[0.1, 0.2], [0.3, -0.4]]);
+
+        let x = TestAutodiffTensor::<2>::from_data(data_x.clone(), &device).require_grad();
+        let w = TestAutodiffTensor::<2>::from_data(data_w.clone(), &device);
+        let grads = checkpointed_block(x.clone(), w.clone()).sum().backward();
+
+        let x_ref = TestAutodiffTensor::<2>::from_data(data_x, &device).require_grad();
+        let w_ref = TestAutodiffTensor::<2>::from_data(data_w, &device);
+        let grads_ref = block(x_ref.clone(), w_ref).sum().backward();
+
+        assert!(w.grad(&grads).is_none());
+        x.grad(&grads).unwrap().into_data().assert_approx_eq::<FT>(
+            &x_ref.grad(&grads_ref).unwrap().into_data(),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_report_recomputed_activations_per_graph() {
+        let device = Default::default();
+        let x = Tensor::<BudgetBackend<0>, 2>::from_data([[1.0, -2.0], [0.5, 3.0]], &device)
+            .require_grad();
+        let y = Tensor::<BudgetBackend<0>, 2>::from_data([[0.2, 1.5], [-1.0, 0.3]], &device)
+            .require_grad();
+
+        let hidden = (x.clone().exp() * y.clone()).sin();
+        let output = (hidden.clone().matmul(y).tanh() * hidden).sum();
+        // The same leaves start another graph, which has its own report.
+        let other = x.exp().sum();
+        output.backward();
+
+        let report = CheckpointReport::of(&output);
+        assert_eq!(report.backward_passes, 1);
+        assert_eq!(report.recomputed_segments, 0);
+        assert!(report.recomputed_ops > 0);
+        assert!(report.recomputed_bytes > 0);
+        assert_eq!(CheckpointReport::of(&other).backward_passes, 0);
+    }
+
+    #[test]
+    fn should_report_recomputed_segments() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let w = TestAutodiffTensor::<2>::from_data([[0.5, 0.5], [0.5, 0.5]], &device);
+
+        let output = checkpointed_block(x, w.require_grad());
+        let output = (output.clone() * output).sum();
+        output.backward();
+
+        let report = CheckpointReport::of(&output);
+        assert_eq!(report.backward_passes, 1);
+        assert_eq!(report.recomputed_segments, 1);
+        assert!(report.kept_bytes > 0);
+        assert!(report.recomputed_bytes > 0);
+        assert!(report.peak_bytes > 0);
+    }
+}
@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(ad_bessel)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_i0() {
+        let tensor = TestAutodiffTensor::<1>::from([-2.0, 0.0, 1.0, 5.0]).require_grad();
+
+        let grads = tensor.clone().i0().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([-1.5906368, 0.0, 0.5651591, 24.335642]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_i1() {
+        let tensor = TestAutodiffTensor::<1>::from([-2.0, 0.0, 1.0, 5.0]).require_grad();
+
+        let grads = tensor.clone().i1().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([1.4842669, 0.5, 0.7009068, 22.372744]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
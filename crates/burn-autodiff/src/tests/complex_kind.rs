@@ -0,0 +1,88 @@
+#[burn_tensor_testgen::testgen(ad_complex_kind)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Tensor, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_squared_modulus() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<1>::from_data([1.0, -2.0, 0.5], &device).require_grad();
+        let imag = TestAutodiffTensor::<1>::from_data([3.0, 1.0, -1.0], &device).require_grad();
+        let tensor = Tensor::<_, 1, Complex>::from_parts(real, imag);
+
+        let loss = tensor.clone().mul(tensor.clone().conj()).real().sum();
+        let grads = loss.backward();
+
+        // The gradient of |z|² is 2z.
+        let grad = tensor.grad(&grads).unwrap();
+        let expected = TensorData::complex(
+            TensorData::from([2.0, -4.0, 1.0]),
+            TensorData::from([6.0, 2.0, -2.0]),
+        );
+        grad.into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_mul() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<1>::from_data([1.0, 2.0], &device).require_grad();
+        let imag = TestAutodiffTensor::<1>::from_data([-1.0, 0.5], &device).require_grad();
+        let tensor = Tensor::<_, 1, Complex>::from_parts(real, imag);
+        let weights = Tensor::<_, 1, Complex>::from_parts(
+            TestAutodiffTensor::from_data([3.0, -1.0], &device),
+            TestAutodiffTensor::from_data([2.0, 4.0], &device),
+        );
+
+        let loss = tensor.clone().mul(weights).real().sum();
+        let grads = loss.backward();
+
+        // The gradient of Re(zw) is conj(w).
+        let grad = tensor.grad(&grads).unwrap();
+        let expected = TensorData::complex(
+            TensorData::from([3.0, -1.0]),
+            TensorData::from([-2.0, -4.0]),
+        );
+        grad.into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_abs() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<1>::from_data([3.0, 0.0, 0.0], &device).require_grad();
+        let imag = TestAutodiffTensor::<1>::from_data([4.0, -2.0, 0.0], &device).require_grad();
+        let tensor = Tensor::<_, 1, Complex>::from_parts(real, imag);
+
+        let loss = tensor.clone().abs().sum();
+        let grads = loss.backward();
+
+        // The gradient of |z| is z / |z|, and zero at zero.
+        let grad = tensor.grad(&grads).unwrap();
+        let expected = TensorData::complex(
+            TensorData::from([0.6, 0.0, 0.0]),
+            TensorData::from([0.8, -1.0, 0.0]),
+        );
+        grad.into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_angle() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<1>::from_data([1.0, 0.0], &device).require_grad();
+        let imag = TestAutodiffTensor::<1>::from_data([1.0, 2.0], &device).require_grad();
+        let tensor = Tensor::<_, 1, Complex>::from_parts(real, imag);
+
+        let loss = tensor.clone().angle().sum();
+        let grads = loss.backward();
+
+        // The gradient of arg(z) is i z / |z|².
+        let grad = tensor.grad(&grads).unwrap();
+        let expected =
+            TensorData::complex(TensorData::from([-0.5, -0.5]), TensorData::from([0.5, 0.0]));
+        grad.into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
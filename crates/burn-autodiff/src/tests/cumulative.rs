@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+        let weights = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let tensor_2 = tensor_1.clone().cumsum(0).mul(weights).sum();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([6.0, 5.0, 3.0]), false);
+    }
+
+    #[test]
+    fn should_diff_cumsum_2d() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+                .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumsum(0).sum();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data().assert_eq(
+            &TensorData::from([[3.0, 3.0], [2.0, 2.0], [1.0, 1.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(0).sum();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq::<FT>(&TensorData::from([9.0, 4.0, 2.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[2.0, 0.0, 3.0], [0.0, 2.0, 3.0]], &device)
+                .require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 0.0, 0.0], &device).require_grad();
+
+        let grads = tensor_1.clone().cumprod(1).sum().backward();
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 8.0, 0.0], [9.0, 0.0, 0.0]]),
+            Tolerance::default(),
+        );
+
+        // Only the first zero receives a gradient from the later outputs.
+        let grads = tensor_2.clone().cumprod(0).sum().backward();
+        let grad = tensor_2.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0, 1.0, 0.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_cummax() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 3.0, 2.0, 4.0], &device).require_grad();
+        let weights = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+
+        let tensor_2 = tensor_1.clone().cummax(0).mul(weights).sum();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([1.0, 5.0, 0.0, 4.0]), false);
+    }
+
+    #[test]
+    fn should_diff_cummin() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 1.0], [2.0, 4.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cummin(0).sum();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [1.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_logcumsumexp() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([0.0, 0.0, 0.0], &device).require_grad();
+        let weights = TestAutodiffTensor::<1>::from_floats([1.0, -1.0, 1.0], &device);
+
+        let tensor_2 = tensor_1.clone().logcumsumexp(0).mul(weights).sum();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.8333333, -0.1666667, 0.3333333]),
+            Tolerance::default(),
+        );
+    }
+}
@@ -0,0 +1,230 @@
+#[burn_tensor_testgen::testgen(ad_custom_function)]
+mod tests {
+    use super::*;
+    use burn_autodiff::function::{Function, Saved, apply, from_fn};
+    use burn_tensor::{Tensor, TensorData, backend::Backend};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    /// Computes `x * y + x`.
+    #[derive(Debug, Clone)]
+    struct MulAdd;
+
+    impl<B: Backend> Function<B, 2, 2, 2> for MulAdd {
+        fn forward(&self, [x, y]: [Tensor<B, 2>; 2]) -> Tensor<B, 2> {
+            x.clone() * y + x
+        }
+
+        fn backward(
+            &self,
+            saved: Saved<B, 2, 2, 2>,
+            grad: Tensor<B, 2>,
+        ) -> [Option<Tensor<B, 2>>; 2] {
+            let grad_x = saved
+                .requires_grad(0)
+                .then(|| grad.clone() * saved.input(1).add_scalar(1.0));
+            let grad_y = saved.requires_grad(1).then(|| grad * saved.input(0));
+
+            [grad_x, grad_y]
+        }
+    }
+
+    /// Computes `exp(x)` from the output, with the input not saved.
+    #[derive(Debug, Clone)]
+    struct Exp {
+        memory_bound: bool,
+    }
+
+    impl<B: Backend> Function<B, 1, 1, 1> for Exp {
+        fn forward(&self, [x]: [Tensor<B, 1>; 1]) -> Tensor<B, 1> {
+            x.exp()
+        }
+
+        fn backward(
+            &self,
+            saved: Saved<B, 1, 1, 1>,
+            grad: Tensor<B, 1>,
+        ) -> [Option<Tensor<B, 1>>; 1] {
+            [Some(grad * saved.output())]
+        }
+
+        fn save_inputs(&self) -> [bool; 1] {
+            [false]
+        }
+
+        fn save_output(&self) -> bool {
+            true
+        }
+
+        fn memory_bound(&self) -> bool {
+            self.memory_bound
+        }
+    }
+
+    /// Computes `x + bias` and `x * x` concatenated along the first dimension, with a bias of
+    /// shape `[1, d]`.
+    #[derive(Debug, Clone)]
+    struct AddBiasAndSquare;
+
+    impl<B: Backend> Function<B, 2, 2, 2> for AddBiasAndSquare {
+        fn forward(&self, [x, bias]: [Tensor<B, 2>; 2]) -> Tensor<B, 2> {
+            Tensor::cat(vec![x.clone() + bias, x.clone() * x], 0)
+        }
+
+        fn backward(
+            &self,
+            saved: Saved<B, 2, 2, 2>,
+            grad: Tensor<B, 2>,
+        ) -> [Option<Tensor<B, 2>>; 2] {
+            let [rows, _] = saved.input(0).dims();
+            let grad_sum = grad.clone().slice([0..rows]);
+            let grad_square = grad.slice([rows..2 * rows]);
+            let grad_x = grad_sum.clone() + grad_square * saved.input(0).mul_scalar(2.0);
+
+            [Some(grad_x), Some(grad_sum.sum_dim(0))]
+        }
+
+        fn save_inputs(&self) -> [bool; 2] {
+            [true, false]
+        }
+    }
+
+    #[test]
+    fn should_compute_gradients_of_custom_function() {
+        let device = Default::default();
+        let data_x = TensorData::from([[1.0, -2.0], [3.0, 0.5]]);
+        let data_y = TensorData::from([[4.0, 1.5], [-1.0, 2.0]]);
+
+        let x = TestAutodiffTensor::<2>::from_data(data_x.clone(), &device).require_grad();
+        let y = TestAutodiffTensor::<2>::from_data(data_y.clone(), &device).require_grad();
+        let output = apply(MulAdd, [x.clone(), y.clone()]);
+        let grads = (output.clone() * output.clone()).sum().backward();
+
+        let x_ref = TestAutodiffTensor::<2>::from_data(data_x, &device).require_grad();
+        let y_ref = TestAutodiffTensor::<2>::from_data(data_y, &device).require_grad();
+        let output_ref = x_ref.clone() * y_ref.clone() + x_ref.clone();
+        let grads_ref = (output_ref.clone() * output_ref.clone()).sum().backward();
+
+        output.into_data().assert_eq(&output_ref.into_data(), false);
+        x.grad(&grads).unwrap().into_data().assert_approx_eq::<FT>(
+            &x_ref.grad(&grads_ref).unwrap().into_data(),
+            Tolerance::default(),
+        );
+        y.grad(&grads).unwrap().into_data().assert_approx_eq::<FT>(
+            &y_ref.grad(&grads_ref).unwrap().into_data(),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_not_compute_gradient_of_untracked_input() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let y =
+            TestAutodiffTensor::<2>::from_data([[2.0, 0.0], [1.0, -1.0]], &device).require_grad();
+
+        let output = apply(MulAdd, [x.clone(), y.clone()]);
+        let grads = output.sum().backward();
+
+        assert!(x.grad(&grads).is_none());
+        y.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [3.0, 4.0]]), false);
+    }
+
+    #[test]
+    fn should_compute_gradients_of_inputs_of_different_ranks_and_several_outputs() {
+        // The inputs of a function share the same rank and it has a single output, so the bias is
+        // unsqueezed and the outputs are concatenated.
+        let device = Default::default();
+        let data_x = TensorData::from([[1.0, -2.0, 0.5], [3.0, 0.5, -1.0]]);
+        let data_bias = TensorData::from([0.5, -1.0, 2.0]);
+
+        let x = TestAutodiffTensor::<2>::from_data(data_x.clone(), &device).require_grad();
+        let bias = TestAutodiffTensor::<1>::from_data(data_bias.clone(), &device).require_grad();
+        let output = apply(AddBiasAndSquare, [x.clone(), bias.clone().unsqueeze()]);
+        let sum = output.clone().slice([0..2]);
+        let square = output.slice([2..4]);
+        let grads = (sum.clone() * square.clone()).sum().backward();
+
+        let x_ref = TestAutodiffTensor::<2>::from_data(data_x, &device).require_grad();
+        let bias_ref = TestAutodiffTensor::<1>::from_data(data_bias, &device).require_grad();
+        let sum_ref = x_ref.clone() + bias_ref.clone().unsqueeze();
+        let square_ref = x_ref.clone() * x_ref.clone();
+        let grads_ref = (sum_ref.clone() * square_ref.clone()).sum().backward();
+
+        sum.into_data().assert_eq(&sum_ref.into_data(), false);
+        square.into_data().assert_eq(&square_ref.into_data(), false);
+        x.grad(&grads).unwrap().into_data().assert_approx_eq::<FT>(
+            &x_ref.grad(&grads_ref).unwrap().into_data(),
+            Tolerance::default(),
+        );
+        bias.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &bias_ref.grad(&grads_ref).unwrap().into_data(),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn should_compute_gradients_with_saved_output() {
+        for memory_bound in [false, true] {
+            let device = Default::default();
+            let x = TestAutodiffTensor::<1>::from_data([0.0, 1.0, -1.0], &device).require_grad();
+
+            // The output is also used by the following operations, so it may be recomputed.
+            let output = apply(Exp { memory_bound }, [x.clone().mul_scalar(2.0)]);
+            let output = output.clone() * output.add_scalar(1.0);
+            let grads = output.sum().backward();
+
+            // d/dx (e^2x (e^2x + 1)) = 4 e^4x + 2 e^2x
+            let expected = x.clone().inner().mul_scalar(4.0).exp().mul_scalar(4.0)
+                + x.clone().inner().mul_scalar(2.0).exp().mul_scalar(2.0);
+            x.grad(&grads)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+        }
+    }
+
+    #[test]
+    fn should_compute_gradients_of_closure_function() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+
+        let cube = from_fn(
+            |[x]: [TestTensor<1>; 1]| x.powf_scalar(3.0),
+            |saved, grad| [Some(grad * saved.input(0).powf_scalar(2.0).mul_scalar(3.0))],
+        )
+        .memory_bound();
+        let output: TestAutodiffTensor<1> = apply(cube, [x.clone().sin()]);
+        let grads = output.sum().backward();
+
+        // d/dx sin(x)³ = 3 sin(x)² cos(x)
+        let x_inner = x.clone().inner();
+        let expected = x_inner.clone().sin().powf_scalar(2.0).mul_scalar(3.0) * x_inner.cos();
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_input_is_not_saved() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data([1.0, 2.0], &device).require_grad();
+
+        let function = from_fn(
+            |[x]: [TestTensor<1>; 1]| x.mul_scalar(2.0),
+            |saved, grad| [Some(grad * saved.input(0))],
+        )
+        .save_inputs([false]);
+        let output: TestAutodiffTensor<1> = apply(function, [x]);
+
+        output.sum().backward();
+    }
+}
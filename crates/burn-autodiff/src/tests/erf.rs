@@ -30,4 +30,30 @@ mod tests {
             .to_data()
             .assert_approx_eq::<FT>(&expected, Tolerance::default());
     }
+
+    #[test]
+    fn should_diff_erfc() {
+        let tensor = TestAutodiffTensor::<1>::from([-1.0, 0.0, 0.5, 2.0]).require_grad();
+
+        let grads = tensor.clone().erfc().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([-0.4151075, -1.1283792, -0.8787826, -0.020666985]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_erfinv() {
+        let tensor = TestAutodiffTensor::<1>::from([-0.5, 0.0, 0.3, 0.9]).require_grad();
+
+        let grads = tensor.clone().erfinv().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([1.1125848, 0.88622695, 0.95452034, 3.4280428]);
+        grad.to_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_relative(2e-2),
+        );
+    }
 }
@@ -30,4 +30,16 @@ mod tests {
             .to_data()
             .assert_approx_eq::<FT>(&expected, tolerance);
     }
+
+    #[test]
+    fn should_diff_expm1() {
+        let tensor = TestAutodiffTensor::<1>::from([-1.0, 0.0, 0.5, 2.0]).require_grad();
+
+        let grads = tensor.clone().expm1().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0.36787945, 1.0, 1.6487213, 7.389056]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
 }
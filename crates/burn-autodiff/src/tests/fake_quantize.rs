@@ -0,0 +1,106 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::quantization::{QuantInputType, QuantMode, QuantScheme};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_fake_quantize_symmetric() {
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_q_type(QuantInputType::QInt4);
+        let tensor =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([-1.0, 0.2, 0.74, 2.0]), &device)
+                .require_grad();
+        let scales =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([0.1]), &device).require_grad();
+
+        let output = tensor.clone().fake_quantize(&scheme, scales.clone(), None);
+        let grads = output.clone().sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_scales = scales.grad(&grads).unwrap();
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([-0.7, 0.2, 0.7, 0.7]),
+            Tolerance::default(),
+        );
+        // Straight-through estimator, except for the clamped values
+        grad_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 1.0, 1.0, 0.0]), false);
+        // -7 + (2 - 2) + (7 - 7.4) + 7
+        grad_scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([-0.4]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_affine() {
+        let device = Default::default();
+        let scheme = QuantScheme::default()
+            .set_mode(QuantMode::Affine)
+            .set_q_type(QuantInputType::QUInt4);
+        let tensor =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([-1.0, 0.2, 0.74, 2.0]), &device)
+                .require_grad();
+        let scales =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([0.1]), &device).require_grad();
+        let offsets =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([5.0]), &device).require_grad();
+
+        let output = tensor
+            .clone()
+            .fake_quantize(&scheme, scales.clone(), Some(offsets.clone()));
+        let grads = output.clone().sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_scales = scales.grad(&grads).unwrap();
+        let grad_offsets = offsets.grad(&grads).unwrap();
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([-0.5, 0.2, 0.7, 1.0]),
+            Tolerance::default(),
+        );
+        grad_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 1.0, 1.0, 0.0]), false);
+        // (0 - 5) + 0 + (12 - 12.4) + (15 - 5)
+        grad_scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([4.6]), Tolerance::default());
+        // Only the clamped values contribute `-scale`
+        grad_offsets
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([-0.2]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_per_channel() {
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[0.5, 2.0], [-0.3, 0.04]]),
+            &device,
+        )
+        .require_grad();
+        let scales =
+            TestAutodiffTensor::<2>::from_data(TensorData::from([[0.01], [0.001]]), &device)
+                .require_grad();
+
+        let output = tensor.clone().fake_quantize(&scheme, scales.clone(), None);
+        let grads = output.sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_scales = scales.grad(&grads).unwrap();
+
+        grad_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), false);
+        // The gradients are reduced to the shape of the scales: 0 + 127 and -127 + 0
+        grad_scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[127.0], [-127.0]]), Tolerance::default());
+    }
+}
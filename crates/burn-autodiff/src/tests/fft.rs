@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem, signal};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_fft() {
+        let device = Default::default();
+        let real =
+            TestAutodiffTensor::<1>::from_data([1.0, -2.0, 0.5, 3.0], &device).require_grad();
+        let imag =
+            TestAutodiffTensor::<1>::from_data([0.0, 1.0, 2.0, -1.0], &device).require_grad();
+        let weights_real = TestAutodiffTensor::<1>::from_data([1.0, 2.0, -1.0, 0.5], &device);
+        let weights_imag = TestAutodiffTensor::<1>::from_data([0.5, -1.0, 3.0, 1.0], &device);
+
+        let (output_real, output_imag) = signal::fft(real.clone(), imag.clone(), 0);
+        let loss = (output_real * weights_real + output_imag * weights_imag).sum();
+        let grads = loss.backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([2.5, 4.0, -2.5, 0.0]),
+            Tolerance::absolute(1e-4),
+        );
+        grad_imag.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([3.5, -1.0, 3.5, -4.0]),
+            Tolerance::absolute(1e-4),
+        );
+    }
+
+    #[test]
+    fn should_diff_rfft() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data([[1.0, -2.0, 3.0, 0.5, 2.0]], &device)
+            .require_grad();
+        let weights_real = TestAutodiffTensor::<2>::from_data([[1.0, -1.0, 2.0]], &device);
+        let weights_imag = TestAutodiffTensor::<2>::from_data([[0.5, 2.0, -1.0]], &device);
+
+        let (output_real, output_imag) = signal::rfft(tensor.clone(), 1);
+        let loss = (output_real * weights_real + output_imag * weights_imag).sum();
+        let grads = loss.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[2.0, -2.2414, 0.3004, 4.5537, 0.3873]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_diff_irfft() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<1>::from_data([1.0, 2.0, -1.0], &device).require_grad();
+        let imag = TestAutodiffTensor::<1>::from_data([0.5, 1.0, 2.0], &device).require_grad();
+        let weights = TestAutodiffTensor::<1>::from_data([1.0, -1.0, 2.0, 0.5], &device);
+
+        let output = signal::irfft(real.clone(), imag.clone(), 0, Some(4));
+        let grads = (output * weights).sum().backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.625, -0.5, 0.875]),
+            Tolerance::absolute(1e-4),
+        );
+        grad_imag.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 0.75, 0.0]),
+            Tolerance::absolute(1e-4),
+        );
+    }
+}
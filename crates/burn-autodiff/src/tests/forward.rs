@@ -0,0 +1,352 @@
+#[burn_tensor_testgen::testgen(ad_forward)]
+mod tests {
+    use super::*;
+    use burn_autodiff::forward::{Dual, make_dual, unpack_dual};
+    use burn_autodiff::functional::{hessian, hvp, jacobian, jacobian_fwd, jvp};
+    use burn_tensor::module::{avg_pool2d, conv2d, deform_conv2d, interpolate, max_pool2d};
+    use burn_tensor::ops::{ConvOptions, DeformConvOptions, InterpolateMode, InterpolateOptions};
+    use burn_tensor::{Int, Tensor, TensorData, activation, linalg};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    type TestDualBackend = Dual<TestBackend>;
+    type TestDualTensor<const D: usize> = Tensor<TestDualBackend, D>;
+
+    #[test]
+    fn should_compute_jvp_of_unary_ops() {
+        assert_unary_jvp(|x| x.sin());
+        assert_unary_jvp(|x| x.cos());
+        assert_unary_jvp(|x| x.exp());
+        assert_unary_jvp(|x| x.log());
+        assert_unary_jvp(|x| x.sqrt());
+        assert_unary_jvp(|x| x.tanh());
+        assert_unary_jvp(|x| x.erf());
+        assert_unary_jvp(|x| x.recip());
+        assert_unary_jvp(|x| x.powf_scalar(3.0));
+        assert_unary_jvp(|x| (x - 1.1).abs());
+        assert_unary_jvp(|x| activation::sigmoid(x));
+        assert_unary_jvp(|x| activation::gelu(x));
+        assert_unary_jvp(|x| activation::relu(x - 1.1));
+    }
+
+    #[test]
+    fn should_compute_jvp_of_binary_ops() {
+        assert_binary_jvp(|x, y| x * y);
+        assert_binary_jvp(|x, y| x / y);
+        assert_binary_jvp(|x, y| x - y.mul_scalar(2.0));
+        assert_binary_jvp(|x, y| x.powf(y));
+        assert_binary_jvp(|x, y| x.matmul(y.transpose()));
+        assert_binary_jvp(|x, y| Tensor::cat(vec![x, y], 1).sum_dim(0));
+    }
+
+    #[test]
+    fn should_compute_jvp_of_indexing_ops() {
+        assert_unary_jvp(|x| x.flip([1]).slice([0..2, 1..3]) * 2.0);
+        assert_unary_jvp(|x| x.sort(1));
+        assert_unary_jvp(|x| x.max_dim(1));
+        assert_unary_jvp(|x| x.clone().mask_fill(x.greater_elem(1.1), 0.0));
+        assert_binary_jvp(|x, y| {
+            let indices = Tensor::<TestDualBackend, 2, Int>::from_data(
+                [[2, 0, 1, 2], [1, 1, 0, 3]],
+                &x.device(),
+            );
+            x.gather(1, indices.clone()) + y.clone().scatter(1, indices, y)
+        });
+    }
+
+    #[test]
+    fn should_compute_jvp_of_conv2d_bias() {
+        let device = Default::default();
+        let x = TestDualTensor::<4>::from_data(sample_data([1, 1, 3, 3], 0), &device);
+        let weight = TestDualTensor::<4>::from_data(sample_data([2, 1, 2, 2], 1), &device);
+
+        let (_, tangent) = jvp(
+            |[bias]| {
+                conv2d(
+                    x,
+                    weight,
+                    Some(bias),
+                    ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+                )
+            },
+            [TestTensor::<1>::from_data([0.5, -1.0], &device)],
+            [TestTensor::<1>::from_data([2.0, 3.0], &device)],
+        );
+
+        tangent.into_data().assert_eq(
+            &TensorData::from([[[[2.0, 2.0], [2.0, 2.0]], [[3.0, 3.0], [3.0, 3.0]]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_compute_jvp_of_module_ops() {
+        let options = || InterpolateOptions::new(InterpolateMode::Bilinear);
+
+        assert_jvp(
+            |[x, weight]| conv2d(x, weight, None, ConvOptions::new([1, 1], [1, 1], [1, 1], 1)),
+            [sample_data([1, 2, 4, 4], 0), sample_data([3, 2, 3, 3], 1)],
+            [sample_data([1, 2, 4, 4], 2), sample_data([3, 2, 3, 3], 3)],
+        );
+        assert_jvp(
+            |[x]| avg_pool2d(x, [2, 2], [1, 1], [0, 0], true),
+            [sample_data([1, 2, 4, 4], 0)],
+            [sample_data([1, 2, 4, 4], 1)],
+        );
+        assert_jvp(
+            |[x]| max_pool2d(x, [2, 2], [2, 2], [0, 0], [1, 1]),
+            [sample_data([1, 2, 4, 4], 0)],
+            [sample_data([1, 2, 4, 4], 1)],
+        );
+        assert_jvp(
+            |[x]| interpolate(x, [6, 5], options()),
+            [sample_data([1, 2, 4, 4], 0)],
+            [sample_data([1, 2, 4, 4], 1)],
+        );
+    }
+
+    #[test]
+    fn should_compute_jvp_of_deform_conv2d() {
+        let device = Default::default();
+        let options = || DeformConvOptions::new([1, 1], [0, 0], [1, 1], 1, 1);
+        let weight = || TestDualTensor::<4>::from_data(sample_data([2, 1, 2, 2], 2), &device);
+
+        // The offsets of seed 1 are away from integers, where bilinear sampling isn't
+        // differentiable.
+        assert_jvp(
+            |[x, offset, mask]| deform_conv2d(x, offset, weight(), Some(mask), None, options()),
+            [
+                sample_data([1, 1, 3, 3], 0),
+                sample_data([1, 8, 2, 2], 1),
+                sample_data([1, 4, 2, 2], 3),
+            ],
+            [
+                sample_data([1, 1, 3, 3], 4),
+                sample_data([1, 8, 2, 2], 5),
+                sample_data([1, 4, 2, 2], 6),
+            ],
+        );
+        assert_jvp(
+            |[offset]| {
+                let x = TestDualTensor::<4>::from_data(sample_data([1, 1, 3, 3], 0), &device);
+                deform_conv2d(x, offset, weight(), None, None, options())
+            },
+            [sample_data([1, 8, 2, 2], 1)],
+            [sample_data([1, 8, 2, 2], 5)],
+        );
+    }
+
+    #[test]
+    fn should_compute_jvp_with_inputs_and_outputs_of_different_ranks() {
+        let device = Default::default();
+        let x = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let bias = TestTensor::<1>::from_data([0.5, -1.0], &device);
+        let dx = TestTensor::<2>::from_data([[1.0, 0.0], [0.0, 1.0]], &device);
+        let dbias = TestTensor::<1>::from_data([2.0, 3.0], &device);
+
+        let ((output, sum), (tangent, sum_tangent)) = jvp(
+            |(x, bias): (TestDualTensor<2>, TestDualTensor<1>)| {
+                let output = x * bias.unsqueeze::<2>();
+                (output.clone(), output.sum())
+            },
+            (x, bias),
+            (dx, dbias),
+        );
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.5, -2.0], [1.5, -4.0]]), false);
+        sum.into_data().assert_eq(&TensorData::from([-4.0]), false);
+        tangent
+            .into_data()
+            .assert_eq(&TensorData::from([[2.5, 6.0], [6.0, 11.0]]), false);
+        sum_tangent
+            .into_data()
+            .assert_eq(&TensorData::from([25.5]), false);
+    }
+
+    #[test]
+    fn should_compute_jvp_of_linalg_ops() {
+        let matrix = TensorData::from([[4.0, 1.0, 0.5], [1.0, 3.0, 0.2], [0.5, 0.2, 2.0]]);
+        let direction = TensorData::from([[0.3, -0.1, 0.2], [-0.1, 0.5, 0.4], [0.2, 0.4, -0.2]]);
+        let assert_linalg = |func: fn(TestDualTensor<2>) -> TestDualTensor<2>| {
+            assert_jvp(|[x]| func(x), [matrix.clone()], [direction.clone()])
+        };
+
+        assert_linalg(|x| linalg::inverse(x));
+        assert_linalg(|x| linalg::det(x));
+        assert_linalg(|x| linalg::slogdet(x).1);
+        assert_linalg(|x| linalg::cholesky(x));
+        assert_linalg(|x| linalg::eigh(x).0);
+        assert_linalg(|x| linalg::svd(x).1);
+        assert_linalg(|x| {
+            let b = TestDualTensor::from_data([[1.0], [2.0], [-1.0]], &x.device());
+            linalg::solve(x, b)
+        });
+    }
+
+    #[test]
+    fn should_return_zero_tangent_for_constant_output() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+
+        let (output, tangent) = jvp(
+            |[x]| TestDualTensor::<1>::ones([2], &x.device()),
+            [x.clone()],
+            [x.ones_like()],
+        );
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 1.0]), false);
+        tangent
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 0.0]), false);
+    }
+
+    #[test]
+    fn should_drop_tangent_of_detached_tensor() {
+        let device = Default::default();
+        let x = make_dual(
+            TestTensor::<1>::from_data([1.0, 2.0], &device),
+            TestTensor::<1>::from_data([1.0, 1.0], &device),
+        );
+
+        let (_, tangent) = unpack_dual(x.clone().detach() * x);
+
+        tangent
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0]), false);
+    }
+
+    #[test]
+    fn should_compute_same_jacobian_in_both_modes() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_data([0.5, -1.0, 2.0], &device);
+        let expected =
+            TensorData::from([[-1.0, 0.5, 0.0], [0.0, 0.0, -0.416146837], [1.0, 0.0, 1.0]]);
+
+        let reverse = jacobian(
+            |x| {
+                let [a, b, c] = [0, 1, 2].map(|i| x.clone().slice([i..i + 1]));
+                Tensor::cat(vec![a.clone() * b, c.clone().sin(), a + c], 0)
+            },
+            x.clone(),
+        );
+        let forward = jacobian_fwd(
+            |x| {
+                let [a, b, c] = [0, 1, 2].map(|i| x.clone().slice([i..i + 1]));
+                Tensor::cat(vec![a.clone() * b, c.clone().sin(), a + c], 0)
+            },
+            x,
+        );
+
+        reverse
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        forward
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_compute_hessian_with_forward_over_reverse() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_data([1.0, -2.0, 0.5], &device);
+
+        // f(x) = Σ x³ + x₀ x₁, so H = diag(6x) + e₀e₁ᵀ + e₁e₀ᵀ
+        let func = |x: Tensor<burn_autodiff::Autodiff<TestDualBackend>, 1>| {
+            let cross = x.clone().slice([0..1]) * x.clone().slice([1..2]);
+            x.powf_scalar(3.0).sum() + cross
+        };
+
+        let matrix = hessian(func, x.clone());
+        let (grad, product) = hvp(func, x.clone(), x.ones_like());
+
+        matrix.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[6.0, 1.0, 0.0], [1.0, -12.0, 0.0], [0.0, 0.0, 3.0]]),
+            Tolerance::default(),
+        );
+        grad.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0, 13.0, 0.75]), Tolerance::default());
+        product
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([7.0, -11.0, 3.0]), Tolerance::default());
+    }
+
+    fn assert_unary_jvp<F>(func: F)
+    where
+        F: Fn(TestDualTensor<2>) -> TestDualTensor<2>,
+    {
+        assert_jvp(
+            |[x]| func(x),
+            [sample_positive_data([2, 4], 0)],
+            [sample_data([2, 4], 1)],
+        );
+    }
+
+    fn assert_binary_jvp<F>(func: F)
+    where
+        F: Fn(TestDualTensor<2>, TestDualTensor<2>) -> TestDualTensor<2>,
+    {
+        assert_jvp(
+            |[x, y]| func(x, y),
+            [
+                sample_positive_data([2, 4], 0),
+                sample_positive_data([2, 4], 1),
+            ],
+            [sample_data([2, 4], 2), sample_data([2, 4], 3)],
+        );
+    }
+
+    /// Compares the tangent computed by [jvp] with central finite differences.
+    fn assert_jvp<const N: usize, const D: usize, const D2: usize, F>(
+        func: F,
+        inputs: [TensorData; N],
+        directions: [TensorData; N],
+    ) where
+        F: Fn([TestDualTensor<D>; N]) -> TestDualTensor<D2>,
+    {
+        let device = Default::default();
+        let epsilon = 1e-3;
+        let inputs = inputs.map(|data| TestTensor::<D>::from_data(data, &device));
+        let directions = directions.map(|data| TestTensor::<D>::from_data(data, &device));
+
+        let (_, tangent) = jvp(&func, inputs.clone(), directions.clone());
+
+        let evaluate = |step: f64| {
+            let mut directions = directions.clone().into_iter();
+            let inputs = inputs.clone().map(|input| {
+                let direction = directions.next().unwrap();
+                TestDualTensor::from_data((input + direction.mul_scalar(step)).into_data(), &device)
+            });
+            unpack_dual(func(inputs)).0
+        };
+        let expected = (evaluate(epsilon) - evaluate(-epsilon)).div_scalar(2.0 * epsilon);
+
+        tangent
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::rel_abs(2e-2, 2e-2));
+    }
+
+    /// Deterministic data with distinct values in `[-1, 1)`.
+    fn sample_data<const D: usize>(shape: [usize; D], seed: usize) -> TensorData {
+        let num_elements = shape.iter().product::<usize>();
+        let values = (0..num_elements)
+            .map(|i| ((i * 37 + seed * 13) % 101) as f32 / 50.5 - 1.0)
+            .collect::<Vec<_>>();
+
+        TensorData::new(values, shape)
+    }
+
+    /// Deterministic data with distinct values in `[0.5, 2.5)`.
+    fn sample_positive_data<const D: usize>(shape: [usize; D], seed: usize) -> TensorData {
+        let num_elements = shape.iter().product::<usize>();
+        let values = (0..num_elements)
+            .map(|i| ((i * 37 + seed * 13) % 101) as f32 / 50.5 + 0.5)
+            .collect::<Vec<_>>();
+
+        TensorData::new(values, shape)
+    }
+}
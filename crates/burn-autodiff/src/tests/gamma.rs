@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(ad_gamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_lgamma() {
+        let tensor = TestAutodiffTensor::<1>::from([0.5, 1.0, 2.5, -0.5]).require_grad();
+
+        let grads = tensor.clone().lgamma().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([-1.96351, -0.5772157, 0.70315665, 0.036489975]);
+        grad.to_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_absolute(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_diff_digamma() {
+        let tensor = TestAutodiffTensor::<1>::from([0.5, 1.0, 2.5, -0.5]).require_grad();
+
+        let grads = tensor.clone().digamma().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([4.934802, 1.644934, 0.49035776, 8.934802]);
+        grad.to_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_relative(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_diff_polygamma() {
+        let tensor = TestAutodiffTensor::<1>::from([0.5, 1.0, 2.5, 4.0]).require_grad();
+
+        let grads = tensor.clone().polygamma(1).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([-16.828796, -2.4041138, -0.23620405, -0.08003973]);
+        grad.to_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_relative(1e-2),
+        );
+    }
+}
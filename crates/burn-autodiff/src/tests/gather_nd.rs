@@ -0,0 +1,101 @@
+#[burn_tensor_testgen::testgen(ad_gather_nd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn test_gather_nd_grad() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        )
+        .require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data(
+            TensorData::from([[2, 0], [0, 1], [2, 0]]),
+            &device,
+        );
+        let weights =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([1.0, 2.0, 3.0]), &device);
+
+        let output: TestAutodiffTensor<1> = tensor.clone().gather_nd(indices);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data().assert_eq(
+            &TensorData::from([[0.0, 2.0], [0.0, 0.0], [4.0, 0.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_scatter_nd_grad() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        )
+        .require_grad();
+        let values = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[7.0, 8.0], [9.0, 10.0]]),
+            &device,
+        )
+        .require_grad();
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_data(TensorData::from([[1], [2]]), &device);
+        let weights = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        );
+
+        let output = tensor.clone().scatter_nd(indices, values.clone(), false);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_values = values.grad(&grads).unwrap();
+
+        grad_tensor.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0], [0.0, 0.0], [0.0, 0.0]]),
+            false,
+        );
+        grad_values
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 4.0], [5.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn test_scatter_nd_accumulate_grad() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        )
+        .require_grad();
+        let values = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[7.0, 8.0], [9.0, 10.0]]),
+            &device,
+        )
+        .require_grad();
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_data(TensorData::from([[1], [1]]), &device);
+        let weights = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        );
+
+        let output = tensor.clone().scatter_nd(indices, values.clone(), true);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_values = values.grad(&grads).unwrap();
+
+        grad_tensor.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            false,
+        );
+        grad_values
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 4.0], [3.0, 4.0]]), false);
+    }
+}
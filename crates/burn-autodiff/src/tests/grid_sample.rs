@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_grid_sample_bilinear() {
+        let options = GridSampleOptions::new(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+
+        assert_grads(
+            options,
+            TensorData::from([[
+                [[0.64, 0.0, 0.675], [0.16, 0.5625, 0.5825]],
+                [[0.64, 0.0, 0.675], [0.16, 0.5625, 0.5825]],
+            ]]),
+            TensorData::from([[
+                [[3.9, 4.4], [-1.35, -4.625]],
+                [[-4.5, 4.75], [-6.45, -0.35]],
+            ]]),
+        );
+    }
+
+    #[test]
+    fn should_diff_grid_sample_bicubic_reflection() {
+        let options = GridSampleOptions::new(
+            GridSampleMode::Bicubic,
+            GridSamplePaddingMode::Reflection,
+            true,
+        );
+
+        assert_grads(
+            options,
+            TensorData::from([[
+                [[0.6005, 0.3867, 1.1797], [0.16, 0.805, 0.868]],
+                [[0.6005, 0.3867, 1.1797], [0.16, 0.805, 0.868]],
+            ]]),
+            TensorData::from([[
+                [[-1.2361, 3.7538], [-0.8668, 1.2159]],
+                [[4.1397, -0.0324], [-0.5012, -0.6508]],
+            ]]),
+        );
+    }
+
+    fn assert_grads(options: GridSampleOptions, x_grad: TensorData, grid_grad: TensorData) {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_data(
+            [[
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                [[0.5, -1.0, 2.0], [3.0, 0.0, -2.0]],
+            ]],
+            &device,
+        )
+        .require_grad();
+        let grid = TestAutodiffTensor::<4>::from_data(
+            [[[[-0.8, -0.3], [0.25, 0.6]], [[0.7, -0.9], [1.1, 0.2]]]],
+            &device,
+        )
+        .require_grad();
+
+        let output = grid_sample_2d(x.clone(), grid.clone(), options);
+        let grads = output.sum().backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        let grid_grad_actual = grid.grad(&grads).unwrap();
+
+        x_grad_actual
+            .to_data()
+            .assert_approx_eq::<FT>(&x_grad, Tolerance::absolute(1e-3));
+        grid_grad_actual
+            .to_data()
+            .assert_approx_eq::<FT>(&grid_grad, Tolerance::absolute(1e-3));
+    }
+}
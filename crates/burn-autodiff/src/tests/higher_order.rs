@@ -0,0 +1,230 @@
+#[burn_tensor_testgen::testgen(ad_higher_order)]
+mod tests {
+    use super::*;
+    use burn_tensor::activation;
+    use burn_tensor::module::{
+        adaptive_avg_pool2d, avg_pool2d, embedding, interpolate, max_pool2d,
+    };
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::{Tensor, TensorData};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    /// The gradients computed with this backend are tensors of the inner autodiff backend, which
+    /// record their own graph.
+    type TestHigherOrderBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+    type TestHigherOrderTensor<const D: usize> = Tensor<TestHigherOrderBackend, D>;
+
+    #[test]
+    fn should_diff_gradients_of_unary_ops() {
+        assert_gradient_penalty(|x| x.sin());
+        assert_gradient_penalty(|x| x.exp());
+        assert_gradient_penalty(|x| x.tanh());
+        assert_gradient_penalty(|x| x.log());
+        assert_gradient_penalty(|x| x.sqrt());
+        assert_gradient_penalty(|x| x.recip());
+        assert_gradient_penalty(|x| x.powf_scalar(3.0));
+        assert_gradient_penalty(|x| activation::sigmoid(x));
+    }
+
+    #[test]
+    fn should_diff_gradients_of_binary_ops() {
+        assert_gradient_penalty(|x| x.clone() * x.clone() * x);
+        assert_gradient_penalty(|x| x.clone() / (x + 1.0));
+        assert_gradient_penalty(|x| x.clone().powf(x));
+    }
+
+    #[test]
+    fn should_diff_gradient_penalty_with_respect_to_weights() {
+        let device = Default::default();
+
+        assert_directional_derivative(
+            |weights| {
+                let x = TestHigherOrderTensor::<2>::from_data(
+                    [[0.5, -1.0, 2.0], [1.5, 0.2, -0.3]],
+                    &device,
+                )
+                .require_grad();
+                let output = x.clone().matmul(weights).tanh();
+
+                let grad = grad_graph(output, &x);
+                let norm = (grad.clone() * grad).sum_dim(1).sqrt() - 1.0;
+
+                (norm.clone() * norm).mean()
+            },
+            TensorData::from([[0.1, -0.4], [0.3, 0.2], [-0.5, 0.6]]),
+            TensorData::from([[0.2, 0.1], [-0.3, 0.4], [0.5, -0.1]]),
+        );
+    }
+
+    #[test]
+    fn should_diff_through_a_gradient_descent_step() {
+        let device = Default::default();
+
+        assert_directional_derivative(
+            |weights| {
+                let x = TestHigherOrderTensor::<2>::from_data([[1.0, 2.0], [0.5, -1.0]], &device);
+                let y = TestHigherOrderTensor::<2>::from_data([[1.0], [0.0]], &device);
+                let error = x.matmul(weights.clone()) - y;
+                let grad = grad_graph(error.clone() * error, &weights);
+
+                // The updated weights are differentiable with respect to the initial weights.
+                let weights = weights.inner() - grad.mul_scalar(0.1);
+
+                let x = TestAutodiffTensor::<2>::from_data([[0.3, 1.0], [-1.0, 0.5]], &device);
+                let y = TestAutodiffTensor::<2>::from_data([[0.5], [1.0]], &device);
+                let error = x.matmul(weights) - y;
+
+                (error.clone() * error).sum()
+            },
+            TensorData::from([[0.2], [-0.3]]),
+            TensorData::from([[1.0], [0.5]]),
+        );
+    }
+
+    #[test]
+    fn should_diff_gradients_of_avg_pool2d() {
+        assert_module_penalty(
+            |x| avg_pool2d(x, [3, 3], [1, 1], [1, 1], false),
+            [1, 2, 4, 4],
+            [1, 2, 4, 4],
+        );
+    }
+
+    #[test]
+    fn should_diff_gradients_of_max_pool2d() {
+        assert_module_penalty(
+            |x| max_pool2d(x, [2, 2], [1, 1], [0, 0], [1, 1]),
+            [1, 2, 4, 4],
+            [1, 2, 3, 3],
+        );
+    }
+
+    #[test]
+    fn should_diff_gradients_of_adaptive_avg_pool2d() {
+        assert_module_penalty(
+            |x| adaptive_avg_pool2d(x, [2, 3]),
+            [1, 2, 4, 4],
+            [1, 2, 2, 3],
+        );
+    }
+
+    #[test]
+    fn should_diff_gradients_of_interpolate() {
+        assert_module_penalty(
+            |x| {
+                interpolate(
+                    x,
+                    [4, 5],
+                    InterpolateOptions::new(InterpolateMode::Bilinear),
+                )
+            },
+            [1, 2, 3, 3],
+            [1, 2, 4, 5],
+        );
+    }
+
+    #[test]
+    fn should_diff_gradients_of_embedding() {
+        let device = Default::default();
+
+        assert_module_penalty(
+            |table| {
+                let indices = TestTensorInt::<2>::from_data([[0, 2, 2], [4, 1, 0]], &device);
+                embedding(table, Tensor::from_inner(Tensor::from_inner(indices)))
+            },
+            [5, 3],
+            [2, 3, 3],
+        );
+    }
+
+    /// Returns the gradient of `output` with respect to `x`, which is a tensor tracked by the inner
+    /// autodiff backend.
+    fn grad_graph<const D: usize, const D2: usize>(
+        output: TestHigherOrderTensor<D2>,
+        x: &TestHigherOrderTensor<D>,
+    ) -> TestAutodiffTensor<D> {
+        let grads = output.sum().backward();
+        x.grad(&grads).unwrap()
+    }
+
+    /// Checks the derivative of the squared gradient norm of `func` against finite differences.
+    fn assert_gradient_penalty<F>(func: F)
+    where
+        F: Fn(TestHigherOrderTensor<1>) -> TestHigherOrderTensor<1>,
+    {
+        assert_directional_derivative(
+            |x| {
+                let grad = grad_graph(func(x.clone()), &x);
+                (grad.clone() * grad).sum()
+            },
+            TensorData::from([0.5, 1.0, 1.5, 2.0]),
+            TensorData::from([0.3, -0.2, 0.5, 0.1]),
+        );
+    }
+
+    /// Checks the derivative of the squared gradient norm of `op`, weighted by a tensor of the
+    /// shape of its output, with respect to those weights against finite differences.
+    fn assert_module_penalty<const D: usize, const D2: usize, F>(
+        op: F,
+        shape_x: [usize; D],
+        shape_output: [usize; D2],
+    ) where
+        F: Fn(TestHigherOrderTensor<D>) -> TestHigherOrderTensor<D2>,
+    {
+        let device = Default::default();
+
+        assert_directional_derivative(
+            |weights| {
+                let x = TestHigherOrderTensor::from_data(sample_data(shape_x, 0), &device)
+                    .require_grad();
+                let grad = grad_graph(op(x.clone()) * weights, &x);
+
+                (grad.clone() * grad).sum()
+            },
+            sample_data(shape_output, 1),
+            sample_data(shape_output, 2),
+        );
+    }
+
+    /// Compares the derivative of `func` with respect to its input along `direction` with central
+    /// finite differences.
+    fn assert_directional_derivative<const D: usize, F>(
+        func: F,
+        input: TensorData,
+        direction: TensorData,
+    ) where
+        F: Fn(TestHigherOrderTensor<D>) -> TestAutodiffTensor<1>,
+    {
+        let device = Default::default();
+        let epsilon = 1e-2;
+        let direction = TestTensor::<D>::from_data(direction, &device);
+        let input = TestHigherOrderTensor::<D>::from_data(input, &device).require_grad();
+
+        let grads = func(input.clone()).backward();
+        let grad = input.clone().inner().grad(&grads).unwrap();
+        let derivative = (grad * direction.clone()).sum();
+
+        let evaluate = |step: f64| {
+            let input = input.clone().inner().inner() + direction.clone().mul_scalar(step);
+            let input = TestHigherOrderTensor::from_inner(Tensor::from_inner(input)).require_grad();
+
+            func(input).inner()
+        };
+        let expected = (evaluate(epsilon) - evaluate(-epsilon)).div_scalar(2.0 * epsilon);
+
+        derivative
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::rel_abs(2e-2, 2e-2));
+    }
+
+    /// Deterministic data with distinct values in `[-1, 1)`.
+    fn sample_data<const D: usize>(shape: [usize; D], seed: usize) -> TensorData {
+        let num_elements = shape.iter().product::<usize>();
+        let values = (0..num_elements)
+            .map(|i| ((i * 37 + seed * 13) % 101) as f32 / 50.5 - 1.0)
+            .collect::<Vec<_>>();
+
+        TensorData::new(values, shape)
+    }
+}
@@ -0,0 +1,106 @@
+#[burn_tensor_testgen::testgen(ad_hook)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    use std::sync::{Arc, Mutex};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_retain_grad_of_intermediate_tensor() {
+        let device = Default::default();
+        let x =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let y = x.clone().mul_scalar(2.0).retain_grad();
+        let z = x.clone().mul_scalar(3.0);
+        let output = (y.clone() * y.clone() + z.clone()).sum();
+
+        let grads = output.backward();
+        let grad_y = y.grad(&grads).unwrap();
+        let grad_x = x.grad(&grads).unwrap();
+
+        grad_y
+            .into_data()
+            .assert_eq(&TensorData::from([[4.0, 8.0], [12.0, 16.0]]), false);
+        grad_x
+            .into_data()
+            .assert_eq(&TensorData::from([[11.0, 19.0], [27.0, 35.0]]), false);
+        assert!(z.grad(&grads).is_none());
+    }
+
+    #[test]
+    fn should_modify_gradient_with_hook() {
+        let device = Default::default();
+        let x =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let y = x
+            .clone()
+            .mul_scalar(2.0)
+            .register_hook(|grad| grad.clamp(-5.0, 5.0));
+        let output = (y.clone() * y).sum();
+
+        let grads = output.backward();
+        let grad_x = x.grad(&grads).unwrap();
+
+        grad_x
+            .into_data()
+            .assert_eq(&TensorData::from([[8.0, 10.0], [10.0, 10.0]]), false);
+    }
+
+    #[test]
+    fn should_call_hook_with_accumulated_gradient() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+        let received = Arc::new(Mutex::new(None));
+
+        let hook_received = received.clone();
+        let y = x.clone().exp().register_hook(move |grad| {
+            *hook_received.lock().unwrap() = Some(grad.to_data());
+            grad
+        });
+        let output = (y.clone().mul_scalar(2.0) + y.mul_scalar(3.0)).sum();
+
+        let grads = output.backward();
+        let grad_x = x.grad(&grads).unwrap();
+
+        received
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap()
+            .assert_eq(&TensorData::from([5.0, 5.0, 5.0]), false);
+        grad_x.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([13.591409, 36.945280, 100.427685]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_apply_hooks_on_leaf_in_registration_order() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data([1.0, -2.0, 3.0], &device)
+            .require_grad()
+            .register_hook(|grad| grad.mul_scalar(2.0))
+            .register_hook(|grad| grad.add_scalar(1.0));
+
+        let output = (x.clone() * x.clone()).sum();
+
+        let grads = output.backward();
+        let grad_x = x.grad(&grads).unwrap();
+
+        grad_x
+            .into_data()
+            .assert_eq(&TensorData::from([5.0, -7.0, 13.0]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_registering_hook_on_untracked_tensor() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+
+        let _x = x.register_hook(|grad| grad);
+    }
+}
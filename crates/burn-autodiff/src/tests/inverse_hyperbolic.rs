@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(ad_inverse_hyperbolic)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_asinh() {
+        let tensor = TestAutodiffTensor::<1>::from([-2.0, 0.0, 0.5, 3.0]).require_grad();
+
+        let grads = tensor.clone().asinh().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0.4472136, 1.0, 0.8944272, 0.31622776]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_acosh() {
+        let tensor = TestAutodiffTensor::<1>::from([1.5, 2.0, 3.0, 10.0]).require_grad();
+
+        let grads = tensor.clone().acosh().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0.8944272, 0.57735026, 0.35355338, 0.10050378]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_atanh() {
+        let tensor = TestAutodiffTensor::<1>::from([-0.5, 0.0, 0.3, 0.9]).require_grad();
+
+        let grads = tensor.clone().atanh().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([1.3333334, 1.0, 1.0989012, 5.263158]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
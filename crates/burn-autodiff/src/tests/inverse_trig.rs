@@ -0,0 +1,82 @@
+#[burn_tensor_testgen::testgen(ad_inverse_trig)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_asin() {
+        let tensor = TestAutodiffTensor::<1>::from([-0.5, 0.0, 0.3, 0.9]).require_grad();
+
+        let grads = tensor.clone().asin().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([1.1547005, 1.0, 1.0482849, 2.2941573]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_acos() {
+        let tensor = TestAutodiffTensor::<1>::from([-0.5, 0.0, 0.3, 0.9]).require_grad();
+
+        let grads = tensor.clone().acos().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([-1.1547005, -1.0, -1.0482849, -2.2941573]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_atan() {
+        let tensor = TestAutodiffTensor::<1>::from([-2.0, 0.0, 0.5, 3.0]).require_grad();
+
+        let grads = tensor.clone().atan().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0.2, 1.0, 0.8, 0.1]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_atan2() {
+        let lhs = TestAutodiffTensor::<1>::from([1.0, -2.0, 0.5]).require_grad();
+        let rhs = TestAutodiffTensor::<1>::from([2.0, 1.0, -3.0]).require_grad();
+
+        let grads = lhs.clone().atan2(rhs.clone()).sum().backward();
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0.4, 0.2, -0.32432434]);
+        grad_lhs
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+
+        let expected = TensorData::from([-0.2, 0.4, -0.054054055]);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_hypot() {
+        let lhs = TestAutodiffTensor::<1>::from([3.0, -1.0, 2.0]).require_grad();
+        let rhs = TestAutodiffTensor::<1>::from([4.0, 2.0, -0.5]).require_grad();
+
+        let grads = lhs.clone().hypot(rhs.clone()).sum().backward();
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0.6, -0.4472136, 0.9701425]);
+        grad_lhs
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+
+        let expected = TensorData::from([0.8, 0.8944272, -0.24253562]);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
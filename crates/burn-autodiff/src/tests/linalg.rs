@@ -0,0 +1,230 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, linalg, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[4.0, 2.0], [2.0, 5.0]], &device).require_grad();
+
+        let tensor_2 = linalg::cholesky(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.1875, 0.125], [0.125, 0.25]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_qr_reconstruction() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<3>::from_data(
+            [
+                [[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]],
+                [[2.0, -1.0], [0.5, 1.0], [1.0, 3.0]],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let (q, r) = linalg::qr(tensor_1.clone());
+        let grads = q.matmul(r).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                [[1.0, 1.0], [1.0, 1.0], [1.0, 1.0]],
+                [[1.0, 1.0], [1.0, 1.0], [1.0, 1.0]],
+            ]),
+            Tolerance::rel_abs(1e-3, 1e-3),
+        );
+    }
+
+    #[test]
+    fn should_diff_svd_singular_values() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]], &device)
+                .require_grad();
+
+        let (_u, s, _vh) = linalg::svd(tensor_1.clone());
+        let grads = s.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // The gradient of the nuclear norm is U Vh.
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                [2.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0],
+                [1.0 / 3.0, 2.0 / 3.0, -2.0 / 3.0],
+            ]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_svd_reconstruction() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]], &device)
+                .require_grad();
+        let weights =
+            TestAutodiffTensor::<2>::from_data([[1.0, -2.0, 0.5], [3.0, 0.0, -1.0]], &device);
+
+        let (u, s, vh) = linalg::svd(tensor_1.clone());
+        let grads = u.mul(s).matmul(vh).mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, -2.0, 0.5], [3.0, 0.0, -1.0]]),
+            Tolerance::rel_abs(1e-3, 1e-3),
+        );
+    }
+
+    #[test]
+    fn should_diff_eigh_values() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 2.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data([[1.0, 2.0]], &device);
+
+        let (values, _vectors) = linalg::eigh(tensor_1.clone());
+        let grads = values.mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.5, 0.5], [0.5, 1.5]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_eigh_reconstruction() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(
+            [[4.0, 1.0, 0.5], [1.0, 3.0, 0.2], [0.5, 0.2, 2.0]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data(
+            [[1.0, 2.0, 0.0], [0.0, -1.0, 3.0], [4.0, 1.0, 1.0]],
+            &device,
+        );
+
+        let (values, vectors) = linalg::eigh(tensor_1.clone());
+        let reconstructed = vectors.clone().mul(values).matmul(vectors.transpose());
+        let grads = reconstructed.mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // The gradient is symmetrized.
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 1.0, 2.0], [1.0, -1.0, 2.0], [2.0, 2.0, 1.0]]),
+            Tolerance::rel_abs(1e-3, 1e-3),
+        );
+    }
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[3.0, 1.0], [1.0, 2.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::<2>::from_data([[9.0], [8.0]], &device).require_grad();
+
+        let tensor_3 = linalg::solve(tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[-0.4, -0.6], [-0.8, -1.2]]),
+            Tolerance::default(),
+        );
+        grad_2
+            .to_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[0.2], [0.4]]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_solve_triangular() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[2.0, 0.0], [3.0, 1.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::<2>::from_data([[4.0], [7.0]], &device).require_grad();
+
+        let tensor_3 = linalg::solve_triangular(tensor_1.clone(), tensor_2.clone(), false);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[2.0, 0.0], [-2.0, -1.0]]),
+            Tolerance::default(),
+        );
+        grad_2
+            .to_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[-1.0], [1.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+        let tensor_2 = linalg::inverse(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.04, -0.08], [-0.03, 0.06]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_det() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let tensor_2 = linalg::det(tensor_1.clone());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[4.0, -3.0], [-2.0, 1.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_slogdet() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let (_sign, logabsdet) = linalg::slogdet(tensor_1.clone());
+        let grads = logabsdet.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[-2.0, 1.5], [1.0, -0.5]]),
+            Tolerance::default(),
+        );
+    }
+}
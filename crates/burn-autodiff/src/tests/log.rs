@@ -30,4 +30,28 @@ mod tests {
             .to_data()
             .assert_approx_eq::<FloatType>(&expected, tolerance);
     }
+
+    #[test]
+    fn should_diff_log2() {
+        let tensor = TestAutodiffTensor::<1>::from([0.5, 1.0, 2.0, 8.0]).require_grad();
+
+        let grads = tensor.clone().log2().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([2.88539, 1.442695, 0.7213475, 0.18033688]);
+        grad.to_data()
+            .assert_approx_eq::<FloatType>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_log10() {
+        let tensor = TestAutodiffTensor::<1>::from([0.5, 1.0, 2.0, 10.0]).require_grad();
+
+        let grads = tensor.clone().log10().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([0.868589, 0.4342945, 0.21714724, 0.04342945]);
+        grad.to_data()
+            .assert_approx_eq::<FloatType>(&expected, Tolerance::default());
+    }
 }
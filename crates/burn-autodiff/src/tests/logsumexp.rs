@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(ad_logsumexp)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_logsumexp() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, 1.0], [0.0, 1.0986123]]),
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data(TensorData::from([[1.0], [2.0]]), &device);
+
+        let output = tensor.clone().logsumexp(1);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.5, 0.5], [0.5, 1.5]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_logsumexp_large_values() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data(
+            TensorData::from([1000.0, 1000.0, f32::NEG_INFINITY]),
+            &device,
+        )
+        .require_grad();
+
+        let output = tensor.clone().logsumexp(0);
+        let grads = output.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.5, 0.5, 0.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_diff_logsumexp_all_negative_infinity() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data(
+            TensorData::from([f32::NEG_INFINITY, f32::NEG_INFINITY]),
+            &device,
+        )
+        .require_grad();
+
+        let output = tensor.clone().logsumexp(0);
+        let grads = output.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data()
+            .assert_eq(&TensorData::from([0.0, 0.0]), false);
+    }
+}
@@ -70,4 +70,45 @@ mod tests {
             .into_data()
             .assert_approx_eq::<FT>(&expected, tolerance);
     }
+
+    #[test]
+    fn should_diff_sum_masked_dim() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, f32::NAN, 3.0], [4.0, 5.0, 6.0]]),
+            &device,
+        )
+        .require_grad();
+        let mask = Tensor::<TestAutodiffBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false, true], [false, true, true]]),
+            &device,
+        );
+        let weights = TestAutodiffTensor::<2>::from_data(TensorData::from([[2.0], [3.0]]), &device);
+
+        let output = tensor.clone().sum_masked_dim(mask, 1);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data()
+            .assert_eq(&TensorData::from([[2.0, 0.0, 2.0], [0.0, 3.0, 3.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_nanmean_dim() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[1.0, f32::NAN, 3.0], [4.0, 5.0, f32::NAN]]),
+            &device,
+        )
+        .require_grad();
+
+        let output = tensor.clone().nanmean_dim(1);
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data()
+            .assert_eq(&TensorData::from([[0.5, 0.0, 0.5], [0.5, 0.5, 0.0]]), false);
+    }
 }
@@ -1,6 +1,7 @@
 #![allow(missing_docs)]
 
 mod abs;
+mod activation_checkpointing;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
@@ -8,12 +9,14 @@ mod aggregation;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
+mod bessel;
 mod bridge;
 mod broadcast;
 mod cat;
 mod ceil;
 mod checkpoint;
 mod complex;
+mod complex_kind;
 mod conv1d;
 mod conv2d;
 mod conv3d;
@@ -22,19 +25,34 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
+mod custom_function;
 mod deform_conv2d;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
+mod fake_quantize;
+mod fft;
 mod flip;
 mod floor;
+mod forward;
+mod gamma;
+mod gather_nd;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod higher_order;
+mod hook;
+mod inverse_hyperbolic;
+mod inverse_trig;
+mod linalg;
 mod log;
 mod log1p;
 mod log_sigmoid;
+mod logsumexp;
 mod mask;
 mod matmul;
 mod maxmin;
@@ -46,6 +64,7 @@ mod multithread;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
+mod order_statistics;
 mod permute;
 mod pow;
 mod recip;
@@ -159,6 +178,11 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_bridge!();
         burn_autodiff::testgen_checkpoint!();
         burn_autodiff::testgen_memory_management!();
+        burn_autodiff::testgen_ad_higher_order!();
+        burn_autodiff::testgen_ad_hook!();
+        burn_autodiff::testgen_ad_forward!();
+        burn_autodiff::testgen_ad_custom_function!();
+        burn_autodiff::testgen_ad_activation_checkpointing!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -181,6 +205,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
+        burn_autodiff::testgen_ad_grid_sample!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -191,15 +216,28 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
         burn_autodiff::testgen_ad_div!();
+        burn_autodiff::testgen_ad_einsum!();
+        burn_autodiff::testgen_ad_linalg!();
+        burn_autodiff::testgen_ad_complex_kind!();
+        burn_autodiff::testgen_ad_fft!();
+        burn_autodiff::testgen_ad_order_statistics!();
         burn_autodiff::testgen_ad_remainder!();
         burn_autodiff::testgen_ad_erf!();
+        burn_autodiff::testgen_ad_inverse_trig!();
+        burn_autodiff::testgen_ad_inverse_hyperbolic!();
+        burn_autodiff::testgen_ad_gamma!();
+        burn_autodiff::testgen_ad_bessel!();
         burn_autodiff::testgen_ad_exp!();
+        burn_autodiff::testgen_ad_fake_quantize!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
+        burn_autodiff::testgen_ad_gather_nd!();
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
+        burn_autodiff::testgen_ad_logsumexp!();
         burn_autodiff::testgen_ad_mask!();
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(ad_order_statistics)]
+mod tests {
+    use super::*;
+    use burn_tensor::{QuantileInterpolation, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_quantile() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            [[1.0, 4.0, 2.0, 3.0, 5.0], [0.5, -1.0, 7.0, 2.0, 3.0]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data([[2.0], [-1.0]], &device);
+
+        let output = tensor
+            .clone()
+            .quantile(0.3, 1, QuantileInterpolation::Linear);
+        let grads = (output * weights).sum().backward();
+
+        // The quantile interpolates between the second and third smallest elements.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.0, 0.0, 1.6, 0.4, 0.0], [-0.8, 0.0, 0.0, -0.2, 0.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_median_and_kthvalue() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[3.0, 1.0, 2.0], [-1.0, 5.0, 0.0]], &device)
+                .require_grad();
+
+        let median = tensor.clone().median(1);
+        let kthvalue = tensor.clone().kthvalue(3, 1);
+        let grads = (median.mul_scalar(2.0) + kthvalue).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 0.0, 2.0], [0.0, 1.0, 2.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_nanmedian() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            [[1.0, f32::NAN, 3.0, 2.0], [f32::NAN, 4.0, f32::NAN, -1.0]],
+            &device,
+        )
+        .require_grad();
+
+        let grads = tensor.clone().nanmedian(1).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, 1.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_diff_mode() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 5.0, 1.0], [4.0, 4.0, 4.0]], &device)
+                .require_grad();
+
+        let grads = tensor.clone().mode(1).sum().backward();
+
+        // The gradient flows to a single occurrence of the mode.
+        let grad = tensor.grad(&grads).unwrap().into_data();
+        let grad = grad.iter::<f32>().collect::<Vec<_>>();
+        assert_eq!(grad[..3], [0.0, 0.0, 1.0]);
+        assert_eq!(grad[3..].iter().sum::<f32>(), 1.0);
+    }
+}
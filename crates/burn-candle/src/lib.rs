@@ -48,6 +48,7 @@ mod tests {
     burn_tensor::testgen_module_forward!();
     burn_tensor::testgen_module_conv1d!();
     burn_tensor::testgen_module_nearest_interpolate!();
+    burn_tensor::testgen_module_grid_sample!();
     // burn_tensor::testgen_module_conv2d!();
     // burn_tensor::testgen_module_conv_transpose1d!();
     // burn_tensor::testgen_module_conv_transpose2d!();
@@ -70,16 +71,21 @@ mod tests {
     burn_tensor::testgen_recip!();
     burn_tensor::testgen_clamp!();
     burn_tensor::testgen_cos!();
+    burn_tensor::testgen_complex!();
+    burn_tensor::testgen_cumulative!();
     burn_tensor::testgen_close!();
     // burn_tensor::testgen_div!();
+    burn_tensor::testgen_einsum!();
     burn_tensor::testgen_erf!();
     burn_tensor::testgen_exp!();
     burn_tensor::testgen_flatten!();
     burn_tensor::testgen_full!();
     burn_tensor::testgen_gather_scatter!();
+    burn_tensor::testgen_gather_nd!();
     burn_tensor::testgen_init!();
     burn_tensor::testgen_log!();
     burn_tensor::testgen_log1p!();
+    burn_tensor::testgen_logsumexp!();
     burn_tensor::testgen_map_comparison!();
     burn_tensor::testgen_mask!();
     burn_tensor::testgen_matmul!();
@@ -91,8 +97,12 @@ mod tests {
     // burn_tensor::testgen_remainder!();
     burn_tensor::testgen_flip!();
     burn_tensor::testgen_argwhere_nonzero!();
+    burn_tensor::testgen_unique!();
+    burn_tensor::testgen_searchsorted!();
+    burn_tensor::testgen_bincount!();
     burn_tensor::testgen_sign!();
     burn_tensor::testgen_nan!();
+    burn_tensor::testgen_nan_reduce!();
     burn_tensor::testgen_inf!();
     burn_tensor::testgen_finite!();
     burn_tensor::testgen_round!();
@@ -105,6 +115,7 @@ mod tests {
     // burn_tensor::testgen_powf!();
 
     burn_tensor::testgen_random!();
+    burn_tensor::testgen_multinomial!();
     burn_tensor::testgen_repeat_dim!();
     burn_tensor::testgen_reshape!();
     burn_tensor::testgen_select!();
@@ -121,6 +132,27 @@ mod tests {
     // test stats
     burn_tensor::testgen_var!();
     burn_tensor::testgen_display!();
+    burn_tensor::testgen_quantile!();
+    burn_tensor::testgen_median!();
+    burn_tensor::testgen_mode!();
+    burn_tensor::testgen_kthvalue!();
+    burn_tensor::testgen_histc!();
+
+    // test linalg
+    burn_tensor::testgen_cholesky!();
+    burn_tensor::testgen_qr!();
+    burn_tensor::testgen_svd!();
+    burn_tensor::testgen_eigh!();
+    burn_tensor::testgen_inverse!();
+    burn_tensor::testgen_det!();
+    burn_tensor::testgen_solve!();
+    burn_tensor::testgen_lstsq!();
+
+    // test signal
+    burn_tensor::testgen_fft!();
+    burn_tensor::testgen_stft!();
+    burn_tensor::testgen_window!();
+    burn_tensor::testgen_mel_weight_matrix!();
 
     // Behavior
     // burn_autodiff::testgen_ad_broadcast!();
@@ -128,6 +160,10 @@ mod tests {
     // Activation
     burn_autodiff::testgen_ad_relu!();
     burn_autodiff::testgen_ad_gelu!();
+    burn_autodiff::testgen_ad_linalg!();
+    burn_autodiff::testgen_ad_complex_kind!();
+    burn_autodiff::testgen_ad_fft!();
+    burn_autodiff::testgen_ad_order_statistics!();
 
     // Modules
     // burn_autodiff::testgen_ad_conv1d!();
@@ -141,6 +177,7 @@ mod tests {
     // burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
     // burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
     burn_autodiff::testgen_module_backward!();
+    burn_autodiff::testgen_ad_grid_sample!();
 
     // Tensor
     burn_autodiff::testgen_ad_complex!();
@@ -156,9 +193,11 @@ mod tests {
     burn_autodiff::testgen_ad_exp!();
     burn_autodiff::testgen_ad_slice!();
     burn_autodiff::testgen_ad_gather_scatter!();
+    burn_autodiff::testgen_ad_gather_nd!();
     burn_autodiff::testgen_ad_select!();
     burn_autodiff::testgen_ad_log!();
     burn_autodiff::testgen_ad_log1p!();
+    burn_autodiff::testgen_ad_logsumexp!();
     burn_autodiff::testgen_ad_mask!();
     burn_autodiff::testgen_ad_matmul!();
     burn_autodiff::testgen_ad_mul!();
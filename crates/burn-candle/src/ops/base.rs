@@ -58,7 +58,8 @@ pub fn permute(tensor: CandleTensor, axes: &[usize]) -> CandleTensor {
 
 pub fn flip(tensor: CandleTensor, axes: &[usize]) -> CandleTensor {
     // FIXME: Replace with an appropriate method when Candle provides one.
-    let mut tensor = tensor.tensor;
+    // Index select only supports contiguous tensors.
+    let mut tensor = tensor.tensor.contiguous().unwrap();
     for &axis in axes {
         let indexes = candle_core::Tensor::arange_step(
             tensor.dim(axis).unwrap() as i64 - 1,
@@ -0,0 +1,8 @@
+use burn_tensor::ops::ComplexOps;
+
+use crate::{
+    Candle,
+    element::{FloatCandleElement, IntCandleElement},
+};
+
+impl<F: FloatCandleElement, I: IntCandleElement> ComplexOps<Self> for Candle<F, I> {}
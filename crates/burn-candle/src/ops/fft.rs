@@ -0,0 +1,8 @@
+use burn_tensor::ops::FftOps;
+
+use crate::{
+    Candle,
+    element::{FloatCandleElement, IntCandleElement},
+};
+
+impl<F: FloatCandleElement, I: IntCandleElement> FftOps<Self> for Candle<F, I> {}
@@ -1,11 +1,12 @@
 use burn_common::future::DynFut;
+use burn_common::rand::get_seeded_rng;
 use burn_tensor::{
     Bool, Device, Distribution, ElementConversion, Shape, TensorData,
     ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
 };
 
 use crate::{
-    Candle, CandleTensor,
+    Candle, CandleDevice, CandleTensor,
     element::{CandleElement, FloatCandleElement, IntCandleElement},
 };
 
@@ -96,12 +97,9 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         indices: IntTensor<Self>,
         value: IntTensor<Self>,
     ) -> IntTensor<Self> {
-        CandleTensor::new(
-            tensor
-                .tensor
-                .scatter_add(&indices.tensor, &value.tensor, dim)
-                .unwrap(),
-        )
+        let indices = indices.tensor.contiguous().unwrap();
+        let value = value.tensor.contiguous().unwrap();
+        CandleTensor::new(tensor.tensor.scatter_add(&indices, &value, dim).unwrap())
     }
 
     fn int_select(
@@ -353,6 +351,12 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            // Candle has no sampler for the other distributions, which are sampled on the host.
+            distribution => {
+                let data =
+                    TensorData::random::<I, _, _>(shape, distribution, &mut get_seeded_rng());
+                Self::int_from_data(data, &CandleDevice::from(device.clone()))
+            }
         }
     }
 
@@ -0,0 +1,8 @@
+use burn_tensor::ops::LinalgOps;
+
+use crate::{
+    Candle,
+    element::{FloatCandleElement, IntCandleElement},
+};
+
+impl<F: FloatCandleElement, I: IntCandleElement> LinalgOps<Self> for Candle<F, I> {}
@@ -2,7 +2,10 @@ mod activation;
 mod base;
 mod bool_tensor;
 mod candle_utils;
+mod complex;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 mod tensor;
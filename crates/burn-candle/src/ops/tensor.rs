@@ -1,5 +1,6 @@
 use std::borrow::Borrow;
 
+use burn_common::rand::get_seeded_rng;
 use burn_tensor::{
     Device, Distribution, ElementConversion, FloatDType, Shape, TensorData,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
@@ -8,7 +9,7 @@ use candle_core::{Tensor, backend::BackendStorage, shape};
 use half::{bf16, f16};
 
 use crate::{
-    Candle, CandleTensor,
+    Candle, CandleDevice, CandleTensor,
     element::{CandleElement, FloatCandleElement, IntCandleElement},
 };
 
@@ -56,6 +57,12 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            // Candle has no sampler for the other distributions, which are sampled on the host.
+            distribution => {
+                let data =
+                    TensorData::random::<F, _, _>(shape, distribution, &mut get_seeded_rng());
+                Self::float_from_data(data, &CandleDevice::from(device.clone()))
+            }
         }
     }
 
@@ -173,12 +180,9 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         indices: IntTensor<Self>,
         value: FloatTensor<Self>,
     ) -> FloatTensor<Self> {
-        CandleTensor::new(
-            tensor
-                .tensor
-                .scatter_add(&indices.tensor, &value.tensor, dim)
-                .unwrap(),
-        )
+        let indices = indices.tensor.contiguous().unwrap();
+        let value = value.tensor.contiguous().unwrap();
+        CandleTensor::new(tensor.tensor.scatter_add(&indices, &value, dim).unwrap())
     }
 
     fn float_select(
@@ -313,6 +317,10 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         CandleTensor::new(tensor.tensor.sum_keepdim(dim).unwrap())
     }
 
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        CandleTensor::new(tensor.tensor.cumsum(dim).unwrap())
+    }
+
     fn float_mean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
         CandleTensor::new(tensor.tensor.mean_keepdim(dim).unwrap())
     }
@@ -469,6 +477,9 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
             FloatDType::F32 => candle_core::DType::F32,
             FloatDType::F16 => candle_core::DType::F16,
             FloatDType::BF16 => candle_core::DType::BF16,
+            FloatDType::E4M3 | FloatDType::E5M2 => {
+                panic!("Candle backend does not support {dtype:?} tensors")
+            }
         };
 
         if tensor.tensor.dtype() == dtype {
@@ -0,0 +1,348 @@
+use crate::module::{
+    AutodiffModule, Content, Module, ModuleDisplay, ModuleDisplayDefault, ModuleMapper,
+    ModuleVisitor, ParamId,
+};
+
+use alloc::{vec, vec::Vec};
+
+use burn_common::stub::Mutex;
+use burn_tensor::{
+    Tensor, TensorPrimitive,
+    backend::{AutodiffBackend, Backend},
+    ops::{Device, FloatTensor},
+};
+
+/// Wraps a module to recompute its forward pass during the backward pass instead of keeping its
+/// intermediate activations in memory, like `torch.utils.checkpoint`.
+///
+/// Only the inputs and the parameters of the module are kept for the backward pass, which saves
+/// the memory of the activations of large blocks, such as transformer layers, at the cost of a
+/// second forward pass. Checkpointing only applies to autodiff backends: with other backends, e.g.
+/// during inference, call the wrapped module directly.
+///
+/// # Notes
+///
+/// The forward pass is computed twice when the gradients are required, so it should not depend on
+/// randomness, e.g. dropout, or update a state, e.g. the running statistics of a batch norm.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let block = Checkpointed::new(TransformerEncoderLayer::new(...));
+/// let output = block.forward(input, |block, input| block.forward(input));
+///
+/// // Several inputs, possibly of different ranks, are given as a tuple.
+/// let output = block.forward((input, mask), |block, (input, mask)| block.forward(input, mask));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Checkpointed<M> {
+    /// The wrapped module.
+    pub module: M,
+}
+
+impl<M> Checkpointed<M> {
+    /// Wraps the module.
+    pub fn new(module: M) -> Self {
+        Self { module }
+    }
+
+    /// Calls the function with the wrapped module and the inputs, recomputing it during the
+    /// backward pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The inputs of the module, e.g. a tensor, an array of tensors of the same rank
+    ///   or a tuple of tensors of different ranks.
+    /// * `func` - The forward pass of the module, which can be called again with the same inputs.
+    ///
+    /// # Returns
+    ///
+    /// The output of the function.
+    pub fn forward<B, I, F, const D2: usize>(&self, inputs: I, func: F) -> Tensor<B, D2>
+    where
+        B: AutodiffBackend,
+        M: Module<B> + 'static,
+        I: CheckpointInputs<B>,
+        F: Fn(&M, I) -> Tensor<B, D2> + Send + Sync + 'static,
+    {
+        let mut collector = ParamCollector::<B> {
+            tensors: Vec::new(),
+        };
+        self.module.visit(&mut collector);
+
+        // The module is rebuilt with the tensors given by the backend, which may not be the ones
+        // of the module, e.g. when they are tracked again for the backward pass.
+        let module = Mutex::new(self.module.clone());
+        let output = B::checkpoint(
+            inputs.into_primitives(),
+            collector.tensors,
+            move |inputs, parameters| {
+                let module = module.lock().unwrap().clone();
+                let module = module.map(&mut ParamReplacer::<B> {
+                    tensors: parameters.into_iter(),
+                });
+
+                func(&module, I::from_primitives(inputs))
+                    .into_primitive()
+                    .tensor()
+            },
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(output))
+    }
+}
+
+/// The inputs of a [checkpointed](Checkpointed) module.
+///
+/// Implemented for tensors, arrays of tensors of the same rank and tuples of up to 6 tensors of
+/// different ranks.
+pub trait CheckpointInputs<B: Backend>: Sized {
+    /// Returns the float tensors of the inputs.
+    fn into_primitives(self) -> Vec<FloatTensor<B>>;
+
+    /// Creates the inputs from their float tensors, in the order of
+    /// [into_primitives](CheckpointInputs::into_primitives).
+    fn from_primitives(tensors: Vec<FloatTensor<B>>) -> Self;
+}
+
+impl<B: Backend, const D: usize> CheckpointInputs<B> for Tensor<B, D> {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        vec![self.into_primitive().tensor()]
+    }
+
+    fn from_primitives(mut tensors: Vec<FloatTensor<B>>) -> Self {
+        Tensor::from_primitive(TensorPrimitive::Float(tensors.remove(0)))
+    }
+}
+
+impl<B: Backend, const D: usize, const N: usize> CheckpointInputs<B> for [Tensor<B, D>; N] {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        self.into_iter()
+            .map(|tensor| tensor.into_primitive().tensor())
+            .collect()
+    }
+
+    fn from_primitives(tensors: Vec<FloatTensor<B>>) -> Self {
+        let mut tensors = tensors.into_iter();
+
+        core::array::from_fn(|_| {
+            let tensor = tensors.next().expect("Should have a tensor for each input");
+            Tensor::from_primitive(TensorPrimitive::Float(tensor))
+        })
+    }
+}
+
+macro_rules! impl_checkpoint_inputs_tuple {
+    ($($index:tt: $rank:ident),+) => {
+        impl<B: Backend, $(const $rank: usize),+> CheckpointInputs<B> for ($(Tensor<B, $rank>,)+) {
+            fn into_primitives(self) -> Vec<FloatTensor<B>> {
+                vec![$(self.$index.into_primitive().tensor()),+]
+            }
+
+            fn from_primitives(tensors: Vec<FloatTensor<B>>) -> Self {
+                let mut tensors = tensors.into_iter();
+
+                ($(
+                    Tensor::<B, $rank>::from_primitive(TensorPrimitive::Float(
+                        tensors.next().expect("Should have a tensor for each input"),
+                    )),
+                )+)
+            }
+        }
+    };
+}
+
+impl_checkpoint_inputs_tuple!(0: D1);
+impl_checkpoint_inputs_tuple!(0: D1, 1: D2);
+impl_checkpoint_inputs_tuple!(0: D1, 1: D2, 2: D3);
+impl_checkpoint_inputs_tuple!(0: D1, 1: D2, 2: D3, 3: D4);
+impl_checkpoint_inputs_tuple!(0: D1, 1: D2, 2: D3, 3: D4, 4: D5);
+impl_checkpoint_inputs_tuple!(0: D1, 1: D2, 2: D3, 3: D4, 4: D5, 5: D6);
+
+/// Collects the float tensors of a module.
+struct ParamCollector<B: Backend> {
+    tensors: Vec<FloatTensor<B>>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamCollector<B> {
+    fn visit_float<const D: usize>(&mut self, _id: ParamId, tensor: &Tensor<B, D>) {
+        self.tensors.push(tensor.clone().into_primitive().tensor());
+    }
+}
+
+/// Replaces the float tensors of a module, in the order they are visited.
+struct ParamReplacer<B: Backend> {
+    tensors: vec::IntoIter<FloatTensor<B>>,
+}
+
+impl<B: Backend> ModuleMapper<B> for ParamReplacer<B> {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, _tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let tensor = self
+            .tensors
+            .next()
+            .expect("Should have a tensor for each float tensor of the module");
+
+        Tensor::from_primitive(TensorPrimitive::Float(tensor))
+    }
+}
+
+impl<M, B> Module<B> for Checkpointed<M>
+where
+    M: Module<B>,
+    B: Backend,
+{
+    type Record = M::Record;
+
+    fn num_params(&self) -> usize {
+        self.module.num_params()
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.module.visit(visitor)
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        Self::new(self.module.map(mapper))
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self::new(self.module.load_record(record))
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.module.into_record()
+    }
+
+    fn to_device(self, device: &Device<B>) -> Self {
+        Self::new(self.module.to_device(device))
+    }
+
+    fn fork(self, device: &Device<B>) -> Self {
+        Self::new(self.module.fork(device))
+    }
+
+    fn collect_devices(&self, devices: Vec<B::Device>) -> Vec<B::Device> {
+        self.module.collect_devices(devices)
+    }
+}
+
+impl<M: ModuleDisplay> ModuleDisplayDefault for Checkpointed<M> {
+    fn content(&self, content: Content) -> Option<Content> {
+        content
+            .set_top_level_type("Checkpointed")
+            .add("module", &self.module)
+            .optional()
+    }
+}
+
+impl<M: ModuleDisplay> ModuleDisplay for Checkpointed<M> {}
+
+impl<M, B> AutodiffModule<B> for Checkpointed<M>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    type InnerModule = Checkpointed<M::InnerModule>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Checkpointed::new(self.module.valid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend,
+        nn::{Linear, LinearConfig},
+    };
+    use burn_tensor::{Distribution, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    #[test]
+    fn checkpointed_module_should_have_same_gradients() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 3).init(&device);
+        let checkpointed = Checkpointed::new(linear.clone());
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default, &device);
+
+        let x = input.clone().require_grad();
+        let output = linear.forward(x.clone()).tanh();
+        let grads = output.clone().sum().backward();
+
+        let x_checkpointed = input.require_grad();
+        let output_checkpointed = checkpointed
+            .forward(x_checkpointed.clone(), |linear, x| linear.forward(x))
+            .tanh();
+        let grads_checkpointed = output_checkpointed.clone().sum().backward();
+
+        output_checkpointed
+            .into_data()
+            .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+        x_checkpointed
+            .grad(&grads_checkpointed)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&x.grad(&grads).unwrap().into_data(), Tolerance::default());
+        checkpointed
+            .module
+            .weight
+            .grad(&grads_checkpointed)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &linear.weight.grad(&grads).unwrap().into_data(),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn checkpointed_module_should_support_inputs_of_different_ranks() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 3).init(&device);
+        let checkpointed = Checkpointed::new(linear.clone());
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default, &device);
+        let shift = Tensor::<TestAutodiffBackend, 1>::random([3], Distribution::Default, &device);
+
+        let x = input.clone().require_grad();
+        let s = shift.clone().require_grad();
+        let output = (linear.forward(x.clone()) + s.clone().unsqueeze()).tanh();
+        let grads = output.clone().sum().backward();
+
+        let x_checkpointed = input.require_grad();
+        let s_checkpointed = shift.require_grad();
+        let output_checkpointed = checkpointed
+            .forward(
+                (x_checkpointed.clone(), s_checkpointed.clone()),
+                |linear, (x, shift)| linear.forward(x) + shift.unsqueeze(),
+            )
+            .tanh();
+        let grads_checkpointed = output_checkpointed.clone().sum().backward();
+
+        output_checkpointed
+            .into_data()
+            .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+        x_checkpointed
+            .grad(&grads_checkpointed)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&x.grad(&grads).unwrap().into_data(), Tolerance::default());
+        s_checkpointed
+            .grad(&grads_checkpointed)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&s.grad(&grads).unwrap().into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn checkpointed_module_should_delegate_to_module() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 3).init(&device);
+        let checkpointed = Checkpointed::new(linear.clone());
+
+        assert_eq!(checkpointed.num_params(), linear.num_params());
+        assert_eq!(checkpointed.valid().num_params(), linear.num_params());
+    }
+}
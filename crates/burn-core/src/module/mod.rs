@@ -1,4 +1,5 @@
 mod base;
+mod checkpoint;
 mod display;
 mod param;
 mod quantize;
@@ -6,6 +7,7 @@ mod quantize;
 mod reinit;
 
 pub use base::*;
+pub use checkpoint::*;
 pub use display::*;
 pub use param::*;
 pub use quantize::*;
@@ -1,7 +1,8 @@
+use alloc::{collections::BTreeMap, string::String};
 use burn_tensor::{
-    Tensor,
+    DType, ElementConversion, Tensor,
     backend::Backend,
-    quantization::{Calibration, QuantScheme},
+    quantization::{Calibration, CalibrationObserver, QuantScheme, QuantizationParameters},
 };
 
 use crate::module::{ModuleMapper, ParamId};
@@ -21,3 +22,164 @@ impl<B: Backend> ModuleMapper<B> for Quantizer {
         tensor.quantize(&self.scheme, qparams)
     }
 }
+
+/// Quantizes a single parameter of a module with the given quantization parameters (e.g., learned
+/// with quantization-aware training).
+pub struct ParamQuantizer<B: Backend> {
+    id: ParamId,
+    scheme: QuantScheme,
+    qparams: Option<QuantizationParameters<B>>,
+}
+
+impl<B: Backend> ParamQuantizer<B> {
+    /// Create a new quantizer for the parameter with the given id.
+    pub fn new(id: ParamId, scheme: QuantScheme, qparams: QuantizationParameters<B>) -> Self {
+        Self {
+            id,
+            scheme,
+            qparams: Some(qparams),
+        }
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for ParamQuantizer<B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if id != self.id {
+            return tensor;
+        }
+
+        match self.qparams.take() {
+            Some(qparams) => tensor.quantize(&self.scheme, qparams),
+            None => tensor,
+        }
+    }
+}
+
+/// Accumulates the statistics of named activations over calibration batches to compute their
+/// quantization parameters.
+///
+/// Observation is manual: modules don't share a common forward method, so nothing is recorded
+/// automatically. Each activation to quantize must be passed to
+/// [observe](ActivationObserver::observe) while running the forward pass on the calibration data,
+/// where it's identified by name. The statistics of an activation are accumulated over all calls.
+pub struct ActivationObserver<B: Backend> {
+    /// The calibration method used for the activations.
+    pub calibration: Calibration,
+    /// The quantization scheme of the activations.
+    pub scheme: QuantScheme,
+    observers: BTreeMap<String, CalibrationObserver<B>>,
+}
+
+impl<B: Backend> ActivationObserver<B> {
+    /// Create a new activation observer.
+    pub fn new(scheme: QuantScheme, calibration: Calibration) -> Self {
+        Self {
+            calibration,
+            scheme,
+            observers: BTreeMap::new(),
+        }
+    }
+
+    /// Record the values of the activation with the given name.
+    ///
+    /// The activation is accumulated with the values previously recorded under the same name.
+    pub fn observe<const D: usize>(&mut self, name: &str, tensor: &Tensor<B, D>) {
+        self.observers
+            .entry(name.into())
+            .or_insert_with(|| CalibrationObserver::new(self.scheme, self.calibration.clone()))
+            .observe(tensor);
+    }
+
+    /// The names of the observed activations.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.observers.keys().map(String::as_str)
+    }
+
+    /// Compute the quantization parameters of the activation with the given name, if it was
+    /// observed.
+    pub fn q_params(&self, name: &str) -> Option<QuantizationParameters<B>> {
+        self.observers
+            .get(name)
+            .map(CalibrationObserver::compute_q_params)
+    }
+}
+
+/// Compares the outputs of a quantized model against the outputs of the floating point model.
+///
+/// The outputs are accumulated over batches with [update](QuantizationReport::update). The last
+/// dimension of the outputs is treated as the class dimension to compare the top-1 predictions.
+#[derive(Debug, Clone, Default)]
+pub struct QuantizationReport {
+    num_elements: usize,
+    num_predictions: usize,
+    num_mismatches: usize,
+    sum_abs_error: f64,
+    max_abs_error: f64,
+}
+
+impl QuantizationReport {
+    /// Create a new empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accumulate the outputs of a batch, where quantized outputs are dequantized.
+    pub fn update<B: Backend, const D: usize>(
+        &mut self,
+        float_output: Tensor<B, D>,
+        quant_output: Tensor<B, D>,
+    ) {
+        let quant_output = match quant_output.dtype() {
+            DType::QFloat(_) => quant_output.dequantize(),
+            _ => quant_output,
+        };
+        let error = (float_output.clone() - quant_output.clone()).abs();
+        let dim = D - 1;
+
+        self.num_elements += error.shape().num_elements();
+        self.num_predictions += error.shape().num_elements() / error.dims()[dim].max(1);
+        self.sum_abs_error += error.clone().sum().into_scalar().elem::<f64>();
+        self.max_abs_error = self
+            .max_abs_error
+            .max(error.max().into_scalar().elem::<f64>());
+        self.num_mismatches += float_output
+            .argmax(dim)
+            .not_equal(quant_output.argmax(dim))
+            .int()
+            .sum()
+            .into_scalar()
+            .elem::<i64>() as usize;
+    }
+
+    /// The mean absolute error of the quantized outputs.
+    pub fn mean_abs_error(&self) -> f64 {
+        self.sum_abs_error / self.num_elements.max(1) as f64
+    }
+
+    /// The maximum absolute error of the quantized outputs.
+    pub fn max_abs_error(&self) -> f64 {
+        self.max_abs_error
+    }
+
+    /// The fraction of top-1 predictions of the quantized model that differ from the floating
+    /// point model.
+    ///
+    /// This isn't an accuracy delta: no labels are involved, so a disagreement can happen where
+    /// both models are wrong, and the accuracy can drop by less than this rate.
+    pub fn disagreement_rate(&self) -> f64 {
+        self.num_mismatches as f64 / self.num_predictions.max(1) as f64
+    }
+}
+
+impl core::fmt::Display for QuantizationReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Quantization report: top-1 disagreement rate {:.2}% over {} predictions, mean abs error {:.6}, max abs error {:.6}",
+            self.disagreement_rate() * 100.0,
+            self.num_predictions,
+            self.mean_abs_error(),
+            self.max_abs_error()
+        )
+    }
+}
@@ -197,6 +197,18 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        let (weights, context) = self.attention(input);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
+    }
+
+    /// Computes the attention context, which is the input of the output linear layer.
+    pub(crate) fn forward_context(&self, input: MhaInput<B>) -> Tensor<B, 3> {
+        self.attention(input).1
+    }
+
+    fn attention(&self, input: MhaInput<B>) -> (Tensor<B, 4>, Tensor<B, 3>) {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
         let query = self.attention_linear(input.query, &self.query);
@@ -210,9 +222,8 @@ impl<B: Backend> MultiHeadAttention<B> {
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
-        let context = self.output.forward(context);
 
-        MhaOutput { weights, context }
+        (weights, context)
     }
 
     /// Applies the forward pass using a cache.
@@ -19,6 +19,9 @@ pub mod transformer;
 /// Interpolate module
 pub mod interpolate;
 
+/// Quantization module
+pub mod quantization;
+
 mod dropout;
 mod embedding;
 mod gelu;
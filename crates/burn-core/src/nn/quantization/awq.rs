@@ -0,0 +1,202 @@
+use alloc::vec::Vec;
+
+use crate::nn::Linear;
+use crate::tensor::{
+    ElementConversion, Tensor,
+    backend::Backend,
+    quantization::{Calibration, CalibrationRange, QuantScheme, QuantizationParameters},
+};
+
+use super::LinearQuantizer;
+
+/// Quantizes the weights of linear layers with AWQ, from the paper
+/// [AWQ: Activation-aware Weight Quantization for LLM Compression and Acceleration](https://arxiv.org/abs/2306.00978).
+///
+/// The weights of the input features with large activations are scaled up before quantization to
+/// protect them from rounding errors, where the per-channel scales are searched on the calibration
+/// inputs. The inverse scales are folded into the operation producing the inputs, so the scaling
+/// is only applied when such an operation exists (e.g., a layer norm).
+///
+/// The range of the weights is also clipped, where the clipping ratio is searched to minimize the
+/// output error of the layer.
+#[derive(Clone, Debug)]
+pub struct Awq {
+    /// The quantization scheme of the weights.
+    pub scheme: QuantScheme,
+    /// The number of points in the grid search of the scaling exponent and of the clipping ratio.
+    pub grid_size: usize,
+    /// The smallest clipping ratio of the weight range.
+    pub min_clip_ratio: f64,
+    /// The maximum number of calibration samples used to evaluate the output error.
+    pub max_samples: usize,
+}
+
+impl Awq {
+    /// Create a new AWQ quantizer with the default search parameters.
+    pub fn new(scheme: QuantScheme) -> Self {
+        Self {
+            scheme,
+            grid_size: 20,
+            min_clip_ratio: 0.5,
+            max_samples: 4096,
+        }
+    }
+
+    fn samples<B: Backend>(&self, inputs: &Tensor<B, 2>) -> Tensor<B, 2> {
+        let [num_samples, _] = inputs.dims();
+        inputs.clone().slice([0..num_samples.min(self.max_samples)])
+    }
+
+    fn quantize_dequantize<B: Backend>(
+        &self,
+        weight: Tensor<B, 2>,
+        qparams: QuantizationParameters<B>,
+    ) -> Tensor<B, 2> {
+        weight.quantize(&self.scheme, qparams).dequantize()
+    }
+}
+
+impl<B: Backend> LinearQuantizer<B> for Awq {
+    fn input_scales(
+        &mut self,
+        linears: &[&Linear<B>],
+        inputs: &Tensor<B, 2>,
+    ) -> Option<Tensor<B, 1>> {
+        let inputs = self.samples(inputs);
+        let [_, d_input] = inputs.dims();
+        let weights = linears
+            .iter()
+            .map(|linear| linear.weight.val())
+            .collect::<Vec<_>>();
+        let reference = inputs.clone().matmul(Tensor::cat(weights.clone(), 1));
+        let activation = inputs.clone().abs().mean_dim(0).reshape([d_input]);
+
+        let mut best: Option<(f64, Tensor<B, 1>)> = None;
+        for step in 0..self.grid_size {
+            let exponent = step as f64 / self.grid_size as f64;
+            let scales = activation.clone().powf_scalar(exponent).clamp_min(1e-4);
+            // Normalize the scales around one to keep the weights in a similar range.
+            let norm = (scales.clone().max() * scales.clone().min()).sqrt();
+            let scales = scales.div(norm);
+
+            let quantized = weights
+                .iter()
+                .map(|weight| {
+                    let weight = weight.clone().mul(scales.clone().reshape([d_input, 1]));
+                    let range = self.scheme.compute_range(&weight, &Calibration::MinMax);
+                    let qparams = self.scheme.compute_q_params(range);
+                    self.quantize_dequantize(weight, qparams)
+                })
+                .collect::<Vec<_>>();
+            let output = inputs
+                .clone()
+                .div(scales.clone().reshape([1, d_input]))
+                .matmul(Tensor::cat(quantized, 1));
+            let error = output_error(output, reference.clone());
+
+            if best.as_ref().is_none_or(|(best, _)| error < *best) {
+                best = Some((error, scales));
+            }
+        }
+
+        best.map(|(_, scales)| scales)
+    }
+
+    fn quantize_linear(&mut self, linear: Linear<B>, inputs: &Tensor<B, 2>) -> Linear<B> {
+        let inputs = self.samples(inputs);
+        let weight = linear.weight.val();
+        let reference = inputs.clone().matmul(weight.clone());
+        let range = self.scheme.compute_range(&weight, &Calibration::MinMax);
+
+        let mut best: Option<(f64, QuantizationParameters<B>)> = None;
+        for step in 0..=self.grid_size {
+            let ratio = 1.0 - (1.0 - self.min_clip_ratio) * step as f64 / self.grid_size as f64;
+            let qparams = self.scheme.compute_q_params(CalibrationRange {
+                min: range.min.clone().mul_scalar(ratio),
+                max: range.max.clone().mul_scalar(ratio),
+            });
+
+            let output = inputs
+                .clone()
+                .matmul(self.quantize_dequantize(weight.clone(), qparams.clone()));
+            let error = output_error(output, reference.clone());
+
+            if best.as_ref().is_none_or(|(best, _)| error < *best) {
+                best = Some((error, qparams));
+            }
+        }
+
+        let (_, qparams) = best.expect("The clipping grid should not be empty");
+        let scheme = self.scheme;
+
+        Linear {
+            weight: linear
+                .weight
+                .map(|weight| weight.quantize(&scheme, qparams)),
+            bias: linear.bias,
+        }
+    }
+}
+
+/// Mean squared error between the output of a quantized layer and the reference output.
+fn output_error<B: Backend>(output: Tensor<B, 2>, reference: Tensor<B, 2>) -> f64 {
+    (output - reference)
+        .powi_scalar(2)
+        .mean()
+        .into_scalar()
+        .elem::<f64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::module::{Module, Quantizer};
+    use crate::nn::LinearConfig;
+    use crate::tensor::{
+        Distribution,
+        quantization::{QuantInputType, QuantLevel},
+    };
+
+    #[test]
+    fn awq_should_reduce_output_error() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(1))
+            .set_q_type(QuantInputType::QInt4);
+        let linear = LinearConfig::new(32, 16)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        // A few input features with large activations, which AWQ protects.
+        let mut salient = [1.0; 32];
+        salient[3] = 20.0;
+        salient[17] = 10.0;
+        let inputs = Tensor::<TestBackend, 2>::random([256, 32], Distribution::Default, &device)
+            .mul(Tensor::<TestBackend, 1>::from_floats(salient, &device).reshape([1, 32]));
+        let reference = linear.forward(inputs.clone());
+        let error = |output: Tensor<TestBackend, 2>| -> f32 {
+            (output - reference.clone())
+                .powi_scalar(2)
+                .mean()
+                .into_scalar()
+        };
+
+        let rounded = linear.clone().quantize_weights(&mut Quantizer {
+            calibration: Calibration::MinMax,
+            scheme,
+        });
+        let mut awq = Awq::new(scheme);
+        let scales = awq.input_scales(&[&linear], &inputs).unwrap();
+        let inputs_scaled = inputs.clone().div(scales.clone().reshape([1, 32]));
+        let linear_scaled = Linear {
+            weight: linear
+                .weight
+                .map(|weight| weight.mul(scales.reshape([32, 1]))),
+            bias: None,
+        };
+        let quantized = awq.quantize_linear(linear_scaled, &inputs_scaled);
+
+        assert!(error(quantized.forward(inputs_scaled)) < error(rounded.forward(inputs)));
+    }
+}
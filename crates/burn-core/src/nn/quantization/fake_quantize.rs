@@ -0,0 +1,190 @@
+use alloc::format;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::tensor::{
+    Tensor,
+    backend::Backend,
+    quantization::{Calibration, QuantLevel, QuantScheme, QuantizationParameters},
+};
+
+/// Configuration to create a [FakeQuantize](FakeQuantize) module using the
+/// [init function](FakeQuantizeConfig::init).
+#[derive(Config, Debug)]
+pub struct FakeQuantizeConfig {
+    /// The simulated quantization scheme.
+    pub scheme: QuantScheme,
+    /// If the scales and offsets are learned during training.
+    #[config(default = true)]
+    pub learnable: bool,
+}
+
+/// Simulates the quantization of its input during training, with learnable scales and offsets
+/// (LSQ).
+///
+/// Should be created with [FakeQuantizeConfig]. Only per-tensor and per-channel quantization are
+/// supported.
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct FakeQuantize<B: Backend> {
+    /// The scaling factors, with a single value per tensor or per channel.
+    pub scales: Param<Tensor<B, 1>>,
+    /// The zero-point offsets, only used with affine quantization.
+    pub offsets: Option<Param<Tensor<B, 1>>>,
+    /// The simulated quantization scheme.
+    pub scheme: Ignored<QuantScheme>,
+}
+
+impl FakeQuantizeConfig {
+    /// Initialize a new [fake quantization](FakeQuantize) module, where the initial quantization
+    /// parameters are computed from the range of the given tensor (e.g., the weights of a layer or a
+    /// batch of activations).
+    pub fn init<B: Backend, const D: usize>(&self, tensor: &Tensor<B, D>) -> FakeQuantize<B> {
+        let range = self
+            .scheme
+            .compute_range(&tensor.clone().detach(), &Calibration::MinMax);
+        self.init_with_q_params(self.scheme.compute_q_params(range))
+    }
+
+    /// Initialize a new [fake quantization](FakeQuantize) module with the given initial
+    /// quantization parameters (e.g., computed by an
+    /// [activation observer](crate::module::ActivationObserver)).
+    pub fn init_with_q_params<B: Backend>(
+        &self,
+        qparams: QuantizationParameters<B>,
+    ) -> FakeQuantize<B> {
+        assert!(
+            matches!(
+                self.scheme.level,
+                QuantLevel::Tensor | QuantLevel::Channel(_)
+            ),
+            "Fake quantization only supports per-tensor and per-channel quantization, got {:?}",
+            self.scheme.level
+        );
+
+        let scales = Param::from_tensor(qparams.scales.detach()).set_require_grad(self.learnable);
+        let offsets = qparams
+            .offsets
+            .map(|offsets| Param::from_tensor(offsets.detach()).set_require_grad(self.learnable));
+
+        FakeQuantize {
+            scales,
+            offsets,
+            scheme: Ignored(self.scheme),
+        }
+    }
+}
+
+impl<B: Backend> FakeQuantize<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// The values are quantized and immediately dequantized, so the output has the same shape and
+    /// floating point precision as the input.
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = self.params_shape::<D>();
+        let scales = self.scales.val().reshape(shape);
+        let offsets = self
+            .offsets
+            .as_ref()
+            .map(|offsets| offsets.val().reshape(shape));
+
+        input.fake_quantize(&self.scheme, scales, offsets)
+    }
+
+    /// Returns the learned quantization parameters, where the offsets are rounded to integers in
+    /// the quantized range.
+    pub fn q_params(&self) -> QuantizationParameters<B> {
+        let (a, b) = self.scheme.q_type.range(self.scheme.mode);
+
+        QuantizationParameters {
+            scales: self.scales.val().detach(),
+            offsets: self
+                .offsets
+                .as_ref()
+                .map(|offsets| offsets.val().detach().round().clamp(a, b)),
+        }
+    }
+
+    /// Shape of the parameters broadcast to an input tensor of rank `D`.
+    fn params_shape<const D: usize>(&self) -> [usize; D] {
+        let mut shape = [1; D];
+        if let QuantLevel::Channel(axis) = self.scheme.level {
+            shape[axis] = self.scales.dims()[0];
+        }
+        shape
+    }
+}
+
+impl<B: Backend> ModuleDisplay for FakeQuantize<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("level", &format!("{:?}", self.scheme.level))
+            .add("mode", &format!("{:?}", self.scheme.mode))
+            .add("q_type", &format!("{:?}", self.scheme.q_type))
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use crate::tensor::{
+        TensorData, Tolerance,
+        ops::FloatElem,
+        quantization::{QuantInputType, QuantMode},
+    };
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    #[test]
+    fn fake_quantize_should_learn_scales_and_offsets() {
+        let device = Default::default();
+        let weight = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[-1.0, 0.5, 2.0], [0.1, -0.2, 0.3]],
+            &device,
+        );
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(0))
+            .set_mode(QuantMode::Affine)
+            .set_q_type(QuantInputType::QUInt4);
+        let fake_quant = FakeQuantizeConfig::new(scheme).init(&weight);
+
+        let output = fake_quant.forward(weight.clone());
+        let grads = output.sum().backward();
+
+        // The initial parameters cover the range of each channel, so the error is at most half of
+        // the largest scale
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&weight.into_data(), Tolerance::absolute(0.1 + 1e-5));
+        assert_eq!(fake_quant.scales.grad(&grads).unwrap().dims(), [2]);
+        assert!(fake_quant.offsets.as_ref().unwrap().grad(&grads).is_some());
+    }
+
+    #[test]
+    fn fake_quantize_should_not_track_fixed_params() {
+        let device = Default::default();
+        let input =
+            Tensor::<TestAutodiffBackend, 1>::from_floats([-1.0, 0.5, 2.0], &device).require_grad();
+        let fake_quant = FakeQuantizeConfig::new(QuantScheme::default())
+            .with_learnable(false)
+            .init(&input);
+
+        let grads = fake_quant.forward(input.clone()).sum().backward();
+
+        assert!(fake_quant.scales.grad(&grads).is_none());
+        input
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 1.0, 1.0]), false);
+    }
+}
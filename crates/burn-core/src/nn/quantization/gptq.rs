@@ -0,0 +1,343 @@
+use alloc::{vec, vec::Vec};
+
+use crate::nn::Linear;
+use crate::tensor::{
+    Tensor, TensorData,
+    backend::Backend,
+    quantization::{QuantLevel, QuantMode, QuantScheme, QuantizationParameters},
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use super::LinearQuantizer;
+
+/// Quantizes the weights of linear layers with GPTQ, from the paper
+/// [GPTQ: Accurate Post-Training Quantization for Generative Pre-trained Transformers](https://arxiv.org/abs/2210.17323).
+///
+/// The weights are rounded one input feature at a time, and the rounding error is compensated by
+/// updating the weights of the remaining input features with the inverse Hessian of the layer
+/// reconstruction error, computed from the calibration inputs.
+///
+/// The quantization parameters of each group of weights sharing them, as defined by the
+/// [quantization level](QuantLevel), are computed from the updated weights when the first weight of
+/// the group is rounded. With blocks, the groups are rounded after the errors of the previous groups
+/// are compensated, as in GPTQ with grouping. Only integer quantization types are supported.
+#[derive(Clone, Debug)]
+pub struct Gptq {
+    /// The quantization scheme of the weights.
+    pub scheme: QuantScheme,
+    /// The dampening added to the diagonal of the Hessian, relative to its mean diagonal value.
+    pub damp: f64,
+}
+
+impl Gptq {
+    /// Create a new GPTQ quantizer with the default dampening of 1%.
+    pub fn new(scheme: QuantScheme) -> Self {
+        Self { scheme, damp: 0.01 }
+    }
+}
+
+impl<B: Backend> LinearQuantizer<B> for Gptq {
+    fn quantize_linear(&mut self, linear: Linear<B>, inputs: &Tensor<B, 2>) -> Linear<B> {
+        let [num_samples, _] = inputs.dims();
+        let hessian = inputs
+            .clone()
+            .transpose()
+            .matmul(inputs.clone())
+            .mul_scalar(2.0 / num_samples.max(1) as f64);
+
+        let scheme = self.scheme;
+        let damp = self.damp;
+        let weight = linear
+            .weight
+            .map(|weight| gptq_quantize(weight, hessian, &scheme, damp));
+
+        Linear {
+            weight,
+            bias: linear.bias,
+        }
+    }
+}
+
+/// Quantize the weights of shape `[d_input, d_output]` given the Hessian of shape
+/// `[d_input, d_input]`.
+fn gptq_quantize<B: Backend>(
+    weight: Tensor<B, 2>,
+    hessian: Tensor<B, 2>,
+    scheme: &QuantScheme,
+    damp: f64,
+) -> Tensor<B, 2> {
+    assert!(
+        scheme.q_type.is_int(),
+        "GPTQ only supports integer quantization types, got {:?}",
+        scheme.q_type
+    );
+
+    let device = weight.device();
+    let [d_input, d_output] = weight.dims();
+    let (a, b) = scheme.q_type.range(scheme.mode);
+    let (a, b) = (a as f64, b as f64);
+
+    let num_params = match scheme.level {
+        QuantLevel::Tensor => 1,
+        QuantLevel::Block(block_size) => (d_input * d_output).div_ceil(block_size),
+        QuantLevel::Channel(axis) => [d_input, d_output][axis],
+    };
+    let mut qparams: Vec<Option<(f64, f64)>> = vec![None; num_params];
+
+    let mut weights = read(weight);
+    let mut hessian = read(hessian);
+
+    // Input features that are always zero don't contribute to the error.
+    for i in 0..d_input {
+        if hessian[i * d_input + i] == 0.0 {
+            hessian[i * d_input + i] = 1.0;
+            weights[i * d_output..(i + 1) * d_output].fill(0.0);
+        }
+    }
+
+    let inverse = inverse_hessian_cholesky(hessian, d_input, damp);
+
+    for i in 0..d_input {
+        let diag = inverse[i * d_input + i];
+
+        for j in 0..d_output {
+            let index = i * d_output + j;
+            let param = param_index(&scheme.level, index, [i, j]);
+            let (scale, offset) = *qparams[param].get_or_insert_with(|| {
+                let (min, max) = param_range(&scheme.level, param, &weights, [d_input, d_output]);
+                compute_q_params(scheme, min, max, a, b)
+            });
+
+            let value = weights[index];
+            let quantized = ((value / scale).round() + offset).clamp(a, b);
+            let quantized = (quantized - offset) * scale;
+            weights[index] = quantized;
+
+            // Compensate the error on the input features that are not quantized yet.
+            let error = (value - quantized) / diag;
+            for k in i + 1..d_input {
+                weights[k * d_output + j] -= error * inverse[i * d_input + k];
+            }
+        }
+    }
+
+    // Every group has at least one weight, so all the parameters are computed.
+    let (scales, offsets): (Vec<_>, Vec<_>) = qparams
+        .into_iter()
+        .map(|qparams| {
+            let (scale, offset) = qparams.unwrap();
+            (scale as f32, offset as f32)
+        })
+        .unzip();
+    let qparams = QuantizationParameters {
+        scales: Tensor::from_data(TensorData::new(scales, [num_params]), &device),
+        offsets: match scheme.mode {
+            QuantMode::Symmetric => None,
+            QuantMode::Affine => Some(Tensor::from_data(
+                TensorData::new(offsets, [num_params]),
+                &device,
+            )),
+        },
+    };
+
+    let weights = weights.into_iter().map(|w| w as f32).collect::<Vec<_>>();
+    Tensor::<B, 2>::from_data(TensorData::new(weights, [d_input, d_output]), &device)
+        .quantize(scheme, qparams)
+}
+
+/// Range of the current values of the weights sharing the given quantization parameters.
+fn param_range(level: &QuantLevel, param: usize, weights: &[f64], dims: [usize; 2]) -> (f64, f64) {
+    let [d_input, d_output] = dims;
+    let values: Vec<f64> = match level {
+        QuantLevel::Tensor => weights.to_vec(),
+        QuantLevel::Block(block_size) => {
+            let end = ((param + 1) * block_size).min(weights.len());
+            weights[param * block_size..end].to_vec()
+        }
+        QuantLevel::Channel(0) => weights[param * d_output..(param + 1) * d_output].to_vec(),
+        QuantLevel::Channel(_) => (0..d_input)
+            .map(|i| weights[i * d_output + param])
+            .collect(),
+    };
+
+    values
+        .into_iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+/// Scale and offset of integer quantization for the given range, computed as in
+/// [compute_q_params](QuantScheme::compute_q_params).
+fn compute_q_params(scheme: &QuantScheme, min: f64, max: f64, a: f64, b: f64) -> (f64, f64) {
+    // The affine range must include zero so that it is exactly representable.
+    let (alpha, beta) = match scheme.mode {
+        QuantMode::Symmetric => (-min.abs().max(max.abs()), min.abs().max(max.abs())),
+        QuantMode::Affine => (min.min(0.0), max.max(0.0)),
+    };
+
+    // Arbitrary scale for a group of zeros to avoid a division by zero.
+    let scale = (beta - alpha) / (b - a);
+    let scale = if scale == 0.0 { 0.1 } else { scale };
+
+    match scheme.mode {
+        QuantMode::Symmetric => (scale, 0.0),
+        QuantMode::Affine => (scale, (-alpha / scale + a).round().clamp(a, b)),
+    }
+}
+
+/// Index of the quantization parameters of a weight at the given flat index and position.
+fn param_index(level: &QuantLevel, index: usize, position: [usize; 2]) -> usize {
+    match level {
+        QuantLevel::Tensor => 0,
+        QuantLevel::Block(block_size) => index / block_size,
+        QuantLevel::Channel(axis) => position[*axis],
+    }
+}
+
+/// Compute the upper Cholesky factor of the inverse of the dampened Hessian.
+///
+/// The dampening is increased until the Hessian is positive definite.
+fn inverse_hessian_cholesky(hessian: Vec<f64>, n: usize, damp: f64) -> Vec<f64> {
+    let mean_diag = (0..n).map(|i| hessian[i * n + i]).sum::<f64>() / n.max(1) as f64;
+    let mut damp = damp;
+
+    loop {
+        let mut dampened = hessian.clone();
+        for i in 0..n {
+            dampened[i * n + i] += damp * mean_diag;
+        }
+
+        if let Some(lower) = cholesky(&dampened, n) {
+            let inverse = cholesky_inverse(&lower, n);
+            if let Some(lower) = cholesky(&inverse, n) {
+                // Transpose the lower factor into the upper factor.
+                let mut upper = vec![0.0; n * n];
+                for i in 0..n {
+                    for j in 0..=i {
+                        upper[j * n + i] = lower[i * n + j];
+                    }
+                }
+                return upper;
+            }
+        }
+
+        damp *= 10.0;
+        assert!(
+            damp < 1e6,
+            "The Hessian could not be made positive definite, the calibration inputs are degenerate"
+        );
+    }
+}
+
+/// Lower Cholesky factor of a symmetric positive definite matrix, if it exists.
+fn cholesky(matrix: &[f64], n: usize) -> Option<Vec<f64>> {
+    let mut lower = vec![0.0; n * n];
+
+    for i in 0..n {
+        for j in 0..=i {
+            let sum = (0..j)
+                .map(|k| lower[i * n + k] * lower[j * n + k])
+                .sum::<f64>();
+
+            if i == j {
+                let value = matrix[i * n + i] - sum;
+                if value <= 0.0 || !value.is_finite() {
+                    return None;
+                }
+                lower[i * n + i] = value.sqrt();
+            } else {
+                lower[i * n + j] = (matrix[i * n + j] - sum) / lower[j * n + j];
+            }
+        }
+    }
+
+    Some(lower)
+}
+
+/// Inverse of a matrix given its lower Cholesky factor `L`, computed as `L^-T L^-1`.
+fn cholesky_inverse(lower: &[f64], n: usize) -> Vec<f64> {
+    // Invert the lower triangular factor with forward substitution.
+    let mut lower_inv = vec![0.0; n * n];
+    for j in 0..n {
+        lower_inv[j * n + j] = 1.0 / lower[j * n + j];
+        for i in j + 1..n {
+            let sum = (j..i)
+                .map(|k| lower[i * n + k] * lower_inv[k * n + j])
+                .sum::<f64>();
+            lower_inv[i * n + j] = -sum / lower[i * n + i];
+        }
+    }
+
+    let mut inverse = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..=i {
+            let value = (i..n)
+                .map(|k| lower_inv[k * n + i] * lower_inv[k * n + j])
+                .sum::<f64>();
+            inverse[i * n + j] = value;
+            inverse[j * n + i] = value;
+        }
+    }
+
+    inverse
+}
+
+fn read<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Vec<f64> {
+    tensor.into_data().iter::<f64>().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::module::{Module, Quantizer};
+    use crate::nn::LinearConfig;
+    use crate::tensor::{
+        Distribution,
+        quantization::{Calibration, QuantInputType},
+    };
+
+    #[test]
+    fn gptq_should_reduce_output_error() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(1))
+            .set_q_type(QuantInputType::QInt4);
+        let linear = LinearConfig::new(32, 16)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        // Correlated input features, where compensating the rounding errors matters.
+        let inputs = Tensor::<TestBackend, 2>::random([256, 8], Distribution::Default, &device)
+            .matmul(Tensor::random([8, 32], Distribution::Default, &device));
+        let reference = linear.forward(inputs.clone());
+        let error = |linear: Linear<TestBackend>| -> f32 {
+            (linear.forward(inputs.clone()) - reference.clone())
+                .powi_scalar(2)
+                .mean()
+                .into_scalar()
+        };
+
+        let rounded = linear.clone().quantize_weights(&mut Quantizer {
+            calibration: Calibration::MinMax,
+            scheme,
+        });
+        let quantized = Gptq::new(scheme).quantize_linear(linear, &inputs);
+
+        assert!(error(quantized) < error(rounded));
+    }
+
+    #[test]
+    #[should_panic(expected = "GPTQ only supports integer quantization types")]
+    fn gptq_should_panic_with_float_type() {
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_q_type(QuantInputType::E4M3);
+        let linear = LinearConfig::new(4, 2).init::<TestBackend>(&device);
+        let inputs = Tensor::<TestBackend, 2>::ones([8, 4], &device);
+
+        Gptq::new(scheme).quantize_linear(linear, &inputs);
+    }
+}
@@ -0,0 +1,13 @@
+mod awq;
+mod fake_quantize;
+mod gptq;
+mod qat;
+mod static_quant;
+mod weight_only;
+
+pub use awq::*;
+pub use fake_quantize::*;
+pub use gptq::*;
+pub use qat::*;
+pub use static_quant::*;
+pub use weight_only::*;
@@ -0,0 +1,198 @@
+use crate as burn;
+
+use crate::module::{Module, ParamQuantizer};
+use crate::nn::{Linear, conv::Conv2d};
+use crate::tensor::module::{conv2d, linear};
+use crate::tensor::ops::ConvOptions;
+use crate::tensor::{Tensor, backend::Backend};
+
+use super::{FakeQuantize, FakeQuantizeConfig};
+
+/// A [linear](Linear) layer which simulates the quantization of its weights, and optionally of its
+/// input activations, for quantization-aware training.
+///
+/// Once trained, the layer is converted to a linear layer with quantized weights with
+/// [into_quantized](QatLinear::into_quantized).
+#[derive(Module, Debug)]
+pub struct QatLinear<B: Backend> {
+    /// The wrapped linear layer.
+    pub linear: Linear<B>,
+    /// The fake quantization of the weights.
+    pub weight_quant: FakeQuantize<B>,
+    /// The fake quantization of the input activations.
+    pub input_quant: Option<FakeQuantize<B>>,
+}
+
+impl<B: Backend> QatLinear<B> {
+    /// Wrap a linear layer, where the initial quantization parameters of the weights are computed
+    /// from their range.
+    ///
+    /// For per-channel quantization, the output features are on axis 1 of the weights.
+    pub fn new(linear: Linear<B>, config: &FakeQuantizeConfig) -> Self {
+        let weight_quant = config.init(&linear.weight.val());
+
+        Self {
+            linear,
+            weight_quant,
+            input_quant: None,
+        }
+    }
+
+    /// Also simulate the quantization of the input activations.
+    pub fn with_input_quant(mut self, input_quant: FakeQuantize<B>) -> Self {
+        self.input_quant = Some(input_quant);
+        self
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [Linear::forward](Linear::forward) for more information.
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let input = match &self.input_quant {
+            Some(input_quant) => input_quant.forward(input),
+            None => input,
+        };
+
+        linear(
+            input,
+            self.weight_quant.forward(self.linear.weight.val()),
+            self.linear.bias.as_ref().map(|b| b.val()),
+        )
+    }
+
+    /// Convert to a linear layer with weights quantized using the learned quantization parameters.
+    pub fn into_quantized(self) -> Linear<B> {
+        let mut quantizer = ParamQuantizer::new(
+            self.linear.weight.id,
+            *self.weight_quant.scheme,
+            self.weight_quant.q_params(),
+        );
+        self.linear.map(&mut quantizer)
+    }
+}
+
+/// A [2D convolution](Conv2d) layer which simulates the quantization of its weights, and optionally
+/// of its input activations, for quantization-aware training.
+///
+/// Once trained, the layer is converted to a convolution layer with quantized weights with
+/// [into_quantized](QatConv2d::into_quantized).
+#[derive(Module, Debug)]
+pub struct QatConv2d<B: Backend> {
+    /// The wrapped convolution layer.
+    pub conv: Conv2d<B>,
+    /// The fake quantization of the weights.
+    pub weight_quant: FakeQuantize<B>,
+    /// The fake quantization of the input activations.
+    pub input_quant: Option<FakeQuantize<B>>,
+}
+
+impl<B: Backend> QatConv2d<B> {
+    /// Wrap a convolution layer, where the initial quantization parameters of the weights are
+    /// computed from their range.
+    ///
+    /// For per-channel quantization, the output channels are on axis 0 of the weights.
+    pub fn new(conv: Conv2d<B>, config: &FakeQuantizeConfig) -> Self {
+        let weight_quant = config.init(&conv.weight.val());
+
+        Self {
+            conv,
+            weight_quant,
+            input_quant: None,
+        }
+    }
+
+    /// Also simulate the quantization of the input activations.
+    pub fn with_input_quant(mut self, input_quant: FakeQuantize<B>) -> Self {
+        self.input_quant = Some(input_quant);
+        self
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [Conv2d::forward](Conv2d::forward) for more information.
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let input = match &self.input_quant {
+            Some(input_quant) => input_quant.forward(input),
+            None => input,
+        };
+
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding = self.conv.padding.calculate_padding_2d(
+            height_in,
+            width_in,
+            &self.conv.kernel_size,
+            &self.conv.stride,
+        );
+        conv2d(
+            input,
+            self.weight_quant.forward(self.conv.weight.val()),
+            self.conv.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(
+                self.conv.stride,
+                padding,
+                self.conv.dilation,
+                self.conv.groups,
+            ),
+        )
+    }
+
+    /// Convert to a convolution layer with weights quantized using the learned quantization
+    /// parameters.
+    pub fn into_quantized(self) -> Conv2d<B> {
+        let mut quantizer = ParamQuantizer::new(
+            self.conv.weight.id,
+            *self.weight_quant.scheme,
+            self.weight_quant.q_params(),
+        );
+        self.conv.map(&mut quantizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::nn::LinearConfig;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::tensor::quantization::{QuantLevel, QuantScheme};
+    use crate::tensor::{DType, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn qat_linear_should_match_quantized_linear() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_level(QuantLevel::Channel(1));
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&device);
+        let qat = QatLinear::new(linear, &FakeQuantizeConfig::new(scheme));
+        let input = Tensor::<TestBackend, 2>::ones([2, 4], &device);
+
+        let expected = qat.forward(input.clone());
+        let quantized = qat.into_quantized();
+
+        assert!(matches!(quantized.weight.val().dtype(), DType::QFloat(_)));
+        quantized
+            .forward(input)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn qat_conv2d_should_match_quantized_conv2d() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_level(QuantLevel::Channel(0));
+        let conv = Conv2dConfig::new([2, 3], [2, 2]).init::<TestBackend>(&device);
+        let qat = QatConv2d::new(conv, &FakeQuantizeConfig::new(scheme));
+        let input = Tensor::<TestBackend, 4>::ones([1, 2, 3, 3], &device);
+
+        let expected = qat.forward(input.clone());
+        let quantized = qat.into_quantized();
+
+        assert!(matches!(quantized.weight.val().dtype(), DType::QFloat(_)));
+        quantized
+            .forward(input)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+}
@@ -0,0 +1,310 @@
+use alloc::format;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{
+    Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param, ParamQuantizer,
+};
+use crate::nn::{Linear, conv::Conv2d};
+use crate::tensor::module::{quantized_conv2d, quantized_linear};
+use crate::tensor::ops::ConvOptions;
+use crate::tensor::{
+    DType, Tensor,
+    backend::Backend,
+    quantization::{Calibration, QuantScheme, QuantizationParameters},
+};
+
+/// Configuration to create a [StaticQuantize](StaticQuantize) module using the
+/// [init function](StaticQuantizeConfig::init).
+#[derive(Config, Debug)]
+pub struct StaticQuantizeConfig {
+    /// The quantization scheme of the activations.
+    pub scheme: QuantScheme,
+}
+
+/// Quantizes activations with fixed quantization parameters, usually computed from calibration
+/// data with an [activation observer](crate::module::ActivationObserver).
+///
+/// Should be created with [StaticQuantizeConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct StaticQuantize<B: Backend> {
+    /// The scaling factors.
+    pub scales: Param<Tensor<B, 1>>,
+    /// The zero-point offsets, only used with affine quantization.
+    pub offsets: Option<Param<Tensor<B, 1>>>,
+    /// The quantization scheme of the activations.
+    pub scheme: Ignored<QuantScheme>,
+}
+
+impl StaticQuantizeConfig {
+    /// Initialize a new [static quantization](StaticQuantize) module with the given quantization
+    /// parameters.
+    pub fn init<B: Backend>(&self, qparams: QuantizationParameters<B>) -> StaticQuantize<B> {
+        let scales = Param::from_tensor(qparams.scales.detach()).set_require_grad(false);
+        let offsets = qparams
+            .offsets
+            .map(|offsets| Param::from_tensor(offsets.detach()).set_require_grad(false));
+
+        StaticQuantize {
+            scales,
+            offsets,
+            scheme: Ignored(self.scheme),
+        }
+    }
+}
+
+impl<B: Backend> StaticQuantize<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// Floating point inputs are quantized, while inputs that are already quantized (e.g., the
+    /// output of a previous quantized layer) are returned unchanged, since they carry their own
+    /// quantization parameters.
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        match input.dtype() {
+            DType::QFloat(_) => input,
+            _ => input.quantize(&self.scheme, self.q_params()),
+        }
+    }
+
+    /// Returns the quantization parameters.
+    pub fn q_params(&self) -> QuantizationParameters<B> {
+        QuantizationParameters {
+            scales: self.scales.val(),
+            offsets: self.offsets.as_ref().map(|offsets| offsets.val()),
+        }
+    }
+}
+
+impl<B: Backend> ModuleDisplay for StaticQuantize<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("level", &format!("{:?}", self.scheme.level))
+            .add("mode", &format!("{:?}", self.scheme.mode))
+            .add("q_type", &format!("{:?}", self.scheme.q_type))
+            .optional()
+    }
+}
+
+/// A [linear](Linear) layer which runs on quantized inputs with quantized weights, where the
+/// output is requantized with static quantization parameters.
+///
+/// Chaining quantized layers keeps the activations quantized between layers, so only the output
+/// of the model has to be dequantized.
+#[derive(Module, Debug)]
+pub struct QuantizedLinear<B: Backend> {
+    /// The linear layer with quantized weights.
+    pub linear: Linear<B>,
+    /// The quantization of the input activations.
+    pub input_quant: StaticQuantize<B>,
+    /// The requantization of the output activations.
+    pub output_quant: StaticQuantize<B>,
+}
+
+impl<B: Backend> QuantizedLinear<B> {
+    /// Wrap a linear layer, where the weights are quantized with the given scheme unless they are
+    /// already quantized (e.g., with [QatLinear::into_quantized](super::QatLinear::into_quantized)).
+    ///
+    /// For per-channel quantization, the output features are on axis 1 of the weights.
+    pub fn new(
+        linear: Linear<B>,
+        weight_scheme: &QuantScheme,
+        input_quant: StaticQuantize<B>,
+        output_quant: StaticQuantize<B>,
+    ) -> Self {
+        let linear = quantize_weight(linear, weight_scheme, |linear| &linear.weight);
+
+        Self {
+            linear,
+            input_quant,
+            output_quant,
+        }
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [Linear::forward](Linear::forward) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`, quantized
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        quantized_linear(
+            self.input_quant.forward(input),
+            self.linear.weight.val(),
+            self.linear.bias.as_ref().map(|b| b.val()),
+            &self.output_quant.scheme,
+            self.output_quant.q_params(),
+        )
+    }
+}
+
+/// A [2D convolution](Conv2d) layer which runs on quantized inputs with quantized weights, where
+/// the output is requantized with static quantization parameters.
+///
+/// Chaining quantized layers keeps the activations quantized between layers, so only the output
+/// of the model has to be dequantized.
+#[derive(Module, Debug)]
+pub struct QuantizedConv2d<B: Backend> {
+    /// The convolution layer with quantized weights.
+    pub conv: Conv2d<B>,
+    /// The quantization of the input activations.
+    pub input_quant: StaticQuantize<B>,
+    /// The requantization of the output activations.
+    pub output_quant: StaticQuantize<B>,
+}
+
+impl<B: Backend> QuantizedConv2d<B> {
+    /// Wrap a convolution layer, where the weights are quantized with the given scheme unless they
+    /// are already quantized (e.g., with [QatConv2d::into_quantized](super::QatConv2d::into_quantized)).
+    ///
+    /// For per-channel quantization, the output channels are on axis 0 of the weights.
+    pub fn new(
+        conv: Conv2d<B>,
+        weight_scheme: &QuantScheme,
+        input_quant: StaticQuantize<B>,
+        output_quant: StaticQuantize<B>,
+    ) -> Self {
+        let conv = quantize_weight(conv, weight_scheme, |conv| &conv.weight);
+
+        Self {
+            conv,
+            input_quant,
+            output_quant,
+        }
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [Conv2d::forward](Conv2d::forward) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`, quantized
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding = self.conv.padding.calculate_padding_2d(
+            height_in,
+            width_in,
+            &self.conv.kernel_size,
+            &self.conv.stride,
+        );
+        quantized_conv2d(
+            self.input_quant.forward(input),
+            self.conv.weight.val(),
+            self.conv.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(
+                self.conv.stride,
+                padding,
+                self.conv.dilation,
+                self.conv.groups,
+            ),
+            &self.output_quant.scheme,
+            self.output_quant.q_params(),
+        )
+    }
+}
+
+/// Quantize the weight parameter of a layer from its range, unless it is already quantized.
+fn quantize_weight<B: Backend, M: Module<B>, const D: usize>(
+    layer: M,
+    scheme: &QuantScheme,
+    weight: impl Fn(&M) -> &Param<Tensor<B, D>>,
+) -> M {
+    let param = weight(&layer);
+    let tensor = param.val();
+    if let DType::QFloat(_) = tensor.dtype() {
+        return layer;
+    }
+
+    let range = scheme.compute_range(&tensor, &Calibration::MinMax);
+    let mut quantizer = ParamQuantizer::new(param.id, *scheme, scheme.compute_q_params(range));
+    layer.map(&mut quantizer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::module::QuantizationReport;
+    use crate::nn::LinearConfig;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::tensor::quantization::QuantLevel;
+    use crate::tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn static_quant(
+        scheme: QuantScheme,
+        tensor: &Tensor<TestBackend, 2>,
+    ) -> StaticQuantize<TestBackend> {
+        let range = scheme.compute_range(tensor, &Calibration::MinMax);
+        StaticQuantizeConfig::new(scheme).init(scheme.compute_q_params(range))
+    }
+
+    #[test]
+    fn quantized_linear_should_match_linear() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::random(
+            [8, 4],
+            crate::tensor::Distribution::Uniform(-1.0, 1.0),
+            &device,
+        );
+        let expected = linear.forward(input.clone());
+
+        let quantized = QuantizedLinear::new(
+            linear,
+            &scheme.set_level(QuantLevel::Channel(1)),
+            static_quant(scheme, &input),
+            static_quant(scheme, &expected),
+        );
+        let output = quantized.forward(input);
+
+        assert!(matches!(output.dtype(), DType::QFloat(_)));
+        let mut report = QuantizationReport::new();
+        report.update(expected.clone(), output.clone());
+        assert!(report.max_abs_error() < 5e-2);
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(5e-2));
+    }
+
+    #[test]
+    fn quantized_conv2d_should_match_conv2d() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let conv = Conv2dConfig::new([2, 3], [2, 2]).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random(
+            [1, 2, 4, 4],
+            crate::tensor::Distribution::Uniform(-1.0, 1.0),
+            &device,
+        );
+        let expected = conv.forward(input.clone());
+
+        let quantized = QuantizedConv2d::new(
+            conv,
+            &scheme.set_level(QuantLevel::Channel(0)),
+            static_quant(scheme, &input.clone().flatten(0, 2)),
+            static_quant(scheme, &expected.clone().flatten(0, 2)),
+        );
+        let output = quantized.forward(input);
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(5e-2));
+    }
+}
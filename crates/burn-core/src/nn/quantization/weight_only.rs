@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+
+use crate::nn::{
+    LayerNorm, Linear, attention::MultiHeadAttention, transformer::PositionWiseFeedForward,
+};
+use crate::tensor::{Tensor, backend::Backend};
+
+/// A weight-only quantization algorithm for [linear](Linear) layers, which uses the inputs of the
+/// layers observed on calibration data (e.g., [Gptq](super::Gptq) or [Awq](super::Awq)).
+///
+/// The linear layers of a [transformer encoder](crate::nn::transformer::TransformerEncoder) or
+/// [decoder](crate::nn::transformer::TransformerDecoder) are quantized with `quantize_linears`.
+pub trait LinearQuantizer<B: Backend> {
+    /// Compute the per-input-feature scales of linear layers sharing the same inputs of shape
+    /// `[num_samples, d_input]`.
+    ///
+    /// The weights are multiplied by the scales before quantization, while the inputs are divided
+    /// by folding the inverse scales into the operation producing them. Returns `None` when the
+    /// weights are not scaled, which is the default.
+    fn input_scales(
+        &mut self,
+        _linears: &[&Linear<B>],
+        _inputs: &Tensor<B, 2>,
+    ) -> Option<Tensor<B, 1>> {
+        None
+    }
+
+    /// Quantize the weights of a linear layer, given its inputs of shape `[num_samples, d_input]`.
+    fn quantize_linear(&mut self, linear: Linear<B>, inputs: &Tensor<B, 2>) -> Linear<B>;
+}
+
+/// Concatenate the calibration activations of shape `[batch_size, seq_length, d_model]` into a
+/// single tensor of shape `[num_samples, d_model]`.
+pub(crate) fn calibration_samples<B: Backend>(activations: Vec<Tensor<B, 3>>) -> Tensor<B, 2> {
+    Tensor::cat(
+        activations
+            .into_iter()
+            .map(|activation| activation.flatten(0, 1))
+            .collect(),
+        0,
+    )
+}
+
+/// Quantize the query, key, value and output linear layers of a multi-head attention module.
+///
+/// The key and value inputs are the query inputs for self-attention. When the query inputs are
+/// produced by a layer norm, the inverse input scales of the query (and key and value for
+/// self-attention) are folded into it. The inverse input scales of the output are always folded
+/// into the value layer.
+pub(crate) fn quantize_attention<B: Backend, Q: LinearQuantizer<B>>(
+    quantizer: &mut Q,
+    mut mha: MultiHeadAttention<B>,
+    query_inputs: Tensor<B, 2>,
+    key_value_inputs: Option<Tensor<B, 2>>,
+    contexts: Tensor<B, 2>,
+    norm: Option<&mut LayerNorm<B>>,
+) -> MultiHeadAttention<B> {
+    // The output scales are folded into the value layer before it is quantized.
+    let contexts = match quantizer.input_scales(&[&mha.output], &contexts) {
+        Some(scales) => {
+            mha.output = scale_inputs(mha.output, scales.clone());
+            mha.value = unscale_outputs(mha.value, scales.clone());
+            divide_features(contexts, scales)
+        }
+        None => contexts,
+    };
+
+    match key_value_inputs {
+        None => {
+            let [query, key, value] = quantize_group(
+                quantizer,
+                [mha.query, mha.key, mha.value],
+                query_inputs,
+                norm,
+            );
+            (mha.query, mha.key, mha.value) = (query, key, value);
+        }
+        Some(key_value_inputs) => {
+            let [query] = quantize_group(quantizer, [mha.query], query_inputs, norm);
+            let [key, value] =
+                quantize_group(quantizer, [mha.key, mha.value], key_value_inputs, None);
+            (mha.query, mha.key, mha.value) = (query, key, value);
+        }
+    }
+
+    mha.output = quantizer.quantize_linear(mha.output, &contexts);
+    mha
+}
+
+/// Quantize the linear layers of a position-wise feed-forward module, where the inverse input
+/// scales of the inner layer are folded into the layer norm producing its inputs when given.
+pub(crate) fn quantize_pwff<B: Backend, Q: LinearQuantizer<B>>(
+    quantizer: &mut Q,
+    mut pwff: PositionWiseFeedForward<B>,
+    inputs: Tensor<B, 2>,
+    hiddens: Tensor<B, 2>,
+    norm: Option<&mut LayerNorm<B>>,
+) -> PositionWiseFeedForward<B> {
+    let [linear_inner] = quantize_group(quantizer, [pwff.linear_inner], inputs, norm);
+    pwff.linear_inner = linear_inner;
+    pwff.linear_outer = quantizer.quantize_linear(pwff.linear_outer, &hiddens);
+    pwff
+}
+
+/// Quantize linear layers sharing the same inputs, which are scaled only when the inverse scales
+/// can be folded into the layer norm producing the inputs.
+fn quantize_group<B: Backend, Q: LinearQuantizer<B>, const N: usize>(
+    quantizer: &mut Q,
+    linears: [Linear<B>; N],
+    inputs: Tensor<B, 2>,
+    norm: Option<&mut LayerNorm<B>>,
+) -> [Linear<B>; N] {
+    let (linears, inputs) = match norm {
+        Some(norm) => {
+            let scales = quantizer.input_scales(&linears.each_ref(), &inputs);
+            match scales {
+                Some(scales) => {
+                    *norm = unscale_norm(norm.clone(), scales.clone());
+                    (
+                        linears.map(|linear| scale_inputs(linear, scales.clone())),
+                        divide_features(inputs, scales),
+                    )
+                }
+                None => (linears, inputs),
+            }
+        }
+        None => (linears, inputs),
+    };
+
+    linears.map(|linear| quantizer.quantize_linear(linear, &inputs))
+}
+
+/// Multiply the weights of each input feature by its scale.
+fn scale_inputs<B: Backend>(linear: Linear<B>, scales: Tensor<B, 1>) -> Linear<B> {
+    let [d_input] = scales.dims();
+    Linear {
+        weight: linear
+            .weight
+            .map(|weight| weight.mul(scales.reshape([d_input, 1]))),
+        bias: linear.bias,
+    }
+}
+
+/// Divide the weights and bias of each output feature by its scale.
+fn unscale_outputs<B: Backend>(linear: Linear<B>, scales: Tensor<B, 1>) -> Linear<B> {
+    let [d_output] = scales.dims();
+    Linear {
+        weight: linear
+            .weight
+            .map(|weight| weight.div(scales.clone().reshape([1, d_output]))),
+        bias: linear.bias.map(|bias| bias.map(|bias| bias.div(scales))),
+    }
+}
+
+/// Divide the output features of a layer norm by their scale.
+fn unscale_norm<B: Backend>(mut norm: LayerNorm<B>, scales: Tensor<B, 1>) -> LayerNorm<B> {
+    norm.gamma = norm.gamma.map(|gamma| gamma.div(scales.clone()));
+    norm.beta = norm.beta.map(|beta| beta.div(scales));
+    norm
+}
+
+fn divide_features<B: Backend>(inputs: Tensor<B, 2>, scales: Tensor<B, 1>) -> Tensor<B, 2> {
+    let [d_input] = scales.dims();
+    inputs.div(scales.reshape([1, d_input]))
+}
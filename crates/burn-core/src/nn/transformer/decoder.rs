@@ -13,6 +13,7 @@ use crate::{
     nn::{
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
         attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
+        quantization::{LinearQuantizer, calibration_samples, quantize_attention, quantize_pwff},
     },
     tensor::{Tensor, backend::Backend},
 };
@@ -328,6 +329,109 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         input
     }
 
+    fn quantize_linears<Q: LinearQuantizer<B>>(
+        mut self,
+        quantizer: &mut Q,
+        batches: &[TransformerDecoderInput<B>],
+    ) -> Self {
+        let mut self_attn_inputs = Vec::with_capacity(batches.len());
+        let mut self_contexts = Vec::with_capacity(batches.len());
+        let mut cross_attn_inputs = Vec::with_capacity(batches.len());
+        let mut memories = Vec::with_capacity(batches.len());
+        let mut cross_contexts = Vec::with_capacity(batches.len());
+        let mut pwff_inputs = Vec::with_capacity(batches.len());
+        let mut hiddens = Vec::with_capacity(batches.len());
+
+        // Record the inputs of the linear layers, following the forward pass.
+        for input in batches {
+            let x = input.target.clone();
+            let self_attn_input = if self.norm_first {
+                self.norm_3.forward(x.clone())
+            } else {
+                x.clone()
+            };
+
+            let mut input_mhs = MhaInput::self_attn(self_attn_input.clone());
+            if let Some(mask_pad) = &input.target_mask_pad {
+                input_mhs = input_mhs.mask_pad(mask_pad.clone());
+            }
+            if let Some(mask_attn) = &input.target_mask_attn {
+                input_mhs = input_mhs.mask_attn(mask_attn.clone());
+            }
+            let self_context = self.self_attn.forward_context(input_mhs);
+            let residual_path = self.self_attn.output.forward(self_context.clone());
+            let x = x + self.dropout.forward(residual_path);
+
+            // The cross attention input is normalized with both norm first and norm last.
+            let cross_attn_input = self.norm_1.forward(x.clone());
+            let x = if self.norm_first {
+                x
+            } else {
+                cross_attn_input.clone()
+            };
+
+            let mut input_mhs = MhaInput::new(
+                cross_attn_input.clone(),
+                input.memory.clone(),
+                input.memory.clone(),
+            );
+            if let Some(mask_pad) = &input.memory_mask_pad {
+                input_mhs = input_mhs.mask_pad(mask_pad.clone());
+            }
+            if let Some(mask_attn) = &input.memory_mask_attn {
+                input_mhs = input_mhs.mask_attn(mask_attn.clone());
+            }
+            let cross_context = self.cross_attn.forward_context(input_mhs);
+            let residual_path = self.cross_attn.output.forward(cross_context.clone());
+            let x = x + self.dropout.forward(residual_path);
+
+            let pwff_input = self.norm_2.forward(x);
+            let hidden = self.pwff.linear_inner.forward(pwff_input.clone());
+            let hidden = self.pwff.dropout.forward(self.pwff.gelu.forward(hidden));
+
+            self_attn_inputs.push(self_attn_input);
+            self_contexts.push(self_context);
+            cross_attn_inputs.push(cross_attn_input);
+            memories.push(input.memory.clone());
+            cross_contexts.push(cross_context);
+            pwff_inputs.push(pwff_input);
+            hiddens.push(hidden);
+        }
+
+        // With norm last, the layer norms also produce the residual stream, so the inverse input
+        // scales can't be folded into them.
+        let norm = self.norm_first.then_some(&mut self.norm_3);
+        self.self_attn = quantize_attention(
+            quantizer,
+            self.self_attn,
+            calibration_samples(self_attn_inputs),
+            None,
+            calibration_samples(self_contexts),
+            norm,
+        );
+
+        let norm = self.norm_first.then_some(&mut self.norm_1);
+        self.cross_attn = quantize_attention(
+            quantizer,
+            self.cross_attn,
+            calibration_samples(cross_attn_inputs),
+            Some(calibration_samples(memories)),
+            calibration_samples(cross_contexts),
+            norm,
+        );
+
+        let norm = self.norm_first.then_some(&mut self.norm_2);
+        self.pwff = quantize_pwff(
+            quantizer,
+            self.pwff,
+            calibration_samples(pwff_inputs),
+            calibration_samples(hiddens),
+            norm,
+        );
+
+        self
+    }
+
     fn forward_autoregressive_inference(
         &self,
         mut input: TransformerDecoderInput<B>,
@@ -451,6 +555,33 @@ impl<B: Backend> TransformerDecoder<B> {
     pub fn new_autoregressive_cache(&self) -> TransformerDecoderAutoregressiveCache<B> {
         TransformerDecoderAutoregressiveCache::empty(self.layers.len())
     }
+
+    /// Quantize the weights of the linear layers with a weight-only quantization algorithm, such as
+    /// [GPTQ](crate::nn::quantization::Gptq) or [AWQ](crate::nn::quantization::Awq).
+    ///
+    /// The layers are quantized one after the other, where the calibration inputs of a layer are
+    /// the outputs of the previous quantized layers on the calibration batches.
+    pub fn quantize_linears<Q: LinearQuantizer<B>>(
+        mut self,
+        quantizer: &mut Q,
+        mut batches: Vec<TransformerDecoderInput<B>>,
+    ) -> Self {
+        let layers = core::mem::take(&mut self.layers);
+
+        self.layers = layers
+            .into_iter()
+            .map(|layer| {
+                let layer = layer.quantize_linears(quantizer, &batches);
+                batches = batches
+                    .into_iter()
+                    .map(|input| layer.forward(input))
+                    .collect();
+                layer
+            })
+            .collect();
+
+        self
+    }
 }
 
 #[cfg(test)]
@@ -458,6 +589,9 @@ mod tests {
     use burn_tensor::Device;
 
     use super::*;
+    use crate::nn::quantization::Gptq;
+    use crate::tensor::quantization::{QuantInputType, QuantLevel, QuantScheme};
+    use crate::tensor::{DType, Distribution};
     use crate::{TestBackend, nn::attention::generate_autoregressive_mask};
 
     use burn_tensor::{Tolerance, ops::FloatElem};
@@ -526,6 +660,40 @@ mod tests {
             .assert_approx_eq::<FT>(&output_2.into_data(), Tolerance::default());
     }
 
+    #[test]
+    fn quantize_linears_should_load_as_record() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let config = TransformerDecoderConfig::new(8, 16, 2, 2);
+        let decoder = config.init::<TestBackend>(&device);
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(1))
+            .set_q_type(QuantInputType::QInt4);
+        let random = |shape: [usize; 3]| {
+            Tensor::<TestBackend, 3>::random(shape, Distribution::Default, &device)
+        };
+        let batches = (0..2)
+            .map(|_| TransformerDecoderInput::new(random([2, 4, 8]), random([2, 6, 8])))
+            .collect();
+        let [target, memory] = [random([1, 4, 8]), random([1, 6, 8])];
+
+        let quantized = decoder.quantize_linears(&mut Gptq::new(scheme), batches);
+        let expected =
+            quantized.forward(TransformerDecoderInput::new(target.clone(), memory.clone()));
+        let loaded = config
+            .init::<TestBackend>(&device)
+            .load_record(quantized.into_record());
+
+        assert!(matches!(
+            loaded.layers[0].cross_attn.key.weight.val().dtype(),
+            DType::QFloat(_)
+        ));
+        loaded
+            .forward(TransformerDecoderInput::new(target, memory))
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
     #[test]
     fn display() {
         let config = TransformerDecoderConfig::new(2, 4, 2, 3);
@@ -12,6 +12,7 @@ use crate::{
     nn::{
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
         attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
+        quantization::{LinearQuantizer, calibration_samples, quantize_attention, quantize_pwff},
     },
     tensor::{Tensor, backend::Backend},
 };
@@ -201,6 +202,40 @@ impl<B: Backend> TransformerEncoder<B> {
     pub fn new_autoregressive_cache(&self) -> TransformerEncoderAutoregressiveCache<B> {
         TransformerEncoderAutoregressiveCache::empty(self.layers.len())
     }
+
+    /// Quantize the weights of the linear layers with a weight-only quantization algorithm, such as
+    /// [GPTQ](crate::nn::quantization::Gptq) or [AWQ](crate::nn::quantization::Awq).
+    ///
+    /// The layers are quantized one after the other, where the calibration inputs of a layer are
+    /// the outputs of the previous quantized layers on the calibration batches.
+    pub fn quantize_linears<Q: LinearQuantizer<B>>(
+        mut self,
+        quantizer: &mut Q,
+        mut batches: Vec<TransformerEncoderInput<B>>,
+    ) -> Self {
+        let layers = core::mem::take(&mut self.layers);
+
+        self.layers = layers
+            .into_iter()
+            .map(|layer| {
+                let layer = layer.quantize_linears(quantizer, &batches);
+                batches = batches
+                    .into_iter()
+                    .map(|input| TransformerEncoderInput {
+                        tensor: layer.forward(
+                            input.tensor,
+                            input.mask_pad.clone(),
+                            input.mask_attn.clone(),
+                        ),
+                        ..input
+                    })
+                    .collect();
+                layer
+            })
+            .collect();
+
+        self
+    }
 }
 
 /// Transformer encoder layer module.
@@ -290,6 +325,71 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         x
     }
 
+    fn quantize_linears<Q: LinearQuantizer<B>>(
+        mut self,
+        quantizer: &mut Q,
+        batches: &[TransformerEncoderInput<B>],
+    ) -> Self {
+        let mut attn_inputs = Vec::with_capacity(batches.len());
+        let mut contexts = Vec::with_capacity(batches.len());
+        let mut pwff_inputs = Vec::with_capacity(batches.len());
+        let mut hiddens = Vec::with_capacity(batches.len());
+
+        // Record the inputs of the linear layers, following the forward pass.
+        for input in batches {
+            let x = input.tensor.clone();
+            let attn_input = if self.norm_first {
+                self.norm_2.forward(x.clone())
+            } else {
+                x.clone()
+            };
+
+            let mut input_mhs = MhaInput::self_attn(attn_input.clone());
+            if let Some(mask_pad) = &input.mask_pad {
+                input_mhs = input_mhs.mask_pad(mask_pad.clone());
+            }
+            if let Some(mask_attn) = &input.mask_attn {
+                input_mhs = input_mhs.mask_attn(mask_attn.clone());
+            }
+            let context = self.mha.forward_context(input_mhs);
+            let residual_path = self.mha.output.forward(context.clone());
+            let x = x + self.dropout.forward(residual_path);
+
+            // The feed forward input is normalized with both norm first and norm last.
+            let pwff_input = self.norm_1.forward(x);
+            let hidden = self.pwff.linear_inner.forward(pwff_input.clone());
+            let hidden = self.pwff.dropout.forward(self.pwff.gelu.forward(hidden));
+
+            attn_inputs.push(attn_input);
+            contexts.push(context);
+            pwff_inputs.push(pwff_input);
+            hiddens.push(hidden);
+        }
+
+        // With norm last, the layer norms also produce the residual stream, so the inverse input
+        // scales can't be folded into them.
+        let norm = self.norm_first.then_some(&mut self.norm_2);
+        self.mha = quantize_attention(
+            quantizer,
+            self.mha,
+            calibration_samples(attn_inputs),
+            None,
+            calibration_samples(contexts),
+            norm,
+        );
+
+        let norm = self.norm_first.then_some(&mut self.norm_1);
+        self.pwff = quantize_pwff(
+            quantizer,
+            self.pwff,
+            calibration_samples(pwff_inputs),
+            calibration_samples(hiddens),
+            norm,
+        );
+
+        self
+    }
+
     fn forward_autoregressive_inference(
         &self,
         input: Tensor<B, 3>,
@@ -391,7 +491,9 @@ impl<B: Backend> TransformerEncoderAutoregressiveCache<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tensor::Distribution;
+    use crate::nn::quantization::Awq;
+    use crate::tensor::quantization::{QuantInputType, QuantLevel, QuantScheme};
+    use crate::tensor::{DType, Distribution};
     use crate::{TestBackend, nn::attention::generate_autoregressive_mask};
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
@@ -446,6 +548,42 @@ mod tests {
             .assert_approx_eq::<FT>(&output_2.into_data(), Tolerance::permissive());
     }
 
+    #[test]
+    fn quantize_linears_should_load_as_record() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let config = TransformerEncoderConfig::new(8, 16, 2, 2).with_norm_first(true);
+        let encoder = config.init::<TestBackend>(&device);
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Block(8))
+            .set_q_type(QuantInputType::QInt4);
+        let batches = (0..2)
+            .map(|_| {
+                TransformerEncoderInput::new(Tensor::random(
+                    [2, 4, 8],
+                    Distribution::Default,
+                    &device,
+                ))
+            })
+            .collect();
+        let input = Tensor::<TestBackend, 3>::random([1, 4, 8], Distribution::Default, &device);
+
+        let quantized = encoder.quantize_linears(&mut Awq::new(scheme), batches);
+        let expected = quantized.forward(TransformerEncoderInput::new(input.clone()));
+        let loaded = config
+            .init::<TestBackend>(&device)
+            .load_record(quantized.into_record());
+
+        assert!(matches!(
+            loaded.layers[0].pwff.linear_inner.weight.val().dtype(),
+            DType::QFloat(_)
+        ));
+        loaded
+            .forward(TransformerEncoderInput::new(input))
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
     #[test]
     fn display() {
         let config = TransformerEncoderConfig::new(2, 4, 2, 3);
@@ -1,7 +1,9 @@
 use core::marker::PhantomData;
 
 use super::{PrecisionSettings, Record};
-use burn_tensor::{Bool, DType, Element, Int, Tensor, TensorData, backend::Backend};
+use burn_tensor::{
+    Bool, Complex, Complex32, Complex64, DType, Element, Int, Tensor, TensorData, backend::Backend,
+};
 use serde::{Deserialize, Serialize};
 
 use alloc::format;
@@ -25,6 +27,17 @@ where
     Ok(data)
 }
 
+/// Converts complex data to the complex precision matching the float element `E`.
+///
+/// Complex numbers only have single and double precision, so half precision settings store
+/// single precision complex numbers.
+fn convert_complex<E: Element>(data: TensorData) -> TensorData {
+    match E::dtype() {
+        DType::F64 => data.convert::<Complex64>(),
+        _ => data.convert::<Complex32>(),
+    }
+}
+
 /// This struct implements serde to lazily serialize and deserialize a float tensor
 /// using the given [record settings](RecordSettings).
 #[derive(new, Clone, Debug)]
@@ -47,6 +60,14 @@ pub struct BoolTensorSerde {
     data: TensorData,
 }
 
+/// This struct implements serde to lazily serialize and deserialize a complex tensor
+/// using the given [record settings](RecordSettings).
+#[derive(new, Clone, Debug)]
+pub struct ComplexTensorSerde<S: PrecisionSettings> {
+    data: TensorData,
+    _e: PhantomData<S::FloatElem>,
+}
+
 // --- SERDE IMPLEMENTATIONS --- //
 
 impl<S: PrecisionSettings> Serialize for FloatTensorSerde<S> {
@@ -109,6 +130,26 @@ impl<'de> Deserialize<'de> for BoolTensorSerde {
     }
 }
 
+impl<S: PrecisionSettings> Serialize for ComplexTensorSerde<S> {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        self.data.serialize(serializer)
+    }
+}
+
+impl<'de, S: PrecisionSettings> Deserialize<'de> for ComplexTensorSerde<S> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let data = deserialize_data::<Complex64, De>(deserializer)?;
+
+        Ok(Self::new(convert_complex::<S::FloatElem>(data)))
+    }
+}
+
 // --- RECORD IMPLEMENTATIONS --- //
 
 impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
@@ -157,3 +198,15 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
         Tensor::from_data(item.data, device)
     }
 }
+
+impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Complex> {
+    type Item<S: PrecisionSettings> = ComplexTensorSerde<S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        ComplexTensorSerde::new(convert_complex::<S::FloatElem>(self.into_data()))
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Tensor::from_data(item.data, device)
+    }
+}
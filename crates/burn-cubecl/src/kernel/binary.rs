@@ -7,7 +7,7 @@ use cubecl::{
     tensor_line_size_parallel,
 };
 
-use super::into_contiguous;
+use super::{into_contiguous, special};
 
 pub(crate) trait BinaryOpFamily: Send + Sync + 'static {
     type BinaryOp<C: Numeric>: BinaryOp<C>;
@@ -37,6 +37,16 @@ pub(crate) struct PowOp<F: Float> {
     _f: PhantomData<F>,
 }
 
+/// Four-quadrant inverse tangent, following the same casting strategy as [PowOp].
+pub(crate) struct Atan2Op<F: Float> {
+    _f: PhantomData<F>,
+}
+
+/// Euclidean norm of two values, following the same casting strategy as [PowOp].
+pub(crate) struct HypotOp<F: Float> {
+    _f: PhantomData<F>,
+}
+
 impl BinaryOpFamily for AddOp {
     type BinaryOp<C: Numeric> = Self;
 }
@@ -61,6 +71,14 @@ impl<F: Float> BinaryOpFamily for PowOp<F> {
     type BinaryOp<C: Numeric> = Self;
 }
 
+impl<F: Float> BinaryOpFamily for Atan2Op<F> {
+    type BinaryOp<C: Numeric> = Self;
+}
+
+impl<F: Float> BinaryOpFamily for HypotOp<F> {
+    type BinaryOp<C: Numeric> = Self;
+}
+
 impl BinaryOpFamily for AndOp {
     type BinaryOp<C: Numeric> = Self;
 }
@@ -115,6 +133,28 @@ impl<N: Numeric, F: Float> BinaryOp<N> for PowOp<F> {
     }
 }
 
+#[cube]
+impl<N: Numeric, F: Float> BinaryOp<N> for Atan2Op<F> {
+    fn execute(lhs: Line<N>, rhs: Line<N>) -> Line<N> {
+        let lhs = Line::<F>::cast_from(lhs);
+        let rhs = Line::<F>::cast_from(rhs);
+        let out = special::atan2(lhs, rhs);
+
+        Line::cast_from(out)
+    }
+}
+
+#[cube]
+impl<N: Numeric, F: Float> BinaryOp<N> for HypotOp<F> {
+    fn execute(lhs: Line<N>, rhs: Line<N>) -> Line<N> {
+        let lhs = Line::<F>::cast_from(lhs);
+        let rhs = Line::<F>::cast_from(rhs);
+        let out = special::hypot(lhs, rhs);
+
+        Line::cast_from(out)
+    }
+}
+
 #[cube]
 impl<N: Numeric> BinaryOp<N> for AndOp {
     fn execute(lhs: Line<N>, rhs: Line<N>) -> Line<N> {
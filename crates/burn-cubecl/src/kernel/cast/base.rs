@@ -1,4 +1,5 @@
 use crate::{CubeElement, CubeRuntime, tensor::CubeTensor};
+use burn_tensor::DType;
 use cubecl::std::tensor::index_offset_with_layout;
 use cubecl::{calculate_cube_count_elemwise, prelude::*, tensor_vectorization_factor};
 use std::any::TypeId;
@@ -43,6 +44,17 @@ pub fn cast<R: CubeRuntime, EI: CubeElement, EO: CubeElement>(
         );
     }
 
+    cast_primitive::<R, EI, EO>(input, EO::dtype())
+}
+
+/// Cast a tensor to the given primitive type, which has the given data type.
+///
+/// Unlike [cast], the element types aren't required to be [CubeElement], which allows casting to
+/// types that are only supported as storage, such as 8-bit floats.
+pub(crate) fn cast_primitive<R: CubeRuntime, EI: CubePrimitive, EO: CubePrimitive>(
+    input: CubeTensor<R>,
+    dtype: DType,
+) -> CubeTensor<R> {
     // Vectorization is only enabled when the last dimension is contiguous.
     let rank = input.shape.num_dims();
     let vectorization_factor =
@@ -60,17 +72,32 @@ pub fn cast<R: CubeRuntime, EI: CubeElement, EO: CubeElement>(
         input.device.clone(),
         input.shape.clone(),
         handle,
-        EO::dtype(),
+        dtype,
     );
 
-    cast_element::launch::<EI, EO, R>(
-        &client,
-        cube_count,
-        cube_dim,
-        input.as_tensor_arg::<EI>(vectorization_factor),
-        output.as_tensor_arg::<EO>(vectorization_factor),
-        Some(rank as u32),
-    );
+    let input_arg = input.as_handle_ref();
+    let output_arg = output.as_handle_ref();
+
+    unsafe {
+        cast_element::launch::<EI, EO, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            TensorArg::from_raw_parts::<EI>(
+                input_arg.handle,
+                input_arg.strides,
+                input_arg.shape,
+                vectorization_factor,
+            ),
+            TensorArg::from_raw_parts::<EO>(
+                output_arg.handle,
+                output_arg.strides,
+                output_arg.shape,
+                vectorization_factor,
+            ),
+            Some(rank as u32),
+        );
+    }
 
     output
 }
@@ -0,0 +1,40 @@
+use super::cast_primitive;
+use crate::{CubeElement, CubeRuntime, tensor::CubeTensor};
+use burn_common::{e4m3, e5m2};
+use burn_tensor::{DType, FloatDType};
+use cubecl::prelude::*;
+
+/// Cast a float tensor to an 8-bit float type, rounding each value to the nearest one of the
+/// format.
+///
+/// # Panics
+/// When the 8-bit float type isn't supported by the device.
+pub fn cast_to_fp8<R: CubeRuntime, E: CubeElement>(
+    input: CubeTensor<R>,
+    dtype: FloatDType,
+) -> CubeTensor<R> {
+    match dtype {
+        FloatDType::E4M3 => {
+            if !e4m3::is_supported(&input.client) {
+                panic!("E4M3 is not supported by the device");
+            }
+            cast_primitive::<R, E, e4m3>(input, DType::E4M3)
+        }
+        FloatDType::E5M2 => {
+            if !e5m2::is_supported(&input.client) {
+                panic!("E5M2 is not supported by the device");
+            }
+            cast_primitive::<R, E, e5m2>(input, DType::E5M2)
+        }
+        _ => panic!("Expected an 8-bit float type, got {dtype:?}"),
+    }
+}
+
+/// Cast an 8-bit float tensor to the given element type, which represents every value exactly.
+pub fn cast_from_fp8<R: CubeRuntime, E: CubeElement>(input: CubeTensor<R>) -> CubeTensor<R> {
+    match input.dtype {
+        DType::E4M3 => cast_primitive::<R, e4m3, E>(input, E::dtype()),
+        DType::E5M2 => cast_primitive::<R, e5m2, E>(input, E::dtype()),
+        dtype => panic!("Expected an 8-bit float tensor, got {dtype:?}"),
+    }
+}
@@ -1,5 +1,7 @@
 mod base;
 mod bool_cast;
+mod fp8;
 
 pub use base::*;
 pub use bool_cast::*;
+pub use fp8::*;
@@ -71,6 +71,13 @@ fn into_contiguous_quantized<R: CubeRuntime>(tensor: CubeTensor<R>) -> CubeTenso
             &scales.as_handle_ref(),
             &out_scales.as_handle_ref(),
         );
+        if let (Some(offsets), Some(out_offsets)) = (tensor.offsets(), output.offsets()) {
+            cubecl::std::tensor::into_contiguous_ref::<R, E>(
+                &offsets.client,
+                &offsets.as_handle_ref(),
+                &out_offsets.as_handle_ref(),
+            );
+        }
 
         output
     })
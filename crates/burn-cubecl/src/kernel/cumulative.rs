@@ -0,0 +1,256 @@
+use std::marker::PhantomData;
+
+use crate::{
+    CubeRuntime,
+    element::{CubeElement, FloatElement, IntElement},
+    kernel::into_contiguous,
+    ops::numeric::empty_device,
+    tensor::CubeTensor,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+pub(crate) trait CumulativeOpFamily: Send + Sync + 'static {
+    type CumulativeOp<N: Numeric>: CumulativeOp<N>;
+}
+
+#[cube]
+pub(crate) trait CumulativeOp<N: Numeric>: 'static + Send + Sync {
+    /// Combine the running accumulator with the next value along the scanned dimension.
+    fn execute(acc: N, value: N) -> N;
+}
+
+pub(crate) struct CumSumOp;
+pub(crate) struct CumProdOp;
+
+/// Since log-sum-exp only works on float, the input values are cast to the float precision of the
+/// family type, similar to [PowOp](super::PowOp).
+pub(crate) struct LogCumSumExpOp<F: Float> {
+    _f: PhantomData<F>,
+}
+
+impl CumulativeOpFamily for CumSumOp {
+    type CumulativeOp<N: Numeric> = Self;
+}
+
+impl CumulativeOpFamily for CumProdOp {
+    type CumulativeOp<N: Numeric> = Self;
+}
+
+impl<F: Float> CumulativeOpFamily for LogCumSumExpOp<F> {
+    type CumulativeOp<N: Numeric> = Self;
+}
+
+#[cube]
+impl<N: Numeric> CumulativeOp<N> for CumSumOp {
+    fn execute(acc: N, value: N) -> N {
+        acc + value
+    }
+}
+
+#[cube]
+impl<N: Numeric> CumulativeOp<N> for CumProdOp {
+    fn execute(acc: N, value: N) -> N {
+        acc * value
+    }
+}
+
+#[cube]
+impl<N: Numeric, F: Float> CumulativeOp<N> for LogCumSumExpOp<F> {
+    fn execute(acc: N, value: N) -> N {
+        let acc = F::cast_from(acc);
+        let value = F::cast_from(value);
+
+        // log(e^a + e^b) = max(a, b) + log(1 + e^(min(a, b) - max(a, b)))
+        let max = Max::max(acc, value);
+        let min = Min::min(acc, value);
+        let mut diff = min - max;
+
+        // Avoids `inf - inf` when both values are infinite.
+        if max == min {
+            diff = F::new(0.0);
+        }
+
+        N::cast_from(max + Log1p::log1p(Exp::exp(diff)))
+    }
+}
+
+/// Each unit scans a single line of the tensor along `dim`.
+#[cube(launch_unchecked)]
+fn cumulative_kernel<N: Numeric, O: CumulativeOpFamily>(
+    input: &Tensor<N>,
+    output: &mut Tensor<N>,
+    dim: u32,
+) {
+    let stride = input.stride(dim);
+    let size = input.shape(dim);
+
+    if ABSOLUTE_POS >= input.len() / size {
+        terminate!();
+    }
+
+    let offset = (ABSOLUTE_POS / stride) * stride * size + ABSOLUTE_POS % stride;
+    let mut acc = input[offset];
+    output[offset] = acc;
+
+    for i in 1..size {
+        let index = offset + i * stride;
+        acc = O::CumulativeOp::<N>::execute(acc, input[index]);
+        output[index] = acc;
+    }
+}
+
+/// Each unit scans a single line of the tensor along `dim`, keeping track of the position of the
+/// running extremum. Ties select the last occurrence.
+#[cube(launch_unchecked)]
+fn cumulative_extremum_kernel<N: Numeric, I: Int>(
+    input: &Tensor<N>,
+    output: &mut Tensor<N>,
+    indices: &mut Tensor<I>,
+    dim: u32,
+    #[comptime] maximum: bool,
+) {
+    let stride = input.stride(dim);
+    let size = input.shape(dim);
+
+    if ABSOLUTE_POS >= input.len() / size {
+        terminate!();
+    }
+
+    let offset = (ABSOLUTE_POS / stride) * stride * size + ABSOLUTE_POS % stride;
+    let mut acc = input[offset];
+    let mut acc_index = 0;
+    output[offset] = acc;
+    indices[offset] = I::cast_from(acc_index);
+
+    for i in 1..size {
+        let index = offset + i * stride;
+        let value = input[index];
+
+        let mut selected = value <= acc;
+        if comptime![maximum] {
+            selected = value >= acc;
+        }
+
+        if selected {
+            acc = value;
+            acc_index = i;
+        }
+
+        output[index] = acc;
+        indices[index] = I::cast_from(acc_index);
+    }
+}
+
+fn cumulative<R: CubeRuntime, E: CubeElement, O: CumulativeOpFamily>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let output = empty_device::<R, E>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let num_elems = tensor.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems / tensor.shape.dims[dim], cube_dim);
+
+    unsafe {
+        cumulative_kernel::launch_unchecked::<E, O, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+        );
+    }
+
+    output
+}
+
+fn cumulative_extremum<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+    maximum: bool,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let tensor = into_contiguous(tensor);
+    let output = empty_device::<R, E>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+    let indices = empty_device::<R, I>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let num_elems = tensor.shape.num_elements();
+    if num_elems == 0 {
+        return (output, indices);
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems / tensor.shape.dims[dim], cube_dim);
+
+    unsafe {
+        cumulative_extremum_kernel::launch_unchecked::<E, I, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            ScalarArg::new(dim as u32),
+            maximum,
+        );
+    }
+
+    (output, indices)
+}
+
+/// Cumulative sum of the tensor along `dim`.
+pub(crate) fn cumsum<R: CubeRuntime, E: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    cumulative::<R, E, CumSumOp>(tensor, dim)
+}
+
+/// Cumulative product of the tensor along `dim`.
+pub(crate) fn cumprod<R: CubeRuntime, E: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    cumulative::<R, E, CumProdOp>(tensor, dim)
+}
+
+/// Cumulative log-sum-exp of the tensor along `dim`.
+pub(crate) fn logcumsumexp<R: CubeRuntime, E: FloatElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    cumulative::<R, E, LogCumSumExpOp<E>>(tensor, dim)
+}
+
+/// Cumulative maximum of the tensor along `dim` with the indices of the selected elements.
+pub(crate) fn cummax<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    cumulative_extremum::<R, E, I>(tensor, dim, true)
+}
+
+/// Cumulative minimum of the tensor along `dim` with the indices of the selected elements.
+pub(crate) fn cummin<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    cumulative_extremum::<R, E, I>(tensor, dim, false)
+}
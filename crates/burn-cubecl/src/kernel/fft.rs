@@ -0,0 +1,259 @@
+use crate::{
+    CubeRuntime,
+    element::FloatElement,
+    kernel::into_contiguous,
+    ops::{from_data, numeric::empty_device},
+    tensor::CubeTensor,
+};
+use alloc::vec::Vec;
+use burn_tensor::{ElementConversion, Shape, TensorData};
+use core::f64::consts::PI;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Each unit computes a radix-2 butterfly of a Stockham pass, combining two transforms of `span`
+/// elements into a transform of `2 * span` elements.
+///
+/// The Stockham formulation writes the outputs in their final order, so no bit-reversal
+/// permutation is needed, at the cost of alternating between two buffers.
+#[cube(launch_unchecked)]
+fn stockham_kernel<F: Float>(
+    input_real: &Tensor<F>,
+    input_imag: &Tensor<F>,
+    output_real: &mut Tensor<F>,
+    output_imag: &mut Tensor<F>,
+    dim: u32,
+    span: u32,
+    #[comptime] inverse: bool,
+) {
+    if ABSOLUTE_POS >= input_real.len() / 2 {
+        terminate!();
+    }
+
+    let stride = input_real.stride(dim);
+    let size = input_real.shape(dim);
+    let half = size / 2;
+    let line = ABSOLUTE_POS / half;
+    let j = ABSOLUTE_POS % half;
+    // The tensors are contiguous, so the lines are indexed by the dimensions before and after
+    // `dim`.
+    let base = (line / stride) * size * stride + line % stride;
+
+    let k = j % span;
+    let mut angle = F::new(-core::f32::consts::PI) * F::cast_from(k) / F::cast_from(span);
+    if comptime![inverse] {
+        angle = -angle;
+    }
+    let cos = Cos::cos(angle);
+    let sin = Sin::sin(angle);
+
+    let a = base + j * stride;
+    let b = a + half * stride;
+    let a_real = input_real[a];
+    let a_imag = input_imag[a];
+    let b_real = input_real[b] * cos - input_imag[b] * sin;
+    let b_imag = input_real[b] * sin + input_imag[b] * cos;
+
+    let first = base + ((j / span) * span * 2 + k) * stride;
+    let second = first + span * stride;
+    output_real[first] = a_real + b_real;
+    output_imag[first] = a_imag + b_imag;
+    output_real[second] = a_real - b_real;
+    output_imag[second] = a_imag - b_imag;
+}
+
+/// Each unit multiplies an element of the signals by the factor of its position along `dim` and
+/// by `scale`.
+///
+/// The output can be longer or shorter than the input along `dim`: the positions missing from the
+/// input or from the factors are set to zero.
+#[cube(launch_unchecked)]
+fn multiply_kernel<F: Float>(
+    input_real: &Tensor<F>,
+    input_imag: &Tensor<F>,
+    factor_real: &Tensor<F>,
+    factor_imag: &Tensor<F>,
+    output_real: &mut Tensor<F>,
+    output_imag: &mut Tensor<F>,
+    dim: u32,
+    scale: F,
+) {
+    if ABSOLUTE_POS >= output_real.len() {
+        terminate!();
+    }
+
+    let stride = output_real.stride(dim);
+    let size = output_real.shape(dim);
+    let input_size = input_real.shape(dim);
+    let k = (ABSOLUTE_POS / stride) % size;
+
+    let mut real = F::new(0.0);
+    let mut imag = F::new(0.0);
+
+    if k < input_size && k < factor_real.len() {
+        let index =
+            (ABSOLUTE_POS / (size * stride)) * input_size * stride + ABSOLUTE_POS % (size * stride);
+        let value_real = input_real[index];
+        let value_imag = input_imag[index];
+
+        real = (value_real * factor_real[k] - value_imag * factor_imag[k]) * scale;
+        imag = (value_real * factor_imag[k] + value_imag * factor_real[k]) * scale;
+    }
+
+    output_real[ABSOLUTE_POS] = real;
+    output_imag[ABSOLUTE_POS] = imag;
+}
+
+/// Unnormalized discrete Fourier transform of complex signals along `dim`.
+///
+/// Signals whose length is a power of two are transformed with `log2(n)` radix-2 Stockham passes,
+/// in `O(n log n)`. Other lengths use Bluestein's algorithm, which rewrites the transform as a
+/// circular convolution computed with power of two transforms, also in `O(n log n)`.
+pub(crate) fn fft<R: CubeRuntime, E: FloatElement>(
+    real: CubeTensor<R>,
+    imag: CubeTensor<R>,
+    dim: usize,
+    inverse: bool,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let real = into_contiguous(real);
+    let imag = into_contiguous(imag);
+    let size = real.shape.dims[dim];
+
+    if real.shape.num_elements() == 0 || size <= 1 {
+        return (real, imag);
+    }
+
+    match size.is_power_of_two() {
+        true => radix2::<R, E>(real, imag, dim, inverse),
+        false => bluestein::<R, E>(real, imag, dim, inverse),
+    }
+}
+
+/// Transforms contiguous signals whose length along `dim` is a power of two.
+fn radix2<R: CubeRuntime, E: FloatElement>(
+    mut real: CubeTensor<R>,
+    mut imag: CubeTensor<R>,
+    dim: usize,
+    inverse: bool,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let size = real.shape.dims[dim];
+    let cube_dim = CubeDim::default();
+    let mut span = 1;
+
+    while span < size {
+        let output_real =
+            empty_device::<R, E>(real.client.clone(), real.device.clone(), real.shape.clone());
+        let output_imag =
+            empty_device::<R, E>(real.client.clone(), real.device.clone(), real.shape.clone());
+        let cube_count = calculate_cube_count_elemwise(real.shape.num_elements() / 2, cube_dim);
+
+        unsafe {
+            stockham_kernel::launch_unchecked::<E, R>(
+                &real.client,
+                cube_count,
+                cube_dim,
+                real.as_tensor_arg::<E>(1),
+                imag.as_tensor_arg::<E>(1),
+                output_real.as_tensor_arg::<E>(1),
+                output_imag.as_tensor_arg::<E>(1),
+                ScalarArg::new(dim as u32),
+                ScalarArg::new(span as u32),
+                inverse,
+            );
+        }
+
+        real = output_real;
+        imag = output_imag;
+        span *= 2;
+    }
+
+    (real, imag)
+}
+
+/// Transforms contiguous signals of any length along `dim` with Bluestein's algorithm.
+///
+/// The chirp `c[k] = exp(±iπ k² / n)` and its filter are computed on the host in double
+/// precision, since `k²` doesn't fit in 32 bits for long signals.
+fn bluestein<R: CubeRuntime, E: FloatElement>(
+    real: CubeTensor<R>,
+    imag: CubeTensor<R>,
+    dim: usize,
+    inverse: bool,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let n = real.shape.dims[dim];
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    let chirp = (0..n)
+        .map(|k| {
+            let angle = sign * PI * ((k * k) % (2 * n)) as f64 / n as f64;
+            angle.sin_cos()
+        })
+        .collect::<Vec<_>>();
+
+    // The filter is the conjugate of the chirp, wrapped around to make the convolution circular.
+    let mut filter_real = alloc::vec![0.0; m];
+    let mut filter_imag = alloc::vec![0.0; m];
+    for (k, (sin, cos)) in chirp.iter().enumerate() {
+        filter_real[k] = *cos;
+        filter_imag[k] = -sin;
+        if k > 0 {
+            filter_real[m - k] = *cos;
+            filter_imag[m - k] = -sin;
+        }
+    }
+
+    let upload = |values: Vec<f64>| {
+        let shape = Shape::new([values.len()]);
+        let values = values.into_iter().map(E::from_elem).collect::<Vec<_>>();
+        from_data::<R>(TensorData::new(values, shape), &real.device)
+    };
+    let chirp_real = upload(chirp.iter().map(|(_, cos)| *cos).collect());
+    let chirp_imag = upload(chirp.iter().map(|(sin, _)| *sin).collect());
+    let (filter_real, filter_imag) =
+        radix2::<R, E>(upload(filter_real), upload(filter_imag), 0, false);
+
+    let (real, imag) = multiply::<R, E>(real, imag, &chirp_real, &chirp_imag, dim, m, 1.0);
+    let (real, imag) = radix2::<R, E>(real, imag, dim, false);
+    let (real, imag) = multiply::<R, E>(real, imag, &filter_real, &filter_imag, dim, m, 1.0);
+    let (real, imag) = radix2::<R, E>(real, imag, dim, true);
+
+    multiply::<R, E>(real, imag, &chirp_real, &chirp_imag, dim, n, 1.0 / m as f64)
+}
+
+/// Multiplies contiguous signals by the given factors along `dim`, resizing them to `size`.
+fn multiply<R: CubeRuntime, E: FloatElement>(
+    real: CubeTensor<R>,
+    imag: CubeTensor<R>,
+    factor_real: &CubeTensor<R>,
+    factor_imag: &CubeTensor<R>,
+    dim: usize,
+    size: usize,
+    scale: f64,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let mut shape = real.shape.clone();
+    shape.dims[dim] = size;
+
+    let output_real = empty_device::<R, E>(real.client.clone(), real.device.clone(), shape.clone());
+    let output_imag = empty_device::<R, E>(real.client.clone(), real.device.clone(), shape);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output_real.shape.num_elements(), cube_dim);
+
+    unsafe {
+        multiply_kernel::launch_unchecked::<E, R>(
+            &real.client,
+            cube_count,
+            cube_dim,
+            real.as_tensor_arg::<E>(1),
+            imag.as_tensor_arg::<E>(1),
+            factor_real.as_tensor_arg::<E>(1),
+            factor_imag.as_tensor_arg::<E>(1),
+            output_real.as_tensor_arg::<E>(1),
+            output_imag.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(E::from_elem(scale)),
+        );
+    }
+
+    (output_real, output_imag)
+}
@@ -0,0 +1,242 @@
+use burn_tensor::{
+    Shape,
+    ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode},
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    CubeRuntime, element::FloatElement, kernel::into_contiguous, ops::numeric::empty_device,
+    tensor::CubeTensor,
+};
+
+/// The coefficient of the cubic convolution, same as PyTorch.
+const CUBIC_A: f32 = -0.75;
+
+/// An input location used to sample a grid coordinate along a spatial dimension.
+#[derive(CubeType)]
+struct Tap<F: Float> {
+    /// The input index, always in bound.
+    index: u32,
+    /// The weight of the input value, zero when the index was out of bound.
+    weight: F,
+}
+
+/// Each unit samples a single channel of the input at a single grid location.
+#[cube(launch_unchecked)]
+fn grid_sample_kernel<F: Float>(
+    input: &Tensor<F>,
+    grid: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] num_spatial: u32,
+    #[comptime] options: GridSampleOptions,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let num_locations = output.stride(1);
+    let location = ABSOLUTE_POS % num_locations;
+    let channel = ABSOLUTE_POS / num_locations;
+    let batch = channel / output.shape(1);
+
+    let grid_offset = (batch * num_locations + location) * num_spatial;
+    let input_offset = channel * input.stride(1);
+
+    let num_taps = comptime![match options.mode {
+        GridSampleMode::Nearest => 1,
+        GridSampleMode::Bilinear => 2,
+        GridSampleMode::Bicubic => 4,
+    }];
+
+    // The grid coordinates are in the reverse order of the spatial dimensions.
+    let coord_x = grid[grid_offset];
+    let coord_y = grid[grid_offset + 1];
+    let width = input.shape(num_spatial + 1);
+    let height = input.shape(num_spatial);
+
+    let mut value = F::new(0.0);
+
+    if comptime![num_spatial == 2] {
+        #[unroll]
+        for j in 0..num_taps {
+            let tap_y = axis_tap::<F>(coord_y, height, j, options);
+
+            #[unroll]
+            for i in 0..num_taps {
+                let tap_x = axis_tap::<F>(coord_x, width, i, options);
+                let index = input_offset + tap_y.index * input.stride(2) + tap_x.index;
+
+                value += input[index] * tap_y.weight * tap_x.weight;
+            }
+        }
+    } else {
+        let coord_z = grid[grid_offset + 2];
+        let depth = input.shape(2);
+
+        #[unroll]
+        for k in 0..num_taps {
+            let tap_z = axis_tap::<F>(coord_z, depth, k, options);
+
+            #[unroll]
+            for j in 0..num_taps {
+                let tap_y = axis_tap::<F>(coord_y, height, j, options);
+
+                #[unroll]
+                for i in 0..num_taps {
+                    let tap_x = axis_tap::<F>(coord_x, width, i, options);
+                    let index = input_offset
+                        + tap_z.index * input.stride(2)
+                        + tap_y.index * input.stride(3)
+                        + tap_x.index;
+
+                    value += input[index] * tap_z.weight * tap_y.weight * tap_x.weight;
+                }
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = value;
+}
+
+/// Computes the `i`-th input location used to sample the grid coordinate along a spatial
+/// dimension of the given size.
+#[cube]
+fn axis_tap<F: Float>(
+    coord: F,
+    size: u32,
+    i: u32,
+    #[comptime] options: GridSampleOptions,
+) -> Tap<F> {
+    let max = F::cast_from(size) - F::new(1.0);
+
+    // Unnormalize the coordinate from [-1, 1] to the input indices.
+    let mut scale = F::cast_from(size) / F::new(2.0);
+    if comptime![options.align_corners] {
+        scale = max / F::new(2.0);
+    }
+    let coord = coord * scale + max / F::new(2.0);
+
+    let mut index = Floor::floor(coord);
+    let mut weight = F::new(1.0);
+
+    if comptime![options.mode == GridSampleMode::Bicubic] {
+        // The padding is applied to each tap instead of the coordinate.
+        weight = cubic_weight::<F>(coord - index, i);
+        index += F::cast_from(i) - F::new(1.0);
+        if comptime![options.padding_mode != GridSamplePaddingMode::Zeros] {
+            index = pad_coordinate::<F>(index, max, options);
+        }
+    } else {
+        let coord = pad_coordinate::<F>(coord, max, options);
+        index = Floor::floor(coord);
+
+        if comptime![options.mode == GridSampleMode::Nearest] {
+            index = Round::round(coord);
+        } else {
+            let t = coord - index;
+            index += F::cast_from(i);
+            weight = select(i == 0, F::new(1.0) - t, t);
+        }
+    }
+
+    let valid = index >= F::new(0.0) && index <= max;
+
+    Tap::<F> {
+        index: u32::cast_from(Min::min(Max::max(index, F::new(0.0)), max)),
+        weight: select(valid, weight, F::new(0.0)),
+    }
+}
+
+/// Applies the padding to an unnormalized coordinate, `max` being the last index of the input.
+#[cube]
+fn pad_coordinate<F: Float>(coord: F, max: F, #[comptime] options: GridSampleOptions) -> F {
+    let mut coord = coord;
+
+    if comptime![options.padding_mode == GridSamplePaddingMode::Reflection] {
+        if comptime![options.align_corners] {
+            coord = reflect::<F>(coord, F::new(0.0), F::new(2.0) * max);
+        } else {
+            coord = reflect::<F>(coord, F::new(-1.0), F::new(2.0) * max + F::new(1.0));
+        }
+    }
+    if comptime![options.padding_mode != GridSamplePaddingMode::Zeros] {
+        coord = Min::min(Max::max(coord, F::new(0.0)), max);
+    }
+
+    coord
+}
+
+/// Reflects the coordinate at the bounds `twice_low / 2` and `twice_high / 2` until it is in
+/// bound.
+#[cube]
+fn reflect<F: Float>(coord: F, twice_low: F, twice_high: F) -> F {
+    let mut reflected = F::new(0.0);
+
+    if twice_low != twice_high {
+        let min = twice_low / F::new(2.0);
+        let span = (twice_high - twice_low) / F::new(2.0);
+
+        let coord = Abs::abs(coord - min);
+        let flips = Floor::floor(coord / span);
+        let extra = coord - flips * span;
+        let odd = flips - Floor::floor(flips / F::new(2.0)) * F::new(2.0);
+
+        reflected = select(odd == F::new(0.0), extra + min, span - extra + min);
+    }
+
+    reflected
+}
+
+/// Computes the cubic convolution weight of the `i`-th tap, `t` being the distance between the
+/// coordinate and the second tap.
+#[cube]
+fn cubic_weight<F: Float>(t: F, i: u32) -> F {
+    let a = F::new(CUBIC_A);
+    let index = F::cast_from(i);
+    let distance = select(i < 2, t + F::new(1.0) - index, index - F::new(1.0) - t);
+
+    let near =
+        ((a + F::new(2.0)) * distance - (a + F::new(3.0))) * distance * distance + F::new(1.0);
+    let far = ((a * distance - F::new(5.0) * a) * distance + F::new(8.0) * a) * distance
+        - F::new(4.0) * a;
+
+    select(i == 1 || i == 2, near, far)
+}
+
+/// Samples the input at the grid locations, for 4D and 5D inputs.
+pub(crate) fn grid_sample<R: CubeRuntime, E: FloatElement>(
+    x: CubeTensor<R>,
+    grid: CubeTensor<R>,
+    options: GridSampleOptions,
+) -> CubeTensor<R> {
+    let x = into_contiguous(x);
+    let grid = into_contiguous(grid);
+    let rank = grid.shape.num_dims();
+
+    let mut shape = vec![x.shape.dims[0], x.shape.dims[1]];
+    shape.extend(&grid.shape.dims[1..rank - 1]);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), Shape::from(shape));
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        grid_sample_kernel::launch_unchecked::<E, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            grid.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            rank as u32 - 2,
+            options,
+        );
+    }
+
+    output
+}
@@ -4,8 +4,12 @@ mod cast;
 mod clamp;
 mod comparison;
 mod contiguous;
+mod cumulative;
+mod fft;
+mod grid_sample;
 mod index;
 mod mask;
+mod special;
 mod unary_float;
 mod unary_int;
 mod unary_numeric;
@@ -14,7 +18,11 @@ pub(crate) use binary::*;
 pub(crate) use binary_int::*;
 pub use cast::*;
 pub use contiguous::*;
+pub(crate) use cumulative::*;
+pub(crate) use fft::*;
+pub(crate) use grid_sample::*;
 pub use mask::*;
+pub(crate) use special::polygamma;
 pub(crate) use unary_float::*;
 pub(crate) use unary_int::*;
 pub(crate) use unary_numeric::*;
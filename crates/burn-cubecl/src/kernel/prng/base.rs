@@ -0,0 +1,86 @@
+use burn_common::rand::get_seeded_rng;
+use cubecl::prelude::*;
+use cubecl::random::to_unit_interval_open;
+use rand::RngCore;
+use std::f32::consts::PI;
+
+use crate::SEED;
+
+/// Draws the seed of a sampling kernel from the backend generator.
+pub(crate) fn kernel_seed() -> u32 {
+    let mut seed = SEED.lock().unwrap();
+    let mut rng = if let Some(rng_seeded) = seed.as_ref() {
+        rng_seeded.clone()
+    } else {
+        get_seeded_rng()
+    };
+    let value = rng.next_u32();
+    *seed = Some(rng);
+
+    value
+}
+
+/// PCG hash, used both to derive an independent stream for each unit and to step it.
+#[cube]
+fn pcg_hash(input: u32) -> u32 {
+    let state = input * 747796405u32 + 2891336453u32;
+    let word = ((state >> ((state >> 28u32) + 4u32)) ^ state) * 277803737u32;
+    (word >> 22u32) ^ word
+}
+
+/// Initializes the generator state of the current unit.
+#[cube]
+pub(crate) fn init_state(seed: u32) -> u32 {
+    pcg_hash(ABSOLUTE_POS + pcg_hash(seed))
+}
+
+/// Samples a value in the open interval `(0, 1)`.
+#[cube]
+pub(crate) fn sample_uniform(state: &mut u32) -> f32 {
+    *state = pcg_hash(*state);
+    to_unit_interval_open(*state)
+}
+
+/// Samples a value from the standard normal distribution with the Box-Muller transform.
+#[cube]
+pub(crate) fn sample_normal(state: &mut u32) -> f32 {
+    let unit_0 = sample_uniform(state);
+    let unit_1 = sample_uniform(state);
+    let coeff = Sqrt::sqrt(Log::log(unit_0) * -2.0);
+
+    f32::cos(2.0 * PI * unit_1) * coeff
+}
+
+/// Samples a value from the gamma distribution with unit scale (Marsaglia and Tsang's method).
+#[cube]
+pub(crate) fn sample_gamma(state: &mut u32, shape: f32) -> f32 {
+    // A shape lower than one is boosted by one, and corrected with `u^(1/shape)`.
+    let boosted = shape < 1.0;
+    let mut d = shape - 1.0 / 3.0;
+    if boosted {
+        d += 1.0;
+    }
+    let c = 1.0 / Sqrt::sqrt(9.0 * d);
+    let mut value = 0.0;
+    let mut accepted = false;
+
+    while !accepted {
+        let x = sample_normal(state);
+        let v = 1.0 + c * x;
+
+        if v > 0.0 {
+            let v = v * v * v;
+            let u = sample_uniform(state);
+
+            accepted = Log::log(u) < 0.5 * x * x + d - d * v + d * Log::log(v);
+            value = d * v;
+        }
+    }
+
+    if boosted {
+        let u = sample_uniform(state);
+        value *= Exp::exp(Log::log(u) / shape);
+    }
+
+    value
+}
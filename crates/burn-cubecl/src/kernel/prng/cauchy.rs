@@ -0,0 +1,51 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+use std::f32::consts::PI;
+
+use super::{init_state, kernel_seed, sample_uniform};
+use crate::{CubeRuntime, element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor};
+
+#[cube(launch_unchecked)]
+fn cauchy_kernel<E: Numeric>(output: &mut Tensor<E>, seed: u32, median: f32, scale: f32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    let angle = PI * (sample_uniform(&mut state) - 0.5);
+
+    output[ABSOLUTE_POS] = E::cast_from(median + scale * f32::sin(angle) / f32::cos(angle));
+}
+
+/// Pseudo-random generator with Cauchy distribution
+pub fn random_cauchy<R: CubeRuntime, E: CubeElement + Numeric>(
+    shape: Shape,
+    device: &R::Device,
+    median: f32,
+    scale: f32,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let output = empty_device::<R, E>(client.clone(), device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        cauchy_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(kernel_seed()),
+            ScalarArg::new(median),
+            ScalarArg::new(scale),
+        );
+    }
+
+    output
+}
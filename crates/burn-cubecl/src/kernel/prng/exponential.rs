@@ -0,0 +1,48 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{init_state, kernel_seed, sample_uniform};
+use crate::{CubeRuntime, element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor};
+
+#[cube(launch_unchecked)]
+fn exponential_kernel<E: Numeric>(output: &mut Tensor<E>, seed: u32, rate: f32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    let unit = sample_uniform(&mut state);
+
+    output[ABSOLUTE_POS] = E::cast_from(-Log::log(unit) / rate);
+}
+
+/// Pseudo-random generator with exponential distribution
+pub fn random_exponential<R: CubeRuntime, E: CubeElement + Numeric>(
+    shape: Shape,
+    device: &R::Device,
+    rate: f32,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let output = empty_device::<R, E>(client.clone(), device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        exponential_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(kernel_seed()),
+            ScalarArg::new(rate),
+        );
+    }
+
+    output
+}
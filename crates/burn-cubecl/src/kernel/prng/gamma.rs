@@ -0,0 +1,96 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{init_state, kernel_seed, sample_gamma};
+use crate::{CubeRuntime, element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor};
+
+#[cube(launch_unchecked)]
+fn gamma_kernel<E: Numeric>(output: &mut Tensor<E>, seed: u32, concentration: f32, scale: f32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    let value = sample_gamma(&mut state, concentration);
+
+    output[ABSOLUTE_POS] = E::cast_from(value * scale);
+}
+
+/// Pseudo-random generator with gamma distribution
+pub fn random_gamma<R: CubeRuntime, E: CubeElement + Numeric>(
+    shape: Shape,
+    device: &R::Device,
+    concentration: f32,
+    scale: f32,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let output = empty_device::<R, E>(client.clone(), device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        gamma_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(kernel_seed()),
+            ScalarArg::new(concentration),
+            ScalarArg::new(scale),
+        );
+    }
+
+    output
+}
+
+#[cube(launch_unchecked)]
+fn beta_kernel<E: Numeric>(output: &mut Tensor<E>, seed: u32, alpha: f32, beta: f32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    let x = sample_gamma(&mut state, alpha);
+    let y = sample_gamma(&mut state, beta);
+
+    output[ABSOLUTE_POS] = E::cast_from(x / (x + y));
+}
+
+/// Pseudo-random generator with beta distribution
+pub fn random_beta<R: CubeRuntime, E: CubeElement + Numeric>(
+    shape: Shape,
+    device: &R::Device,
+    alpha: f32,
+    beta: f32,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let output = empty_device::<R, E>(client.clone(), device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        beta_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(kernel_seed()),
+            ScalarArg::new(alpha),
+            ScalarArg::new(beta),
+        );
+    }
+
+    output
+}
@@ -0,0 +1,50 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{init_state, kernel_seed, sample_normal};
+use crate::{CubeRuntime, element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor};
+
+#[cube(launch_unchecked)]
+fn log_normal_kernel<E: Numeric>(output: &mut Tensor<E>, seed: u32, mean: f32, std: f32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    let normal = sample_normal(&mut state);
+
+    output[ABSOLUTE_POS] = E::cast_from(f32::exp(mean + std * normal));
+}
+
+/// Pseudo-random generator with log-normal distribution
+pub fn random_log_normal<R: CubeRuntime, E: CubeElement + Numeric>(
+    shape: Shape,
+    device: &R::Device,
+    mean: f32,
+    std: f32,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let output = empty_device::<R, E>(client.clone(), device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        log_normal_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(kernel_seed()),
+            ScalarArg::new(mean),
+            ScalarArg::new(std),
+        );
+    }
+
+    output
+}
@@ -1,7 +1,23 @@
+mod base;
 mod bernoulli;
+mod cauchy;
+mod exponential;
+mod gamma;
+mod log_normal;
+mod multinomial;
 mod normal;
+mod poisson;
+mod truncated_normal;
 mod uniform;
 
+pub(crate) use base::*;
 pub use bernoulli::*;
+pub use cauchy::*;
+pub use exponential::*;
+pub use gamma::*;
+pub use log_normal::*;
+pub(crate) use multinomial::*;
 pub use normal::*;
+pub use poisson::*;
+pub use truncated_normal::*;
 pub use uniform::*;
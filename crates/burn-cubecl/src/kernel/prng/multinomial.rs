@@ -0,0 +1,113 @@
+use burn_tensor::{Shape, ops::FloatTensorOps};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{init_state, kernel_seed, sample_uniform};
+use crate::{
+    CubeBackend, CubeRuntime, FloatElement, IntElement,
+    element::BoolElement,
+    kernel::{into_contiguous, slice},
+    ops::numeric::empty_device,
+    tensor::CubeTensor,
+};
+
+/// Each unit draws a single sample with replacement, by inverse transform sampling on its row.
+#[cube(launch_unchecked)]
+fn multinomial_kernel<F: Float, I: Int>(weights: &Tensor<F>, output: &mut Tensor<I>, seed: u32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let num_categories = weights.shape(1);
+    let row = ABSOLUTE_POS / output.shape(1) * num_categories;
+
+    let mut total = F::new(0.0);
+    for i in 0..num_categories {
+        total += weights[row + i];
+    }
+
+    let mut state = init_state(seed);
+    let value = F::cast_from(sample_uniform(&mut state)) * total;
+
+    // Categories with a zero weight never exceed the value, so they are never selected.
+    let mut cumulative = F::new(0.0);
+    let mut index = num_categories - 1;
+    for i in 0..num_categories {
+        cumulative += weights[row + i];
+        if cumulative > value && index == num_categories - 1 {
+            index = i;
+        }
+    }
+
+    output[ABSOLUTE_POS] = I::cast_from(index);
+}
+
+/// Each unit perturbs a single log-weight with Gumbel noise.
+#[cube(launch_unchecked)]
+fn gumbel_keys_kernel<F: Float>(weights: &Tensor<F>, output: &mut Tensor<F>, seed: u32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    let gumbel = -Log::log(-Log::log(F::cast_from(sample_uniform(&mut state))));
+
+    output[ABSOLUTE_POS] = Log::log(weights[ABSOLUTE_POS]) + gumbel;
+}
+
+/// Samples `num_samples` category indices from the weights of each row of a 2D tensor.
+///
+/// Without replacement, the categories with the largest perturbed log-weights are kept, which is
+/// equivalent to drawing them one after the other (Gumbel-top-k trick).
+pub(crate) fn multinomial<R: CubeRuntime, F: FloatElement, I: IntElement, BT: BoolElement>(
+    weights: CubeTensor<R>,
+    num_samples: usize,
+    replacement: bool,
+) -> CubeTensor<R> {
+    let weights = into_contiguous(weights);
+    let batch_size = weights.shape.dims[0];
+    let num_elems = weights.shape.num_elements();
+    let cube_dim = CubeDim::default();
+
+    if replacement {
+        let output = empty_device::<R, I>(
+            weights.client.clone(),
+            weights.device.clone(),
+            Shape::new([batch_size, num_samples]),
+        );
+        let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+        unsafe {
+            multinomial_kernel::launch_unchecked::<F, I, R>(
+                &weights.client,
+                cube_count,
+                cube_dim,
+                weights.as_tensor_arg::<F>(1),
+                output.as_tensor_arg::<I>(1),
+                ScalarArg::new(kernel_seed()),
+            );
+        }
+
+        return output;
+    }
+
+    let keys = empty_device::<R, F>(
+        weights.client.clone(),
+        weights.device.clone(),
+        weights.shape.clone(),
+    );
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        gumbel_keys_kernel::launch_unchecked::<F, R>(
+            &weights.client,
+            cube_count,
+            cube_dim,
+            weights.as_tensor_arg::<F>(1),
+            keys.as_tensor_arg::<F>(1),
+            ScalarArg::new(kernel_seed()),
+        );
+    }
+
+    let indices = CubeBackend::<R, F, I, BT>::float_argsort(keys, 1, true);
+    slice::<R, I>(indices, &[0..batch_size, 0..num_samples])
+}
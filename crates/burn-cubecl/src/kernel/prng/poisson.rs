@@ -0,0 +1,95 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{init_state, kernel_seed, sample_uniform};
+use crate::{CubeRuntime, element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor};
+
+#[cube(launch_unchecked)]
+fn poisson_kernel<E: Numeric>(output: &mut Tensor<E>, seed: u32, rate: f32) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    let mut count = 0.0;
+
+    if rate < 10.0 {
+        // Knuth's algorithm: counts the arrivals of a unit rate process until time `rate`.
+        let limit = Exp::exp(-rate);
+        let mut product = sample_uniform(&mut state);
+
+        while product > limit {
+            count += 1.0;
+            product *= sample_uniform(&mut state);
+        }
+    } else {
+        // Transformed rejection with squeeze (Hörmann's PTRS).
+        let log_rate = Log::log(rate);
+        let b = 0.931 + 2.53 * Sqrt::sqrt(rate);
+        let a = -0.059 + 0.02483 * b;
+        let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let v_r = 0.9277 - 3.6224 / (b - 2.0);
+
+        loop {
+            let u = sample_uniform(&mut state) - 0.5;
+            let v = sample_uniform(&mut state);
+            let us = 0.5 - Abs::abs(u);
+            let k = Floor::floor((2.0 * a / us + b) * u + rate + 0.43);
+
+            if us >= 0.07 && v <= v_r {
+                count = k;
+                break;
+            }
+
+            if k >= 0.0 && (us >= 0.013 || v <= us) {
+                let lhs = Log::log(v) + Log::log(inv_alpha) - Log::log(a / (us * us) + b);
+                if lhs <= -rate + k * log_rate - log_factorial(k) {
+                    count = k;
+                    break;
+                }
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = E::cast_from(count);
+}
+
+/// Natural logarithm of `k!`, using Stirling's series.
+#[cube]
+fn log_factorial(k: f32) -> f32 {
+    let x = k + 1.0;
+    let inv = 1.0 / x;
+
+    (x - 0.5) * Log::log(x) - x + 0.918_938_5 + inv / 12.0 - inv * inv * inv / 360.0
+}
+
+/// Pseudo-random generator with Poisson distribution
+pub fn random_poisson<R: CubeRuntime, E: CubeElement + Numeric>(
+    shape: Shape,
+    device: &R::Device,
+    rate: f32,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let output = empty_device::<R, E>(client.clone(), device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        poisson_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(kernel_seed()),
+            ScalarArg::new(rate),
+        );
+    }
+
+    output
+}
@@ -0,0 +1,117 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{init_state, kernel_seed, sample_normal, sample_uniform};
+use crate::{CubeRuntime, element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor};
+
+#[cube(launch_unchecked)]
+fn truncated_normal_kernel<E: Numeric>(
+    output: &mut Tensor<E>,
+    seed: u32,
+    mean: f32,
+    std: f32,
+    low: f32,
+    high: f32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let mut state = init_state(seed);
+    // The bounds of the standard normal distribution, mirrored when the interval is negative so
+    // that the tail case only has to handle positive intervals.
+    let mirrored = high < mean;
+    let mut a = (low - mean) / std;
+    let mut b = (high - mean) / std;
+    if mirrored {
+        let tmp = a;
+        a = -b;
+        b = -tmp;
+    }
+
+    let mut z = 0.0;
+
+    if a > 0.0 {
+        let alpha = (a + Sqrt::sqrt(a * a + 4.0)) / 2.0;
+
+        if (b - a) * alpha < 1.0 {
+            z = sample_uniform_proposal(&mut state, a, b, a);
+        } else {
+            let mut accepted = false;
+
+            while !accepted {
+                z = a - Log::log(sample_uniform(&mut state)) / alpha;
+                let acceptance = Exp::exp(-(z - alpha) * (z - alpha) / 2.0);
+                accepted = sample_uniform(&mut state) <= acceptance && z <= b;
+            }
+        }
+    } else if b - a >= 1.0 {
+        let mut accepted = false;
+
+        while !accepted {
+            z = sample_normal(&mut state);
+            accepted = z >= a && z <= b;
+        }
+    } else {
+        z = sample_uniform_proposal(&mut state, a, b, 0.0);
+    }
+
+    if mirrored {
+        z = -z;
+    }
+
+    let value = f32::clamp(mean + std * z, low, high);
+    output[ABSOLUTE_POS] = E::cast_from(value);
+}
+
+/// Samples from `[low, high]` with a uniform proposal, where `closest` is the point of the
+/// interval closest to 0.
+#[cube]
+fn sample_uniform_proposal(state: &mut u32, low: f32, high: f32, closest: f32) -> f32 {
+    let mut z = 0.0;
+    let mut accepted = false;
+
+    while !accepted {
+        z = low + (high - low) * sample_uniform(state);
+        accepted = sample_uniform(state) <= Exp::exp((closest * closest - z * z) / 2.0);
+    }
+
+    z
+}
+
+/// Pseudo-random generator with truncated normal distribution
+pub fn random_truncated_normal<R: CubeRuntime, E: CubeElement + Numeric>(
+    shape: Shape,
+    device: &R::Device,
+    mean: f32,
+    std: f32,
+    low: f32,
+    high: f32,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let output = empty_device::<R, E>(client.clone(), device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        truncated_normal_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(kernel_seed()),
+            ScalarArg::new(mean),
+            ScalarArg::new(std),
+            ScalarArg::new(low),
+            ScalarArg::new(high),
+        );
+    }
+
+    output
+}
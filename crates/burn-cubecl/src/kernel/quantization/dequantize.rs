@@ -3,19 +3,43 @@
 use super::QParams;
 use crate::{CubeRuntime, FloatElement, kernel::utils::strided_layout, ops::max_line_size};
 use crate::{ops::numeric::empty_device_strided, tensor::CubeTensor};
+use burn_common::{e4m3, e5m2};
 use burn_tensor::DType;
 use burn_tensor::quantization::{
     QuantInputType, QuantLevel, QuantMode, QuantScheme, QuantStoreType,
 };
 use cubecl::calculate_cube_count_elemwise;
 use cubecl::prelude::*;
-use cubecl::std::tensor::{StridedLayout, index_offset_contiguous};
+use cubecl::std::{
+    CubeOption,
+    tensor::{StridedLayout, index_offset_contiguous},
+};
 
-/// Dequantize a line of values into floating-point values using the provided scale.
+/// Dequantize a line of values into floating-point values using the provided scale and offset.
 #[cube]
-pub fn dequantize_symmetric<F: Float>(value: Line<F>, scale: f32) -> Line<F> {
-    // x = scale * x_q
-    Line::cast_from(scale) * value
+pub fn dequantize_int<F: Float>(value: Line<F>, scale: f32, offset: f32) -> Line<F> {
+    // x = scale * (x_q - offset)
+    Line::cast_from(scale) * (value - Line::cast_from(offset))
+}
+
+/// Dequantize a line of values stored as the bits of the quantization input type.
+#[cube]
+fn dequantize_native_value<F: Float>(
+    value: Line<i8>,
+    scale: f32,
+    offset: f32,
+    #[comptime] q_type: QuantInputType,
+) -> Line<F> {
+    let value = match comptime!(q_type) {
+        QuantInputType::QInt8 => Line::cast_from(value),
+        QuantInputType::E4M3 => Line::cast_from(Line::<e4m3>::reinterpret(value)),
+        QuantInputType::E5M2 => Line::cast_from(Line::<e5m2>::reinterpret(value)),
+        QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+            panic!("Native storage is not supported for {q_type:?}")
+        }
+    };
+
+    dequantize_int(value, scale, offset)
 }
 
 // TODO: use for fusion @nath
@@ -27,31 +51,36 @@ pub fn dequantize_symmetric<F: Float>(value: Line<F>, scale: f32) -> Line<F> {
 #[cube]
 pub fn dequantize_packed_values<F: Float, QI: Int>(
     position: u32,
-    values: &Tensor<QI>,
+    values: &Tensor<Line<QI>>,
     scales: &Tensor<f32>,
+    offsets: &CubeOption<Tensor<f32>>,
     #[comptime] scheme: QuantScheme,
 ) -> Line<F> {
-    let value = values[position];
-    dequantize_packed_value_at::<F, QI>(position, value, scales, scheme)
+    let qparams = QParams::new(scheme);
+    let index = qparams.index(values, position);
+    let value = values[position][0];
+    dequantize_packed_value_at::<F, QI>(index, value, scales, offsets, scheme)
 }
 
-/// Dequantize a single value using the scale at the specified position.
+/// Dequantize a single value using the quantization parameters at the specified index.
 ///
 /// Returns a line of floating-point values. The number of values in the line depends on the number of packed
 /// values in the stored quantization type.
 #[cube]
 pub fn dequantize_packed_value_at<F: Float, QI: Int>(
-    position: u32,
+    index: u32,
     value: QI,
     scales: &Tensor<f32>,
+    offsets: &CubeOption<Tensor<f32>>,
     #[comptime] scheme: QuantScheme,
 ) -> Line<F> {
     let qparams = QParams::new(scheme);
-    let scale = qparams.scale(scales, position);
-    dequantize_packed_value::<F, QI>(value, scale, scheme)
+    let scale = qparams.scale(scales, index);
+    let offset = qparams.offset(offsets, index);
+    dequantize_packed_value::<F, QI>(value, scale, offset, scheme)
 }
 
-/// Dequantize a single packed value using the scale provided.
+/// Dequantize a single packed value using the scale and offset provided.
 ///
 /// Returns a line of floating-point values. The number of values in the line depends on the number of packed
 /// values in the stored quantization type.
@@ -59,12 +88,13 @@ pub fn dequantize_packed_value_at<F: Float, QI: Int>(
 pub fn dequantize_packed_value<F: Float, QS: Int>(
     value: QS,
     scale: f32,
+    offset: f32,
     #[comptime] scheme: QuantScheme,
 ) -> Line<F> {
     // TODO: q_store_type: QuantStoreType::Native
     let floats = unpack_q::<F, QS>(value, scheme.q_type);
 
-    dequantize_symmetric(floats, scale)
+    dequantize_int(floats, scale, offset)
 }
 
 /// Unpack a quantized integer into a line of floating-point values, according to the specified quantization input type.
@@ -75,6 +105,10 @@ pub fn dequantize_packed_value<F: Float, QS: Int>(
 fn unpack_q<F: Float, QS: Int>(value: QS, #[comptime] quant: QuantInputType) -> Line<F> {
     let size_quant = comptime!(match quant {
         QuantInputType::QInt8 => 8,
+        QuantInputType::QInt4 | QuantInputType::QUInt4 => 4,
+        QuantInputType::E4M3 | QuantInputType::E5M2 => {
+            panic!("Packed storage is not supported for {quant:?}")
+        }
     });
     let size_store = comptime!(QS::size_bits().unwrap() as u32);
     let num_quant = comptime!(size_store / size_quant);
@@ -82,14 +116,19 @@ fn unpack_q<F: Float, QS: Int>(value: QS, #[comptime] quant: QuantInputType) ->
     let mut output = Line::empty(num_quant);
     let mut position = comptime!(0);
     let mask = QS::cast_from(comptime!((1 << size_quant) - 1));
-    let shift_sign = QS::cast_from(comptime!(24));
+    let sign_shift = comptime!(32 - size_quant);
+    let shift_sign = QS::cast_from(sign_shift);
 
     #[unroll]
     for _ in 0..num_quant {
         let offset = QS::cast_from(comptime!(position * size_quant));
         let raw = (value >> offset) & mask;
-        // Sign-extend: move sign bit to MSB via leftshift, then rightshift to restore sign
-        output[position] = F::cast_from(i32::cast_from(raw << shift_sign) >> 24);
+        if comptime!(quant == QuantInputType::QUInt4) {
+            output[position] = F::cast_from(raw);
+        } else {
+            // Sign-extend: move sign bit to MSB via leftshift, then rightshift to restore sign
+            output[position] = F::cast_from(i32::cast_from(raw << shift_sign) >> sign_shift);
+        }
         comptime!(position += 1);
     }
 
@@ -97,9 +136,10 @@ fn unpack_q<F: Float, QS: Int>(value: QS, #[comptime] quant: QuantInputType) ->
 }
 
 #[cube(launch_unchecked)]
-fn dequantize_symmetric_packed_kernel<F: Float>(
+fn dequantize_packed_kernel<F: Float>(
     input: &Tensor<Line<u32>>,
     scales: &Tensor<f32>,
+    offsets: &CubeOption<Tensor<f32>>,
     output: &mut Tensor<Line<F>>,
     #[comptime] scheme: QuantScheme,
 ) {
@@ -110,10 +150,8 @@ fn dequantize_symmetric_packed_kernel<F: Float>(
     // Input line size = 1
     let qparams = QParams::new(scheme);
     let num_quants = comptime!(qparams.num_quants);
-    let scale = qparams.scale(scales, ABSOLUTE_POS);
-    let value = input[ABSOLUTE_POS][0];
 
-    let out = dequantize_packed_value::<F, u32>(value, scale, scheme);
+    let out = dequantize_packed_values::<F, u32>(ABSOLUTE_POS, input, scales, offsets, scheme);
 
     if comptime!(output.line_size() == num_quants) {
         output[ABSOLUTE_POS] = out;
@@ -121,15 +159,20 @@ fn dequantize_symmetric_packed_kernel<F: Float>(
         // Output line size = 1
         #[unroll]
         for i in 0..out.size() {
-            output[ABSOLUTE_POS * out.size() + i] = Line::cast_from(out[i]);
+            // The last packed value is padded when the values don't fill it completely
+            let out_pos = ABSOLUTE_POS * out.size() + i;
+            if out_pos < output.len() {
+                output[out_pos] = Line::cast_from(out[i]);
+            }
         }
     }
 }
 
 #[cube(launch_unchecked)]
-fn dequantize_symmetric_int8_native_kernel<F: Float>(
+fn dequantize_native_kernel<F: Float>(
     input: &Tensor<Line<i8>>,
-    scale: &Tensor<f32>,
+    scales: &Tensor<f32>,
+    offsets: &CubeOption<Tensor<f32>>,
     output: &mut Tensor<Line<F>>,
     out_layout: StridedLayout,
     #[comptime] scheme: QuantScheme,
@@ -144,9 +187,11 @@ fn dequantize_symmetric_int8_native_kernel<F: Float>(
 
     let qparams = QParams::new(scheme);
     // Absolute pos represents the logical block (scale) used to dequantize, not layout
-    let scale = qparams.scale(scale, ABSOLUTE_POS * input.line_size());
+    let index = qparams.index(input, ABSOLUTE_POS * input.line_size());
+    let scale = qparams.scale(scales, index);
+    let offset = qparams.offset(offsets, index);
 
-    output[out_pos] = dequantize_symmetric(Line::cast_from(input[in_pos]), scale);
+    output[out_pos] = dequantize_native_value(input[in_pos], scale, offset, scheme.q_type);
 }
 
 /// Convert the tensor back to a higher precision data type.
@@ -161,10 +206,23 @@ where
     match tensor.dtype {
         DType::QFloat(scheme) => match scheme {
             QuantScheme {
-                q_type: QuantInputType::QInt8,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                ..
+            } => panic!(
+                "Affine quantization is not supported for {:?}",
+                scheme.q_type
+            ),
+            QuantScheme {
+                q_type: QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4,
                 q_store_type: QuantStoreType::U32,
                 ..
             } => dequantize_packed::<R, F>(tensor, output),
+            QuantScheme {
+                q_type: QuantInputType::QInt4 | QuantInputType::QUInt4,
+                q_store_type: QuantStoreType::Native,
+                ..
+            } => panic!("Native storage is not supported for {:?}", scheme.q_type),
             QuantScheme {
                 q_type: QuantInputType::QInt8,
                 q_store_type: QuantStoreType::Native,
@@ -176,6 +234,25 @@ where
 
                 dequantize_native::<R, F>(tensor, output)
             }
+            QuantScheme {
+                q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                q_store_type: QuantStoreType::Native,
+                ..
+            } => {
+                if !e4m3::is_supported(&tensor.client) || !e5m2::is_supported(&tensor.client) {
+                    panic!(
+                        "{:?} is not supported for native quantization",
+                        scheme.q_type
+                    );
+                }
+
+                dequantize_native::<R, F>(tensor, output)
+            }
+            QuantScheme {
+                q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                q_store_type: QuantStoreType::U32,
+                ..
+            } => panic!("Packed storage is not supported for {:?}", scheme.q_type),
         },
         _ => panic!("Expected QFloat dtype"),
     }
@@ -186,78 +263,60 @@ where
     R: CubeRuntime,
     F: FloatElement,
 {
-    // The actual number of elements is 1/4 (four int8 values packed in a single u32)
+    let scheme = match tensor.dtype {
+        DType::QFloat(s) => s,
+        _ => panic!("Expected QFloat dtype"),
+    };
+
+    // The actual number of elements is smaller (multiple quantized values packed in a single u32)
     // so we choose a line size to match a valid input binding size.
+    let num_quants = (scheme.bits_stored() / scheme.bits_type()) as u8;
     let num_out_elems = tensor.shape.num_elements();
-    let num_elems = usize::div_ceil(num_out_elems, 4);
+    let num_elems = usize::div_ceil(num_out_elems, num_quants as usize);
     let line_size_in = 1;
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(num_elems / line_size_in as usize, cube_dim);
 
-    let scheme = match tensor.dtype {
-        DType::QFloat(s) => s,
-        _ => panic!("Expected QFloat dtype"),
-    };
+    match scheme.level {
+        QuantLevel::Tensor => {}
+        QuantLevel::Block(block_size) => assert!(
+            block_size % num_quants as usize == 0,
+            "Block size must be divisible by {num_quants}, got block_size={block_size}"
+        ),
+        QuantLevel::Channel(axis) => {
+            // The values packed together must belong to the same channel
+            let stride: usize = tensor.shape.dims[axis + 1..].iter().product();
+            assert!(
+                stride % num_quants as usize == 0,
+                "Channel stride must be divisible by {num_quants}, got stride={stride}"
+            );
+        }
+    }
 
     // Output line size selected based on the number of packed values per storage type
-    let num_quants = (scheme.bits_stored() / scheme.bits_type()) as u8;
     let use_packed_line_size =
         num_out_elems % num_quants as usize == 0 && R::supported_line_sizes().contains(&num_quants);
 
     let line_size_out = if use_packed_line_size { num_quants } else { 1 };
 
-    match scheme {
-        QuantScheme {
-            level: QuantLevel::Tensor,
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            q_store_type: QuantStoreType::U32,
-            ..
-        } => {
-            let scales = tensor.scales().unwrap();
-
-            unsafe {
-                dequantize_symmetric_packed_kernel::launch_unchecked::<F, R>(
-                    &tensor.client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<u32>(line_size_in),
-                    scales.as_tensor_arg::<f32>(1),
-                    output.as_tensor_arg::<F>(line_size_out),
-                    scheme,
-                )
-            };
-        }
-        QuantScheme {
-            level: QuantLevel::Block(block_size),
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            q_store_type: QuantStoreType::U32,
-            ..
-        } => {
-            assert!(
-                block_size % 4 == 0,
-                "Block size must be divisible by 4, got block_size={block_size}"
-            );
-            let scales = tensor.scales().unwrap();
-
-            unsafe {
-                dequantize_symmetric_packed_kernel::launch_unchecked::<F, R>(
-                    &tensor.client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<u32>(line_size_in),
-                    scales.as_tensor_arg::<f32>(1),
-                    output.as_tensor_arg::<F>(line_size_out),
-                    scheme,
-                )
-            };
-        }
-        QuantScheme {
-            q_store_type: QuantStoreType::Native,
-            ..
-        } => panic!("Invalid quantization storage type for scheme {scheme:?}"),
-    }
+    let scales = tensor.scales().unwrap();
+    let offsets = tensor.offsets();
+
+    unsafe {
+        dequantize_packed_kernel::launch_unchecked::<F, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<u32>(line_size_in),
+            scales.as_tensor_arg::<f32>(1),
+            offsets
+                .as_ref()
+                .map(|offsets| offsets.as_tensor_arg::<f32>(1))
+                .into(),
+            output.as_tensor_arg::<F>(line_size_out),
+            scheme,
+        )
+    };
 
     output
 }
@@ -267,76 +326,52 @@ where
     R: CubeRuntime,
     F: FloatElement,
 {
-    let num_elems = tensor.shape.num_elements();
-    let line_size = max_line_size(&tensor);
-    let cube_dim = CubeDim::default();
-    let cube_count = calculate_cube_count_elemwise(num_elems / line_size as usize, cube_dim);
-
-    let out_layout = strided_layout(&output);
-
     let scheme = match tensor.dtype {
         DType::QFloat(s) => s,
         _ => panic!("Expected QFloat dtype"),
     };
 
-    match scheme {
-        QuantScheme {
-            level: QuantLevel::Tensor,
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            q_store_type: QuantStoreType::Native,
-            ..
-        } => {
-            let scales = tensor.scales().unwrap();
-
-            unsafe {
-                dequantize_symmetric_int8_native_kernel::launch_unchecked::<F, R>(
-                    &tensor.client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<i8>(line_size),
-                    scales.as_tensor_arg::<f32>(1),
-                    output.as_tensor_arg::<F>(line_size),
-                    out_layout,
-                    scheme,
-                    Some(tensor.shape.num_dims() as u32),
-                )
-            };
-        }
-        QuantScheme {
-            level: QuantLevel::Block(block_size),
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            q_store_type: QuantStoreType::Native,
-            ..
-        } => {
+    let line_size = match scheme.level {
+        QuantLevel::Tensor => max_line_size(&tensor),
+        QuantLevel::Block(block_size) => {
             // We could use line_size = block_size if it's in the supported line sizes.. but let's keep it simple
+            let line_size = max_line_size(&tensor);
             assert!(
                 block_size as u8 % line_size == 0,
                 "Block size must evenly divide line size, got {block_size} / {line_size}"
             );
-
-            let scales = tensor.scales().unwrap();
-
-            unsafe {
-                dequantize_symmetric_int8_native_kernel::launch_unchecked::<F, R>(
-                    &tensor.client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<i8>(line_size),
-                    scales.as_tensor_arg::<f32>(1),
-                    output.as_tensor_arg::<F>(line_size),
-                    out_layout,
-                    scheme,
-                    Some(tensor.shape.num_dims() as u32),
-                )
-            };
+            line_size
         }
-        QuantScheme {
-            q_store_type: QuantStoreType::U32,
-            ..
-        } => panic!("Invalid quantization storage type for scheme {scheme:?}"),
-    }
+        // The values of a line could belong to different channels
+        QuantLevel::Channel(_) => 1,
+    };
+
+    let num_elems = tensor.shape.num_elements();
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems / line_size as usize, cube_dim);
+
+    let out_layout = strided_layout(&output);
+
+    let scales = tensor.scales().unwrap();
+    let offsets = tensor.offsets();
+
+    unsafe {
+        dequantize_native_kernel::launch_unchecked::<F, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<i8>(line_size),
+            scales.as_tensor_arg::<f32>(1),
+            offsets
+                .as_ref()
+                .map(|offsets| offsets.as_tensor_arg::<f32>(1))
+                .into(),
+            output.as_tensor_arg::<F>(line_size),
+            out_layout,
+            scheme,
+            Some(tensor.shape.num_dims() as u32),
+        )
+    };
 
     output
 }
@@ -1,7 +1,9 @@
 mod dequantize;
+mod module;
 mod qtensor;
 mod quantize;
 
 pub use dequantize::*;
+pub(crate) use module::*;
 pub use qtensor::*;
 pub use quantize::*;
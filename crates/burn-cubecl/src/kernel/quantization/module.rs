@@ -0,0 +1,305 @@
+use crate::{
+    CubeRuntime, FloatElement, kernel::into_contiguous, ops::numeric::empty_device,
+    tensor::CubeTensor,
+};
+use burn_tensor::{
+    DType, Shape,
+    ops::{ConvOptions, conv::calculate_conv_output_size},
+    quantization::{QuantInputType, QuantLevel, QuantMode, QuantScheme},
+};
+use cubecl::std::CubeOption;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Returns the value of the bias at the given index, or zero without bias.
+#[cube]
+fn bias_value<F: Float>(bias: &CubeOption<Tensor<F>>, index: u32) -> F {
+    match bias {
+        CubeOption::Some(bias) => bias[index],
+        CubeOption::None => F::new(0.0),
+    }
+}
+
+/// Each unit computes an output feature of a row of the input, accumulating the products of the
+/// quantized values in i32 before rescaling the sum to float.
+#[cube(launch_unchecked)]
+fn q_linear_kernel<F: Float>(
+    input: &Tensor<i8>,
+    weight: &Tensor<i8>,
+    input_scale: &Tensor<f32>,
+    weight_scales: &Tensor<f32>,
+    bias: &CubeOption<Tensor<F>>,
+    output: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let d_input = weight.shape(0);
+    let d_output = weight.shape(1);
+    let row = ABSOLUTE_POS / d_output;
+    let col = ABSOLUTE_POS % d_output;
+
+    let mut acc = i32::from_int(0);
+    for k in 0..d_input {
+        acc +=
+            i32::cast_from(input[row * d_input + k]) * i32::cast_from(weight[k * d_output + col]);
+    }
+
+    let scale = input_scale[0] * weight_scales[col % weight_scales.len()];
+    output[ABSOLUTE_POS] = F::cast_from(acc) * F::cast_from(scale) + bias_value(bias, col);
+}
+
+/// Each unit copies a quantized value of the input to the columns of a group.
+///
+/// The columns of each group have one row per output position and one column per weight of an
+/// output channel. The zero-point of symmetric quantization is zero, so the padded values are set
+/// to zero.
+#[cube(launch_unchecked)]
+fn q_im2col_kernel(
+    input: &Tensor<i8>,
+    columns: &mut Tensor<i8>,
+    kernel_h: u32,
+    kernel_w: u32,
+    height_out: u32,
+    width_out: u32,
+    stride_h: u32,
+    stride_w: u32,
+    padding_h: u32,
+    padding_w: u32,
+    dilation_h: u32,
+    dilation_w: u32,
+) {
+    if ABSOLUTE_POS >= columns.len() {
+        terminate!();
+    }
+
+    let num_rows = columns.shape(1);
+    let num_cols = columns.shape(2);
+    let channels_in = input.shape(1);
+    let height_in = input.shape(2);
+    let width_in = input.shape(3);
+    let channels_per_group = num_cols / (kernel_h * kernel_w);
+
+    let k = ABSOLUTE_POS % num_cols;
+    let row = (ABSOLUTE_POS / num_cols) % num_rows;
+    let group = ABSOLUTE_POS / (num_cols * num_rows);
+
+    let b = row / (height_out * width_out);
+    let oh = (row / width_out) % height_out;
+    let ow = row % width_out;
+    let c = k / (kernel_h * kernel_w);
+    let kh = (k / kernel_w) % kernel_h;
+    let kw = k % kernel_w;
+
+    let ih = i32::cast_from(oh * stride_h + kh * dilation_h) - i32::cast_from(padding_h);
+    let iw = i32::cast_from(ow * stride_w + kw * dilation_w) - i32::cast_from(padding_w);
+
+    if ih >= 0 && ih < i32::cast_from(height_in) && iw >= 0 && iw < i32::cast_from(width_in) {
+        let ic = group * channels_per_group + c;
+        let ih = u32::cast_from(ih);
+        let iw = u32::cast_from(iw);
+        columns[ABSOLUTE_POS] = input[((b * channels_in + ic) * height_in + ih) * width_in + iw];
+    } else {
+        columns[ABSOLUTE_POS] = i8::from_int(0);
+    }
+}
+
+/// Each unit computes an output value of the convolution from the columns of its group,
+/// accumulating the products of the quantized values in i32 before rescaling the sum to float.
+#[cube(launch_unchecked)]
+fn q_conv2d_kernel<F: Float>(
+    columns: &Tensor<i8>,
+    weight: &Tensor<i8>,
+    input_scale: &Tensor<f32>,
+    weight_scales: &Tensor<f32>,
+    bias: &CubeOption<Tensor<F>>,
+    output: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let num_rows = columns.shape(1);
+    let num_cols = columns.shape(2);
+    let groups = columns.shape(0);
+    let channels_out = output.shape(1);
+    let spatial = output.shape(2) * output.shape(3);
+
+    let oc = (ABSOLUTE_POS / spatial) % channels_out;
+    let b = ABSOLUTE_POS / (spatial * channels_out);
+    let row = b * spatial + ABSOLUTE_POS % spatial;
+    let group = oc / (channels_out / groups);
+
+    let columns_offset = (group * num_rows + row) * num_cols;
+    let weight_offset = oc * num_cols;
+
+    let mut acc = i32::from_int(0);
+    for k in 0..num_cols {
+        acc +=
+            i32::cast_from(columns[columns_offset + k]) * i32::cast_from(weight[weight_offset + k]);
+    }
+
+    let scale = input_scale[0] * weight_scales[oc % weight_scales.len()];
+    output[ABSOLUTE_POS] = F::cast_from(acc) * F::cast_from(scale) + bias_value(bias, oc);
+}
+
+/// Whether the tensor is quantized to 8-bit integers with per-tensor symmetric quantization, or
+/// with per-channel symmetric quantization along `channel_axis` if given.
+///
+/// The integer values of such tensors can be multiplied directly, since their zero-point is zero.
+pub(crate) fn is_int8_symmetric(scheme: &QuantScheme, channel_axis: Option<usize>) -> bool {
+    match scheme {
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Symmetric,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => true,
+        QuantScheme {
+            level: QuantLevel::Channel(axis),
+            mode: QuantMode::Symmetric,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => Some(*axis) == channel_axis,
+        _ => false,
+    }
+}
+
+/// Reinterprets the values of a tensor quantized to 8-bit integers as i8, along with its scales.
+fn int8_values<R: CubeRuntime>(tensor: CubeTensor<R>) -> (CubeTensor<R>, CubeTensor<R>) {
+    let mut tensor = into_contiguous(tensor);
+    let scales = tensor.scales().unwrap();
+    tensor.dtype = DType::I8;
+
+    (tensor, scales)
+}
+
+/// Linear transformation of an input quantized to 8-bit integers with per-tensor symmetric
+/// quantization, by weights quantized per-tensor or per output feature.
+///
+/// The products are accumulated in i32, and the output is returned in floating point to be
+/// requantized by the caller.
+pub(crate) fn q_linear<R: CubeRuntime, F: FloatElement>(
+    input: CubeTensor<R>,
+    weight: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+) -> CubeTensor<R> {
+    let (input, input_scale) = int8_values(input);
+    let (weight, weight_scales) = int8_values(weight);
+    let bias = bias.map(into_contiguous);
+
+    let mut shape = input.shape.clone();
+    *shape.dims.last_mut().unwrap() = weight.shape.dims[1];
+    let output = empty_device::<R, F>(input.client.clone(), input.device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        q_linear_kernel::launch_unchecked::<F, R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg::<i8>(1),
+            weight.as_tensor_arg::<i8>(1),
+            input_scale.as_tensor_arg::<f32>(1),
+            weight_scales.as_tensor_arg::<f32>(1),
+            bias.as_ref().map(|bias| bias.as_tensor_arg::<F>(1)).into(),
+            output.as_tensor_arg::<F>(1),
+        );
+    }
+
+    output
+}
+
+/// 2D convolution of an input quantized to 8-bit integers with per-tensor symmetric quantization,
+/// by weights quantized per-tensor or per output channel.
+///
+/// The quantized input is unfolded into columns (im2col), then the products are accumulated in
+/// i32, and the output is returned in floating point to be requantized by the caller.
+pub(crate) fn q_conv2d<R: CubeRuntime, F: FloatElement>(
+    input: CubeTensor<R>,
+    weight: CubeTensor<R>,
+    bias: Option<CubeTensor<R>>,
+    options: ConvOptions<2>,
+) -> CubeTensor<R> {
+    let (input, input_scale) = int8_values(input);
+    let (weight, weight_scales) = int8_values(weight);
+    let bias = bias.map(into_contiguous);
+
+    let [batch_size, _, height_in, width_in] = input.shape.dims();
+    let [channels_out, channels_per_group, kernel_h, kernel_w] = weight.shape.dims();
+    let [stride_h, stride_w] = options.stride;
+    let [padding_h, padding_w] = options.padding;
+    let [dilation_h, dilation_w] = options.dilation;
+
+    let height_out =
+        calculate_conv_output_size(kernel_h, stride_h, padding_h, dilation_h, height_in);
+    let width_out = calculate_conv_output_size(kernel_w, stride_w, padding_w, dilation_w, width_in);
+
+    let output = empty_device::<R, F>(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([batch_size, channels_out, height_out, width_out]),
+    );
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let columns = empty_device::<R, i8>(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([
+            options.groups,
+            batch_size * height_out * width_out,
+            channels_per_group * kernel_h * kernel_w,
+        ]),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(columns.shape.num_elements(), cube_dim);
+
+    unsafe {
+        q_im2col_kernel::launch_unchecked::<R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg::<i8>(1),
+            columns.as_tensor_arg::<i8>(1),
+            ScalarArg::new(kernel_h as u32),
+            ScalarArg::new(kernel_w as u32),
+            ScalarArg::new(height_out as u32),
+            ScalarArg::new(width_out as u32),
+            ScalarArg::new(stride_h as u32),
+            ScalarArg::new(stride_w as u32),
+            ScalarArg::new(padding_h as u32),
+            ScalarArg::new(padding_w as u32),
+            ScalarArg::new(dilation_h as u32),
+            ScalarArg::new(dilation_w as u32),
+        );
+    }
+
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        q_conv2d_kernel::launch_unchecked::<F, R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            columns.as_tensor_arg::<i8>(1),
+            weight.as_tensor_arg::<i8>(1),
+            input_scale.as_tensor_arg::<f32>(1),
+            weight_scales.as_tensor_arg::<f32>(1),
+            bias.as_ref().map(|bias| bias.as_tensor_arg::<F>(1)).into(),
+            output.as_tensor_arg::<F>(1),
+        );
+    }
+
+    output
+}
@@ -1,7 +1,8 @@
 #![allow(missing_docs)] // cube derive macros
 
-use burn_tensor::quantization::{QuantInputType, QuantLevel, QuantMode, QuantScheme};
+use burn_tensor::quantization::{QuantLevel, QuantScheme};
 use cubecl::prelude::*;
+use cubecl::std::{CubeOption, CubeOptionExpand};
 
 /// Quantization parameters.
 #[derive(CubeLaunch, CubeType)]
@@ -20,27 +21,40 @@ impl QParams {
         QParams { scheme, num_quants }
     }
 
-    /// Get the quantization parameters values.
-    pub fn scale(&self, scale_tensor: &Tensor<f32>, in_pos: u32) -> f32 {
-        match comptime!(self.scheme) {
-            // Symmetric quantization only contains the scaling factor as the last element
-            QuantScheme {
-                level: QuantLevel::Tensor,
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => scale_tensor[0],
-            QuantScheme {
-                level: QuantLevel::Block(block_size),
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => {
-                // The input position is `num_quants` smaller because it acts as vectorize with a line
-                // size, but the scales don't have any line size.
-                let position = in_pos * self.num_quants;
-                scale_tensor[position / comptime! {block_size as u32}]
+    /// Get the index of the quantization parameters for the value at the given position.
+    ///
+    /// The tensor must have the logical shape of the quantized tensor, which is used to find the
+    /// channel of a value for per-channel quantization.
+    pub fn index<T: CubePrimitive>(&self, tensor: &Tensor<Line<T>>, in_pos: u32) -> u32 {
+        // The input position is `num_quants` smaller because it acts as vectorize with a line
+        // size, but the parameters don't have any line size.
+        let position = in_pos * self.num_quants;
+
+        match comptime!(self.scheme.level) {
+            // Per-tensor quantization only contains a single set of parameters
+            QuantLevel::Tensor => 0u32,
+            QuantLevel::Block(block_size) => position / comptime! {block_size as u32},
+            QuantLevel::Channel(axis) => {
+                let axis = comptime!(axis as u32);
+                let mut stride = 1u32;
+                for i in axis + 1..tensor.rank() {
+                    stride *= tensor.shape(i);
+                }
+                (position / stride) % tensor.shape(axis)
             }
         }
     }
+
+    /// Get the scaling factor at the given index.
+    pub fn scale(&self, scale_tensor: &Tensor<f32>, index: u32) -> f32 {
+        scale_tensor[index]
+    }
+
+    /// Get the zero-point offset at the given index, which is always zero for symmetric quantization.
+    pub fn offset(&self, offset_tensor: &CubeOption<Tensor<f32>>, index: u32) -> f32 {
+        match offset_tensor {
+            CubeOption::Some(offset_tensor) => offset_tensor[index],
+            CubeOption::None => f32::new(0.0),
+        }
+    }
 }
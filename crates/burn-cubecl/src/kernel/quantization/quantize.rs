@@ -1,45 +1,93 @@
+use super::QParams;
 use crate::{
     CubeRuntime, FloatElement,
     kernel::into_contiguous,
     ops::{empty_qtensor, max_line_size},
 };
 use crate::{kernel::utils::strided_layout, tensor::CubeTensor};
+use burn_common::{e4m3, e5m2};
 use burn_tensor::quantization::{
     QuantInputType, QuantLevel, QuantMode, QuantScheme, QuantStoreType,
 };
 use cubecl::calculate_cube_count_elemwise;
 use cubecl::prelude::*;
-use cubecl::std::tensor::{StridedLayout, index_offset_contiguous};
+use cubecl::std::{
+    CubeOption,
+    tensor::{StridedLayout, index_offset_contiguous},
+};
 
 #[cube]
-fn quantize_symmetric<F: Float>(value: Line<F>, scale: f32, range_min: F, range_max: F) -> Line<F> {
-    // x_q = clamp(round(x / scale), a, b)
+fn quantize_int<F: Float>(
+    value: Line<F>,
+    scale: f32,
+    offset: f32,
+    range_min: F,
+    range_max: F,
+) -> Line<F> {
+    // x_q = clamp(round(x / scale) + offset, a, b)
     Line::clamp(
-        Line::round(value / Line::cast_from(scale)),
+        Line::round(value / Line::cast_from(scale)) + Line::cast_from(offset),
         Line::new(range_min),
         Line::new(range_max),
     )
 }
 
 #[cube]
-fn quantize_symmetric_i<F: Float, I: Int>(
+fn quantize_int_i<F: Float, I: Int>(
     value: Line<F>,
     scale: f32,
+    offset: f32,
     range_min: F,
     range_max: F,
 ) -> Line<I> {
-    Line::cast_from(quantize_symmetric(value, scale, range_min, range_max))
+    Line::cast_from(quantize_int(value, scale, offset, range_min, range_max))
+}
+
+/// Quantize a line of values to the bits of the quantization input type.
+#[cube]
+fn quantize_native_value<F: Float>(
+    value: Line<F>,
+    scale: f32,
+    offset: f32,
+    range_min: F,
+    range_max: F,
+    #[comptime] q_type: QuantInputType,
+) -> Line<i8> {
+    match comptime!(q_type) {
+        QuantInputType::QInt8 => quantize_int_i(value, scale, offset, range_min, range_max),
+        // The fp8 values are clamped before the cast, which rounds to the nearest value
+        QuantInputType::E4M3 => {
+            let value = Line::clamp(
+                value / Line::cast_from(scale),
+                Line::new(range_min),
+                Line::new(range_max),
+            );
+            Line::reinterpret(Line::<e4m3>::cast_from(value))
+        }
+        QuantInputType::E5M2 => {
+            let value = Line::clamp(
+                value / Line::cast_from(scale),
+                Line::new(range_min),
+                Line::new(range_max),
+            );
+            Line::reinterpret(Line::<e5m2>::cast_from(value))
+        }
+        QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+            panic!("Native storage is not supported for {q_type:?}")
+        }
+    }
 }
 
 #[cube]
 fn quantize_packed_value<F: Float, QS: Int>(
     value: Line<F>,
     scale: f32,
+    offset: f32,
     range_min: F,
     range_max: F,
     #[comptime] scheme: QuantScheme,
 ) -> QS {
-    let value = quantize_symmetric(value, scale, range_min, range_max);
+    let value = quantize_int(value, scale, offset, range_min, range_max);
     pack_q::<F, QS>(value, scheme.q_type)
 }
 
@@ -50,7 +98,10 @@ fn quantize_packed_value<F: Float, QS: Int>(
 fn pack_q<F: Float, QS: Int>(value: Line<F>, #[comptime] quant: QuantInputType) -> QS {
     let size_quant = comptime!(match quant {
         QuantInputType::QInt8 => 8,
-        // Add more types (e.g., QInt4) here if needed
+        QuantInputType::QInt4 | QuantInputType::QUInt4 => 4,
+        QuantInputType::E4M3 | QuantInputType::E5M2 => {
+            panic!("Packed storage is not supported for {quant:?}")
+        }
     });
 
     let size_store = comptime!(QS::size_bits().unwrap() as u32);
@@ -72,70 +123,17 @@ fn pack_q<F: Float, QS: Int>(value: Line<F>, #[comptime] quant: QuantInputType)
     packed
 }
 
-#[cube]
-fn write_scale_per_tensor(in_pos: u32, scale: &Array<f32>, out_scale: &mut Array<f32>) -> f32 {
-    let scale = scale[0];
-
-    // Write the scale into the output buffer
-    if in_pos == 0 {
-        out_scale[in_pos] = scale;
-    }
-
-    scale
-}
-
-#[cube]
-fn write_scale_per_block(
-    in_pos: u32,
-    scale: &Array<f32>,
-    out_scale: &mut Array<f32>,
-    #[comptime] block_size: u32,
-) -> f32 {
-    let scale_pos = in_pos / block_size;
-    let scale = scale[scale_pos];
-
-    // Write the scale into the output buffer
-    if in_pos % block_size == 0 {
-        out_scale[scale_pos] = scale;
-    }
-
-    scale
-}
-
 #[cube(launch_unchecked)]
-fn quantize_per_tensor_symmetric_int8_kernel<F: Float>(
+fn quantize_native_kernel<F: Float>(
     input: &Tensor<Line<F>>,
-    scale: &Array<f32>,
+    scale: &Tensor<f32>,
+    offset: &CubeOption<Tensor<f32>>,
     range_min: F,
     range_max: F,
     output: &mut Tensor<Line<i8>>,
-    out_scale: &mut Array<f32>,
-    out_layout: StridedLayout,
-    #[comptime] rank: Option<u32>,
-) {
-    if ABSOLUTE_POS >= output.len() {
-        terminate!();
-    }
-
-    let scale = write_scale_per_tensor(ABSOLUTE_POS, scale, out_scale);
-
-    let in_pos = index_offset_contiguous(input, ABSOLUTE_POS, rank);
-    let out_pos = out_layout.index(output, ABSOLUTE_POS);
-
-    output[out_pos] = quantize_symmetric_i(input[in_pos], scale, range_min, range_max);
-}
-
-#[cube(launch_unchecked)]
-fn quantize_per_block_symmetric_int8_kernel<F: Float>(
-    input: &Tensor<Line<F>>,
-    scale: &Array<f32>,
-    range_min: F,
-    range_max: F,
-    output: &mut Tensor<Line<i8>>,
-    out_scale: &mut Array<f32>,
     out_layout: StridedLayout,
+    #[comptime] scheme: QuantScheme,
     #[comptime] rank: Option<u32>,
-    #[comptime] block_size: u32,
 ) {
     if ABSOLUTE_POS >= output.len() {
         terminate!();
@@ -144,75 +142,57 @@ fn quantize_per_block_symmetric_int8_kernel<F: Float>(
     let in_pos = index_offset_contiguous(input, ABSOLUTE_POS, rank);
     let out_pos = out_layout.index(output, ABSOLUTE_POS);
 
-    let scale = write_scale_per_block(in_pos * input.line_size(), scale, out_scale, block_size);
-
-    output[out_pos] = quantize_symmetric_i(input[in_pos], scale, range_min, range_max);
+    let qparams = QParams::new(scheme);
+    // Absolute pos represents the logical position used to find the quantization parameters
+    let index = qparams.index(input, ABSOLUTE_POS * input.line_size());
+    let scale = qparams.scale(scale, index);
+    let offset = qparams.offset(offset, index);
+
+    output[out_pos] = quantize_native_value(
+        input[in_pos],
+        scale,
+        offset,
+        range_min,
+        range_max,
+        scheme.q_type,
+    );
 }
 
 #[cube(launch_unchecked)]
-fn quantize_per_tensor_symmetric_int8_packed_kernel<F: Float>(
+fn quantize_packed_kernel<F: Float>(
     input: &Tensor<Line<F>>,
-    scale: &Array<f32>,
+    scale: &Tensor<f32>,
+    offset: &CubeOption<Tensor<f32>>,
     range_min: F,
     range_max: F,
     output: &mut Array<u32>,
-    out_scale: &mut Array<f32>,
     #[comptime] scheme: QuantScheme,
 ) {
     if ABSOLUTE_POS >= output.len() {
         terminate!();
     }
 
-    let scale = write_scale_per_tensor(ABSOLUTE_POS, scale, out_scale);
-
-    let num_quants = comptime!((scheme.bits_stored() / scheme.bits_type()) as u32);
-    if comptime!(input.line_size() == num_quants) {
-        output[ABSOLUTE_POS] = quantize_packed_value::<F, u32>(
-            input[ABSOLUTE_POS],
-            scale,
-            range_min,
-            range_max,
-            scheme,
-        );
-    } else {
-        // Input line size = 1
-        let mut values = Line::<F>::empty(num_quants);
-        #[unroll]
-        for i in 0..num_quants {
-            values[i] = input[ABSOLUTE_POS * num_quants + i][0];
-        }
-        output[ABSOLUTE_POS] =
-            quantize_packed_value::<F, u32>(values, scale, range_min, range_max, scheme);
-    }
-}
-
-#[cube(launch_unchecked)]
-fn quantize_per_block_symmetric_int8_packed_kernel<F: Float>(
-    input: &Tensor<Line<F>>,
-    scale: &Array<f32>,
-    range_min: F,
-    range_max: F,
-    output: &mut Array<u32>,
-    out_scale: &mut Array<f32>,
-    #[comptime] scheme: QuantScheme,
-    #[comptime] block_size: u32,
-) {
-    if ABSOLUTE_POS >= output.len() {
-        terminate!();
-    }
+    // Input line size = 1
+    let qparams = QParams::new(scheme);
+    let num_quants = comptime!(qparams.num_quants);
+    let index = qparams.index(input, ABSOLUTE_POS);
+    let scale = qparams.scale(scale, index);
+    let offset = qparams.offset(offset, index);
 
-    // Input line size 1
-    let num_quants = comptime!((scheme.bits_stored() / scheme.bits_type()) as u32);
     let packed_pos = ABSOLUTE_POS * num_quants;
-    let scale = write_scale_per_block(packed_pos, scale, out_scale, block_size);
-
     let mut values = Line::<F>::empty(num_quants);
     #[unroll]
     for i in 0..num_quants {
-        values[i] = input[packed_pos + i][0];
+        // The last packed value is padded when the values don't fill it completely
+        let mut value = F::new(0.0);
+        if packed_pos + i < input.len() {
+            value = input[packed_pos + i][0];
+        }
+        values[i] = value;
     }
+
     output[ABSOLUTE_POS] =
-        quantize_packed_value::<F, u32>(values, scale, range_min, range_max, scheme);
+        quantize_packed_value::<F, u32>(values, scale, offset, range_min, range_max, scheme);
 }
 
 /// Convert the tensor to a lower precision data type based on the quantization scheme and parameters.
@@ -220,6 +200,7 @@ pub fn quantize<R, F>(
     tensor: CubeTensor<R>,
     scheme: &QuantScheme,
     scale: CubeTensor<R>,
+    offset: Option<CubeTensor<R>>,
 ) -> CubeTensor<R>
 where
     R: CubeRuntime,
@@ -227,12 +208,25 @@ where
 {
     let output = empty_qtensor(tensor.shape.clone(), *scheme, &tensor.device);
 
-    match scheme {
+    let output = match scheme {
         QuantScheme {
-            q_type: QuantInputType::QInt8,
+            mode: QuantMode::Affine,
+            q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+            ..
+        } => panic!(
+            "Affine quantization is not supported for {:?}",
+            scheme.q_type
+        ),
+        QuantScheme {
+            q_type: QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4,
             q_store_type: QuantStoreType::U32,
             ..
-        } => quantize_packed::<R, F>(tensor, scheme, scale, output),
+        } => quantize_packed::<R, F>(tensor, scheme, &scale, offset.as_ref(), output),
+        QuantScheme {
+            q_type: QuantInputType::QInt4 | QuantInputType::QUInt4,
+            q_store_type: QuantStoreType::Native,
+            ..
+        } => panic!("Native storage is not supported for {:?}", scheme.q_type),
         QuantScheme {
             q_type: QuantInputType::QInt8,
             q_store_type: QuantStoreType::Native,
@@ -242,7 +236,65 @@ where
                 panic!("QInt8 is not supported for native quantization");
             }
 
-            quantize_native::<R, F>(tensor, scheme, scale, output)
+            quantize_native::<R, F>(tensor, scheme, &scale, offset.as_ref(), output)
+        }
+        QuantScheme {
+            q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+            q_store_type: QuantStoreType::Native,
+            ..
+        } => {
+            if !e4m3::is_supported(&tensor.client) || !e5m2::is_supported(&tensor.client) {
+                panic!(
+                    "{:?} is not supported for native quantization",
+                    scheme.q_type
+                );
+            }
+
+            quantize_native::<R, F>(tensor, scheme, &scale, offset.as_ref(), output)
+        }
+        QuantScheme {
+            q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+            q_store_type: QuantStoreType::U32,
+            ..
+        } => panic!("Packed storage is not supported for {:?}", scheme.q_type),
+    };
+
+    write_qparams(scale, offset, &output);
+
+    output
+}
+
+/// Write the quantization parameters into the quantized tensor buffer.
+fn write_qparams<R: CubeRuntime>(
+    scale: CubeTensor<R>,
+    offset: Option<CubeTensor<R>>,
+    output: &CubeTensor<R>,
+) {
+    let out_scale = output.scales().unwrap();
+    cubecl::std::tensor::into_contiguous_ref::<R, f32>(
+        &scale.client,
+        &scale.as_handle_ref(),
+        &out_scale.as_handle_ref(),
+    );
+
+    if let (Some(offset), Some(out_offset)) = (offset, output.offsets()) {
+        cubecl::std::tensor::into_contiguous_ref::<R, f32>(
+            &offset.client,
+            &offset.as_handle_ref(),
+            &out_offset.as_handle_ref(),
+        );
+    }
+}
+
+/// The range of the quantized values.
+fn quantization_range(scheme: &QuantScheme) -> (i64, i64) {
+    // The fp8 formats saturate to their largest finite magnitude
+    match scheme.q_type {
+        QuantInputType::E4M3 => (-448, 448),
+        QuantInputType::E5M2 => (-57344, 57344),
+        QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+            let (a, b) = scheme.q_type.range(scheme.mode);
+            (a as i64, b as i64)
         }
     }
 }
@@ -250,71 +302,50 @@ where
 fn quantize_native<R: CubeRuntime, F: FloatElement>(
     tensor: CubeTensor<R>,
     scheme: &QuantScheme,
-    scale: CubeTensor<R>,
+    scale: &CubeTensor<R>,
+    offset: Option<&CubeTensor<R>>,
     output: CubeTensor<R>,
 ) -> CubeTensor<R> {
     let client = tensor.client.clone();
     let num_elems = tensor.shape.num_elements();
 
     let out_layout = strided_layout(&output);
-    let out_scale = output.scales().unwrap();
-
-    let line_size = max_line_size(&tensor);
-    let cube_dim = CubeDim::default();
-    let cube_count = calculate_cube_count_elemwise(num_elems / line_size as usize, cube_dim);
 
-    match scheme {
-        QuantScheme {
-            level: QuantLevel::Tensor,
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
-            unsafe {
-                quantize_per_tensor_symmetric_int8_kernel::launch_unchecked::<F, R>(
-                    &client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<F>(line_size),
-                    scale.as_array_arg::<f32>(1),
-                    ScalarArg::new(F::from_int(-i8::MAX as i64)),
-                    ScalarArg::new(F::from_int(i8::MAX as i64)),
-                    output.as_tensor_arg::<i8>(line_size),
-                    out_scale.as_array_arg::<f32>(1),
-                    out_layout,
-                    Some(tensor.shape.num_dims() as u32),
-                )
-            };
-        }
-        QuantScheme {
-            level: QuantLevel::Block(block_size),
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
+    let line_size = match scheme.level {
+        QuantLevel::Tensor => max_line_size(&tensor),
+        QuantLevel::Block(block_size) => {
             // We could use line_size = block_size if it's in the supported line sizes.. but let's keep it simple
+            let line_size = max_line_size(&tensor);
             assert!(
-                *block_size as u8 % line_size == 0,
+                block_size as u8 % line_size == 0,
                 "Block size must be divisible by line size, got block_size={block_size}, line_size={line_size}"
             );
-            unsafe {
-                quantize_per_block_symmetric_int8_kernel::launch_unchecked::<F, R>(
-                    &client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<F>(line_size),
-                    scale.as_array_arg::<f32>(1),
-                    ScalarArg::new(F::from_int(-i8::MAX as i64)),
-                    ScalarArg::new(F::from_int(i8::MAX as i64)),
-                    output.as_tensor_arg::<i8>(line_size),
-                    out_scale.as_array_arg::<f32>(1),
-                    out_layout,
-                    Some(tensor.shape.num_dims() as u32),
-                    *block_size as u32,
-                )
-            };
+            line_size
         }
-    }
+        // The values of a line could belong to different channels
+        QuantLevel::Channel(_) => 1,
+    };
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems / line_size as usize, cube_dim);
+
+    let (range_min, range_max) = quantization_range(scheme);
+
+    unsafe {
+        quantize_native_kernel::launch_unchecked::<F, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<F>(line_size),
+            scale.as_tensor_arg::<f32>(1),
+            offset.map(|offset| offset.as_tensor_arg::<f32>(1)).into(),
+            ScalarArg::new(F::from_int(range_min)),
+            ScalarArg::new(F::from_int(range_max)),
+            output.as_tensor_arg::<i8>(line_size),
+            out_layout,
+            *scheme,
+            Some(tensor.shape.num_dims() as u32),
+        )
+    };
 
     output
 }
@@ -322,73 +353,55 @@ fn quantize_native<R: CubeRuntime, F: FloatElement>(
 fn quantize_packed<R: CubeRuntime, F: FloatElement>(
     tensor: CubeTensor<R>,
     scheme: &QuantScheme,
-    scale: CubeTensor<R>,
+    scale: &CubeTensor<R>,
+    offset: Option<&CubeTensor<R>>,
     output: CubeTensor<R>,
 ) -> CubeTensor<R> {
     let tensor = into_contiguous(tensor);
     let client = tensor.client.clone();
-    // Output tensor contains 4x less elements (four int8 values packed in a single u32)
-    let num_elems = tensor.shape.num_elements();
-
-    let out_scale = output.scales().unwrap();
+    // Output tensor contains less elements (multiple quantized values packed in a single u32)
+    let num_quants = (scheme.bits_stored() / scheme.bits_type()) as usize;
+    let num_elems = tensor.shape.num_elements().div_ceil(num_quants);
+
+    match scheme.level {
+        QuantLevel::Tensor => {}
+        QuantLevel::Block(block_size) => assert!(
+            block_size % num_quants == 0,
+            "Block size must be divisible by {num_quants}, got block_size={block_size}"
+        ),
+        QuantLevel::Channel(axis) => {
+            // The values packed together must belong to the same channel
+            let stride: usize = tensor.shape.dims[axis + 1..].iter().product();
+            assert!(
+                stride % num_quants == 0,
+                "Channel stride must be divisible by {num_quants}, got stride={stride}"
+            );
+        }
+    }
 
-    // Force vectorization to process 4 quantized values packed for 1 output value
+    // Force vectorization to process the quantized values packed for 1 output value
     let line_size: u8 = 1;
     let cube_dim = CubeDim::default();
-    let cube_count =
-        calculate_cube_count_elemwise(num_elems.div_ceil(line_size as usize), cube_dim);
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
 
     // TODO: line_size_in based on num_quants similar to dequant
 
-    match scheme {
-        QuantScheme {
-            level: QuantLevel::Tensor,
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
-            unsafe {
-                quantize_per_tensor_symmetric_int8_packed_kernel::launch_unchecked::<F, R>(
-                    &client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<F>(line_size),
-                    scale.as_array_arg::<f32>(1),
-                    ScalarArg::new(F::from_int(-i8::MAX as i64)),
-                    ScalarArg::new(F::from_int(i8::MAX as i64)),
-                    output.as_array_arg::<u32>(1),
-                    out_scale.as_array_arg::<f32>(1),
-                    *scheme,
-                )
-            };
-        }
-        QuantScheme {
-            level: QuantLevel::Block(block_size),
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
-            assert!(
-                *block_size % 4 == 0,
-                "Block size must be divisible by 4, got block_size={block_size}"
-            );
-            unsafe {
-                quantize_per_block_symmetric_int8_packed_kernel::launch_unchecked::<F, R>(
-                    &client,
-                    cube_count,
-                    cube_dim,
-                    tensor.as_tensor_arg::<F>(line_size),
-                    scale.as_array_arg::<f32>(1),
-                    ScalarArg::new(F::from_int(-i8::MAX as i64)),
-                    ScalarArg::new(F::from_int(i8::MAX as i64)),
-                    output.as_array_arg::<u32>(1),
-                    out_scale.as_array_arg::<f32>(1),
-                    *scheme,
-                    *block_size as u32,
-                )
-            };
-        }
-    }
+    let (range_min, range_max) = quantization_range(scheme);
+
+    unsafe {
+        quantize_packed_kernel::launch_unchecked::<F, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<F>(line_size),
+            scale.as_tensor_arg::<f32>(1),
+            offset.map(|offset| offset.as_tensor_arg::<f32>(1)).into(),
+            ScalarArg::new(F::from_int(range_min)),
+            ScalarArg::new(F::from_int(range_max)),
+            output.as_array_arg::<u32>(1),
+            *scheme,
+        )
+    };
 
     output
 }
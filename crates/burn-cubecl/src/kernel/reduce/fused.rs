@@ -0,0 +1,224 @@
+use crate::{
+    BoolElement, CubeRuntime,
+    element::{CubeElement, FloatElement},
+    kernel::into_contiguous,
+    ops::numeric::empty_device,
+    tensor::CubeTensor,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Each cube reduces a single line of the tensor along `dim` with an online log-sum-exp, where
+/// the running sum is rescaled every time a new maximum is found.
+///
+/// The units of the cube reduce interleaved elements of the line, then the partial results are
+/// merged in shared memory with a tree reduction.
+#[cube(launch_unchecked)]
+fn logsumexp_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    dim: u32,
+    #[comptime] cube_size: u32,
+) {
+    if CUBE_POS >= output.len() {
+        terminate!();
+    }
+
+    let stride = input.stride(dim);
+    let size = input.shape(dim);
+    let offset = (CUBE_POS / stride) * stride * size + CUBE_POS % stride;
+
+    let mut max = F::new(f32::NEG_INFINITY);
+    let mut sum = F::new(0.0);
+
+    for i in range_stepped(UNIT_POS, size, cube_size) {
+        let value = input[offset + i * stride];
+
+        // Equal values are handled apart to avoid `inf - inf` with infinite values.
+        if value == max {
+            sum += F::new(1.0);
+        } else if value > max {
+            sum = sum * Exp::exp(max - value) + F::new(1.0);
+            max = value;
+        } else {
+            sum += Exp::exp(value - max);
+        }
+    }
+
+    let mut shared_max = SharedMemory::<F>::new(cube_size);
+    let mut shared_sum = SharedMemory::<F>::new(cube_size);
+    shared_max[UNIT_POS] = max;
+    shared_sum[UNIT_POS] = sum;
+    sync_cube();
+
+    let mut active = cube_size / 2;
+    while active > 0 {
+        if UNIT_POS < active {
+            let max = shared_max[UNIT_POS];
+            let sum = shared_sum[UNIT_POS];
+            let other_max = shared_max[UNIT_POS + active];
+            let other_sum = shared_sum[UNIT_POS + active];
+
+            if other_max == max {
+                shared_sum[UNIT_POS] = sum + other_sum;
+            } else if other_max > max {
+                shared_max[UNIT_POS] = other_max;
+                shared_sum[UNIT_POS] = sum * Exp::exp(max - other_max) + other_sum;
+            } else {
+                shared_sum[UNIT_POS] = sum + other_sum * Exp::exp(other_max - max);
+            }
+        }
+        sync_cube();
+        active /= 2;
+    }
+
+    if UNIT_POS == 0 {
+        output[CUBE_POS] = shared_max[0] + Log::log(shared_sum[0]);
+    }
+}
+
+/// Each cube sums the masked elements of a single line of the tensor along `dim`.
+///
+/// The units of the cube sum interleaved elements of the line, then the partial sums are merged
+/// in shared memory with a tree reduction.
+#[cube(launch_unchecked)]
+fn sum_masked_kernel<N: Numeric, B: Int>(
+    input: &Tensor<N>,
+    mask: &Tensor<B>,
+    output: &mut Tensor<N>,
+    dim: u32,
+    #[comptime] cube_size: u32,
+) {
+    if CUBE_POS >= output.len() {
+        terminate!();
+    }
+
+    let stride = input.stride(dim);
+    let size = input.shape(dim);
+    let offset = (CUBE_POS / stride) * stride * size + CUBE_POS % stride;
+
+    let mut sum = N::from_int(0);
+
+    for i in range_stepped(UNIT_POS, size, cube_size) {
+        let index = offset + i * stride;
+
+        // The unmasked elements are skipped instead of multiplied by zero to discard NaN values.
+        if bool::cast_from(mask[index]) {
+            sum += input[index];
+        }
+    }
+
+    let mut shared_sum = SharedMemory::<N>::new(cube_size);
+    shared_sum[UNIT_POS] = sum;
+    sync_cube();
+
+    let mut active = cube_size / 2;
+    while active > 0 {
+        if UNIT_POS < active {
+            shared_sum[UNIT_POS] += shared_sum[UNIT_POS + active];
+        }
+        sync_cube();
+        active /= 2;
+    }
+
+    if UNIT_POS == 0 {
+        output[CUBE_POS] = shared_sum[0];
+    }
+}
+
+/// Maximum number of units reducing a line.
+const MAX_CUBE_SIZE: usize = 256;
+
+/// Returns the number of units reducing each line of `size` elements, as a power of two so the
+/// partial results can be merged pairwise.
+fn reduce_cube_size(size: usize) -> u32 {
+    Ord::min(size.next_power_of_two(), MAX_CUBE_SIZE) as u32
+}
+
+/// Creates the output of a reduction of the tensor along `dim`.
+fn reduce_output<R: CubeRuntime, E: CubeElement>(
+    tensor: &CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    let mut shape = tensor.shape.clone();
+    shape.dims[dim] = 1;
+
+    empty_device::<R, E>(tensor.client.clone(), tensor.device.clone(), shape)
+}
+
+/// Log-sum-exp of the tensor along `dim`, computed in a single pass with one cube per line.
+pub(crate) fn logsumexp<R: CubeRuntime, E: FloatElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let output = reduce_output::<R, E>(&tensor, dim);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    // One cube per line.
+    let cube_size = reduce_cube_size(tensor.shape.dims[dim]);
+    let cube_dim = CubeDim {
+        x: cube_size,
+        y: 1,
+        z: 1,
+    };
+    let cube_count = calculate_cube_count_elemwise(num_elems * cube_size as usize, cube_dim);
+
+    unsafe {
+        logsumexp_kernel::launch_unchecked::<E, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+            cube_size,
+        );
+    }
+
+    output
+}
+
+/// Sum of the elements of the tensor selected by the mask along `dim`, computed in a single pass
+/// with one cube per line.
+pub(crate) fn sum_masked_dim<R: CubeRuntime, E: CubeElement, BT: BoolElement>(
+    tensor: CubeTensor<R>,
+    mask: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let mask = into_contiguous(mask);
+    let output = reduce_output::<R, E>(&tensor, dim);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    // One cube per line.
+    let cube_size = reduce_cube_size(tensor.shape.dims[dim]);
+    let cube_dim = CubeDim {
+        x: cube_size,
+        y: 1,
+        z: 1,
+    };
+    let cube_count = calculate_cube_count_elemwise(num_elems * cube_size as usize, cube_dim);
+
+    unsafe {
+        sum_masked_kernel::launch_unchecked::<E, BT, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            mask.as_tensor_arg::<BT>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+            cube_size,
+        );
+    }
+
+    output
+}
@@ -1,7 +1,9 @@
 mod base;
+mod fused;
 #[cfg(feature = "autotune")]
 mod tune;
 
 pub use base::*;
+pub(crate) use fused::*;
 #[cfg(feature = "autotune")]
 pub use tune::*;
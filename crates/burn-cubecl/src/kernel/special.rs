@@ -0,0 +1,370 @@
+//! Special mathematical functions written from the float primitives available in kernels.
+//!
+//! The approximations mirror the default implementations of
+//! [FloatTensorOps](burn_tensor::ops::FloatTensorOps), so that all backends agree on the results.
+
+use cubecl::prelude::*;
+
+#[cube]
+fn lined<F: Float>(x: &Line<F>, #[comptime] v: f32) -> Line<F> {
+    Line::empty(x.size()).fill(F::new(v))
+}
+
+#[cube]
+fn filled<F: Float>(x: &Line<F>, v: F) -> Line<F> {
+    Line::empty(x.size()).fill(v)
+}
+
+#[cube]
+fn negate<F: Float>(x: Line<F>) -> Line<F> {
+    lined(&x, 0.0) - x
+}
+
+/// Inverse tangent, using the range reduction and the polynomial of the Cephes library.
+#[cube]
+pub(crate) fn atan<F: Float>(x: Line<F>) -> Line<F> {
+    let abs = Line::abs(x);
+    let large = abs.greater_than(lined(&x, 2.414_213_5));
+    let medium = abs.greater_than(lined(&x, 0.414_213_57));
+
+    let reduced = select_many(medium, (abs - lined(&x, 1.0)) / (abs + lined(&x, 1.0)), abs);
+    let reduced = select_many(large, negate(Line::recip(abs)), reduced);
+    let offset = select_many(
+        medium,
+        lined(&x, core::f32::consts::FRAC_PI_4),
+        lined(&x, 0.0),
+    );
+    let offset = select_many(large, lined(&x, core::f32::consts::FRAC_PI_2), offset);
+
+    let squared = reduced * reduced;
+    let mut series = lined(&x, 0.080_537_446) * squared + lined(&x, -0.138_776_86);
+    series = series * squared + lined(&x, 0.199_777_1);
+    series = series * squared + lined(&x, -0.333_329_5);
+    let output = offset + series * squared * reduced + reduced;
+
+    let zero = lined(&x, 0.0);
+    select_many(x.less_than(zero), negate(output), output)
+}
+
+/// Four-quadrant inverse tangent of `y / x`.
+#[cube]
+pub(crate) fn atan2<F: Float>(y: Line<F>, x: Line<F>) -> Line<F> {
+    let zero = lined(&x, 0.0);
+    let half_turn = select_many(
+        y.less_than(zero),
+        negate(lined(&x, core::f32::consts::PI)),
+        lined(&x, core::f32::consts::PI),
+    );
+
+    let output = atan(y / x);
+    let output = select_many(x.less_than(zero), output + half_turn, output);
+    let output = select_many(x.equal(zero), half_turn / lined(&x, 2.0), output);
+
+    select_many((Line::abs(x) + Line::abs(y)).equal(zero), zero, output)
+}
+
+/// Computes `sqrt((1 - x) (1 + x))`, which is more accurate than `sqrt(1 - x^2)` near one.
+#[cube]
+fn complement_sqrt<F: Float>(x: Line<F>) -> Line<F> {
+    Line::sqrt((lined(&x, 1.0) - x) * (lined(&x, 1.0) + x))
+}
+
+/// Inverse sine, computed as `atan2(x, sqrt(1 - x^2))`.
+#[cube]
+pub(crate) fn asin<F: Float>(x: Line<F>) -> Line<F> {
+    atan2(x, complement_sqrt(x))
+}
+
+/// Inverse cosine, computed as `atan2(sqrt(1 - x^2), x)`.
+#[cube]
+pub(crate) fn acos<F: Float>(x: Line<F>) -> Line<F> {
+    atan2(complement_sqrt(x), x)
+}
+
+/// Inverse hyperbolic sine, computed as `sign(x) log1p(|x| + x^2 / (1 + sqrt(1 + x^2)))`.
+#[cube]
+pub(crate) fn asinh<F: Float>(x: Line<F>) -> Line<F> {
+    let abs = Line::abs(x);
+    let squared = abs * abs;
+    let root = Line::sqrt(squared + lined(&x, 1.0)) + lined(&x, 1.0);
+    let output = Line::log1p(abs + squared / root);
+
+    let zero = lined(&x, 0.0);
+    select_many(x.less_than(zero), negate(output), output)
+}
+
+/// Inverse hyperbolic cosine, computed as `log1p((x - 1) + sqrt((x - 1) (x + 1)))`.
+#[cube]
+pub(crate) fn acosh<F: Float>(x: Line<F>) -> Line<F> {
+    let minus_one = x - lined(&x, 1.0);
+    Line::log1p(minus_one + Line::sqrt(minus_one * (x + lined(&x, 1.0))))
+}
+
+/// Inverse hyperbolic tangent, computed as `log1p(2x / (1 - x)) / 2`.
+#[cube]
+pub(crate) fn atanh<F: Float>(x: Line<F>) -> Line<F> {
+    Line::log1p(lined(&x, 2.0) * x / (lined(&x, 1.0) - x)) * lined(&x, 0.5)
+}
+
+/// Computes `exp(x) - 1`, using `2 tanh(x/2) / (1 - tanh(x/2))` near zero to avoid cancellation.
+#[cube]
+pub(crate) fn expm1<F: Float>(x: Line<F>) -> Line<F> {
+    let tanh = Line::tanh(x * lined(&x, 0.5));
+    let output_small = lined(&x, 2.0) * tanh / (lined(&x, 1.0) - tanh);
+
+    select_many(
+        Line::abs(x).less_than(lined(&x, 0.5)),
+        output_small,
+        Line::exp(x) - lined(&x, 1.0),
+    )
+}
+
+/// Complementary error function.
+#[cube]
+pub(crate) fn erfc<F: Float>(x: Line<F>) -> Line<F> {
+    lined(&x, 1.0) - Line::erf(x)
+}
+
+/// Base 2 logarithm.
+#[cube]
+pub(crate) fn log2<F: Float>(x: Line<F>) -> Line<F> {
+    Line::log(x) * lined(&x, core::f32::consts::LOG2_E)
+}
+
+/// Base 10 logarithm.
+#[cube]
+pub(crate) fn log10<F: Float>(x: Line<F>) -> Line<F> {
+    Line::log(x) * lined(&x, core::f32::consts::LOG10_E)
+}
+
+/// Computes `sqrt(x^2 + y^2)`.
+#[cube]
+pub(crate) fn hypot<F: Float>(x: Line<F>, y: Line<F>) -> Line<F> {
+    Line::sqrt(x * x + y * y)
+}
+
+/// Natural logarithm of the absolute value of the gamma function, using the recurrence
+/// `lgamma(x) = lgamma(x + 6) - ln(x (x + 1) ... (x + 5))` up to Stirling's series and the
+/// reflection formula for `x < 0.5`.
+#[cube]
+pub(crate) fn lgamma<F: Float>(x: Line<F>) -> Line<F> {
+    let reflect = x.less_than(lined(&x, 0.5));
+    let z = select_many(reflect, lined(&x, 1.0) - x, x);
+
+    // Only small values are shifted, the product is clamped to avoid overflows elsewhere.
+    let shifted = z.less_than(lined(&x, 6.0));
+    let y = select_many(shifted, z + lined(&x, 6.0), z);
+    let clamped = select_many(shifted, z, lined(&x, 6.0));
+    let mut product = clamped;
+    let mut term = clamped;
+    #[unroll]
+    for _ in 1..6 {
+        term += lined(&x, 1.0);
+        product *= term;
+    }
+
+    // (y - 0.5) ln(y) - y + 0.5 ln(2 pi) + series
+    let y_inv = Line::recip(y);
+    let y_inv2 = y_inv * y_inv;
+    let mut series = lined(&x, -5.952_381e-4) * y_inv2 + lined(&x, 7.936_508e-4);
+    series = series * y_inv2 + lined(&x, -2.777_778e-3);
+    series = series * y_inv2 + lined(&x, 8.333_333e-2);
+    let output = (y - lined(&x, 0.5)) * Line::log(y) - y + series * y_inv + lined(&x, 0.918_938_5);
+    let output = select_many(shifted, output - Line::log(product), output);
+
+    let sin = Line::abs(Line::sin(x * lined(&x, core::f32::consts::PI)));
+    let reflected = negate(Line::log(sin / lined(&x, core::f32::consts::PI))) - output;
+
+    select_many(reflect, reflected, output)
+}
+
+/// Digamma function, using the recurrence `psi(x) = psi(x + 1) - 1 / x` up to the asymptotic
+/// expansion and the reflection formula `psi(x) = psi(1 - x) - pi / tan(pi x)` for `x < 0.5`.
+#[cube]
+pub(crate) fn digamma<F: Float>(x: Line<F>) -> Line<F> {
+    let reflect = x.less_than(lined(&x, 0.5));
+    let mut y = select_many(reflect, lined(&x, 1.0) - x, x);
+
+    let mut shift = lined(&x, 0.0);
+    #[unroll]
+    for _ in 0..10 {
+        shift += Line::recip(y);
+        y += lined(&x, 1.0);
+    }
+
+    // ln(y) - 1 / (2y) - sum B_2k / (2k y^2k)
+    let y_inv = Line::recip(y);
+    let y_inv2 = y_inv * y_inv;
+    let mut series = lined(&x, -7.575_758e-3) * y_inv2 + lined(&x, 4.166_667e-3);
+    series = series * y_inv2 + lined(&x, -3.968_254e-3);
+    series = series * y_inv2 + lined(&x, 8.333_333e-3);
+    series = series * y_inv2 + lined(&x, -8.333_333e-2);
+    let output = Line::log(y) - y_inv * lined(&x, 0.5) + series * y_inv2 - shift;
+
+    let angle = x * lined(&x, core::f32::consts::PI);
+    let cot = Line::cos(angle) / Line::sin(angle);
+    let reflected = output - cot * lined(&x, core::f32::consts::PI);
+
+    select_many(reflect, reflected, output)
+}
+
+/// Raises a line to a positive integer power with repeated multiplications, which is exact for
+/// negative bases.
+#[cube]
+fn powi<F: Float>(x: Line<F>, exponent: u32) -> Line<F> {
+    let mut output = x;
+    for _ in 1..exponent {
+        output *= x;
+    }
+    output
+}
+
+/// Polygamma function of order `n >= 1`, computed from the Hurwitz zeta function with
+/// `psi_n(x) = (-1)^(n + 1) n! zeta(n + 1, x)`.
+#[cube]
+pub(crate) fn polygamma<F: Float>(x: Line<F>, order: u32) -> Line<F> {
+    let s = order + 1;
+
+    let mut y = x;
+    let mut output = lined(&x, 0.0);
+    #[unroll]
+    for _ in 0..10 {
+        output += powi(Line::recip(y), s);
+        y += lined(&x, 1.0);
+    }
+
+    // y^-s (y / (s - 1) + 1 / 2 + sum B_2j / (2j)! s (s + 1) ... (s + 2j - 2) y^(1 - 2j))
+    let y_inv = Line::recip(y);
+    let y_inv2 = y_inv * y_inv;
+    let s_float = F::cast_from(s);
+    let mut tail = y / filled(&x, F::cast_from(order)) + lined(&x, 0.5);
+    let mut factor = s_float;
+    let mut power = y_inv;
+    tail += power * filled(&x, factor * F::new(8.333_333e-2));
+    factor *= (s_float + F::new(1.0)) * (s_float + F::new(2.0));
+    power *= y_inv2;
+    tail += power * filled(&x, factor * F::new(-1.388_888_9e-3));
+    factor *= (s_float + F::new(3.0)) * (s_float + F::new(4.0));
+    power *= y_inv2;
+    tail += power * filled(&x, factor * F::new(3.306_878_3e-5));
+    factor *= (s_float + F::new(5.0)) * (s_float + F::new(6.0));
+    power *= y_inv2;
+    tail += power * filled(&x, factor * F::new(-8.267_196e-7));
+    factor *= (s_float + F::new(7.0)) * (s_float + F::new(8.0));
+    power *= y_inv2;
+    tail += power * filled(&x, factor * F::new(2.087_676_6e-8));
+    factor *= (s_float + F::new(9.0)) * (s_float + F::new(10.0));
+    power *= y_inv2;
+    tail += power * filled(&x, factor * F::new(-5.284_190_1e-10));
+    output += tail * powi(y_inv, s);
+
+    // (-1)^(n + 1) n!
+    let mut scale = F::new(-1.0);
+    for k in 1..order + 1 {
+        scale *= F::new(0.0) - F::cast_from(k);
+    }
+
+    output * filled(&x, scale)
+}
+
+/// Inverse error function, using the approximation from
+/// [Approximating the erfinv function](https://people.maths.ox.ac.uk/gilesm/files/gems_erfinv.pdf)
+/// refined with one step of Newton's method.
+#[cube]
+pub(crate) fn erfinv<F: Float>(x: Line<F>) -> Line<F> {
+    let w = negate(Line::log((lined(&x, 1.0) - x) * (lined(&x, 1.0) + x)));
+
+    let c = w - lined(&x, 2.5);
+    let mut central = lined(&x, 2.810_226_4e-8) * c + lined(&x, 3.432_739_4e-7);
+    central = central * c + lined(&x, -3.523_387_7e-6);
+    central = central * c + lined(&x, -4.391_506_5e-6);
+    central = central * c + lined(&x, 2.185_808_7e-4);
+    central = central * c + lined(&x, -1.253_725e-3);
+    central = central * c + lined(&x, -4.177_681_6e-3);
+    central = central * c + lined(&x, 2.466_407_3e-1);
+    central = central * c + lined(&x, 1.501_409_4);
+
+    let t = Line::sqrt(w) - lined(&x, 3.0);
+    let mut tail = lined(&x, -2.002_142_6e-4) * t + lined(&x, 1.009_505_6e-4);
+    tail = tail * t + lined(&x, 1.349_343_2e-3);
+    tail = tail * t + lined(&x, -3.673_428_4e-3);
+    tail = tail * t + lined(&x, 5.739_507_7e-3);
+    tail = tail * t + lined(&x, -7.622_461_3e-3);
+    tail = tail * t + lined(&x, 9.438_870_5e-3);
+    tail = tail * t + lined(&x, 1.001_674_1);
+    tail = tail * t + lined(&x, 2.832_976_8);
+
+    let output = select_many(w.less_than(lined(&x, 5.0)), central, tail) * x;
+
+    // Newton step: r - (erf(r) - x) / (2 / sqrt(pi) exp(-r^2))
+    let derivative =
+        Line::exp(negate(output * output)) * lined(&x, core::f32::consts::FRAC_2_SQRT_PI);
+    let output = output - (Line::erf(output) - x) / derivative;
+
+    let output = select_many(x.equal(lined(&x, 1.0)), lined(&x, f32::INFINITY), output);
+    select_many(
+        x.equal(lined(&x, -1.0)),
+        lined(&x, f32::NEG_INFINITY),
+        output,
+    )
+}
+
+/// Modified Bessel function of the first kind of order zero, using the polynomial approximations
+/// of Abramowitz and Stegun (9.8.1 and 9.8.2).
+#[cube]
+pub(crate) fn i0<F: Float>(x: Line<F>) -> Line<F> {
+    let abs = Line::abs(x);
+
+    let t = x / lined(&x, 3.75);
+    let t2 = t * t;
+    let mut small = lined(&x, 0.004_581_3) * t2 + lined(&x, 0.036_076_8);
+    small = small * t2 + lined(&x, 0.265_973_2);
+    small = small * t2 + lined(&x, 1.206_749_2);
+    small = small * t2 + lined(&x, 3.089_942_4);
+    small = small * t2 + lined(&x, 3.515_622_9);
+    small = small * t2 + lined(&x, 1.0);
+
+    let u = lined(&x, 3.75) / abs;
+    let mut large = lined(&x, 0.003_923_77) * u + lined(&x, -0.016_476_33);
+    large = large * u + lined(&x, 0.026_355_37);
+    large = large * u + lined(&x, -0.020_577_06);
+    large = large * u + lined(&x, 0.009_162_81);
+    large = large * u + lined(&x, -0.001_575_65);
+    large = large * u + lined(&x, 0.002_253_19);
+    large = large * u + lined(&x, 0.013_285_92);
+    large = large * u + lined(&x, 0.398_942_28);
+    let large = large * Line::exp(abs) / Line::sqrt(abs);
+
+    select_many(abs.greater_than(lined(&x, 3.75)), large, small)
+}
+
+/// Modified Bessel function of the first kind of order one, using the polynomial approximations
+/// of Abramowitz and Stegun (9.8.3 and 9.8.4).
+#[cube]
+pub(crate) fn i1<F: Float>(x: Line<F>) -> Line<F> {
+    let abs = Line::abs(x);
+
+    let t = x / lined(&x, 3.75);
+    let t2 = t * t;
+    let mut small = lined(&x, 0.000_324_11) * t2 + lined(&x, 0.003_015_32);
+    small = small * t2 + lined(&x, 0.026_587_33);
+    small = small * t2 + lined(&x, 0.150_849_34);
+    small = small * t2 + lined(&x, 0.514_988_69);
+    small = small * t2 + lined(&x, 0.878_905_94);
+    small = small * t2 + lined(&x, 0.5);
+    let small = small * x;
+
+    let u = lined(&x, 3.75) / abs;
+    let mut large = lined(&x, -0.004_200_59) * u + lined(&x, 0.017_876_54);
+    large = large * u + lined(&x, -0.028_953_12);
+    large = large * u + lined(&x, 0.022_829_67);
+    large = large * u + lined(&x, -0.010_315_55);
+    large = large * u + lined(&x, 0.001_638_01);
+    large = large * u + lined(&x, -0.003_620_18);
+    large = large * u + lined(&x, -0.039_880_24);
+    large = large * u + lined(&x, 0.398_942_28);
+    let large = large * Line::exp(abs) / Line::sqrt(abs);
+    let large = select_many(x.less_than(lined(&x, 0.0)), negate(large), large);
+
+    select_many(abs.greater_than(lined(&x, 3.75)), large, small)
+}
@@ -109,7 +109,7 @@ where
 /// Use comptime enum to implement all unary operations that don't have any input argument in the
 /// kernel definition.
 pub(crate) mod unary_basic {
-    use crate::execute_with_dtype;
+    use crate::{execute_with_dtype, kernel::special};
 
     use super::*;
 
@@ -142,6 +142,21 @@ pub(crate) mod unary_basic {
         Ceil,
         Erf,
         Recip,
+        Asin,
+        Acos,
+        Atan,
+        Asinh,
+        Acosh,
+        Atanh,
+        Erfc,
+        Erfinv,
+        Expm1,
+        Log2,
+        Log10,
+        Lgamma,
+        Digamma,
+        I0,
+        I1,
     }
 
     #[derive(CubeLaunch, CubeType)]
@@ -170,6 +185,21 @@ pub(crate) mod unary_basic {
                 BasicFloatUnaryKind::Ceil => Line::ceil(input),
                 BasicFloatUnaryKind::Erf => Line::erf(input),
                 BasicFloatUnaryKind::Recip => Line::recip(input),
+                BasicFloatUnaryKind::Asin => special::asin(input),
+                BasicFloatUnaryKind::Acos => special::acos(input),
+                BasicFloatUnaryKind::Atan => special::atan(input),
+                BasicFloatUnaryKind::Asinh => special::asinh(input),
+                BasicFloatUnaryKind::Acosh => special::acosh(input),
+                BasicFloatUnaryKind::Atanh => special::atanh(input),
+                BasicFloatUnaryKind::Erfc => special::erfc(input),
+                BasicFloatUnaryKind::Erfinv => special::erfinv(input),
+                BasicFloatUnaryKind::Expm1 => special::expm1(input),
+                BasicFloatUnaryKind::Log2 => special::log2(input),
+                BasicFloatUnaryKind::Log10 => special::log10(input),
+                BasicFloatUnaryKind::Lgamma => special::lgamma(input),
+                BasicFloatUnaryKind::Digamma => special::digamma(input),
+                BasicFloatUnaryKind::I0 => special::i0(input),
+                BasicFloatUnaryKind::I1 => special::i1(input),
             }
         }
     }
@@ -105,6 +105,10 @@ pub fn permute_nhwc_to_nchw<R: CubeRuntime>(tensor: CubeTensor<R>) -> CubeTensor
     permute(tensor, &dims)
 }
 
+/// Broadcast a tensor to the target shape without copying its data.
+///
+/// # Panics
+/// When the tensor is quantized per block or per channel and its shape changes.
 pub(crate) fn expand<R: CubeRuntime>(tensor: CubeTensor<R>, target_shape: Shape) -> CubeTensor<R> {
     let ndims_in = tensor.shape.num_dims();
     let ndims_out = target_shape.num_dims();
@@ -144,11 +148,14 @@ pub(crate) fn expand<R: CubeRuntime>(tensor: CubeTensor<R>, target_shape: Shape)
         }
     }
 
-    // Extra check to ensure block scales must be properly handled once they're added
-    if tensor.qparams.is_some() {
+    // The scales of per-block and per-channel quantization follow the layout of the values, so
+    // they can't be broadcast with strides.
+    if tensor.qparams.is_some() && tensor.shape != target_shape {
         match tensor.scheme().level {
             QuantLevel::Tensor => {}
-            QuantLevel::Block(_) => todo!(),
+            level @ (QuantLevel::Block(_) | QuantLevel::Channel(_)) => panic!(
+                "Can't expand a tensor quantized with {level:?}, it must be dequantized first"
+            ),
         }
     }
 
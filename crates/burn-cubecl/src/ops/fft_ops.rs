@@ -0,0 +1,26 @@
+use crate::{
+    CubeBackend, CubeRuntime, FloatElement, IntElement, element::BoolElement, execute_with_dtype,
+    kernel,
+};
+use burn_tensor::ops::{FftOps, FloatTensor};
+
+impl<R, F, I, BT> FftOps<Self> for CubeBackend<R, F, I, BT>
+where
+    R: CubeRuntime,
+    F: FloatElement,
+    I: IntElement,
+    BT: BoolElement,
+{
+    fn fft(
+        real: FloatTensor<Self>,
+        imag: FloatTensor<Self>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        execute_with_dtype!(
+            float(real.dtype),
+            E,
+            kernel::fft::<R, E>(real, imag, dim, inverse)
+        )
+    }
+}
@@ -1,5 +1,8 @@
 use super::{expand, numeric, permute};
-use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
+use crate::kernel::prng::{
+    multinomial, random_bernoulli, random_beta, random_cauchy, random_exponential, random_gamma,
+    random_log_normal, random_normal, random_poisson, random_truncated_normal, random_uniform,
+};
 use crate::kernel::unary_basic::BasicFloatUnaryKind;
 use crate::kernel::{
     self, FloatUnaryOp, FloatUnaryOpFamily, launch_unary_float, reduce, unary_basic,
@@ -49,6 +52,30 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem::<F>(), std.elem())
             }
+            Distribution::TruncatedNormal(mean, std, low, high) => random_truncated_normal::<R, F>(
+                shape,
+                device,
+                mean as f32,
+                std as f32,
+                low as f32,
+                high as f32,
+            ),
+            Distribution::Exponential(rate) => {
+                random_exponential::<R, F>(shape, device, rate as f32)
+            }
+            Distribution::Gamma(concentration, scale) => {
+                random_gamma::<R, F>(shape, device, concentration as f32, scale as f32)
+            }
+            Distribution::Beta(alpha, beta) => {
+                random_beta::<R, F>(shape, device, alpha as f32, beta as f32)
+            }
+            Distribution::Poisson(rate) => random_poisson::<R, F>(shape, device, rate as f32),
+            Distribution::Cauchy(median, scale) => {
+                random_cauchy::<R, F>(shape, device, median as f32, scale as f32)
+            }
+            Distribution::LogNormal(mean, std) => {
+                random_log_normal::<R, F>(shape, device, mean as f32, std as f32)
+            }
         }
     }
 
@@ -478,6 +505,58 @@ where
         )
     }
 
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_dtype!(float(tensor.dtype), E, kernel::cumsum::<R, E>(tensor, dim))
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_dtype!(float(tensor.dtype), E, kernel::cumprod::<R, E>(tensor, dim))
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize) -> (FloatTensor<Self>, IntTensor<Self>) {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cummax::<R, E, I>(tensor, dim)
+        )
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize) -> (FloatTensor<Self>, IntTensor<Self>) {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cummin::<R, E, I>(tensor, dim)
+        )
+    }
+
+    fn float_logcumsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::logcumsumexp::<R, E>(tensor, dim)
+        )
+    }
+
+    fn float_logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            reduce::logsumexp::<R, E>(tensor, dim)
+        )
+    }
+
+    fn float_sum_masked_dim(
+        tensor: FloatTensor<Self>,
+        mask: BoolTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            reduce::sum_masked_dim::<R, E, BT>(tensor, mask, dim)
+        )
+    }
+
     fn float_exp(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Exp)
     }
@@ -550,6 +629,102 @@ where
         unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Erf)
     }
 
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Asin)
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Acos)
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Atan)
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_dtype!(float(lhs.dtype), E, numeric::atan2::<R, E>(lhs, rhs))
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Asinh)
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Acosh)
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Atanh)
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Erfinv)
+    }
+
+    fn float_erfc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Erfc)
+    }
+
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Expm1)
+    }
+
+    fn float_log2(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Log2)
+    }
+
+    fn float_log10(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Log10)
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_dtype!(float(lhs.dtype), E, numeric::hypot::<R, E>(lhs, rhs))
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Lgamma)
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Digamma)
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Self>, n: u32) -> FloatTensor<Self> {
+        if n == 0 {
+            return Self::float_digamma(tensor);
+        }
+
+        struct Polygamma;
+
+        #[cube]
+        impl<F: Float> FloatUnaryOp<F> for Polygamma {
+            type Options = u32;
+
+            fn execute(input: Line<F>, options: &Self::Options) -> Line<F> {
+                kernel::polygamma(input, *options)
+            }
+        }
+
+        impl FloatUnaryOpFamily for Polygamma {
+            type Options<F: Float> = u32;
+            type Unary<F: Float> = Self;
+        }
+
+        execute_with_dtype!(
+            float(tensor.dtype),
+            F,
+            launch_unary_float::<R, F, Polygamma, _>(tensor, |_| ScalarArg::new(n))
+        )
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::I0)
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::I1)
+    }
+
     fn float_argmax(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<Self> {
         execute_with_dtype!(
             float(tensor.dtype),
@@ -564,6 +739,18 @@ where
         )
     }
 
+    fn float_multinomial(
+        tensor: FloatTensor<Self>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            multinomial::<R, E, I, BT>(tensor, num_samples, replacement)
+        )
+    }
+
     fn float_argmin(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<Self> {
         execute_with_dtype!(
             float(tensor.dtype),
@@ -631,7 +818,9 @@ where
             (DType::F64, FloatDType::F64)
             | (DType::F32, FloatDType::F32)
             | (DType::BF16, FloatDType::BF16)
-            | (DType::F16, FloatDType::F16) => tensor,
+            | (DType::F16, FloatDType::F16)
+            | (DType::E4M3, FloatDType::E4M3)
+            | (DType::E5M2, FloatDType::E5M2) => tensor,
             (DType::F64, FloatDType::F32) => kernel::cast::<R, f64, f32>(tensor),
             (DType::F64, FloatDType::F16) => kernel::cast::<R, f64, f16>(tensor),
             (DType::F64, FloatDType::BF16) => kernel::cast::<R, f64, bf16>(tensor),
@@ -644,6 +833,20 @@ where
             (DType::BF16, FloatDType::F64) => kernel::cast::<R, bf16, f64>(tensor),
             (DType::BF16, FloatDType::F32) => kernel::cast::<R, bf16, f32>(tensor),
             (DType::BF16, FloatDType::F16) => kernel::cast::<R, bf16, f16>(tensor),
+            // 8-bit floats are converted through f32, which represents them exactly.
+            (DType::E4M3 | DType::E5M2, FloatDType::E4M3 | FloatDType::E5M2) => {
+                kernel::cast_to_fp8::<R, f32>(kernel::cast_from_fp8::<R, f32>(tensor), dtype)
+            }
+            (DType::E4M3 | DType::E5M2, _) => execute_with_dtype!(
+                float(DType::from(dtype)),
+                E,
+                kernel::cast_from_fp8::<R, E>(tensor)
+            ),
+            (_, FloatDType::E4M3 | FloatDType::E5M2) => execute_with_dtype!(
+                float(tensor.dtype),
+                E,
+                kernel::cast_to_fp8::<R, E>(tensor, dtype)
+            ),
             _ => unimplemented!("Unsupported floating point type cast"),
         }
     }
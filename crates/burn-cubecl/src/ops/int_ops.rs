@@ -8,7 +8,10 @@ use crate::kernel::{
 use crate::{CubeBackend, CubeRuntime, FloatElement, IntElement, kernel};
 use crate::{
     element::BoolElement,
-    kernel::prng::{random_bernoulli, random_normal, random_uniform},
+    kernel::prng::{
+        random_bernoulli, random_beta, random_cauchy, random_exponential, random_gamma,
+        random_log_normal, random_normal, random_poisson, random_truncated_normal, random_uniform,
+    },
 };
 use burn_tensor::DType;
 use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
@@ -238,6 +241,22 @@ where
         .unwrap()
     }
 
+    fn int_cumsum(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        kernel::cumsum::<R, I>(tensor, dim)
+    }
+
+    fn int_cumprod(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        kernel::cumprod::<R, I>(tensor, dim)
+    }
+
+    fn int_cummax(tensor: IntTensor<Self>, dim: usize) -> (IntTensor<Self>, IntTensor<Self>) {
+        kernel::cummax::<R, I, I>(tensor, dim)
+    }
+
+    fn int_cummin(tensor: IntTensor<Self>, dim: usize) -> (IntTensor<Self>, IntTensor<Self>) {
+        kernel::cummin::<R, I, I>(tensor, dim)
+    }
+
     fn int_max(tensor: IntTensor<Self>) -> IntTensor<Self> {
         reduce::reduce::<R, I, I, I>(tensor, Default::default(), ReduceFnConfig::Max).unwrap()
     }
@@ -342,6 +361,30 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem::<I>(), std.elem())
             }
+            Distribution::TruncatedNormal(mean, std, low, high) => random_truncated_normal::<R, I>(
+                shape,
+                device,
+                mean as f32,
+                std as f32,
+                low as f32,
+                high as f32,
+            ),
+            Distribution::Exponential(rate) => {
+                random_exponential::<R, I>(shape, device, rate as f32)
+            }
+            Distribution::Gamma(concentration, scale) => {
+                random_gamma::<R, I>(shape, device, concentration as f32, scale as f32)
+            }
+            Distribution::Beta(alpha, beta) => {
+                random_beta::<R, I>(shape, device, alpha as f32, beta as f32)
+            }
+            Distribution::Poisson(rate) => random_poisson::<R, I>(shape, device, rate as f32),
+            Distribution::Cauchy(median, scale) => {
+                random_cauchy::<R, I>(shape, device, median as f32, scale as f32)
+            }
+            Distribution::LogNormal(mean, std) => {
+                random_log_normal::<R, I>(shape, device, mean as f32, std as f32)
+            }
         }
     }
 
@@ -0,0 +1,11 @@
+use crate::{CubeBackend, CubeRuntime, FloatElement, IntElement, element::BoolElement};
+use burn_tensor::ops::LinalgOps;
+
+impl<R, F, I, BT> LinalgOps<Self> for CubeBackend<R, F, I, BT>
+where
+    R: CubeRuntime,
+    F: FloatElement,
+    I: IntElement,
+    BT: BoolElement,
+{
+}
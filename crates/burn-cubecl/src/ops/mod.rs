@@ -1,7 +1,10 @@
 mod activation_ops;
 mod bool_ops;
+mod complex_ops;
+mod fft_ops;
 mod float_ops;
 mod int_ops;
+mod linalg_ops;
 mod module_ops;
 mod qtensor;
 mod transaction;
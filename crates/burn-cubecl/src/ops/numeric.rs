@@ -1,6 +1,7 @@
 use crate::kernel::{
-    AddOp, BitwiseAndOp, BitwiseOrOp, BitwiseXorOp, DivOp, MulOp, PowOp, RemainderOp, SubOp,
-    launch_binop, launch_binop_int, launch_scalar_binop, launch_scalar_binop_int,
+    AddOp, Atan2Op, BitwiseAndOp, BitwiseOrOp, BitwiseXorOp, DivOp, HypotOp, MulOp, PowOp,
+    RemainderOp, SubOp, launch_binop, launch_binop_int, launch_scalar_binop,
+    launch_scalar_binop_int,
 };
 use crate::{CubeRuntime, FloatElement, IntElement};
 use crate::{element::CubeElement, tensor::CubeTensor};
@@ -188,6 +189,22 @@ pub fn pow<R: CubeRuntime, E: FloatElement>(
     launch_binop::<R, E, PowOp<E>>(lhs, rhs)
 }
 
+/// Calculate the four-quadrant inverse tangent of `lhs / rhs`
+pub fn atan2<R: CubeRuntime, E: FloatElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+) -> CubeTensor<R> {
+    launch_binop::<R, E, Atan2Op<E>>(lhs, rhs)
+}
+
+/// Calculate the euclidean norm `sqrt(lhs^2 + rhs^2)`
+pub fn hypot<R: CubeRuntime, E: FloatElement>(
+    lhs: CubeTensor<R>,
+    rhs: CubeTensor<R>,
+) -> CubeTensor<R> {
+    launch_binop::<R, E, HypotOp<E>>(lhs, rhs)
+}
+
 /// Bitwise and two tensors
 pub fn bitwise_and<R: CubeRuntime, E: IntElement>(
     lhs: CubeTensor<R>,
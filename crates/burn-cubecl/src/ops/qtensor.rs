@@ -2,7 +2,9 @@ use std::ops::Range;
 
 use burn_tensor::{
     DType, Device, Shape, TensorData, TensorPrimitive,
-    ops::{FloatTensor, FloatTensorOps, IntTensor, QTensorOps, QuantizedTensor},
+    ops::{
+        ConvOptions, FloatTensor, FloatTensorOps, IntTensor, ModuleOps, QTensorOps, QuantizedTensor,
+    },
     quantization::{
         QParamTensor, QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantPropagation,
         QuantScheme, QuantizationParametersPrimitive,
@@ -12,6 +14,7 @@ use cubecl::{
     Feature, Runtime,
     client::ComputeClient,
     ir::{Elem, IntKind},
+    server::Handle,
 };
 
 use crate::{
@@ -31,63 +34,7 @@ fn new_qtensor<R: CubeRuntime, S: Into<Shape>>(
     scheme: QuantScheme,
     device: &R::Device,
 ) -> CubeTensor<R> {
-    let client = R::client(device);
-    let shape: Shape = shape.into();
-    let scales_shape: Shape;
-    let scales_dtype = DType::F32; // Make this variable at some point
-
-    let (data, shapes, elem_sizes) = match scheme {
-        // Just to ensure we get and error if more modes are added and unhandled
-        QuantScheme {
-            level: QuantLevel::Tensor,
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
-            let data = vec![&data[..shape.num_elements()], &data[shape.num_elements()..]];
-            let shapes = vec![shape.dims.as_slice(), &[1]];
-            let elem_sizes = vec![size_of::<i8>(), size_of::<f32>()];
-            scales_shape = Shape::new([1]);
-            (data, shapes, elem_sizes)
-        }
-        QuantScheme {
-            level: QuantLevel::Block(block_size),
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
-            let numel = shape.num_elements();
-            let num_blocks = numel / block_size;
-            scales_shape = Shape::new([num_blocks]);
-            let data = vec![&data[..numel], &data[numel..]];
-            let shapes = vec![shape.dims.as_slice(), scales_shape.dims.as_slice()];
-            let elem_sizes = vec![size_of::<i8>(), size_of::<f32>()];
-            (data, shapes, elem_sizes)
-        }
-    };
-
-    let mut tensors = client.create_tensors(data, shapes, elem_sizes);
-    let (scales_handle, scales_strides) = tensors.remove(1);
-    let (handle, strides) = tensors.remove(0);
-
-    let scales = QParamTensor {
-        offset_start: scales_handle.offset_start.unwrap_or(0) as usize,
-        offset_end: scales_handle.offset_end.unwrap_or(0) as usize,
-        shape: scales_shape,
-        strides: scales_strides,
-        dtype: scales_dtype,
-    };
-    let qparams = QParams { scales };
-
-    CubeTensor::new_quantized(
-        client,
-        handle,
-        shape,
-        device.clone(),
-        strides,
-        DType::QFloat(scheme),
-        qparams,
-    )
+    new_qtensor_optional(Some(data), shape.into(), scheme, device)
 }
 
 /// Create an empty quantized tensor.
@@ -95,52 +42,94 @@ pub fn empty_qtensor<R: CubeRuntime>(
     shape: impl Into<Shape>,
     scheme: QuantScheme,
     device: &R::Device,
+) -> CubeTensor<R> {
+    new_qtensor_optional(None, shape.into(), scheme, device)
+}
+
+fn new_qtensor_optional<R: CubeRuntime>(
+    data: Option<&[u8]>,
+    shape: Shape,
+    scheme: QuantScheme,
+    device: &R::Device,
 ) -> CubeTensor<R> {
     let client = R::client(device);
-    let shape: Shape = shape.into();
-    let scales_shape: Shape;
-    let scales_dtype: DType;
-    let (shapes, elem_sizes) = match scheme {
-        // Just to ensure we get and error if more modes are added and unhandled
-        QuantScheme {
-            level: QuantLevel::Tensor,
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
-            let shapes = vec![shape.dims.as_slice(), &[1]];
-            let elem_sizes = vec![size_of::<i8>(), size_of::<f32>()];
-            scales_shape = Shape::new([1]);
-            scales_dtype = DType::F32;
-            (shapes, elem_sizes)
+    let numel = shape.num_elements();
+    let qparams_dtype = DType::F32; // Make this variable at some point
+
+    let num_qparams = match scheme.level {
+        QuantLevel::Tensor => 1,
+        QuantLevel::Block(block_size) => numel / block_size,
+        QuantLevel::Channel(axis) => shape.dims[axis],
+    };
+    let qparams_shape = Shape::new([num_qparams]);
+
+    // The 4-bit values are always packed, so the values buffer is smaller than the logical shape
+    let packed_shape;
+    let (values_shape, values_elem_size, values_len) = match scheme.q_type {
+        QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+            let num_packed = numel.div_ceil(8);
+            packed_shape = [num_packed];
+            (
+                packed_shape.as_slice(),
+                size_of::<u32>(),
+                num_packed * size_of::<u32>(),
+            )
         }
-        QuantScheme {
-            level: QuantLevel::Block(block_size),
-            mode: QuantMode::Symmetric,
-            q_type: QuantInputType::QInt8,
-            ..
-        } => {
-            let num_blocks = shape.num_elements() / block_size;
-            scales_shape = Shape::new([num_blocks]);
-            scales_dtype = DType::F32;
-            let shapes = vec![shape.dims.as_slice(), scales_shape.dims.as_slice()];
-            let elem_sizes = vec![size_of::<i8>(), size_of::<f32>()];
-            (shapes, elem_sizes)
+        QuantInputType::QInt8 | QuantInputType::E4M3 | QuantInputType::E5M2 => {
+            (shape.dims.as_slice(), size_of::<i8>(), numel)
         }
     };
 
-    let mut tensors = client.empty_tensors(shapes, elem_sizes);
-    let (scales_handle, scales_strides) = tensors.remove(1);
-    let (handle, strides) = tensors.remove(0);
+    // Affine quantization stores the offsets before the scales
+    let num_params_tensors = match scheme.mode {
+        QuantMode::Symmetric => 1,
+        QuantMode::Affine => 2,
+    };
+    let mut shapes = vec![values_shape];
+    let mut elem_sizes = vec![values_elem_size];
+    for _ in 0..num_params_tensors {
+        shapes.push(qparams_shape.dims.as_slice());
+        elem_sizes.push(size_of::<f32>());
+    }
+
+    let mut tensors = match data {
+        Some(data) => {
+            // Quantization parameters are always at the end of the data
+            let qparams_len = num_qparams * size_of::<f32>();
+            let mut data_split = vec![&data[..values_len]];
+            let mut qparams_start = data.len() - num_params_tensors * qparams_len;
+            for _ in 0..num_params_tensors {
+                data_split.push(&data[qparams_start..qparams_start + qparams_len]);
+                qparams_start += qparams_len;
+            }
+            client.create_tensors(data_split, shapes, elem_sizes)
+        }
+        None => client.empty_tensors(shapes, elem_sizes),
+    };
+
+    let qparam_tensor = |tensors: &mut Vec<(Handle, Vec<usize>)>| {
+        let (handle, strides) = tensors.remove(1);
+        QParamTensor {
+            offset_start: handle.offset_start.unwrap_or(0) as usize,
+            offset_end: handle.offset_end.unwrap_or(0) as usize,
+            shape: qparams_shape.clone(),
+            strides,
+            dtype: qparams_dtype,
+        }
+    };
+    let offsets = match scheme.mode {
+        QuantMode::Symmetric => None,
+        QuantMode::Affine => Some(qparam_tensor(&mut tensors)),
+    };
+    let scales = qparam_tensor(&mut tensors);
+    let qparams = QParams { scales, offsets };
 
-    let scales = QParamTensor {
-        offset_start: scales_handle.offset_start.unwrap_or(0) as usize,
-        offset_end: scales_handle.offset_end.unwrap_or(0) as usize,
-        shape: scales_shape,
-        strides: scales_strides,
-        dtype: scales_dtype,
+    let (handle, strides) = tensors.remove(0);
+    let strides = match scheme.q_type {
+        // Packed 4-bit values are always contiguous
+        QuantInputType::QInt4 | QuantInputType::QUInt4 => contiguous_strides(&shape.dims),
+        QuantInputType::QInt8 | QuantInputType::E4M3 | QuantInputType::E5M2 => strides,
     };
-    let qparams = QParams { scales };
 
     CubeTensor::new_quantized(
         client,
@@ -153,6 +142,14 @@ pub fn empty_qtensor<R: CubeRuntime>(
     )
 }
 
+fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
 impl<R, F, I, BT> QTensorOps<Self> for CubeBackend<R, F, I, BT>
 where
     R: CubeRuntime,
@@ -162,18 +159,11 @@ where
 {
     fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
         match data.dtype {
-            DType::QFloat(scheme) => match scheme {
-                QuantScheme {
-                    level: QuantLevel::Tensor | QuantLevel::Block(_),
-                    mode: QuantMode::Symmetric,
-                    q_type: QuantInputType::QInt8,
-                    ..
-                } => {
-                    // TensorData quantized representation is the same, with multiple quantized values
-                    // packed into u32 and quantization parameters appended to the bytes
-                    new_qtensor(data.as_bytes(), data.shape.clone(), scheme, device)
-                }
-            },
+            DType::QFloat(scheme) => {
+                // TensorData quantized representation is the same, with multiple quantized values
+                // packed into u32 and quantization parameters appended to the bytes
+                new_qtensor(data.as_bytes(), data.shape.clone(), scheme, device)
+            }
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
                 data.dtype
@@ -188,13 +178,57 @@ where
         scheme: &QuantScheme,
         qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self> {
-        kernel::quantization::quantize::<R, F>(tensor, scheme, qparams.scales)
+        kernel::quantization::quantize::<R, F>(tensor, scheme, qparams.scales, qparams.offsets)
     }
 
     fn dequantize(tensor: QuantizedTensor<Self>) -> FloatTensor<Self> {
         kernel::quantization::dequantize::<R, F>(tensor)
     }
 
+    fn q_linear(
+        input: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self> {
+        let output = if int8_enabled::<R>(&input.client)
+            && kernel::quantization::is_int8_symmetric(input.scheme(), None)
+            && kernel::quantization::is_int8_symmetric(weight.scheme(), Some(1))
+        {
+            kernel::quantization::q_linear::<R, F>(input, weight, bias)
+        } else {
+            Self::linear(Self::dequantize(input), Self::dequantize(weight), bias)
+        };
+
+        Self::quantize(output, scheme, qparams)
+    }
+
+    fn q_conv2d(
+        input: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<2>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self> {
+        let output = if int8_enabled::<R>(&input.client)
+            && kernel::quantization::is_int8_symmetric(input.scheme(), None)
+            && kernel::quantization::is_int8_symmetric(weight.scheme(), Some(0))
+        {
+            kernel::quantization::q_conv2d::<R, F>(input, weight, bias, options)
+        } else {
+            Self::conv2d(
+                Self::dequantize(input),
+                Self::dequantize(weight),
+                bias,
+                options,
+            )
+        };
+
+        Self::quantize(output, scheme, qparams)
+    }
+
     fn q_device(tensor: &QuantizedTensor<Self>) -> Device<Self> {
         tensor.device.clone()
     }
@@ -214,12 +248,37 @@ where
 
         let tensor = kernel::into_contiguous_aligned(tensor);
         let mut data = match tensor.scheme() {
+            // The fp8 values are read as their bits
             QuantScheme {
-                q_type: QuantInputType::QInt8,
+                q_type: QuantInputType::QInt8 | QuantInputType::E4M3 | QuantInputType::E5M2,
                 ..
             } => into_data::<R, i8>(tensor.clone()).await,
+            // The 4-bit values are read as packed u32
+            QuantScheme {
+                q_type: QuantInputType::QInt4 | QuantInputType::QUInt4,
+                ..
+            } => {
+                let num_packed = tensor.shape.num_elements().div_ceil(8);
+                let values = CubeTensor::new_contiguous(
+                    tensor.client.clone(),
+                    tensor.device.clone(),
+                    Shape::new([num_packed]),
+                    tensor.handle.clone(),
+                    DType::U32,
+                );
+                let mut data = into_data::<R, u32>(values).await;
+                data.shape = tensor.shape.dims.clone();
+                data
+            }
         };
         data.dtype = tensor.dtype; // Reset to qfloat after reading
+
+        // Quantization parameters are appended as [offsets, scales]
+        if let Some(offsets) = tensor.offsets() {
+            let offsets_data =
+                execute_with_dtype!(offsets.dtype, E, into_data::<R, E>(offsets).await);
+            data.bytes.extend_from_byte_slice(&offsets_data.bytes);
+        }
         let scales = tensor.scales().unwrap();
         let scales_data = execute_with_dtype!(scales.dtype, E, into_data::<R, E>(scales).await);
         data.bytes.extend_from_byte_slice(&scales_data.bytes);
@@ -313,10 +372,14 @@ fn both_matches_symmetric_qint8(lhs: &QuantScheme, rhs: &QuantScheme) -> bool {
 }
 
 fn features_enabled<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> bool {
-    client
-        .properties()
-        .feature_enabled(Feature::Type(Elem::Int(IntKind::I8)))
+    int8_enabled::<R>(client)
         && client
             .properties()
             .feature_enabled(Feature::DynamicLineSize)
 }
+
+fn int8_enabled<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> bool {
+    client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Int(IntKind::I8)))
+}
@@ -34,17 +34,27 @@ impl<R: CubeRuntime> CubeTensor<R> {
     /// Construct a separate tensor for the quantization scales, if present
     pub fn scales(&self) -> Option<CubeTensor<R>> {
         let qparams = self.qparams.as_ref()?;
+        Some(self.qparam_tensor(&qparams.scales))
+    }
+
+    /// Construct a separate tensor for the quantization zero-point offsets, if present
+    pub fn offsets(&self) -> Option<CubeTensor<R>> {
+        let qparams = self.qparams.as_ref()?;
+        Some(self.qparam_tensor(qparams.offsets.as_ref()?))
+    }
+
+    fn qparam_tensor(&self, qparam: &QParamTensor) -> CubeTensor<R> {
         let mut handle = self.handle.clone();
-        handle.offset_start = Some(qparams.scales.offset_start as u64);
-        handle.offset_end = Some(qparams.scales.offset_end as u64);
+        handle.offset_start = Some(qparam.offset_start as u64);
+        handle.offset_end = Some(qparam.offset_end as u64);
 
-        Some(CubeTensor::new(
+        CubeTensor::new(
             self.client.clone(),
             handle,
-            qparams.scales.shape.clone(),
+            qparam.shape.clone(),
             self.device.clone(),
-            qparams.scales.strides.clone(),
-            qparams.scales.dtype,
-        ))
+            qparam.strides.clone(),
+            qparam.dtype,
+        )
     }
 }
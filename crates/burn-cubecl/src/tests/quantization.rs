@@ -3,7 +3,7 @@ mod tests {
     use super::*;
     use burn_tensor::{
         Tensor,
-        quantization::{QuantLevel, QuantScheme, QuantStoreType},
+        quantization::{QuantInputType, QuantLevel, QuantMode, QuantScheme, QuantStoreType},
     };
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
@@ -88,4 +88,61 @@ mod tests {
             .to_data()
             .assert_approx_eq::<FT>(&output_ref.to_data(), Tolerance::default());
     }
+
+    #[test]
+    fn should_quantize_dequantize_affine_multiple() {
+        let scheme = QuantScheme::default().set_mode(QuantMode::Affine);
+        let input =
+            Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0, 0.0, 0.5, 0.0], &Default::default());
+        let input_ref =
+            Tensor::<ReferenceBackend, 1>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&output_ref.to_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn should_quantize_dequantize_int4_per_channel() {
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(0))
+            .set_mode(QuantMode::Affine)
+            .set_q_type(QuantInputType::QUInt4);
+
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [
+                [
+                    -1.8, -1.0, 0.0, 0.5, -1.8, -1.0, 0.0, 0.5, 0.01, 0.025, 0.03, 0.04, 0.01,
+                    0.025, 0.03, 0.04,
+                ],
+                [
+                    1.8, 1.0, 0.0, -0.5, 1.8, 1.0, 0.0, -0.5, -0.01, -0.025, -0.03, -0.04, -0.01,
+                    -0.025, -0.03, -0.04,
+                ],
+            ],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&output_ref.to_data(), Tolerance::default());
+    }
 }
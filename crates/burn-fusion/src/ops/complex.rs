@@ -0,0 +1,4 @@
+use crate::{Fusion, FusionBackend};
+use burn_tensor::ops::ComplexOps;
+
+impl<B: FusionBackend> ComplexOps<Self> for Fusion<B> {}
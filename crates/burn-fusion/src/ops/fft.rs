@@ -0,0 +1,61 @@
+use crate::{
+    Fusion, FusionBackend,
+    client::FusionClient,
+    stream::{OperationStreams, execution::Operation},
+};
+use burn_ir::{FftOpIr, FloatOperationIr, HandleContainer, OperationIr};
+use burn_tensor::ops::{FftOps, FloatTensor};
+use std::marker::PhantomData;
+
+impl<B: FusionBackend> FftOps<Self> for Fusion<B> {
+    fn fft(
+        real: FloatTensor<Self>,
+        imag: FloatTensor<Self>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        #[derive(new, Debug)]
+        struct FftOps<B: FusionBackend> {
+            desc: FftOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for FftOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let real = handles.get_float_tensor::<B>(&self.desc.real);
+                let imag = handles.get_float_tensor::<B>(&self.desc.imag);
+                let (output_real, output_imag) =
+                    B::fft(real, imag, self.desc.dim, self.desc.inverse);
+
+                handles.register_float_tensor::<B>(&self.desc.out_real.id, output_real);
+                handles.register_float_tensor::<B>(&self.desc.out_imag.id, output_imag);
+            }
+        }
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&real);
+        streams.tensor(&imag);
+
+        let shape = real.shape.clone();
+        let dtype = real.dtype;
+        let client = real.client.clone();
+        let out_real = client.tensor_uninitialized(shape.clone(), dtype);
+        let out_imag = client.tensor_uninitialized(shape, dtype);
+
+        let desc = FftOpIr {
+            real: real.into_ir(),
+            imag: imag.into_ir(),
+            dim,
+            inverse,
+            out_real: out_real.to_ir_out(),
+            out_imag: out_imag.to_ir_out(),
+        };
+        client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Fft(desc.clone())),
+            FftOps::<B>::new(desc),
+        );
+
+        (out_real, out_imag)
+    }
+}
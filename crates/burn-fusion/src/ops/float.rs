@@ -1361,13 +1361,514 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(CumSumOps, B::float_cumsum);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        out.client.register(
+            streams,
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumSum(desc.clone())),
+            CumSumOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(CumProdOps, B::float_cumprod);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        out.client.register(
+            streams,
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumProd(desc.clone())),
+            CumProdOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize) -> (FloatTensor<Self>, IntTensor<Self>) {
+        #[derive(new, Debug)]
+        struct CumMaxOps<B: FusionBackend> {
+            desc: ReduceDimWithIndicesOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CumMaxOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.tensor);
+                let (output, indices) = B::float_cummax(tensor, self.desc.dim);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+                handles.register_int_tensor::<B>(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+
+        let shape = tensor.shape.clone();
+        let dtype = tensor.dtype;
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), dtype);
+        let out_indices = client.tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+        client.register(
+            streams,
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumMax(desc.clone())),
+            CumMaxOps::<B>::new(desc),
+        );
+
+        (out, out_indices)
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize) -> (FloatTensor<Self>, IntTensor<Self>) {
+        #[derive(new, Debug)]
+        struct CumMinOps<B: FusionBackend> {
+            desc: ReduceDimWithIndicesOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CumMinOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.tensor);
+                let (output, indices) = B::float_cummin(tensor, self.desc.dim);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+                handles.register_int_tensor::<B>(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+
+        let shape = tensor.shape.clone();
+        let dtype = tensor.dtype;
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), dtype);
+        let out_indices = client.tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+        client.register(
+            streams,
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumMin(desc.clone())),
+            CumMinOps::<B>::new(desc),
+        );
+
+        (out, out_indices)
+    }
+
+    fn float_logcumsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(LogCumSumExpOps, B::float_logcumsumexp);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::LogCumSumExp(desc.clone())),
+            LogCumSumExpOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_logsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(LogSumExpOps, B::float_logsumexp);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor.client.tensor_uninitialized(shape, dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::LogSumExp(desc.clone())),
+            LogSumExpOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_sum_masked_dim(
+        tensor: FloatTensor<Self>,
+        mask: BoolTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        #[derive(new, Debug)]
+        struct SumMaskedDimOps<B: FusionBackend> {
+            desc: MaskedReduceDimOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for SumMaskedDimOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.input);
+                let mask = handles.get_bool_tensor::<B>(&self.desc.mask);
+
+                let output = B::float_sum_masked_dim(tensor, mask, self.desc.axis);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        streams.tensor(&mask);
+
+        let dtype = tensor.dtype;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor.client.tensor_uninitialized(shape, dtype);
+
+        let desc = MaskedReduceDimOpIr {
+            input: tensor.into_ir(),
+            mask: mask.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::SumMaskedDim(desc.clone())),
+            SumMaskedDimOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_mean(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         unary_float_ops!(MeanOps, B::float_mean, reduce);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
         let dtype = tensor.dtype;
-        let out = tensor.client.tensor_uninitialized(vec![1], dtype);
+        let out = tensor.client.tensor_uninitialized(vec![1], dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::NumericFloat(dtype, NumericOperationIr::Mean(desc.clone())),
+            MeanOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_mean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(MeanDimOps, B::float_mean_dim);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor.client.tensor_uninitialized(shape, dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::NumericFloat(dtype, NumericOperationIr::MeanDim(desc.clone())),
+            MeanDimOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_exp(lhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(ExpOps, B::float_exp);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&lhs);
+        let dtype = lhs.dtype;
+        let out = lhs.client.tensor_uninitialized(lhs.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: lhs.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Exp(desc.clone())),
+            ExpOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_log(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(LogOps, B::float_log);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Log(desc.clone())),
+            LogOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(Log1pOps, B::float_log1p);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Log1p(desc.clone())),
+            Log1pOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_powf_scalar(lhs: FloatTensor<Self>, rhs: f32) -> FloatTensor<Self> {
+        scalar_float_ops!(PowfOps, B::float_powf_scalar, f32);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&lhs);
+        let dtype = lhs.dtype;
+        let out = lhs.client.tensor_uninitialized(lhs.shape.clone(), dtype);
+
+        let desc = ScalarOpIr {
+            lhs: lhs.into_ir(),
+            rhs,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::PowfScalar(desc.clone())),
+            PowfOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_sqrt(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(SqrtOps, B::float_sqrt);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Sqrt(desc.clone())),
+            SqrtOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_abs(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AbsOps, B::float_abs);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::NumericFloat(dtype, NumericOperationIr::Abs(desc.clone())),
+            AbsOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(CosOps, B::float_cos);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Cos(desc.clone())),
+            CosOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_sin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(SinOps, B::float_sin);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Sin(desc.clone())),
+            SinOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_tanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(TanhOps, B::float_tanh);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Tanh(desc.clone())),
+            TanhOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_recip(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(Recip, B::float_recip);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Recip(desc.clone())),
+            Recip::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_erf(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(TanhOps, B::float_erf);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
 
         let desc = UnaryOpIr {
             input: tensor.into_ir(),
@@ -1375,60 +1876,132 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::NumericFloat(dtype, NumericOperationIr::Mean(desc.clone())),
-            MeanOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Erf(desc.clone())),
+            TanhOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_mean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
-        reduce_float_ops!(MeanDimOps, B::float_mean_dim);
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AsinOps, B::float_asin);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
         let dtype = tensor.dtype;
-        let mut shape = tensor.shape.clone();
-        shape[dim] = 1;
-        let out = tensor.client.tensor_uninitialized(shape, dtype);
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
 
-        let desc = ReduceDimOpIr {
+        let desc = UnaryOpIr {
             input: tensor.into_ir(),
-            axis: dim,
             out: out.to_ir_out(),
         };
         out.client.register(
             streams,
-            OperationIr::NumericFloat(dtype, NumericOperationIr::MeanDim(desc.clone())),
-            MeanDimOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Asin(desc.clone())),
+            AsinOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_exp(lhs: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(ExpOps, B::float_exp);
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AcosOps, B::float_acos);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Acos(desc.clone())),
+            AcosOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AtanOps, B::float_atan);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Atan(desc.clone())),
+            AtanOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary_float_ops!(Atan2Ops, B::float_atan2);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&lhs);
+        streams.tensor(&rhs);
         let dtype = lhs.dtype;
-        let out = lhs.client.tensor_uninitialized(lhs.shape.clone(), dtype);
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape), dtype);
+
+        let desc = BinaryOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Atan2(desc.clone())),
+            Atan2Ops::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AsinhOps, B::float_asinh);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
 
         let desc = UnaryOpIr {
-            input: lhs.into_ir(),
+            input: tensor.into_ir(),
             out: out.to_ir_out(),
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Exp(desc.clone())),
-            ExpOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Asinh(desc.clone())),
+            AsinhOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_log(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(LogOps, B::float_log);
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AcoshOps, B::float_acosh);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1443,15 +2016,15 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Log(desc.clone())),
-            LogOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Acosh(desc.clone())),
+            AcoshOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(Log1pOps, B::float_log1p);
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(AtanhOps, B::float_atanh);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1466,37 +2039,38 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Log1p(desc.clone())),
-            Log1pOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Atanh(desc.clone())),
+            AtanhOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_powf_scalar(lhs: FloatTensor<Self>, rhs: f32) -> FloatTensor<Self> {
-        scalar_float_ops!(PowfOps, B::float_powf_scalar, f32);
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(ErfinvOps, B::float_erfinv);
 
         let mut streams = OperationStreams::default();
-        streams.tensor(&lhs);
-        let dtype = lhs.dtype;
-        let out = lhs.client.tensor_uninitialized(lhs.shape.clone(), dtype);
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
 
-        let desc = ScalarOpIr {
-            lhs: lhs.into_ir(),
-            rhs,
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
             out: out.to_ir_out(),
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::PowfScalar(desc.clone())),
-            PowfOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Erfinv(desc.clone())),
+            ErfinvOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_sqrt(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(SqrtOps, B::float_sqrt);
+    fn float_erfc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(ErfcOps, B::float_erfc);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1511,15 +2085,15 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Sqrt(desc.clone())),
-            SqrtOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Erfc(desc.clone())),
+            ErfcOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_abs(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(AbsOps, B::float_abs);
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(Expm1Ops, B::float_expm1);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1534,15 +2108,15 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::NumericFloat(dtype, NumericOperationIr::Abs(desc.clone())),
-            AbsOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Expm1(desc.clone())),
+            Expm1Ops::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_cos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(CosOps, B::float_cos);
+    fn float_log2(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(Log2Ops, B::float_log2);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1557,15 +2131,15 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Cos(desc.clone())),
-            CosOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Log2(desc.clone())),
+            Log2Ops::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_sin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(SinOps, B::float_sin);
+    fn float_log10(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(Log10Ops, B::float_log10);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1580,15 +2154,40 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Sin(desc.clone())),
-            SinOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Log10(desc.clone())),
+            Log10Ops::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_tanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(TanhOps, B::float_tanh);
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary_float_ops!(HypotOps, B::float_hypot);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&lhs);
+        streams.tensor(&rhs);
+        let dtype = lhs.dtype;
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape), dtype);
+
+        let desc = BinaryOpIr {
+            lhs: lhs.into_ir(),
+            rhs: rhs.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Hypot(desc.clone())),
+            HypotOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(LgammaOps, B::float_lgamma);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1603,15 +2202,15 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Tanh(desc.clone())),
-            TanhOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Lgamma(desc.clone())),
+            LgammaOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_recip(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(Recip, B::float_recip);
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(DigammaOps, B::float_digamma);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1619,21 +2218,59 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         let out = tensor
             .client
             .tensor_uninitialized(tensor.shape.clone(), dtype);
+
         let desc = UnaryOpIr {
             input: tensor.into_ir(),
             out: out.to_ir_out(),
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Recip(desc.clone())),
-            Recip::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::Digamma(desc.clone())),
+            DigammaOps::<B>::new(desc),
         );
 
         out
     }
 
-    fn float_erf(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        unary_float_ops!(TanhOps, B::float_erf);
+    fn float_polygamma(tensor: FloatTensor<Self>, n: u32) -> FloatTensor<Self> {
+        #[derive(new, Debug)]
+        struct PolygammaOps<B: FusionBackend> {
+            desc: PolygammaOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for PolygammaOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_float_tensor::<B>(&self.desc.input);
+                let output = B::float_polygamma(input, self.desc.order);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = PolygammaOpIr {
+            input: tensor.into_ir(),
+            order: n,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Polygamma(desc.clone())),
+            PolygammaOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(I0Ops, B::float_i0);
 
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
@@ -1648,8 +2285,31 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         };
         out.client.register(
             streams,
-            OperationIr::Float(dtype, FloatOperationIr::Erf(desc.clone())),
-            TanhOps::<B>::new(desc),
+            OperationIr::Float(dtype, FloatOperationIr::I0(desc.clone())),
+            I0Ops::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float_ops!(I1Ops, B::float_i1);
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = UnaryOpIr {
+            input: tensor.into_ir(),
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::I1(desc.clone())),
+            I1Ops::<B>::new(desc),
         );
 
         out
@@ -1774,6 +2434,51 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_multinomial(
+        tensor: FloatTensor<Self>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<Self> {
+        #[derive(new, Debug)]
+        struct MultinomialOps<B: FusionBackend> {
+            desc: MultinomialOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for MultinomialOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.input);
+
+                let output =
+                    B::float_multinomial(tensor, self.desc.num_samples, self.desc.replacement);
+
+                handles.register_int_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&tensor);
+        let dtype = tensor.dtype;
+        let shape = vec![tensor.shape[0], num_samples];
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = MultinomialOpIr {
+            input: tensor.into_ir(),
+            num_samples,
+            replacement,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            streams,
+            OperationIr::Float(dtype, FloatOperationIr::Multinomial(desc.clone())),
+            MultinomialOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_argmin(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<Self> {
         reduce_float2int_ops!(ArgMinOps, B::float_argmin);
 
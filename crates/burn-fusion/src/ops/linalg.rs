@@ -0,0 +1,4 @@
+use crate::{Fusion, FusionBackend};
+use burn_tensor::ops::LinalgOps;
+
+impl<B: FusionBackend> LinalgOps<Self> for Fusion<B> {}
@@ -1,8 +1,11 @@
 mod activation;
 mod binary;
 mod boolean;
+mod complex;
+mod fft;
 mod float;
 mod int;
+mod linalg;
 mod module;
 mod qtensor;
 mod transaction;
@@ -8,8 +8,8 @@ use burn_tensor::{
     Element,
     ops::{
         ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, FloatTensor,
-        IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        GridSampleBackward, GridSampleOptions, IntTensor, InterpolateOptions, MaxPool1dBackward,
+        MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
         conv::{
             calculate_conv_output_size, calculate_conv_transpose_output_size,
             calculate_pool_output_size,
@@ -1400,4 +1400,96 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         );
         out
     }
+
+    fn grid_sample(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            GridSampleOps,
+            GridSampleOpIr,
+            |args: &GridSampleOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grid = handles.get_float_tensor::<B>(&args.grid);
+                let output = B::grid_sample(x, grid, args.options.clone().into());
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+        streams.tensor(&grid);
+
+        let mut shape = vec![x.shape[0], x.shape[1]];
+        shape.extend(&grid.shape[1..grid.shape.len() - 1]);
+        let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = GridSampleOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            options: options.into(),
+            out: out.to_ir_out(),
+        };
+
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::GridSample(desc.clone())),
+            GridSampleOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn grid_sample_backward(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> GridSampleBackward<Self> {
+        make_ops!(
+            GridSampleBackwardOps,
+            GridSampleBackwardOpIr,
+            |args: &GridSampleBackwardOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grid = handles.get_float_tensor::<B>(&args.grid);
+                let output_grad = handles.get_float_tensor::<B>(&args.out_grad);
+
+                let output =
+                    B::grid_sample_backward(x, grid, output_grad, args.options.clone().into());
+
+                handles.register_float_tensor::<B>(&args.x_grad.id, output.x_grad);
+                handles.register_float_tensor::<B>(&args.grid_grad.id, output.grid_grad);
+            }
+        );
+
+        let mut streams = OperationStreams::default();
+        streams.tensor(&x);
+        streams.tensor(&grid);
+        streams.tensor(&output_grad);
+
+        let x_grad = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+        let grid_grad = x
+            .client
+            .tensor_uninitialized(grid.shape.clone(), B::FloatElem::dtype());
+
+        let desc = GridSampleBackwardOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            out_grad: output_grad.into_ir(),
+            options: options.into(),
+            x_grad: x_grad.to_ir_out(),
+            grid_grad: grid_grad.to_ir_out(),
+        };
+
+        x_grad.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::GridSampleBackward(desc.clone())),
+            GridSampleBackwardOps::<B>::new(desc),
+        );
+
+        GridSampleBackward::new(x_grad, grid_grad)
+    }
 }
@@ -57,7 +57,14 @@ impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
                 let tensor = handles.get_float_tensor::<B>(&self.desc.tensor);
                 let scales = handles.get_float_tensor::<B>(&self.desc.qparams.scales);
 
-                let qparams = QuantizationParametersPrimitive { scales };
+                let offsets = self
+                    .desc
+                    .qparams
+                    .offsets
+                    .as_ref()
+                    .map(|offsets| handles.get_float_tensor::<B>(offsets));
+
+                let qparams = QuantizationParametersPrimitive { scales, offsets };
                 let output = B::quantize(tensor, &self.desc.scheme, qparams);
                 handles.register_quantized_tensor::<B>(&self.desc.out.id, output);
             }
@@ -72,11 +79,15 @@ impl<B: FusionBackend> QTensorOps<Self> for Fusion<B> {
         let mut streams = OperationStreams::default();
         streams.tensor(&tensor);
         streams.tensor(&qparams.scales);
+        if let Some(offsets) = qparams.offsets.as_ref() {
+            streams.tensor(offsets)
+        }
 
         let desc = QuantizeOpIr {
             tensor: tensor.into_ir(),
             qparams: QuantizationParametersIr {
                 scales: qparams.scales.clone().into_ir(),
+                offsets: qparams.offsets.map(|offsets| offsets.into_ir()),
             },
             scheme: *scheme,
             out: out.to_ir_out(),
@@ -449,6 +449,22 @@ impl RelativeOps for ModuleOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationIr::GridSample(desc) => ModuleOperationIr::GridSample(GridSampleOpIr {
+                x: desc.x.to_relative(converter),
+                grid: desc.grid.to_relative(converter),
+                options: desc.options.clone(),
+                out: desc.out.to_relative(converter),
+            }),
+            ModuleOperationIr::GridSampleBackward(desc) => {
+                ModuleOperationIr::GridSampleBackward(GridSampleBackwardOpIr {
+                    x: desc.x.to_relative(converter),
+                    grid: desc.grid.to_relative(converter),
+                    out_grad: desc.out_grad.to_relative(converter),
+                    options: desc.options.clone(),
+                    x_grad: desc.x_grad.to_relative(converter),
+                    grid_grad: desc.grid_grad.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -475,6 +491,113 @@ impl RelativeOpsScalar<f32> for FloatOperationIr {
                 input: desc.input.to_relative(converter),
                 out: desc.out.to_relative(converter),
             }),
+            FloatOperationIr::Asin(desc) => FloatOperationIr::Asin(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Acos(desc) => FloatOperationIr::Acos(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Atan(desc) => FloatOperationIr::Atan(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Asinh(desc) => FloatOperationIr::Asinh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Acosh(desc) => FloatOperationIr::Acosh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Atanh(desc) => FloatOperationIr::Atanh(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Erfc(desc) => FloatOperationIr::Erfc(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Erfinv(desc) => FloatOperationIr::Erfinv(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Expm1(desc) => FloatOperationIr::Expm1(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Log2(desc) => FloatOperationIr::Log2(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Log10(desc) => FloatOperationIr::Log10(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Lgamma(desc) => FloatOperationIr::Lgamma(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Digamma(desc) => FloatOperationIr::Digamma(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::I0(desc) => FloatOperationIr::I0(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::I1(desc) => FloatOperationIr::I1(UnaryOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Atan2(desc) => FloatOperationIr::Atan2(BinaryOpIr {
+                lhs: desc.lhs.to_relative(converter),
+                rhs: desc.rhs.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Hypot(desc) => FloatOperationIr::Hypot(BinaryOpIr {
+                lhs: desc.lhs.to_relative(converter),
+                rhs: desc.rhs.to_relative(converter),
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::Polygamma(desc) => FloatOperationIr::Polygamma(PolygammaOpIr {
+                input: desc.input.to_relative(converter),
+                order: desc.order,
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::LogCumSumExp(desc) => FloatOperationIr::LogCumSumExp(ReduceDimOpIr {
+                input: desc.input.to_relative(converter),
+                axis: desc.axis,
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::LogSumExp(desc) => FloatOperationIr::LogSumExp(ReduceDimOpIr {
+                input: desc.input.to_relative(converter),
+                axis: desc.axis,
+                out: desc.out.to_relative(converter),
+            }),
+            FloatOperationIr::SumMaskedDim(desc) => {
+                FloatOperationIr::SumMaskedDim(MaskedReduceDimOpIr {
+                    input: desc.input.to_relative(converter),
+                    mask: desc.mask.to_relative(converter),
+                    axis: desc.axis,
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationIr::Fft(desc) => FloatOperationIr::Fft(FftOpIr {
+                real: desc.real.to_relative(converter),
+                imag: desc.imag.to_relative(converter),
+                dim: desc.dim,
+                inverse: desc.inverse,
+                out_real: desc.out_real.to_relative(converter),
+                out_imag: desc.out_imag.to_relative(converter),
+            }),
+            FloatOperationIr::Multinomial(desc) => FloatOperationIr::Multinomial(MultinomialOpIr {
+                input: desc.input.to_relative(converter),
+                num_samples: desc.num_samples,
+                replacement: desc.replacement,
+                out: desc.out.to_relative(converter),
+            }),
             FloatOperationIr::PowfScalar(desc) => FloatOperationIr::PowfScalar(ScalarOpIr {
                 lhs: desc.lhs.to_relative(converter),
                 rhs: local_elem(converter, &desc.rhs.elem()),
@@ -517,6 +640,11 @@ impl RelativeOpsScalar<f32> for FloatOperationIr {
                 tensor: desc.tensor.to_relative(converter),
                 qparams: QuantizationParametersIr {
                     scales: desc.qparams.scales.to_relative(converter),
+                    offsets: desc
+                        .qparams
+                        .offsets
+                        .as_ref()
+                        .map(|t| t.to_relative(converter)),
                 },
                 scheme: desc.scheme,
                 out: desc.out.to_relative(converter),
@@ -804,6 +932,32 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationIr<E> {
                 axis: desc.axis,
                 out: desc.out.to_relative(converter),
             }),
+            NumericOperationIr::CumSum(desc) => NumericOperationIr::CumSum(ReduceDimOpIr {
+                input: desc.input.to_relative(converter),
+                axis: desc.axis,
+                out: desc.out.to_relative(converter),
+            }),
+            NumericOperationIr::CumProd(desc) => NumericOperationIr::CumProd(ReduceDimOpIr {
+                input: desc.input.to_relative(converter),
+                axis: desc.axis,
+                out: desc.out.to_relative(converter),
+            }),
+            NumericOperationIr::CumMax(desc) => {
+                NumericOperationIr::CumMax(ReduceDimWithIndicesOpIr {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
+            NumericOperationIr::CumMin(desc) => {
+                NumericOperationIr::CumMin(ReduceDimWithIndicesOpIr {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
             NumericOperationIr::EqualElem(desc) => NumericOperationIr::EqualElem(ScalarOpIr {
                 lhs: desc.lhs.to_relative(converter),
                 rhs: local_elem(converter, &desc.rhs),
@@ -6,11 +6,17 @@ fn main() {
 
     // Add onnx models.
     ModelGen::new()
+        .input("tests/acos/acos.onnx")
+        .input("tests/acosh/acosh.onnx")
         .input("tests/add/add.onnx")
         .input("tests/and/and.onnx")
         .input("tests/add/add_int.onnx")
         .input("tests/argmax/argmax.onnx")
         .input("tests/argmin/argmin.onnx")
+        .input("tests/asin/asin.onnx")
+        .input("tests/asinh/asinh.onnx")
+        .input("tests/atan/atan.onnx")
+        .input("tests/atanh/atanh.onnx")
         .input("tests/attention/attention_4d.onnx")
         .input("tests/attention/attention_3d.onnx")
         .input("tests/attention/attention_attn_mask_bool.onnx")
@@ -67,10 +73,14 @@ fn main() {
         .input("tests/conv_transpose3d/conv_transpose3d.onnx")
         .input("tests/cos/cos.onnx")
         .input("tests/cosh/cosh.onnx")
+        .input("tests/cumsum/cumsum.onnx")
         .input("tests/depth_to_space/depth_to_space_dcr.onnx")
         .input("tests/depth_to_space/depth_to_space_crd.onnx")
+        .input("tests/det/det.onnx")
+        .input("tests/dft/dft.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout.onnx")
+        .input("tests/einsum/einsum.onnx")
         .input("tests/equal/equal.onnx")
         .input("tests/erf/erf.onnx")
         .input("tests/exp/exp.onnx")
@@ -86,6 +96,7 @@ fn main() {
         .input("tests/gather/gather_shape.onnx")
         .input("tests/gather/gather_scalar_out.onnx")
         .input("tests/gather_elements/gather_elements.onnx")
+        .input("tests/gather_nd/gather_nd.onnx")
         .input("tests/gelu/gelu.onnx")
         .input("tests/gemm/gemm.onnx")
         .input("tests/gemm/gemm_non_unit_alpha_beta.onnx")
@@ -96,6 +107,7 @@ fn main() {
         .input("tests/greater/greater_scalar.onnx")
         .input("tests/greater_or_equal/greater_or_equal.onnx")
         .input("tests/greater_or_equal/greater_or_equal_scalar.onnx")
+        .input("tests/grid_sample/grid_sample.onnx")
         .input("tests/group_norm/group_norm.onnx")
         .input("tests/hard_sigmoid/hard_sigmoid.onnx")
         .input("tests/instance_norm1d/instance_norm1d.onnx")
@@ -128,7 +140,9 @@ fn main() {
         .input("tests/maxpool2d/maxpool2d.onnx")
         .input("tests/min/min.onnx")
         .input("tests/mean/mean.onnx")
+        .input("tests/mel_weight_matrix/mel_weight_matrix.onnx")
         .input("tests/mul/mul.onnx")
+        .input("tests/multinomial/multinomial.onnx")
         .input("tests/neg/neg.onnx")
         .input("tests/not/not.onnx")
         .input("tests/one_hot/one_hot.onnx")
@@ -143,11 +157,16 @@ fn main() {
         .input("tests/random_uniform_like/random_uniform_like.onnx")
         .input("tests/range/range.onnx")
         .input("tests/recip/recip.onnx")
+        .input("tests/reduce_l1/reduce_l1.onnx")
+        .input("tests/reduce_l2/reduce_l2.onnx")
+        .input("tests/reduce_log_sum/reduce_log_sum.onnx")
+        .input("tests/reduce_log_sum_exp/reduce_log_sum_exp.onnx")
         .input("tests/reduce_max/reduce_max.onnx")
         .input("tests/reduce_mean/reduce_mean.onnx")
         .input("tests/reduce_min/reduce_min.onnx")
         .input("tests/reduce_prod/reduce_prod.onnx")
         .input("tests/reduce_sum/reduce_sum.onnx")
+        .input("tests/reduce_sum_square/reduce_sum_square.onnx")
         .input("tests/relu/relu.onnx")
         .input("tests/reshape/reshape.onnx")
         .input("tests/resize/resize_with_sizes.onnx")
@@ -157,6 +176,8 @@ fn main() {
         .input("tests/resize/resize_2d_bilinear_scale.onnx")
         .input("tests/resize/resize_2d_nearest_scale.onnx")
         .input("tests/round/round.onnx")
+        .input("tests/scatter_elements/scatter_elements.onnx")
+        .input("tests/scatter_nd/scatter_nd.onnx")
         .input("tests/shape/shape.onnx")
         .input("tests/sigmoid/sigmoid.onnx")
         .input("tests/sign/sign.onnx")
@@ -170,6 +191,7 @@ fn main() {
         .input("tests/sqrt/sqrt.onnx")
         .input("tests/squeeze/squeeze_multiple.onnx")
         .input("tests/squeeze/squeeze.onnx")
+        .input("tests/stft/stft.onnx")
         .input("tests/sub/sub.onnx")
         .input("tests/sub/sub_int.onnx")
         .input("tests/sum/sum.onnx")
@@ -181,9 +203,11 @@ fn main() {
         .input("tests/trilu/trilu_upper.onnx")
         .input("tests/trilu/trilu_lower.onnx")
         .input("tests/transpose/transpose.onnx")
+        .input("tests/unique/unique.onnx")
         .input("tests/unsqueeze/unsqueeze_runtime_axes.onnx")
         .input("tests/unsqueeze/unsqueeze_like.onnx")
         .input("tests/split/split.onnx")
+        .input("tests/window/window.onnx")
         .input("tests/xor/xor.onnx")
         .out_dir("model/")
         .run_from_script();
@@ -0,0 +1,27 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(acosh);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn acosh() {
+        let device = Default::default();
+        let model: acosh::Model<Backend> = acosh::Model::new(&device);
+
+        let input = Tensor::<Backend, 4>::from_floats([[[[1.5, 2.0, 3.0, 10.0]]]], &device);
+
+        let output = model.forward(input);
+        let expected = TensorData::from([[[[0.9624, 1.3170, 1.7627, 2.9932]]]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
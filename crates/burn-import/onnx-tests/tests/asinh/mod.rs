@@ -0,0 +1,27 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(asinh);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn asinh() {
+        let device = Default::default();
+        let model: asinh::Model<Backend> = asinh::Model::new(&device);
+
+        let input = Tensor::<Backend, 4>::from_floats([[[[-2.0, 0.0, 0.5, 3.0]]]], &device);
+
+        let output = model.forward(input);
+        let expected = TensorData::from([[[[-1.4436, 0.0000, 0.4812, 1.8184]]]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
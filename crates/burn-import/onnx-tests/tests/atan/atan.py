@@ -0,0 +1,56 @@
+#!/usr/bin/env python3
+
+# used to generate model: atan.onnx
+
+# this model is exported using onnx directly to keep a single node graph
+
+import onnx
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Atan",
+                    inputs=["input1"],
+                    outputs=["output1"],
+                    name="/Atan"
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[1, 1, 1, 4]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[1, 1, 1, 4]
+                    ),
+                )
+            ]
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "atan.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}") 
+
+if __name__ == "__main__":
+    main()
+
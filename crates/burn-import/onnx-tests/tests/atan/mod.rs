@@ -0,0 +1,27 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(atan);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn atan() {
+        let device = Default::default();
+        let model: atan::Model<Backend> = atan::Model::new(&device);
+
+        let input = Tensor::<Backend, 4>::from_floats([[[[-2.0, 0.0, 0.5, 3.0]]]], &device);
+
+        let output = model.forward(input);
+        let expected = TensorData::from([[[[-1.1071, 0.0000, 0.4636, 1.2490]]]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
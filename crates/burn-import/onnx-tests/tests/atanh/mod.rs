@@ -0,0 +1,27 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(atanh);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn atanh() {
+        let device = Default::default();
+        let model: atanh::Model<Backend> = atanh::Model::new(&device);
+
+        let input = Tensor::<Backend, 4>::from_floats([[[[-0.5, 0.0, 0.3, 0.9]]]], &device);
+
+        let output = model.forward(input);
+        let expected = TensorData::from([[[[-0.5493, 0.0000, 0.3095, 1.4722]]]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
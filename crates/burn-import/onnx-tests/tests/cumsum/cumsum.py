@@ -0,0 +1,83 @@
+#!/usr/bin/env python3
+
+# used to generate model: cumsum.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph inputs and outputs
+    input = onnx.helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])
+    output_exclusive_reverse = onnx.helper.make_tensor_value_info(
+        "output_exclusive_reverse", TensorProto.FLOAT, [2, 3]
+    )
+
+    # The axis is a scalar input, given by a constant
+    axis = onnx.helper.make_node(
+        "Constant",
+        inputs=[],
+        outputs=["axis"],
+        name="AxisNode",
+        value=onnx.helper.make_tensor("axis_value", TensorProto.INT64, [], [1]),
+    )
+
+    # Create the CumSum nodes, with the default and the exclusive reverse attributes
+    cumsum = onnx.helper.make_node(
+        "CumSum",
+        inputs=["input", "axis"],
+        outputs=["output"],
+        name="CumSumNode",
+    )
+    cumsum_exclusive_reverse = onnx.helper.make_node(
+        "CumSum",
+        inputs=["input", "axis"],
+        outputs=["output_exclusive_reverse"],
+        name="CumSumExclusiveReverseNode",
+        exclusive=1,
+        reverse=1,
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [axis, cumsum, cumsum_exclusive_reverse],
+        "CumSumModel",
+        [input],
+        [output, output_exclusive_reverse],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "cumsum.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    input = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], dtype=np.float32)
+    print(f"Test input data: {input}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    output, output_exclusive_reverse = session.run(None, {"input": input})
+    print(f"Test output: {output}")
+    print(f"Test output exclusive reverse: {output_exclusive_reverse}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,30 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(cumsum);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::Backend;
+
+    #[test]
+    fn cumsum() {
+        let device = Default::default();
+        let model: cumsum::Model<Backend> = cumsum::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let (output, output_exclusive_reverse) = model.forward(input);
+
+        output.to_data().assert_eq(
+            &TensorData::from([[1.0f32, 3.0, 6.0], [4.0, 9.0, 15.0]]),
+            true,
+        );
+        output_exclusive_reverse.to_data().assert_eq(
+            &TensorData::from([[5.0f32, 3.0, 0.0], [11.0, 6.0, 0.0]]),
+            true,
+        );
+    }
+}
@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+
+# used to generate model: det.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph inputs and outputs
+    input = onnx.helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 2, 2])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [2])
+
+    # Create the Det node
+    det = onnx.helper.make_node(
+        "Det",
+        inputs=["input"],
+        outputs=["output"],
+        name="DetNode",
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [det],
+        "DetModel",
+        [input],
+        [output],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "det.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    input = np.array(
+        [[[1.0, 2.0], [3.0, 4.0]], [[2.0, 0.5], [1.0, 3.0]]],
+        dtype=np.float32,
+    )
+    print(f"Test input data: {input}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    (output,) = session.run(None, {"input": input})
+    print(f"Test output data shape: {output.shape}")
+    print(f"Test output: {output}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,30 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(det);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn det() {
+        let device = Default::default();
+        let model: det::Model<Backend> = det::Model::new(&device);
+
+        let input = Tensor::<Backend, 3>::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[2.0, 0.5], [1.0, 3.0]]],
+            &device,
+        );
+
+        let output = model.forward(input);
+        let expected = TensorData::from([-2.0, 5.5]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
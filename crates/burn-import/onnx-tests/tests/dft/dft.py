@@ -0,0 +1,79 @@
+#!/usr/bin/env python3
+
+# used to generate model: dft.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph inputs and outputs
+    input = onnx.helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 8, 1])
+    spectrum = onnx.helper.make_tensor_value_info("spectrum", TensorProto.FLOAT, [2, 8, 2])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 8, 2])
+
+    # Create the DFT node of the real signal
+    dft = onnx.helper.make_node(
+        "DFT",
+        inputs=["input"],
+        outputs=["spectrum"],
+        name="DftNode",
+        axis=1,
+        onesided=0,
+    )
+
+    # Create the inverse DFT node of the complex spectrum
+    inverse_dft = onnx.helper.make_node(
+        "DFT",
+        inputs=["spectrum"],
+        outputs=["output"],
+        name="InverseDftNode",
+        axis=1,
+        inverse=1,
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [dft, inverse_dft],
+        "DftModel",
+        [input],
+        [spectrum, output],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 17)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "dft.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    input = np.array(
+        [[1.0, 2.0, 3.0, 4.0, 0.0, -1.0, -2.0, -3.0], [0.5, 0.0, 1.0, 0.0, -1.0, 2.0, 0.0, 1.0]],
+        dtype=np.float32,
+    ).reshape(2, 8, 1)
+    print(f"Test input data: {input}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    spectrum, output = session.run(None, {"input": input})
+    print(f"Test spectrum: {spectrum}")
+    print(f"Test output: {output}")
+
+
+if __name__ == "__main__":
+    main()
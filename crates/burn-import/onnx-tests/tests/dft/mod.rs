@@ -0,0 +1,81 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(dft);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn dft() {
+        let device = Default::default();
+        let model: dft::Model<Backend> = dft::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [
+                [1.0, 2.0, 3.0, 4.0, 0.0, -1.0, -2.0, -3.0],
+                [0.5, 0.0, 1.0, 0.0, -1.0, 2.0, 0.0, 1.0],
+            ],
+            &device,
+        )
+        .unsqueeze_dim::<3>(2);
+
+        let (spectrum, output) = model.forward(input);
+        let expected_spectrum = TensorData::from([
+            [
+                [4.0, 0.0],
+                [-1.8284, -12.0711],
+                [0.0, 0.0],
+                [3.8284, -2.0711],
+                [0.0, 0.0],
+                [3.8284, 2.0711],
+                [0.0, 0.0],
+                [-1.8284, 12.0711],
+            ],
+            [
+                [3.5, 0.0],
+                [0.7929, 1.1213],
+                [-1.5, -1.0],
+                [2.2071, 3.1213],
+                [-2.5, 0.0],
+                [2.2071, -3.1213],
+                [-1.5, 1.0],
+                [0.7929, -1.1213],
+            ],
+        ]);
+        let expected_output = TensorData::from([
+            [
+                [1.0, 0.0],
+                [2.0, 0.0],
+                [3.0, 0.0],
+                [4.0, 0.0],
+                [0.0, 0.0],
+                [-1.0, 0.0],
+                [-2.0, 0.0],
+                [-3.0, 0.0],
+            ],
+            [
+                [0.5, 0.0],
+                [0.0, 0.0],
+                [1.0, 0.0],
+                [0.0, 0.0],
+                [-1.0, 0.0],
+                [2.0, 0.0],
+                [0.0, 0.0],
+                [1.0, 0.0],
+            ],
+        ]);
+
+        let tolerance = Tolerance::absolute(1e-3);
+        spectrum
+            .to_data()
+            .assert_approx_eq::<FT>(&expected_spectrum, tolerance);
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected_output, tolerance);
+    }
+}
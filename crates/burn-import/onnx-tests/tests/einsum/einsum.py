@@ -0,0 +1,69 @@
+#!/usr/bin/env python3
+
+# used to generate model: einsum.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph inputs and outputs
+    lhs = onnx.helper.make_tensor_value_info("lhs", TensorProto.FLOAT, [2, 2, 3])
+    rhs = onnx.helper.make_tensor_value_info("rhs", TensorProto.FLOAT, [3, 2])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 2, 2])
+
+    # Create the Einsum node, the inputs have different ranks
+    einsum = onnx.helper.make_node(
+        "Einsum",
+        inputs=["lhs", "rhs"],
+        outputs=["output"],
+        name="EinsumNode",
+        equation="bij,jk->bik",
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [einsum],
+        "EinsumModel",
+        [lhs, rhs],
+        [output],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "einsum.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    lhs = np.array(
+        [[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], [[-1.0, 0.5, 0.0], [2.0, -2.0, 1.0]]],
+        dtype=np.float32,
+    )
+    rhs = np.array([[1.0, -1.0], [0.5, 2.0], [-2.0, 1.0]], dtype=np.float32)
+    print(f"Test input data: {lhs}, {rhs}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    (output,) = session.run(None, {"lhs": lhs, "rhs": rhs})
+    print(f"Test output data shape: {output.shape}")
+    print(f"Test output: {output}")
+
+
+if __name__ == "__main__":
+    main()
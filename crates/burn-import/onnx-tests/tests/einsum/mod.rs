@@ -0,0 +1,36 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(einsum);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn einsum() {
+        let device = Default::default();
+        let model: einsum::Model<Backend> = einsum::Model::new(&device);
+
+        let lhs = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                [[-1.0, 0.5, 0.0], [2.0, -2.0, 1.0]],
+            ],
+            &device,
+        );
+        let rhs =
+            Tensor::<Backend, 2>::from_floats([[1.0, -1.0], [0.5, 2.0], [-2.0, 1.0]], &device);
+
+        let output = model.forward(lhs, rhs);
+        let expected =
+            TensorData::from([[[-4.0, 6.0], [-5.5, 12.0]], [[-0.75, 2.0], [-1.0, -5.0]]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
@@ -0,0 +1,56 @@
+#!/usr/bin/env python3
+
+# used to generate model: gather_nd.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    data = onnx.helper.make_tensor_value_info("data", TensorProto.FLOAT, [2, 2, 2])
+    indices = onnx.helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 1, 2])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 1, 2])
+
+    node = onnx.helper.make_node(
+        "GatherND",
+        inputs=["data", "indices"],
+        outputs=["output"],
+        name="GatherNDNode",
+    )
+
+    graph = onnx.helper.make_graph([node], "GatherNDModel", [data, indices], [output])
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "gather_nd.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    data = np.arange(8, dtype=np.float32).reshape(2, 2, 2)
+    indices = np.array([[[0, 1]], [[1, 0]]], dtype=np.int64)
+    print(f"Test data: {data}")
+    print(f"Test indices: {indices}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    (output,) = session.run(None, {"data": data, "indices": indices})
+    print(f"Test output: {output}")
+
+
+if __name__ == "__main__":
+    main()
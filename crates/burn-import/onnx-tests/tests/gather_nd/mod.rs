@@ -0,0 +1,28 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(gather_nd);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData};
+
+    use crate::backend::Backend;
+
+    #[test]
+    fn gather_nd() {
+        let device = Default::default();
+        let model: gather_nd::Model<Backend> = gather_nd::Model::new(&device);
+
+        let data = Tensor::<Backend, 3>::from_floats(
+            [[[0., 1.], [2., 3.]], [[4., 5.], [6., 7.]]],
+            &device,
+        );
+        let indices = Tensor::<Backend, 3, Int>::from_ints([[[0, 1]], [[1, 0]]], &device);
+
+        let output = model.forward(data, indices);
+        let expected = TensorData::from([[[2f32, 3.]], [[4., 5.]]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+}
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+
+# used to generate model: grid_sample.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    input = onnx.helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 3, 4])
+    grid = onnx.helper.make_tensor_value_info("grid", TensorProto.FLOAT, [1, 2, 3, 2])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 1, 2, 3])
+
+    node = onnx.helper.make_node(
+        "GridSample",
+        inputs=["input", "grid"],
+        outputs=["output"],
+        name="GridSampleNode",
+        align_corners=1,
+        mode="bilinear",
+        padding_mode="border",
+    )
+
+    graph = onnx.helper.make_graph([node], "GridSampleModel", [input, grid], [output])
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "grid_sample.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    input = np.arange(12, dtype=np.float32).reshape(1, 1, 3, 4)
+    grid = np.array(
+        [[[[-1.0, -1.0], [0.5, -0.25], [1.5, 0.0]], [[-0.2, 0.8], [0.0, 0.0], [-1.3, 1.2]]]],
+        dtype=np.float32,
+    )
+    print(f"Test input data: {input}")
+    print(f"Test grid data: {grid}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    (output,) = session.run(None, {"input": input, "grid": grid})
+    print(f"Test output: {output}")
+
+
+if __name__ == "__main__":
+    main()
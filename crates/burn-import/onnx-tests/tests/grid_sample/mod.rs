@@ -0,0 +1,38 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(grid_sample);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn grid_sample() {
+        let device = Default::default();
+        let model: grid_sample::Model<Backend> = grid_sample::Model::new(&device);
+
+        let input = Tensor::<Backend, 1>::from_floats(
+            [0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11.],
+            &device,
+        )
+        .reshape([1, 1, 3, 4]);
+        let grid = Tensor::<Backend, 4>::from_floats(
+            [[
+                [[-1.0, -1.0], [0.5, -0.25], [1.5, 0.0]],
+                [[-0.2, 0.8], [0.0, 0.0], [-1.3, 1.2]],
+            ]],
+            &device,
+        );
+
+        let output = model.forward(input, grid);
+        let expected = TensorData::from([[[[0.0f32, 5.25, 7.0], [8.4, 5.5, 8.0]]]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
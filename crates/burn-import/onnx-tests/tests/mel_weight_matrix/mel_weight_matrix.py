@@ -0,0 +1,74 @@
+#!/usr/bin/env python3
+
+# used to generate model: mel_weight_matrix.onnx
+
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph outputs
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [9, 4])
+
+    # The scalar inputs are stored as initializers
+    initializers = [
+        onnx.helper.make_tensor("num_mel_bins", TensorProto.INT64, [], [4]),
+        onnx.helper.make_tensor("dft_length", TensorProto.INT64, [], [16]),
+        onnx.helper.make_tensor("sample_rate", TensorProto.INT64, [], [8000]),
+        onnx.helper.make_tensor("lower_edge_hertz", TensorProto.FLOAT, [], [0.0]),
+        onnx.helper.make_tensor("upper_edge_hertz", TensorProto.FLOAT, [], [4000.0]),
+    ]
+
+    # Create the MelWeightMatrix node
+    mel_weight_matrix = onnx.helper.make_node(
+        "MelWeightMatrix",
+        inputs=[
+            "num_mel_bins",
+            "dft_length",
+            "sample_rate",
+            "lower_edge_hertz",
+            "upper_edge_hertz",
+        ],
+        outputs=["output"],
+        name="MelWeightMatrixNode",
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [mel_weight_matrix],
+        "MelWeightMatrixModel",
+        [],
+        [output],
+        initializer=initializers,
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 17)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "mel_weight_matrix.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    (output,) = session.run(None, {})
+    print(f"Test output data shape: {output.shape}")
+    print(f"Test output: {output}")
+
+
+if __name__ == "__main__":
+    main()
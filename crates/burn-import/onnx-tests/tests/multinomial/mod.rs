@@ -0,0 +1,42 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(multinomial);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, cast::ToElement};
+
+    use crate::backend::Backend;
+
+    #[test]
+    fn multinomial() {
+        let device = Default::default();
+        let model: multinomial::Model<Backend> = multinomial::Model::new(&device);
+
+        // Log-probabilities, where -inf is a class with a zero probability.
+        let input = Tensor::<Backend, 2>::from_floats(
+            [
+                [
+                    0.5f32.ln(),
+                    0.5f32.ln(),
+                    f32::NEG_INFINITY,
+                    f32::NEG_INFINITY,
+                ],
+                [f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY, 0.0],
+            ],
+            &device,
+        );
+
+        let output = model.forward(input);
+
+        assert_eq!(output.dims(), [2, 8]);
+        output
+            .clone()
+            .slice([1..2, 0..8])
+            .to_data()
+            .assert_eq(&TensorData::from([[3i64; 8]]), false);
+        let max = output.slice([0..1, 0..8]).max().into_scalar().to_i64();
+        assert!(max <= 1);
+    }
+}
@@ -0,0 +1,60 @@
+#!/usr/bin/env python3
+
+# used to generate model: multinomial.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+
+
+def build_model():
+    # Define the graph inputs and outputs
+    input = onnx.helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 4])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.INT64, [2, 8])
+
+    # Create the Multinomial node, sampling 8 classes per batch from log-probabilities
+    node = onnx.helper.make_node(
+        "Multinomial",
+        inputs=["input"],
+        outputs=["output"],
+        name="MultinomialNode",
+        dtype=TensorProto.INT64,
+        sample_size=8,
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [node],
+        "MultinomialModel",
+        [input],
+        [output],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "multinomial.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # The output is random, so the test only checks the shape and the sampled classes. A class
+    # with a very low log-probability is never drawn.
+    input = np.log(np.array([[0.5, 0.5, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0]], dtype=np.float32))
+    print(f"Test input data: {input}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,67 @@
+#!/usr/bin/env python3
+
+# used to generate model: reduce_l2.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    input = onnx.helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])
+    output1 = onnx.helper.make_tensor_value_info("output1", TensorProto.FLOAT, [])
+    output2 = onnx.helper.make_tensor_value_info("output2", TensorProto.FLOAT, [2, 1])
+
+    nodes = [
+        # ReduceL2, keepdims=0, axes=None
+        onnx.helper.make_node(
+            "ReduceL2",
+            inputs=["input"],
+            outputs=["output1"],
+            name="ReduceL2Node1",
+            keepdims=0,
+        ),
+        # ReduceL2, keepdims=1, axes=[-1]
+        onnx.helper.make_node(
+            "ReduceL2",
+            inputs=["input"],
+            outputs=["output2"],
+            name="ReduceL2Node2",
+            axes=[-1],
+            keepdims=1,
+        ),
+    ]
+
+    graph = onnx.helper.make_graph(nodes, "ReduceL2Model", [input], [output1, output2])
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "reduce_l2.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    input = np.array([[1.0, -2.0, 3.0], [-4.0, 5.0, 6.0]], dtype=np.float32)
+    print(f"Test input data: {input}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    output1, output2 = session.run(None, {"input": input})
+    print(f"Test output data: {output1}, {output2}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,32 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(reduce_log_sum);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn reduce_log_sum() {
+        let device = Default::default();
+        let model: reduce_log_sum::Model<Backend> = reduce_log_sum::Model::new(&device);
+
+        // Run the model
+        let input =
+            Tensor::<Backend, 2>::from_floats([[1.0, -2.0, 3.0], [-4.0, 5.0, 6.0]], &device);
+        let (output_scalar, output_tensor) = model.forward(input);
+        let expected_scalar = TensorData::from([2.1972246f32]);
+        let expected = TensorData::from([[core::f32::consts::LN_2], [1.9459101]]);
+
+        output_scalar
+            .to_data()
+            .assert_approx_eq::<FT>(&expected_scalar, Tolerance::default());
+        output_tensor
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
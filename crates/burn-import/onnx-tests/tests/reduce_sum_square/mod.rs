@@ -0,0 +1,32 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(reduce_sum_square);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn reduce_sum_square() {
+        let device = Default::default();
+        let model: reduce_sum_square::Model<Backend> = reduce_sum_square::Model::new(&device);
+
+        // Run the model
+        let input =
+            Tensor::<Backend, 2>::from_floats([[1.0, -2.0, 3.0], [-4.0, 5.0, 6.0]], &device);
+        let (output_scalar, output_tensor) = model.forward(input);
+        let expected_scalar = TensorData::from([91.0f32]);
+        let expected = TensorData::from([[14.0], [77.0]]);
+
+        output_scalar
+            .to_data()
+            .assert_approx_eq::<FT>(&expected_scalar, Tolerance::default());
+        output_tensor
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
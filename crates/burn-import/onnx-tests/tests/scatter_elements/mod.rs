@@ -0,0 +1,34 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(scatter_elements);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData};
+
+    use crate::backend::Backend;
+
+    #[test]
+    fn scatter_elements() {
+        let device = Default::default();
+        let model: scatter_elements::Model<Backend> = scatter_elements::Model::new(&device);
+
+        let data =
+            Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]], &device);
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[1, 0, 2], [0, 2, 1]], &device);
+        let updates =
+            Tensor::<Backend, 2>::from_floats([[10., 11., 12.], [13., 14., 15.]], &device);
+
+        let (output_replace, output_add) = model.forward(data, indices, updates);
+
+        output_replace.to_data().assert_eq(
+            &TensorData::from([[13f32, 11., 3.], [10., 5., 15.], [7., 14., 12.]]),
+            true,
+        );
+        output_add.to_data().assert_eq(
+            &TensorData::from([[14f32, 13., 3.], [14., 5., 21.], [7., 22., 21.]]),
+            true,
+        );
+    }
+}
@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+
+# used to generate model: scatter_elements.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    data = onnx.helper.make_tensor_value_info("data", TensorProto.FLOAT, [3, 3])
+    indices = onnx.helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 3])
+    updates = onnx.helper.make_tensor_value_info("updates", TensorProto.FLOAT, [2, 3])
+    output1 = onnx.helper.make_tensor_value_info("output1", TensorProto.FLOAT, [3, 3])
+    output2 = onnx.helper.make_tensor_value_info("output2", TensorProto.FLOAT, [3, 3])
+
+    # The updates replace the data
+    replace = onnx.helper.make_node(
+        "ScatterElements",
+        inputs=["data", "indices", "updates"],
+        outputs=["output1"],
+        name="ScatterElementsNode1",
+        axis=0,
+    )
+
+    # The updates are summed to the data
+    add = onnx.helper.make_node(
+        "ScatterElements",
+        inputs=["data", "indices", "updates"],
+        outputs=["output2"],
+        name="ScatterElementsNode2",
+        axis=0,
+        reduction="add",
+    )
+
+    graph = onnx.helper.make_graph(
+        [replace, add], "ScatterElementsModel", [data, indices, updates], [output1, output2]
+    )
+
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "scatter_elements.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    data = np.arange(1, 10, dtype=np.float32).reshape(3, 3)
+    indices = np.array([[1, 0, 2], [0, 2, 1]], dtype=np.int64)
+    updates = np.arange(10, 16, dtype=np.float32).reshape(2, 3)
+    print(f"Test data: {data}")
+    print(f"Test indices: {indices}")
+    print(f"Test updates: {updates}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    output1, output2 = session.run(
+        None, {"data": data, "indices": indices, "updates": updates}
+    )
+    print(f"Test output1: {output1}")
+    print(f"Test output2: {output2}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,34 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(scatter_nd);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Int, Tensor, TensorData};
+
+    use crate::backend::Backend;
+
+    #[test]
+    fn scatter_nd() {
+        let device = Default::default();
+        let model: scatter_nd::Model<Backend> = scatter_nd::Model::new(&device);
+
+        let data =
+            Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]], &device);
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[2], [0]], &device);
+        let updates =
+            Tensor::<Backend, 2>::from_floats([[10., 11., 12.], [13., 14., 15.]], &device);
+
+        let (output_replace, output_add) = model.forward(data, indices, updates);
+
+        output_replace.to_data().assert_eq(
+            &TensorData::from([[13f32, 14., 15.], [4., 5., 6.], [10., 11., 12.]]),
+            true,
+        );
+        output_add.to_data().assert_eq(
+            &TensorData::from([[14f32, 16., 18.], [4., 5., 6.], [17., 19., 21.]]),
+            true,
+        );
+    }
+}
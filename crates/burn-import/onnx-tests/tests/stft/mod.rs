@@ -0,0 +1,56 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(stft);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn stft() {
+        let device = Default::default();
+        let model: stft::Model<Backend> = stft::Model::new(&device);
+
+        let signal = Tensor::<Backend, 2>::from_floats(
+            [[
+                0.0, 1.0, 2.0, 3.0, 2.0, 1.0, 0.0, -1.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0,
+            ]],
+            &device,
+        )
+        .unsqueeze_dim::<3>(2);
+
+        let output = model.forward(signal);
+        #[allow(clippy::approx_constant)]
+        let expected = TensorData::from([[
+            [
+                [6.4142, 0.0],
+                [-4.4142, -2.4142],
+                [1.0, 1.4142],
+                [0.4142, -0.4142],
+                [-0.4142, 0.0],
+            ],
+            [
+                [-3.4142, 0.0],
+                [3.4142, 0.0],
+                [-2.0, 0.0],
+                [0.5858, 0.0],
+                [-0.5858, 0.0],
+            ],
+            [
+                [8.0, 0.0],
+                [-4.0, 3.8284],
+                [0.0, -0.8284],
+                [0.0, -0.1716],
+                [0.0, 0.0],
+            ],
+        ]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::absolute(1e-3));
+    }
+}
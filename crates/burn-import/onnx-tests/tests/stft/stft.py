@@ -0,0 +1,79 @@
+#!/usr/bin/env python3
+
+# used to generate model: stft.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph inputs and outputs
+    signal = onnx.helper.make_tensor_value_info("signal", TensorProto.FLOAT, [1, 16, 1])
+    output = onnx.helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 3, 5, 2])
+
+    # The scalar inputs are stored as initializers
+    frame_step = onnx.helper.make_tensor("frame_step", TensorProto.INT64, [], [4])
+    window_size = onnx.helper.make_tensor("window_size", TensorProto.INT64, [], [8])
+
+    # Create the periodic Hann window
+    window = onnx.helper.make_node(
+        "HannWindow",
+        inputs=["window_size"],
+        outputs=["window"],
+        name="HannWindowNode",
+    )
+
+    # Create the one-sided STFT node
+    stft = onnx.helper.make_node(
+        "STFT",
+        inputs=["signal", "frame_step", "window"],
+        outputs=["output"],
+        name="StftNode",
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [window, stft],
+        "StftModel",
+        [signal],
+        [output],
+        initializer=[frame_step, window_size],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 17)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "stft.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    signal = np.array(
+        [0.0, 1.0, 2.0, 3.0, 2.0, 1.0, 0.0, -1.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+        dtype=np.float32,
+    ).reshape(1, 16, 1)
+    print(f"Test input data: {signal}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    (output,) = session.run(None, {"signal": signal})
+    print(f"Test output data shape: {output.shape}")
+    print(f"Test output: {output}")
+
+
+if __name__ == "__main__":
+    main()
@@ -5,10 +5,16 @@ extern crate alloc;
 mod backend;
 
 // Import individual node modules
+pub mod acos;
+pub mod acosh;
 pub mod add;
 pub mod and;
 pub mod argmax;
 pub mod argmin;
+pub mod asin;
+pub mod asinh;
+pub mod atan;
+pub mod atanh;
 pub mod attention;
 pub mod avg_pool;
 pub mod batch_norm;
@@ -28,9 +34,13 @@ pub mod conv;
 pub mod conv_transpose;
 pub mod cos;
 pub mod cosh;
+pub mod cumsum;
 pub mod depth_to_space;
+pub mod det;
+pub mod dft;
 pub mod div;
 pub mod dropout;
+pub mod einsum;
 pub mod equal;
 pub mod erf;
 pub mod exp;
@@ -38,12 +48,14 @@ pub mod expand;
 pub mod flatten;
 pub mod floor;
 pub mod gather;
+pub mod gather_nd;
 pub mod gelu;
 pub mod gemm;
 pub mod global_avr_pool;
 pub mod graph_multiple_output_tracking;
 pub mod greater;
 pub mod greater_or_equal;
+pub mod grid_sample;
 pub mod group_norm;
 pub mod hard_sigmoid;
 pub mod instance_norm;
@@ -61,8 +73,10 @@ pub mod matmul;
 pub mod max;
 pub mod maxpool;
 pub mod mean;
+pub mod mel_weight_matrix;
 pub mod min;
 pub mod mul;
+pub mod multinomial;
 pub mod neg;
 pub mod not;
 pub mod one_hot;
@@ -76,15 +90,22 @@ pub mod random_uniform;
 pub mod random_uniform_like;
 pub mod range;
 pub mod recip;
+pub mod reduce_l1;
+pub mod reduce_l2;
+pub mod reduce_log_sum;
+pub mod reduce_log_sum_exp;
 pub mod reduce_max;
 pub mod reduce_mean;
 pub mod reduce_min;
 pub mod reduce_prod;
 pub mod reduce_sum;
+pub mod reduce_sum_square;
 pub mod relu;
 pub mod reshape;
 pub mod resize;
 pub mod round;
+pub mod scatter_elements;
+pub mod scatter_nd;
 pub mod shape;
 pub mod sigmoid;
 pub mod sign;
@@ -96,6 +117,7 @@ pub mod space_to_depth;
 pub mod split;
 pub mod sqrt;
 pub mod squeeze;
+pub mod stft;
 pub mod sub;
 pub mod sum;
 pub mod tan;
@@ -104,7 +126,9 @@ pub mod tile;
 pub mod topk;
 pub mod transpose;
 pub mod trilu;
+pub mod unique;
 pub mod unsqueeze;
+pub mod window;
 pub mod xor;
 
 /// Include specified models in the `model` directory in the target directory.
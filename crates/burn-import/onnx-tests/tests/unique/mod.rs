@@ -0,0 +1,34 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(unique);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    use crate::backend::Backend;
+
+    #[test]
+    fn unique() {
+        let device = Default::default();
+        let model: unique::Model<Backend> = unique::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[2.0, 1.0, 2.0], [3.0, 1.0, 0.5]], &device);
+
+        let (unique, indices, inverse_indices, counts) = model.forward(input);
+
+        unique
+            .to_data()
+            .assert_eq(&TensorData::from([0.5f32, 1.0, 2.0, 3.0]), false);
+        indices
+            .to_data()
+            .assert_eq(&TensorData::from([5i64, 1, 0, 3]), false);
+        inverse_indices
+            .to_data()
+            .assert_eq(&TensorData::from([2i64, 1, 2, 3, 1, 0]), false);
+        counts
+            .to_data()
+            .assert_eq(&TensorData::from([1i64, 2, 2, 1]), false);
+    }
+}
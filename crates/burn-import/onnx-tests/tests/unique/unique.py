@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+# used to generate model: unique.onnx
+
+import numpy as np
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph inputs and outputs
+    input = onnx.helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])
+    unique = onnx.helper.make_tensor_value_info("unique", TensorProto.FLOAT, ["n"])
+    indices = onnx.helper.make_tensor_value_info("indices", TensorProto.INT64, ["n"])
+    inverse_indices = onnx.helper.make_tensor_value_info(
+        "inverse_indices", TensorProto.INT64, [6]
+    )
+    counts = onnx.helper.make_tensor_value_info("counts", TensorProto.INT64, ["n"])
+
+    # Create the Unique node on the flattened input
+    node = onnx.helper.make_node(
+        "Unique",
+        inputs=["input"],
+        outputs=["unique", "indices", "inverse_indices", "counts"],
+        name="UniqueNode",
+        sorted=1,
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [node],
+        "UniqueModel",
+        [input],
+        [unique, indices, inverse_indices, counts],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "unique.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    input = np.array([[2.0, 1.0, 2.0], [3.0, 1.0, 0.5]], dtype=np.float32)
+    print(f"Test input data: {input}")
+
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    unique, indices, inverse_indices, counts = session.run(None, {"input": input})
+    print(f"Test unique: {unique}")
+    print(f"Test indices: {indices}")
+    print(f"Test inverse indices: {inverse_indices}")
+    print(f"Test counts: {counts}")
+
+
+if __name__ == "__main__":
+    main()
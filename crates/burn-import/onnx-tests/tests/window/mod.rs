@@ -0,0 +1,33 @@
+// Import the shared macro
+use crate::include_models;
+include_models!(window);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{TensorData, Tolerance, ops::FloatElem};
+
+    use crate::backend::Backend;
+    type FT = FloatElem<Backend>;
+
+    #[test]
+    fn window() {
+        let device = Default::default();
+        let model: window::Model<Backend> = window::Model::new(&device);
+
+        let (hamming, blackman) = model.forward();
+        let expected_hamming = TensorData::from([
+            0.086957, 0.220669, 0.543478, 0.866288, 1.0, 0.866288, 0.543478, 0.220669,
+        ]);
+        let expected_blackman = TensorData::from([
+            0.0, 0.090453, 0.459183, 0.920364, 0.920364, 0.459183, 0.090453, 0.0,
+        ]);
+
+        hamming
+            .to_data()
+            .assert_approx_eq::<FT>(&expected_hamming, Tolerance::default());
+        blackman
+            .to_data()
+            .assert_approx_eq::<FT>(&expected_blackman, Tolerance::default());
+    }
+}
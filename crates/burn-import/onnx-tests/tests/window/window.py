@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+
+# used to generate model: window.onnx
+
+import onnx
+import onnx.helper
+from onnx import TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def build_model():
+    # Define the graph outputs
+    hamming = onnx.helper.make_tensor_value_info("hamming", TensorProto.FLOAT, [8])
+    blackman = onnx.helper.make_tensor_value_info("blackman", TensorProto.FLOAT, [8])
+
+    # The window size is stored as an initializer
+    size = onnx.helper.make_tensor("size", TensorProto.INT64, [], [8])
+
+    # Create the periodic Hamming window
+    hamming_window = onnx.helper.make_node(
+        "HammingWindow",
+        inputs=["size"],
+        outputs=["hamming"],
+        name="HammingWindowNode",
+    )
+
+    # Create the symmetric Blackman window
+    blackman_window = onnx.helper.make_node(
+        "BlackmanWindow",
+        inputs=["size"],
+        outputs=["blackman"],
+        name="BlackmanWindowNode",
+        periodic=0,
+    )
+
+    # Create the graph
+    graph = onnx.helper.make_graph(
+        [hamming_window, blackman_window],
+        "WindowModel",
+        [],
+        [hamming, blackman],
+        initializer=[size],
+    )
+
+    # Create the model
+    model = onnx.helper.make_model(
+        opset_imports=[onnx.helper.make_operatorsetid("", 17)],
+        graph=graph,
+        producer_name="ONNX_Generator",
+    )
+
+    return model
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "window.onnx"
+
+    # Ensure valid ONNX and save
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    session = ReferenceEvaluator(onnx_model, verbose=1)
+    hamming, blackman = session.run(None, {})
+    print(f"Test hamming: {hamming}")
+    print(f"Test blackman: {blackman}")
+
+
+if __name__ == "__main__":
+    main()
@@ -8,17 +8,21 @@ use super::{
     concat::ConcatNode, constant::ConstantNode, constant_of_shape::ConstantOfShapeNode,
     conv_transpose_1d::ConvTranspose1dNode, conv_transpose_2d::ConvTranspose2dNode,
     conv_transpose_3d::ConvTranspose3dNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
-    conv3d::Conv3dNode, depth_to_space::DepthToSpaceNode, dropout::DropoutNode, expand::ExpandNode,
-    floor::FloorNode, gather::GatherNode, gather_elements::GatherElementsNode, gemm::GemmNode,
-    global_avg_pool::GlobalAvgPoolNode, group_norm::GroupNormNode, instance_norm::InstanceNormNode,
-    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode,
-    pad::PadNode, prelu::PReluNode, random_normal::RandomNormalNode,
-    random_normal_like::RandomNormalLikeNode, random_uniform::RandomUniformNode,
-    random_uniform_like::RandomUniformLikeNode, range::RangeNode, reshape::ReshapeNode,
-    resize::ResizeNode, round::RoundNode, slice::SliceNode, split::SplitNode, squeeze::SqueezeNode,
-    sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode, unary::UnaryNode,
-    unsqueeze::UnsqueezeNode,
+    conv3d::Conv3dNode, cumsum::CumSumNode, depth_to_space::DepthToSpaceNode, dft::DftNode,
+    dropout::DropoutNode, einsum::EinsumNode, expand::ExpandNode, floor::FloorNode,
+    gather::GatherNode, gather_elements::GatherElementsNode, gather_nd::GatherNdNode,
+    gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode, grid_sample::GridSampleNode,
+    group_norm::GroupNormNode, instance_norm::InstanceNormNode, layer_norm::LayerNormNode,
+    linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode, max_pool1d::MaxPool1dNode,
+    max_pool2d::MaxPool2dNode, mean::MeanNode, mel_weight_matrix::MelWeightMatrixNode,
+    multinomial::MultinomialNode, one_hot::OneHotNode, pad::PadNode, prelu::PReluNode,
+    random_normal::RandomNormalNode, random_normal_like::RandomNormalLikeNode,
+    random_uniform::RandomUniformNode, random_uniform_like::RandomUniformLikeNode,
+    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, round::RoundNode,
+    scatter_elements::ScatterElementsNode, scatter_nd::ScatterNdNode, slice::SliceNode,
+    split::SplitNode, squeeze::SqueezeNode, stft::StftNode, sum::SumNode, tile::TileNode,
+    top_k::TopKNode, trilu::TriluNode, unary::UnaryNode, unique::UniqueNode,
+    unsqueeze::UnsqueezeNode, window::WindowNode,
 };
 use crate::burn::{
     BurnImports, Scope, Type,
@@ -112,16 +116,21 @@ pub enum Node<PS: PrecisionSettings> {
     ConvTranspose1d(ConvTranspose1dNode),
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
+    CumSum(CumSumNode),
     DepthToSpace(DepthToSpaceNode),
     PRelu(PReluNode),
     Dropout(DropoutNode),
+    Dft(DftNode),
+    Einsum(EinsumNode),
     Expand(ExpandNode),
     Floor(FloorNode),
     Ceil(CeilNode),
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
+    GatherNd(GatherNdNode),
     Gemm(GemmNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GridSample(GridSampleNode),
     InstanceNorm(InstanceNormNode),
     LayerNorm(LayerNormNode),
     GroupNorm(GroupNormNode),
@@ -130,23 +139,30 @@ pub enum Node<PS: PrecisionSettings> {
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
     Mean(MeanNode),
+    MelWeightMatrix(MelWeightMatrixNode),
+    Multinomial(MultinomialNode),
     OneHot(OneHotNode),
     Pad(PadNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
     Round(RoundNode),
+    ScatterElements(ScatterElementsNode),
+    ScatterNd(ScatterNdNode),
     Slice(SliceNode),
     Squeeze(SqueezeNode),
     SpaceToDepth(SpaceToDepthNode),
     Split(SplitNode),
+    Stft(StftNode),
     Sum(SumNode),
     Tile(TileNode),
     TopK(TopKNode),
     Trilu(TriluNode),
     Unary(UnaryNode),
+    Unique(UniqueNode),
     Unsqueeze(UnsqueezeNode),
     Where(WhereNode),
+    Window(WindowNode),
     RandomNormal(RandomNormalNode),
     RandomNormalLike(RandomNormalLikeNode),
     RandomUniform(RandomUniformNode),
@@ -183,16 +199,21 @@ macro_rules! match_all {
             Node::ConvTranspose1d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
+            Node::CumSum(node) => $func(node),
             Node::DepthToSpace(node) => $func(node),
             Node::PRelu(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Dft(node) => $func(node),
+            Node::Einsum(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Floor(node) => $func(node),
             Node::Ceil(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
+            Node::GatherNd(node) => $func(node),
             Node::Gemm(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GridSample(node) => $func(node),
             Node::InstanceNorm(node) => $func(node),
             Node::LayerNorm(node) => $func(node),
             Node::GroupNorm(node) => $func(node),
@@ -201,12 +222,16 @@ macro_rules! match_all {
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Mean(node) => $func(node),
+            Node::MelWeightMatrix(node) => $func(node),
+            Node::Multinomial(node) => $func(node),
             Node::OneHot(node) => $func(node),
             Node::Pad(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
             Node::Round(node) => $func(node),
+            Node::ScatterElements(node) => $func(node),
+            Node::ScatterNd(node) => $func(node),
             Node::Slice(node) => $func(node),
             Node::SpaceToDepth(node) => $func(node),
             Node::Squeeze(node) => $func(node),
@@ -215,14 +240,17 @@ macro_rules! match_all {
             Node::TopK(node) => $func(node),
             Node::Trilu(node) => $func(node),
             Node::Unary(node) => $func(node),
+            Node::Unique(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
             Node::Where(node) => $func(node),
+            Node::Window(node) => $func(node),
             Node::RandomNormal(node) => $func(node),
             Node::RandomNormalLike(node) => $func(node),
             Node::RandomUniform(node) => $func(node),
             Node::RandomUniformLike(node) => $func(node),
             Node::ConstantOfShape(node) => $func(node),
             Node::Split(node) => $func(node),
+            Node::Stft(node) => $func(node),
             _ => unimplemented!(),
         }
     }};
@@ -262,16 +290,21 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::ConvTranspose1d(_) => "conv_transpose1d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
+            Node::CumSum(_) => "cumsum",
             Node::DepthToSpace(_) => "depth_to_space",
             Node::PRelu(_) => "prelu",
             Node::Dropout(_) => "dropout",
+            Node::Dft(_) => "dft",
+            Node::Einsum(_) => "einsum",
             Node::Expand(_) => "expand",
             Node::Floor(_) => "floor",
             Node::Ceil(_) => "ceil",
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
+            Node::GatherNd(_) => "gather_nd",
             Node::Gemm(_) => "gemm",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GridSample(_) => "grid_sample",
             Node::InstanceNorm(_) => "instance_norm",
             Node::LayerNorm(_) => "layer_norm",
             Node::GroupNorm(_) => "group_norm",
@@ -280,12 +313,16 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Mean(_) => "mean",
+            Node::MelWeightMatrix(_) => "mel_weight_matrix",
+            Node::Multinomial(_) => "multinomial",
             Node::OneHot(_) => "one_hot",
             Node::Pad(_) => "pad",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
             Node::Round(_) => "round",
+            Node::ScatterElements(_) => "scatter_elements",
+            Node::ScatterNd(_) => "scatter_nd",
             Node::Slice(_) => "slice",
             Node::SpaceToDepth(_) => "space_to_depth",
             Node::Squeeze(_) => "squeeze",
@@ -294,14 +331,17 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::TopK(_) => "top_k",
             Node::Trilu(_) => "trilu",
             Node::Unary(unary) => unary.kind.as_str(),
+            Node::Unique(_) => "unique",
             Node::Unsqueeze(_) => "unsqueeze",
             Node::Where(_) => "where",
+            Node::Window(_) => "window",
             Node::RandomNormal(_) => "random_normal",
             Node::RandomNormalLike(_) => "random_normal_like",
             Node::RandomUniform(_) => "random_uniform",
             Node::RandomUniformLike(_) => "random_uniform_like",
             Node::ConstantOfShape(_) => "constant_of_shape",
             Node::Split(_) => "split",
+            Node::Stft(_) => "stft",
             _ => unimplemented!(),
         }
     }
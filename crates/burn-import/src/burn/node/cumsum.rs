@@ -0,0 +1,132 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::cumsum::CumSumConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct CumSumNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: CumSumConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CumSumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+
+        // A reverse sum is computed on the flipped axis.
+        let flip = |tensor: TokenStream| match self.config.reverse {
+            true => quote! { #tensor.flip([#axis]) },
+            false => tensor,
+        };
+
+        let input = flip(input);
+        // An exclusive sum removes each element from its inclusive sum.
+        let sum = match self.config.exclusive {
+            true => quote! {
+                {
+                    let input = #input;
+                    input.clone().cumsum(#axis) - input
+                }
+            },
+            false => quote! { #input.cumsum(#axis) },
+        };
+        let sum = flip(sum);
+
+        quote! {
+            let #output = #sum;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::CumSum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{cumsum::CumSumNode, test::assert_tokens},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    fn codegen(config: CumSumConfig) -> TokenStream {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(CumSumNode::new(
+            TensorType::new_float("input", 2),
+            TensorType::new_float("output", 2),
+            config,
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        graph.codegen()
+    }
+
+    fn expected(body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+                    #body
+                    output
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_cumsum() {
+        let tokens = codegen(CumSumConfig::new(1, false, false));
+
+        assert_tokens(tokens, expected(quote! { let output = input.cumsum(1); }));
+    }
+
+    #[test]
+    fn test_codegen_cumsum_exclusive_reverse() {
+        let tokens = codegen(CumSumConfig::new(0, true, true));
+
+        assert_tokens(
+            tokens,
+            expected(quote! {
+                let output = {
+                    let input = input.flip([0]);
+                    input.clone().cumsum(0) - input
+                }
+                .flip([0]);
+            }),
+        );
+    }
+}
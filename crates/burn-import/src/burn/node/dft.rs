@@ -0,0 +1,141 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::dft::DftConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone)]
+pub struct DftNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: DftConfig,
+}
+
+impl DftNode {
+    pub fn new(input: TensorType, output: TensorType, config: DftConfig) -> Self {
+        if input.kind != TensorKind::Float {
+            panic!("DFT is only implemented for float tensors");
+        }
+        Self {
+            input,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DftNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+        // The real and imaginary parts are stored along the last dimension, and real signals
+        // only have the real part.
+        let parts = (self.input.rank - 1).to_tokens();
+
+        let transform = match self.config.inverse {
+            true => quote! { burn::tensor::signal::ifft },
+            false => quote! { burn::tensor::signal::fft },
+        };
+        let onesided = match self.config.onesided {
+            true => quote! {
+                let size = real.dims()[#axis] / 2 + 1;
+                let (real, imag) = (real.narrow(#axis, 0, size), imag.narrow(#axis, 0, size));
+            },
+            false => quote! {},
+        };
+
+        quote! {
+            let #output = {
+                let input = #input;
+                let real = input.clone().narrow(#parts, 0, 1);
+                let imag = match input.dims()[#parts] {
+                    2 => input.narrow(#parts, 1, 1),
+                    _ => real.zeros_like(),
+                };
+                let (real, imag) = #transform(real, imag, #axis);
+                #onesided
+                burn::tensor::Tensor::cat([real, imag].into(), #parts)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Dft(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{dft::DftNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_dft() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DftNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            DftConfig::new(1, false, true),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let real = input.clone().narrow(2, 0, 1);
+                        let imag = match input.dims()[2] {
+                            2 => input.narrow(2, 1, 1),
+                            _ => real.zeros_like(),
+                        };
+                        let (real, imag) = burn::tensor::signal::fft(real, imag, 1);
+                        let size = real.dims()[1] / 2 + 1;
+                        let (real, imag) = (real.narrow(1, 0, size), imag.narrow(1, 0, size));
+                        burn::tensor::Tensor::cat([real, imag].into(), 2)
+                    };
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
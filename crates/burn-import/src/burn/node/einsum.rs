@@ -0,0 +1,229 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::einsum::EinsumConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone)]
+pub struct EinsumNode {
+    pub inputs: Vec<TensorType>,
+    pub output: TensorType,
+    pub config: EinsumConfig,
+}
+
+impl EinsumNode {
+    pub fn new(inputs: Vec<TensorType>, output: TensorType, config: EinsumConfig) -> Self {
+        if inputs.iter().any(|input| input.kind != TensorKind::Float) {
+            panic!("Einsum is only implemented for float tensors");
+        }
+        Self {
+            inputs,
+            output,
+            config,
+        }
+    }
+
+    /// The rank shared by all inputs once unsqueezed.
+    fn rank(&self) -> usize {
+        self.inputs.iter().map(|input| input.rank).max().unwrap()
+    }
+
+    /// Returns an explicit equation for the inputs unsqueezed to the same rank.
+    ///
+    /// The leading dimensions added to the lower-rank inputs are labeled with unused subscripts
+    /// that don't appear in the output, so they are summed away.
+    fn equation(&self) -> String {
+        let equation = &self.config.equation;
+        let (lhs, output) = match equation.split_once("->") {
+            Some((lhs, rhs)) => (lhs, rhs.to_string()),
+            None => {
+                // Implicit output: the ellipsis dimensions followed by the subscripts appearing
+                // exactly once, in alphabetical order.
+                let labels = equation
+                    .chars()
+                    .filter(char::is_ascii_alphabetic)
+                    .collect::<Vec<_>>();
+                let mut unique = labels
+                    .iter()
+                    .copied()
+                    .filter(|label| labels.iter().filter(|l| *l == label).count() == 1)
+                    .collect::<Vec<_>>();
+                unique.sort_unstable();
+
+                let ellipsis = if equation.contains("...") { "..." } else { "" };
+                let unique = unique.into_iter().collect::<String>();
+                (equation.as_str(), format!("{ellipsis}{unique}"))
+            }
+        };
+
+        let rank = self.rank();
+        let mut unused = ('a'..='z')
+            .chain('A'..='Z')
+            .filter(|label| !equation.contains(*label));
+
+        let terms = lhs
+            .split(',')
+            .zip(&self.inputs)
+            .map(|(term, input)| {
+                let prefix = (input.rank..rank)
+                    .map(|_| unused.next().expect("Einsum: no unused subscript left"))
+                    .collect::<String>();
+                format!("{prefix}{term}")
+            })
+            .collect::<Vec<_>>();
+
+        format!("{}->{output}", terms.join(","))
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for EinsumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|input| Type::Tensor(input.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let rank = self.rank();
+        let equation = self.equation();
+        let output = &self.output.name;
+        let output_rank = self.output.rank.to_tokens();
+
+        let inputs = self.inputs.iter().map(|input| {
+            let tensor = scope.tensor_use_owned(input, node_position);
+            if input.rank < rank {
+                let rank = rank.to_tokens();
+                quote! { #tensor.unsqueeze::<#rank>() }
+            } else {
+                tensor
+            }
+        });
+
+        quote! {
+            let #output = Tensor::einsum::<#output_rank>(#equation, &[#(#inputs),*]);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Einsum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{einsum::EinsumNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_einsum() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![
+                TensorType::new_float("tensor1", 4),
+                TensorType::new_float("tensor2", 4),
+            ],
+            TensorType::new_float("tensor3", 4),
+            EinsumConfig::new("bhqd,bhkd->bhqk".to_string()),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor3 = Tensor::einsum::<4>("bhqd,bhkd->bhqk", &[tensor1, tensor2]);
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_einsum_broadcast_rank() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![
+                TensorType::new_float("tensor1", 3),
+                TensorType::new_float("tensor2", 2),
+            ],
+            TensorType::new_float("tensor3", 3),
+            EinsumConfig::new("bij,jk".to_string()),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, tensor2: Tensor<B, 2>) -> Tensor<B, 3> {
+                    let tensor3 = Tensor::einsum::<3>(
+                        "bij,ajk->bik",
+                        &[tensor1, tensor2.unsqueeze::<3>()]
+                    );
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
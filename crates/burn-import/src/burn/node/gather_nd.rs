@@ -0,0 +1,98 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX `GatherND` operation, which gathers the slices addressed by index tuples.
+#[derive(Debug, Clone, new)]
+pub struct GatherNdNode {
+    pub input: TensorType,
+    pub indices: TensorType,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNdNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.indices.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let output = &self.output.name;
+        let indices_rank = self.indices.rank;
+        let output_rank = self.output.rank;
+
+        quote! {
+            let #output = #input.gather_nd::<#indices_rank, #output_rank>(#indices);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GatherNd(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{gather_nd::GatherNdNode, test::assert_tokens},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_gather_nd() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(GatherNdNode::new(
+            TensorType::new_float("input", 3),
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("output", 2),
+        ));
+        graph.register_input_output(
+            vec!["input".to_string(), "indices".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>, indices: Tensor<B, 2, Int>) -> Tensor<B, 2> {
+                    let output = input.gather_nd::<2usize, 2usize>(indices);
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
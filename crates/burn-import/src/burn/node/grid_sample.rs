@@ -0,0 +1,142 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::grid_sample::{GridSampleConfig, GridSampleMode, GridSamplePaddingMode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX `GridSample` operation, which samples the input at the grid locations.
+#[derive(Debug, Clone, new)]
+pub struct GridSampleNode {
+    pub input: TensorType,
+    pub grid: TensorType,
+    pub output: TensorType,
+    pub config: GridSampleConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GridSampleNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.grid.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let grid = scope.tensor_use_owned(&self.grid, node_position);
+        let output = &self.output.name;
+
+        let mode = match self.config.mode {
+            GridSampleMode::Nearest => quote! { GridSampleMode::Nearest },
+            GridSampleMode::Linear => quote! { GridSampleMode::Bilinear },
+            GridSampleMode::Cubic => quote! { GridSampleMode::Bicubic },
+        };
+        let padding_mode = match self.config.padding_mode {
+            GridSamplePaddingMode::Zeros => quote! { GridSamplePaddingMode::Zeros },
+            GridSamplePaddingMode::Border => quote! { GridSamplePaddingMode::Border },
+            GridSamplePaddingMode::Reflection => quote! { GridSamplePaddingMode::Reflection },
+        };
+        let align_corners = self.config.align_corners;
+
+        let function = match self.input.rank {
+            4 => quote! { grid_sample_2d },
+            5 => quote! { grid_sample_3d },
+            rank => panic!("GridSample: unsupported input rank {rank}"),
+        };
+
+        quote! {
+            let #output = #function(
+                #input,
+                #grid,
+                GridSampleOptions::new(#mode, #padding_mode, #align_corners),
+            );
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.input.rank {
+            4 => imports.register("burn::tensor::module::grid_sample_2d"),
+            _ => imports.register("burn::tensor::module::grid_sample_3d"),
+        }
+        imports.register("burn::tensor::ops::GridSampleMode");
+        imports.register("burn::tensor::ops::GridSampleOptions");
+        imports.register("burn::tensor::ops::GridSamplePaddingMode");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GridSample(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{grid_sample::GridSampleNode, test::assert_tokens},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_grid_sample() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(GridSampleNode::new(
+            TensorType::new_float("input", 4),
+            TensorType::new_float("grid", 4),
+            TensorType::new_float("output", 4),
+            GridSampleConfig::new(GridSampleMode::Cubic, GridSamplePaddingMode::Border, true),
+        ));
+        graph.register_input_output(
+            vec!["input".to_string(), "grid".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::module::grid_sample_2d;
+            use burn::tensor::ops::GridSampleMode;
+            use burn::tensor::ops::GridSampleOptions;
+            use burn::tensor::ops::GridSamplePaddingMode;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>, grid: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = grid_sample_2d(
+                        input,
+                        grid,
+                        GridSampleOptions::new(
+                            GridSampleMode::Bicubic,
+                            GridSamplePaddingMode::Border,
+                            true
+                        ),
+                    );
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
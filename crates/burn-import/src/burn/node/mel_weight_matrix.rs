@@ -0,0 +1,107 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::mel_weight_matrix::MelWeightMatrixConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct MelWeightMatrixNode {
+    pub output: TensorType,
+    pub config: MelWeightMatrixConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for MelWeightMatrixNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        Vec::with_capacity(0)
+    }
+
+    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+        let num_mel_bins = self.config.num_mel_bins.to_tokens();
+        let dft_length = self.config.dft_length.to_tokens();
+        let sample_rate = self.config.sample_rate.to_tokens();
+        let lower_edge_hertz = self.config.lower_edge_hertz.to_tokens();
+        let upper_edge_hertz = self.config.upper_edge_hertz.to_tokens();
+
+        quote! {
+            let #output = burn::tensor::signal::mel_weight_matrix(
+                #num_mel_bins,
+                #dft_length,
+                #sample_rate,
+                #lower_edge_hertz,
+                #upper_edge_hertz,
+                &*self.device,
+            );
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::MelWeightMatrix(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{mel_weight_matrix::MelWeightMatrixNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_mel_weight_matrix() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MelWeightMatrixNode::new(
+            TensorType::new_float("tensor1", 2),
+            MelWeightMatrixConfig::new(8, 16, 8000, 0.0, 4000.0),
+        ));
+
+        graph.register_input_output(vec![], vec!["tensor1".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self) -> Tensor<B, 2> {
+                    let tensor1 = burn::tensor::signal::mel_weight_matrix(
+                        8,
+                        16,
+                        8000,
+                        0.0,
+                        4000.0,
+                        &*self.device,
+                    );
+                    tensor1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
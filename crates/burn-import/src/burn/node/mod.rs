@@ -24,14 +24,19 @@ pub(crate) mod conv3d;
 pub(crate) mod conv_transpose_1d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod cumsum;
 pub(crate) mod depth_to_space;
+pub(crate) mod dft;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
 pub(crate) mod expand;
 pub(crate) mod floor;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
+pub(crate) mod gather_nd;
 pub(crate) mod gemm;
 pub(crate) mod global_avg_pool;
+pub(crate) mod grid_sample;
 pub(crate) mod group_norm;
 pub(crate) mod instance_norm;
 pub(crate) mod layer_norm;
@@ -41,6 +46,8 @@ pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
 pub(crate) mod mean;
+pub(crate) mod mel_weight_matrix;
+pub(crate) mod multinomial;
 pub(crate) mod one_hot;
 pub(crate) mod pad;
 pub(crate) mod prelu;
@@ -52,16 +59,21 @@ pub(crate) mod range;
 pub(crate) mod reshape;
 pub(crate) mod resize;
 pub(crate) mod round;
+pub(crate) mod scatter_elements;
+pub(crate) mod scatter_nd;
 pub(crate) mod slice;
 pub(crate) mod space_to_depth;
 pub(crate) mod split;
 pub(crate) mod squeeze;
+pub(crate) mod stft;
 pub(crate) mod sum;
 pub(crate) mod tile;
 pub(crate) mod top_k;
 pub(crate) mod trilu;
 pub(crate) mod unary;
+pub(crate) mod unique;
 pub(crate) mod unsqueeze;
+pub(crate) mod window;
 pub(crate) use base::*;
 
 #[cfg(test)]
@@ -0,0 +1,92 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::multinomial::MultinomialConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX `Multinomial` operation, which samples class indices with replacement from
+/// unnormalized log-probabilities.
+#[derive(Debug, Clone, new)]
+pub struct MultinomialNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: MultinomialConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for MultinomialNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let sample_size = self.config.sample_size.to_tokens();
+
+        quote! {
+            let #output = burn::tensor::activation::softmax(#input, 1).multinomial(#sample_size, true);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Multinomial(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{multinomial::MultinomialNode, test::assert_tokens},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_multinomial() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(MultinomialNode::new(
+            TensorType::new_float("input", 2),
+            TensorType::new_int("output", 2),
+            MultinomialConfig { sample_size: 4 },
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2, Int> {
+                    let output = burn::tensor::activation::softmax(input, 1).multinomial(4, true);
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
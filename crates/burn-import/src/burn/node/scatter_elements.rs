@@ -0,0 +1,161 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::{scatter_elements::ScatterElementsConfig, scatter_nd::ScatterReduction};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX `ScatterElements` operation, which updates the elements at the indices along
+/// an axis.
+#[derive(Debug, Clone, new)]
+pub struct ScatterElementsNode {
+    pub input: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub config: ScatterElementsConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterElementsNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+
+        match self.config.reduction {
+            ScatterReduction::Add => quote! {
+                let #output = #input.scatter(#axis, #indices, #updates);
+            },
+            // The scatter sums the updates, so the updated elements are replaced using the mask
+            // of the positions hit by the indices.
+            ScatterReduction::None => quote! {
+                let #output = {
+                    let input = #input;
+                    let indices = #indices;
+                    let updates = #updates;
+                    let mask = input
+                        .zeros_like()
+                        .scatter(#axis, indices.clone(), updates.ones_like())
+                        .greater_elem(0);
+                    let values = input.zeros_like().scatter(#axis, indices, updates);
+
+                    input.mask_where(mask, values)
+                };
+            },
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterElements(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{scatter_elements::ScatterElementsNode, test::assert_tokens},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    fn graph(reduction: ScatterReduction) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(ScatterElementsNode::new(
+            TensorType::new_float("input", 2),
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("updates", 2),
+            TensorType::new_float("output", 2),
+            ScatterElementsConfig::new(1, reduction),
+        ));
+        graph.register_input_output(
+            vec![
+                "input".to_string(),
+                "indices".to_string(),
+                "updates".to_string(),
+            ],
+            vec!["output".to_string()],
+        );
+        graph
+    }
+
+    fn expected(body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 2>,
+                    indices: Tensor<B, 2, Int>,
+                    updates: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    #body
+                    output
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements_add() {
+        let expected = expected(quote! {
+            let output = input.scatter(1, indices, updates);
+        });
+
+        assert_tokens(graph(ScatterReduction::Add).codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements() {
+        let expected = expected(quote! {
+            let output = {
+                let input = input;
+                let indices = indices;
+                let updates = updates;
+                let mask = input
+                    .zeros_like()
+                    .scatter(1, indices.clone(), updates.ones_like())
+                    .greater_elem(0);
+                let values = input.zeros_like().scatter(1, indices, updates);
+
+                input.mask_where(mask, values)
+            };
+        });
+
+        assert_tokens(graph(ScatterReduction::None).codegen(), expected);
+    }
+}
@@ -0,0 +1,170 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::stft::StftConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone)]
+pub struct StftNode {
+    pub signal: TensorType,
+    pub window: Option<TensorType>,
+    pub output: TensorType,
+    pub config: StftConfig,
+}
+
+impl StftNode {
+    pub fn new(
+        signal: TensorType,
+        window: Option<TensorType>,
+        output: TensorType,
+        config: StftConfig,
+    ) -> Self {
+        if signal.kind != TensorKind::Float {
+            panic!("STFT is only implemented for float tensors");
+        }
+        Self {
+            signal,
+            window,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for StftNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.signal.clone())];
+        if let Some(window) = &self.window {
+            inputs.push(Type::Tensor(window.clone()));
+        }
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let signal = scope.tensor_use_owned(&self.signal, node_position);
+        let output = &self.output.name;
+        let frame_step = self.config.frame_step.to_tokens();
+        let onesided = self.config.onesided;
+
+        let (window, window_arg, frame_length) = match &self.window {
+            Some(window) => {
+                let window = scope.tensor_use_owned(window, node_position);
+                let frame_length = match self.config.frame_length {
+                    Some(frame_length) => frame_length.to_tokens(),
+                    None => quote! { window.dims()[0] },
+                };
+                (
+                    quote! { let window = #window; },
+                    quote! { Some(window) },
+                    frame_length,
+                )
+            }
+            None => (
+                quote! {},
+                quote! { None },
+                self.config.frame_length.unwrap().to_tokens(),
+            ),
+        };
+
+        // Only real signals of shape `[batch_size, signal_length, 1]` are supported.
+        quote! {
+            let #output = {
+                #window
+                let options = burn::tensor::signal::StftOptions::new(
+                    #frame_length,
+                    #frame_step,
+                    false,
+                    #onesided,
+                );
+                let (real, imag) =
+                    burn::tensor::signal::stft(#signal.squeeze::<2>(2), #window_arg, options);
+                burn::tensor::Tensor::stack::<4>(
+                    [real.swap_dims(1, 2), imag.swap_dims(1, 2)].into(),
+                    3,
+                )
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Stft(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{stft::StftNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_stft() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(StftNode::new(
+            TensorType::new_float("tensor1", 3),
+            Some(TensorType::new_float("tensor2", 1)),
+            TensorType::new_float("tensor3", 4),
+            StftConfig::new(4, None, true),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, tensor2: Tensor<B, 1>) -> Tensor<B, 4> {
+                    let tensor3 = {
+                        let window = tensor2;
+                        let options = burn::tensor::signal::StftOptions::new(
+                            window.dims()[0],
+                            4,
+                            false,
+                            true,
+                        );
+                        let (real, imag) =
+                            burn::tensor::signal::stft(tensor1.squeeze::<2>(2), Some(window), options);
+                        burn::tensor::Tensor::stack::<4>(
+                            [real.swap_dims(1, 2), imag.swap_dims(1, 2)].into(),
+                            3,
+                        )
+                    };
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -22,9 +22,16 @@ pub struct UnaryNode {
 #[derive(Clone)]
 pub enum UnaryNodeKind {
     // Input and output tensor types (required for codegen imports)
+    Acos,
+    Acosh,
+    Asin,
+    Asinh,
+    Atan,
+    Atanh,
     Cast(Option<TensorKind>, Option<TensorKind>),
     Cos,
     Cosh,
+    Det,
     Erf,
     Exp,
     Flatten,
@@ -37,11 +44,16 @@ pub enum UnaryNodeKind {
     LogSoftmax,
     Neg,
     Not,
+    ReduceL1,
+    ReduceL2,
+    ReduceLogSum,
+    ReduceLogSumExp,
     ReduceMax,
     ReduceMin,
     ReduceMean,
     ReduceProd,
     ReduceSum,
+    ReduceSumSquare,
     Reciprocal,
     Relu,
     Shape,
@@ -60,9 +72,16 @@ pub enum UnaryNodeKind {
 impl UnaryNodeKind {
     pub fn as_str(&self) -> &str {
         match self {
+            Self::Acos => "acos",
+            Self::Acosh => "acosh",
+            Self::Asin => "asin",
+            Self::Asinh => "asinh",
+            Self::Atan => "atan",
+            Self::Atanh => "atanh",
             Self::Cast(..) => "cast",
             Self::Cos => "cos",
             Self::Cosh => "cosh",
+            Self::Det => "det",
             Self::Erf => "erf",
             Self::Exp => "exp",
             Self::Flatten => "flatten",
@@ -75,11 +94,16 @@ impl UnaryNodeKind {
             Self::LogSoftmax => "log_softmax",
             Self::Neg => "neg",
             Self::Not => "not",
+            Self::ReduceL1 => "reduce_l1",
+            Self::ReduceL2 => "reduce_l2",
+            Self::ReduceLogSum => "reduce_log_sum",
+            Self::ReduceLogSumExp => "reduce_log_sum_exp",
             Self::ReduceMax => "reduce_max",
             Self::ReduceMin => "reduce_min",
             Self::ReduceMean => "reduce_mean",
             Self::ReduceProd => "reduce_prod",
             Self::ReduceSum => "reduce_sum",
+            Self::ReduceSumSquare => "reduce_sum_square",
             Self::Reciprocal => "reciprocal",
             Self::Relu => "relu",
             Self::Shape => "shape",
@@ -181,6 +205,24 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
 }
 
 impl UnaryNode {
+    pub(crate) fn det(input: Type, output: Type) -> Self {
+        let (input_rank, output_rank) = match (&input, &output) {
+            (Type::Tensor(input), Type::Tensor(output)) => (input.rank, output.rank),
+            _ => panic!("Det only supports tensor input and output"),
+        };
+
+        // The determinants keep the matrix dimensions, which are removed from the output.
+        let function = move |input| {
+            if input_rank == 2 {
+                quote! { burn::tensor::linalg::det(#input).reshape([1]) }
+            } else {
+                let output_rank = output_rank.to_tokens();
+                quote! { burn::tensor::linalg::det(#input).squeeze_dims::<#output_rank>(&[-2, -1]) }
+            }
+        };
+        Self::new(input, output, UnaryNodeKind::Det, Rc::new(function))
+    }
+
     pub(crate) fn erf(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.erf() };
         Self::new(input, output, UnaryNodeKind::Erf, Rc::new(function))
@@ -266,6 +308,36 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Reciprocal, Rc::new(function))
     }
 
+    pub(crate) fn acos(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.acos()};
+        Self::new(input, output, UnaryNodeKind::Acos, Rc::new(function))
+    }
+
+    pub(crate) fn acosh(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.acosh()};
+        Self::new(input, output, UnaryNodeKind::Acosh, Rc::new(function))
+    }
+
+    pub(crate) fn asin(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.asin()};
+        Self::new(input, output, UnaryNodeKind::Asin, Rc::new(function))
+    }
+
+    pub(crate) fn asinh(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.asinh()};
+        Self::new(input, output, UnaryNodeKind::Asinh, Rc::new(function))
+    }
+
+    pub(crate) fn atan(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.atan()};
+        Self::new(input, output, UnaryNodeKind::Atan, Rc::new(function))
+    }
+
+    pub(crate) fn atanh(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.atanh()};
+        Self::new(input, output, UnaryNodeKind::Atanh, Rc::new(function))
+    }
+
     pub(crate) fn cos(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.cos()};
         Self::new(input, output, UnaryNodeKind::Cos, Rc::new(function))
@@ -509,6 +581,151 @@ impl UnaryNode {
         }
     }
 
+    pub(crate) fn reduce_l1(input: Type, output: Type, dim: Option<usize>) -> Self {
+        if let Type::Tensor(ref tensor) = output {
+            if tensor.kind == TensorKind::Bool {
+                panic!("ReduceL1 is not supported for boolean");
+            }
+
+            if let Some(dim) = dim {
+                // ReduceL1, keepdims=1, axes=[dim]
+                let dim = dim.to_tokens();
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceL1,
+                    Rc::new(move |input| quote! { #input.abs().sum_dim(#dim) }),
+                )
+            } else {
+                // ReduceL1, keepdims=0, axes=None
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceL1,
+                    Rc::new(move |input| quote! { #input.abs().sum() }),
+                )
+            }
+        } else {
+            panic!("ReduceL1 only supports tensor output");
+        }
+    }
+
+    pub(crate) fn reduce_l2(input: Type, output: Type, dim: Option<usize>) -> Self {
+        if let Type::Tensor(ref tensor) = output {
+            if tensor.kind != TensorKind::Float {
+                panic!("ReduceL2 is only supported for float tensors");
+            }
+
+            if let Some(dim) = dim {
+                // ReduceL2, keepdims=1, axes=[dim]
+                let dim = dim.to_tokens();
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceL2,
+                    Rc::new(move |input| quote! { #input.powi_scalar(2).sum_dim(#dim).sqrt() }),
+                )
+            } else {
+                // ReduceL2, keepdims=0, axes=None
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceL2,
+                    Rc::new(move |input| quote! { #input.powi_scalar(2).sum().sqrt() }),
+                )
+            }
+        } else {
+            panic!("ReduceL2 only supports tensor output");
+        }
+    }
+
+    pub(crate) fn reduce_log_sum(input: Type, output: Type, dim: Option<usize>) -> Self {
+        if let Type::Tensor(ref tensor) = output {
+            if tensor.kind != TensorKind::Float {
+                panic!("ReduceLogSum is only supported for float tensors");
+            }
+
+            if let Some(dim) = dim {
+                // ReduceLogSum, keepdims=1, axes=[dim]
+                let dim = dim.to_tokens();
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceLogSum,
+                    Rc::new(move |input| quote! { #input.sum_dim(#dim).log() }),
+                )
+            } else {
+                // ReduceLogSum, keepdims=0, axes=None
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceLogSum,
+                    Rc::new(move |input| quote! { #input.sum().log() }),
+                )
+            }
+        } else {
+            panic!("ReduceLogSum only supports tensor output");
+        }
+    }
+
+    pub(crate) fn reduce_log_sum_exp(input: Type, output: Type, dim: Option<usize>) -> Self {
+        if let Type::Tensor(ref tensor) = output {
+            if tensor.kind != TensorKind::Float {
+                panic!("ReduceLogSumExp is only supported for float tensors");
+            }
+
+            if let Some(dim) = dim {
+                // ReduceLogSumExp, keepdims=1, axes=[dim]
+                let dim = dim.to_tokens();
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceLogSumExp,
+                    Rc::new(move |input| quote! { #input.logsumexp(#dim) }),
+                )
+            } else {
+                // ReduceLogSumExp, keepdims=0, axes=None
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceLogSumExp,
+                    Rc::new(move |input| quote! { #input.reshape([-1]).logsumexp(0) }),
+                )
+            }
+        } else {
+            panic!("ReduceLogSumExp only supports tensor output");
+        }
+    }
+
+    pub(crate) fn reduce_sum_square(input: Type, output: Type, dim: Option<usize>) -> Self {
+        if let Type::Tensor(ref tensor) = output {
+            if tensor.kind == TensorKind::Bool {
+                panic!("ReduceSumSquare is not supported for boolean");
+            }
+
+            if let Some(dim) = dim {
+                // ReduceSumSquare, keepdims=1, axes=[dim]
+                let dim = dim.to_tokens();
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceSumSquare,
+                    Rc::new(move |input| quote! { #input.powi_scalar(2).sum_dim(#dim) }),
+                )
+            } else {
+                // ReduceSumSquare, keepdims=0, axes=None
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::ReduceSumSquare,
+                    Rc::new(move |input| quote! { #input.powi_scalar(2).sum() }),
+                )
+            }
+        } else {
+            panic!("ReduceSumSquare only supports tensor output");
+        }
+    }
+
     pub(crate) fn shape(input: Type, output: Type, start_dim: usize, end_dim: usize) -> Self {
         let start_dim = start_dim.to_tokens();
         let end_dim = end_dim.to_tokens();
@@ -593,6 +810,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_det() {
+        one_node_graph(
+            UnaryNode::det(
+                Type::Tensor(TensorType::new_float("tensor1", 3)),
+                Type::Tensor(TensorType::new_float("tensor2", 1)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 1> {
+                    let tensor2 = burn::tensor::linalg::det(tensor1).squeeze_dims::<1>(&[-2, -1]);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_det_matrix() {
+        one_node_graph(
+            UnaryNode::det(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Tensor(TensorType::new_float("tensor2", 1)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 1> {
+                    let tensor2 = burn::tensor::linalg::det(tensor1).reshape([1]);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_erf() {
         one_node_graph(
@@ -974,6 +1229,123 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_reduce_log_sum_exp() {
+        one_node_graph(
+            UnaryNode::reduce_log_sum_exp(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                Some(2),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.logsumexp(2);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        one_node_graph(
+            UnaryNode::reduce_log_sum_exp(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Tensor(TensorType::new_float("tensor2", 1)),
+                None,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 1> {
+                    let tensor2 = tensor1.reshape([-1]).logsumexp(0);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_log_sum() {
+        one_node_graph(
+            UnaryNode::reduce_log_sum(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                Some(1),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.sum_dim(1).log();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_sum_square() {
+        one_node_graph(
+            UnaryNode::reduce_sum_square(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                Some(1),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.powi_scalar(2).sum_dim(1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_l1() {
+        one_node_graph(
+            UnaryNode::reduce_l1(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 1)),
+                None,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 1> {
+                    let tensor2 = tensor1.abs().sum();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_l2() {
+        one_node_graph(
+            UnaryNode::reduce_l2(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                Some(3),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.powi_scalar(2).sum_dim(3).sqrt();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_reciprocal() {
         one_node_graph(
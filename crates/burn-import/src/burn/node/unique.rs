@@ -0,0 +1,158 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX `Unique` operation on the flattened input.
+///
+/// The outputs are, in order, the sorted unique elements and the optional first occurrence
+/// indices, inverse indices and counts.
+#[derive(Debug, Clone, new)]
+pub struct UniqueNode {
+    pub input: TensorType,
+    pub outputs: Vec<TensorType>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for UniqueNode {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs
+            .iter()
+            .map(|t| Type::Tensor(t.clone()))
+            .collect()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let names = self.outputs.iter().map(|t| &t.name).collect::<Vec<_>>();
+
+        if let [unique] = names[..] {
+            return quote! {
+                let #unique = #input.unique();
+            };
+        }
+
+        let results = [
+            quote! { unique },
+            quote! { indices },
+            quote! { inverse },
+            quote! { counts },
+        ];
+        let results = &results[..names.len()];
+
+        // The first occurrence of each unique element is its smallest position, which comes
+        // first when sorting the positions by unique element.
+        quote! {
+            let (#(#names),*) = {
+                let (unique, inverse, counts) = #input.unique_with_inverse_and_counts();
+                let inverse = inverse.reshape([-1]);
+                let num_elements = inverse.dims()[0] as i64;
+                let positions = Tensor::<B, 1, Int>::arange(0..num_elements, &*self.device);
+                let starts = counts.clone().cumsum(0).sub(counts.clone());
+                let indices = inverse
+                    .clone()
+                    .mul_scalar(num_elements)
+                    .add(positions)
+                    .sort(0)
+                    .select(0, starts)
+                    .remainder_scalar(num_elements);
+
+                (#(#results),*)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Unique(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{test::assert_tokens, unique::UniqueNode},
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UniqueNode::new(
+            TensorType::new_float("input_tensor", 2),
+            vec![
+                TensorType::new_float("unique_tensor", 1),
+                TensorType::new_int("indices_tensor", 1),
+                TensorType::new_int("inverse_tensor", 1),
+                TensorType::new_int("counts_tensor", 1),
+            ],
+        ));
+
+        graph.register_input_output(
+            vec!["input_tensor".to_string()],
+            vec![
+                "unique_tensor".to_string(),
+                "indices_tensor".to_string(),
+                "inverse_tensor".to_string(),
+                "counts_tensor".to_string(),
+            ],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input_tensor: Tensor<B, 2>
+                ) -> (Tensor<B, 1>, Tensor<B, 1, Int>, Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+                    let (unique_tensor, indices_tensor, inverse_tensor, counts_tensor) = {
+                        let (unique, inverse, counts) = input_tensor.unique_with_inverse_and_counts();
+                        let inverse = inverse.reshape([-1]);
+                        let num_elements = inverse.dims()[0] as i64;
+                        let positions = Tensor::<B, 1, Int>::arange(0..num_elements, &*self.device);
+                        let starts = counts.clone().cumsum(0).sub(counts.clone());
+                        let indices = inverse
+                            .clone()
+                            .mul_scalar(num_elements)
+                            .add(positions)
+                            .sort(0)
+                            .select(0, starts)
+                            .remainder_scalar(num_elements);
+
+                        (unique, indices, inverse, counts)
+                    };
+                    (unique_tensor, indices_tensor, inverse_tensor, counts_tensor)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
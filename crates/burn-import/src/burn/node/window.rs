@@ -0,0 +1,131 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use onnx_ir::node::window::WindowConfig;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The window functions generated by the ONNX window operators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowKind {
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+#[derive(Debug, Clone)]
+pub struct WindowNode {
+    pub output: TensorType,
+    pub kind: WindowKind,
+    pub config: WindowConfig,
+}
+
+impl WindowNode {
+    pub fn new(output: TensorType, kind: WindowKind, config: WindowConfig) -> Self {
+        Self {
+            output,
+            kind,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for WindowNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        Vec::with_capacity(0)
+    }
+
+    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+        let size = self.config.size.to_tokens();
+        let periodic = self.config.periodic;
+
+        let window = match self.kind {
+            WindowKind::Hann => quote! {
+                burn::tensor::signal::hann_window(#size, #periodic, &*self.device)
+            },
+            // ONNX uses the exact coefficients 25/46 and 21/46 rather than 0.54 and 0.46.
+            WindowKind::Hamming => {
+                let coefficients = [25.0 / 46.0, 21.0 / 46.0].map(|c: f64| c.to_tokens());
+                quote! {
+                    burn::tensor::signal::cosine_window(
+                        #size,
+                        #periodic,
+                        &[#(#coefficients),*],
+                        &*self.device,
+                    )
+                }
+            }
+            WindowKind::Blackman => quote! {
+                burn::tensor::signal::blackman_window(#size, #periodic, &*self.device)
+            },
+        };
+
+        quote! {
+            let #output = #window;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Window(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{test::assert_tokens, window::WindowNode},
+    };
+
+    #[test]
+    fn test_codegen_hann_window() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(WindowNode::new(
+            TensorType::new_float("tensor1", 1),
+            WindowKind::Hann,
+            WindowConfig::new(16, true),
+        ));
+
+        graph.register_input_output(vec![], vec!["tensor1".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self) -> Tensor<B, 1> {
+                    let tensor1 = burn::tensor::signal::hann_window(16, true, &*self.device);
+                    tensor1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -40,14 +40,19 @@ use crate::{
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv3d::Conv3dNode,
+            cumsum::CumSumNode,
             depth_to_space::DepthToSpaceNode,
+            dft::DftNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
             expand::ExpandNode,
             floor::FloorNode,
             gather::GatherNode,
             gather_elements::GatherElementsNode,
+            gather_nd::GatherNdNode,
             gemm::GemmNode,
             global_avg_pool::GlobalAvgPoolNode,
+            grid_sample::GridSampleNode,
             group_norm::GroupNormNode,
             instance_norm::InstanceNormNode,
             layer_norm::LayerNormNode,
@@ -56,6 +61,8 @@ use crate::{
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
+            mel_weight_matrix::MelWeightMatrixNode,
+            multinomial::MultinomialNode,
             one_hot::OneHotNode,
             pad::PadNode,
             prelu::PReluNode,
@@ -67,16 +74,21 @@ use crate::{
             reshape::ReshapeNode,
             resize::ResizeNode,
             round::RoundNode,
+            scatter_elements::ScatterElementsNode,
+            scatter_nd::ScatterNdNode,
             slice::SliceNode,
             space_to_depth::SpaceToDepthNode,
             split::SplitNode,
             squeeze::SqueezeNode,
+            stft::StftNode,
             sum::SumNode,
             tile::TileNode,
             top_k::TopKNode,
             trilu::TriluNode,
             unary::UnaryNode,
+            unique::UniqueNode,
             unsqueeze::UnsqueezeNode,
+            window::{WindowKind, WindowNode},
         },
     },
     format_tokens,
@@ -95,18 +107,23 @@ use onnx_ir::{
         batch_norm::batch_norm_config, clip::clip_config, concat::concat_config,
         conv_transpose1d::conv_transpose1d_config, conv_transpose2d::conv_transpose2d_config,
         conv_transpose3d::conv_transpose3d_config, conv1d::conv1d_config, conv2d::conv2d_config,
-        conv3d::conv3d_config, depth_to_space::depth_to_space_config, dropout::dropout_config,
-        expand::expand_config, flatten::flatten_config, gather::gather_config, gemm::gemm_config,
-        group_norm::group_norm_config, hard_sigmoid::hard_sigmoid_config,
-        instance_norm::instance_norm_config, is_inf::is_inf_config, layer_norm::layer_norm_config,
-        leaky_relu::leaky_relu_config, linear::linear_config, log_softmax::log_softmax_config,
-        max_pool1d::max_pool1d_config, max_pool2d::max_pool2d_config, one_hot::one_hot_config,
-        pad::pad_config, reduce_max::reduce_max_config, reduce_mean::reduce_mean_config,
+        conv3d::conv3d_config, cumsum::cumsum_config, depth_to_space::depth_to_space_config,
+        dft::dft_config, dropout::dropout_config, einsum::einsum_config, expand::expand_config,
+        flatten::flatten_config, gather::gather_config, gather_nd::gather_nd_config,
+        gemm::gemm_config, grid_sample::grid_sample_config, group_norm::group_norm_config,
+        hard_sigmoid::hard_sigmoid_config, instance_norm::instance_norm_config,
+        is_inf::is_inf_config, layer_norm::layer_norm_config, leaky_relu::leaky_relu_config,
+        linear::linear_config, log_softmax::log_softmax_config, max_pool1d::max_pool1d_config,
+        max_pool2d::max_pool2d_config, mel_weight_matrix::mel_weight_matrix_config,
+        multinomial::multinomial_config, one_hot::one_hot_config, pad::pad_config,
+        reduce::reduce_config, reduce_max::reduce_max_config, reduce_mean::reduce_mean_config,
         reduce_min::reduce_min_config, reduce_prod::reduce_prod_config,
         reduce_sum::reduce_sum_config, reshape::reshape_config, resize::resize_config,
+        scatter_elements::scatter_elements_config, scatter_nd::scatter_nd_config,
         slice::slice_config, softmax::softmax_config, space_to_depth::space_to_depth_config,
-        split::split_config, squeeze::squeeze_config, tile::tile_config, topk::top_k_config,
-        transpose::transpose_config, trilu::trilu_config, unsqueeze::unsqueeze_config,
+        split::split_config, squeeze::squeeze_config, stft::stft_config, tile::tile_config,
+        topk::top_k_config, transpose::transpose_config, trilu::trilu_config,
+        unsqueeze::unsqueeze_config, window::window_config,
     },
     parse_onnx,
     util::shape_config,
@@ -312,14 +329,22 @@ impl ParsedOnnxGraph {
                 NodeType::Mul => graph.register(Self::mul_conversion(node)),
                 NodeType::Div => graph.register(Self::div_conversion(node)),
                 NodeType::Equal => graph.register(Self::equal_conversion(node)),
+                NodeType::Det => graph.register(Self::det_conversion(node)),
                 NodeType::Erf => graph.register(Self::erf_conversion(node)),
                 NodeType::Exp => graph.register(Self::exp_conversion(node)),
                 NodeType::Expand => graph.register(Self::expand_conversion(node)),
                 NodeType::Floor => graph.register(Self::floor_conversion(node)),
                 NodeType::Ceil => graph.register(Self::ceil_conversion(node)),
                 NodeType::Clip => graph.register(Self::clip_conversion(node)),
+                NodeType::Acos => graph.register(Self::acos_conversion(node)),
+                NodeType::Acosh => graph.register(Self::acosh_conversion(node)),
+                NodeType::Asin => graph.register(Self::asin_conversion(node)),
+                NodeType::Asinh => graph.register(Self::asinh_conversion(node)),
+                NodeType::Atan => graph.register(Self::atan_conversion(node)),
+                NodeType::Atanh => graph.register(Self::atanh_conversion(node)),
                 NodeType::Cos => graph.register(Self::cos_conversion(node)),
                 NodeType::Cosh => graph.register(Self::cosh_conversion(node)),
+                NodeType::CumSum => graph.register(Self::cumsum_conversion(node)),
                 NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
                 NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
                 NodeType::Conv3d => graph.register(Self::conv3d_conversion::<PS>(node)),
@@ -360,6 +385,7 @@ impl ParsedOnnxGraph {
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
                 NodeType::Gather => graph.register(Self::gather_conversion(node)),
                 NodeType::GatherElements => graph.register(Self::gather_elements_conversion(node)),
+                NodeType::GatherND => graph.register(Self::gather_nd_conversion(node)),
                 NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
@@ -376,6 +402,21 @@ impl ParsedOnnxGraph {
                 NodeType::ReduceMean => graph.register(Self::reduce_mean_conversion(node)),
                 NodeType::ReduceProd => graph.register(Self::reduce_prod_conversion(node)),
                 NodeType::ReduceSum => graph.register(Self::reduce_sum_conversion(node)),
+                NodeType::ReduceSumSquare => {
+                    graph.register(Self::reduce_conversion(node, UnaryNode::reduce_sum_square))
+                }
+                NodeType::ReduceL1 => {
+                    graph.register(Self::reduce_conversion(node, UnaryNode::reduce_l1))
+                }
+                NodeType::ReduceL2 => {
+                    graph.register(Self::reduce_conversion(node, UnaryNode::reduce_l2))
+                }
+                NodeType::ReduceLogSum => {
+                    graph.register(Self::reduce_conversion(node, UnaryNode::reduce_log_sum))
+                }
+                NodeType::ReduceLogSumExp => {
+                    graph.register(Self::reduce_conversion(node, UnaryNode::reduce_log_sum_exp))
+                }
                 NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
                 NodeType::Resize => graph.register(Self::resize_conversion(node)),
                 NodeType::Reciprocal => graph.register(Self::reciprocal_conversion(node)),
@@ -392,6 +433,22 @@ impl ParsedOnnxGraph {
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
                 NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::Einsum => graph.register(Self::einsum_conversion(node)),
+                NodeType::DFT => graph.register(Self::dft_conversion(node)),
+                NodeType::STFT => graph.register(Self::stft_conversion(node)),
+                NodeType::HannWindow => {
+                    graph.register(Self::window_conversion(node, WindowKind::Hann))
+                }
+                NodeType::HammingWindow => {
+                    graph.register(Self::window_conversion(node, WindowKind::Hamming))
+                }
+                NodeType::BlackmanWindow => {
+                    graph.register(Self::window_conversion(node, WindowKind::Blackman))
+                }
+                NodeType::MelWeightMatrix => {
+                    graph.register(Self::mel_weight_matrix_conversion(node))
+                }
+                NodeType::Multinomial => graph.register(Self::multinomial_conversion(node)),
                 NodeType::GlobalAveragePool => {
                     graph.register(Self::global_avg_pool_conversion(node))
                 }
@@ -417,6 +474,7 @@ impl ParsedOnnxGraph {
                 NodeType::Tile => graph.register(Self::tile_conversion(node)),
                 NodeType::TopK => graph.register(Self::top_k_conversion(node)),
                 NodeType::Trilu => graph.register(Self::trilu_conversion(node)),
+                NodeType::Unique => graph.register(Self::unique_conversion(node)),
                 NodeType::RandomNormal => graph.register(Self::random_normal_conversion(node)),
                 NodeType::RandomNormalLike => {
                     graph.register(Self::random_normal_like_conversion(node))
@@ -426,6 +484,11 @@ impl ParsedOnnxGraph {
                 }
                 NodeType::Split => graph.register(Self::split_conversion(node)),
                 NodeType::Gemm => graph.register(Self::gemm_conversion(node)),
+                NodeType::GridSample => graph.register(Self::grid_sample_conversion(node)),
+                NodeType::ScatterElements => {
+                    graph.register(Self::scatter_elements_conversion(node))
+                }
+                NodeType::ScatterND => graph.register(Self::scatter_nd_conversion(node)),
                 NodeType::IsNaN => graph.register(Self::is_nan_conversion(node)),
                 NodeType::IsInf => graph.register(Self::is_inf_conversion(node)),
                 node_type => unsupported_ops.push(node_type),
@@ -740,6 +803,13 @@ impl ParsedOnnxGraph {
         BinaryNode::max_pair(lhs, rhs, output)
     }
 
+    fn det_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::det(input, output)
+    }
+
     fn erf_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -810,6 +880,15 @@ impl ParsedOnnxGraph {
         GatherElementsNode::new(input, index, output, dim)
     }
 
+    fn gather_nd_conversion(node: Node) -> GatherNdNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let indices = TensorType::from(node.inputs.get(1).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        gather_nd_config(&node);
+
+        GatherNdNode::new(input, indices, output)
+    }
+
     fn transpose_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -916,6 +995,18 @@ impl ParsedOnnxGraph {
         UnaryNode::reduce_sum(input, output, dim)
     }
 
+    /// Converts the reduce operations composed of Burn ops, which share the same configuration.
+    fn reduce_conversion(
+        node: Node,
+        reduce: fn(Type, Type, Option<usize>) -> UnaryNode,
+    ) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        let dim = reduce_config(&node);
+
+        reduce(input, output, dim)
+    }
+
     fn shape_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -1097,6 +1188,57 @@ impl ParsedOnnxGraph {
         DropoutNode::new(name, input, output, config)
     }
 
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = einsum_config(&node);
+
+        EinsumNode::new(inputs, output, config)
+    }
+
+    fn dft_conversion(node: Node) -> DftNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = dft_config(&node);
+
+        DftNode::new(input, output, config)
+    }
+
+    fn stft_conversion(node: Node) -> StftNode {
+        let signal = TensorType::from(node.inputs.first().unwrap());
+        let window = node
+            .inputs
+            .get(2)
+            .filter(|arg| !arg.name.is_empty())
+            .map(TensorType::from);
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = stft_config(&node);
+
+        StftNode::new(signal, window, output, config)
+    }
+
+    fn window_conversion(node: Node, kind: WindowKind) -> WindowNode {
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = window_config(&node);
+
+        WindowNode::new(output, kind, config)
+    }
+
+    fn mel_weight_matrix_conversion(node: Node) -> MelWeightMatrixNode {
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = mel_weight_matrix_config(&node);
+
+        MelWeightMatrixNode::new(output, config)
+    }
+
+    fn multinomial_conversion(node: Node) -> MultinomialNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = multinomial_config(&node);
+
+        MultinomialNode::new(input, output, config)
+    }
+
     fn batch_norm_conversion<PS: PrecisionSettings>(node: Node) -> BatchNormNode {
         let config = batch_norm_config(&node);
         let input = TensorType::from(node.inputs.first().unwrap());
@@ -1370,6 +1512,48 @@ impl ParsedOnnxGraph {
         UnaryNode::cos(input, output)
     }
 
+    fn acos_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::acos(input, output)
+    }
+
+    fn acosh_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::acosh(input, output)
+    }
+
+    fn asin_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::asin(input, output)
+    }
+
+    fn asinh_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::asinh(input, output)
+    }
+
+    fn atan_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::atan(input, output)
+    }
+
+    fn atanh_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::atanh(input, output)
+    }
+
     fn cosh_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -1516,6 +1700,20 @@ impl ParsedOnnxGraph {
         TopKNode::new(input, outputs, config)
     }
 
+    fn unique_conversion(node: Node) -> UniqueNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let outputs = node.outputs.iter().map(TensorType::from).collect();
+
+        UniqueNode::new(input, outputs)
+    }
+
+    fn cumsum_conversion(node: Node) -> CumSumNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = cumsum_config(&node);
+        CumSumNode::new(input, output, config)
+    }
+
     fn trilu_conversion(node: Node) -> TriluNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1570,6 +1768,35 @@ impl ParsedOnnxGraph {
         GemmNode::new(a, b, c, output, alpha, beta, trans_a, trans_b)
     }
 
+    fn grid_sample_conversion(node: Node) -> GridSampleNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let grid = TensorType::from(node.inputs.get(1).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = grid_sample_config(&node);
+
+        GridSampleNode::new(input, grid, output, config)
+    }
+
+    fn scatter_elements_conversion(node: Node) -> ScatterElementsNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let indices = TensorType::from(node.inputs.get(1).unwrap());
+        let updates = TensorType::from(node.inputs.get(2).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = scatter_elements_config(&node);
+
+        ScatterElementsNode::new(input, indices, updates, output, config)
+    }
+
+    fn scatter_nd_conversion(node: Node) -> ScatterNdNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let indices = TensorType::from(node.inputs.get(1).unwrap());
+        let updates = TensorType::from(node.inputs.get(2).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let reduction = scatter_nd_config(&node);
+
+        ScatterNdNode::new(input, indices, updates, output, reduction)
+    }
+
     fn is_inf_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -9,7 +9,8 @@ use alloc::{string::String, vec, vec::Vec};
 use burn_tensor::{
     DType, Distribution, Element,
     ops::{
-        ConvOptions, ConvTransposeOptions, DeformConvOptions, InterpolateMode, InterpolateOptions,
+        ConvOptions, ConvTransposeOptions, DeformConvOptions, GridSampleMode, GridSampleOptions,
+        GridSamplePaddingMode, InterpolateMode, InterpolateOptions,
     },
     quantization::QuantScheme,
 };
@@ -96,6 +97,52 @@ pub enum FloatOperationIr {
     Log1p(UnaryOpIr),
     /// Operation corresponding to [erf](burn_tensor::ops::FloatTensorOps::float_erf).
     Erf(UnaryOpIr),
+    /// Operation corresponding to [asin](burn_tensor::ops::FloatTensorOps::float_asin).
+    Asin(UnaryOpIr),
+    /// Operation corresponding to [acos](burn_tensor::ops::FloatTensorOps::float_acos).
+    Acos(UnaryOpIr),
+    /// Operation corresponding to [atan](burn_tensor::ops::FloatTensorOps::float_atan).
+    Atan(UnaryOpIr),
+    /// Operation corresponding to [asinh](burn_tensor::ops::FloatTensorOps::float_asinh).
+    Asinh(UnaryOpIr),
+    /// Operation corresponding to [acosh](burn_tensor::ops::FloatTensorOps::float_acosh).
+    Acosh(UnaryOpIr),
+    /// Operation corresponding to [atanh](burn_tensor::ops::FloatTensorOps::float_atanh).
+    Atanh(UnaryOpIr),
+    /// Operation corresponding to [erfc](burn_tensor::ops::FloatTensorOps::float_erfc).
+    Erfc(UnaryOpIr),
+    /// Operation corresponding to [erfinv](burn_tensor::ops::FloatTensorOps::float_erfinv).
+    Erfinv(UnaryOpIr),
+    /// Operation corresponding to [expm1](burn_tensor::ops::FloatTensorOps::float_expm1).
+    Expm1(UnaryOpIr),
+    /// Operation corresponding to [log2](burn_tensor::ops::FloatTensorOps::float_log2).
+    Log2(UnaryOpIr),
+    /// Operation corresponding to [log10](burn_tensor::ops::FloatTensorOps::float_log10).
+    Log10(UnaryOpIr),
+    /// Operation corresponding to [lgamma](burn_tensor::ops::FloatTensorOps::float_lgamma).
+    Lgamma(UnaryOpIr),
+    /// Operation corresponding to [digamma](burn_tensor::ops::FloatTensorOps::float_digamma).
+    Digamma(UnaryOpIr),
+    /// Operation corresponding to [i0](burn_tensor::ops::FloatTensorOps::float_i0).
+    I0(UnaryOpIr),
+    /// Operation corresponding to [i1](burn_tensor::ops::FloatTensorOps::float_i1).
+    I1(UnaryOpIr),
+    /// Operation corresponding to [atan2](burn_tensor::ops::FloatTensorOps::float_atan2).
+    Atan2(BinaryOpIr),
+    /// Operation corresponding to [hypot](burn_tensor::ops::FloatTensorOps::float_hypot).
+    Hypot(BinaryOpIr),
+    /// Operation corresponding to [polygamma](burn_tensor::ops::FloatTensorOps::float_polygamma).
+    Polygamma(PolygammaOpIr),
+    /// Operation corresponding to [logcumsumexp](burn_tensor::ops::FloatTensorOps::float_logcumsumexp).
+    LogCumSumExp(ReduceDimOpIr),
+    /// Operation corresponding to [logsumexp](burn_tensor::ops::FloatTensorOps::float_logsumexp).
+    LogSumExp(ReduceDimOpIr),
+    /// Operation corresponding to [sum masked dim](burn_tensor::ops::FloatTensorOps::float_sum_masked_dim).
+    SumMaskedDim(MaskedReduceDimOpIr),
+    /// Operation corresponding to [fft](burn_tensor::ops::FftOps::fft).
+    Fft(FftOpIr),
+    /// Operation corresponding to [multinomial](burn_tensor::ops::FloatTensorOps::float_multinomial).
+    Multinomial(MultinomialOpIr),
     /// Operation corresponding to [powf_scalar](burn_tensor::ops::FloatTensorOps::float_powf_scalar).
     PowfScalar(ScalarOpIr<f32>),
     /// Operation corresponding to [sqrt](burn_tensor::ops::FloatTensorOps::float_sqrt).
@@ -193,6 +240,10 @@ pub enum ModuleOperationIr {
     Interpolate(InterpolateOpIr),
     /// Operation corresponding to [interpolate backward](burn_tensor::ops::ModuleOps::interpolate_backward).
     InterpolateBackward(InterpolateBackwardOpIr),
+    /// Operation corresponding to [grid sample](burn_tensor::ops::ModuleOps::grid_sample).
+    GridSample(GridSampleOpIr),
+    /// Operation corresponding to [grid sample backward](burn_tensor::ops::ModuleOps::grid_sample_backward).
+    GridSampleBackward(GridSampleBackwardOpIr),
 }
 
 /// Basic operations that can be done on any tensor type.
@@ -414,6 +465,26 @@ pub enum NumericOperationIr<E> {
     /// Float => [prod dim](burn_tensor::ops::FloatTensorOps::float_prod_dim).
     /// Int => [prod dim](burn_tensor::ops::IntTensorOps::int_prod_dim).
     ProdDim(ReduceDimOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumsum](burn_tensor::ops::FloatTensorOps::float_cumsum).
+    /// Int => [cumsum](burn_tensor::ops::IntTensorOps::int_cumsum).
+    CumSum(ReduceDimOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumprod](burn_tensor::ops::FloatTensorOps::float_cumprod).
+    /// Int => [cumprod](burn_tensor::ops::IntTensorOps::int_cumprod).
+    CumProd(ReduceDimOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cummax](burn_tensor::ops::FloatTensorOps::float_cummax).
+    /// Int => [cummax](burn_tensor::ops::IntTensorOps::int_cummax).
+    CumMax(ReduceDimWithIndicesOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cummin](burn_tensor::ops::FloatTensorOps::float_cummin).
+    /// Int => [cummin](burn_tensor::ops::IntTensorOps::int_cummin).
+    CumMin(ReduceDimWithIndicesOpIr),
 
     /// Operation corresponding to:
     ///
@@ -687,6 +758,15 @@ pub struct ReduceDimOpIr {
     pub axis: usize,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaskedReduceDimOpIr {
+    pub input: TensorIr,
+    pub mask: TensorIr,
+    pub out: TensorIr,
+    pub axis: usize,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct GatherOpIr {
@@ -795,6 +875,34 @@ pub struct ReduceDimWithIndicesOpIr {
     pub out_indices: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct FftOpIr {
+    pub real: TensorIr,
+    pub imag: TensorIr,
+    pub dim: usize,
+    pub inverse: bool,
+    pub out_real: TensorIr,
+    pub out_imag: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MultinomialOpIr {
+    pub input: TensorIr,
+    pub num_samples: usize,
+    pub replacement: bool,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct PolygammaOpIr {
+    pub input: TensorIr,
+    pub order: u32,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct EmbeddingOpIr {
@@ -973,6 +1081,8 @@ pub struct ConvTranspose3dOptionsIr {
 pub struct QuantizationParametersIr {
     /// The scaling factor.
     pub scales: TensorIr,
+    /// The zero-point offset.
+    pub offsets: Option<TensorIr>,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -1372,6 +1482,88 @@ pub struct InterpolateBackwardOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum GridSampleModeIr {
+    Nearest,
+    Bilinear,
+    Bicubic,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum GridSamplePaddingModeIr {
+    Zeros,
+    Border,
+    Reflection,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GridSampleOptionsIr {
+    pub mode: GridSampleModeIr,
+    pub padding_mode: GridSamplePaddingModeIr,
+    pub align_corners: bool,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GridSampleOpIr {
+    pub x: TensorIr,
+    pub grid: TensorIr,
+    pub options: GridSampleOptionsIr,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GridSampleBackwardOpIr {
+    pub x: TensorIr,
+    pub grid: TensorIr,
+    pub out_grad: TensorIr,
+    pub options: GridSampleOptionsIr,
+    pub x_grad: TensorIr,
+    pub grid_grad: TensorIr,
+}
+
+impl From<GridSampleOptionsIr> for GridSampleOptions {
+    fn from(val: GridSampleOptionsIr) -> Self {
+        let mode = match val.mode {
+            GridSampleModeIr::Nearest => GridSampleMode::Nearest,
+            GridSampleModeIr::Bilinear => GridSampleMode::Bilinear,
+            GridSampleModeIr::Bicubic => GridSampleMode::Bicubic,
+        };
+        let padding_mode = match val.padding_mode {
+            GridSamplePaddingModeIr::Zeros => GridSamplePaddingMode::Zeros,
+            GridSamplePaddingModeIr::Border => GridSamplePaddingMode::Border,
+            GridSamplePaddingModeIr::Reflection => GridSamplePaddingMode::Reflection,
+        };
+
+        Self::new(mode, padding_mode, val.align_corners)
+    }
+}
+
+impl From<GridSampleOptions> for GridSampleOptionsIr {
+    fn from(val: GridSampleOptions) -> Self {
+        let mode = match val.mode {
+            GridSampleMode::Nearest => GridSampleModeIr::Nearest,
+            GridSampleMode::Bilinear => GridSampleModeIr::Bilinear,
+            GridSampleMode::Bicubic => GridSampleModeIr::Bicubic,
+        };
+        let padding_mode = match val.padding_mode {
+            GridSamplePaddingMode::Zeros => GridSamplePaddingModeIr::Zeros,
+            GridSamplePaddingMode::Border => GridSamplePaddingModeIr::Border,
+            GridSamplePaddingMode::Reflection => GridSamplePaddingModeIr::Reflection,
+        };
+
+        Self {
+            mode,
+            padding_mode,
+            align_corners: val.align_corners,
+        }
+    }
+}
+
 impl OperationIr {
     /// Get all [tensor](TensorIr) involved with the current operation.
     pub fn nodes(&self) -> Vec<&TensorIr> {
@@ -1632,6 +1824,18 @@ impl<E: Element> NumericOperationIr<E> {
             NumericOperationIr::ProdDim(repr) => {
                 vec![&repr.input, &repr.out]
             }
+            NumericOperationIr::CumSum(repr) => {
+                vec![&repr.input, &repr.out]
+            }
+            NumericOperationIr::CumProd(repr) => {
+                vec![&repr.input, &repr.out]
+            }
+            NumericOperationIr::CumMax(repr) => {
+                vec![&repr.tensor, &repr.out_indices, &repr.out]
+            }
+            NumericOperationIr::CumMin(repr) => {
+                vec![&repr.tensor, &repr.out_indices, &repr.out]
+            }
             NumericOperationIr::Max(repr) => {
                 vec![&repr.input, &repr.out]
             }
@@ -1794,6 +1998,18 @@ impl<E: Element> NumericOperationIr<E> {
             NumericOperationIr::ProdDim(repr) => {
                 repr.input.mark_read_only(nodes, &mut output);
             }
+            NumericOperationIr::CumSum(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            NumericOperationIr::CumProd(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            NumericOperationIr::CumMax(repr) => {
+                repr.tensor.mark_read_only(nodes, &mut output);
+            }
+            NumericOperationIr::CumMin(repr) => {
+                repr.tensor.mark_read_only(nodes, &mut output);
+            }
             NumericOperationIr::Max(repr) => {
                 repr.input.mark_read_only(nodes, &mut output);
             }
@@ -1840,6 +2056,31 @@ impl FloatOperationIr {
             FloatOperationIr::Log(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Log1p(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Erf(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Asin(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Acos(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Atan(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Asinh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Acosh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Atanh(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Erfc(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Erfinv(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Expm1(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Log2(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Log10(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Lgamma(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Digamma(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::I0(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::I1(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Atan2(repr) => vec![&repr.lhs, &repr.rhs, &repr.out],
+            FloatOperationIr::Hypot(repr) => vec![&repr.lhs, &repr.rhs, &repr.out],
+            FloatOperationIr::Polygamma(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::LogCumSumExp(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::LogSumExp(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::SumMaskedDim(repr) => vec![&repr.input, &repr.mask, &repr.out],
+            FloatOperationIr::Fft(repr) => {
+                vec![&repr.real, &repr.imag, &repr.out_real, &repr.out_imag]
+            }
+            FloatOperationIr::Multinomial(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Recip(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::PowfScalar(repr) => vec![&repr.lhs, &repr.out],
             FloatOperationIr::Sqrt(repr) => vec![&repr.input, &repr.out],
@@ -1850,7 +2091,13 @@ impl FloatOperationIr {
             FloatOperationIr::Floor(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Ceil(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::IntoInt(repr) => vec![&repr.input, &repr.out],
-            FloatOperationIr::Quantize(repr) => vec![&repr.tensor, &repr.qparams.scales, &repr.out],
+            FloatOperationIr::Quantize(repr) => {
+                if let Some(offsets) = &repr.qparams.offsets {
+                    vec![&repr.tensor, &repr.qparams.scales, offsets, &repr.out]
+                } else {
+                    vec![&repr.tensor, &repr.qparams.scales, &repr.out]
+                }
+            }
             FloatOperationIr::Dequantize(repr) => vec![&repr.input, &repr.out],
         }
     }
@@ -1876,6 +2123,79 @@ impl FloatOperationIr {
             FloatOperationIr::Erf(repr) => {
                 repr.input.mark_read_only(nodes, &mut output);
             }
+            FloatOperationIr::Asin(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Acos(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Atan(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Asinh(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Acosh(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Atanh(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Erfc(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Erfinv(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Expm1(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Log2(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Log10(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Lgamma(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Digamma(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::I0(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::I1(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Polygamma(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Atan2(repr) => {
+                repr.lhs.mark_read_only(nodes, &mut output);
+                repr.rhs.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Hypot(repr) => {
+                repr.lhs.mark_read_only(nodes, &mut output);
+                repr.rhs.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::LogCumSumExp(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::LogSumExp(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::SumMaskedDim(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+                repr.mask.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Fft(repr) => {
+                repr.real.mark_read_only(nodes, &mut output);
+                repr.imag.mark_read_only(nodes, &mut output);
+            }
+            FloatOperationIr::Multinomial(repr) => {
+                repr.input.mark_read_only(nodes, &mut output);
+            }
             FloatOperationIr::Recip(repr) => {
                 repr.input.mark_read_only(nodes, &mut output);
             }
@@ -1906,6 +2226,9 @@ impl FloatOperationIr {
             FloatOperationIr::Quantize(repr) => {
                 repr.tensor.mark_read_only(nodes, &mut output);
                 repr.qparams.scales.mark_read_only(nodes, &mut output);
+                if let Some(offsets) = &mut repr.qparams.offsets {
+                    offsets.mark_read_only(nodes, &mut output);
+                }
             }
             FloatOperationIr::Dequantize(repr) => {
                 repr.input.mark_read_only(nodes, &mut output);
@@ -2168,6 +2491,18 @@ impl ModuleOperationIr {
             ModuleOperationIr::InterpolateBackward(repr) => {
                 vec![&repr.x, &repr.out, &repr.grad]
             }
+            ModuleOperationIr::GridSample(repr) => {
+                vec![&repr.x, &repr.grid, &repr.out]
+            }
+            ModuleOperationIr::GridSampleBackward(repr) => {
+                vec![
+                    &repr.x,
+                    &repr.grid,
+                    &repr.out_grad,
+                    &repr.x_grad,
+                    &repr.grid_grad,
+                ]
+            }
         }
     }
 
@@ -2319,6 +2654,15 @@ impl ModuleOperationIr {
                 repr.x.mark_read_only(nodes, &mut output);
                 repr.grad.mark_read_only(nodes, &mut output);
             }
+            ModuleOperationIr::GridSample(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grid.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::GridSampleBackward(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grid.mark_read_only(nodes, &mut output);
+                repr.out_grad.mark_read_only(nodes, &mut output);
+            }
         };
 
         output
@@ -2355,6 +2699,13 @@ impl core::hash::Hash for RandomOpIr {
             Distribution::Bernoulli(_) => 2u8.hash(state),
             Distribution::Uniform(_, _) => 3u8.hash(state),
             Distribution::Normal(_, _) => 4u8.hash(state),
+            Distribution::TruncatedNormal(_, _, _, _) => 5u8.hash(state),
+            Distribution::Exponential(_) => 6u8.hash(state),
+            Distribution::Gamma(_, _) => 7u8.hash(state),
+            Distribution::Beta(_, _) => 8u8.hash(state),
+            Distribution::Poisson(_) => 9u8.hash(state),
+            Distribution::Cauchy(_, _) => 10u8.hash(state),
+            Distribution::LogNormal(_, _) => 11u8.hash(state),
         }
     }
 }
@@ -2410,6 +2761,10 @@ impl<E> core::hash::Hash for NumericOperationIr<E> {
             NumericOperationIr::SumDim(repr) => repr.hash(state),
             NumericOperationIr::Prod(repr) => repr.hash(state),
             NumericOperationIr::ProdDim(repr) => repr.hash(state),
+            NumericOperationIr::CumSum(repr) => repr.hash(state),
+            NumericOperationIr::CumProd(repr) => repr.hash(state),
+            NumericOperationIr::CumMax(repr) => repr.hash(state),
+            NumericOperationIr::CumMin(repr) => repr.hash(state),
             NumericOperationIr::EqualElem(repr) => repr.hash(state),
             NumericOperationIr::Greater(repr) => repr.hash(state),
             NumericOperationIr::GreaterElem(repr) => repr.hash(state),
@@ -199,7 +199,8 @@ macro_rules! dispatch_binary_simd {
             let simd = match $elem::dtype() {
                 $(DType::[<$ty:upper>] => try_binary_simd::<$elem, $elem, $ty, $ty, $op>($lhs, $rhs),)*
                 DType::QFloat(strategy) => match strategy.q_type {
-                    QuantInputType::QInt8 => try_binary_simd::<$elem, $elem, i8, i8, $op>($lhs, $rhs),
+                    QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4 => try_binary_simd::<$elem, $elem, i8, i8, $op>($lhs, $rhs),
+                    QuantInputType::E4M3 | QuantInputType::E5M2 => Err(($lhs, $rhs)),
                 },
                 _ => Err(($lhs, $rhs)),
             };
@@ -236,7 +237,8 @@ macro_rules! dispatch_binary_scalar_simd {
             let simd = match $elem::dtype() {
                 $(DType::[<$ty:upper>] => try_binary_scalar_simd::<$elem, $elem, $ty, $ty, $op>($lhs, $rhs),)*
                 DType::QFloat(strategy) => match strategy.q_type {
-                    QuantInputType::QInt8 => try_binary_scalar_simd::<$elem, $elem, i8, i8, $op>($lhs, $rhs),
+                    QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4 => try_binary_scalar_simd::<$elem, $elem, i8, i8, $op>($lhs, $rhs),
+                    QuantInputType::E4M3 | QuantInputType::E5M2 => Err($lhs),
                 },
                 _ => Err($lhs),
             };
@@ -261,7 +263,8 @@ macro_rules! dispatch_cmp_simd {
             let simd = match $elem::dtype() {
                 $(DType::[<$ty:upper>] => try_cmp_simd::<$elem, $ty, $op>($lhs, $rhs),)*
                 DType::QFloat(strategy) => match strategy.q_type {
-                    QuantInputType::QInt8 => try_cmp_simd::<$elem, i8, $op>($lhs, $rhs),
+                    QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4 => try_cmp_simd::<$elem, i8, $op>($lhs, $rhs),
+                    QuantInputType::E4M3 | QuantInputType::E5M2 => Err(($lhs, $rhs)),
                 },
                 _ => Err(($lhs, $rhs)),
             };
@@ -285,7 +288,8 @@ macro_rules! dispatch_cmp_scalar_simd {
             let simd = match $elem::dtype() {
                 $(DType::[<$ty:upper>] => try_cmp_scalar_simd::<$elem, $ty, $op>($lhs, $rhs),)*
                 DType::QFloat(strategy) => match strategy.q_type {
-                    QuantInputType::QInt8 => try_cmp_scalar_simd::<$elem, i8, $op>($lhs, $rhs),
+                    QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4 => try_cmp_scalar_simd::<$elem, i8, $op>($lhs, $rhs),
+                    QuantInputType::E4M3 | QuantInputType::E5M2 => Err($lhs),
                 },
                 _ => Err($lhs),
             };
@@ -696,6 +700,51 @@ where
         arg(tensor, dim, CmpType::Min)
     }
 
+    pub fn cumsum(tensor: NdArrayTensor<E>, dim: usize) -> NdArrayTensor<E> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr += prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod(tensor: NdArrayTensor<E>, dim: usize) -> NdArrayTensor<E> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr = *curr * prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cummax<I: NdArrayElement>(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+    ) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+        cum_arg(tensor, dim, CmpType::Max)
+    }
+
+    pub fn cummin<I: NdArrayElement>(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+    ) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+        cum_arg(tensor, dim, CmpType::Min)
+    }
+
+    pub fn logcumsumexp(tensor: NdArrayTensor<E>, dim: usize) -> NdArrayTensor<E> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| {
+            // log(e^a + e^b) = max(a, b) + log(1 + e^(min(a, b) - max(a, b)))
+            let (max, min) = if prev > *curr {
+                (prev, *curr)
+            } else {
+                (*curr, prev)
+            };
+            // Avoids `inf - inf` when both values are infinite.
+            let diff: E = if max == min { 0.elem() } else { min - max };
+            *curr = max + diff.exp_elem().log1p_elem();
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     pub fn clamp_min(tensor: NdArrayTensor<E>, min: E) -> NdArrayTensor<E> {
         let mut tensor = dispatch_binary_scalar_simd!(
             E,
@@ -1226,6 +1275,44 @@ fn arg<E: NdArrayElement, I: NdArrayElement>(
     }
 }
 
+fn cum_arg<E: NdArrayElement, I: NdArrayElement>(
+    tensor: NdArrayTensor<E>,
+    dim: usize,
+    cmp: CmpType,
+) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+    let mut array = tensor.array.into_owned();
+    let mut indices = ndarray::Array::<I, IxDyn>::zeros(array.raw_dim());
+
+    Zip::from(array.lanes_mut(Axis(dim)))
+        .and(indices.lanes_mut(Axis(dim)))
+        .for_each(|mut values, mut indices| {
+            if values.is_empty() {
+                return;
+            }
+
+            // Keep the running min/max value, ties select the last occurrence.
+            let mut acc = (values[0], 0usize);
+            for (idx, (value, index)) in values.iter_mut().zip(indices.iter_mut()).enumerate() {
+                let cmp = match cmp {
+                    CmpType::Min => *value <= acc.0,
+                    CmpType::Max => *value >= acc.0,
+                };
+
+                if cmp {
+                    acc = (*value, idx);
+                }
+
+                *value = acc.0;
+                *index = (acc.1 as i64).elem();
+            }
+        });
+
+    (
+        NdArrayTensor::new(array.into_shared()),
+        NdArrayTensor::new(indices.into_shared()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,64 @@
+use crate::{
+    NdArray, NdArrayTensor, NdArrayTensorFloat,
+    element::{FloatNdArrayElement, IntNdArrayElement, QuantElement},
+};
+use burn_tensor::{
+    ElementConversion, TensorMetadata,
+    ops::{ComplexOps, ComplexTensor, FloatTensor},
+};
+use ndarray::Zip;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> ComplexOps<Self>
+    for NdArray<E, I, Q>
+{
+    fn complex_abs(tensor: ComplexTensor<Self>) -> FloatTensor<Self> {
+        map_parts(tensor.real, tensor.imag, f64::hypot)
+    }
+
+    fn complex_angle(tensor: ComplexTensor<Self>) -> FloatTensor<Self> {
+        map_parts(tensor.real, tensor.imag, |re, im| im.atan2(re))
+    }
+}
+
+/// Maps the real and imaginary parts of each element to a real value.
+fn map_parts(
+    real: NdArrayTensorFloat,
+    imag: NdArrayTensorFloat,
+    func: fn(f64, f64) -> f64,
+) -> NdArrayTensorFloat {
+    let (real_dtype, imag_dtype) = (real.dtype(), imag.dtype());
+
+    match (real, imag) {
+        (NdArrayTensorFloat::F32(real), NdArrayTensorFloat::F32(imag)) => {
+            NdArrayTensorFloat::F32(zip_parts(real, imag, func))
+        }
+        (NdArrayTensorFloat::F64(real), NdArrayTensorFloat::F64(imag)) => {
+            NdArrayTensorFloat::F64(zip_parts(real, imag, func))
+        }
+        (NdArrayTensorFloat::E4M3(real), NdArrayTensorFloat::E4M3(imag)) => {
+            let output = zip_parts(real.convert::<f32>(), imag.convert::<f32>(), func);
+            NdArrayTensorFloat::E4M3(output.convert())
+        }
+        (NdArrayTensorFloat::E5M2(real), NdArrayTensorFloat::E5M2(imag)) => {
+            let output = zip_parts(real.convert::<f32>(), imag.convert::<f32>(), func);
+            NdArrayTensorFloat::E5M2(output.convert())
+        }
+        _ => panic!("Data type mismatch (real: {real_dtype:?}, imag: {imag_dtype:?})"),
+    }
+}
+
+fn zip_parts<E: FloatNdArrayElement>(
+    real: NdArrayTensor<E>,
+    imag: NdArrayTensor<E>,
+    func: fn(f64, f64) -> f64,
+) -> NdArrayTensor<E> {
+    let array = Zip::from(&real.array)
+        .and(&imag.array)
+        .map_collect(|real, imag| func(real.elem(), imag.elem()).elem());
+
+    NdArrayTensor::new(array.into_shared())
+}
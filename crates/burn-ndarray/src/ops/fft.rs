@@ -0,0 +1,85 @@
+use crate::{
+    NdArray, NdArrayTensor, NdArrayTensorFloat,
+    element::{FloatNdArrayElement, IntNdArrayElement, QuantElement},
+};
+use alloc::vec;
+use burn_tensor::{
+    ElementConversion, TensorMetadata,
+    ops::{FftOps, FloatTensor, fft_in_place},
+};
+use ndarray::{Axis, Zip};
+
+impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FftOps<Self>
+    for NdArray<E, I, Q>
+{
+    fn fft(
+        real: FloatTensor<Self>,
+        imag: FloatTensor<Self>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let (real_dtype, imag_dtype) = (real.dtype(), imag.dtype());
+
+        match (real, imag) {
+            (NdArrayTensorFloat::F32(real), NdArrayTensorFloat::F32(imag)) => {
+                let (real, imag) = fft(real, imag, dim, inverse);
+                (NdArrayTensorFloat::F32(real), NdArrayTensorFloat::F32(imag))
+            }
+            (NdArrayTensorFloat::F64(real), NdArrayTensorFloat::F64(imag)) => {
+                let (real, imag) = fft(real, imag, dim, inverse);
+                (NdArrayTensorFloat::F64(real), NdArrayTensorFloat::F64(imag))
+            }
+            (NdArrayTensorFloat::E4M3(real), NdArrayTensorFloat::E4M3(imag)) => {
+                let (real, imag) = fft(real.convert::<f32>(), imag.convert::<f32>(), dim, inverse);
+                (
+                    NdArrayTensorFloat::E4M3(real.convert()),
+                    NdArrayTensorFloat::E4M3(imag.convert()),
+                )
+            }
+            (NdArrayTensorFloat::E5M2(real), NdArrayTensorFloat::E5M2(imag)) => {
+                let (real, imag) = fft(real.convert::<f32>(), imag.convert::<f32>(), dim, inverse);
+                (
+                    NdArrayTensorFloat::E5M2(real.convert()),
+                    NdArrayTensorFloat::E5M2(imag.convert()),
+                )
+            }
+            _ => panic!("Data type mismatch (real: {real_dtype:?}, imag: {imag_dtype:?})"),
+        }
+    }
+}
+
+/// Computes the discrete Fourier transform of each line along `dim`.
+fn fft<E: FloatNdArrayElement>(
+    real: NdArrayTensor<E>,
+    imag: NdArrayTensor<E>,
+    dim: usize,
+    inverse: bool,
+) -> (NdArrayTensor<E>, NdArrayTensor<E>) {
+    let mut real = real.array.into_owned();
+    let mut imag = imag.array.into_owned();
+
+    let size = real.shape()[dim];
+    let mut line_real = vec![0.0; size];
+    let mut line_imag = vec![0.0; size];
+
+    Zip::from(real.lanes_mut(Axis(dim)))
+        .and(imag.lanes_mut(Axis(dim)))
+        .for_each(|mut real, mut imag| {
+            for i in 0..size {
+                line_real[i] = real[i].elem();
+                line_imag[i] = imag[i].elem();
+            }
+
+            fft_in_place(&mut line_real, &mut line_imag, inverse);
+
+            for i in 0..size {
+                real[i] = line_real[i].elem();
+                imag[i] = line_imag[i].elem();
+            }
+        });
+
+    (
+        NdArrayTensor::new(real.into_shared()),
+        NdArrayTensor::new(imag.into_shared()),
+    )
+}
@@ -0,0 +1,374 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_common::{iter_range_par, run_par};
+use burn_tensor::{
+    ElementConversion,
+    ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode},
+};
+use ndarray::{ArrayD, IxDyn};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use super::tensor::round_ties_even_wrapper;
+use crate::{FloatNdArrayElement, NdArrayTensor, UnsafeSharedRef};
+
+/// The coefficient of the cubic convolution, same as PyTorch.
+const CUBIC_A: f64 = -0.75;
+
+/// Shapes of a grid sampling operation.
+struct Layout {
+    batch_size: usize,
+    channels: usize,
+    num_spatial: usize,
+    num_elements: usize,
+    num_locations: usize,
+    /// The stride of the input spatial dimension sampled by each grid coordinate.
+    strides: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl Layout {
+    fn new(x: &[usize], grid: &[usize]) -> Self {
+        let num_spatial = grid.len() - 2;
+        let spatial = &x[2..];
+
+        // The grid coordinates are in the reverse order of the spatial dimensions.
+        let (sizes, strides) = (0..num_spatial)
+            .map(|axis| {
+                let dim = num_spatial - 1 - axis;
+                (spatial[dim], spatial[dim + 1..].iter().product::<usize>())
+            })
+            .unzip();
+
+        Self {
+            batch_size: x[0],
+            channels: x[1],
+            num_spatial,
+            num_elements: spatial.iter().product(),
+            num_locations: grid[1..grid.len() - 1].iter().product(),
+            strides,
+            sizes,
+        }
+    }
+}
+
+/// The in bound input locations used to sample a grid coordinate along a spatial dimension.
+#[derive(Default)]
+struct AxisTaps {
+    len: usize,
+    offset: [usize; 4],
+    weight: [f64; 4],
+    /// The derivative of the weight with respect to the grid coordinate.
+    weight_grad: [f64; 4],
+}
+
+impl AxisTaps {
+    fn new(coord: f64, size: usize, stride: usize, options: &GridSampleOptions) -> Self {
+        let mut taps = Self::default();
+
+        // Unnormalize the coordinate from [-1, 1] to the input indices.
+        let scale = match options.align_corners {
+            true => (size as f64 - 1.0) / 2.0,
+            false => size as f64 / 2.0,
+        };
+        let coord = coord * scale + (size as f64 - 1.0) / 2.0;
+
+        match options.mode {
+            GridSampleMode::Nearest => {
+                let (coord, _) = pad_coordinate(coord, size, options);
+                taps.push(round_ties_even_wrapper(coord), size, stride, 1.0, 0.0);
+            }
+            GridSampleMode::Bilinear => {
+                let (coord, coord_grad) = pad_coordinate(coord, size, options);
+                let index = coord.floor();
+                let t = coord - index;
+                let weight_grad = coord_grad * scale;
+
+                taps.push(index, size, stride, 1.0 - t, -weight_grad);
+                taps.push(index + 1.0, size, stride, t, weight_grad);
+            }
+            GridSampleMode::Bicubic => {
+                // The padding is applied to each tap instead of the coordinate.
+                let index = coord.floor();
+                let t = coord - index;
+
+                for i in 0..4 {
+                    let tap = pad_index(index + i as f64 - 1.0, size, options);
+                    let (weight, weight_grad) = cubic_weight(t, i);
+                    taps.push(tap, size, stride, weight, weight_grad * scale);
+                }
+            }
+        }
+
+        taps
+    }
+
+    fn push(&mut self, index: f64, size: usize, stride: usize, weight: f64, weight_grad: f64) {
+        // Out of bound locations are sampled as zeros.
+        if index < 0.0 || index > size as f64 - 1.0 {
+            return;
+        }
+
+        self.offset[self.len] = index as usize * stride;
+        self.weight[self.len] = weight;
+        self.weight_grad[self.len] = weight_grad;
+        self.len += 1;
+    }
+}
+
+/// Calls `func` with the flat input offset, the weight and the derivatives of the weight with
+/// respect to each grid coordinate, for every input location used to sample a grid point.
+fn for_each_sample<F: FnMut(usize, f64, &[f64; 3])>(taps: &[AxisTaps], func: &mut F) {
+    visit_samples(taps, 0, 0, 1.0, [0.0; 3], func);
+}
+
+fn visit_samples<F: FnMut(usize, f64, &[f64; 3])>(
+    taps: &[AxisTaps],
+    axis: usize,
+    offset: usize,
+    weight: f64,
+    weight_grads: [f64; 3],
+    func: &mut F,
+) {
+    if axis == taps.len() {
+        func(offset, weight, &weight_grads);
+        return;
+    }
+
+    let axis_taps = &taps[axis];
+    for i in 0..axis_taps.len {
+        let mut grads = weight_grads;
+        for grad in grads.iter_mut().take(axis) {
+            *grad *= axis_taps.weight[i];
+        }
+        grads[axis] = weight * axis_taps.weight_grad[i];
+
+        visit_samples(
+            taps,
+            axis + 1,
+            offset + axis_taps.offset[i],
+            weight * axis_taps.weight[i],
+            grads,
+            func,
+        );
+    }
+}
+
+/// Computes the input locations used to sample the grid point at `index`.
+fn grid_taps<E: FloatNdArrayElement>(
+    grid: &[E],
+    index: usize,
+    layout: &Layout,
+    options: &GridSampleOptions,
+) -> Vec<AxisTaps> {
+    (0..layout.num_spatial)
+        .map(|axis| {
+            let coord = grid[index * layout.num_spatial + axis].elem::<f64>();
+            AxisTaps::new(coord, layout.sizes[axis], layout.strides[axis], options)
+        })
+        .collect()
+}
+
+pub(crate) fn grid_sample<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E>,
+    grid: NdArrayTensor<E>,
+    options: GridSampleOptions,
+) -> NdArrayTensor<E> {
+    let layout = Layout::new(x.array.shape(), grid.array.shape());
+    let mut output_shape = vec![layout.batch_size, layout.channels];
+    output_shape.extend(&grid.array.shape()[1..layout.num_spatial + 1]);
+
+    let x = x.array.as_standard_layout();
+    let x = x.as_slice().unwrap();
+    let grid = grid.array.as_standard_layout();
+    let grid = grid.as_slice().unwrap();
+
+    let mut output =
+        vec![0.elem::<E>(); layout.batch_size * layout.channels * layout.num_locations];
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, layout.batch_size * layout.num_locations).for_each(|id| {
+            let b = id / layout.num_locations;
+            let location = id % layout.num_locations;
+            let taps = grid_taps(grid, id, &layout, &options);
+
+            let mut values = vec![0.0; layout.channels];
+            for_each_sample(&taps, &mut |offset, weight, _| {
+                for (c, value) in values.iter_mut().enumerate() {
+                    let index = (b * layout.channels + c) * layout.num_elements + offset;
+                    *value += weight * x[index].elem::<f64>();
+                }
+            });
+
+            unsafe {
+                let output = unsafe_shared_out.get();
+                for (c, value) in values.into_iter().enumerate() {
+                    let index = (b * layout.channels + c) * layout.num_locations + location;
+                    output[index] = value.elem();
+                }
+            }
+        });
+    });
+
+    let output = ArrayD::from_shape_vec(IxDyn(&output_shape), output).unwrap();
+    NdArrayTensor::new(output.into_shared())
+}
+
+pub(crate) fn grid_sample_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E>,
+    grid: NdArrayTensor<E>,
+    output_grad: NdArrayTensor<E>,
+    options: GridSampleOptions,
+) -> (NdArrayTensor<E>, NdArrayTensor<E>) {
+    let x_shape = x.array.shape().to_vec();
+    let grid_shape = grid.array.shape().to_vec();
+    let layout = Layout::new(&x_shape, &grid_shape);
+
+    let x = x.array.as_standard_layout();
+    let x = x.as_slice().unwrap();
+    let grid = grid.array.as_standard_layout();
+    let grid = grid.as_slice().unwrap();
+    let output_grad = output_grad.array.as_standard_layout();
+    let output_grad = output_grad.as_slice().unwrap();
+
+    let mut x_grad = vec![0.0; x.len()];
+    let mut grid_grad = vec![0.0; grid.len()];
+    let unsafe_shared_x_grad = UnsafeSharedRef::new(&mut x_grad);
+    let unsafe_shared_grid_grad = UnsafeSharedRef::new(&mut grid_grad);
+
+    // Each batch accumulates into its own part of the gradients.
+    run_par!(|| {
+        iter_range_par!(0, layout.batch_size).for_each(|b| unsafe {
+            let x_grad = unsafe_shared_x_grad.get();
+            let grid_grad = unsafe_shared_grid_grad.get();
+
+            for location in 0..layout.num_locations {
+                let id = b * layout.num_locations + location;
+                let taps = grid_taps(grid, id, &layout, &options);
+                let mut coord_grads = [0.0; 3];
+
+                for_each_sample(&taps, &mut |offset, weight, weight_grads| {
+                    let mut value_grad = 0.0;
+                    for c in 0..layout.channels {
+                        let channel = b * layout.channels + c;
+                        let grad =
+                            output_grad[channel * layout.num_locations + location].elem::<f64>();
+                        let index = channel * layout.num_elements + offset;
+
+                        x_grad[index] += grad * weight;
+                        value_grad += grad * x[index].elem::<f64>();
+                    }
+
+                    for (coord_grad, weight_grad) in coord_grads.iter_mut().zip(weight_grads) {
+                        *coord_grad += value_grad * weight_grad;
+                    }
+                });
+
+                for (axis, coord_grad) in coord_grads.iter().take(layout.num_spatial).enumerate() {
+                    grid_grad[id * layout.num_spatial + axis] = *coord_grad;
+                }
+            }
+        });
+    });
+
+    let x_grad = x_grad.into_iter().map(|value| value.elem()).collect();
+    let grid_grad = grid_grad.into_iter().map(|value| value.elem()).collect();
+
+    (
+        NdArrayTensor::new(
+            ArrayD::<E>::from_shape_vec(IxDyn(&x_shape), x_grad)
+                .unwrap()
+                .into_shared(),
+        ),
+        NdArrayTensor::new(
+            ArrayD::<E>::from_shape_vec(IxDyn(&grid_shape), grid_grad)
+                .unwrap()
+                .into_shared(),
+        ),
+    )
+}
+
+/// Applies the padding to an unnormalized coordinate, also returning the derivative of the
+/// padded coordinate.
+fn pad_coordinate(coord: f64, size: usize, options: &GridSampleOptions) -> (f64, f64) {
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => (coord, 1.0),
+        GridSamplePaddingMode::Border => clip(coord, size),
+        GridSamplePaddingMode::Reflection => {
+            let (coord, reflect_grad) = match options.align_corners {
+                true => reflect(coord, 0.0, 2.0 * (size as f64 - 1.0)),
+                false => reflect(coord, -1.0, 2.0 * size as f64 - 1.0),
+            };
+            let (coord, clip_grad) = clip(coord, size);
+
+            (coord, reflect_grad * clip_grad)
+        }
+    }
+}
+
+/// Applies the padding to the index of a tap.
+fn pad_index(index: f64, size: usize, options: &GridSampleOptions) -> f64 {
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => index,
+        _ => pad_coordinate(index, size, options).0,
+    }
+}
+
+/// Clips the coordinate to the input, with the derivative of the clipping.
+fn clip(coord: f64, size: usize) -> (f64, f64) {
+    let max = size as f64 - 1.0;
+
+    if coord <= 0.0 {
+        (0.0, 0.0)
+    } else if coord >= max {
+        (max, 0.0)
+    } else {
+        (coord, 1.0)
+    }
+}
+
+/// Reflects the coordinate at the bounds `twice_low / 2` and `twice_high / 2` until it is in
+/// bound, with the derivative of the reflection.
+fn reflect(coord: f64, twice_low: f64, twice_high: f64) -> (f64, f64) {
+    if twice_low == twice_high {
+        return (0.0, 0.0);
+    }
+
+    let min = twice_low / 2.0;
+    let span = (twice_high - twice_low) / 2.0;
+
+    let coord = coord - min;
+    let sign = if coord < 0.0 { -1.0 } else { 1.0 };
+    let coord = coord.abs();
+    let flips = (coord / span).floor();
+    let extra = coord - flips * span;
+
+    if flips % 2.0 == 0.0 {
+        (extra + min, sign)
+    } else {
+        (span - extra + min, -sign)
+    }
+}
+
+/// Computes the cubic convolution weight of the `i`-th tap, with its derivative with respect to
+/// `t`, the distance between the coordinate and the second tap.
+fn cubic_weight(t: f64, i: usize) -> (f64, f64) {
+    const A: f64 = CUBIC_A;
+    // Coefficients of the polynomials for distances in [0, 1] and [1, 2], highest degree first.
+    let near = [A + 2.0, -(A + 3.0), 0.0, 1.0];
+    let far = [A, -5.0 * A, 8.0 * A, -4.0 * A];
+
+    let (distance, [c3, c2, c1, c0], sign) = match i {
+        0 => (t + 1.0, far, 1.0),
+        1 => (t, near, 1.0),
+        2 => (1.0 - t, near, -1.0),
+        _ => (2.0 - t, far, -1.0),
+    };
+
+    let weight = ((c3 * distance + c2) * distance + c1) * distance + c0;
+    let weight_grad = ((3.0 * c3 * distance + 2.0 * c2) * distance + c1) * sign;
+
+    (weight, weight_grad)
+}
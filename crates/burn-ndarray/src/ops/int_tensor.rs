@@ -201,6 +201,22 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         NdArrayMathOps::prod_dim(tensor, dim)
     }
 
+    fn int_cumsum(tensor: NdArrayTensor<I>, dim: usize) -> NdArrayTensor<I> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod(tensor: NdArrayTensor<I>, dim: usize) -> NdArrayTensor<I> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax(tensor: NdArrayTensor<I>, dim: usize) -> (NdArrayTensor<I>, NdArrayTensor<I>) {
+        NdArrayMathOps::cummax(tensor, dim)
+    }
+
+    fn int_cummin(tensor: NdArrayTensor<I>, dim: usize) -> (NdArrayTensor<I>, NdArrayTensor<I>) {
+        NdArrayMathOps::cummin(tensor, dim)
+    }
+
     fn int_mean(tensor: NdArrayTensor<I>) -> NdArrayTensor<I> {
         NdArrayMathOps::mean(tensor)
     }
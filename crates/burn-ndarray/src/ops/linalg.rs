@@ -0,0 +1,10 @@
+use crate::{
+    NdArray,
+    element::{FloatNdArrayElement, IntNdArrayElement, QuantElement},
+};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> LinalgOps<Self>
+    for NdArray<E, I, Q>
+{
+}
@@ -1,7 +1,10 @@
 mod activations;
 mod base;
 mod bool_tensor;
+mod complex;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 #[cfg(feature = "simd")]
@@ -13,10 +16,13 @@ pub(crate) mod adaptive_avgpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
 pub(crate) mod deform_conv;
+pub(crate) mod grid_sample;
 pub(crate) mod interpolate;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
+pub(crate) mod multinomial;
 pub(crate) mod padding;
+pub(crate) mod special;
 
 pub(crate) use base::*;
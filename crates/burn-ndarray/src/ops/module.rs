@@ -3,6 +3,7 @@ use super::{
     avgpool::{avg_pool2d, avg_pool2d_backward},
     conv::{conv_transpose2d, conv_transpose3d, conv2d, conv3d},
     deform_conv::{backward::deform_conv2d_backward, deform_conv2d},
+    grid_sample::{grid_sample, grid_sample_backward},
     interpolate::{bicubic_interpolate, bilinear_interpolate, nearest_interpolate},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
 };
@@ -280,6 +281,34 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> ModuleOps<Se
         }
     }
 
+    fn grid_sample(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        module_op!(inp(x, grid), opt(), E, |x, grid| grid_sample::<E>(
+            x, grid, options
+        )
+        .into())
+    }
+
+    fn grid_sample_backward(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> GridSampleBackward<Self> {
+        module_op!(
+            inp(x, grid, output_grad),
+            opt(),
+            E,
+            |x, grid, output_grad| {
+                let (x_grad, grid_grad) = grid_sample_backward::<E>(x, grid, output_grad, options);
+                GridSampleBackward::new(x_grad.into(), grid_grad.into())
+            }
+        )
+    }
+
     fn conv3d(
         x: FloatTensor<Self>,
         weight: FloatTensor<Self>,
@@ -0,0 +1,61 @@
+use crate::{
+    NdArrayTensor, SEED,
+    element::{FloatNdArrayElement, IntNdArrayElement},
+};
+use alloc::vec::Vec;
+use burn_common::rand::get_seeded_rng;
+use burn_tensor::ElementConversion;
+use ndarray::{Array2, Axis};
+use rand::Rng;
+
+/// Samples `num_samples` category indices from the weights of each row of a 2D tensor.
+pub(crate) fn multinomial<E: FloatNdArrayElement, I: IntNdArrayElement>(
+    tensor: NdArrayTensor<E>,
+    num_samples: usize,
+    replacement: bool,
+) -> NdArrayTensor<I> {
+    let mut seed = SEED.lock().unwrap();
+    let mut rng = if let Some(rng_seeded) = seed.as_ref() {
+        rng_seeded.clone()
+    } else {
+        get_seeded_rng()
+    };
+
+    let batch_size = tensor.array.shape()[0];
+    let mut output = Array2::<I>::zeros((batch_size, num_samples));
+    let mut cumulative = Vec::new();
+
+    for (row, mut samples) in tensor
+        .array
+        .axis_iter(Axis(0))
+        .zip(output.axis_iter_mut(Axis(0)))
+    {
+        let mut weights: Vec<f64> = row.iter().map(|w| w.elem()).collect();
+
+        for sample in samples.iter_mut() {
+            cumulative.clear();
+            let mut total = 0.0;
+            for weight in weights.iter() {
+                total += weight;
+                cumulative.push(total);
+            }
+
+            let value = rng.random::<f64>() * total;
+            // Categories with a zero weight have the same cumulative weight as the previous
+            // one, so they are never selected.
+            let index = cumulative
+                .partition_point(|c| *c <= value)
+                .min(weights.len() - 1);
+
+            *sample = (index as i64).elem();
+
+            if !replacement {
+                weights[index] = 0.0;
+            }
+        }
+    }
+
+    *seed = Some(rng);
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
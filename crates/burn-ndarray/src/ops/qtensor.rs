@@ -1,13 +1,15 @@
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 use core::ops::Range;
 
 use burn_tensor::{
     DType, Shape, TensorData, TensorMetadata,
-    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
+    ops::{
+        ConvOptions, FloatTensor, IntTensor, ModuleOps, QTensorOps, QuantizedTensor,
+        conv::calculate_conv_output_size,
+    },
     quantization::{
         QParams, QuantInputType, QuantLevel, QuantMode, QuantScheme,
         QuantizationParametersPrimitive, QuantizationStrategy, QuantizedBytes,
-        SymmetricQuantization,
     },
 };
 
@@ -29,9 +31,63 @@ fn into_data_f(tensor: NdArrayTensorFloat) -> TensorData {
     match tensor {
         NdArrayTensorFloat::F32(tensor) => into_data(tensor),
         NdArrayTensorFloat::F64(tensor) => into_data(tensor),
+        NdArrayTensorFloat::E4M3(tensor) => into_data(tensor.convert::<f32>()),
+        NdArrayTensorFloat::E5M2(tensor) => into_data(tensor.convert::<f32>()),
     }
 }
 
+/// Splits the quantization parameters into a single set of parameters per quantization group.
+fn split_qparams(qparams: QParams<Vec<f32>>) -> Vec<QParams<f32>> {
+    qparams
+        .scales
+        .into_iter()
+        .enumerate()
+        .map(|(i, scales)| QParams {
+            scales,
+            offsets: qparams.offsets.as_ref().map(|offsets| offsets[i]),
+        })
+        .collect()
+}
+
+/// The scale of a tensor quantized to 8-bit integers with per-tensor symmetric quantization, for
+/// which the integer values can be used directly in integer arithmetic.
+fn int8_symmetric_scale<Q: QuantElement>(tensor: &NdArrayQTensor<Q>) -> Option<f32> {
+    match tensor.scheme {
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Symmetric,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => Some(tensor.qparams[0].scales),
+        _ => None,
+    }
+}
+
+/// The scales of a tensor quantized to 8-bit integers with per-tensor or per-channel (along the
+/// given axis) symmetric quantization.
+fn int8_symmetric_channel_scales<Q: QuantElement>(
+    tensor: &NdArrayQTensor<Q>,
+    axis: usize,
+) -> Option<Vec<f32>> {
+    match tensor.scheme {
+        QuantScheme {
+            level: QuantLevel::Channel(channel_axis),
+            mode: QuantMode::Symmetric,
+            q_type: QuantInputType::QInt8,
+            ..
+        } if channel_axis == axis => Some(tensor.qparams.iter().map(|q| q.scales).collect()),
+        _ => int8_symmetric_scale(tensor).map(|scale| vec![scale]),
+    }
+}
+
+fn int_values<Q: QuantElement>(tensor: NdArrayTensor<Q>) -> Vec<i32> {
+    tensor
+        .array
+        .iter()
+        .map(|value| value.elem::<i32>())
+        .collect()
+}
+
 impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<Self>
     for NdArray<E, I, Q>
 {
@@ -46,29 +102,15 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
                     num_elements,
                 };
 
-                match scheme {
-                    QuantScheme {
-                        level: QuantLevel::Tensor | QuantLevel::Block(_),
-                        mode: QuantMode::Symmetric,
-                        q_type: QuantInputType::QInt8,
-                        ..
-                    } => {
-                        // We should probably check that `Q` matches i8.. but it's the only valid type now
-                        let (values, qparams) = q_bytes.into_vec_i8();
-                        let data = TensorData::new(values, shape);
-
-                        let qparams = qparams
-                            .scales
-                            .into_iter()
-                            .map(|scales| QParams { scales })
-                            .collect();
-
-                        NdArrayQTensor {
-                            qtensor: NdArrayTensor::<Q>::from_data(data),
-                            scheme,
-                            qparams,
-                        }
-                    }
+                // We should probably check that `Q` matches i8.. but it's the only valid type now.
+                // The fp8 values are stored as their bits, and the 4-bit values are unpacked.
+                let (values, qparams) = q_bytes.into_vec_i8();
+                let data = TensorData::new(values, shape);
+
+                NdArrayQTensor {
+                    qtensor: NdArrayTensor::<Q>::from_data(data),
+                    scheme,
+                    qparams: split_qparams(qparams),
                 }
             }
             _ => panic!(
@@ -84,39 +126,16 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self> {
         // Implement with ndarray instead of QuantizationStrategy?
-        let (strategy, qparams) = match scheme {
-            QuantScheme {
-                level: QuantLevel::Tensor,
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => {
-                let scales = into_data_f(qparams.scales).iter().next().unwrap();
-                (
-                    QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                        scales,
-                    )),
-                    vec![QParams { scales }],
-                )
-            }
-            QuantScheme {
-                level: QuantLevel::Block(block_size),
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => {
-                let (strategy, qparams) = into_data_f(qparams.scales)
-                    .iter()
-                    .map(|s| (SymmetricQuantization::init(s), QParams { scales: s }))
-                    .unzip();
-                (
-                    QuantizationStrategy::PerBlockSymmetricInt8(strategy, *block_size),
-                    qparams,
-                )
-            }
+        let qparams = QParams {
+            scales: into_data_f(qparams.scales).iter().collect::<Vec<f32>>(),
+            offsets: qparams
+                .offsets
+                .map(|offsets| into_data_f(offsets).iter().collect::<Vec<f32>>()),
         };
-
         let shape = tensor.shape();
+        let strategy = QuantizationStrategy::from_qparams(scheme, &qparams, &shape.dims);
+        let qparams = split_qparams(qparams);
+
         let data = into_data_f(tensor).with_quantization(strategy);
         let num_elements = data.num_elements();
         let q_bytes = QuantizedBytes {
@@ -142,6 +161,142 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         new_tensor_float!(NdArrayTensor::from_data(data.dequantize().unwrap()))
     }
 
+    fn q_linear(
+        input: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self> {
+        let (Some(input_scale), Some(weight_scales)) = (
+            int8_symmetric_scale(&input),
+            int8_symmetric_channel_scales(&weight, 1),
+        ) else {
+            let output = Self::linear(Self::dequantize(input), Self::dequantize(weight), bias);
+            return Self::quantize(output, scheme, qparams);
+        };
+
+        let shape = input.qtensor.shape();
+        let [d_input, d_output] = weight.qtensor.shape().dims();
+        let num_rows = shape.num_elements() / d_input;
+        let x = int_values(input.qtensor);
+        let w = int_values(weight.qtensor);
+        let bias = bias.map(|bias| into_data_f(bias).iter::<f32>().collect::<Vec<_>>());
+
+        // Accumulate the products of the quantized values in i32, then rescale to float
+        let mut output = vec![0.0f32; num_rows * d_output];
+        for (row, out) in output.chunks_mut(d_output).enumerate() {
+            let mut acc = vec![0i32; d_output];
+            for (k, x) in x[row * d_input..(row + 1) * d_input].iter().enumerate() {
+                for (acc, w) in acc.iter_mut().zip(&w[k * d_output..(k + 1) * d_output]) {
+                    *acc += x * w;
+                }
+            }
+            for (j, (out, acc)) in out.iter_mut().zip(acc).enumerate() {
+                *out = acc as f32 * input_scale * weight_scales[j % weight_scales.len()]
+                    + bias.as_ref().map(|bias| bias[j]).unwrap_or(0.0);
+            }
+        }
+
+        let mut dims = shape.dims;
+        *dims.last_mut().unwrap() = d_output;
+        let output = TensorData::new(output, dims);
+        Self::quantize(
+            new_tensor_float!(NdArrayTensor::from_data(output)),
+            scheme,
+            qparams,
+        )
+    }
+
+    fn q_conv2d(
+        input: QuantizedTensor<Self>,
+        weight: QuantizedTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<2>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self> {
+        let (Some(input_scale), Some(weight_scales)) = (
+            int8_symmetric_scale(&input),
+            int8_symmetric_channel_scales(&weight, 0),
+        ) else {
+            let output = Self::conv2d(
+                Self::dequantize(input),
+                Self::dequantize(weight),
+                bias,
+                options,
+            );
+            return Self::quantize(output, scheme, qparams);
+        };
+
+        let [batch_size, channels_in, height_in, width_in] = input.qtensor.shape().dims();
+        let [channels_out, channels_per_group, kernel_h, kernel_w] = weight.qtensor.shape().dims();
+        let [stride_h, stride_w] = options.stride;
+        let [padding_h, padding_w] = options.padding;
+        let [dilation_h, dilation_w] = options.dilation;
+        let out_per_group = channels_out / options.groups;
+
+        let height_out =
+            calculate_conv_output_size(kernel_h, stride_h, padding_h, dilation_h, height_in);
+        let width_out =
+            calculate_conv_output_size(kernel_w, stride_w, padding_w, dilation_w, width_in);
+
+        let x = int_values(input.qtensor);
+        let w = int_values(weight.qtensor);
+        let bias = bias.map(|bias| into_data_f(bias).iter::<f32>().collect::<Vec<_>>());
+
+        let mut output = vec![0.0f32; batch_size * channels_out * height_out * width_out];
+        let mut index = 0;
+        for b in 0..batch_size {
+            for oc in 0..channels_out {
+                let group = oc / out_per_group;
+                let scale = input_scale * weight_scales[oc % weight_scales.len()];
+                let bias = bias.as_ref().map(|bias| bias[oc]).unwrap_or(0.0);
+
+                for oh in 0..height_out {
+                    for ow in 0..width_out {
+                        // The zero-point of symmetric quantization is zero, so the padded values
+                        // don't contribute to the accumulation.
+                        let mut acc = 0i32;
+                        for c in 0..channels_per_group {
+                            let ic = group * channels_per_group + c;
+                            for kh in 0..kernel_h {
+                                let ih =
+                                    (oh * stride_h + kh * dilation_h) as isize - padding_h as isize;
+                                if ih < 0 || ih >= height_in as isize {
+                                    continue;
+                                }
+                                for kw in 0..kernel_w {
+                                    let iw = (ow * stride_w + kw * dilation_w) as isize
+                                        - padding_w as isize;
+                                    if iw < 0 || iw >= width_in as isize {
+                                        continue;
+                                    }
+                                    let x = x[((b * channels_in + ic) * height_in + ih as usize)
+                                        * width_in
+                                        + iw as usize];
+                                    let w = w[((oc * channels_per_group + c) * kernel_h + kh)
+                                        * kernel_w
+                                        + kw];
+                                    acc += x * w;
+                                }
+                            }
+                        }
+                        output[index] = acc as f32 * scale + bias;
+                        index += 1;
+                    }
+                }
+            }
+        }
+
+        let output = TensorData::new(output, [batch_size, channels_out, height_out, width_out]);
+        Self::quantize(
+            new_tensor_float!(NdArrayTensor::from_data(output)),
+            scheme,
+            qparams,
+        )
+    }
+
     fn q_device(_tensor: &QuantizedTensor<Self>) -> NdArrayDevice {
         NdArrayDevice::Cpu
     }
@@ -0,0 +1,219 @@
+use core::f64::consts::{FRAC_2_SQRT_PI, PI};
+
+use burn_tensor::ElementConversion;
+use burn_tensor::cast::ToElement;
+
+use crate::{NdArrayTensorFloat, execute_with_float_dtype, tensor::NdArrayTensor};
+
+use super::NdArrayMathOps;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// Bernoulli numbers `B_2, B_4, ..., B_14` used by the asymptotic expansions of the digamma and
+/// Hurwitz zeta functions.
+const BERNOULLI: [f64; 7] = [
+    1.0 / 6.0,
+    -1.0 / 30.0,
+    1.0 / 42.0,
+    -1.0 / 30.0,
+    5.0 / 66.0,
+    -691.0 / 2730.0,
+    7.0 / 6.0,
+];
+
+/// Applies a scalar function element-wise, computed in double precision.
+pub(crate) fn unary(tensor: NdArrayTensorFloat, func: fn(f64) -> f64) -> NdArrayTensorFloat {
+    execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+        let array = tensor
+            .array
+            .mapv_into(|a| func(a.to_f64()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    })
+}
+
+/// Applies a scalar function element-wise on two broadcast tensors, computed in double precision.
+pub(crate) fn binary(
+    lhs: NdArrayTensorFloat,
+    rhs: NdArrayTensorFloat,
+    func: fn(f64, f64) -> f64,
+) -> NdArrayTensorFloat {
+    execute_with_float_dtype!((lhs, rhs), E, |lhs, rhs| NdArrayMathOps::elementwise_op(
+        lhs,
+        rhs,
+        |a: &E, b: &E| func(a.to_f64(), b.to_f64()).elem()
+    ))
+}
+
+/// Digamma function, using the reflection formula for `x < 0.5`, the recurrence
+/// `psi(x) = psi(x + 1) - 1 / x` and the asymptotic expansion for large `x`.
+pub(crate) fn digamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    if x <= 0.0 && x == x.floor() {
+        return if x == 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::NAN
+        };
+    }
+    if x < 0.5 {
+        return digamma(1.0 - x) - PI / (PI * x).tan();
+    }
+
+    let mut x = x;
+    let mut output = 0.0;
+    while x < 10.0 {
+        output -= 1.0 / x;
+        x += 1.0;
+    }
+
+    // ln(x) - 1 / (2x) - sum B_2k / (2k x^2k)
+    let x_inv2 = 1.0 / (x * x);
+    let mut power = x_inv2;
+    output += x.ln() - 0.5 / x;
+    for (k, bernoulli) in BERNOULLI.iter().enumerate() {
+        output -= bernoulli / (2.0 * (k + 1) as f64) * power;
+        power *= x_inv2;
+    }
+    output
+}
+
+/// Polygamma function of order `n`, computed from the Hurwitz zeta function with
+/// `psi_n(x) = (-1)^(n + 1) n! zeta(n + 1, x)` for `n >= 1`.
+pub(crate) fn polygamma(n: u32, x: f64) -> f64 {
+    if n == 0 {
+        return digamma(x);
+    }
+    if x <= 0.0 && x == x.floor() {
+        return f64::NAN;
+    }
+
+    let sign = if n.is_multiple_of(2) { -1.0 } else { 1.0 };
+    let factorial = (1..=n).map(|k| k as f64).product::<f64>();
+    sign * factorial * hurwitz_zeta((n + 1) as f64, x)
+}
+
+/// Hurwitz zeta function `sum (x + k)^-s` for an integer `s >= 2`, using the recurrence
+/// `zeta(s, x) = x^-s + zeta(s, x + 1)` up to the Euler-Maclaurin asymptotic expansion.
+fn hurwitz_zeta(s: f64, x: f64) -> f64 {
+    let mut x = x;
+    let mut output = 0.0;
+    while x < 10.0 + s {
+        output += x.powf(-s);
+        x += 1.0;
+    }
+
+    // x^-s (x / (s - 1) + 1 / 2 + sum B_2j / (2j)! s (s + 1) ... (s + 2j - 2) x^(1 - 2j))
+    let mut tail = x / (s - 1.0) + 0.5;
+    let mut factor = s / x;
+    let mut factorial = 2.0;
+    for (j, bernoulli) in BERNOULLI.iter().enumerate() {
+        tail += bernoulli / factorial * factor;
+        let next = s + 2.0 * j as f64;
+        factor *= (next + 1.0) * (next + 2.0) / (x * x);
+        factorial *= (2.0 * j as f64 + 3.0) * (2.0 * j as f64 + 4.0);
+    }
+    output + tail * x.powf(-s)
+}
+
+/// Inverse error function, using the single precision approximation from
+/// [Approximating the erfinv function](https://people.maths.ox.ac.uk/gilesm/files/gems_erfinv.pdf)
+/// refined with Newton's method.
+pub(crate) fn erfinv(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if x.abs() == 1.0 {
+        return x * f64::INFINITY;
+    }
+
+    let w = -((1.0 - x) * (1.0 + x)).ln();
+    let p = if w < 5.0 {
+        let w = w - 2.5;
+        [
+            3.432_739_39e-7,
+            -3.523_387_7e-6,
+            -4.391_506_54e-6,
+            2.185_808_7e-4,
+            -1.253_725_03e-3,
+            -4.177_681_64e-3,
+            2.466_407_27e-1,
+            1.501_409_41,
+        ]
+        .iter()
+        .fold(2.810_226_36e-8, |p, c| c + p * w)
+    } else {
+        let w = w.sqrt() - 3.0;
+        [
+            1.009_505_58e-4,
+            1.349_343_22e-3,
+            -3.673_428_44e-3,
+            5.739_507_73e-3,
+            -7.622_461_3e-3,
+            9.438_870_47e-3,
+            1.001_674_06,
+            2.832_976_82,
+        ]
+        .iter()
+        .fold(-2.002_142_57e-4, |p, c| c + p * w)
+    };
+
+    // Newton's method converges quadratically from the single precision approximation.
+    let mut output = p * x;
+    for _ in 0..2 {
+        let derivative = FRAC_2_SQRT_PI * (-output * output).exp();
+        output -= (libm::erf(output) - x) / derivative;
+    }
+    output
+}
+
+/// Modified Bessel function of the first kind of order zero.
+pub(crate) fn i0(x: f64) -> f64 {
+    bessel_i(0, x)
+}
+
+/// Modified Bessel function of the first kind of order one.
+pub(crate) fn i1(x: f64) -> f64 {
+    bessel_i(1, x)
+}
+
+/// Modified Bessel function of the first kind of order zero or one, using the power series for
+/// small inputs and the asymptotic expansion for large inputs.
+fn bessel_i(order: u32, x: f64) -> f64 {
+    let abs = x.abs();
+    let output = if abs <= 30.0 {
+        // sum (x/2)^(2k + n) / (k! (k + n)!)
+        let quarter = abs * abs / 4.0;
+        let mut term = if order == 0 { 1.0 } else { abs / 2.0 };
+        let mut output = term;
+        let mut k = 1.0;
+        while term > output * f64::EPSILON {
+            term *= quarter / (k * (k + order as f64));
+            output += term;
+            k += 1.0;
+        }
+        output
+    } else {
+        // e^x / sqrt(2 pi x) sum (-1)^k (mu - 1) (mu - 9) ... (mu - (2k - 1)^2) / (k! (8x)^k)
+        let mu = 4.0 * (order * order) as f64;
+        let mut term = 1.0;
+        let mut output = term;
+        for k in 1..30 {
+            let odd = (2 * k - 1) as f64;
+            term *= -(mu - odd * odd) / (k as f64 * 8.0 * abs);
+            output += term;
+        }
+        output * abs.exp() / (2.0 * PI * abs).sqrt()
+    };
+
+    if order == 1 && x < 0.0 {
+        -output
+    } else {
+        output
+    }
+}
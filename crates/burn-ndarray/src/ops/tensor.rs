@@ -5,31 +5,31 @@ use burn_tensor::ops::FloatTensor;
 use core::ops::Range;
 
 // Current crate
-use super::{NdArrayMathOps, NdArrayOps, matmul::matmul};
+use super::{NdArrayMathOps, NdArrayOps, matmul::matmul, multinomial::multinomial, special};
 use crate::element::{ExpElement, FloatNdArrayElement, IntNdArrayElement, QuantElement};
 use crate::{NdArray, tensor::NdArrayTensor};
 use crate::{NdArrayDevice, NdArrayTensorFloat, SEED, execute_with_float_dtype};
 
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
-use burn_tensor::{DType, Distribution, FloatDType};
+use burn_tensor::{DType, Distribution, Element, FloatDType, TensorMetadata};
 use burn_tensor::{ElementConversion, Shape, TensorData, backend::Backend, ops::FloatTensorOps};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::Float;
 
-use libm::erf;
+use libm::{erf, erfc, lgamma};
 
 #[cfg(feature = "std")]
 #[allow(dead_code)]
-fn round_ties_even_wrapper(x: f64) -> f64 {
+pub(crate) fn round_ties_even_wrapper(x: f64) -> f64 {
     x.round_ties_even()
 }
 
 #[cfg(not(feature = "std"))]
 #[allow(dead_code)]
-fn round_ties_even_wrapper(x: f64) -> f64 {
+pub(crate) fn round_ties_even_wrapper(x: f64) -> f64 {
     if (x - x.floor()) == 0.5 {
         (x * 0.5).round() * 2.0
     } else {
@@ -44,6 +44,8 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         match data.dtype {
             DType::F64 => NdArrayTensorFloat::F64(NdArrayTensor::from_data(data)),
             DType::F32 => NdArrayTensorFloat::F32(NdArrayTensor::from_data(data)),
+            DType::E4M3 => NdArrayTensorFloat::E4M3(NdArrayTensor::from_data(data)),
+            DType::E5M2 => NdArrayTensorFloat::E5M2(NdArrayTensor::from_data(data)),
             _ => unimplemented!("Unsupported dtype for `float_from_data`"),
         }
     }
@@ -71,6 +73,8 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         match tensor {
             NdArrayTensorFloat::F32(tensor) => NdArrayOps::into_data(tensor),
             NdArrayTensorFloat::F64(tensor) => NdArrayOps::into_data(tensor),
+            NdArrayTensorFloat::E4M3(tensor) => NdArrayOps::into_data(tensor),
+            NdArrayTensorFloat::E5M2(tensor) => NdArrayOps::into_data(tensor),
         }
     }
 
@@ -312,6 +316,14 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         execute_with_float_dtype!(tensor => |tensor| NdArrayMathOps::argmin(tensor, dim))
     }
 
+    fn float_multinomial(
+        tensor: FloatTensor<Self>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> NdArrayTensor<I> {
+        execute_with_float_dtype!(tensor => |tensor| multinomial(tensor, num_samples, replacement))
+    }
+
     fn float_exp(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
             let array = tensor.array.mapv_into(|a| a.exp_elem()).into_shared();
@@ -336,6 +348,66 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::prod_dim(tensor, dim))
     }
 
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::cumsum(tensor, dim))
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::cumprod(tensor, dim))
+    }
+
+    fn float_cummax(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, NdArrayTensor<I>) {
+        match tensor {
+            NdArrayTensorFloat::F32(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummax(tensor, dim);
+                (NdArrayTensorFloat::F32(output), indices)
+            }
+            NdArrayTensorFloat::F64(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummax(tensor, dim);
+                (NdArrayTensorFloat::F64(output), indices)
+            }
+            NdArrayTensorFloat::E4M3(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummax(tensor.convert::<f32>(), dim);
+                (NdArrayTensorFloat::E4M3(output.convert()), indices)
+            }
+            NdArrayTensorFloat::E5M2(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummax(tensor.convert::<f32>(), dim);
+                (NdArrayTensorFloat::E5M2(output.convert()), indices)
+            }
+        }
+    }
+
+    fn float_cummin(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, NdArrayTensor<I>) {
+        match tensor {
+            NdArrayTensorFloat::F32(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummin(tensor, dim);
+                (NdArrayTensorFloat::F32(output), indices)
+            }
+            NdArrayTensorFloat::F64(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummin(tensor, dim);
+                (NdArrayTensorFloat::F64(output), indices)
+            }
+            NdArrayTensorFloat::E4M3(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummin(tensor.convert::<f32>(), dim);
+                (NdArrayTensorFloat::E4M3(output.convert()), indices)
+            }
+            NdArrayTensorFloat::E5M2(tensor) => {
+                let (output, indices) = NdArrayMathOps::cummin(tensor.convert::<f32>(), dim);
+                (NdArrayTensorFloat::E5M2(output.convert()), indices)
+            }
+        }
+    }
+
+    fn float_logcumsumexp(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::logcumsumexp(tensor, dim))
+    }
+
     fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
             let array = tensor.array.mapv_into(|a| a.log1p_elem()).into_shared();
@@ -457,6 +529,85 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         })
     }
 
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::asin)
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::acos)
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::atan)
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::binary(lhs, rhs, f64::atan2)
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::asinh)
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::acosh)
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::atanh)
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, special::erfinv)
+    }
+
+    fn float_erfc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, erfc)
+    }
+
+    fn float_expm1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::exp_m1)
+    }
+
+    fn float_log2(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::log2)
+    }
+
+    fn float_log10(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, f64::log10)
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::binary(lhs, rhs, f64::hypot)
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, lgamma)
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, special::digamma)
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Self>, n: u32) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            let array = tensor
+                .array
+                .mapv_into(|a| special::polygamma(n, a.to_f64()).elem())
+                .into_shared();
+
+            NdArrayTensor::new(array)
+        })
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, special::i0)
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        special::unary(tensor, special::i1)
+    }
+
     fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
         match &tensors[0] {
             NdArrayTensorFloat::F32(_) => {
@@ -485,6 +636,18 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
                     .collect::<Vec<_>>();
                 NdArrayTensorFloat::F64(NdArrayOps::concatenate(&tensors, dim))
             }
+            // 8-bit floats are exactly represented in f32.
+            tensor => {
+                let dtype = tensor.dtype();
+                let tensors = tensors
+                    .into_iter()
+                    .map(|t| {
+                        assert_eq!(t.dtype(), dtype, "Concatenate data type mismatch");
+                        Self::float_cast(t, FloatDType::F32)
+                    })
+                    .collect();
+                Self::float_cast(Self::float_cat(tensors, dim), dtype.into())
+            }
         }
     }
 
@@ -542,25 +705,19 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
     }
 
     fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
-        fn cast<E1: FloatNdArrayElement, E2: FloatNdArrayElement>(
-            tensor: &NdArrayTensor<E1>,
-        ) -> NdArrayTensor<E2> {
-            let array = tensor.array.mapv(|a| a.elem()).into_shared();
-            NdArrayTensor { array }
+        fn cast<E1: Element, E2: Element>(tensor: NdArrayTensor<E1>) -> NdArrayTensor<E2> {
+            tensor.convert()
         }
 
-        match (&tensor, dtype) {
-            // No cast
-            (NdArrayTensorFloat::F32(_), FloatDType::F32)
-            | (NdArrayTensorFloat::F64(_), FloatDType::F64) => tensor,
-            // F32 to F64
-            (NdArrayTensorFloat::F32(tensor), FloatDType::F64) => {
-                NdArrayTensorFloat::F64(cast(tensor))
-            }
-            // F64 to F32
-            (NdArrayTensorFloat::F64(tensor), FloatDType::F32) => {
-                NdArrayTensorFloat::F32(cast(tensor))
-            }
+        if tensor.dtype() == dtype.into() {
+            return tensor;
+        }
+
+        match dtype {
+            FloatDType::F64 => NdArrayTensorFloat::F64(execute_with_float_dtype!(tensor => cast)),
+            FloatDType::F32 => NdArrayTensorFloat::F32(execute_with_float_dtype!(tensor => cast)),
+            FloatDType::E4M3 => NdArrayTensorFloat::E4M3(execute_with_float_dtype!(tensor => cast)),
+            FloatDType::E5M2 => NdArrayTensorFloat::E5M2(execute_with_float_dtype!(tensor => cast)),
             _ => panic!("Invalid cast types"),
         }
     }
@@ -1,11 +1,8 @@
 use core::mem;
 
 use burn_tensor::{
-    DType, Element, Shape, TensorData, TensorMetadata,
-    quantization::{
-        QParams, QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantScheme,
-        QuantizationStrategy, SymmetricQuantization,
-    },
+    DType, Element, Shape, TensorData, TensorMetadata, e4m3, e5m2,
+    quantization::{QParams, QTensorPrimitive, QuantScheme, QuantizationStrategy},
 };
 
 use alloc::vec::Vec;
@@ -37,6 +34,10 @@ pub enum NdArrayTensorFloat {
     F32(NdArrayTensor<f32>),
     /// 64-bit float.
     F64(NdArrayTensor<f64>),
+    /// 8-bit float with 4 exponent bits and 3 mantissa bits.
+    E4M3(NdArrayTensor<e4m3>),
+    /// 8-bit float with 5 exponent bits and 2 mantissa bits.
+    E5M2(NdArrayTensor<e5m2>),
 }
 
 impl From<NdArrayTensor<f32>> for NdArrayTensorFloat {
@@ -56,6 +57,8 @@ impl TensorMetadata for NdArrayTensorFloat {
         match self {
             NdArrayTensorFloat::F32(tensor) => tensor.dtype(),
             NdArrayTensorFloat::F64(tensor) => tensor.dtype(),
+            NdArrayTensorFloat::E4M3(tensor) => tensor.dtype(),
+            NdArrayTensorFloat::E5M2(tensor) => tensor.dtype(),
         }
     }
 
@@ -63,6 +66,8 @@ impl TensorMetadata for NdArrayTensorFloat {
         match self {
             NdArrayTensorFloat::F32(tensor) => tensor.shape(),
             NdArrayTensorFloat::F64(tensor) => tensor.shape(),
+            NdArrayTensorFloat::E4M3(tensor) => tensor.shape(),
+            NdArrayTensorFloat::E5M2(tensor) => tensor.shape(),
         }
     }
 }
@@ -83,6 +88,9 @@ macro_rules! new_tensor_float {
 
 /// Macro to execute an operation a given element type.
 ///
+/// Operations on 8-bit floats are computed with f32 values, which are rounded back to the 8-bit
+/// format when the output is a float tensor.
+///
 /// # Panics
 /// Since there is no automatic type cast at this time, binary operations for different
 /// floating point precision data types will panic with a data type mismatch.
@@ -99,6 +107,16 @@ macro_rules! execute_with_float_dtype {
             ($crate::NdArrayTensorFloat::F32(lhs), $crate::NdArrayTensorFloat::F32(rhs)) => {
                 $crate::NdArrayTensorFloat::F32($op(lhs, rhs))
             }
+            ($crate::NdArrayTensorFloat::E4M3(lhs), $crate::NdArrayTensorFloat::E4M3(rhs)) => {
+                $crate::NdArrayTensorFloat::E4M3(
+                    $op(lhs.convert::<f32>(), rhs.convert::<f32>()).convert(),
+                )
+            }
+            ($crate::NdArrayTensorFloat::E5M2(lhs), $crate::NdArrayTensorFloat::E5M2(rhs)) => {
+                $crate::NdArrayTensorFloat::E5M2(
+                    $op(lhs.convert::<f32>(), rhs.convert::<f32>()).convert(),
+                )
+            }
             _ => panic!(
                 "Data type mismatch (lhs: {:?}, rhs: {:?})",
                 lhs_dtype, rhs_dtype
@@ -119,6 +137,18 @@ macro_rules! execute_with_float_dtype {
                 type $element = f32;
                 $crate::NdArrayTensorFloat::F32($op(lhs, rhs))
             }
+            ($crate::NdArrayTensorFloat::E4M3(lhs), $crate::NdArrayTensorFloat::E4M3(rhs)) => {
+                type $element = f32;
+                $crate::NdArrayTensorFloat::E4M3(
+                    $op(lhs.convert::<f32>(), rhs.convert::<f32>()).convert(),
+                )
+            }
+            ($crate::NdArrayTensorFloat::E5M2(lhs), $crate::NdArrayTensorFloat::E5M2(rhs)) => {
+                type $element = f32;
+                $crate::NdArrayTensorFloat::E5M2(
+                    $op(lhs.convert::<f32>(), rhs.convert::<f32>()).convert(),
+                )
+            }
             _ => panic!(
                 "Data type mismatch (lhs: {:?}, rhs: {:?})",
                 lhs_dtype, rhs_dtype
@@ -137,6 +167,12 @@ macro_rules! execute_with_float_dtype {
             ($crate::NdArrayTensorFloat::F32(lhs), $crate::NdArrayTensorFloat::F32(rhs)) => {
                 $op(lhs, rhs)
             }
+            ($crate::NdArrayTensorFloat::E4M3(lhs), $crate::NdArrayTensorFloat::E4M3(rhs)) => {
+                $op(lhs.convert::<f32>(), rhs.convert::<f32>())
+            }
+            ($crate::NdArrayTensorFloat::E5M2(lhs), $crate::NdArrayTensorFloat::E5M2(rhs)) => {
+                $op(lhs.convert::<f32>(), rhs.convert::<f32>())
+            }
             _ => panic!(
                 "Data type mismatch (lhs: {:?}, rhs: {:?})",
                 lhs_dtype, rhs_dtype
@@ -149,6 +185,12 @@ macro_rules! execute_with_float_dtype {
         match $tensor {
             $crate::NdArrayTensorFloat::F64(tensor) => $crate::NdArrayTensorFloat::F64($op(tensor)),
             $crate::NdArrayTensorFloat::F32(tensor) => $crate::NdArrayTensorFloat::F32($op(tensor)),
+            $crate::NdArrayTensorFloat::E4M3(tensor) => {
+                $crate::NdArrayTensorFloat::E4M3($op(tensor.convert::<f32>()).convert())
+            }
+            $crate::NdArrayTensorFloat::E5M2(tensor) => {
+                $crate::NdArrayTensorFloat::E5M2($op(tensor.convert::<f32>()).convert())
+            }
         }
     }};
 
@@ -163,6 +205,14 @@ macro_rules! execute_with_float_dtype {
                 type $element = f32;
                 $crate::NdArrayTensorFloat::F32($op(tensor))
             }
+            $crate::NdArrayTensorFloat::E4M3(tensor) => {
+                type $element = f32;
+                $crate::NdArrayTensorFloat::E4M3($op(tensor.convert::<f32>()).convert())
+            }
+            $crate::NdArrayTensorFloat::E5M2(tensor) => {
+                type $element = f32;
+                $crate::NdArrayTensorFloat::E5M2($op(tensor.convert::<f32>()).convert())
+            }
         }
     }};
 
@@ -171,6 +221,8 @@ macro_rules! execute_with_float_dtype {
         match $tensor {
             $crate::NdArrayTensorFloat::F64(tensor) => $op(tensor),
             $crate::NdArrayTensorFloat::F32(tensor) => $op(tensor),
+            $crate::NdArrayTensorFloat::E4M3(tensor) => $op(tensor.convert::<f32>()),
+            $crate::NdArrayTensorFloat::E5M2(tensor) => $op(tensor.convert::<f32>()),
         }
     }};
 
@@ -185,6 +237,14 @@ macro_rules! execute_with_float_dtype {
                 type $element = f32;
                 $op(tensor)
             }
+            $crate::NdArrayTensorFloat::E4M3(tensor) => {
+                type $element = f32;
+                $op(tensor.convert::<f32>())
+            }
+            $crate::NdArrayTensorFloat::E5M2(tensor) => {
+                type $element = f32;
+                $op(tensor.convert::<f32>())
+            }
         }
     }};
 }
@@ -234,6 +294,12 @@ mod utils {
             }
             is_contiguous(shape, &strides)
         }
+
+        /// Converts the elements of the tensor to another element type.
+        pub(crate) fn convert<T: Element>(&self) -> NdArrayTensor<T> {
+            let array = self.array.mapv(|a| a.elem()).into_shared();
+            NdArrayTensor { array }
+        }
     }
 }
 
@@ -329,28 +395,11 @@ pub struct NdArrayQTensor<Q: QuantElement> {
 impl<Q: QuantElement> NdArrayQTensor<Q> {
     /// Returns the quantization strategy, including quantization parameters, for the given tensor.
     pub fn strategy(&self) -> QuantizationStrategy {
-        match self.scheme {
-            QuantScheme {
-                level: QuantLevel::Tensor,
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                self.qparams[0].scales,
-            )),
-            QuantScheme {
-                level: QuantLevel::Block(block_size),
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => QuantizationStrategy::PerBlockSymmetricInt8(
-                self.qparams
-                    .iter()
-                    .map(|q| SymmetricQuantization::init(q.scales))
-                    .collect(),
-                block_size,
-            ),
-        }
+        let qparams = QParams {
+            scales: self.qparams.iter().map(|q| q.scales).collect(),
+            offsets: self.qparams.iter().map(|q| q.offsets).collect(),
+        };
+        QuantizationStrategy::from_qparams(&self.scheme, &qparams, &self.qtensor.shape().dims)
     }
 }
 
@@ -377,9 +426,12 @@ mod tests {
     use super::*;
     use burn_common::rand::get_seeded_rng;
     use burn_tensor::{
-        Distribution,
+        Distribution, FloatDType,
         ops::{FloatTensorOps, QTensorOps},
-        quantization::QuantizationParametersPrimitive,
+        quantization::{
+            IntQuantization, QuantInputType, QuantLevel, QuantMode,
+            QuantizationParametersPrimitive, ScaledFloat8Quantization, SymmetricQuantization,
+        },
     };
 
     #[test]
@@ -448,6 +500,7 @@ mod tests {
         let scheme = QuantScheme::default();
         let qparams = QuantizationParametersPrimitive {
             scales: B::float_from_data(TensorData::from([scale]), &device),
+            offsets: None,
         };
         let qtensor: NdArrayQTensor<i8> = B::quantize(tensor, &scheme, qparams);
 
@@ -457,4 +510,91 @@ mod tests {
             QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(scale))
         );
     }
+
+    #[test]
+    fn should_support_qtensor_strategy_fp8() {
+        type B = NdArray<f32, i64, i8>;
+        let scale: f32 = 0.004_017_857;
+        let device = Default::default();
+
+        let tensor = B::float_from_data(TensorData::from([-1.8f32, -1.0, 0.0, 0.5]), &device);
+        let scheme = QuantScheme::default().set_q_type(QuantInputType::E4M3);
+        let qparams = QuantizationParametersPrimitive {
+            scales: B::float_from_data(TensorData::from([scale]), &device),
+            offsets: None,
+        };
+        let qtensor: NdArrayQTensor<i8> = B::quantize(tensor, &scheme, qparams);
+
+        assert_eq!(qtensor.scheme(), &scheme);
+        assert_eq!(
+            qtensor.strategy(),
+            QuantizationStrategy::PerTensorScaledE4M3(ScaledFloat8Quantization::init(scale))
+        );
+    }
+
+    #[test]
+    fn should_support_qtensor_strategy_per_channel_affine() {
+        type B = NdArray<f32, i64, i8>;
+        let device = Default::default();
+
+        let tensor = B::float_from_data(
+            TensorData::from([[-1.8f32, -1.0, 0.0, 0.5], [0.0, 0.5, 1.0, 1.5]]),
+            &device,
+        );
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(0))
+            .set_mode(QuantMode::Affine)
+            .set_q_type(QuantInputType::QUInt4);
+        let qparams = QuantizationParametersPrimitive {
+            scales: B::float_from_data(TensorData::from([0.153_333_3f32, 0.1]), &device),
+            offsets: Some(B::float_from_data(
+                TensorData::from([12.0f32, 0.0]),
+                &device,
+            )),
+        };
+        let qtensor: NdArrayQTensor<i8> = B::quantize(tensor, &scheme, qparams);
+
+        assert_eq!(qtensor.scheme(), &scheme);
+        assert_eq!(
+            qtensor.strategy(),
+            QuantizationStrategy::PerChannelInt(
+                vec![
+                    IntQuantization::affine(0.153_333_3, 12, QuantInputType::QUInt4),
+                    IntQuantization::affine(0.1, 0, QuantInputType::QUInt4),
+                ],
+                0,
+                4
+            )
+        );
+        assert_eq!(
+            qtensor.qtensor.array.into_iter().collect::<Vec<_>>(),
+            vec![0, 5, 12, 15, 0, 5, 10, 15]
+        );
+    }
+
+    #[test]
+    fn should_cast_to_fp8() {
+        type B = NdArray<f32>;
+        let device = Default::default();
+
+        let tensor = B::float_from_data(TensorData::from([1.07f32, -500.0, 0.3, 1e6]), &device);
+        let into_f32 = |tensor| match B::float_cast(tensor, FloatDType::F32) {
+            NdArrayTensorFloat::F32(tensor) => tensor.into_data(),
+            _ => unreachable!(),
+        };
+
+        let output = B::float_cast(tensor.clone(), FloatDType::E4M3);
+        assert_eq!(output.dtype(), DType::E4M3);
+        into_f32(output.clone())
+            .assert_eq(&TensorData::from([1.125f32, -448.0, 0.3125, 448.0]), true);
+
+        // Operations keep the 8-bit format, saturating to its largest finite value.
+        let output = B::float_add(output.clone(), output);
+        assert_eq!(output.dtype(), DType::E4M3);
+        into_f32(output).assert_eq(&TensorData::from([2.25f32, -448.0, 0.625, 448.0]), true);
+
+        let output = B::float_cast(tensor, FloatDType::E5M2);
+        assert_eq!(output.dtype(), DType::E5M2);
+        into_f32(output).assert_eq(&TensorData::from([1.0f32, -512.0, 0.3125, 57344.0]), true);
+    }
 }
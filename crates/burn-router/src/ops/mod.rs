@@ -1,8 +1,11 @@
 mod binary;
 mod op_activation;
 mod op_bool;
+mod op_complex;
+mod op_fft;
 mod op_float;
 mod op_int;
+mod op_linalg;
 mod op_module;
 mod op_qfloat;
 mod transaction;
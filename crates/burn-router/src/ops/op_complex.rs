@@ -0,0 +1,5 @@
+use burn_tensor::ops::ComplexOps;
+
+use crate::{BackendRouter, RunnerChannel};
+
+impl<R: RunnerChannel> ComplexOps<Self> for BackendRouter<R> {}
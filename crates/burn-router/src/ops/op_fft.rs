@@ -0,0 +1,32 @@
+use burn_ir::{FftOpIr, FloatOperationIr, OperationIr};
+use burn_tensor::ops::{FftOps, FloatTensor};
+
+use crate::{BackendRouter, RunnerChannel, RunnerClient};
+
+impl<R: RunnerChannel> FftOps<Self> for BackendRouter<R> {
+    fn fft(
+        real: FloatTensor<Self>,
+        imag: FloatTensor<Self>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let client = real.client.clone();
+        let dtype = real.dtype;
+        let shape = real.shape.clone();
+        let out_real = client.register_empty_tensor(shape.clone(), dtype);
+        let out_imag = client.register_empty_tensor(shape, dtype);
+
+        let desc = FftOpIr {
+            real: real.into_ir(),
+            imag: imag.into_ir(),
+            dim,
+            inverse,
+            out_real: out_real.to_ir_out(),
+            out_imag: out_imag.to_ir_out(),
+        };
+
+        client.register(OperationIr::Float(dtype, FloatOperationIr::Fft(desc)));
+
+        (out_real, out_imag)
+    }
+}
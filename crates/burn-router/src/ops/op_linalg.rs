@@ -0,0 +1,4 @@
+use crate::{BackendRouter, RunnerChannel};
+use burn_tensor::ops::LinalgOps;
+
+impl<R: RunnerChannel> LinalgOps<Self> for BackendRouter<R> {}
@@ -4,10 +4,10 @@ use burn_ir::{
     AdaptiveAvgPool1dBackwardOpIr, AdaptiveAvgPool1dOpIr, AdaptiveAvgPool2dBackwardOpIr,
     AdaptiveAvgPool2dOpIr, AvgPool1dBackwardOpIr, AvgPool1dOpIr, AvgPool2dBackwardOpIr,
     AvgPool2dOpIr, Conv1dOpIr, Conv2dOpIr, Conv3dOpIr, ConvTranspose1dOpIr, ConvTranspose2dOpIr,
-    ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr, InterpolateBackwardOpIr,
-    InterpolateOpIr, MaxPool1dOpIr, MaxPool1dWithIndicesBackwardOpIr, MaxPool1dWithIndicesOpIr,
-    MaxPool2dOpIr, MaxPool2dWithIndicesBackwardOpIr, MaxPool2dWithIndicesOpIr, ModuleOperationIr,
-    OperationIr,
+    ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr, GridSampleBackwardOpIr,
+    GridSampleOpIr, InterpolateBackwardOpIr, InterpolateOpIr, MaxPool1dOpIr,
+    MaxPool1dWithIndicesBackwardOpIr, MaxPool1dWithIndicesOpIr, MaxPool2dOpIr,
+    MaxPool2dWithIndicesBackwardOpIr, MaxPool2dWithIndicesOpIr, ModuleOperationIr, OperationIr,
 };
 use burn_tensor::Element;
 use burn_tensor::ops::conv::{
@@ -15,7 +15,7 @@ use burn_tensor::ops::conv::{
 };
 use burn_tensor::ops::{
     ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, FloatTensor,
-    IntElem, ModuleOps,
+    GridSampleBackward, GridSampleOptions, IntElem, ModuleOps,
 };
 use burn_tensor::ops::{
     IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
@@ -710,6 +710,55 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         out
     }
 
+    fn grid_sample(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        let mut shape = vec![x.shape[0], x.shape[1]];
+        shape.extend(&grid.shape[1..grid.shape.len() - 1]);
+
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape, x.dtype);
+
+        let desc = GridSampleOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            options: options.into(),
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::GridSample(desc)));
+
+        out
+    }
+
+    fn grid_sample_backward(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> GridSampleBackward<Self> {
+        let client = x.client.clone();
+        let x_grad = client.register_empty_tensor(x.shape.clone(), x.dtype);
+        let grid_grad = client.register_empty_tensor(grid.shape.clone(), grid.dtype);
+
+        let desc = GridSampleBackwardOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            out_grad: output_grad.into_ir(),
+            options: options.into(),
+            x_grad: x_grad.to_ir_out(),
+            grid_grad: grid_grad.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::GridSampleBackward(
+            desc,
+        )));
+
+        GridSampleBackward::new(x_grad, grid_grad)
+    }
+
     fn deform_conv2d(
         x: FloatTensor<Self>,
         offset: FloatTensor<Self>,
@@ -487,6 +487,26 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 NumericOperationIr::ProdDim(desc) => {
                     reduce_float_dim_ops!(handles, desc, B::float_prod_dim)
                 }
+                NumericOperationIr::CumSum(desc) => {
+                    reduce_float_dim_ops!(handles, desc, B::float_cumsum)
+                }
+                NumericOperationIr::CumProd(desc) => {
+                    reduce_float_dim_ops!(handles, desc, B::float_cumprod)
+                }
+                NumericOperationIr::CumMax(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::float_cummax(tensor, desc.dim);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
+                NumericOperationIr::CumMin(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::float_cummin(tensor, desc.dim);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
                 NumericOperationIr::EqualElem(desc) => {
                     scalar_float_cmp_ops!(handles, desc, B::float_equal_elem)
                 }
@@ -675,6 +695,26 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 NumericOperationIr::ProdDim(desc) => {
                     reduce_int_dim_ops!(handles, desc, B::int_prod_dim)
                 }
+                NumericOperationIr::CumSum(desc) => {
+                    reduce_int_dim_ops!(handles, desc, B::int_cumsum)
+                }
+                NumericOperationIr::CumProd(desc) => {
+                    reduce_int_dim_ops!(handles, desc, B::int_cumprod)
+                }
+                NumericOperationIr::CumMax(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::int_cummax(tensor, desc.dim);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
+                NumericOperationIr::CumMin(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::int_cummin(tensor, desc.dim);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
                 NumericOperationIr::EqualElem(desc) => {
                     scalar_int_cmp_ops!(handles, desc, B::int_equal_elem)
                 }
@@ -840,6 +880,90 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 FloatOperationIr::Erf(desc) => {
                     unary_float_ops!(handles, desc, B::float_erf)
                 }
+                FloatOperationIr::Asin(desc) => {
+                    unary_float_ops!(handles, desc, B::float_asin)
+                }
+                FloatOperationIr::Acos(desc) => {
+                    unary_float_ops!(handles, desc, B::float_acos)
+                }
+                FloatOperationIr::Atan(desc) => {
+                    unary_float_ops!(handles, desc, B::float_atan)
+                }
+                FloatOperationIr::Asinh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_asinh)
+                }
+                FloatOperationIr::Acosh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_acosh)
+                }
+                FloatOperationIr::Atanh(desc) => {
+                    unary_float_ops!(handles, desc, B::float_atanh)
+                }
+                FloatOperationIr::Erfc(desc) => {
+                    unary_float_ops!(handles, desc, B::float_erfc)
+                }
+                FloatOperationIr::Erfinv(desc) => {
+                    unary_float_ops!(handles, desc, B::float_erfinv)
+                }
+                FloatOperationIr::Expm1(desc) => {
+                    unary_float_ops!(handles, desc, B::float_expm1)
+                }
+                FloatOperationIr::Log2(desc) => {
+                    unary_float_ops!(handles, desc, B::float_log2)
+                }
+                FloatOperationIr::Log10(desc) => {
+                    unary_float_ops!(handles, desc, B::float_log10)
+                }
+                FloatOperationIr::Lgamma(desc) => {
+                    unary_float_ops!(handles, desc, B::float_lgamma)
+                }
+                FloatOperationIr::Digamma(desc) => {
+                    unary_float_ops!(handles, desc, B::float_digamma)
+                }
+                FloatOperationIr::I0(desc) => {
+                    unary_float_ops!(handles, desc, B::float_i0)
+                }
+                FloatOperationIr::I1(desc) => {
+                    unary_float_ops!(handles, desc, B::float_i1)
+                }
+                FloatOperationIr::Atan2(desc) => {
+                    binary_float_ops!(handles, desc, B::float_atan2)
+                }
+                FloatOperationIr::Hypot(desc) => {
+                    binary_float_ops!(handles, desc, B::float_hypot)
+                }
+                FloatOperationIr::Polygamma(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_polygamma(tensor, desc.order);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                FloatOperationIr::LogCumSumExp(desc) => {
+                    reduce_float_dim_ops!(handles, desc, B::float_logcumsumexp)
+                }
+                FloatOperationIr::LogSumExp(desc) => {
+                    reduce_float_dim_ops!(handles, desc, B::float_logsumexp)
+                }
+                FloatOperationIr::SumMaskedDim(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+                    let mask = handles.get_bool_tensor::<B>(&desc.mask);
+
+                    let output = B::float_sum_masked_dim(tensor, mask, desc.axis);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                FloatOperationIr::Fft(desc) => {
+                    let real = handles.get_float_tensor::<B>(&desc.real);
+                    let imag = handles.get_float_tensor::<B>(&desc.imag);
+
+                    let (output_real, output_imag) = B::fft(real, imag, desc.dim, desc.inverse);
+                    handles.register_float_tensor::<B>(&desc.out_real.id, output_real);
+                    handles.register_float_tensor::<B>(&desc.out_imag.id, output_imag);
+                }
+                FloatOperationIr::Multinomial(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_multinomial(tensor, desc.num_samples, desc.replacement);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
                 FloatOperationIr::PowfScalar(desc) => {
                     scalar_float_ops!(handles, desc, B::float_powf_scalar)
                 }
@@ -1204,6 +1328,24 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     );
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                ModuleOperationIr::GridSample(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let grid = handles.get_float_tensor::<B>(&desc.grid);
+
+                    let output = B::grid_sample(x, grid, desc.options.clone().into());
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationIr::GridSampleBackward(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let grid = handles.get_float_tensor::<B>(&desc.grid);
+                    let output_grad = handles.get_float_tensor::<B>(&desc.out_grad);
+
+                    let output =
+                        B::grid_sample_backward(x, grid, output_grad, desc.options.clone().into());
+
+                    handles.register_float_tensor::<B>(&desc.x_grad.id, output.x_grad);
+                    handles.register_float_tensor::<B>(&desc.grid_grad.id, output.grid_grad);
+                }
             },
             OperationIr::Custom(_) => {
                 panic!("Can't execute custom operation here")
@@ -0,0 +1,36 @@
+#[cfg(feature = "ndarray")]
+mod ndarray {
+    use crate::SparseBackend;
+    use burn_ndarray::{FloatNdArrayElement, IntNdArrayElement, NdArray, QuantElement};
+
+    impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> SparseBackend
+        for NdArray<E, I, Q>
+    {
+    }
+}
+
+#[cfg(feature = "autodiff")]
+mod autodiff {
+    use crate::SparseBackend;
+    use burn_autodiff::{Autodiff, checkpoint::strategy::CheckpointStrategy};
+
+    // The default implementations are composed of differentiable tensor operations, so the
+    // gradients flow to the dense operands and the sparse values.
+    impl<B: SparseBackend, C: CheckpointStrategy> SparseBackend for Autodiff<B, C> {}
+}
+
+#[cfg(feature = "candle")]
+mod candle {
+    use crate::SparseBackend;
+    use burn_candle::{Candle, FloatCandleElement, IntCandleElement};
+
+    impl<F: FloatCandleElement, I: IntCandleElement> SparseBackend for Candle<F, I> {}
+}
+
+#[cfg(feature = "tch")]
+mod tch {
+    use crate::SparseBackend;
+    use burn_tch::{LibTorch, TchElement};
+
+    impl<E: TchElement, Q: burn_tch::QuantElement> SparseBackend for LibTorch<E, Q> {}
+}
@@ -0,0 +1,240 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    Int, Shape, Tensor, TensorMetadata, TensorPrimitive,
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+};
+
+/// Sparse tensor operations.
+///
+/// The sparse tensors are described by dense tensors: the indices of the non-zero values and the
+/// values themselves. The default implementations are composed of dense tensor operations, which
+/// makes them differentiable with respect to the values and the dense operands on autodiff
+/// backends. Backends with native sparse kernels can override them.
+pub trait SparseBackend: Backend {
+    /// Converts a sparse tensor in the coordinate format to a dense tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The indices of the values, of shape `[D, nnz]`.
+    /// * `values` - The values, of shape `[nnz]`.
+    /// * `shape` - The shape of the dense tensor.
+    ///
+    /// # Returns
+    ///
+    /// The dense tensor, where the values with duplicate indices are summed.
+    fn sparse_coo_to_dense(
+        indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        shape: Shape,
+    ) -> FloatTensor<Self> {
+        let indices = Tensor::<Self, 2, Int>::from_primitive(indices);
+        let values = Tensor::<Self, 1>::from_primitive(TensorPrimitive::Float(values));
+        let device = values.device();
+
+        let linear = linear_indices(indices, &shape);
+        let dense = Tensor::<Self, 1>::zeros([shape.num_elements()], &device)
+            .select_assign(0, linear, values);
+
+        Self::float_reshape(dense.into_primitive().tensor(), shape)
+    }
+
+    /// Converts a dense tensor to a sparse tensor in the coordinate format.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the non-zero values, of shape `[D, nnz]`, in row-major order, and the
+    /// non-zero values, of shape `[nnz]`.
+    ///
+    /// # Remarks
+    ///
+    /// The number of non-zero values depends on the data, so the tensor is read to determine the
+    /// output size, which might cause a synchronization.
+    fn sparse_coo_from_dense(tensor: FloatTensor<Self>) -> (IntTensor<Self>, FloatTensor<Self>) {
+        let shape = tensor.shape();
+        let num_elements = shape.num_elements();
+        let tensor = Tensor::<Self, 1>::from_primitive(TensorPrimitive::Float(
+            Self::float_reshape(tensor, Shape::new([num_elements])),
+        ));
+
+        let linear = tensor
+            .clone()
+            .not_equal_elem(0)
+            .argwhere()
+            .flatten::<1>(0, 1);
+        let values = tensor.select(0, linear.clone());
+        let indices = unravel_indices(linear, &shape);
+
+        (indices.into_primitive(), values.into_primitive().tensor())
+    }
+
+    /// Sorts the values of a sparse tensor in the coordinate format in row-major order and sums
+    /// the values with duplicate indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The indices of the values, of shape `[D, nnz]`.
+    /// * `values` - The values, of shape `[nnz]`.
+    /// * `shape` - The shape of the dense tensor.
+    ///
+    /// # Returns
+    ///
+    /// The unique indices and their values.
+    ///
+    /// # Remarks
+    ///
+    /// The number of unique indices depends on the data, so the indices are read to determine the
+    /// output size, which might cause a synchronization.
+    fn sparse_coo_coalesce(
+        indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        shape: Shape,
+    ) -> (IntTensor<Self>, FloatTensor<Self>) {
+        let indices = Tensor::<Self, 2, Int>::from_primitive(indices);
+        let values = Tensor::<Self, 1>::from_primitive(TensorPrimitive::Float(values));
+
+        if values.dims()[0] == 0 {
+            return (indices.into_primitive(), values.into_primitive().tensor());
+        }
+
+        let linear = linear_indices(indices, &shape);
+        let (unique, inverse, _counts) = linear.unique_with_inverse_and_counts();
+        let values = Tensor::<Self, 1>::zeros([unique.dims()[0]], &values.device())
+            .select_assign(0, inverse, values);
+        let indices = unravel_indices(unique, &shape);
+
+        (indices.into_primitive(), values.into_primitive().tensor())
+    }
+
+    /// Multiplies a sparse matrix in the coordinate format with a dense matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The indices of the values, of shape `[2, nnz]`.
+    /// * `values` - The values, of shape `[nnz]`.
+    /// * `shape` - The shape `[m, k]` of the sparse matrix.
+    /// * `dense` - The dense matrix, of shape `[k, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The dense product, of shape `[m, n]`.
+    fn sparse_coo_spmm(
+        indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        shape: Shape,
+        dense: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let indices = Tensor::<Self, 2, Int>::from_primitive(indices);
+        let [_, nnz] = indices.dims();
+        let rows = indices.clone().slice([0..1]).reshape([nnz]);
+        let cols = indices.slice([1..2]).reshape([nnz]);
+
+        sparse_matmul::<Self>(rows, cols, values, shape.dims[0], dense)
+    }
+
+    /// Multiplies a sparse matrix in the compressed sparse row format with a dense matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `crow_indices` - The offset of each row in the values, of shape `[m + 1]`.
+    /// * `col_indices` - The column of each value, of shape `[nnz]`.
+    /// * `values` - The values, of shape `[nnz]`.
+    /// * `shape` - The shape `[m, k]` of the sparse matrix.
+    /// * `dense` - The dense matrix, of shape `[k, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The dense product, of shape `[m, n]`.
+    fn sparse_csr_spmm(
+        crow_indices: IntTensor<Self>,
+        col_indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        shape: Shape,
+        dense: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let cols = Tensor::<Self, 1, Int>::from_primitive(col_indices);
+        let rows = csr_row_indices(Tensor::from_primitive(crow_indices), cols.dims()[0]);
+
+        sparse_matmul::<Self>(rows, cols, values, shape.dims[0], dense)
+    }
+}
+
+/// Accumulates the rows of the dense matrix selected by the columns of the values, weighted by
+/// the values, into the rows of the values.
+fn sparse_matmul<B: Backend>(
+    rows: Tensor<B, 1, Int>,
+    cols: Tensor<B, 1, Int>,
+    values: FloatTensor<B>,
+    num_rows: usize,
+    dense: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let values = Tensor::<B, 1>::from_primitive(TensorPrimitive::Float(values));
+    let dense = Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(dense));
+    let [_, n] = dense.dims();
+
+    let weighted = dense.select(0, cols) * values.unsqueeze_dim(1);
+    let output =
+        Tensor::<B, 2>::zeros([num_rows, n], &weighted.device()).select_assign(0, rows, weighted);
+
+    output.into_primitive().tensor()
+}
+
+/// Returns the row-major strides of the shape as a column of shape `[D, 1]`.
+fn strides<B: Backend>(shape: &Shape, device: &B::Device) -> Tensor<B, 2, Int> {
+    let rank = shape.num_dims();
+    let mut strides = Vec::with_capacity(rank);
+    let mut stride = 1;
+
+    for dim in shape.dims.iter().rev() {
+        strides.push(stride as i64);
+        stride *= dim;
+    }
+    strides.reverse();
+
+    Tensor::<B, 1, Int>::from_data(strides.as_slice(), device).reshape([rank, 1])
+}
+
+/// Converts the indices of shape `[D, nnz]` into the row-major linear indices of shape `[nnz]`.
+pub(crate) fn linear_indices<B: Backend>(
+    indices: Tensor<B, 2, Int>,
+    shape: &Shape,
+) -> Tensor<B, 1, Int> {
+    let [_, nnz] = indices.dims();
+    let strides = strides::<B>(shape, &indices.device());
+
+    (indices * strides).sum_dim(0).reshape([nnz])
+}
+
+/// Converts the row-major linear indices of shape `[nnz]` into indices of shape `[D, nnz]`.
+pub(crate) fn unravel_indices<B: Backend>(
+    linear: Tensor<B, 1, Int>,
+    shape: &Shape,
+) -> Tensor<B, 2, Int> {
+    let rank = shape.num_dims();
+    let device = linear.device();
+    let strides = strides::<B>(shape, &device);
+    let dims = shape.dims.iter().map(|dim| *dim as i64).collect::<Vec<_>>();
+    let dims = Tensor::<B, 1, Int>::from_data(dims.as_slice(), &device).reshape([rank, 1]);
+
+    linear.unsqueeze::<2>().div(strides).remainder(dims)
+}
+
+/// Expands the offset of each row of shape `[m + 1]` into the row of each value of shape `[nnz]`.
+pub(crate) fn csr_row_indices<B: Backend>(
+    crow_indices: Tensor<B, 1, Int>,
+    nnz: usize,
+) -> Tensor<B, 1, Int> {
+    let [length] = crow_indices.dims();
+    let device = crow_indices.device();
+
+    if nnz == 0 {
+        return Tensor::zeros([0], &device);
+    }
+
+    let positions = Tensor::arange(0..nnz as i64, &device);
+
+    // The row of a value is the number of rows ending at or before its position.
+    crow_indices
+        .slice([1..length])
+        .searchsorted(positions, true)
+}
@@ -0,0 +1,286 @@
+use alloc::vec;
+use burn_tensor::{ElementConversion, Int, Shape, Tensor, TensorPrimitive};
+
+use crate::{CsrTensor, SparseBackend, base::linear_indices};
+
+/// A sparse tensor in the coordinate (COO) format.
+///
+/// Each non-zero value is stored along with its indices. The same indices may appear more than
+/// once, in which case the values are summed, until the tensor is [coalesced](Self::coalesce).
+#[derive(Clone, Debug)]
+pub struct CooTensor<B: SparseBackend, const D: usize> {
+    indices: Tensor<B, 2, Int>,
+    values: Tensor<B, 1>,
+    shape: Shape,
+    coalesced: bool,
+}
+
+impl<B: SparseBackend, const D: usize> CooTensor<B, D> {
+    /// Creates a sparse tensor from the indices of shape `[D, nnz]` of the values of shape
+    /// `[nnz]`.
+    ///
+    /// # Warning
+    /// The indices are not checked, so make sure they are within the bounds of the shape.
+    pub fn new(indices: Tensor<B, 2, Int>, values: Tensor<B, 1>, shape: [usize; D]) -> Self {
+        let [rank, nnz] = indices.dims();
+        assert_eq!(
+            rank, D,
+            "The indices must have one row per dimension of the sparse tensor."
+        );
+        assert_eq!(
+            nnz,
+            values.dims()[0],
+            "The indices and the values must have the same number of elements."
+        );
+
+        Self {
+            indices,
+            values,
+            shape: Shape::new(shape),
+            coalesced: false,
+        }
+    }
+
+    /// Creates a sparse tensor from the non-zero values of a dense tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The number of non-zero values depends on the data, so the tensor is read to determine the
+    /// output size, which might cause a synchronization.
+    pub fn from_dense(tensor: Tensor<B, D>) -> Self {
+        let shape = tensor.shape();
+        let (indices, values) = B::sparse_coo_from_dense(tensor.into_primitive().tensor());
+
+        Self {
+            indices: Tensor::from_primitive(indices),
+            values: Tensor::from_primitive(TensorPrimitive::Float(values)),
+            shape,
+            coalesced: true,
+        }
+    }
+
+    /// Converts the sparse tensor to a dense tensor.
+    pub fn to_dense(&self) -> Tensor<B, D> {
+        Tensor::from_primitive(TensorPrimitive::Float(B::sparse_coo_to_dense(
+            self.indices.clone().into_primitive(),
+            self.values.clone().into_primitive().tensor(),
+            self.shape.clone(),
+        )))
+    }
+
+    /// Returns the indices of the values, of shape `[D, nnz]`.
+    pub fn indices(&self) -> Tensor<B, 2, Int> {
+        self.indices.clone()
+    }
+
+    /// Returns the values, of shape `[nnz]`.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// Returns the shape of the sparse tensor.
+    pub fn shape(&self) -> Shape {
+        self.shape.clone()
+    }
+
+    /// Returns the dimensions of the sparse tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.shape.dims()
+    }
+
+    /// Returns the number of stored values, including the duplicates.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// Returns the device of the sparse tensor.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Returns whether the indices are known to be unique and sorted in row-major order.
+    pub fn is_coalesced(&self) -> bool {
+        self.coalesced
+    }
+
+    /// Returns the indices and the values.
+    pub fn into_parts(self) -> (Tensor<B, 2, Int>, Tensor<B, 1>) {
+        (self.indices, self.values)
+    }
+
+    /// Sorts the values in row-major order and sums the values with duplicate indices.
+    ///
+    /// # Remarks
+    ///
+    /// The number of unique indices depends on the data, so the indices are read to determine the
+    /// output size, which might cause a synchronization. Coalesced tensors are returned as is.
+    pub fn coalesce(self) -> Self {
+        if self.coalesced {
+            return self;
+        }
+
+        let (indices, values) = B::sparse_coo_coalesce(
+            self.indices.into_primitive(),
+            self.values.into_primitive().tensor(),
+            self.shape.clone(),
+        );
+
+        Self {
+            indices: Tensor::from_primitive(indices),
+            values: Tensor::from_primitive(TensorPrimitive::Float(values)),
+            shape: self.shape,
+            coalesced: true,
+        }
+    }
+
+    /// Applies the function to the stored values.
+    ///
+    /// The function is only applied to the stored values, so it should map zero to zero for the
+    /// result to match the dense equivalent. Non-linear functions should be applied to coalesced
+    /// tensors, since the duplicate values are only summed afterward.
+    pub fn map_values<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tensor<B, 1>) -> Tensor<B, 1>,
+    {
+        let values = func(self.values);
+        assert_eq!(
+            values.dims()[0],
+            self.indices.dims()[1],
+            "The function must preserve the number of values."
+        );
+
+        Self { values, ..self }
+    }
+
+    /// Multiplies the values by a scalar.
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map_values(|values| values.mul_scalar(other))
+    }
+
+    /// Divides the values by a scalar.
+    pub fn div_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map_values(|values| values.div_scalar(other))
+    }
+
+    /// Negates the values.
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> Self {
+        self.map_values(|values| values.neg())
+    }
+
+    /// Applies the absolute value to the values.
+    ///
+    /// The tensor should be coalesced, see [map_values](Self::map_values).
+    pub fn abs(self) -> Self {
+        self.map_values(|values| values.abs())
+    }
+
+    /// Raises the values to the power of a scalar.
+    ///
+    /// The tensor should be coalesced and the power positive, see
+    /// [map_values](Self::map_values).
+    pub fn powf_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map_values(|values| values.powf_scalar(other))
+    }
+
+    /// Adds another sparse tensor of the same shape.
+    ///
+    /// The values are concatenated, so the result has duplicate indices until it is coalesced.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        assert_eq!(
+            self.shape, other.shape,
+            "The sparse tensors must have the same shape."
+        );
+
+        Self {
+            indices: Tensor::cat(vec![self.indices, other.indices], 1),
+            values: Tensor::cat(vec![self.values, other.values], 0),
+            shape: self.shape,
+            coalesced: false,
+        }
+    }
+
+    /// Multiplies the values by the elements of a dense tensor at the same indices.
+    ///
+    /// The result keeps the sparsity of the current tensor.
+    pub fn mul_dense(self, other: Tensor<B, D>) -> Self {
+        assert_eq!(
+            self.shape,
+            other.shape(),
+            "The sparse and dense tensors must have the same shape."
+        );
+
+        let linear = linear_indices(self.indices.clone(), &self.shape);
+        let other = other.reshape([self.shape.num_elements()]).select(0, linear);
+
+        self.map_values(|values| values * other)
+    }
+
+    /// Sums all the values.
+    pub fn sum(self) -> Tensor<B, 1> {
+        self.values.sum()
+    }
+}
+
+impl<B: SparseBackend> CooTensor<B, 2> {
+    /// Swaps the rows and the columns of the sparse matrix.
+    pub fn transpose(self) -> Self {
+        let [rows, cols] = self.shape.dims();
+
+        Self {
+            indices: self.indices.flip([0]),
+            values: self.values,
+            shape: Shape::new([cols, rows]),
+            coalesced: false,
+        }
+    }
+
+    /// Multiplies the sparse matrix of shape `[m, k]` with a dense matrix of shape `[k, n]`.
+    pub fn spmm(&self, dense: Tensor<B, 2>) -> Tensor<B, 2> {
+        assert_eq!(
+            self.shape.dims[1],
+            dense.dims()[0],
+            "The number of columns of the sparse matrix must match the number of rows of the \
+             dense matrix."
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(B::sparse_coo_spmm(
+            self.indices.clone().into_primitive(),
+            self.values.clone().into_primitive().tensor(),
+            self.shape.clone(),
+            dense.into_primitive().tensor(),
+        )))
+    }
+
+    /// Multiplies the sparse matrix of shape `[m, k]` with a dense vector of shape `[k]`.
+    pub fn spmv(&self, vector: Tensor<B, 1>) -> Tensor<B, 1> {
+        let [rows, _] = self.shape.dims();
+
+        self.spmm(vector.unsqueeze_dim(1)).reshape([rows])
+    }
+
+    /// Converts the sparse matrix to the compressed sparse row format.
+    ///
+    /// # Remarks
+    ///
+    /// The tensor is coalesced first, which might cause a synchronization.
+    pub fn to_csr(self) -> CsrTensor<B> {
+        let tensor = self.coalesce();
+        let [rows, cols] = tensor.shape.dims();
+        let [_, nnz] = tensor.indices.dims();
+        let device = tensor.device();
+
+        let row_indices = tensor.indices.clone().slice([0..1]).reshape([nnz]);
+        let col_indices = tensor.indices.slice([1..2]).reshape([nnz]);
+
+        // The offset of a row is the number of values in the previous rows.
+        let crow_indices = if nnz == 0 {
+            Tensor::zeros([rows + 1], &device)
+        } else {
+            row_indices.searchsorted(Tensor::arange(0..rows as i64 + 1, &device), false)
+        };
+
+        CsrTensor::new(crow_indices, col_indices, tensor.values, [rows, cols])
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::vec;
+use burn_tensor::{ElementConversion, Int, Shape, Tensor, TensorPrimitive};
+
+use crate::{CooTensor, SparseBackend, base::csr_row_indices};
+
+/// A sparse matrix in the compressed sparse row (CSR) format.
+///
+/// The values are stored in row-major order with their column, and the rows are compressed into
+/// the offset of each row in the values, so the values of the row `i` are in the range
+/// `crow_indices[i]..crow_indices[i + 1]`.
+#[derive(Clone, Debug)]
+pub struct CsrTensor<B: SparseBackend> {
+    crow_indices: Tensor<B, 1, Int>,
+    col_indices: Tensor<B, 1, Int>,
+    values: Tensor<B, 1>,
+    shape: Shape,
+}
+
+impl<B: SparseBackend> CsrTensor<B> {
+    /// Creates a sparse matrix from the offset of each row of shape `[m + 1]`, and the column of
+    /// shape `[nnz]` of the values of shape `[nnz]`.
+    ///
+    /// # Warning
+    /// The indices are not checked, so make sure the offsets are sorted and the columns are within
+    /// the bounds of the shape.
+    pub fn new(
+        crow_indices: Tensor<B, 1, Int>,
+        col_indices: Tensor<B, 1, Int>,
+        values: Tensor<B, 1>,
+        shape: [usize; 2],
+    ) -> Self {
+        assert_eq!(
+            crow_indices.dims()[0],
+            shape[0] + 1,
+            "The row offsets must have one more element than the number of rows."
+        );
+        assert_eq!(
+            col_indices.dims()[0],
+            values.dims()[0],
+            "The column indices and the values must have the same number of elements."
+        );
+
+        Self {
+            crow_indices,
+            col_indices,
+            values,
+            shape: Shape::new(shape),
+        }
+    }
+
+    /// Creates a sparse matrix from the non-zero values of a dense matrix.
+    ///
+    /// # Remarks
+    ///
+    /// The number of non-zero values depends on the data, so the tensor is read to determine the
+    /// output size, which might cause a synchronization.
+    pub fn from_dense(tensor: Tensor<B, 2>) -> Self {
+        CooTensor::from_dense(tensor).to_csr()
+    }
+
+    /// Converts the sparse matrix to a dense matrix.
+    pub fn to_dense(&self) -> Tensor<B, 2> {
+        self.to_coo().to_dense()
+    }
+
+    /// Converts the sparse matrix to the coordinate format.
+    pub fn to_coo(&self) -> CooTensor<B, 2> {
+        let rows = csr_row_indices(self.crow_indices.clone(), self.nnz());
+        let indices = Tensor::stack(vec![rows, self.col_indices.clone()], 0);
+
+        CooTensor::new(indices, self.values.clone(), self.shape.dims())
+    }
+
+    /// Returns the offset of each row in the values, of shape `[m + 1]`.
+    pub fn crow_indices(&self) -> Tensor<B, 1, Int> {
+        self.crow_indices.clone()
+    }
+
+    /// Returns the column of each value, of shape `[nnz]`.
+    pub fn col_indices(&self) -> Tensor<B, 1, Int> {
+        self.col_indices.clone()
+    }
+
+    /// Returns the values, of shape `[nnz]`.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// Returns the shape of the sparse matrix.
+    pub fn shape(&self) -> Shape {
+        self.shape.clone()
+    }
+
+    /// Returns the dimensions of the sparse matrix.
+    pub fn dims(&self) -> [usize; 2] {
+        self.shape.dims()
+    }
+
+    /// Returns the number of stored values.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// Returns the device of the sparse matrix.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Returns the row offsets, the column indices and the values.
+    pub fn into_parts(self) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>, Tensor<B, 1>) {
+        (self.crow_indices, self.col_indices, self.values)
+    }
+
+    /// Applies the function to the stored values.
+    ///
+    /// The function is only applied to the stored values, so it should map zero to zero for the
+    /// result to match the dense equivalent.
+    pub fn map_values<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tensor<B, 1>) -> Tensor<B, 1>,
+    {
+        let values = func(self.values);
+        assert_eq!(
+            values.dims()[0],
+            self.col_indices.dims()[0],
+            "The function must preserve the number of values."
+        );
+
+        Self { values, ..self }
+    }
+
+    /// Multiplies the values by a scalar.
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map_values(|values| values.mul_scalar(other))
+    }
+
+    /// Divides the values by a scalar.
+    pub fn div_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map_values(|values| values.div_scalar(other))
+    }
+
+    /// Negates the values.
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> Self {
+        self.map_values(|values| values.neg())
+    }
+
+    /// Applies the absolute value to the values.
+    pub fn abs(self) -> Self {
+        self.map_values(|values| values.abs())
+    }
+
+    /// Raises the values to the power of a scalar.
+    pub fn powf_scalar<E: ElementConversion>(self, other: E) -> Self {
+        self.map_values(|values| values.powf_scalar(other))
+    }
+
+    /// Sums all the values.
+    pub fn sum(self) -> Tensor<B, 1> {
+        self.values.sum()
+    }
+
+    /// Multiplies the sparse matrix of shape `[m, k]` with a dense matrix of shape `[k, n]`.
+    pub fn spmm(&self, dense: Tensor<B, 2>) -> Tensor<B, 2> {
+        assert_eq!(
+            self.shape.dims[1],
+            dense.dims()[0],
+            "The number of columns of the sparse matrix must match the number of rows of the \
+             dense matrix."
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(B::sparse_csr_spmm(
+            self.crow_indices.clone().into_primitive(),
+            self.col_indices.clone().into_primitive(),
+            self.values.clone().into_primitive().tensor(),
+            self.shape.clone(),
+            dense.into_primitive().tensor(),
+        )))
+    }
+
+    /// Multiplies the sparse matrix of shape `[m, k]` with a dense vector of shape `[k]`.
+    pub fn spmv(&self, vector: Tensor<B, 1>) -> Tensor<B, 1> {
+        let [rows, _] = self.shape.dims();
+
+        self.spmm(vector.unsqueeze_dim(1)).reshape([rows])
+    }
+}
@@ -0,0 +1,37 @@
+//! Sparse tensors for burn, built on top of the dense tensor operations of any backend.
+//!
+//! # Layouts
+//! - [`CooTensor`]: coordinate format, where each non-zero value is stored with its indices.
+//! - [`CsrTensor`]: compressed sparse row format for matrices, where the row indices are
+//!   compressed into the offset of each row in the values.
+//!
+//! # Operations
+//! Currently implemented are:
+//! - conversion to and from dense tensors
+//! - `coalesce`
+//! - `spmm` and `spmv`
+//! - elementwise operations on the values
+//!
+//! The operations are differentiable with respect to the dense operands and the sparse values
+//! when used with an autodiff backend.
+
+#![warn(missing_docs)]
+#![allow(clippy::single_range_in_vec_init)]
+
+extern crate alloc;
+
+/// Backend implementations
+pub mod backends;
+mod base;
+mod coo;
+mod csr;
+mod tensor;
+
+#[cfg(feature = "export-tests")]
+#[allow(missing_docs)]
+pub mod tests;
+
+pub use base::*;
+pub use coo::*;
+pub use csr::*;
+pub use tensor::*;
@@ -0,0 +1,32 @@
+use burn_tensor::Tensor;
+
+use crate::{CooTensor, CsrTensor, SparseBackend};
+
+/// Sparse conversion tensor extensions
+pub trait ToSparse<B: SparseBackend, const D: usize> {
+    /// Converts the non-zero values of the tensor to a sparse tensor in the coordinate format.
+    ///
+    /// See [CooTensor::from_dense].
+    fn to_sparse_coo(self) -> CooTensor<B, D>;
+}
+
+/// Sparse matrix conversion tensor extensions
+pub trait ToSparseCsr<B: SparseBackend> {
+    /// Converts the non-zero values of the matrix to a sparse matrix in the compressed sparse row
+    /// format.
+    ///
+    /// See [CsrTensor::from_dense].
+    fn to_sparse_csr(self) -> CsrTensor<B>;
+}
+
+impl<B: SparseBackend, const D: usize> ToSparse<B, D> for Tensor<B, D> {
+    fn to_sparse_coo(self) -> CooTensor<B, D> {
+        CooTensor::from_dense(self)
+    }
+}
+
+impl<B: SparseBackend> ToSparseCsr<B> for Tensor<B, 2> {
+    fn to_sparse_csr(self) -> CsrTensor<B> {
+        CsrTensor::from_dense(self)
+    }
+}
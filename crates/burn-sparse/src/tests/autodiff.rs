@@ -0,0 +1,100 @@
+#[burn_tensor_testgen::testgen(autodiff)]
+mod tests {
+    use super::*;
+    use burn_sparse::{CooTensor, CsrTensor};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_coo_spmm() {
+        let device = Default::default();
+        let indices = TestAutodiffTensorInt::<2>::from_data([[0, 1, 1], [1, 0, 2]], &device);
+        let values = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+        let dense =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+                .require_grad();
+
+        let tensor = CooTensor::new(indices, values.clone(), [2, 3]);
+        let output = tensor.spmm(dense.clone());
+        let grads = output.sum().backward();
+
+        let grad_values = values.grad(&grads).unwrap();
+        let grad_dense = dense.grad(&grads).unwrap();
+
+        // The gradient of a value is the sum of the row of the dense matrix at its column.
+        grad_values
+            .into_data()
+            .assert_eq(&TensorData::from([7.0, 3.0, 11.0]), false);
+        // The gradient of a row of the dense matrix is the sum of the sparse column.
+        grad_dense.into_data().assert_eq(
+            &TensorData::from([[2.0, 2.0], [1.0, 1.0], [3.0, 3.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_diff_csr_spmv() {
+        let device = Default::default();
+        let crow_indices = TestAutodiffTensorInt::<1>::from_data([0, 2, 2, 3], &device);
+        let col_indices = TestAutodiffTensorInt::<1>::from_data([0, 2, 1], &device);
+        let values = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+        let vector = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+
+        let tensor = CsrTensor::new(crow_indices, col_indices, values.clone(), [3, 3]);
+        let output = tensor.spmv(vector.clone());
+        let weights = TestAutodiffTensor::<1>::from_data([1.0, 10.0, 100.0], &device);
+        let grads = (output * weights).sum().backward();
+
+        let grad_values = values.grad(&grads).unwrap();
+        let grad_vector = vector.grad(&grads).unwrap();
+
+        grad_values
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 3.0, 200.0]), false);
+        grad_vector
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 300.0, 2.0]), false);
+    }
+
+    #[test]
+    fn should_diff_coo_to_dense() {
+        let device = Default::default();
+        let indices = TestAutodiffTensorInt::<2>::from_data([[0, 1, 0], [1, 0, 1]], &device);
+        let values = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let tensor = CooTensor::new(indices, values.clone(), [2, 2]).mul_scalar(2.0);
+        let grads = (tensor.to_dense() * weights).sum().backward();
+
+        values
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 6.0, 4.0]), false);
+    }
+
+    #[test]
+    fn should_diff_coalesce_and_mul_dense() {
+        let device = Default::default();
+        let indices = TestAutodiffTensorInt::<2>::from_data([[1, 0, 1], [0, 1, 0]], &device);
+        let values = TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+        let dense =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let tensor = CooTensor::new(indices, values.clone(), [2, 2]).coalesce();
+        let output = tensor.mul_dense(dense.clone()).powf_scalar(2.0);
+        let grads = output.sum().backward();
+
+        // The coalesced values are [2, 4] at [0, 1] and [1, 0], so the gradient of a value is
+        // 2 * coalesced * dense^2 at its index.
+        values
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([72.0, 16.0, 72.0]), false);
+        dense
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 16.0], [96.0, 0.0]]), false);
+    }
+}
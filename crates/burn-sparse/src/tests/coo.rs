@@ -0,0 +1,177 @@
+#[burn_tensor_testgen::testgen(coo)]
+mod tests {
+    use super::*;
+    use burn_sparse::{CooTensor, ToSparse};
+    use burn_tensor::{TensorData, Tolerance};
+
+    fn sparse_matrix() -> CooTensor<TestBackend, 2> {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_data([[0, 1, 1, 2], [1, 0, 2, 2]], &device);
+        let values = TestTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device);
+
+        CooTensor::new(indices, values, [3, 3])
+    }
+
+    #[test]
+    fn should_support_coo_to_dense() {
+        let tensor = sparse_matrix();
+
+        tensor.to_dense().into_data().assert_eq(
+            &TensorData::from([[0.0, 1.0, 0.0], [2.0, 0.0, 3.0], [0.0, 0.0, 4.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_sum_duplicates_coo_to_dense() {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_data([[0, 1, 0], [1, 0, 1]], &device);
+        let values = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+        let tensor = CooTensor::new(indices, values, [2, 2]);
+
+        tensor
+            .to_dense()
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 4.0], [2.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_support_coo_from_dense() {
+        let device = Default::default();
+        let dense = TestTensor::<3>::from_data(
+            [[[0.0, 1.0], [0.0, 0.0]], [[2.0, 0.0], [0.0, -3.0]]],
+            &device,
+        );
+        let tensor = dense.clone().to_sparse_coo();
+
+        assert_eq!(tensor.nnz(), 3);
+        assert!(tensor.is_coalesced());
+        tensor
+            .indices()
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1], [0, 0, 1], [1, 0, 1]]), false);
+        tensor
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, -3.0]), false);
+        tensor
+            .to_dense()
+            .into_data()
+            .assert_eq(&dense.into_data(), false);
+    }
+
+    #[test]
+    fn should_support_coo_from_dense_zeros() {
+        let device = Default::default();
+        let tensor = CooTensor::from_dense(TestTensor::<2>::zeros([2, 3], &device));
+
+        assert_eq!(tensor.nnz(), 0);
+        tensor
+            .to_dense()
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0; 3]; 2]), false);
+    }
+
+    #[test]
+    fn should_support_coalesce() {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_data([[1, 0, 1, 0], [0, 2, 0, 1]], &device);
+        let values = TestTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device);
+        let tensor = CooTensor::new(indices, values, [2, 3]).coalesce();
+
+        assert!(tensor.is_coalesced());
+        tensor
+            .indices()
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 1], [1, 2, 0]]), false);
+        tensor
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 2.0, 4.0]), false);
+    }
+
+    #[test]
+    fn should_support_spmm() {
+        let device = Default::default();
+        let tensor = sparse_matrix();
+        let dense = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+
+        let output = tensor.spmm(dense.clone());
+        let expected = tensor.to_dense().matmul(dense);
+
+        output
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_spmv() {
+        let device = Default::default();
+        let tensor = sparse_matrix();
+        let vector = TestTensor::<1>::from_data([1.0, -1.0, 2.0], &device);
+
+        tensor
+            .spmv(vector)
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0, 8.0, 8.0]), false);
+    }
+
+    #[test]
+    fn should_support_transpose() {
+        let tensor = sparse_matrix();
+        let expected = tensor.to_dense().transpose();
+
+        tensor
+            .transpose()
+            .to_dense()
+            .into_data()
+            .assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn should_support_add() {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_data([[1, 2], [0, 0]], &device);
+        let values = TestTensor::<1>::from_data([5.0, 6.0], &device);
+        let other = CooTensor::new(indices, values, [3, 3]);
+
+        let output = sparse_matrix().add(other);
+
+        assert_eq!(output.nnz(), 6);
+        assert_eq!(output.clone().coalesce().nnz(), 5);
+        output.to_dense().into_data().assert_eq(
+            &TensorData::from([[0.0, 1.0, 0.0], [7.0, 0.0, 3.0], [6.0, 0.0, 4.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_values_ops() {
+        let tensor = sparse_matrix().mul_scalar(2.0).neg().powf_scalar(2.0);
+
+        tensor
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 16.0, 36.0, 64.0]), false);
+    }
+
+    #[test]
+    fn should_support_mul_dense() {
+        let device = Default::default();
+        let dense = TestTensor::<2>::from_data(
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            &device,
+        );
+
+        let output = sparse_matrix().mul_dense(dense);
+
+        output
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 8.0, 18.0, 36.0]), false);
+        output
+            .sum()
+            .into_data()
+            .assert_eq(&TensorData::from([64.0]), false);
+    }
+}
@@ -0,0 +1,114 @@
+#[burn_tensor_testgen::testgen(csr)]
+mod tests {
+    use super::*;
+    use burn_sparse::{CooTensor, CsrTensor, ToSparseCsr};
+    use burn_tensor::{TensorData, Tolerance};
+
+    fn sparse_matrix() -> CsrTensor<TestBackend> {
+        let device = Default::default();
+        let crow_indices = TestTensorInt::<1>::from_data([0, 2, 2, 3], &device);
+        let col_indices = TestTensorInt::<1>::from_data([0, 3, 1], &device);
+        let values = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+
+        CsrTensor::new(crow_indices, col_indices, values, [3, 4])
+    }
+
+    #[test]
+    fn should_support_csr_to_dense() {
+        sparse_matrix().to_dense().into_data().assert_eq(
+            &TensorData::from([
+                [1.0, 0.0, 0.0, 2.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 3.0, 0.0, 0.0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_csr_from_dense() {
+        let tensor = sparse_matrix().to_dense().to_sparse_csr();
+
+        tensor
+            .crow_indices()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 2, 2, 3]), false);
+        tensor
+            .col_indices()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 3, 1]), false);
+        tensor
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0]), false);
+    }
+
+    #[test]
+    fn should_support_csr_from_dense_zeros() {
+        let device = Default::default();
+        let tensor = CsrTensor::from_dense(TestTensor::<2>::zeros([2, 2], &device));
+
+        assert_eq!(tensor.nnz(), 0);
+        tensor
+            .crow_indices()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 0]), false);
+    }
+
+    #[test]
+    fn should_support_coo_to_csr() {
+        let device = Default::default();
+        let indices = TestTensorInt::<2>::from_data([[2, 0, 0, 2], [1, 3, 0, 1]], &device);
+        let values = TestTensor::<1>::from_data([1.0, 2.0, 1.0, 2.0], &device);
+        let tensor = CooTensor::new(indices, values, [3, 4]).to_csr();
+
+        tensor
+            .crow_indices()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 2, 2, 3]), false);
+        tensor
+            .col_indices()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 3, 1]), false);
+        tensor
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0]), false);
+    }
+
+    #[test]
+    fn should_support_csr_spmm() {
+        let device = Default::default();
+        let tensor = sparse_matrix();
+        let dense =
+            TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]], &device);
+
+        let output = tensor.spmm(dense.clone());
+        let expected = tensor.to_dense().matmul(dense);
+
+        output
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_csr_spmv() {
+        let device = Default::default();
+        let vector = TestTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device);
+
+        sparse_matrix()
+            .spmv(vector)
+            .into_data()
+            .assert_eq(&TensorData::from([9.0, 0.0, 6.0]), false);
+    }
+
+    #[test]
+    fn should_support_csr_values_ops() {
+        let tensor = sparse_matrix().div_scalar(2.0).neg().abs();
+
+        tensor
+            .values()
+            .into_data()
+            .assert_eq(&TensorData::from([0.5, 1.0, 1.5]), false);
+    }
+}
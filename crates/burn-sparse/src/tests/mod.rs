@@ -0,0 +1,26 @@
+mod autodiff;
+mod coo;
+mod csr;
+
+#[macro_export]
+macro_rules! testgen_all {
+    () => {
+        use burn_tensor::{Float, Int};
+
+        pub type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+        pub type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, Int>;
+
+        pub type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+        pub type TestAutodiffTensor<const D: usize> = burn_tensor::Tensor<TestAutodiffBackend, D>;
+        pub type TestAutodiffTensorInt<const D: usize> =
+            burn_tensor::Tensor<TestAutodiffBackend, D, Int>;
+
+        pub mod sparse {
+            pub use super::*;
+
+            burn_sparse::testgen_autodiff!();
+            burn_sparse::testgen_coo!();
+            burn_sparse::testgen_csr!();
+        }
+    };
+}
@@ -0,0 +1,6 @@
+#[cfg(all(test, feature = "test-cpu"))]
+mod tests_cpu {
+    pub type TestBackend = burn_ndarray::NdArray<f32, i32>;
+
+    burn_sparse::testgen_all!();
+}
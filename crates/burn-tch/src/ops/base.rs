@@ -308,6 +308,40 @@ impl TchOps {
         )
     }
 
+    pub fn cumsum(tensor: TchTensor, dim: usize) -> TchTensor {
+        let storage = tensor.storage.clone();
+        let tensor = tensor.tensor.cumsum(dim as i64, tensor.tensor.kind());
+
+        TchTensor::from_existing(tensor, storage)
+    }
+
+    pub fn cumprod(tensor: TchTensor, dim: usize) -> TchTensor {
+        let storage = tensor.storage.clone();
+        let tensor = tensor.tensor.cumprod(dim as i64, tensor.tensor.kind());
+
+        TchTensor::from_existing(tensor, storage)
+    }
+
+    pub fn cummax(tensor: TchTensor, dim: usize) -> (TchTensor, TchTensor) {
+        let storage = tensor.storage.clone();
+        let (tensor, indices) = tensor.tensor.cummax(dim as i64);
+
+        let tensor = TchTensor::from_existing(tensor, storage);
+        let indices = TchTensor::new(indices);
+
+        (tensor, indices)
+    }
+
+    pub fn cummin(tensor: TchTensor, dim: usize) -> (TchTensor, TchTensor) {
+        let storage = tensor.storage.clone();
+        let (tensor, indices) = tensor.tensor.cummin(dim as i64);
+
+        let tensor = TchTensor::from_existing(tensor, storage);
+        let indices = TchTensor::new(indices);
+
+        (tensor, indices)
+    }
+
     pub fn argmax(tensor: TchTensor, dim: usize) -> TchTensor {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.argmax(dim as i64, true);
@@ -409,6 +443,26 @@ impl TchOps {
         )
     }
 
+    pub fn atan2(lhs: TchTensor, rhs: TchTensor) -> TchTensor {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_atan2_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_atan2(rhs).unwrap(),
+            |lhs, rhs| lhs.f_atan2(rhs).unwrap(),
+        )
+    }
+
+    pub fn hypot(lhs: TchTensor, rhs: TchTensor) -> TchTensor {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_hypot_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_hypot(rhs).unwrap(),
+            |lhs, rhs| lhs.f_hypot(rhs).unwrap(),
+        )
+    }
+
     pub fn sign(tensor: TchTensor) -> TchTensor {
         tensor.unary_ops(|mut tensor| tensor.sign_(), |tensor| tensor.sign())
     }
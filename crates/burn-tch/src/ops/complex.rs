@@ -0,0 +1,4 @@
+use crate::{LibTorch, QuantElement, element::TchElement};
+use burn_tensor::ops::ComplexOps;
+
+impl<E: TchElement, Q: QuantElement> ComplexOps<Self> for LibTorch<E, Q> {}
@@ -0,0 +1,4 @@
+use crate::{LibTorch, QuantElement, element::TchElement};
+use burn_tensor::ops::FftOps;
+
+impl<E: TchElement, Q: QuantElement> FftOps<Self> for LibTorch<E, Q> {}
@@ -231,6 +231,22 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
         TchOps::prod_dim(tensor, dim)
     }
 
+    fn int_cumsum(tensor: TchTensor, dim: usize) -> TchTensor {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod(tensor: TchTensor, dim: usize) -> TchTensor {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax(tensor: TchTensor, dim: usize) -> (TchTensor, TchTensor) {
+        TchOps::cummax(tensor, dim)
+    }
+
+    fn int_cummin(tensor: TchTensor, dim: usize) -> (TchTensor, TchTensor) {
+        TchOps::cummin(tensor, dim)
+    }
+
     fn int_mean(tensor: TchTensor) -> TchTensor {
         let tensor: TchTensor =
             TchTensor::new(tensor.tensor.to_dtype(tch::Kind::Float, true, false));
@@ -362,6 +378,11 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
                 let mut tensor = TchTensor::empty::<i64>(shape, *device);
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            // The other distributions are sampled on the host.
+            distribution => {
+                let data = TensorData::random::<i64, _, _>(shape, distribution, &mut rand::rng());
+                Self::int_from_data(data, device)
+            }
         }
     }
 
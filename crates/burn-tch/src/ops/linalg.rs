@@ -0,0 +1,4 @@
+use crate::{LibTorch, QuantElement, element::TchElement};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: TchElement, Q: QuantElement> LinalgOps<Self> for LibTorch<E, Q> {}
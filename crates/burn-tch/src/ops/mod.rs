@@ -1,7 +1,10 @@
 mod activation;
 mod base;
 mod bool_tensor;
+mod complex;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 mod tensor;
@@ -2,9 +2,9 @@ use crate::{LibTorch, QuantElement, TchTensor, element::TchElement};
 use burn_tensor::{
     TensorMetadata,
     ops::{
-        ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions,
-        InterpolateMode, InterpolateOptions, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, GridSampleMode,
+        GridSampleOptions, GridSamplePaddingMode, InterpolateMode, InterpolateOptions,
+        MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
     },
 };
 
@@ -406,4 +406,27 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
 
         TchTensor::new(tensor)
     }
+
+    fn grid_sample(x: TchTensor, grid: TchTensor, options: GridSampleOptions) -> TchTensor {
+        let mode = match options.mode {
+            GridSampleMode::Bilinear => 0,
+            GridSampleMode::Nearest => 1,
+            GridSampleMode::Bicubic => 2,
+        };
+        let padding_mode = match options.padding_mode {
+            GridSamplePaddingMode::Zeros => 0,
+            GridSamplePaddingMode::Border => 1,
+            GridSamplePaddingMode::Reflection => 2,
+        };
+
+        let tensor = tch::Tensor::grid_sampler(
+            &x.tensor,
+            &grid.tensor,
+            mode,
+            padding_mode,
+            options.align_corners,
+        );
+
+        TchTensor::new(tensor)
+    }
 }
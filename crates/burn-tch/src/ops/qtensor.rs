@@ -31,10 +31,32 @@ fn quantize<E: TchElement>(
             q_type: QuantInputType::QInt8,
             ..
         } => tensor.quantize_per_tensor(qparams.scales.elem(), 0, tch::Kind::QInt8),
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Affine,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => tensor.quantize_per_tensor(
+            qparams.scales.elem(),
+            qparams.offsets.unwrap().elem(),
+            tch::Kind::QInt8,
+        ),
         QuantScheme {
             level: QuantLevel::Block(_),
             ..
         } => unimplemented!("LibTorch backend does not support per-block quantization"),
+        QuantScheme {
+            level: QuantLevel::Channel(_),
+            ..
+        } => unimplemented!("LibTorch backend does not support per-channel quantization"),
+        QuantScheme {
+            q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+            ..
+        } => unimplemented!("LibTorch backend does not support fp8 quantization"),
+        QuantScheme {
+            q_type: QuantInputType::QInt4 | QuantInputType::QUInt4,
+            ..
+        } => unimplemented!("LibTorch backend does not support 4-bit quantization"),
     }
 }
 
@@ -50,6 +72,7 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         match data.dtype {
             DType::QFloat(scheme) => match scheme.level {
                 QuantLevel::Tensor => {
+                    let shape = data.shape.clone();
                     let num_elements = data.num_elements();
                     let q_bytes = QuantizedBytes {
                         bytes: data.into_bytes(),
@@ -57,9 +80,10 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                         num_elements,
                     };
 
-                    let (values, qparams) = q_bytes.dequantize();
+                    let (values, qparams) = q_bytes.dequantize(&shape);
                     let qparams = QParams {
                         scales: qparams.scales[0],
+                        offsets: qparams.offsets.map(|offsets| offsets[0]),
                     };
                     let tensor = tch::Tensor::from_slice(&values).to(device);
                     let tensor = quantize(tensor.reshape(shape_tch.dims), &scheme, &qparams);
@@ -72,6 +96,9 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                 QuantLevel::Block(_) => {
                     unimplemented!("LibTorch backend does not support per-block quantization")
                 }
+                QuantLevel::Channel(_) => {
+                    unimplemented!("LibTorch backend does not support per-channel quantization")
+                }
             },
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
@@ -102,10 +129,32 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                 &tch::Tensor::zeros_like(&qparams.scales.tensor),
                 tch::Kind::QInt8,
             ),
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => tensor.tensor.quantize_per_tensor_tensor_qparams(
+                &qparams.scales.tensor,
+                &qparams.offsets.unwrap().tensor.to_kind(tch::Kind::Int64),
+                tch::Kind::QInt8,
+            ),
             QuantScheme {
                 level: QuantLevel::Block(_),
                 ..
             } => unimplemented!("LibTorch backend does not support per-block quantization"),
+            QuantScheme {
+                level: QuantLevel::Channel(_),
+                ..
+            } => unimplemented!("LibTorch backend does not support per-channel quantization"),
+            QuantScheme {
+                q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                ..
+            } => unimplemented!("LibTorch backend does not support fp8 quantization"),
+            QuantScheme {
+                q_type: QuantInputType::QInt4 | QuantInputType::QUInt4,
+                ..
+            } => unimplemented!("LibTorch backend does not support 4-bit quantization"),
         };
 
         TchQTensor {
@@ -129,10 +178,30 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                     .tensor
                     .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false)
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => tensor
+                .tensor
+                .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
             QuantScheme {
                 level: QuantLevel::Block(_),
                 ..
             } => unimplemented!("LibTorch backend does not support per-block quantization"),
+            QuantScheme {
+                level: QuantLevel::Channel(_),
+                ..
+            } => unimplemented!("LibTorch backend does not support per-channel quantization"),
+            QuantScheme {
+                q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                ..
+            } => unimplemented!("LibTorch backend does not support fp8 quantization"),
+            QuantScheme {
+                q_type: QuantInputType::QInt4 | QuantInputType::QUInt4,
+                ..
+            } => unimplemented!("LibTorch backend does not support 4-bit quantization"),
         };
 
         TchQTensor {
@@ -45,6 +45,11 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
                 let mut tensor = TchTensor::empty::<E>(shape, *device);
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            // The other distributions are sampled on the host.
+            distribution => {
+                let data = TensorData::random::<E, _, _>(shape, distribution, &mut rand::rng());
+                Self::float_from_data(data, device)
+            }
         }
     }
 
@@ -307,6 +312,35 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         TchOps::prod_dim(tensor, dim)
     }
 
+    fn float_cumsum(tensor: TchTensor, dim: usize) -> TchTensor {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn float_cumprod(tensor: TchTensor, dim: usize) -> TchTensor {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn float_cummax(tensor: TchTensor, dim: usize) -> (TchTensor, TchTensor) {
+        TchOps::cummax(tensor, dim)
+    }
+
+    fn float_cummin(tensor: TchTensor, dim: usize) -> (TchTensor, TchTensor) {
+        TchOps::cummin(tensor, dim)
+    }
+
+    fn float_logcumsumexp(tensor: TchTensor, dim: usize) -> TchTensor {
+        let storage = tensor.storage.clone();
+        let tensor = tensor.tensor.logcumsumexp(dim as i64);
+
+        TchTensor::from_existing(tensor, storage)
+    }
+
+    fn float_logsumexp(tensor: TchTensor, dim: usize) -> TchTensor {
+        let tensor = tensor.tensor.logsumexp([dim as i64], true);
+
+        TchTensor::new(tensor)
+    }
+
     fn float_argmax(tensor: TchTensor, dim: usize) -> TchTensor {
         TchOps::argmax(tensor, dim)
     }
@@ -386,6 +420,82 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         tensor.unary_ops(|mut tensor| tensor.erf_(), |tensor| tensor.erf())
     }
 
+    fn float_asin(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.asin_(), |tensor| tensor.asin())
+    }
+
+    fn float_acos(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.acos_(), |tensor| tensor.acos())
+    }
+
+    fn float_atan(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.atan_(), |tensor| tensor.atan())
+    }
+
+    fn float_atan2(lhs: TchTensor, rhs: TchTensor) -> TchTensor {
+        TchOps::atan2(lhs, rhs)
+    }
+
+    fn float_asinh(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.asinh_(), |tensor| tensor.asinh())
+    }
+
+    fn float_acosh(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.acosh_(), |tensor| tensor.acosh())
+    }
+
+    fn float_atanh(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.atanh_(), |tensor| tensor.atanh())
+    }
+
+    fn float_erfinv(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.erfinv_(), |tensor| tensor.erfinv())
+    }
+
+    fn float_erfc(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.erfc_(), |tensor| tensor.erfc())
+    }
+
+    fn float_expm1(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.expm1_(), |tensor| tensor.expm1())
+    }
+
+    fn float_log2(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.log2_(), |tensor| tensor.log2())
+    }
+
+    fn float_log10(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.log10_(), |tensor| tensor.log10())
+    }
+
+    fn float_hypot(lhs: TchTensor, rhs: TchTensor) -> TchTensor {
+        TchOps::hypot(lhs, rhs)
+    }
+
+    fn float_lgamma(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.lgamma_(), |tensor| tensor.lgamma())
+    }
+
+    fn float_digamma(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.digamma_(), |tensor| tensor.digamma())
+    }
+
+    fn float_polygamma(tensor: TchTensor, n: u32) -> TchTensor {
+        let n = n as i64;
+        tensor.unary_ops(
+            |mut tensor| tensor.polygamma_(n),
+            |tensor| tch::Tensor::polygamma(n, tensor),
+        )
+    }
+
+    fn float_i0(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|mut tensor| tensor.i0_(), |tensor| tensor.i0())
+    }
+
+    fn float_i1(tensor: TchTensor) -> TchTensor {
+        tensor.unary_ops(|tensor| tensor.special_i1(), |tensor| tensor.special_i1())
+    }
+
     fn float_cat(tensors: Vec<TchTensor>, dim: usize) -> TchTensor {
         TchOps::cat(tensors, dim)
     }
@@ -457,6 +567,9 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
             FloatDType::F32 => tch::Kind::Float,
             FloatDType::F16 => tch::Kind::Half,
             FloatDType::BF16 => tch::Kind::BFloat16,
+            FloatDType::E4M3 | FloatDType::E5M2 => {
+                unimplemented!("LibTorch backend does not support {dtype:?} tensors")
+            }
         };
 
         if tensor.tensor.kind() == kind {
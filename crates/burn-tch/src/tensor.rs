@@ -2,8 +2,8 @@ use crate::{LibTorchDevice, TchElement};
 use burn_tensor::{
     DType, Shape, TensorData, TensorMetadata,
     quantization::{
-        QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantScheme, QuantizationStrategy,
-        SymmetricQuantization,
+        IntQuantization, QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantScheme,
+        QuantizationStrategy, SymmetricQuantization,
     },
 };
 use libc::c_void;
@@ -342,10 +342,36 @@ impl TchQTensor {
                     scale as f32,
                 ))
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => {
+                let scale = self.qtensor.tensor.q_scale();
+                let offset = self.qtensor.tensor.q_zero_point();
+                QuantizationStrategy::PerTensorInt(IntQuantization::affine(
+                    scale as f32,
+                    offset as i32,
+                    QuantInputType::QInt8,
+                ))
+            }
             QuantScheme {
                 level: QuantLevel::Block(_),
                 ..
             } => unimplemented!("LibTorch backend does not support per-block quantization"),
+            QuantScheme {
+                level: QuantLevel::Channel(_),
+                ..
+            } => unimplemented!("LibTorch backend does not support per-channel quantization"),
+            QuantScheme {
+                q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                ..
+            } => unimplemented!("LibTorch backend does not support fp8 quantization"),
+            QuantScheme {
+                q_type: QuantInputType::QInt4 | QuantInputType::QUInt4,
+                ..
+            } => unimplemented!("LibTorch backend does not support 4-bit quantization"),
         }
     }
 }
@@ -443,6 +469,7 @@ mod tests {
                 TensorData::from([0.009_019_608]),
                 tch::Device::Cpu,
             ),
+            offsets: None,
         };
         let qtensor: TchQTensor = LibTorch::quantize(tensor, &scheme, qparams);
 
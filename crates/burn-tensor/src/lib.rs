@@ -42,6 +42,8 @@ mod cube {
                 crate::DType::Flex32 => Elem::Float(FloatKind::Flex32),
                 crate::DType::F16 => Elem::Float(FloatKind::F16),
                 crate::DType::BF16 => Elem::Float(FloatKind::BF16),
+                crate::DType::E4M3 => Elem::Float(FloatKind::E4M3),
+                crate::DType::E5M2 => Elem::Float(FloatKind::E5M2),
                 crate::DType::I64 => Elem::Int(IntKind::I64),
                 crate::DType::I32 => Elem::Int(IntKind::I32),
                 crate::DType::I16 => Elem::Int(IntKind::I16),
@@ -51,6 +53,9 @@ mod cube {
                 crate::DType::U16 => Elem::UInt(UIntKind::U16),
                 crate::DType::U8 => Elem::UInt(UIntKind::U8),
                 crate::DType::Bool => Elem::Bool,
+                crate::DType::Complex32 | crate::DType::Complex64 => {
+                    panic!("complex types are not supported yet.")
+                }
                 crate::DType::QFloat(_) => panic!("quantized type is not supported yet."),
             }
         }
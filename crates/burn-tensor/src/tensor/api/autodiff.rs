@@ -1,5 +1,8 @@
 use crate::{
-    BasicOps, Bool, Float, Int, Tensor, TensorKind, TensorPrimitive, backend::AutodiffBackend,
+    BasicOps, Bool, Complex, ComplexTensorPrimitive, Float, Int, Tensor, TensorKind,
+    TensorMetadata, TensorPrimitive,
+    backend::AutodiffBackend,
+    ops::{FloatTensor, FloatTensorOps},
 };
 
 impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
@@ -52,6 +55,100 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
             ),
         }
     }
+
+    /// Register a hook called with the gradient of the tensor during the next backward pass.
+    ///
+    /// The hook can inspect the gradient, or modify it by returning a different tensor, which is
+    /// then propagated to the operations that created this tensor. It is called once the gradient
+    /// of the tensor is complete, i.e. after the contributions of all the operations using it are
+    /// accumulated.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is not tracked, i.e. neither marked with [require_grad](Tensor::require_grad)
+    /// nor computed from a tracked tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Clip the gradient flowing through an intermediate tensor.
+    /// let hidden = linear.forward(input).register_hook(|grad| grad.clamp(-1.0, 1.0));
+    /// ```
+    pub fn register_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(Tensor<B::InnerBackend, D>) -> Tensor<B::InnerBackend, D> + Send + 'static,
+    {
+        let hook = move |grad: FloatTensor<B::InnerBackend>| {
+            hook(Tensor::new(TensorPrimitive::Float(grad)))
+                .primitive
+                .tensor()
+        };
+
+        Self::new(TensorPrimitive::Float(B::register_hook(
+            self.primitive.tensor(),
+            hook,
+        )))
+    }
+
+    /// Keep the gradient of the tensor in the [grads](AutodiffBackend::Gradients) returned by the
+    /// next backward pass, so that it can be accessed with [grad](Tensor::grad).
+    ///
+    /// Only the gradients of the leaves, the tensors marked with
+    /// [require_grad](Tensor::require_grad), are kept by default.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is not tracked, or if the backend doesn't support retaining gradients.
+    pub fn retain_grad(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::retain_grad(
+            self.primitive.tensor(),
+        )))
+    }
+}
+
+impl<const D: usize, B: AutodiffBackend> Tensor<B, D, Complex> {
+    /// Get the gradients of a complex tensor if it exist.
+    ///
+    /// The gradient of a real loss `L` with respect to `z = x + iy` is `∂L/∂x + i ∂L/∂y`, which
+    /// is twice the conjugate Wirtinger derivative `∂L/∂z̄`. When only one of the parts
+    /// contributes to the loss, the gradient of the other part is zero.
+    pub fn grad(&self, grads: &B::Gradients) -> Option<Tensor<B::InnerBackend, D, Complex>> {
+        Self::complex_grad(
+            B::grad(&self.primitive.real, grads),
+            B::grad(&self.primitive.imag, grads),
+        )
+    }
+
+    /// Remove the grad tensor from the [grads](AutodiffBackend::Gradients) struct returning the result.
+    pub fn grad_remove(
+        &self,
+        grads: &mut B::Gradients,
+    ) -> Option<Tensor<B::InnerBackend, D, Complex>> {
+        Self::complex_grad(
+            B::grad_remove(&self.primitive.real, grads),
+            B::grad_remove(&self.primitive.imag, grads),
+        )
+    }
+
+    fn complex_grad(
+        real: Option<FloatTensor<B::InnerBackend>>,
+        imag: Option<FloatTensor<B::InnerBackend>>,
+    ) -> Option<Tensor<B::InnerBackend, D, Complex>> {
+        let zeros = |tensor: &FloatTensor<B::InnerBackend>| {
+            B::InnerBackend::float_zeros(tensor.shape(), &B::InnerBackend::float_device(tensor))
+        };
+        let (real, imag) = match (real, imag) {
+            (Some(real), Some(imag)) => (real, imag),
+            (Some(real), None) => {
+                let imag = zeros(&real);
+                (real, imag)
+            }
+            (None, Some(imag)) => (zeros(&imag), imag),
+            (None, None) => return None,
+        };
+
+        Some(Tensor::new(ComplexTensorPrimitive::new(real, imag)))
+    }
 }
 
 impl<const D: usize, B: AutodiffBackend, K: BasicAutodiffOps<B>> Tensor<B, D, K> {
@@ -128,6 +225,22 @@ impl<B: AutodiffBackend> BasicAutodiffOps<B> for Bool {
     }
 }
 
+impl<B: AutodiffBackend> BasicAutodiffOps<B> for Complex {
+    type InnerKind = Complex;
+
+    fn inner(
+        tensor: <Self as TensorKind<B>>::Primitive,
+    ) -> <Self::InnerKind as TensorKind<<B as AutodiffBackend>::InnerBackend>>::Primitive {
+        ComplexTensorPrimitive::new(B::inner(tensor.real), B::inner(tensor.imag))
+    }
+
+    fn from_inner(
+        inner: <Self::InnerKind as TensorKind<<B as AutodiffBackend>::InnerBackend>>::Primitive,
+    ) -> <Self as TensorKind<B>>::Primitive {
+        ComplexTensorPrimitive::new(B::from_inner(inner.real), B::from_inner(inner.imag))
+    }
+}
+
 /// Trait that list all operations that can be applied on all tensors on an autodiff backend.
 ///
 /// # Warnings
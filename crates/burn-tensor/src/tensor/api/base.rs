@@ -20,7 +20,7 @@ use crate::{
     Bool, ElementConversion, Float, Int, Shape, TensorData, TensorKind, backend::Backend, check,
     ops::Device,
 };
-use crate::{DType, Element, TensorPrimitive};
+use crate::{Complex, ComplexElem, ComplexTensorPrimitive, DType, Element, TensorPrimitive};
 use crate::{cast::ToElement, check::TensorCheck};
 
 /// A tensor with a given backend, shape and data type.
@@ -588,7 +588,7 @@ where
     /// }
     /// ```
     pub fn squeeze<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
-        check!(TensorCheck::squeeze::<D2>(dim, &self.shape().dims));
+        check!(TensorCheck::squeeze(dim, &self.shape().dims));
 
         let current_dims = self.shape().dims;
         let mut new_dims: [usize; D2] = [0; D2];
@@ -676,7 +676,7 @@ where
         for (index, &dim_size) in current_dims.iter().enumerate() {
             // Exclude the dimension if it's explicitly marked for squeezing
             if dim_indices.contains(&index) {
-                check!(TensorCheck::squeeze::<D2>(index, &current_dims));
+                check!(TensorCheck::squeeze(index, &current_dims));
                 continue;
             }
             new_dims.push(dim_size);
@@ -1560,7 +1560,7 @@ where
     /// ```
     pub fn narrow(self, dim: usize, start: usize, length: usize) -> Self {
         check!(TensorCheck::dim_ops::<D>("narrow", dim));
-        check!(TensorCheck::narrow(&self, dim, start, length));
+        check!(TensorCheck::narrow(&self.shape(), dim, start, length));
         let dims = self.dims();
 
         let ranges: [Range<usize>; D] = dims
@@ -1922,7 +1922,7 @@ where
     ///
     /// If the tensor doesn't have one element.
     pub async fn into_scalar_async(self) -> K::Elem {
-        check!(TensorCheck::into_scalar::<D>(&self.shape()));
+        check!(TensorCheck::into_scalar(&self.shape()));
 
         self.into_data_async().await.iter().next().unwrap()
     }
@@ -3165,6 +3165,179 @@ impl<B: Backend> BasicOps<B> for Bool {
     }
 }
 
+impl<B: Backend> BasicOps<B> for Complex {
+    type Elem = ComplexElem<B::FloatElem>;
+
+    fn empty(shape: Shape, device: &B::Device) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_empty(shape.clone(), device),
+            B::float_empty(shape, device),
+        )
+    }
+
+    fn register_transaction(tr: &mut Transaction<B>, tensor: Self::Primitive) {
+        tr.register_complex(tensor);
+    }
+
+    fn reshape(tensor: Self::Primitive, shape: Shape) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_reshape(tensor.real, shape.clone()),
+            B::float_reshape(tensor.imag, shape),
+        )
+    }
+
+    fn transpose(tensor: Self::Primitive) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_transpose(tensor.real),
+            B::float_transpose(tensor.imag),
+        )
+    }
+
+    fn swap_dims(tensor: Self::Primitive, dim1: usize, dim2: usize) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_swap_dims(tensor.real, dim1, dim2),
+            B::float_swap_dims(tensor.imag, dim1, dim2),
+        )
+    }
+
+    fn slice(tensor: Self::Primitive, ranges: &[Range<usize>]) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_slice(tensor.real, ranges),
+            B::float_slice(tensor.imag, ranges),
+        )
+    }
+
+    fn slice_assign(
+        tensor: Self::Primitive,
+        ranges: &[Range<usize>],
+        value: Self::Primitive,
+    ) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_slice_assign(tensor.real, ranges, value.real),
+            B::float_slice_assign(tensor.imag, ranges, value.imag),
+        )
+    }
+
+    fn device(tensor: &Self::Primitive) -> Device<B> {
+        B::float_device(&tensor.real)
+    }
+
+    fn to_device(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_to_device(tensor.real, device),
+            B::float_to_device(tensor.imag, device),
+        )
+    }
+
+    async fn into_data_async(tensor: Self::Primitive) -> TensorData {
+        let real = B::float_into_data(tensor.real).await;
+        let imag = B::float_into_data(tensor.imag).await;
+
+        TensorData::complex(real, imag)
+    }
+
+    fn from_data(data: TensorData, device: &B::Device) -> Self::Primitive {
+        let (real, imag) = data.convert::<Self::Elem>().into_complex_parts();
+
+        ComplexTensorPrimitive::new(
+            B::float_from_data(real, device),
+            B::float_from_data(imag, device),
+        )
+    }
+
+    fn from_data_dtype(data: TensorData, device: &B::Device, dtype: DType) -> Self::Primitive {
+        if !dtype.is_complex() {
+            panic!("Expected complex dtype, got {dtype:?}")
+        }
+        let (real, imag) = data.convert_dtype(dtype).into_complex_parts();
+
+        ComplexTensorPrimitive::new(
+            B::float_from_data(real, device),
+            B::float_from_data(imag, device),
+        )
+    }
+
+    fn repeat_dim(tensor: Self::Primitive, dim: usize, times: usize) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_repeat_dim(tensor.real, dim, times),
+            B::float_repeat_dim(tensor.imag, dim, times),
+        )
+    }
+
+    fn cat(vectors: Vec<Self::Primitive>, dim: usize) -> Self::Primitive {
+        let (real, imag) = vectors
+            .into_iter()
+            .map(|tensor| (tensor.real, tensor.imag))
+            .unzip();
+
+        ComplexTensorPrimitive::new(B::float_cat(real, dim), B::float_cat(imag, dim))
+    }
+
+    fn equal(lhs: Self::Primitive, rhs: Self::Primitive) -> B::BoolTensorPrimitive {
+        B::bool_and(
+            B::float_equal(lhs.real, rhs.real),
+            B::float_equal(lhs.imag, rhs.imag),
+        )
+    }
+
+    fn not_equal(lhs: Self::Primitive, rhs: Self::Primitive) -> B::BoolTensorPrimitive {
+        B::bool_or(
+            B::float_not_equal(lhs.real, rhs.real),
+            B::float_not_equal(lhs.imag, rhs.imag),
+        )
+    }
+
+    fn any(tensor: Self::Primitive) -> B::BoolTensorPrimitive {
+        B::bool_or(B::float_any(tensor.real), B::float_any(tensor.imag))
+    }
+
+    fn any_dim(tensor: Self::Primitive, dim: usize) -> B::BoolTensorPrimitive {
+        B::bool_or(
+            B::float_any_dim(tensor.real, dim),
+            B::float_any_dim(tensor.imag, dim),
+        )
+    }
+
+    fn all(tensor: Self::Primitive) -> B::BoolTensorPrimitive {
+        B::bool_all(Self::non_zero(tensor))
+    }
+
+    fn all_dim(tensor: Self::Primitive, dim: usize) -> B::BoolTensorPrimitive {
+        B::bool_all_dim(Self::non_zero(tensor), dim)
+    }
+
+    fn permute(tensor: Self::Primitive, axes: &[usize]) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_permute(tensor.real, axes),
+            B::float_permute(tensor.imag, axes),
+        )
+    }
+
+    fn expand(tensor: Self::Primitive, shape: Shape) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_expand(tensor.real, shape.clone()),
+            B::float_expand(tensor.imag, shape),
+        )
+    }
+
+    fn flip(tensor: Self::Primitive, axes: &[usize]) -> Self::Primitive {
+        ComplexTensorPrimitive::new(
+            B::float_flip(tensor.real, axes),
+            B::float_flip(tensor.imag, axes),
+        )
+    }
+}
+
+impl Complex {
+    /// Returns whether each element has a non-zero real or imaginary part.
+    fn non_zero<B: Backend>(tensor: ComplexTensorPrimitive<B>) -> B::BoolTensorPrimitive {
+        B::bool_or(
+            B::float_not_equal_elem(tensor.real, 0.elem()),
+            B::float_not_equal_elem(tensor.imag, 0.elem()),
+        )
+    }
+}
+
 /// Trait used for movedim arguments
 pub trait MovedimArgs {
     /// Converts into a set of dimensions `Vec<usize>` for the `tensor.movedim()` function
@@ -3276,10 +3449,7 @@ impl<const D2: usize> ReshapeArgs<D2> for Shape {
         self,
         tensor: &Tensor<B, D, K>,
     ) -> Shape {
-        check!(TensorCheck::reshape_args_usize::<D, D2>(
-            &tensor.shape(),
-            &self
-        ));
+        check!(TensorCheck::reshape_args_usize(&tensor.shape(), &self));
 
         self
     }
@@ -3291,10 +3461,7 @@ impl<const D2: usize> ReshapeArgs<D2> for [usize; D2] {
     ) -> Shape {
         let shape = Shape::from(self);
 
-        check!(TensorCheck::reshape_args_usize::<D, D2>(
-            &tensor.shape(),
-            &shape
-        ));
+        check!(TensorCheck::reshape_args_usize(&tensor.shape(), &shape));
 
         shape
     }
@@ -1,3 +1,4 @@
+use super::einsum::EinsumEquation;
 use crate::{BasicOps, Numeric, Shape, Tensor, backend::Backend, cast::ToElement};
 use alloc::format;
 use alloc::string::{String, ToString};
@@ -49,7 +50,54 @@ impl TensorCheck {
             .binary_ops_ew_shape::<D>(ops, &lhs.shape(), &rhs.shape())
     }
 
-    pub(crate) fn into_scalar<const D: usize>(shape: &Shape) -> Self {
+    /// Checks device, rank and shape compatibility for element wise binary operations on tensors
+    /// with a rank only known at runtime.
+    pub(crate) fn binary_ops_ew_dyn<Device: PartialEq + core::fmt::Debug>(
+        ops: &str,
+        lhs: (&Shape, &Device),
+        rhs: (&Shape, &Device),
+    ) -> Self {
+        let check = Self::Ok
+            .binary_ops_device(ops, lhs.1, rhs.1)
+            .tensor_ranks(ops, &[lhs.0.num_dims(), rhs.0.num_dims()]);
+
+        match check {
+            Self::Ok => check.binary_ops_ew_dims(ops, lhs.0.num_dims(), lhs.0, rhs.0),
+            check => check,
+        }
+    }
+
+    /// Checks that the rank of a tensor matches the rank it is converted to.
+    pub(crate) fn into_rank<const D: usize>(rank: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if rank != D {
+            check = check.register(
+                "Into Tensor",
+                TensorError::new("The tensor rank doesn't match the requested rank.")
+                    .details(format!("Tensor rank: '{rank}', requested rank: '{D}'.")),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that all tensors of an operation have the same rank.
+    pub(crate) fn tensor_ranks(self, ops: &str, ranks: &[usize]) -> Self {
+        let mut check = self;
+
+        if ranks.windows(2).any(|ranks| ranks[0] != ranks[1]) {
+            check = check.register(
+                ops,
+                TensorError::new("The provided tensors have different ranks.")
+                    .details(format!("Tensor ranks: {ranks:?}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn into_scalar(shape: &Shape) -> Self {
         let mut check = Self::Ok;
 
         if shape.num_elements() != 1 {
@@ -67,13 +115,17 @@ impl TensorCheck {
     }
 
     pub(crate) fn dim_ops<const D: usize>(ops: &str, dim: usize) -> Self {
+        Self::dim_ops_rank(ops, D, dim)
+    }
+
+    pub(crate) fn dim_ops_rank(ops: &str, rank: usize, dim: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim >= D {
+        if dim >= rank {
             check = check.register(
                 ops,
                 TensorError::new("Given dimension is higher than the tensor rank.")
-                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+                    .details(format!("Tensor rank: '{rank}', given dimension: '{dim}'.")),
             );
         }
 
@@ -81,33 +133,35 @@ impl TensorCheck {
     }
 
     pub(crate) fn creation_ops<const D: usize>(ops: &str, dims: &[usize]) -> Self {
-        let mut check = Self::Ok;
+        let mut check = Self::creation_ops_rank(ops, D, dims);
 
-        if D == 0 {
+        if dims.len() != D {
             check = check.register(
                 ops,
-                TensorError::new("Tried to create a 0-dim tensor, which is invalid.")
+                TensorError::new("Given dimensions differ from the tensor rank.")
                     .details(format!("Tensor rank: '{D}', given dimensions: '{dims:?}'.")),
             );
         }
 
-        if dims.len() != D {
+        check
+    }
+
+    pub(crate) fn creation_ops_rank(ops: &str, rank: usize, dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        if rank == 0 {
             check = check.register(
                 ops,
-                TensorError::new("Given dimensions differ from the tensor rank.")
-                    .details(format!("Tensor rank: '{D}', given dimensions: '{dims:?}'.")),
+                TensorError::new("Tried to create a 0-dim tensor, which is invalid.").details(
+                    format!("Tensor rank: '{rank}', given dimensions: '{dims:?}'."),
+                ),
             );
         }
 
         check
     }
 
-    pub(crate) fn narrow<B: Backend, const D: usize, K: BasicOps<B>>(
-        tensor: &Tensor<B, D, K>,
-        dim: usize,
-        start: usize,
-        length: usize,
-    ) -> Self {
+    pub(crate) fn narrow(shape: &Shape, dim: usize, start: usize, length: usize) -> Self {
         let mut check = Self::Ok;
 
         if length == 0 {
@@ -119,24 +173,24 @@ impl TensorCheck {
             );
         }
 
-        if start >= tensor.shape().dims[dim] {
+        if start >= shape.dims[dim] {
             check = check.register(
                 "Narrow",
                 TensorError::new(format!(
                     "Can't narrow at dimension {dim}, start exceeds the size of the tensor along \
                      this dimension (Size={})",
-                    tensor.shape().dims[dim]
+                    shape.dims[dim]
                 )),
             );
         }
 
-        if start + length > tensor.shape().dims[dim] {
+        if start + length > shape.dims[dim] {
             check = check.register(
                 "Narrow",
                 TensorError::new(format!(
                     "Can't narrow at dimension {dim}, start + length exceeds the size of the tensor \
                      along this dimension (Size={})",
-                    tensor.shape().dims[dim]
+                    shape.dims[dim]
                 )),
             );
         }
@@ -144,10 +198,7 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn reshape_args_usize<const D1: usize, const D2: usize>(
-        original: &Shape,
-        target: &Shape,
-    ) -> Self {
+    pub(crate) fn reshape_args_usize(original: &Shape, target: &Shape) -> Self {
         let mut check = Self::Ok;
 
         if original.num_elements() != target.num_elements() {
@@ -284,41 +335,47 @@ impl TensorCheck {
         start_dim: usize,
         end_dim: usize,
     ) -> Self {
-        let mut check = Self::Ok;
+        let mut check = Self::flatten_rank(D1, start_dim, end_dim);
 
-        if start_dim > end_dim {
+        if D2 > D1 {
             check = check.register(
                 "Flatten",
                 TensorError::new(format!(
-                    "The start dim ({start_dim}) must be smaller than or equal to the end dim ({end_dim})"
+                    "Result dim ({D2}) must be smaller than or equal to ({D1})"
                 )),
             );
         }
 
-        if D2 > D1 {
+        if (D2 as i32) < (D1 as i32 - (end_dim as i32 - start_dim as i32)) {
             check = check.register(
                 "Flatten",
                 TensorError::new(format!(
-                    "Result dim ({D2}) must be smaller than or equal to ({D1})"
+                    "The destination dimension ({D2}) must be large enough to accommodate the \
+                     flattening operation."
                 )),
             );
         }
 
-        if D1 < end_dim + 1 {
+        check
+    }
+
+    pub(crate) fn flatten_rank(rank: usize, start_dim: usize, end_dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if start_dim > end_dim {
             check = check.register(
                 "Flatten",
                 TensorError::new(format!(
-                    "The end dim ({end_dim}) must be smaller than the tensor dim ({D1})"
+                    "The start dim ({start_dim}) must be smaller than or equal to the end dim ({end_dim})"
                 )),
             );
         }
 
-        if (D2 as i32) < (D1 as i32 - (end_dim as i32 - start_dim as i32)) {
+        if rank < end_dim + 1 {
             check = check.register(
                 "Flatten",
                 TensorError::new(format!(
-                    "The destination dimension ({D2}) must be large enough to accommodate the \
-                     flattening operation."
+                    "The end dim ({end_dim}) must be smaller than the tensor dim ({rank})"
                 )),
             );
         }
@@ -341,11 +398,11 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn squeeze<const D2: usize>(dim: usize, tensor_dims: &[usize]) -> Self {
+    pub(crate) fn squeeze(dim: usize, tensor_dims: &[usize]) -> Self {
         let mut check = Self::Ok;
         // This should actually be to check that the dimension to squeeze
         // has a size of 1
-        if tensor_dims[dim] != 1 {
+        if dim < tensor_dims.len() && tensor_dims[dim] != 1 {
             check = check.register(
                 "Squeeze",
                 TensorError::new(format!(
@@ -420,20 +477,28 @@ impl TensorCheck {
             );
         }
 
-        if dim > D1 {
+        check = check.unsqueeze_dim_rank(D1, dim);
+
+        if dim >= D2 {
             check = check.register(
                 "Unsqueeze",
                 TensorError::new(format!(
-                    "Can't unsqueeze at dimension {dim}, exceeds tensor dimensions (D={D1})",
+                    "Can't unsqueeze at dimension {dim}, exceeds output tensor dimensions (D2={D2})",
                 )),
             );
         }
 
-        if dim >= D2 {
+        check
+    }
+
+    pub(crate) fn unsqueeze_dim_rank(self, rank: usize, dim: usize) -> Self {
+        let mut check = self;
+
+        if dim > rank {
             check = check.register(
                 "Unsqueeze",
                 TensorError::new(format!(
-                    "Can't unsqueeze at dimension {dim}, exceeds output tensor dimensions (D2={D2})",
+                    "Can't unsqueeze at dimension {dim}, exceeds tensor dimensions (D={rank})",
                 )),
             );
         }
@@ -498,14 +563,18 @@ impl TensorCheck {
     }
 
     pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
+        Self::swap_dims_rank(D, dim1, dim2)
+    }
+
+    pub(crate) fn swap_dims_rank(rank: usize, dim1: usize, dim2: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim1 > D || dim2 > D {
+        if dim1 > rank || dim2 > rank {
             check = check.register(
                 "Swap Dims",
                 TensorError::new("The swap dimensions must be smaller than the tensor dimension")
                     .details(format!(
-                        "Swap dims ({dim1}, {dim2}) on tensor with ({D}) dimensions."
+                        "Swap dims ({dim1}, {dim2}) on tensor with ({rank}) dimensions."
                     )),
             );
         }
@@ -514,19 +583,32 @@ impl TensorCheck {
     }
 
     pub(crate) fn permute<const D: usize>(axes: [usize; D]) -> Self {
+        Self::permute_rank(D, &axes)
+    }
+
+    pub(crate) fn permute_rank(rank: usize, axes: &[usize]) -> Self {
         let check = Self::Ok;
 
+        if axes.len() != rank {
+            return check.register(
+                "permute",
+                TensorError::new("The number of axes must be equal to the tensor rank.")
+                    .details(format!("Tensor rank: '{rank}', given axes: '{axes:?}'.")),
+            );
+        }
+
         // Check if the axes are within the tensor dimensions
-        if let Some(axis) = axes.iter().find(|&x| *x >= D) {
+        if let Some(axis) = axes.iter().find(|&x| *x >= rank) {
             return check.register(
                 "permute",
-                TensorError::new("The axes must be smaller than the tensor dimension.")
-                    .details(format!("The '{axis}' axis is greater than {D} dimensions.")),
+                TensorError::new("The axes must be smaller than the tensor dimension.").details(
+                    format!("The '{axis}' axis is greater than {rank} dimensions."),
+                ),
             );
         }
 
         // Check if the axes are unique
-        let mut seen = [false; D];
+        let mut seen = vec![false; rank];
         axes.iter().for_each(|&x| seen[x] = true);
         if seen.iter().any(|&x| !x) {
             return check.register(
@@ -574,19 +656,35 @@ impl TensorCheck {
     where
         K: BasicOps<B>,
     {
-        let mut check = Self::Ok;
+        Self::Ok
+            .binary_ops_device("Matmul", &lhs.device(), &rhs.device())
+            .matmul_shape(&lhs.shape(), &rhs.shape())
+    }
 
-        check = check.binary_ops_device("Matmul", &lhs.device(), &rhs.device());
+    pub(crate) fn matmul_dyn<Device: PartialEq + core::fmt::Debug>(
+        lhs: (&Shape, &Device),
+        rhs: (&Shape, &Device),
+    ) -> Self {
+        let check = Self::Ok
+            .binary_ops_device("Matmul", lhs.1, rhs.1)
+            .tensor_ranks("Matmul", &[lhs.0.num_dims(), rhs.0.num_dims()]);
 
-        if D < 2 {
-            return check;
+        match check {
+            Self::Ok => check.matmul_shape(lhs.0, rhs.0),
+            check => check,
         }
+    }
+
+    fn matmul_shape(self, shape_lhs: &Shape, shape_rhs: &Shape) -> Self {
+        let mut check = self;
+        let rank = shape_lhs.num_dims();
 
-        let shape_lhs = lhs.shape();
-        let shape_rhs = rhs.shape();
+        if rank < 2 {
+            return check;
+        }
 
-        let dim_lhs = shape_lhs.dims[D - 1];
-        let dim_rhs = shape_rhs.dims[D - 2];
+        let dim_lhs = shape_lhs.dims[rank - 1];
+        let dim_rhs = shape_rhs.dims[rank - 2];
 
         if dim_lhs != dim_rhs {
             check = check.register(
@@ -605,24 +703,262 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn stack<B: Backend, const D1: usize, K: BasicOps<B>, const D2: usize>(
-        tensors: &[Tensor<B, D1, K>],
+    pub(crate) fn einsum<const D2: usize>(equation: &str, shapes: &[Shape]) -> Self {
+        let mut check = Self::Ok;
+
+        if shapes.is_empty() {
+            return check.register(
+                "Einsum",
+                TensorError::new("Can't compute einsum of an empty list of tensors."),
+            );
+        }
+
+        let ranks = shapes.iter().map(Shape::num_dims).collect::<Vec<_>>();
+        let parsed = match EinsumEquation::parse(equation, &ranks) {
+            Ok(parsed) => parsed,
+            Err(reason) => {
+                return check.register(
+                    "Einsum",
+                    TensorError::new("Invalid einsum equation.")
+                        .details(format!("{reason} Equation: '{equation}'.")),
+                );
+            }
+        };
+
+        if parsed.output.len() != D2 {
+            check = check.register(
+                "Einsum",
+                TensorError::new(format!(
+                    "The einsum output has {} dimensions, but the output tensor has {D2} \
+                     dimensions.",
+                    parsed.output.len()
+                ))
+                .details(format!("Equation: '{equation}'.")),
+            );
+        }
+
+        let mut sizes: Vec<(usize, usize)> = Vec::new();
+
+        for (labels, shape) in parsed.inputs.iter().zip(shapes) {
+            for (i, (label, size)) in labels.iter().zip(&shape.dims).enumerate() {
+                let repeated = labels[..i]
+                    .iter()
+                    .zip(&shape.dims)
+                    .find(|(l, s)| *l == label && *s != size);
+
+                if let Some((_, other)) = repeated {
+                    return check.register(
+                        "Einsum",
+                        TensorError::new(
+                            "A subscript repeated within a term must index dimensions of the \
+                             same size.",
+                        )
+                        .details(format!(
+                            "Got sizes {other} and {size} for the same subscript. Equation: \
+                             '{equation}', tensors shapes: {:?}.",
+                            shapes.iter().map(|shape| &shape.dims).collect::<Vec<_>>()
+                        )),
+                    );
+                }
+
+                match sizes.iter_mut().find(|(l, _)| l == label) {
+                    Some((_, current)) if *current == *size || *size == 1 => {}
+                    Some((_, current)) if *current == 1 => *current = *size,
+                    Some((_, current)) => {
+                        return check.register(
+                            "Einsum",
+                            TensorError::new(
+                                "The dimensions sharing the same subscript have incompatible \
+                                 sizes, which can't be broadcasted.",
+                            )
+                            .details(format!(
+                                "Got sizes {current} and {size} for the same subscript. \
+                                 Equation: '{equation}', tensors shapes: {:?}.",
+                                shapes.iter().map(|shape| &shape.dims).collect::<Vec<_>>()
+                            )),
+                        );
+                    }
+                    None => sizes.push((*label, *size)),
+                }
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn linalg_matrix<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The input tensor must have at least 2 dimensions, got {D}."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn linalg_square<const D: usize>(ops: &str, shape: &Shape) -> Self {
+        let check = Self::linalg_matrix::<D>(ops);
+
+        if D >= 2 && shape.dims[D - 2] != shape.dims[D - 1] {
+            return check.register(
+                ops,
+                TensorError::new("The input matrices must be square.")
+                    .details(format!("Shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the inputs of a system `A X = B`, where `A` has shape `[..., m, n]` and `B` has
+    /// shape `[..., m, r]`.
+    pub(crate) fn linalg_system<const D: usize>(
+        ops: &str,
+        shape_a: &Shape,
+        shape_b: &Shape,
+        square: bool,
+    ) -> Self {
+        let mut check = match square {
+            true => Self::linalg_square::<D>(ops, shape_a),
+            false => Self::linalg_matrix::<D>(ops),
+        };
+
+        if D < 2 {
+            return check;
+        }
+
+        if shape_a.dims[..D - 2] != shape_b.dims[..D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new("The batch dimensions of both tensors must be the same.").details(
+                    format!(
+                        "Lhs shape {:?}, rhs shape {:?}.",
+                        shape_a.dims, shape_b.dims
+                    ),
+                ),
+            );
+        }
+
+        if shape_a.dims[D - 2] != shape_b.dims[D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The right-hand side should have {} rows, but got {}.",
+                    shape_a.dims[D - 2],
+                    shape_b.dims[D - 2]
+                ))
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    shape_a.dims, shape_b.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the real and imaginary parts of a complex tensor transformed along `dim`.
+    pub(crate) fn fft<const D: usize>(
+        ops: &str,
         dim: usize,
+        shape_real: &Shape,
+        shape_imag: &Shape,
     ) -> Self {
         let mut check = Self::Ok;
 
-        if dim > D1 {
+        if dim >= D {
             check = check.register(
-                "Stack",
-                TensorError::new(
-                    "Can't stack tensors on a dim that exceeds the tensors dimension (inclusive)",
-                )
-                .details(format!(
-                    "Trying to concatenate tensors with {D1} dimensions on axis {dim}."
+                ops,
+                TensorError::new(format!(
+                    "Can't transform along dimension {dim} of a tensor of rank {D}."
+                )),
+            );
+        }
+
+        if shape_real != shape_imag {
+            check = check.register(
+                ops,
+                TensorError::new("The real and imaginary parts must have the same shape.").details(
+                    format!(
+                        "Real shape {:?}, imaginary shape {:?}.",
+                        shape_real.dims, shape_imag.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the real and imaginary parts of a complex tensor are compatible.
+    pub(crate) fn complex_parts(ops: &str, shape_real: &Shape, shape_imag: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_real != shape_imag {
+            check = check.register(
+                ops,
+                TensorError::new("The real and imaginary parts must have the same shape.").details(
+                    format!(
+                        "Real shape {:?}, imaginary shape {:?}.",
+                        shape_real.dims, shape_imag.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the framing parameters of a short-time Fourier transform.
+    pub(crate) fn stft(
+        ops: &str,
+        n_fft: usize,
+        hop_length: usize,
+        window_length: usize,
+        signal_length: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if n_fft == 0 || hop_length == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The transform size and the hop length must be non-zero.")
+                    .details(format!("n_fft {n_fft}, hop_length {hop_length}.")),
+            );
+        }
+
+        if window_length > n_fft {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The window length {window_length} can't be greater than the transform size {n_fft}."
                 )),
             );
         }
 
+        if signal_length < n_fft {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The signal length {signal_length} must be at least the transform size {n_fft}."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn stack<B: Backend, const D1: usize, K: BasicOps<B>, const D2: usize>(
+        tensors: &[Tensor<B, D1, K>],
+        dim: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
         if D1 == D2 {
             check = check.register(
                 "Stack",
@@ -633,24 +969,40 @@ impl TensorCheck {
             );
         }
 
-        if tensors.is_empty() {
+        let shapes = tensors.iter().map(Tensor::shape).collect::<Vec<_>>();
+        check.stack_shapes(D1, &shapes, dim)
+    }
+
+    pub(crate) fn stack_shapes(self, rank: usize, shapes: &[Shape], dim: usize) -> Self {
+        let mut check = self;
+
+        if dim > rank {
+            check = check.register(
+                "Stack",
+                TensorError::new(
+                    "Can't stack tensors on a dim that exceeds the tensors dimension (inclusive)",
+                )
+                .details(format!(
+                    "Trying to concatenate tensors with {rank} dimensions on axis {dim}."
+                )),
+            );
+        }
+
+        if shapes.is_empty() {
             return check.register(
                 "Stack",
                 TensorError::new("Can't stack an empty list of tensors."),
             );
         }
 
-        let shape_reference = tensors.first().unwrap().shape();
-
-        for tensor in tensors {
-            let shape = tensor.shape();
+        let shape_reference = shapes.first().unwrap();
 
+        for shape in shapes {
             if shape_reference != shape {
                 return check.register(
                     "Stack",
                     TensorError::new("Can't stack tensors with different shapes").details(format!(
-                        "Provided dimension ({dim}), tensors shapes: {:?}",
-                        tensors.iter().map(Tensor::shape).collect::<Vec<_>>()
+                        "Provided dimension ({dim}), tensors shapes: {shapes:?}"
                     )),
                 );
             }
@@ -663,47 +1015,54 @@ impl TensorCheck {
         tensors: &[Tensor<B, D, K>],
         dim: usize,
     ) -> Self {
+        let shapes = tensors.iter().map(Tensor::shape).collect::<Vec<_>>();
+        Self::cat_shapes(D, &shapes, dim)
+    }
+
+    pub(crate) fn cat_shapes(rank: usize, shapes: &[Shape], dim: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim >= D {
-            check = check.register(
+        if dim >= rank {
+            return check.register(
                 "Cat",
                 TensorError::new(
                     "Can't concatenate tensors on a dim that exceeds the tensors dimension",
                 )
                 .details(format!(
-                    "Trying to concatenate tensors with {D} dimensions on axis {dim}."
+                    "Trying to concatenate tensors with {rank} dimensions on axis {dim}."
                 )),
             );
         }
 
-        if tensors.is_empty() {
+        if shapes.is_empty() {
             return check.register(
                 "Cat",
                 TensorError::new("Can't concatenate an empty list of tensors."),
             );
         }
 
-        let mut shape_reference = tensors.first().unwrap().shape();
+        let mut shape_reference = shapes.first().unwrap().clone();
         shape_reference.dims[dim] = 1; // We want to check every dims except the one where the
         // concatenation happens.
 
-        for tensor in tensors {
-            let mut shape = tensor.shape();
-            shape.dims[dim] = 1; // Ignore the concatenate dim.
+        for shape in shapes {
+            let mut shape = shape.clone();
+            if let Some(size) = shape.dims.get_mut(dim) {
+                *size = 1; // Ignore the concatenate dim.
+            }
 
             if shape_reference != shape {
-                return check.register(
+                check = check.register(
                     "Cat",
                     TensorError::new(
                         "Can't concatenate tensors with different shapes, except for the provided \
                          dimension",
                     )
                     .details(format!(
-                        "Provided dimension ({dim}), tensors shapes: {:?}",
-                        tensors.iter().map(Tensor::shape).collect::<Vec<_>>()
+                        "Provided dimension ({dim}), tensors shapes: {shapes:?}"
                     )),
                 );
+                break;
             }
         }
 
@@ -714,9 +1073,13 @@ impl TensorCheck {
         shape: &Shape,
         ranges: &[Range<usize>; D2],
     ) -> Self {
+        Self::slice_rank(D1, shape, ranges)
+    }
+
+    pub(crate) fn slice_rank(rank: usize, shape: &Shape, ranges: &[Range<usize>]) -> Self {
         let mut check = Self::Ok;
-        let n_dims_tensor = D1;
-        let n_dims_ranges = D2;
+        let n_dims_tensor = rank;
+        let n_dims_ranges = ranges.len();
 
         if n_dims_tensor < n_dims_ranges {
             check = check.register(
@@ -733,7 +1096,7 @@ impl TensorCheck {
             );
         }
 
-        for i in 0..usize::min(D1, D2) {
+        for i in 0..usize::min(n_dims_tensor, n_dims_ranges) {
             let d_tensor = shape.dims[i];
             let range = ranges.get(i).unwrap();
 
@@ -774,11 +1137,21 @@ impl TensorCheck {
     pub(crate) fn slice_assign<const D1: usize, const D2: usize>(
         shape: &Shape,
         shape_value: &Shape,
-        ranges: &[Range<usize>; D2],
+        ranges: &[Range<usize>; D2],
+    ) -> Self {
+        Self::slice_assign_rank(D1, shape, shape_value, ranges)
+    }
+
+    pub(crate) fn slice_assign_rank(
+        rank: usize,
+        shape: &Shape,
+        shape_value: &Shape,
+        ranges: &[Range<usize>],
     ) -> Self {
         let mut check = Self::Ok;
+        let n_dims_ranges = ranges.len();
 
-        if D1 < D2 {
+        if rank < n_dims_ranges {
             check = check.register(
                 "Slice Assign",
                 TensorError::new(
@@ -787,12 +1160,13 @@ impl TensorCheck {
                 )
                 .details(format!(
                     "The ranges array must be smaller or equal to the tensor number of \
-                     dimensions. Tensor number of dimensions: {D1}, ranges array length {D2}."
+                     dimensions. Tensor number of dimensions: {rank}, ranges array length \
+                     {n_dims_ranges}."
                 )),
             );
         }
 
-        for i in 0..usize::min(D1, D2) {
+        for i in 0..usize::min(rank, n_dims_ranges) {
             let d_tensor = shape.dims[i];
             let d_tensor_value = shape_value.dims[i];
             let range = ranges.get(i).unwrap();
@@ -870,7 +1244,18 @@ impl TensorCheck {
     }
 
     pub(crate) fn gather<const D: usize>(dim: usize, shape: &Shape, shape_indices: &Shape) -> Self {
-        Self::check_gather_scatter_indices::<D>(Self::Ok, "Gather", dim, shape, shape_indices)
+        Self::gather_rank(D, dim, shape, shape_indices)
+    }
+
+    pub(crate) fn gather_rank(
+        rank: usize,
+        dim: usize,
+        shape: &Shape,
+        shape_indices: &Shape,
+    ) -> Self {
+        Self::Ok
+            .tensor_ranks("Gather", &[rank, shape_indices.num_dims()])
+            .check_gather_scatter_indices(rank, "Gather", dim, shape, shape_indices)
     }
 
     pub(crate) fn scatter<const D: usize>(
@@ -880,8 +1265,7 @@ impl TensorCheck {
         shape_value: &Shape,
     ) -> Self {
         let ops = "Scatter";
-        let mut check =
-            Self::check_gather_scatter_indices::<D>(Self::Ok, ops, dim, shape, shape_indices);
+        let mut check = Self::Ok.check_gather_scatter_indices(D, ops, dim, shape, shape_indices);
 
         if shape_indices != shape_value {
             check = check.register(
@@ -901,42 +1285,153 @@ impl TensorCheck {
     }
 
     pub(crate) fn select<const D: usize>(dim: usize) -> Self {
-        Self::check_select_basic::<D>(Self::Ok, "select", dim)
+        Self::select_rank(D, dim)
+    }
+
+    pub(crate) fn select_rank(rank: usize, dim: usize) -> Self {
+        Self::Ok.check_select_basic(rank, "select", dim)
     }
 
     pub(crate) fn select_assign<const D: usize>(dim: usize) -> Self {
-        Self::check_select_basic::<D>(Self::Ok, "select_assign", dim)
+        Self::Ok.check_select_basic(D, "select_assign", dim)
     }
 
-    fn check_select_basic<const D: usize>(mut check: Self, ops: &str, dim: usize) -> Self {
-        if dim > D {
+    pub(crate) fn gather_nd<const DO: usize>(shape: &Shape, shape_indices: &Shape) -> Self {
+        let ops = "Gather ND";
+        let mut check = Self::check_nd_indices(Self::Ok, ops, shape, shape_indices);
+
+        if matches!(check, Self::Ok) {
+            let k = shape_indices.dims[shape_indices.num_dims() - 1];
+            let rank = shape_indices.num_dims() - 1 + shape.num_dims() - k;
+
+            if rank != DO {
+                check = check.register(
+                    ops,
+                    TensorError::new(format!(
+                        "The output rank ({DO}) should be the number of index tuple dimensions \
+                         plus the number of non-indexed tensor dimensions ({rank})."
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn scatter_nd(shape: &Shape, shape_indices: &Shape, shape_values: &Shape) -> Self {
+        let ops = "Scatter ND";
+        let mut check = Self::check_nd_indices(Self::Ok, ops, shape, shape_indices);
+
+        if matches!(check, Self::Ok) {
+            let k = shape_indices.dims[shape_indices.num_dims() - 1];
+            let mut expected = shape_indices.dims[..shape_indices.num_dims() - 1].to_vec();
+            expected.extend_from_slice(&shape.dims[k..]);
+
+            if expected != shape_values.dims {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The values shape should be the index tuples shape followed by the \
+                         non-indexed tensor dimensions."
+                            .to_string(),
+                    )
+                    .details(format!(
+                        "Expected values shape {:?}, got {:?}",
+                        expected, shape_values.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn index_put<const D: usize>(num_indices: usize, shape_indices: &[Shape]) -> Self {
+        let ops = "Index Put";
+        let mut check = Self::Ok;
+
+        if num_indices == 0 || num_indices > D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Expected between 1 and {D} index tensors, got {num_indices}."
+                )),
+            );
+        }
+
+        if shape_indices
+            .windows(2)
+            .any(|shapes| shapes[0] != shapes[1])
+        {
+            check = check.register(
+                ops,
+                TensorError::new("All index tensors should have the same length.".to_string())
+                    .details(format!(
+                        "Index tensor shapes: {:?}",
+                        shape_indices
+                            .iter()
+                            .map(|shape| shape.dims[0])
+                            .collect::<Vec<_>>()
+                    )),
+            );
+        }
+
+        check
+    }
+
+    fn check_nd_indices(mut check: Self, ops: &str, shape: &Shape, shape_indices: &Shape) -> Self {
+        let k = shape_indices.dims[shape_indices.num_dims() - 1];
+
+        if k == 0 || k > shape.num_dims() {
             check = check.register(
                 ops,
                 TensorError::new(format!(
-                    "Can't index a tensor with ({D}) dimensions on axis ({dim})"
+                    "The last dimension of the indices ({k}) should be between 1 and the tensor \
+                     rank ({}).",
+                    shape.num_dims()
                 )),
             );
         }
 
         check
     }
-    fn check_gather_scatter_indices<const D: usize>(
-        mut check: Self,
+
+    fn check_select_basic(mut self, rank: usize, ops: &str, dim: usize) -> Self {
+        if dim > rank {
+            self = self.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't index a tensor with ({rank}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        self
+    }
+    fn check_gather_scatter_indices(
+        self,
+        rank: usize,
         ops: &str,
         dim: usize,
         shape: &Shape,
         shape_indices: &Shape,
     ) -> Self {
-        if dim > D {
+        let mut check = self;
+
+        if dim > rank {
             check = check.register(
                 ops,
                 TensorError::new(format!(
-                    "Can't index a tensor with ({D}) dimensions on axis ({dim})"
+                    "Can't index a tensor with ({rank}) dimensions on axis ({dim})"
                 )),
             );
         }
 
-        for i in 0..D {
+        if shape_indices.num_dims() != rank {
+            return check;
+        }
+
+        for i in 0..rank {
             if i == dim {
                 continue;
             }
@@ -1001,13 +1496,17 @@ impl TensorCheck {
 
     /// Checks aggregate dimension such as mean and sum.
     pub(crate) fn aggregate_dim<const D: usize>(ops: &str, dim: usize) -> Self {
+        Self::aggregate_dim_rank(ops, D, dim)
+    }
+
+    pub(crate) fn aggregate_dim_rank(ops: &str, rank: usize, dim: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim > D {
+        if dim > rank {
             check = check.register(
                 ops,
                 TensorError::new(format!(
-                    "Can't aggregate a tensor with ({D}) dimensions on axis ({dim})"
+                    "Can't aggregate a tensor with ({rank}) dimensions on axis ({dim})"
                 )),
             );
         }
@@ -1015,6 +1514,26 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn aggregate_masked<const D: usize>(
+        ops: &str,
+        shape: &Shape,
+        shape_mask: &Shape,
+        dim: usize,
+    ) -> Self {
+        let mut check = Self::aggregate_dim::<D>(ops, dim);
+
+        if shape != shape_mask {
+            check = check.register(
+                ops,
+                TensorError::new("The mask must have the same shape as the tensor.").details(
+                    format!("Tensor shape: {shape:?}, mask shape: {shape_mask:?}."),
+                ),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn sort_dim<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
 
@@ -1030,6 +1549,172 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn order_statistic<const D: usize>(ops: &str, dims: &[usize], dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new("Given dimension is greater than or equal to the tensor rank.")
+                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+        } else if dims[dim] == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("Can't compute an order statistic along an empty dimension.")
+                    .details(format!("Tensor shape: {dims:?}, given dimension: '{dim}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn kthvalue<const D: usize>(dims: &[usize], k: usize, dim: usize) -> Self {
+        let ops = "KthValue";
+        let mut check = Self::order_statistic::<D>(ops, dims, dim);
+
+        if dim < D && (k == 0 || k > dims[dim]) {
+            check = check.register(
+                ops,
+                TensorError::new("The value of k must be between 1 and the size of the dimension.")
+                    .details(format!(
+                        "k: '{k}', tensor size along dim '{dim}': '{}'.",
+                        dims[dim]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantile<const D: usize>(dims: &[usize], q: f64, dim: usize) -> Self {
+        let ops = "Quantile";
+        let mut check = Self::order_statistic::<D>(ops, dims, dim);
+
+        if !(0.0..=1.0).contains(&q) {
+            check = check.register(
+                ops,
+                TensorError::new("The quantile must be between 0 and 1.")
+                    .details(format!("q: '{q}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn searchsorted<const D: usize>(
+        sorted_dims: &[usize],
+        values_dims: &[usize],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D == 0 {
+            check = check.register(
+                "Searchsorted",
+                TensorError::new("The sorted tensor must have at least one dimension."),
+            );
+        } else if sorted_dims[..D - 1] != values_dims[..D - 1] {
+            check = check.register(
+                "Searchsorted",
+                TensorError::new(
+                    "All dimensions except the last one of the sorted tensor and the values must \
+                     be equal.",
+                )
+                .details(format!(
+                    "Sorted tensor shape {sorted_dims:?}, values shape {values_dims:?}."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bincount_values(min: i64) -> Self {
+        let mut check = Self::Ok;
+
+        if min < 0 {
+            check = check.register(
+                "Bincount",
+                TensorError::new("The tensor must only contain non-negative values.")
+                    .details(format!("Minimum value: '{min}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bincount_weights(shape: &Shape, weights: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != weights {
+            check = check.register(
+                "Bincount",
+                TensorError::new("The weights must have the same shape as the tensor.").details(
+                    format!("Tensor shape {shape:?}, weights shape {weights:?}."),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn histc(bins: usize, min: f64, max: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if bins == 0 {
+            check = check.register(
+                "Histc",
+                TensorError::new("The number of bins must be greater than 0."),
+            );
+        }
+
+        if min > max {
+            check = check.register(
+                "Histc",
+                TensorError::new("The minimum must be lower or equal to the maximum.")
+                    .details(format!("min: '{min}', max: '{max}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial(
+        num_categories: usize,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if num_categories == 0 {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("The number of categories must be greater than 0."),
+            );
+        }
+
+        if num_samples == 0 {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("The number of samples must be greater than 0."),
+            );
+        }
+
+        if !replacement && num_samples > num_categories {
+            check = check.register(
+                "Multinomial",
+                TensorError::new(
+                    "Can't draw more samples than there are categories without replacement.",
+                )
+                .details(format!(
+                    "num_samples: '{num_samples}', num_categories: '{num_categories}'."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn split<const D: usize>(
         tensor_dims: &[usize],
         split_size: usize,
@@ -1115,9 +1800,13 @@ impl TensorCheck {
         lhs: &Shape,
         rhs: &Shape,
     ) -> Self {
+        self.binary_ops_ew_dims(ops, D, lhs, rhs)
+    }
+
+    fn binary_ops_ew_dims(self, ops: &str, rank: usize, lhs: &Shape, rhs: &Shape) -> Self {
         let mut check = self;
 
-        for i in 0..D {
+        for i in 0..rank {
             let d_lhs = lhs.dims[i];
             let d_rhs = rhs.dims[i];
 
@@ -1164,12 +1853,18 @@ impl TensorCheck {
 
     /// Checks if expand operation is possible for the given shapes.
     pub fn expand<const D1: usize, const D2: usize>(ops: &str, shape: &Shape, to: &Shape) -> Self {
+        Self::expand_rank(ops, shape, to)
+    }
+
+    /// Checks if expand operation is possible for the given shapes, using their runtime ranks.
+    pub fn expand_rank(ops: &str, shape: &Shape, to: &Shape) -> Self {
         let mut check = TensorCheck::Ok;
-        let max_dims = core::cmp::max(D1, D2);
+        let (rank, rank_to) = (shape.num_dims(), to.num_dims());
+        let max_dims = core::cmp::max(rank, rank_to);
 
         // Calculate the starting indices for each shape array, ensuring alignment from the right.
-        let start_index_shape = max_dims.saturating_sub(D1);
-        let start_index_to = max_dims.saturating_sub(D2);
+        let start_index_shape = max_dims.saturating_sub(rank);
+        let start_index_to = max_dims.saturating_sub(rank_to);
 
         for i in 0..max_dims {
             // Use 1 as the default dimension size for dimensions beyond the tensor's rank.
@@ -1228,6 +1923,21 @@ impl TensorCheck {
         check
     }
 
+    /// Checks if input is compatible with linear weights.
+    pub fn linear<const D: usize>(ops: &str, x: [usize; D], weight: [usize; 2]) -> Self {
+        let mut check = TensorCheck::Ok;
+        let features = x[D - 1];
+        let expected = weight[0];
+        if features != expected {
+            check = check.register(
+                ops,
+                TensorError::new("Number of features in input tensor and input features of linear weights must be equal.")
+                .details(format!("got: {features}, expected: {expected}")),
+            );
+        }
+        check
+    }
+
     /// Checks if input is compatible with transposed convolution weights.
     pub fn conv_transpose<const D1: usize, const D2: usize>(
         ops: &str,
@@ -1246,6 +1956,63 @@ impl TensorCheck {
         }
         check
     }
+
+    /// Checks if the grid is compatible with the input of grid sampling.
+    pub fn grid_sample<const D: usize>(
+        ops: &str,
+        x: [usize; D],
+        grid: [usize; D],
+        bicubic: bool,
+    ) -> Self {
+        let mut check = TensorCheck::Ok;
+        let num_spatial = D - 2;
+
+        if x[0] != grid[0] {
+            check = check.register(
+                ops,
+                TensorError::new("The input and the grid must have the same batch size.")
+                    .details(format!("input: {}, grid: {}", x[0], grid[0])),
+            );
+        }
+
+        if grid[D - 1] != num_spatial {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The last dimension of the grid must match the number of spatial dimensions.",
+                )
+                .details(format!("got: {}, expected: {num_spatial}", grid[D - 1])),
+            );
+        }
+
+        if bicubic && num_spatial != 2 {
+            check = check.register(
+                ops,
+                TensorError::new("Bicubic sampling is only supported for 2D inputs."),
+            );
+        }
+
+        check
+    }
+
+    /// Checks if the affine matrices are compatible with the output size of an affine grid.
+    pub fn affine_grid<const D: usize>(ops: &str, theta: [usize; 3], size: [usize; D]) -> Self {
+        let mut check = TensorCheck::Ok;
+        let num_spatial = D - 2;
+        let expected = [size[0], num_spatial, num_spatial + 1];
+
+        if theta != expected {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The affine matrices must have shape [batch_size, dims, dims + 1].",
+                )
+                .details(format!("got: {theta:?}, expected: {expected:?}")),
+            );
+        }
+
+        check
+    }
 }
 
 pub(crate) struct FailedTensorCheck {
@@ -1321,7 +2088,7 @@ mod tests {
     #[test]
     #[should_panic]
     fn reshape_invalid_shape() {
-        check!(TensorCheck::reshape_args_usize::<2, 2>(
+        check!(TensorCheck::reshape_args_usize(
             &Shape::new([2, 2]),
             &Shape::new([1, 3])
         ));
@@ -1329,7 +2096,7 @@ mod tests {
 
     #[test]
     fn reshape_valid_shape() {
-        check!(TensorCheck::reshape_args_usize::<2, 2>(
+        check!(TensorCheck::reshape_args_usize(
             &Shape::new([2, 2]),
             &Shape::new([1, 4])
         ));
@@ -1374,6 +2141,17 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[should_panic]
+    fn linear_input_features_mismatch() {
+        check!(TensorCheck::linear("linear", [2, 3], [4, 5]));
+    }
+
+    #[test]
+    fn linear_input_features_match() {
+        check!(TensorCheck::linear("linear", [2, 3], [3, 5]));
+    }
+
     #[test]
     #[should_panic]
     fn binary_ops_devices() {
@@ -0,0 +1,140 @@
+use crate::{
+    Complex, ComplexTensorPrimitive, Tensor, TensorPrimitive, backend::Backend, check,
+    check::TensorCheck,
+};
+
+impl<const D: usize, B> Tensor<B, D, Complex>
+where
+    B: Backend,
+{
+    /// Creates a complex tensor from its real and imaginary parts.
+    ///
+    /// To compute gradients with respect to a complex tensor, mark both parts as requiring
+    /// gradients before creating it.
+    ///
+    /// # Panics
+    ///
+    /// If the real and imaginary parts don't have the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Complex, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let real = Tensor::<B, 1>::from_floats([1.0, 0.0], &device);
+    ///     let imag = Tensor::<B, 1>::from_floats([2.0, -1.0], &device);
+    ///     let tensor = Tensor::<B, 1, Complex>::from_parts(real, imag);
+    ///     println!("{tensor}");
+    ///     // [1+2i, 0-1i]
+    /// }
+    /// ```
+    pub fn from_parts(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        check!(TensorCheck::complex_parts(
+            "Complex",
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        Self::new(ComplexTensorPrimitive::new(
+            real.primitive.tensor(),
+            imag.primitive.tensor(),
+        ))
+    }
+
+    /// Creates a complex tensor from a real tensor, with a zero imaginary part.
+    pub fn from_real(real: Tensor<B, D>) -> Self {
+        let imag = real.zeros_like();
+        Self::from_parts(real, imag)
+    }
+
+    /// Returns the real and imaginary parts of the tensor.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        let ComplexTensorPrimitive { real, imag } = self.primitive;
+
+        (
+            Tensor::new(TensorPrimitive::Float(real)),
+            Tensor::new(TensorPrimitive::Float(imag)),
+        )
+    }
+
+    /// Returns the real part of the tensor.
+    pub fn real(self) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(self.primitive.real))
+    }
+
+    /// Returns the imaginary part of the tensor.
+    pub fn imag(self) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(self.primitive.imag))
+    }
+
+    /// Applies element wise complex conjugation.
+    ///
+    /// `y = a - bi` for `x = a + bi`
+    pub fn conj(self) -> Self {
+        Self::new(B::complex_conj(self.primitive))
+    }
+
+    /// Returns the modulus of each element.
+    ///
+    /// `y = sqrt(a² + b²)` for `x = a + bi`
+    pub fn abs(self) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(B::complex_abs(self.primitive)))
+    }
+
+    /// Returns the argument of each element, in radians in `[-π, π]`.
+    ///
+    /// `y = atan2(b, a)` for `x = a + bi`
+    pub fn angle(self) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(B::complex_angle(self.primitive)))
+    }
+
+    /// Applies element wise negation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> Self {
+        Self::new(B::complex_neg(self.primitive))
+    }
+
+    /// Applies element wise addition.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Add", &self, &other));
+        Self::new(B::complex_add(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise subtraction.
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Sub", &self, &other));
+        Self::new(B::complex_sub(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise multiplication.
+    ///
+    /// `(a + bi)(c + di) = (ac - bd) + (ad + bc)i`
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Mul", &self, &other));
+        Self::new(B::complex_mul(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise division.
+    ///
+    /// `(a + bi) / (c + di) = ((ac + bd) + (bc - ad)i) / (c² + d²)`
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Div", &self, &other));
+        Self::new(B::complex_div(self.primitive, other.primitive))
+    }
+
+    /// Performs the matrix multiplication of two complex tensors.
+    ///
+    /// Like [matmul](Tensor::matmul) for float tensors, the last two dimensions are used as the
+    /// matrix dimensions and the other dimensions are broadcast.
+    pub fn matmul(self, other: Self) -> Self {
+        check!(TensorCheck::matmul(&self, &other));
+        Self::new(B::complex_matmul(self.primitive, other.primitive))
+    }
+}
@@ -0,0 +1,1007 @@
+use alloc::vec::Vec;
+
+use crate::{
+    BasicOps, Bool, DType, Distribution, Element, ElementConversion, Float, Int, Numeric, Shape,
+    Slice, Tensor, TensorData, TensorKind, TensorMetadata, TensorPrimitive, backend::Backend,
+    check, check::TensorCheck,
+};
+
+/// A tensor with a given backend and data type, whose rank is only known at runtime.
+///
+/// [Tensor](Tensor) carries its rank as a const generic, which catches rank mismatches at compile
+/// time. Code driven by runtime information, such as model interpreters or generic data
+/// pipelines, can use a dynamic-rank tensor instead, where the same validations are performed when
+/// the operations are called.
+///
+/// Converting between both types is free, the rank is checked when converting back to a
+/// [Tensor](Tensor).
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, TensorDyn};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+///
+///     // The rank is now only known at runtime.
+///     let tensor: TensorDyn<B> = tensor.into_dyn();
+///     let tensor = tensor.unsqueeze_dim(0).exp();
+///     assert_eq!(tensor.rank(), 3);
+///
+///     // Convert it back to a tensor with a static rank.
+///     let tensor = tensor.into_tensor::<3>();
+///     println!("{tensor}");
+/// }
+/// ```
+#[derive(new, Clone, Debug)]
+pub struct TensorDyn<B, K = Float>
+where
+    B: Backend,
+    K: TensorKind<B>,
+{
+    pub(crate) primitive: K::Primitive,
+}
+
+impl<B, const D: usize, K> From<Tensor<B, D, K>> for TensorDyn<B, K>
+where
+    B: Backend,
+    K: TensorKind<B>,
+{
+    fn from(tensor: Tensor<B, D, K>) -> Self {
+        Self::new(tensor.primitive)
+    }
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: TensorKind<B>,
+{
+    /// Converts the tensor into a [dynamic-rank tensor](TensorDyn).
+    pub fn into_dyn(self) -> TensorDyn<B, K> {
+        TensorDyn::new(self.primitive)
+    }
+}
+
+impl<B, K> TensorDyn<B, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Converts from a primitive tensor into a dynamic-rank tensor.
+    pub fn from_primitive(tensor: K::Primitive) -> Self {
+        Self::new(tensor)
+    }
+
+    /// Converts the tensor into a primitive tensor.
+    pub fn into_primitive(self) -> K::Primitive {
+        self.primitive
+    }
+
+    /// Converts the tensor into a [Tensor](Tensor) of rank `D`.
+    ///
+    /// # Panics
+    ///
+    /// If the rank of the tensor isn't `D`.
+    pub fn into_tensor<const D: usize>(self) -> Tensor<B, D, K> {
+        check!(TensorCheck::into_rank::<D>(self.rank()));
+        Tensor::new(self.primitive)
+    }
+
+    /// Returns the number of dimensions of the tensor.
+    pub fn rank(&self) -> usize {
+        self.primitive.shape().num_dims()
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> Shape {
+        self.primitive.shape()
+    }
+
+    /// Returns the dimensions of the tensor.
+    pub fn dims(&self) -> Vec<usize> {
+        self.shape().dims
+    }
+
+    /// Returns the data type of the tensor.
+    pub fn dtype(&self) -> DType {
+        self.primitive.dtype()
+    }
+
+    /// Returns the device of the tensor.
+    pub fn device(&self) -> B::Device {
+        K::device(&self.primitive)
+    }
+
+    /// Move the tensor to the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self::new(K::to_device(self.primitive, device))
+    }
+
+    /// Create an empty tensor of the given shape.
+    pub fn empty<S: Into<Shape>>(shape: S, device: &B::Device) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::creation_ops_rank(
+            "Empty",
+            shape.num_dims(),
+            &shape.dims
+        ));
+        Self::new(K::empty(shape, device))
+    }
+
+    /// Create a tensor from the given data on the given device, with the rank of the data.
+    pub fn from_data<T>(data: T, device: &B::Device) -> Self
+    where
+        T: Into<TensorData>,
+    {
+        let data = data.into();
+        check!(TensorCheck::creation_ops_rank(
+            "From Data",
+            data.shape.len(),
+            data.shape.as_slice()
+        ));
+        Self::new(K::from_data(data, device))
+    }
+
+    /// Converts the data of the current tensor.
+    pub fn into_data(self) -> TensorData {
+        crate::try_read_sync(self.into_data_async()).expect(
+            "Failed to read tensor data synchronously.
+        This can happen on platforms that don't support blocking futures like WASM.
+        If possible, try using into_data_async instead.",
+        )
+    }
+
+    /// Converts the data of the current tensor.
+    pub fn to_data(&self) -> TensorData {
+        self.clone().into_data()
+    }
+
+    /// Returns the data of the current tensor.
+    pub async fn into_data_async(self) -> TensorData {
+        K::into_data_async(self.primitive).await
+    }
+
+    /// Convert a tensor with a single element into a scalar.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have one element.
+    pub fn into_scalar(self) -> K::Elem {
+        check!(TensorCheck::into_scalar(&self.shape()));
+        self.into_data().iter().next().unwrap()
+    }
+
+    /// Reshape the tensor to have the given shape, which can be of a different rank.
+    ///
+    /// # Panics
+    ///
+    /// If the shape doesn't have the same number of elements as the tensor.
+    pub fn reshape<S: Into<Shape>>(self, shape: S) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::reshape_args_usize(&self.shape(), &shape));
+        Self::new(K::reshape(self.primitive, shape))
+    }
+
+    /// Transpose the last two dimensions of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions.
+    pub fn transpose(self) -> Self {
+        check!(TensorCheck::dim_ops_rank("Transpose", self.rank(), 1));
+        Self::new(K::transpose(self.primitive))
+    }
+
+    /// Swaps two dimensions of the tensor.
+    pub fn swap_dims(self, dim1: usize, dim2: usize) -> Self {
+        check!(TensorCheck::swap_dims_rank(self.rank(), dim1, dim2));
+        Self::new(K::swap_dims(self.primitive, dim1, dim2))
+    }
+
+    /// Permute the dimensions of the tensor.
+    ///
+    /// The number of axes must be equal to the rank of the tensor, and negative axes are counted
+    /// from the end.
+    pub fn permute(self, axes: &[isize]) -> Self {
+        let rank = self.rank();
+        let axes = axes
+            .iter()
+            .map(|&axis| {
+                if axis < 0 {
+                    (rank as isize + axis) as usize
+                } else {
+                    axis as usize
+                }
+            })
+            .collect::<Vec<_>>();
+
+        check!(TensorCheck::permute_rank(rank, &axes));
+        Self::new(K::permute(self.primitive, &axes))
+    }
+
+    /// Reverse the order of elements in the tensor along the given axes, where negative axes are
+    /// counted from the end.
+    pub fn flip(self, axes: &[isize]) -> Self {
+        let rank = self.rank();
+        let axes = axes
+            .iter()
+            .map(|&axis| {
+                if axis < 0 {
+                    (rank as isize + axis) as usize
+                } else {
+                    axis as usize
+                }
+            })
+            .collect::<Vec<_>>();
+
+        check!(TensorCheck::flip(rank, &axes));
+        Self::new(K::flip(self.primitive, &axes))
+    }
+
+    /// Flatten the dimensions from `start_dim` to `end_dim` (inclusive) into a single dimension.
+    pub fn flatten(self, start_dim: usize, end_dim: usize) -> Self {
+        check!(TensorCheck::flatten_rank(self.rank(), start_dim, end_dim));
+
+        let dims = self.dims();
+        let mut new_dims = dims[..start_dim].to_vec();
+        new_dims.push(dims[start_dim..=end_dim].iter().product());
+        new_dims.extend_from_slice(&dims[end_dim + 1..]);
+
+        Self::new(K::reshape(self.primitive, Shape::from(new_dims)))
+    }
+
+    /// Removes the given dimension of size one, reducing the rank by one.
+    pub fn squeeze(self, dim: usize) -> Self {
+        let mut dims = self.dims();
+        check!(TensorCheck::squeeze(dim, &dims));
+
+        dims.remove(dim);
+        Self::new(K::reshape(self.primitive, Shape::from(dims)))
+    }
+
+    /// Inserts a dimension of size one at the given position, increasing the rank by one.
+    pub fn unsqueeze_dim(self, dim: usize) -> Self {
+        let mut dims = self.dims();
+        check!(TensorCheck::Ok.unsqueeze_dim_rank(dims.len(), dim));
+
+        dims.insert(dim, 1);
+        Self::new(K::reshape(self.primitive, Shape::from(dims)))
+    }
+
+    /// Returns a tensor containing the elements selected by the given slices, one for each of the
+    /// leading dimensions.
+    ///
+    /// See [Tensor::slice](Tensor::slice) for the supported slice formats.
+    pub fn slice<S, I>(self, slices: I) -> Self
+    where
+        S: Into<Slice>,
+        I: IntoIterator<Item = S>,
+    {
+        let shape = self.shape();
+        let ranges = slices
+            .into_iter()
+            .zip(shape.dims.iter())
+            .map(|(slice, &size)| slice.into().into_range(size))
+            .collect::<Vec<_>>();
+
+        check!(TensorCheck::slice_rank(shape.num_dims(), &shape, &ranges));
+        Self::new(K::slice(self.primitive, &ranges))
+    }
+
+    /// Returns a copy of the current tensor with the elements selected by the given slices
+    /// replaced by the given values.
+    pub fn slice_assign<S, I>(self, slices: I, values: Self) -> Self
+    where
+        S: Into<Slice>,
+        I: IntoIterator<Item = S>,
+    {
+        let shape = self.shape();
+        let ranges = slices
+            .into_iter()
+            .zip(shape.dims.iter())
+            .map(|(slice, &size)| slice.into().into_range(size))
+            .collect::<Vec<_>>();
+
+        check!(TensorCheck::slice_assign_rank(
+            shape.num_dims(),
+            &shape,
+            &values.shape(),
+            &ranges
+        ));
+        Self::new(K::slice_assign(self.primitive, &ranges, values.primitive))
+    }
+
+    /// Returns a new tensor with the given dimension narrowed to the given range.
+    pub fn narrow(self, dim: usize, start: usize, length: usize) -> Self {
+        check!(TensorCheck::dim_ops_rank("narrow", self.rank(), dim));
+        check!(TensorCheck::narrow(&self.shape(), dim, start, length));
+
+        Self::new(K::slice_dim(self.primitive, dim, &(start..start + length)))
+    }
+
+    /// Repeat the tensor along the given dimension.
+    pub fn repeat_dim(self, dim: usize, times: usize) -> Self {
+        check!(TensorCheck::dim_ops_rank("repeat_dim", self.rank(), dim));
+        Self::new(K::repeat_dim(self.primitive, dim, times))
+    }
+
+    /// Broadcast the tensor to the given shape, which can be of a higher rank.
+    pub fn expand<S: Into<Shape>>(self, shape: S) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::expand_rank("expand", &self.shape(), &shape));
+        Self::new(K::expand(self.primitive, shape))
+    }
+
+    /// Concatenates all tensors into a new one along the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the tensors don't have the same rank, or the same shape except along `dim`.
+    pub fn cat(tensors: Vec<Self>, dim: usize) -> Self {
+        let shapes = tensors.iter().map(Self::shape).collect::<Vec<_>>();
+        let ranks = shapes.iter().map(Shape::num_dims).collect::<Vec<_>>();
+        let rank = ranks.first().copied().unwrap_or_default();
+
+        check!(TensorCheck::Ok.tensor_ranks("Cat", &ranks));
+        check!(TensorCheck::cat_shapes(rank, &shapes, dim));
+
+        Self::new(K::cat(
+            tensors.into_iter().map(|tensor| tensor.primitive).collect(),
+            dim,
+        ))
+    }
+
+    /// Stacks all tensors along a new dimension inserted at `dim`.
+    ///
+    /// # Panics
+    ///
+    /// If the tensors don't have the same shape.
+    pub fn stack(tensors: Vec<Self>, dim: usize) -> Self {
+        let shapes = tensors.iter().map(Self::shape).collect::<Vec<_>>();
+        let rank = shapes.first().map(Shape::num_dims).unwrap_or_default();
+
+        check!(TensorCheck::Ok.stack_shapes(rank, &shapes, dim));
+
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| tensor.unsqueeze_dim(dim))
+            .collect();
+        Self::cat(tensors, dim)
+    }
+
+    /// Applies element wise equal comparison and returns a boolean tensor.
+    pub fn equal(self, other: Self) -> TensorDyn<B, Bool> {
+        check!(TensorCheck::binary_ops_ew_dyn(
+            "Equal",
+            (&self.shape(), &self.device()),
+            (&other.shape(), &other.device()),
+        ));
+        TensorDyn::new(K::equal(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise non-equality comparison and returns a boolean tensor.
+    pub fn not_equal(self, other: Self) -> TensorDyn<B, Bool> {
+        check!(TensorCheck::binary_ops_ew_dyn(
+            "NotEqual",
+            (&self.shape(), &self.device()),
+            (&other.shape(), &other.device()),
+        ));
+        TensorDyn::new(K::not_equal(self.primitive, other.primitive))
+    }
+
+    /// Tests if any element in the tensor evaluates to True, returning a tensor with a single
+    /// element.
+    pub fn any(self) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::any(self.primitive))
+    }
+
+    /// Tests if any element in the tensor evaluates to True along the given dimension.
+    pub fn any_dim(self, dim: usize) -> TensorDyn<B, Bool> {
+        check!(TensorCheck::dim_ops_rank("any_dim", self.rank(), dim));
+        TensorDyn::new(K::any_dim(self.primitive, dim))
+    }
+
+    /// Tests if all elements in the tensor evaluate to True, returning a tensor with a single
+    /// element.
+    pub fn all(self) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::all(self.primitive))
+    }
+
+    /// Tests if all elements in the tensor evaluate to True along the given dimension.
+    pub fn all_dim(self, dim: usize) -> TensorDyn<B, Bool> {
+        check!(TensorCheck::dim_ops_rank("all_dim", self.rank(), dim));
+        TensorDyn::new(K::all_dim(self.primitive, dim))
+    }
+}
+
+impl<B, K> TensorDyn<B, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Create a tensor of the given shape where each element is zero.
+    pub fn zeros<S: Into<Shape>>(shape: S, device: &B::Device) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::creation_ops_rank(
+            "Zeros",
+            shape.num_dims(),
+            &shape.dims
+        ));
+        Self::new(K::zeros(shape, device))
+    }
+
+    /// Create a tensor of the given shape where each element is one.
+    pub fn ones<S: Into<Shape>>(shape: S, device: &B::Device) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::creation_ops_rank(
+            "Ones",
+            shape.num_dims(),
+            &shape.dims
+        ));
+        Self::new(K::ones(shape, device))
+    }
+
+    /// Create a tensor of the given shape where each element is equal to the provided value.
+    pub fn full<S: Into<Shape>, E: ElementConversion>(
+        shape: S,
+        fill_value: E,
+        device: &B::Device,
+    ) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::creation_ops_rank(
+            "Full",
+            shape.num_dims(),
+            &shape.dims
+        ));
+        Self::new(K::full(shape, fill_value, device))
+    }
+
+    /// Create a tensor of the given shape where each element is sampled from the given
+    /// distribution.
+    pub fn random<S: Into<Shape>>(
+        shape: S,
+        distribution: Distribution,
+        device: &B::Device,
+    ) -> Self {
+        Self::new(K::random(shape.into(), distribution, device))
+    }
+
+    /// Applies element wise addition operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        self.check_binary_ops("Add", &other);
+        Self::new(K::add(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise addition operation with a scalar.
+    pub fn add_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::add_scalar(self.primitive, other))
+    }
+
+    /// Applies element wise subtraction operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, other: Self) -> Self {
+        self.check_binary_ops("Sub", &other);
+        Self::new(K::sub(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise subtraction operation with a scalar.
+    pub fn sub_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::sub_scalar(self.primitive, other))
+    }
+
+    /// Applies element wise multiplication operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        self.check_binary_ops("Mul", &other);
+        Self::new(K::mul(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise multiplication operation with a scalar.
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::mul_scalar(self.primitive, other))
+    }
+
+    /// Applies element wise division operation.
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, other: Self) -> Self {
+        self.check_binary_ops("Div", &other);
+        Self::new(K::div(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise division operation with a scalar.
+    pub fn div_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::div_scalar(self.primitive, other))
+    }
+
+    /// Applies element wise remainder operation.
+    pub fn remainder(self, other: Self) -> Self {
+        self.check_binary_ops("Remainder", &other);
+        Self::new(K::remainder(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise remainder operation with a scalar.
+    pub fn remainder_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::remainder_scalar(self.primitive, other))
+    }
+
+    /// Applies element wise power operation.
+    pub fn powf(self, other: Self) -> Self {
+        self.check_binary_ops("Powf", &other);
+        Self::new(K::powf(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise power operation with a float scalar.
+    pub fn powf_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::powf_scalar(self.primitive, other))
+    }
+
+    /// Applies element wise power operation with an integer scalar.
+    pub fn powi_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(K::powi_scalar(self.primitive, other))
+    }
+
+    /// Switch sign of each element in the tensor.
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> Self {
+        Self::new(K::neg(self.primitive))
+    }
+
+    /// Returns the signs of the elements of the tensor.
+    pub fn sign(self) -> Self {
+        Self::new(K::sign(self.primitive))
+    }
+
+    /// Applies element wise absolute value operation.
+    pub fn abs(self) -> Self {
+        Self::new(K::abs(self.primitive))
+    }
+
+    /// Clamp element wise between the given min and max values.
+    pub fn clamp<E: ElementConversion>(self, min: E, max: E) -> Self {
+        Self::new(K::clamp(self.primitive, min.elem(), max.elem()))
+    }
+
+    /// Clamp element wise under a minimum value.
+    pub fn clamp_min<E: ElementConversion>(self, min: E) -> Self {
+        Self::new(K::clamp_min(self.primitive, min.elem()))
+    }
+
+    /// Clamp element wise over a maximum value.
+    pub fn clamp_max<E: ElementConversion>(self, max: E) -> Self {
+        Self::new(K::clamp_max(self.primitive, max.elem()))
+    }
+
+    /// Aggregate all elements in the tensor with the sum operation, returning a tensor with a
+    /// single element.
+    pub fn sum(self) -> Self {
+        Self::new(K::sum(self.primitive))
+    }
+
+    /// Aggregate all elements along the given dimension with the sum operation.
+    pub fn sum_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim_rank("Sum", self.rank(), dim));
+        Self::new(K::sum_dim(self.primitive, dim))
+    }
+
+    /// Aggregate all elements in the tensor with the product operation, returning a tensor with a
+    /// single element.
+    pub fn prod(self) -> Self {
+        Self::new(K::prod(self.primitive))
+    }
+
+    /// Aggregate all elements along the given dimension with the product operation.
+    pub fn prod_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim_rank("Prod", self.rank(), dim));
+        Self::new(K::prod_dim(self.primitive, dim))
+    }
+
+    /// Aggregate all elements in the tensor with the mean operation, returning a tensor with a
+    /// single element.
+    pub fn mean(self) -> Self {
+        Self::new(K::mean(self.primitive))
+    }
+
+    /// Aggregate all elements along the given dimension with the mean operation.
+    pub fn mean_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim_rank("Mean", self.rank(), dim));
+        Self::new(K::mean_dim(self.primitive, dim))
+    }
+
+    /// Computes the cumulative sum along the given dimension.
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim_rank("CumSum", self.rank(), dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Returns the maximum of all elements, in a tensor with a single element.
+    pub fn max(self) -> Self {
+        Self::new(K::max(self.primitive))
+    }
+
+    /// Find the maximum value along the given dimension.
+    pub fn max_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim_rank("Max", self.rank(), dim));
+        Self::new(K::max_dim(self.primitive, dim))
+    }
+
+    /// Returns the minimum of all elements, in a tensor with a single element.
+    pub fn min(self) -> Self {
+        Self::new(K::min(self.primitive))
+    }
+
+    /// Find the minimum value along the given dimension.
+    pub fn min_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim_rank("Min", self.rank(), dim));
+        Self::new(K::min_dim(self.primitive, dim))
+    }
+
+    /// Find the indices of the maximum values along the given dimension.
+    pub fn argmax(self, dim: usize) -> TensorDyn<B, Int> {
+        check!(TensorCheck::aggregate_dim_rank("Argmax", self.rank(), dim));
+        TensorDyn::new(K::argmax(self.primitive, dim))
+    }
+
+    /// Find the indices of the minimum values along the given dimension.
+    pub fn argmin(self, dim: usize) -> TensorDyn<B, Int> {
+        check!(TensorCheck::aggregate_dim_rank("Argmin", self.rank(), dim));
+        TensorDyn::new(K::argmin(self.primitive, dim))
+    }
+
+    /// Applies element wise equal comparison with a scalar and returns a boolean tensor.
+    pub fn equal_elem<E: Element>(self, other: E) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::equal_elem(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise non-equality comparison with a scalar and returns a boolean tensor.
+    pub fn not_equal_elem<E: Element>(self, other: E) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::not_equal_elem(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise greater comparison and returns a boolean tensor.
+    pub fn greater(self, other: Self) -> TensorDyn<B, Bool> {
+        self.check_binary_ops("Greater", &other);
+        TensorDyn::new(K::greater(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise greater comparison with a scalar and returns a boolean tensor.
+    pub fn greater_elem<E: ElementConversion>(self, other: E) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::greater_elem(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise greater-equal comparison and returns a boolean tensor.
+    pub fn greater_equal(self, other: Self) -> TensorDyn<B, Bool> {
+        self.check_binary_ops("Greater_equal", &other);
+        TensorDyn::new(K::greater_equal(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise greater-equal comparison with a scalar and returns a boolean tensor.
+    pub fn greater_equal_elem<E: ElementConversion>(self, other: E) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::greater_equal_elem(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise lower comparison and returns a boolean tensor.
+    pub fn lower(self, other: Self) -> TensorDyn<B, Bool> {
+        self.check_binary_ops("Lower", &other);
+        TensorDyn::new(K::lower(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise lower comparison with a scalar and returns a boolean tensor.
+    pub fn lower_elem<E: ElementConversion>(self, other: E) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::lower_elem(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise lower-equal comparison and returns a boolean tensor.
+    pub fn lower_equal(self, other: Self) -> TensorDyn<B, Bool> {
+        self.check_binary_ops("Lower_equal", &other);
+        TensorDyn::new(K::lower_equal(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise lower-equal comparison with a scalar and returns a boolean tensor.
+    pub fn lower_equal_elem<E: ElementConversion>(self, other: E) -> TensorDyn<B, Bool> {
+        TensorDyn::new(K::lower_equal_elem(self.primitive, other.elem()))
+    }
+
+    /// Updates the tensor with the values of `value` where the mask is true.
+    pub fn mask_where(self, mask: TensorDyn<B, Bool>, value: Self) -> Self {
+        check!(
+            TensorCheck::Ok.tensor_ranks("Mask Where", &[self.rank(), mask.rank(), value.rank()])
+        );
+        Self::new(K::mask_where(
+            self.primitive,
+            mask.primitive,
+            value.primitive,
+        ))
+    }
+
+    /// Fills the tensor with the given value where the mask is true.
+    pub fn mask_fill<E: ElementConversion>(self, mask: TensorDyn<B, Bool>, value: E) -> Self {
+        check!(TensorCheck::Ok.tensor_ranks("Mask Fill", &[self.rank(), mask.rank()]));
+        Self::new(K::mask_fill(self.primitive, mask.primitive, value.elem()))
+    }
+
+    /// Gather the elements of the tensor along the given dimension, with indices of the same
+    /// rank as the tensor.
+    pub fn gather(self, dim: usize, indices: TensorDyn<B, Int>) -> Self {
+        check!(TensorCheck::gather_rank(
+            self.rank(),
+            dim,
+            &self.shape(),
+            &indices.shape()
+        ));
+        Self::new(K::gather(dim, self.primitive, indices.primitive))
+    }
+
+    /// Select the tensor elements along the given dimension corresponding to the given
+    /// one-dimensional indices.
+    pub fn select(self, dim: usize, indices: Tensor<B, 1, Int>) -> Self {
+        check!(TensorCheck::select_rank(self.rank(), dim));
+        Self::new(K::select(self.primitive, dim, indices))
+    }
+
+    /// Applies the matrix multiplication operation on the last two dimensions.
+    pub fn matmul(self, other: Self) -> Self {
+        check!(TensorCheck::matmul_dyn(
+            (&self.shape(), &self.device()),
+            (&other.shape(), &other.device()),
+        ));
+        Self::new(K::matmul(self.primitive, other.primitive))
+    }
+
+    fn check_binary_ops(&self, ops: &str, other: &Self) {
+        check!(TensorCheck::binary_ops_ew_dyn(
+            ops,
+            (&self.shape(), &self.device()),
+            (&other.shape(), &other.device()),
+        ));
+    }
+}
+
+/// Implements the float element wise operations that don't take any argument.
+macro_rules! float_unary_ops {
+    ($($(#[$doc:meta])* $name:ident => $op:ident,)*) => {
+        impl<B: Backend> TensorDyn<B> {
+            $(
+                $(#[$doc])*
+                pub fn $name(self) -> Self {
+                    Self::new(TensorPrimitive::Float(B::$op(self.primitive.tensor())))
+                }
+            )*
+        }
+    };
+}
+
+float_unary_ops! {
+    /// Applies element wise exponential operation.
+    exp => float_exp,
+    /// Applies element wise exponential minus one operation.
+    expm1 => float_expm1,
+    /// Applies element wise natural log operation.
+    log => float_log,
+    /// Applies element wise natural log of one plus the input.
+    log1p => float_log1p,
+    /// Applies element wise base 2 logarithm operation.
+    log2 => float_log2,
+    /// Applies element wise base 10 logarithm operation.
+    log10 => float_log10,
+    /// Applies element wise square root operation.
+    sqrt => float_sqrt,
+    /// Applies element wise reciprocal operation.
+    recip => float_recip,
+    /// Applies element wise cosine operation.
+    cos => float_cos,
+    /// Applies element wise sine operation.
+    sin => float_sin,
+    /// Applies element wise tangent operation.
+    tan => float_tan,
+    /// Applies element wise hyperbolic cosine operation.
+    cosh => float_cosh,
+    /// Applies element wise hyperbolic sine operation.
+    sinh => float_sinh,
+    /// Applies element wise hyperbolic tangent operation.
+    tanh => float_tanh,
+    /// Applies element wise inverse sine operation.
+    asin => float_asin,
+    /// Applies element wise inverse cosine operation.
+    acos => float_acos,
+    /// Applies element wise inverse tangent operation.
+    atan => float_atan,
+    /// Applies element wise inverse hyperbolic sine operation.
+    asinh => float_asinh,
+    /// Applies element wise inverse hyperbolic cosine operation.
+    acosh => float_acosh,
+    /// Applies element wise inverse hyperbolic tangent operation.
+    atanh => float_atanh,
+    /// Applies the error function element wise.
+    erf => float_erf,
+    /// Applies the complementary error function element wise.
+    erfc => float_erfc,
+    /// Applies the inverse error function element wise.
+    erfinv => float_erfinv,
+    /// Applies the log-gamma function element wise.
+    lgamma => float_lgamma,
+    /// Applies the digamma function element wise.
+    digamma => float_digamma,
+    /// Applies element wise round operation, rounding half to even.
+    round => float_round,
+    /// Applies element wise floor operation.
+    floor => float_floor,
+    /// Applies element wise ceil operation.
+    ceil => float_ceil,
+}
+
+impl<B: Backend> TensorDyn<B> {
+    /// Applies element wise four-quadrant inverse tangent operation, where `self` holds the
+    /// y-coordinates and `other` the x-coordinates.
+    pub fn atan2(self, other: Self) -> Self {
+        self.check_binary_ops("Atan2", &other);
+        Self::new(TensorPrimitive::Float(B::float_atan2(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise hypotenuse operation.
+    pub fn hypot(self, other: Self) -> Self {
+        self.check_binary_ops("Hypot", &other);
+        Self::new(TensorPrimitive::Float(B::float_hypot(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
+    /// Converts each of the elements of the tensor into integers.
+    pub fn int(self) -> TensorDyn<B, Int> {
+        TensorDyn::new(B::float_into_int(self.primitive.tensor()))
+    }
+}
+
+impl<B: Backend> TensorDyn<B, Int> {
+    /// Converts each of the elements of the tensor into floats.
+    pub fn float(self) -> TensorDyn<B> {
+        TensorDyn::new(TensorPrimitive::Float(B::int_into_float(self.primitive)))
+    }
+}
+
+impl<B: Backend> TensorDyn<B, Bool> {
+    /// Convert the bool tensor into an int tensor.
+    pub fn int(self) -> TensorDyn<B, Int> {
+        TensorDyn::new(B::bool_into_int(self.primitive))
+    }
+
+    /// Convert the bool tensor into a float tensor.
+    pub fn float(self) -> TensorDyn<B> {
+        TensorDyn::new(TensorPrimitive::Float(B::bool_into_float(self.primitive)))
+    }
+
+    /// Inverses boolean values.
+    pub fn bool_not(self) -> Self {
+        Self::new(B::bool_not(self.primitive))
+    }
+}
+
+impl<B: Backend, K: Numeric<B>> core::ops::Add<Self> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::add(self, rhs)
+    }
+}
+
+impl<E: ElementConversion, B: Backend, K: Numeric<B>> core::ops::Add<E> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn add(self, other: E) -> Self::Output {
+        Self::add_scalar(self, other)
+    }
+}
+
+impl<B: Backend, K: Numeric<B>> core::ops::Sub<Self> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::sub(self, rhs)
+    }
+}
+
+impl<E: ElementConversion, B: Backend, K: Numeric<B>> core::ops::Sub<E> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn sub(self, other: E) -> Self::Output {
+        Self::sub_scalar(self, other)
+    }
+}
+
+impl<B: Backend, K: Numeric<B>> core::ops::Mul<Self> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::mul(self, rhs)
+    }
+}
+
+impl<E: ElementConversion, B: Backend, K: Numeric<B>> core::ops::Mul<E> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn mul(self, other: E) -> Self::Output {
+        Self::mul_scalar(self, other)
+    }
+}
+
+impl<B: Backend, K: Numeric<B>> core::ops::Div<Self> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::div(self, rhs)
+    }
+}
+
+impl<E: ElementConversion, B: Backend, K: Numeric<B>> core::ops::Div<E> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn div(self, other: E) -> Self::Output {
+        Self::div_scalar(self, other)
+    }
+}
+
+impl<B: Backend, K: Numeric<B>> core::ops::Rem<Self> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self::remainder(self, rhs)
+    }
+}
+
+impl<E: ElementConversion, B: Backend, K: Numeric<B>> core::ops::Rem<E> for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn rem(self, other: E) -> Self::Output {
+        Self::remainder_scalar(self, other)
+    }
+}
+
+impl<B: Backend, K: Numeric<B>> core::ops::Neg for TensorDyn<B, K>
+where
+    K::Elem: Element,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::neg(self)
+    }
+}
@@ -0,0 +1,562 @@
+use crate::{
+    Shape, Tensor, TensorDyn, TensorMetadata, TensorPrimitive, backend::Backend, check,
+    check::TensorCheck, ops::FloatTensor,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Labels of the dimensions covered by an ellipsis start after the ASCII letters, so that sorting
+/// labels keeps the letters in their natural order.
+const ELLIPSIS_LABEL_OFFSET: usize = 128;
+
+/// An einsum equation where every input term and the output have exactly one label per dimension.
+///
+/// Letters are labeled with their ASCII code, while the dimensions covered by an ellipsis are
+/// right-aligned across the operands and labeled starting at [ELLIPSIS_LABEL_OFFSET].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EinsumEquation {
+    /// The labels of each input dimension.
+    pub(crate) inputs: Vec<Vec<usize>>,
+    /// The labels of each output dimension.
+    pub(crate) output: Vec<usize>,
+}
+
+enum Subscript {
+    Label(usize),
+    Ellipsis,
+}
+
+impl EinsumEquation {
+    /// Parses the equation for operands of the given ranks.
+    ///
+    /// When the output term is omitted, the output contains the ellipsis dimensions followed by
+    /// the labels appearing exactly once in the inputs, in alphabetical order.
+    pub(crate) fn parse(equation: &str, ranks: &[usize]) -> Result<Self, String> {
+        let (lhs, rhs) = match equation.split_once("->") {
+            Some((_, rhs)) if rhs.contains("->") => {
+                return Err("The equation can't contain more than one '->'.".into());
+            }
+            Some((lhs, rhs)) => (lhs, Some(rhs)),
+            None => (equation, None),
+        };
+
+        let terms = lhs.split(',').collect::<Vec<_>>();
+        if terms.len() != ranks.len() {
+            return Err(format!(
+                "The equation has {} input terms, but {} tensors were provided.",
+                terms.len(),
+                ranks.len()
+            ));
+        }
+
+        let subscripts = terms
+            .iter()
+            .map(|term| parse_term(term))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut ellipsis_rank = 0;
+        for ((term, subscripts), rank) in terms.iter().zip(&subscripts).zip(ranks) {
+            let num_labels = num_labels(subscripts);
+            let has_ellipsis = num_labels != subscripts.len();
+
+            if num_labels > *rank || (!has_ellipsis && num_labels != *rank) {
+                return Err(format!(
+                    "The term '{term}' has {num_labels} subscripts, but the corresponding tensor \
+                     has {rank} dimensions."
+                ));
+            }
+
+            if has_ellipsis {
+                ellipsis_rank = usize::max(ellipsis_rank, rank - num_labels);
+            }
+        }
+
+        let inputs = subscripts
+            .iter()
+            .zip(ranks)
+            .map(|(subscripts, rank)| {
+                let covered = rank - num_labels(subscripts);
+                let mut labels = Vec::with_capacity(*rank);
+
+                for subscript in subscripts {
+                    match subscript {
+                        Subscript::Label(label) => labels.push(*label),
+                        Subscript::Ellipsis => labels.extend(
+                            (ellipsis_rank - covered..ellipsis_rank)
+                                .map(|i| ELLIPSIS_LABEL_OFFSET + i),
+                        ),
+                    }
+                }
+
+                labels
+            })
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::new();
+
+        match rhs {
+            Some(rhs) => {
+                for subscript in parse_term(rhs)? {
+                    match subscript {
+                        Subscript::Label(label) => {
+                            if !inputs.iter().any(|labels| labels.contains(&label)) {
+                                return Err(format!(
+                                    "The output subscript '{}' doesn't appear in any input term.",
+                                    label as u8 as char
+                                ));
+                            }
+                            if output.contains(&label) {
+                                return Err(format!(
+                                    "The output subscript '{}' appears more than once.",
+                                    label as u8 as char
+                                ));
+                            }
+                            output.push(label);
+                        }
+                        Subscript::Ellipsis => {
+                            output.extend((0..ellipsis_rank).map(|i| ELLIPSIS_LABEL_OFFSET + i))
+                        }
+                    }
+                }
+            }
+            None => {
+                output.extend((0..ellipsis_rank).map(|i| ELLIPSIS_LABEL_OFFSET + i));
+
+                let mut labels = inputs
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|label| *label < ELLIPSIS_LABEL_OFFSET)
+                    .filter(|label| inputs.iter().flatten().filter(|l| *l == label).count() == 1)
+                    .collect::<Vec<_>>();
+                labels.sort_unstable();
+                output.extend(labels);
+            }
+        }
+
+        Ok(Self { inputs, output })
+    }
+}
+
+fn parse_term(term: &str) -> Result<Vec<Subscript>, String> {
+    let mut subscripts = Vec::new();
+    let mut chars = term.chars().filter(|c| !c.is_whitespace());
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_ascii_alphabetic() => subscripts.push(Subscript::Label(c as usize)),
+            '.' => {
+                if chars.next() != Some('.') || chars.next() != Some('.') {
+                    return Err(format!(
+                        "The term '{term}' contains a '.' that isn't part of an ellipsis '...'."
+                    ));
+                }
+                if subscripts
+                    .iter()
+                    .any(|subscript| matches!(subscript, Subscript::Ellipsis))
+                {
+                    return Err(format!(
+                        "The term '{term}' contains more than one ellipsis."
+                    ));
+                }
+                subscripts.push(Subscript::Ellipsis);
+            }
+            c => {
+                return Err(format!(
+                    "The term '{term}' contains the invalid subscript '{c}', only ASCII letters \
+                     and '...' are supported."
+                ));
+            }
+        }
+    }
+
+    Ok(subscripts)
+}
+
+fn num_labels(subscripts: &[Subscript]) -> usize {
+    subscripts
+        .iter()
+        .filter(|subscript| matches!(subscript, Subscript::Label(_)))
+        .count()
+}
+
+/// A tensor with one label per dimension.
+struct Operand<B: Backend> {
+    tensor: FloatTensor<B>,
+    labels: Vec<usize>,
+}
+
+impl<B: Backend> Operand<B> {
+    fn size(&self, label: usize) -> Option<usize> {
+        self.labels
+            .iter()
+            .position(|l| *l == label)
+            .map(|dim| self.tensor.shape().dims[dim])
+    }
+
+    /// Takes the diagonal of the dimensions sharing the same label within the operand.
+    fn diagonal(mut self) -> Self {
+        while let Some((first, second)) = self.labels.iter().enumerate().find_map(|(i, label)| {
+            self.labels[i + 1..]
+                .iter()
+                .position(|l| l == label)
+                .map(|j| (i, i + 1 + j))
+        }) {
+            let mut dims = self.tensor.shape().dims;
+            let size = dims[first];
+            let device = B::float_device(&self.tensor);
+
+            let mut mask_dims = alloc::vec![1; dims.len()];
+            mask_dims[first] = size;
+            mask_dims[second] = size;
+            let mask = B::float_reshape(
+                Tensor::<B, 2>::eye(size, &device).into_primitive().tensor(),
+                Shape::from(mask_dims),
+            );
+
+            let tensor = B::float_sum_dim(B::float_mul(self.tensor, mask), second);
+            dims.remove(second);
+            self.tensor = B::float_reshape(tensor, Shape::from(dims));
+            self.labels.remove(second);
+        }
+
+        self
+    }
+
+    /// Removes the broadcasted dimensions of size one, which don't contribute to the result other
+    /// than through the operand values.
+    fn squeeze_broadcast(mut self, sizes: &[(usize, usize)]) -> Self {
+        let dims = self.tensor.shape().dims;
+        let keep = self
+            .labels
+            .iter()
+            .zip(&dims)
+            .map(|(label, size)| *size != 1 || sizes.iter().any(|(l, s)| l == label && *s == 1))
+            .collect::<Vec<_>>();
+
+        if keep.iter().all(|keep| *keep) {
+            return self;
+        }
+
+        let dims = dims
+            .into_iter()
+            .zip(&keep)
+            .filter_map(|(dim, keep)| keep.then_some(dim))
+            .collect::<Vec<_>>();
+        self.labels = self
+            .labels
+            .into_iter()
+            .zip(&keep)
+            .filter_map(|(label, keep)| keep.then_some(label))
+            .collect();
+        self.tensor = B::float_reshape(self.tensor, Shape::from(dims));
+
+        self
+    }
+
+    /// Sums the dimensions whose label isn't kept.
+    fn sum_out(mut self, keep: impl Fn(usize) -> bool) -> Self {
+        let mut dims = self.tensor.shape().dims;
+        let mut reduced = false;
+
+        for dim in (0..self.labels.len()).rev() {
+            if !keep(self.labels[dim]) {
+                self.tensor = B::float_sum_dim(self.tensor, dim);
+                self.labels.remove(dim);
+                dims.remove(dim);
+                reduced = true;
+            }
+        }
+
+        if reduced {
+            self.tensor = B::float_reshape(self.tensor, Shape::from(dims));
+        }
+
+        self
+    }
+
+    /// Permutes the dimensions so that the labels follow the given order.
+    fn permute(mut self, labels: &[usize]) -> Self {
+        if self.labels == labels {
+            return self;
+        }
+
+        let axes = labels
+            .iter()
+            .map(|label| self.labels.iter().position(|l| l == label).unwrap())
+            .collect::<Vec<_>>();
+        self.tensor = B::float_permute(self.tensor, &axes);
+        self.labels = labels.to_vec();
+
+        self
+    }
+
+    /// Contracts two operands with a single batched matrix multiplication, keeping only the
+    /// labels for which `keep` returns true.
+    fn contract(self, rhs: Self, keep: impl Fn(usize) -> bool) -> Self {
+        let lhs = self.sum_out(|label| keep(label) || rhs.labels.contains(&label));
+        let rhs = rhs.sum_out(|label| keep(label) || lhs.labels.contains(&label));
+
+        let (shared, left): (Vec<_>, Vec<_>) = lhs
+            .labels
+            .iter()
+            .partition(|label| rhs.labels.contains(label));
+        let (batch, contracted): (Vec<_>, Vec<_>) =
+            shared.into_iter().partition(|label| keep(*label));
+        let right = rhs
+            .labels
+            .iter()
+            .copied()
+            .filter(|label| !lhs.labels.contains(label))
+            .collect::<Vec<_>>();
+
+        let size = |labels: &[usize]| {
+            labels
+                .iter()
+                .map(|label| lhs.size(*label).or_else(|| rhs.size(*label)).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let batch_dims = size(&batch);
+        let left_dims = size(&left);
+        let contracted_dims = size(&contracted);
+        let right_dims = size(&right);
+
+        let batch_size = batch_dims.iter().product::<usize>();
+        let left_size = left_dims.iter().product::<usize>();
+        let contracted_size = contracted_dims.iter().product::<usize>();
+        let right_size = right_dims.iter().product::<usize>();
+
+        let lhs = lhs.permute(&[batch.as_slice(), &left, &contracted].concat());
+        let rhs = rhs.permute(&[batch.as_slice(), &contracted, &right].concat());
+
+        let tensor = B::float_matmul(
+            B::float_reshape(
+                lhs.tensor,
+                Shape::from([batch_size, left_size, contracted_size]),
+            ),
+            B::float_reshape(
+                rhs.tensor,
+                Shape::from([batch_size, contracted_size, right_size]),
+            ),
+        );
+
+        Self {
+            tensor: B::float_reshape(
+                tensor,
+                Shape::from([batch_dims, left_dims, right_dims].concat()),
+            ),
+            labels: [batch, left, right].concat(),
+        }
+    }
+}
+
+/// The operands of an [einsum](einsum), which can have different ranks.
+///
+/// It's implemented for slices, arrays and vectors of tensors of the same rank, for tuples of up to
+/// six tensors of any rank, and for slices and vectors of [dynamic-rank tensors](TensorDyn).
+pub trait EinsumOperands<B: Backend> {
+    /// Returns the primitives of the operands, in order.
+    fn into_primitives(self) -> Vec<FloatTensor<B>>;
+}
+
+impl<B: Backend, const D: usize> EinsumOperands<B> for &[Tensor<B, D>] {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        self.iter()
+            .map(|tensor| tensor.primitive.clone().tensor())
+            .collect()
+    }
+}
+
+impl<B: Backend, const D: usize, const N: usize> EinsumOperands<B> for &[Tensor<B, D>; N] {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        self.as_slice().into_primitives()
+    }
+}
+
+impl<B: Backend, const D: usize, const N: usize> EinsumOperands<B> for [Tensor<B, D>; N] {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        self.into_iter()
+            .map(|tensor| tensor.primitive.tensor())
+            .collect()
+    }
+}
+
+impl<B: Backend, const D: usize> EinsumOperands<B> for Vec<Tensor<B, D>> {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        self.into_iter()
+            .map(|tensor| tensor.primitive.tensor())
+            .collect()
+    }
+}
+
+impl<B: Backend> EinsumOperands<B> for &[TensorDyn<B>] {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        self.iter()
+            .map(|tensor| tensor.primitive.clone().tensor())
+            .collect()
+    }
+}
+
+impl<B: Backend> EinsumOperands<B> for Vec<TensorDyn<B>> {
+    fn into_primitives(self) -> Vec<FloatTensor<B>> {
+        self.into_iter()
+            .map(|tensor| tensor.primitive.tensor())
+            .collect()
+    }
+}
+
+macro_rules! impl_einsum_operands_tuple {
+    ($($tensor:ident: $rank:ident),+) => {
+        impl<B: Backend, $(const $rank: usize),+> EinsumOperands<B> for ($(Tensor<B, $rank>,)+) {
+            fn into_primitives(self) -> Vec<FloatTensor<B>> {
+                let ($($tensor,)+) = self;
+
+                vec![$($tensor.primitive.tensor()),+]
+            }
+        }
+    };
+}
+
+impl_einsum_operands_tuple!(t1: D1);
+impl_einsum_operands_tuple!(t1: D1, t2: D2);
+impl_einsum_operands_tuple!(t1: D1, t2: D2, t3: D3);
+impl_einsum_operands_tuple!(t1: D1, t2: D2, t3: D3, t4: D4);
+impl_einsum_operands_tuple!(t1: D1, t2: D2, t3: D3, t4: D4, t5: D5);
+impl_einsum_operands_tuple!(t1: D1, t2: D2, t3: D3, t4: D4, t5: D5, t6: D6);
+
+/// Sums the product of the elements of the input tensors along the dimensions specified using a
+/// notation based on the Einstein summation convention.
+///
+/// The contraction is lowered to a sequence of pairwise batched matrix multiplications, greedily
+/// contracting the pair of operands producing the smallest intermediate tensor first.
+///
+/// # Arguments
+///
+/// * `equation` - The subscripts of each input, separated by commas, optionally followed by `->`
+///   and the subscripts of the output. An ellipsis `...` covers the remaining dimensions.
+/// * `operands` - The input tensors, e.g. a slice of tensors of the same rank or a tuple of
+///   tensors of different ranks.
+///
+/// # Panics
+///
+/// Panics if the equation is invalid, if it doesn't match the shapes of the input tensors, or if
+/// the number of output dimensions isn't `D2`.
+///
+/// # Examples
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, einsum};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let q = Tensor::<B, 4>::ones([2, 4, 8, 16], &device);
+///     let k = Tensor::<B, 4>::ones([2, 4, 10, 16], &device);
+///
+///     let scores: Tensor<B, 4> = einsum("bhqd,bhkd->bhqk", &[q, k]);
+///     println!("{scores}");
+///     // Shape { dims: [2, 4, 8, 10] }
+///
+///     let matrix = Tensor::<B, 2>::ones([3, 4], &device);
+///     let vector = Tensor::<B, 1>::ones([4], &device);
+///
+///     let product: Tensor<B, 1> = einsum("ij,j->i", (matrix, vector));
+///     println!("{product}");
+///     // Shape { dims: [3] }
+/// }
+/// ```
+pub fn einsum<B: Backend, const D2: usize>(
+    equation: &str,
+    operands: impl EinsumOperands<B>,
+) -> Tensor<B, D2> {
+    let primitives = operands.into_primitives();
+    let shapes = primitives
+        .iter()
+        .map(TensorMetadata::shape)
+        .collect::<Vec<_>>();
+    check!(TensorCheck::einsum::<D2>(equation, &shapes));
+
+    let ranks = shapes.iter().map(Shape::num_dims).collect::<Vec<_>>();
+    let equation = EinsumEquation::parse(equation, &ranks).unwrap();
+
+    let operands = primitives
+        .into_iter()
+        .zip(equation.inputs)
+        .map(|(tensor, labels)| Operand::<B> { tensor, labels }.diagonal())
+        .collect::<Vec<_>>();
+
+    let mut sizes: Vec<(usize, usize)> = Vec::new();
+    for operand in &operands {
+        for (label, size) in operand.labels.iter().zip(operand.tensor.shape().dims) {
+            match sizes.iter_mut().find(|(l, _)| l == label) {
+                Some((_, s)) => *s = usize::max(*s, size),
+                None => sizes.push((*label, size)),
+            }
+        }
+    }
+
+    let mut operands = operands
+        .into_iter()
+        .map(|operand| operand.squeeze_broadcast(&sizes))
+        .collect::<Vec<_>>();
+
+    while operands.len() > 1 {
+        let keep = |label: usize, i: usize, j: usize, operands: &[Operand<B>]| {
+            equation.output.contains(&label)
+                || operands
+                    .iter()
+                    .enumerate()
+                    .any(|(k, operand)| k != i && k != j && operand.labels.contains(&label))
+        };
+
+        let mut best = (0, 1, usize::MAX);
+        for i in 0..operands.len() {
+            for j in i + 1..operands.len() {
+                let mut labels = operands[i].labels.clone();
+                labels.extend(
+                    operands[j]
+                        .labels
+                        .iter()
+                        .filter(|label| !operands[i].labels.contains(label)),
+                );
+                let cost = labels
+                    .into_iter()
+                    .filter(|label| keep(*label, i, j, &operands))
+                    .map(|label| {
+                        operands[i]
+                            .size(label)
+                            .or_else(|| operands[j].size(label))
+                            .unwrap()
+                    })
+                    .product::<usize>();
+
+                if cost < best.2 {
+                    best = (i, j, cost);
+                }
+            }
+        }
+
+        let (i, j, _) = best;
+        let rhs = operands.remove(j);
+        let lhs = operands.remove(i);
+        let keep_labels = lhs
+            .labels
+            .iter()
+            .chain(&rhs.labels)
+            .copied()
+            .filter(|label| keep(*label, usize::MAX, usize::MAX, &operands))
+            .collect::<Vec<_>>();
+
+        operands.push(lhs.contract(rhs, |label| keep_labels.contains(&label)));
+    }
+
+    let output = operands
+        .pop()
+        .unwrap()
+        .sum_out(|label| equation.output.contains(&label))
+        .permute(&equation.output);
+
+    Tensor::new(TensorPrimitive::Float(output.tensor))
+}
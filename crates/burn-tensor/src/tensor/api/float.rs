@@ -3,9 +3,10 @@ use crate::Tensor;
 use crate::cast::ToElement;
 use crate::quantization::{QuantScheme, QuantizationParameters};
 use crate::tensor::backend::Backend;
-use crate::tensor::stats;
+use crate::tensor::stats::{self, QuantileInterpolation};
 use crate::tensor::{Distribution, TensorData};
-use crate::{Int, TensorPrimitive};
+use crate::{Int, TensorPrimitive, einsum};
+use crate::{check, check::TensorCheck, try_read_sync};
 
 use super::Bool;
 
@@ -46,6 +47,33 @@ where
         )))
     }
 
+    /// Applies element wise exponential minus one operation, which is accurate for small inputs.
+    ///
+    /// $y_i = e^{x_i} - 1$
+    pub fn expm1(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_expm1(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise base 2 logarithm operation.
+    ///
+    /// $y_i = \log_2\(x_i\)$
+    pub fn log2(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_log2(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise base 10 logarithm operation.
+    ///
+    /// $y_i = \log_{10}\(x_i\)$
+    pub fn log10(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_log10(
+            self.primitive.tensor(),
+        )))
+    }
+
     /// Applies the [error function](https://en.wikipedia.org/wiki/Error_function) element wise.
     ///
     /// $y_i = \text{erf}\(x_i\)$
@@ -59,6 +87,71 @@ where
         )))
     }
 
+    /// Applies the complementary error function element wise.
+    ///
+    /// $y_i = \text{erfc}\(x_i\) = 1 - \text{erf}\(x_i\)$
+    pub fn erfc(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_erfc(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the inverse error function element wise, defined on $[-1, 1]$.
+    ///
+    /// $y_i = \text{erf}^{-1}\(x_i\)$
+    pub fn erfinv(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_erfinv(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the natural logarithm of the absolute value of the
+    /// [gamma function](https://en.wikipedia.org/wiki/Gamma_function) element wise.
+    ///
+    /// $y_i = \ln\|\Gamma\(x_i\)\|$
+    pub fn lgamma(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_lgamma(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the [digamma function](https://en.wikipedia.org/wiki/Digamma_function) element
+    /// wise, the logarithmic derivative of the gamma function.
+    ///
+    /// $y_i = \psi\(x_i\) = \frac{d}{dx} \ln\Gamma\(x_i\)$
+    pub fn digamma(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_digamma(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies the [polygamma function](https://en.wikipedia.org/wiki/Polygamma_function) of
+    /// order `n` element wise, the `n`-th derivative of the digamma function.
+    ///
+    /// $y_i = \psi^{\(n\)}\(x_i\)$
+    pub fn polygamma(self, n: u32) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_polygamma(
+            self.primitive.tensor(),
+            n,
+        )))
+    }
+
+    /// Applies the modified [Bessel function](https://en.wikipedia.org/wiki/Bessel_function#Modified_Bessel_functions:_I%CE%B1,_K%CE%B1)
+    /// of the first kind of order zero element wise.
+    ///
+    /// $y_i = I_0\(x_i\)$
+    pub fn i0(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_i0(self.primitive.tensor())))
+    }
+
+    /// Applies the modified [Bessel function](https://en.wikipedia.org/wiki/Bessel_function#Modified_Bessel_functions:_I%CE%B1,_K%CE%B1)
+    /// of the first kind of order one element wise.
+    ///
+    /// $y_i = I_1\(x_i\)$
+    pub fn i1(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_i1(self.primitive.tensor())))
+    }
+
     /// Applies [reciprocal operation](https://en.wikipedia.org/wiki/Multiplicative_inverse)
     /// (or multiplicative inverse) element wise.
     ///
@@ -174,6 +267,85 @@ where
         )))
     }
 
+    /// Applies element wise inverse sine operation.
+    ///
+    /// $y_i = \arcsin\(x_i\)$
+    pub fn asin(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_asin(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse cosine operation.
+    ///
+    /// $y_i = \arccos\(x_i\)$
+    pub fn acos(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_acos(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse tangent operation.
+    ///
+    /// $y_i = \arctan\(x_i\)$
+    pub fn atan(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_atan(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise four-quadrant inverse tangent operation, where `self` holds the
+    /// y-coordinates and `other` the x-coordinates.
+    ///
+    /// The output is in $[-\pi, \pi]$, with the quadrant given by the signs of both inputs.
+    ///
+    /// $y_i = \operatorname{atan2}\(x_i, \text{other}_i\)$
+    pub fn atan2(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Atan2", &self, &other));
+        Self::new(TensorPrimitive::Float(B::float_atan2(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse hyperbolic sine operation.
+    ///
+    /// $y_i = \operatorname{asinh}\(x_i\)$
+    pub fn asinh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_asinh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse hyperbolic cosine operation.
+    ///
+    /// $y_i = \operatorname{acosh}\(x_i\)$
+    pub fn acosh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_acosh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise inverse hyperbolic tangent operation.
+    ///
+    /// $y_i = \operatorname{atanh}\(x_i\)$
+    pub fn atanh(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_atanh(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise hypotenuse operation.
+    ///
+    /// $y_i = \sqrt{x_i^2 + \text{other}_i^2}$
+    pub fn hypot(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Hypot", &self, &other));
+        Self::new(TensorPrimitive::Float(B::float_hypot(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
     /// Applies element wise round operation.
     ///
     /// This function implements the [round half to even](https://en.wikipedia.org/wiki/Rounding#Rounding_half_to_even)
@@ -198,6 +370,427 @@ where
         )))
     }
 
+    /// Computes the logarithm of the cumulative sum of exponentials along the given dimension.
+    ///
+    /// $y_i = \log \sum_{j \leq i} e^{x_j}$
+    ///
+    /// The computation is numerically stable, even for large input values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///
+    ///     let tensor = Tensor::<B, 1>::from_data([0.0, 0.0, 0.0], &device);
+    ///     println!("{}", tensor.logcumsumexp(0)); // [0.0, 0.6931, 1.0986]
+    /// }
+    /// ```
+    pub fn logcumsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("LogCumSumExp", dim));
+        Self::new(TensorPrimitive::Float(B::float_logcumsumexp(
+            self.primitive.tensor(),
+            dim,
+        )))
+    }
+
+    /// Computes the logarithm of the sum of exponentials along the given dimension.
+    ///
+    /// $y = \log \sum_i e^{x_i}$
+    ///
+    /// The computation is numerically stable, even for large input values.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///
+    ///     let tensor = Tensor::<B, 2>::from_data([[0.0, 0.0], [1000.0, 1000.0]], &device);
+    ///     println!("{}", tensor.logsumexp(1)); // [[0.6931], [1000.6931]]
+    /// }
+    /// ```
+    pub fn logsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("LogSumExp", dim));
+        Self::new(TensorPrimitive::Float(B::float_logsumexp(
+            self.primitive.tensor(),
+            dim,
+        )))
+    }
+
+    /// Sums the elements of the tensor where the mask is true.
+    ///
+    /// The other elements are ignored, even when they are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let mask = Tensor::<B, 2, Bool>::from_data([[true, false, true], [false, true, false]], &device);
+    ///    let tensor = tensor.sum_masked(mask);
+    ///    println!("{tensor}");
+    ///    // [13.0]
+    /// }
+    /// ```
+    pub fn sum_masked(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1> {
+        check!(TensorCheck::aggregate_masked::<D>(
+            "SumMasked",
+            &self.shape(),
+            &mask.shape(),
+            0
+        ));
+        self.reshape([-1]).sum_masked_dim(mask.reshape([-1]), 0)
+    }
+
+    /// Sums the elements of the tensor where the mask is true along the given dimension.
+    ///
+    /// The other elements are ignored, even when they are NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The mask, with the same shape as the tensor.
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let mask = Tensor::<B, 2, Bool>::from_data([[true, false, true], [false, true, false]], &device);
+    ///    let tensor = tensor.sum_masked_dim(mask, 1);
+    ///    println!("{tensor}");
+    ///    // [[4.0], [9.0]]
+    /// }
+    /// ```
+    pub fn sum_masked_dim(self, mask: Tensor<B, D, Bool>, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_masked::<D>(
+            "SumMasked",
+            &self.shape(),
+            &mask.shape(),
+            dim
+        ));
+        Self::new(TensorPrimitive::Float(B::float_sum_masked_dim(
+            self.primitive.tensor(),
+            mask.primitive,
+            dim,
+        )))
+    }
+
+    /// Computes the mean of the elements of the tensor where the mask is true.
+    ///
+    /// The other elements are ignored, even when they are NaN. The mean is NaN when no element is
+    /// selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let mask = Tensor::<B, 2, Bool>::from_data([[true, false, true], [false, true, false]], &device);
+    ///    let tensor = tensor.mean_masked(mask);
+    ///    println!("{tensor}");
+    ///    // [4.3333]
+    /// }
+    /// ```
+    pub fn mean_masked(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1> {
+        check!(TensorCheck::aggregate_masked::<D>(
+            "MeanMasked",
+            &self.shape(),
+            &mask.shape(),
+            0
+        ));
+        self.reshape([-1]).mean_masked_dim(mask.reshape([-1]), 0)
+    }
+
+    /// Computes the mean of the elements of the tensor where the mask is true along the given
+    /// dimension.
+    ///
+    /// The other elements are ignored, even when they are NaN. The mean is NaN when no element is
+    /// selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The mask, with the same shape as the tensor.
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let mask = Tensor::<B, 2, Bool>::from_data([[true, false, true], [false, true, false]], &device);
+    ///    let tensor = tensor.mean_masked_dim(mask, 1);
+    ///    println!("{tensor}");
+    ///    // [[2.0], [9.0]]
+    /// }
+    /// ```
+    pub fn mean_masked_dim(self, mask: Tensor<B, D, Bool>, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_masked::<D>(
+            "MeanMasked",
+            &self.shape(),
+            &mask.shape(),
+            dim
+        ));
+        let count = mask.clone().int().sum_dim(dim).float();
+        self.sum_masked_dim(mask, dim).div(count)
+    }
+
+    /// Sums all the elements of the tensor, ignoring NaN values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.nansum();
+    ///    println!("{tensor}");
+    ///    // [24.0]
+    /// }
+    /// ```
+    pub fn nansum(self) -> Tensor<B, 1> {
+        self.reshape([-1]).nansum_dim(0)
+    }
+
+    /// Sums the elements of the tensor along the given dimension, ignoring NaN values.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.nansum_dim(1);
+    ///    println!("{tensor}");
+    ///    // [[4.0], [20.0]]
+    /// }
+    /// ```
+    pub fn nansum_dim(self, dim: usize) -> Self {
+        let mask = self.clone().is_nan().bool_not();
+        self.sum_masked_dim(mask, dim)
+    }
+
+    /// Computes the mean of all the elements of the tensor, ignoring NaN values.
+    ///
+    /// The mean is NaN when all the elements are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.nanmean();
+    ///    println!("{tensor}");
+    ///    // [4.8]
+    /// }
+    /// ```
+    pub fn nanmean(self) -> Tensor<B, 1> {
+        self.reshape([-1]).nanmean_dim(0)
+    }
+
+    /// Computes the mean of the elements of the tensor along the given dimension, ignoring NaN
+    /// values.
+    ///
+    /// The mean is NaN when all the elements along the dimension are NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.nanmean_dim(1);
+    ///    println!("{tensor}");
+    ///    // [[2.0], [6.6667]]
+    /// }
+    /// ```
+    pub fn nanmean_dim(self, dim: usize) -> Self {
+        let mask = self.clone().is_nan().bool_not();
+        self.mean_masked_dim(mask, dim)
+    }
+
+    /// Returns the maximum of all the elements of the tensor, ignoring NaN values.
+    ///
+    /// The maximum is NaN when all the elements are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.nanmax();
+    ///    println!("{tensor}");
+    ///    // [9.0]
+    /// }
+    /// ```
+    pub fn nanmax(self) -> Tensor<B, 1> {
+        self.reshape([-1]).nanmax_dim(0)
+    }
+
+    /// Returns the maximum of the elements of the tensor along the given dimension, ignoring NaN
+    /// values.
+    ///
+    /// The maximum is NaN when all the elements along the dimension are NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [f32::NAN, f32::NAN, 6.0]], &device);
+    ///    let tensor = tensor.nanmax_dim(1);
+    ///    println!("{tensor}");
+    ///    // [[3.0], [6.0]]
+    /// }
+    /// ```
+    pub fn nanmax_dim(self, dim: usize) -> Self {
+        self.nan_extremum_dim(dim, f32::NEG_INFINITY, Tensor::max_dim)
+    }
+
+    /// Returns the minimum of all the elements of the tensor, ignoring NaN values.
+    ///
+    /// The minimum is NaN when all the elements are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.nanmin();
+    ///    println!("{tensor}");
+    ///    // [1.0]
+    /// }
+    /// ```
+    pub fn nanmin(self) -> Tensor<B, 1> {
+        self.reshape([-1]).nanmin_dim(0)
+    }
+
+    /// Returns the minimum of the elements of the tensor along the given dimension, ignoring NaN
+    /// values.
+    ///
+    /// The minimum is NaN when all the elements along the dimension are NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [f32::NAN, f32::NAN, 6.0]], &device);
+    ///    let tensor = tensor.nanmin_dim(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0], [6.0]]
+    /// }
+    /// ```
+    pub fn nanmin_dim(self, dim: usize) -> Self {
+        self.nan_extremum_dim(dim, f32::INFINITY, Tensor::min_dim)
+    }
+
+    /// Reduces the tensor along the dimension with the extremum function after replacing the NaN
+    /// values by the neutral element of the function, and restores NaN when all the elements are
+    /// NaN.
+    fn nan_extremum_dim(self, dim: usize, neutral: f32, extremum: fn(Self, usize) -> Self) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("NanExtremum", dim));
+        let nan = self.clone().is_nan();
+        let all_nan = nan.clone().all_dim(dim);
+
+        extremum(self.mask_fill(nan, neutral), dim).mask_fill(all_nan, f32::NAN)
+    }
+
+    /// Sums the product of the elements of the input tensors along the dimensions specified
+    /// using a notation based on the Einstein summation convention.
+    ///
+    /// See also [einsum](crate::einsum), which accepts operands of different ranks, e.g. to
+    /// multiply a matrix by a vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///     let rhs = Tensor::<B, 2>::from_data([[5.0, 6.0], [7.0, 8.0]], &device);
+    ///
+    ///     let output: Tensor<B, 2> = Tensor::einsum("ij,jk->ik", &[lhs, rhs]);
+    ///     println!("{output}"); // [[19.0, 22.0], [43.0, 50.0]]
+    /// }
+    /// ```
+    pub fn einsum<const D2: usize>(equation: &str, tensors: &[Self]) -> Tensor<B, D2> {
+        einsum::<B, D2>(equation, tensors)
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -245,6 +838,58 @@ where
         )))
     }
 
+    /// Samples category indices from the non-negative weights along the last dimension.
+    ///
+    /// Each row of the last dimension is treated as the (unnormalized) probabilities of a
+    /// categorical distribution, from which `num_samples` indices are drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of samples to draw for each row.
+    /// * `replacement` - Whether a category can be drawn more than once. Without replacement,
+    ///   `num_samples` can't be greater than the number of categories.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input, except for the last dimension which has size
+    /// `num_samples`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let probs = Tensor::<B, 2>::from_floats([[0.1, 0.9, 0.0], [0.0, 0.0, 1.0]], &device);
+    ///     let samples = probs.multinomial(4, true);
+    ///     println!("{samples}");
+    ///     // The second row is always [2, 2, 2, 2].
+    /// }
+    /// ```
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, D, Int> {
+        let dims = self.dims();
+        let num_categories = dims[D - 1];
+        check!(TensorCheck::multinomial(
+            num_categories,
+            num_samples,
+            replacement
+        ));
+
+        let batch_size = dims[..D - 1].iter().product::<usize>();
+        let weights = self.reshape([batch_size, num_categories]);
+        let samples = Tensor::<B, 2, Int>::new(B::float_multinomial(
+            weights.primitive.tensor(),
+            num_samples,
+            replacement,
+        ));
+
+        let mut shape = dims;
+        shape[D - 1] = num_samples;
+        samples.reshape(shape)
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
@@ -269,6 +914,156 @@ where
         (var, mean)
     }
 
+    /// Computes the `q`-th quantile of the elements along the given dimension.
+    ///
+    /// When the quantile lies between two elements, the value is computed with the given
+    /// interpolation method.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The quantile, between 0 and 1.
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    /// * `interpolation` - The interpolation method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{QuantileInterpolation, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 4.0, 2.0, 3.0], [5.0, 9.0, 6.0, 7.0]], &device);
+    ///    let tensor = tensor.quantile(0.5, 1, QuantileInterpolation::Linear);
+    ///    println!("{tensor}");
+    ///    // [[2.5], [6.5]]
+    /// }
+    /// ```
+    pub fn quantile(self, q: f64, dim: usize, interpolation: QuantileInterpolation) -> Self {
+        check!(TensorCheck::quantile::<D>(&self.dims(), q, dim));
+        stats::quantile(self, q, dim, interpolation)
+    }
+
+    /// Returns the median of the elements along the given dimension, ignoring NaN values.
+    ///
+    /// For an even number of elements, the lower of the two middle elements is returned. When all
+    /// the elements are NaN, the median is NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    pub fn nanmedian(self, dim: usize) -> Self {
+        self.nanmedian_with_indices(dim).0
+    }
+
+    /// Returns the median of the elements along the given dimension, ignoring NaN values, and
+    /// the indices of the median elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, f32::NAN, 3.0], [f32::NAN, 2.0, 0.0]], &device);
+    ///    let (tensor, indices) = tensor.nanmedian_with_indices(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0], [0.0]]
+    ///    println!("{indices}");
+    ///    // [[0], [2]]
+    /// }
+    /// ```
+    pub fn nanmedian_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::order_statistic::<D>(
+            "NanMedian",
+            &self.dims(),
+            dim
+        ));
+        stats::nanmedian_with_indices(self, dim)
+    }
+
+    /// Computes the histogram of the tensor, with `bins` bins of equal width between `min` and
+    /// `max`.
+    ///
+    /// Elements outside of `[min, max]` are ignored. When `min` and `max` are equal, the minimum
+    /// and maximum of the tensor are used instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower bound of the first bin.
+    /// * `max` - The upper bound of the last bin, which is included in the last bin.
+    ///
+    /// # Remarks
+    ///
+    /// When `min` and `max` are equal, the tensor is read to find its range, which might cause a
+    /// synchronization. Use [histc_async](Tensor::histc_async) on backends without synchronous
+    /// reads.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1>::from_data([1.0, 2.0, 1.0, 4.0, 5.0], &device);
+    ///    let histogram = tensor.histc(4, 0.0, 4.0);
+    ///    println!("{histogram}");
+    ///    // [0.0, 2.0, 1.0, 1.0]
+    /// }
+    /// ```
+    pub fn histc(self, bins: usize, min: f64, max: f64) -> Tensor<B, 1> {
+        try_read_sync(self.histc_async(bins, min, max))
+            .expect("Failed to read tensor data synchronously. Try using histc_async instead.")
+    }
+
+    /// Computes the histogram of the tensor, with `bins` bins of equal width between `min` and
+    /// `max`.
+    pub async fn histc_async(self, bins: usize, mut min: f64, mut max: f64) -> Tensor<B, 1> {
+        check!(TensorCheck::histc(bins, min, max));
+
+        let device = self.device();
+        let tensor = self.reshape([-1]);
+        let histogram = Tensor::<B, 1>::zeros([bins], &device);
+
+        if tensor.dims()[0] == 0 {
+            return histogram;
+        }
+
+        if min == max {
+            let bounds = Tensor::cat(alloc::vec![tensor.clone().min(), tensor.clone().max()], 0)
+                .into_data_async()
+                .await;
+            let bounds = bounds.iter::<f64>().collect::<alloc::vec::Vec<_>>();
+            (min, max) = (bounds[0], bounds[1]);
+        }
+        if min == max {
+            min -= 1.0;
+            max += 1.0;
+        }
+
+        let in_range = tensor
+            .clone()
+            .greater_equal_elem(min)
+            .bool_and(tensor.clone().lower_equal_elem(max));
+        let indices = tensor
+            .sub_scalar(min)
+            .mul_scalar(bins as f64 / (max - min))
+            .floor()
+            .int()
+            .clamp(0, bins as i64 - 1);
+
+        histogram.scatter(0, indices, in_range.float())
+    }
+
     /// Converts a tensor to the specified floating point data type.
     ///
     /// # Warning
@@ -385,6 +1180,36 @@ where
         )))
     }
 
+    /// Simulate the quantization of the tensor by quantizing and dequantizing its values, which
+    /// keeps the tensor in floating point precision.
+    ///
+    /// With autodiff, the gradients are computed with the straight-through estimator, and the scales
+    /// and offsets can be learned (LSQ).
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - The quantization scheme, which must use an integer quantization type.
+    /// * `scales` - The scaling factors, broadcastable to the shape of the tensor.
+    /// * `offsets` - The zero-point offsets for affine quantization, broadcastable to the shape of
+    ///   the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The fake-quantized tensor.
+    pub fn fake_quantize(
+        self,
+        scheme: &QuantScheme,
+        scales: Tensor<B, D>,
+        offsets: Option<Tensor<B, D>>,
+    ) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(B::fake_quantize(
+            self.primitive.tensor(),
+            scheme,
+            scales.primitive.tensor(),
+            offsets.map(|offsets| offsets.primitive.tensor()),
+        )))
+    }
+
     /// Convert the tensor back to a higher precision data type.
     ///
     /// If the tensor is not quantized, its value is simply returned.
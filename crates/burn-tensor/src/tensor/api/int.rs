@@ -1,6 +1,8 @@
 use crate::{
     Float, Int, Shape, Tensor, TensorData, TensorPrimitive, backend::Backend, cartesian_grid,
+    check, check::TensorCheck, try_read_sync,
 };
+use alloc::vec;
 
 use core::ops::Range;
 
@@ -27,6 +29,123 @@ where
     pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::int_arange_step(range, step, device))
     }
+
+    /// Counts the number of occurrences of each non-negative integer.
+    ///
+    /// The output has a length of `max(tensor) + 1`, or `minlength` if it is larger.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor contains negative values.
+    ///
+    /// # Remarks
+    ///
+    /// The output size depends on the maximum value, so the tensor is read, which might cause a
+    /// synchronization. Use [bincount_async](Tensor::bincount_async) on backends without
+    /// synchronous reads.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1, Int>::from_data([1, 3, 1, 0], &device);
+    ///    let counts = tensor.bincount(0);
+    ///    println!("{counts}");
+    ///    // [1, 2, 0, 1]
+    /// }
+    /// ```
+    pub fn bincount(self, minlength: usize) -> Self {
+        try_read_sync(self.bincount_async(minlength))
+            .expect("Failed to read tensor data synchronously. Try using bincount_async instead.")
+    }
+
+    /// Counts the number of occurrences of each non-negative integer.
+    pub async fn bincount_async(self, minlength: usize) -> Self {
+        let device = self.device();
+        let length = self.clone().bincount_length(minlength).await;
+
+        if self.dims()[0] == 0 {
+            return Tensor::zeros([length], &device);
+        }
+
+        let ones = self.ones_like();
+        Tensor::zeros([length], &device).scatter(0, self, ones)
+    }
+
+    /// Sums the weights of the occurrences of each non-negative integer.
+    ///
+    /// The output has a length of `max(tensor) + 1`, or `minlength` if it is larger.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor contains negative values, or if the weights don't have the same shape as the
+    /// tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The tensor is read to determine the output size, which might cause a synchronization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1, Int>::from_data([1, 3, 1, 0], &device);
+    ///    let weights = Tensor::<B, 1>::from_data([0.5, 1.0, 2.0, 0.25], &device);
+    ///    let sums = tensor.bincount_weighted(weights, 0);
+    ///    println!("{sums}");
+    ///    // [0.25, 2.5, 0.0, 1.0]
+    /// }
+    /// ```
+    pub fn bincount_weighted(self, weights: Tensor<B, 1>, minlength: usize) -> Tensor<B, 1> {
+        try_read_sync(self.bincount_weighted_async(weights, minlength)).expect(
+            "Failed to read tensor data synchronously. Try using bincount_weighted_async instead.",
+        )
+    }
+
+    /// Sums the weights of the occurrences of each non-negative integer.
+    pub async fn bincount_weighted_async(
+        self,
+        weights: Tensor<B, 1>,
+        minlength: usize,
+    ) -> Tensor<B, 1> {
+        check!(TensorCheck::bincount_weights(
+            &self.shape(),
+            &weights.shape()
+        ));
+
+        let device = self.device();
+        let length = self.clone().bincount_length(minlength).await;
+
+        if self.dims()[0] == 0 {
+            return Tensor::zeros([length], &device);
+        }
+
+        Tensor::zeros([length], &device).scatter(0, self, weights)
+    }
+
+    /// Reads the bounds of the values to compute the length of the output of `bincount`.
+    async fn bincount_length(self, minlength: usize) -> usize {
+        if self.dims()[0] == 0 {
+            return minlength;
+        }
+
+        let bounds = Tensor::cat(vec![self.clone().min(), self.max()], 0)
+            .into_data_async()
+            .await;
+        let bounds = bounds.iter::<i64>().collect::<alloc::vec::Vec<_>>();
+
+        check!(TensorCheck::bincount_values(bounds[0]));
+
+        usize::max(bounds[1] as usize + 1, minlength)
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
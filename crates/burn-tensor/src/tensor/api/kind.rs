@@ -12,6 +12,10 @@ pub struct Int;
 #[derive(Clone, Debug)]
 pub struct Bool;
 
+/// A type-level representation of the kind of a complex tensor.
+#[derive(Clone, Debug)]
+pub struct Complex;
+
 #[derive(Debug, Clone)]
 /// A primitive tensor representation.
 pub enum TensorPrimitive<B: Backend> {
@@ -47,6 +51,36 @@ impl<B: Backend> TensorMetadata for TensorPrimitive<B> {
     }
 }
 
+/// A complex tensor primitive, made of two float tensors of the same shape holding the real and
+/// imaginary parts.
+#[derive(Debug, Clone)]
+pub struct ComplexTensorPrimitive<B: Backend> {
+    /// The real part.
+    pub real: B::FloatTensorPrimitive,
+    /// The imaginary part.
+    pub imag: B::FloatTensorPrimitive,
+}
+
+impl<B: Backend> ComplexTensorPrimitive<B> {
+    /// Creates a complex tensor primitive from its real and imaginary parts.
+    pub fn new(real: B::FloatTensorPrimitive, imag: B::FloatTensorPrimitive) -> Self {
+        Self { real, imag }
+    }
+}
+
+impl<B: Backend> TensorMetadata for ComplexTensorPrimitive<B> {
+    fn dtype(&self) -> DType {
+        match self.real.dtype() {
+            DType::F64 => DType::Complex64,
+            _ => DType::Complex32,
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        self.real.shape()
+    }
+}
+
 /// Tensor metadata trait for tensor primitive.
 pub trait TensorMetadata: Clone + Send + Sync + core::fmt::Debug {
     /// The dtype of the tensor.
@@ -65,6 +99,16 @@ pub trait TensorKind<B: Backend>: Clone + core::fmt::Debug {
     fn name() -> &'static str;
 }
 
+/// A marker for the kinds of tensors whose elements are real numbers, which are the kinds that
+/// support [numeric operations](crate::Numeric).
+///
+/// Complex tensors have their own arithmetic, since many numeric operations such as comparisons,
+/// clamping and sorting rely on an ordering of the elements.
+pub trait RealKind {}
+
+impl RealKind for Float {}
+impl RealKind for Int {}
+
 impl<B: Backend> TensorKind<B> for Float {
     type Primitive = TensorPrimitive<B>;
     fn name() -> &'static str {
@@ -85,3 +129,10 @@ impl<B: Backend> TensorKind<B> for Bool {
         "Bool"
     }
 }
+
+impl<B: Backend> TensorKind<B> for Complex {
+    type Primitive = ComplexTensorPrimitive<B>;
+    fn name() -> &'static str {
+        "Complex"
+    }
+}
@@ -5,18 +5,25 @@ mod autodiff;
 mod base;
 mod bool;
 mod cartesian_grid;
+mod complex;
+mod dynamic;
+mod einsum;
 mod float;
 mod int;
 mod kind;
 mod numeric;
+mod search;
 mod slice;
 mod sort;
 mod transaction;
+mod unique;
 
 pub use argwhere::argwhere_data;
 pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
+pub use dynamic::TensorDyn;
+pub use einsum::{EinsumOperands, einsum};
 pub use float::{DEFAULT_ATOL, DEFAULT_RTOL};
 pub use kind::*;
 pub use numeric::*;
@@ -4,8 +4,9 @@ use crate::{TensorMetadata, alloc::borrow::ToOwned};
 
 use crate::TensorPrimitive;
 use crate::quantization::QTensorPrimitive;
+use crate::tensor::stats;
 use crate::{
-    BasicOps, Bool, Distribution, Element, ElementConversion, Float, Int, Shape, Tensor,
+    BasicOps, Bool, Distribution, Element, ElementConversion, Float, Int, RealKind, Shape, Tensor,
     TensorKind,
     backend::Backend,
     check,
@@ -594,6 +595,170 @@ where
         Self::new(K::prod_dim(self.primitive, dim))
     }
 
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cumsum(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -1.0, 2.0], [5.0, 14.0, 20.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumSum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cumprod(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -2.0, -6.0], [5.0, 45.0, 270.0]]
+    /// }
+    /// ```
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumProd", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummax(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, 1.0, 3.0], [5.0, 9.0, 9.0]]
+    /// }
+    /// ```
+    pub fn cummax(self, dim: usize) -> Self {
+        self.cummax_with_indices(dim).0
+    }
+
+    /// Computes the cumulative maximum of the elements along the given *dimension* or *axis*
+    /// and the indices of the maximum elements.
+    ///
+    /// When the maximum is found multiple times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let (tensor, index) = tensor.cummax_with_indices(1);
+    ///    // [[1.0, 1.0, 3.0], [5.0, 9.0, 9.0]]
+    ///    println!("{tensor}");
+    ///    // [[0, 0, 2], [0, 1, 1]]
+    ///    println!("{index}");
+    /// }
+    /// ```
+    pub fn cummax_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("CumMax", dim));
+
+        let (tensor, index) = K::cummax(self.primitive, dim);
+
+        (Tensor::new(tensor), Tensor::new(index))
+    }
+
+    /// Computes the cumulative minimum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummin(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -2.0, -2.0], [5.0, 5.0, 5.0]]
+    /// }
+    /// ```
+    pub fn cummin(self, dim: usize) -> Self {
+        self.cummin_with_indices(dim).0
+    }
+
+    /// Computes the cumulative minimum of the elements along the given *dimension* or *axis*
+    /// and the indices of the minimum elements.
+    ///
+    /// When the minimum is found multiple times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let (tensor, index) = tensor.cummin_with_indices(1);
+    ///    // [[1.0, -2.0, -2.0], [5.0, 5.0, 5.0]]
+    ///    println!("{tensor}");
+    ///    // [[0, 1, 1], [0, 0, 0]]
+    ///    println!("{index}");
+    /// }
+    /// ```
+    pub fn cummin_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("CumMin", dim));
+
+        let (tensor, index) = K::cummin(self.primitive, dim);
+
+        (Tensor::new(tensor), Tensor::new(index))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Arguments
@@ -1016,6 +1181,136 @@ where
         ))
     }
 
+    /// Gather the slices of the tensor addressed by the index tuples along the last dimension of
+    /// the indices.
+    ///
+    /// With indices of shape `[..., k]`, each index tuple addresses a slice over the last `D - k`
+    /// dimensions of the tensor, so the output has shape `indices.shape[..-1] ++ shape[k..]`.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `output[i, j] = input[indices[i, 0], indices[i, 1], j]; // k = 2`
+    /// `output[i, j, l] = input[indices[i, 0], j, l]; // k = 1`
+    ///
+    /// # Warning
+    /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///   let indices = Tensor::<B, 2, Int>::from_data([[1, 0], [0, 1]], &device);
+    ///   let tensor: Tensor<B, 1> = tensor.gather_nd(indices);
+    ///   println!("{tensor}");
+    ///   // [3.0, 2.0]
+    /// }
+    /// ```
+    pub fn gather_nd<const DI: usize, const DO: usize>(
+        self,
+        indices: Tensor<B, DI, Int>,
+    ) -> Tensor<B, DO, K> {
+        check!(TensorCheck::gather_nd::<DO>(
+            &self.shape(),
+            &indices.shape()
+        ));
+
+        Tensor::new(K::gather_nd(self.primitive, indices.primitive))
+    }
+
+    /// Update the slices of the tensor addressed by the index tuples along the last dimension of
+    /// the indices with the given values.
+    ///
+    /// With indices of shape `[..., k]`, the values have shape `indices.shape[..-1] ++ shape[k..]`.
+    /// When `accumulate` is true the values are summed to the tensor, otherwise they replace it.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i, 0], indices[i, 1], j] = values[i, j]; // k = 2`
+    /// `input[indices[i, 0], indices[i, 1], j] += values[i, j]; // k = 2, accumulate`
+    ///
+    /// # Notes
+    ///
+    /// When the values replace the tensor and an index tuple is duplicated, the result at that
+    /// position is unspecified.
+    ///
+    /// # Warning
+    /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    pub fn scatter_nd<const DI: usize, const DV: usize>(
+        self,
+        indices: Tensor<B, DI, Int>,
+        values: Tensor<B, DV, K>,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::scatter_nd(
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(K::scatter_nd(
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+            accumulate,
+        ))
+    }
+
+    /// Put the values at the positions given by one index tensor per leading dimension.
+    ///
+    /// The `k` index tensors all have the same length `M` and the values have shape
+    /// `[M] ++ shape[k..]`. This is equivalent to [scatter_nd](Tensor::scatter_nd) with the index
+    /// tensors stacked as the index tuples.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[0][i], indices[1][i], j] = values[i, j]; // k = 2`
+    /// `input[indices[0][i], indices[1][i], j] += values[i, j]; // k = 2, accumulate`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::zeros([2, 3], &device);
+    ///   let rows = Tensor::<B, 1, Int>::from_data([0, 1, 0], &device);
+    ///   let cols = Tensor::<B, 1, Int>::from_data([2, 0, 2], &device);
+    ///   let values = Tensor::<B, 1>::from_data([1.0, 2.0, 3.0], &device);
+    ///   let tensor = tensor.index_put(vec![rows, cols], values, true);
+    ///   println!("{tensor}");
+    ///   // [[0.0, 0.0, 4.0], [2.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn index_put<const DV: usize>(
+        self,
+        indices: Vec<Tensor<B, 1, Int>>,
+        values: Tensor<B, DV, K>,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::index_put::<D>(
+            indices.len(),
+            &indices
+                .iter()
+                .map(|index| index.shape())
+                .collect::<Vec<_>>()
+        ));
+
+        self.scatter_nd(
+            Tensor::<B, 1, Int>::stack::<2>(indices, 1),
+            values,
+            accumulate,
+        )
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -1941,6 +2236,130 @@ where
         )
     }
 
+    /// Returns the `k`-th smallest element along the given dimension, where `k = 1` is the
+    /// smallest element.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The rank of the element to return, between 1 and the size of the dimension.
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    pub fn kthvalue(self, k: usize, dim: usize) -> Self {
+        self.kthvalue_with_indices(k, dim).0
+    }
+
+    /// Returns the `k`-th smallest element along the given dimension, where `k = 1` is the
+    /// smallest element, and the indices of the elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The rank of the element to return, between 1 and the size of the dimension.
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[12.0, -2.0, 3.0], [5.0, 3.0, 6.0]], &device);
+    ///    let (tensor, indices) = tensor.kthvalue_with_indices(2, 1);
+    ///    println!("{tensor}");
+    ///    // [[3.0], [5.0]]
+    ///    println!("{indices}");
+    ///    // [[2], [0]]
+    /// }
+    /// ```
+    pub fn kthvalue_with_indices(self, k: usize, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::kthvalue::<D>(&self.dims(), k, dim));
+        stats::kthvalue_with_indices(self, k, dim)
+    }
+
+    /// Returns the median of the elements along the given dimension.
+    ///
+    /// For an even number of elements, the lower of the two middle elements is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    pub fn median(self, dim: usize) -> Self {
+        self.median_with_indices(dim).0
+    }
+
+    /// Returns the median of the elements along the given dimension and the indices of the
+    /// median elements.
+    ///
+    /// For an even number of elements, the lower of the two middle elements is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[12.0, -2.0, 3.0, 1.0], [5.0, 3.0, 6.0, 4.0]], &device);
+    ///    let (tensor, indices) = tensor.median_with_indices(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0], [4.0]]
+    ///    println!("{indices}");
+    ///    // [[3], [3]]
+    /// }
+    /// ```
+    pub fn median_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::order_statistic::<D>(
+            "Median",
+            &self.dims(),
+            dim
+        ));
+        stats::median_with_indices(self, dim)
+    }
+
+    /// Returns the most frequent element along the given dimension.
+    ///
+    /// When several elements are the most frequent, the smallest one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    pub fn mode(self, dim: usize) -> Self {
+        self.mode_with_indices(dim).0
+    }
+
+    /// Returns the most frequent element along the given dimension and the index of one of its
+    /// occurrences.
+    ///
+    /// When several elements are the most frequent, the smallest one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce, which is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2, Int>::from_data([[1, 3, 3, 2], [4, 2, 4, 2]], &device);
+    ///    let (tensor, indices) = tensor.mode_with_indices(1);
+    ///    println!("{tensor}");
+    ///    // [[3], [2]]
+    /// }
+    /// ```
+    pub fn mode_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::order_statistic::<D>("Mode", &self.dims(), dim));
+        stats::mode_with_indices(self, dim)
+    }
+
     /// Pad the tensor of rank two or higher with the given value on the last two dimensions.
     ///
     /// # Arguments
@@ -2141,7 +2560,7 @@ where
 /// # Warnings
 ///
 /// This is an internal trait, use the public API provided by [tensor struct](Tensor).
-pub trait Numeric<B: Backend>: BasicOps<B>
+pub trait Numeric<B: Backend>: BasicOps<B> + RealKind
 where
     Self::Elem: Element,
 {
@@ -2551,6 +2970,92 @@ where
     ///
     fn prod_dim(tensor: Self::Primitive, dim: usize) -> Self::Primitive;
 
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, containing the running sums along `dim`.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative sum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cumsum](Tensor::cumsum) function, which is more high-level and designed for public use.
+    fn cumsum(tensor: Self::Primitive, dim: usize) -> Self::Primitive;
+
+    /// Computes the cumulative product of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, containing the running products along `dim`.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative product of a tensor along a dimension, users should prefer the
+    /// [Tensor::cumprod](Tensor::cumprod) function, which is more high-level and designed for public use.
+    fn cumprod(tensor: Self::Primitive, dim: usize) -> Self::Primitive;
+
+    /// Computes the cumulative maximum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running maximum along `dim` and the indices of the maximum elements.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative maximum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cummax_with_indices](Tensor::cummax_with_indices) function, which is more high-level
+    /// and designed for public use.
+    fn cummax(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, B::IntTensorPrimitive);
+
+    /// Computes the cumulative minimum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running minimum along `dim` and the indices of the minimum elements.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative minimum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cummin_with_indices](Tensor::cummin_with_indices) function, which is more high-level
+    /// and designed for public use.
+    fn cummin(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, B::IntTensorPrimitive);
+
     /// Computes the mean of all the elements of the tensor.
     ///
     /// # Arguments
@@ -2990,6 +3495,58 @@ where
         values: Self::Primitive,
     ) -> Self::Primitive;
 
+    /// Gathers the slices of a tensor addressed by the index tuples along the last dimension of
+    /// the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to gather slices from.
+    /// * `indices` - The index tuples, with shape `[..., k]`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with shape `indices.shape[..-1] ++ tensor.shape[k..]` containing the gathered
+    /// slices.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For gathering slices with index tuples, users should prefer the
+    /// [Tensor::gather_nd](Tensor::gather_nd) function, which is more high-level and designed for public use.
+    fn gather_nd(tensor: Self::Primitive, indices: B::IntTensorPrimitive) -> Self::Primitive;
+
+    /// Updates the slices of a tensor addressed by the index tuples along the last dimension of
+    /// the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to update.
+    /// * `indices` - The index tuples, with shape `[..., k]`.
+    /// * `values` - The values, with shape `indices.shape[..-1] ++ tensor.shape[k..]`.
+    /// * `accumulate` - Whether the values are summed to the tensor instead of replacing it.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor with the updated slices.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For updating slices with index tuples, users should prefer the
+    /// [Tensor::scatter_nd](Tensor::scatter_nd) function, which is more high-level and designed for public use.
+    fn scatter_nd(
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        accumulate: bool,
+    ) -> Self::Primitive;
+
     /// Gets the indices of the maximum elements of a tensor along an axis.
     ///
     /// # Arguments
@@ -3492,6 +4049,22 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_prod_dim(tensor, dim)
     }
 
+    fn cumsum(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn cumprod(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn cummax(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        B::int_cummax(tensor, dim)
+    }
+
+    fn cummin(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        B::int_cummin(tensor, dim)
+    }
+
     fn mean(tensor: Self::Primitive) -> Self::Primitive {
         B::int_mean(tensor)
     }
@@ -3582,6 +4155,19 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_scatter(dim, tensor, indices, values)
     }
 
+    fn gather_nd(tensor: Self::Primitive, indices: B::IntTensorPrimitive) -> Self::Primitive {
+        B::int_gather_nd(tensor, indices)
+    }
+
+    fn scatter_nd(
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        accumulate: bool,
+    ) -> Self::Primitive {
+        B::int_scatter_nd(tensor, indices, values, accumulate)
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         B::int_argmax(tensor, dim)
     }
@@ -3852,6 +4438,24 @@ impl<B: Backend> Numeric<B> for Float {
         }
     }
 
+    fn cumsum(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        TensorPrimitive::Float(B::float_cumsum(tensor.tensor(), dim))
+    }
+
+    fn cumprod(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        TensorPrimitive::Float(B::float_cumprod(tensor.tensor(), dim))
+    }
+
+    fn cummax(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        let (values, indices) = B::float_cummax(tensor.tensor(), dim);
+        (TensorPrimitive::Float(values), indices)
+    }
+
+    fn cummin(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        let (values, indices) = B::float_cummin(tensor.tensor(), dim);
+        (TensorPrimitive::Float(values), indices)
+    }
+
     fn mean(tensor: Self::Primitive) -> Self::Primitive {
         match tensor {
             TensorPrimitive::Float(tensor) => TensorPrimitive::Float(B::float_mean(tensor)),
@@ -3977,6 +4581,24 @@ impl<B: Backend> Numeric<B> for Float {
         ))
     }
 
+    fn gather_nd(tensor: Self::Primitive, indices: B::IntTensorPrimitive) -> Self::Primitive {
+        TensorPrimitive::Float(B::float_gather_nd(tensor.tensor(), indices))
+    }
+
+    fn scatter_nd(
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        accumulate: bool,
+    ) -> Self::Primitive {
+        TensorPrimitive::Float(B::float_scatter_nd(
+            tensor.tensor(),
+            indices,
+            values.tensor(),
+            accumulate,
+        ))
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         match tensor {
             TensorPrimitive::Float(tensor) => B::float_argmax(tensor, dim),
@@ -4270,7 +4892,7 @@ where
     type Output = Self;
 
     fn sub(self, rhs: Tensor<B, D, K>) -> Self::Output {
-        Tensor::sub(self, rhs)
+        Tensor::<B, D, K>::sub(self, rhs)
     }
 }
 
@@ -4298,7 +4920,7 @@ macro_rules! impl_tensor_scalar_sub {
                 type Output = Tensor<B, D, K>;
 
                 fn sub(self, tensor: Tensor<B, D, K>) -> Self::Output {
-                    Tensor::add_scalar(Tensor::neg(tensor), self)
+                    Tensor::add_scalar(Tensor::<B, D, K>::neg(tensor), self)
                 }
             }
         )*
@@ -4314,7 +4936,7 @@ where
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        Tensor::div(self, rhs)
+        Tensor::<B, D, K>::div(self, rhs)
     }
 }
 
@@ -4382,7 +5004,7 @@ where
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Tensor::mul(self, rhs)
+        Tensor::<B, D, K>::mul(self, rhs)
     }
 }
 
@@ -4427,6 +5049,6 @@ where
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Tensor::neg(self)
+        Tensor::<B, D, K>::neg(self)
     }
 }
@@ -0,0 +1,129 @@
+use crate::{Int, Numeric, Tensor, backend::Backend, check, check::TensorCheck};
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+{
+    /// Finds the indices where the values should be inserted in the current tensor to keep it
+    /// sorted.
+    ///
+    /// The current tensor is sorted in ascending order along its last dimension, and all the
+    /// other dimensions must match the values. For each value `v` in a row, the returned index `i`
+    /// satisfies `sorted[i - 1] < v <= sorted[i]`, or `sorted[i - 1] <= v < sorted[i]` when
+    /// `right` is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to insert.
+    /// * `right` - Whether to return the last valid index instead of the first one, for values
+    ///   equal to an element of the sorted tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let sorted = Tensor::<B, 2>::from_data([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]], &device);
+    ///    let values = Tensor::<B, 2>::from_data([[3.0, 6.0], [1.0, 4.0]], &device);
+    ///    let indices = sorted.searchsorted(values, false);
+    ///    println!("{indices}");
+    ///    // [[1, 3], [0, 1]]
+    /// }
+    /// ```
+    pub fn searchsorted(self, values: Self, right: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::searchsorted::<D>(&self.dims(), &values.dims()));
+
+        let length = self.dims()[D - 1];
+        search_sorted(values, length, right, |indices| {
+            self.clone().gather(D - 1, indices)
+        })
+    }
+
+    /// Returns the index of the bucket of each element, given the sorted boundaries of the
+    /// buckets.
+    ///
+    /// For each element `v`, the returned index `i` satisfies
+    /// `boundaries[i - 1] < v <= boundaries[i]`, or `boundaries[i - 1] <= v < boundaries[i]` when
+    /// `right` is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundaries` - The boundaries of the buckets, sorted in ascending order.
+    /// * `right` - Whether the buckets are closed on the left instead of the right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[0.5, 3.0], [7.0, 4.0]], &device);
+    ///    let boundaries = Tensor::<B, 1>::from_data([1.0, 3.0, 5.0], &device);
+    ///    let indices = tensor.bucketize(boundaries, false);
+    ///    println!("{indices}");
+    ///    // [[0, 1], [3, 2]]
+    /// }
+    /// ```
+    pub fn bucketize(self, boundaries: Tensor<B, 1, K>, right: bool) -> Tensor<B, D, Int> {
+        let shape = self.shape();
+        let length = boundaries.dims()[0];
+
+        search_sorted(
+            self.reshape([shape.num_elements()]),
+            length,
+            right,
+            |indices| boundaries.clone().select(0, indices),
+        )
+        .reshape(shape)
+    }
+}
+
+/// Computes the insertion index of each value in a sorted sequence of the given length.
+///
+/// The search moves every value forward by decreasing powers of two while the preceding element
+/// of the sequence is smaller than the value, so it only needs comparisons and lookups of the
+/// sequence, computed by `lookup` from the indices of the elements to compare with.
+fn search_sorted<B, const D: usize, K, F>(
+    values: Tensor<B, D, K>,
+    length: usize,
+    right: bool,
+    lookup: F,
+) -> Tensor<B, D, Int>
+where
+    B: Backend,
+    K: Numeric<B>,
+    F: Fn(Tensor<B, D, Int>) -> Tensor<B, D, K>,
+{
+    let mut positions = Tensor::<B, D, Int>::zeros(values.shape(), &values.device());
+
+    if length == 0 {
+        return positions;
+    }
+
+    let mut step = length.next_power_of_two();
+    while step > 0 {
+        let candidates = positions.clone().add_scalar(step as i64);
+        let in_bounds = candidates.clone().lower_equal_elem(length as i64);
+        let previous = lookup(
+            candidates
+                .clone()
+                .sub_scalar(1)
+                .clamp_max(length as i64 - 1),
+        );
+
+        let advance = match right {
+            true => previous.lower_equal(values.clone()),
+            false => previous.lower(values.clone()),
+        };
+        positions = positions.mask_where(advance.bool_and(in_bounds), candidates);
+        step /= 2;
+    }
+
+    positions
+}
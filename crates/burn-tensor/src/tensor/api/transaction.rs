@@ -1,4 +1,4 @@
-use super::{BasicOps, Tensor, TensorPrimitive};
+use super::{BasicOps, ComplexTensorPrimitive, Tensor, TensorPrimitive};
 use crate::{
     TensorData,
     backend::Backend,
@@ -31,6 +31,7 @@ enum Order {
     QFloat(usize),
     Int(usize),
     Bool(usize),
+    Complex(usize, usize),
 }
 
 impl<B: Backend> Transaction<B> {
@@ -63,6 +64,10 @@ impl<B: Backend> Transaction<B> {
                 Order::QFloat(index) => qfloats.get_mut(index).unwrap().take().unwrap(),
                 Order::Int(index) => ints.get_mut(index).unwrap().take().unwrap(),
                 Order::Bool(index) => bools.get_mut(index).unwrap().take().unwrap(),
+                Order::Complex(real, imag) => TensorData::complex(
+                    floats.get_mut(real).unwrap().take().unwrap(),
+                    floats.get_mut(imag).unwrap().take().unwrap(),
+                ),
             })
             .collect::<Vec<_>>()
     }
@@ -89,4 +94,11 @@ impl<B: Backend> Transaction<B> {
         self.orders.push(Order::Bool(self.op.read_bools.len()));
         self.op.read_bools.push(tensor);
     }
+
+    pub(crate) fn register_complex(&mut self, tensor: ComplexTensorPrimitive<B>) {
+        let index = self.op.read_floats.len();
+        self.orders.push(Order::Complex(index, index + 1));
+        self.op.read_floats.push(tensor.real);
+        self.op.read_floats.push(tensor.imag);
+    }
 }
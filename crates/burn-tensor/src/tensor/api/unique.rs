@@ -0,0 +1,209 @@
+use alloc::vec;
+
+use crate::{Int, Numeric, Tensor, backend::Backend, try_read_sync};
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+{
+    /// Returns the sorted unique elements of the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The number of unique elements depends on the data, so the tensor is read to determine the
+    /// output size, which might cause a synchronization. Use
+    /// [unique_async](Tensor::unique_async) on backends without synchronous reads.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2, Int>::from_data([[3, 1, 3], [2, 1, 5]], &device);
+    ///    let tensor = tensor.unique();
+    ///    println!("{tensor}");
+    ///    // [1, 2, 3, 5]
+    /// }
+    /// ```
+    pub fn unique(self) -> Tensor<B, 1, K> {
+        try_read_sync(self.unique_async())
+            .expect("Failed to read tensor data synchronously. Try using unique_async instead.")
+    }
+
+    /// Returns the sorted unique elements of the tensor.
+    pub async fn unique_async(self) -> Tensor<B, 1, K> {
+        self.unique_with_inverse_and_counts_async().await.0
+    }
+
+    /// Returns the sorted unique elements of the tensor, the index of each element of the tensor
+    /// in the unique elements and the number of occurrences of each unique element.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - the sorted unique elements;
+    /// - the inverse indices, with the same shape as the tensor, such that
+    ///   `unique.select(0, inverse.flatten(0, D - 1))` is the flattened tensor;
+    /// - the counts of each unique element.
+    ///
+    /// # Remarks
+    ///
+    /// The tensor is read to determine the output size, which might cause a synchronization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2, Int>::from_data([[3, 1, 3], [2, 1, 5]], &device);
+    ///    let (unique, inverse, counts) = tensor.unique_with_inverse_and_counts();
+    ///    println!("{unique}");
+    ///    // [1, 2, 3, 5]
+    ///    println!("{inverse}");
+    ///    // [[2, 0, 2], [1, 0, 3]]
+    ///    println!("{counts}");
+    ///    // [2, 1, 2, 1]
+    /// }
+    /// ```
+    pub fn unique_with_inverse_and_counts(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        try_read_sync(self.unique_with_inverse_and_counts_async()).expect(
+            "Failed to read tensor data synchronously. \
+             Try using unique_with_inverse_and_counts_async instead.",
+        )
+    }
+
+    /// Returns the sorted unique elements of the tensor, the index of each element of the tensor
+    /// in the unique elements and the number of occurrences of each unique element.
+    pub async fn unique_with_inverse_and_counts_async(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        let shape = self.shape();
+        let device = self.device();
+        let num_elements = shape.num_elements();
+
+        let (sorted, sorted_indices) = self.reshape([num_elements]).sort_with_indices(0);
+        let (unique, groups, counts) = consecutive_groups(sorted).await;
+
+        // Each sorted element belongs to a group, which is moved back to the original position.
+        let inverse = Tensor::<B, 1, Int>::zeros([num_elements], &device)
+            .scatter(0, sorted_indices, groups)
+            .reshape(shape);
+
+        (unique, inverse, counts)
+    }
+
+    /// Returns the elements of the tensor with consecutive duplicates removed.
+    ///
+    /// The tensor is flattened, so only the duplicates consecutive in the flattened tensor are
+    /// removed.
+    ///
+    /// # Remarks
+    ///
+    /// The tensor is read to determine the output size, which might cause a synchronization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1, Int>::from_data([1, 1, 2, 2, 3, 1, 1], &device);
+    ///    let tensor = tensor.unique_consecutive();
+    ///    println!("{tensor}");
+    ///    // [1, 2, 3, 1]
+    /// }
+    /// ```
+    pub fn unique_consecutive(self) -> Tensor<B, 1, K> {
+        try_read_sync(self.unique_consecutive_async()).expect(
+            "Failed to read tensor data synchronously. Try using unique_consecutive_async instead.",
+        )
+    }
+
+    /// Returns the elements of the tensor with consecutive duplicates removed.
+    pub async fn unique_consecutive_async(self) -> Tensor<B, 1, K> {
+        self.unique_consecutive_with_inverse_and_counts_async()
+            .await
+            .0
+    }
+
+    /// Returns the elements of the tensor with consecutive duplicates removed, the index of each
+    /// element of the tensor in the output and the length of each group of consecutive
+    /// duplicates.
+    ///
+    /// # Remarks
+    ///
+    /// The tensor is read to determine the output size, which might cause a synchronization.
+    pub fn unique_consecutive_with_inverse_and_counts(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        try_read_sync(self.unique_consecutive_with_inverse_and_counts_async()).expect(
+            "Failed to read tensor data synchronously. \
+             Try using unique_consecutive_with_inverse_and_counts_async instead.",
+        )
+    }
+
+    /// Returns the elements of the tensor with consecutive duplicates removed, the index of each
+    /// element of the tensor in the output and the length of each group of consecutive
+    /// duplicates.
+    pub async fn unique_consecutive_with_inverse_and_counts_async(
+        self,
+    ) -> (Tensor<B, 1, K>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        let shape = self.shape();
+        let num_elements = shape.num_elements();
+
+        let (unique, groups, counts) = consecutive_groups(self.reshape([num_elements])).await;
+
+        (unique, groups.reshape(shape), counts)
+    }
+}
+
+/// Splits a 1D tensor into groups of consecutive equal elements.
+///
+/// Returns the first element of each group, the group index of each element and the size of each
+/// group.
+async fn consecutive_groups<B: Backend, K: Numeric<B>>(
+    tensor: Tensor<B, 1, K>,
+) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+    let device = tensor.device();
+    let num_elements = tensor.dims()[0];
+
+    if num_elements == 0 {
+        let empty = Tensor::<B, 1, Int>::empty([0], &device);
+        return (tensor, empty.clone(), empty);
+    }
+
+    // Marks the first element of each group.
+    let mut starts = Tensor::<B, 1, Int>::ones([1], &device).bool();
+    if num_elements > 1 {
+        let changes = tensor
+            .clone()
+            .narrow(0, 1, num_elements - 1)
+            .not_equal(tensor.clone().narrow(0, 0, num_elements - 1));
+        starts = Tensor::cat(vec![starts, changes], 0);
+    }
+    let groups = starts.clone().int().cumsum(0).sub_scalar(1);
+
+    // The number of groups depends on the data, which requires a read.
+    let starts = starts.argwhere_async().await;
+    let num_groups = starts.dims()[0];
+    let starts = starts.reshape([num_groups]);
+
+    let mut ends = Tensor::<B, 1, Int>::full([1], num_elements as i64, &device);
+    if num_groups > 1 {
+        ends = Tensor::cat(vec![starts.clone().narrow(0, 1, num_groups - 1), ends], 0);
+    }
+    let counts = ends - starts.clone();
+
+    (tensor.select(0, starts), groups, counts)
+}
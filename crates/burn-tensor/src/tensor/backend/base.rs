@@ -1,4 +1,4 @@
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use crate::TensorMetadata;
 use crate::tensor::Element;
@@ -58,6 +58,9 @@ pub trait Backend:
     + IntTensorOps<Self>
     + ModuleOps<Self>
     + ActivationOps<Self>
+    + LinalgOps<Self>
+    + FftOps<Self>
+    + ComplexOps<Self>
     + QTensorOps<Self>
     + TransactionOps<Self>
     + Clone
@@ -183,6 +186,78 @@ pub trait AutodiffBackend: Backend {
         grad: FloatTensor<Self::InnerBackend>,
     );
 
+    /// Registers a hook called with the gradient of a tensor during the next backward pass.
+    ///
+    /// The gradient is replaced by the tensor returned by the hook before it is propagated to the
+    /// parents of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// By default, the backend doesn't support gradient hooks.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tracked tensor to register the hook on.
+    /// * `hook` - The function called with the gradient of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the hook registered.
+    #[allow(unused_variables)]
+    fn register_hook<F>(tensor: FloatTensor<Self>, hook: F) -> FloatTensor<Self>
+    where
+        F: Fn(FloatTensor<Self::InnerBackend>) -> FloatTensor<Self::InnerBackend> + Send + 'static,
+    {
+        panic!("Gradient hooks are unsupported by this backend")
+    }
+
+    /// Keeps the gradient of a non-leaf tensor after the backward pass.
+    ///
+    /// # Panics
+    ///
+    /// By default, the backend doesn't support retaining the gradients of non-leaf tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tracked tensor whose gradient is retained.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with its gradient retained.
+    #[allow(unused_variables)]
+    fn retain_grad(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        panic!("Retaining the gradients of non-leaf tensors is unsupported by this backend")
+    }
+
+    /// Computes a function without keeping its intermediate tensors for the backward pass.
+    ///
+    /// The function is recorded as a single operation, which saves its inputs and computes the
+    /// function again during the backward pass to differentiate it, trading compute for memory.
+    /// By default, the function is simply called.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The inputs of the function, saved for the backward pass.
+    /// * `parameters` - The parameters of the function, which are kept by their module anyway.
+    /// * `func` - The function, called with the inputs and the parameters.
+    ///
+    /// # Returns
+    ///
+    /// The output of the function.
+    fn checkpoint<Func>(
+        inputs: Vec<FloatTensor<Self>>,
+        parameters: Vec<FloatTensor<Self>>,
+        func: Func,
+    ) -> FloatTensor<Self>
+    where
+        Func: Fn(Vec<FloatTensor<Self>>, Vec<FloatTensor<Self>>) -> FloatTensor<Self>
+            + Send
+            + Sync
+            + 'static,
+    {
+        func(inputs, parameters)
+    }
+
     /// Returns the tensor with inner backend type.
     ///
     /// # Arguments
@@ -9,15 +9,13 @@ use half::{bf16, f16};
 use num_traits::{Float, ToPrimitive};
 
 use crate::{
-    DType, Distribution, Element, ElementConversion,
+    Complex32, Complex64, ComplexElem, DType, Distribution, Element, ElementConversion, e4m3, e5m2,
     quantization::{QuantInputType, QuantScheme, QuantizationStrategy, QuantizedBytes},
     tensor::bytes::Bytes,
 };
 
 use rand::RngCore;
 
-use super::quantization::{QuantLevel, QuantMode};
-
 /// The things that can go wrong when manipulating tensor data.
 #[derive(Debug)]
 pub enum DataError {
@@ -84,6 +82,62 @@ impl TensorData {
         }
     }
 
+    /// Creates complex tensor data from its real and imaginary parts.
+    ///
+    /// The parts are stored in double precision if either of them is `f64`, and in single
+    /// precision otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the real and imaginary parts don't have the same shape.
+    pub fn complex(real: TensorData, imag: TensorData) -> Self {
+        assert_eq!(
+            real.shape, imag.shape,
+            "The real and imaginary parts must have the same shape"
+        );
+
+        if real.dtype == DType::F64 || imag.dtype == DType::F64 {
+            Self::complex_from_parts::<f64>(real, imag)
+        } else {
+            Self::complex_from_parts::<f32>(real, imag)
+        }
+    }
+
+    fn complex_from_parts<E: Element>(real: TensorData, imag: TensorData) -> Self {
+        let values = real
+            .iter::<E>()
+            .zip(imag.iter::<E>())
+            .map(|(re, im)| ComplexElem::new(re, im))
+            .collect();
+
+        TensorData::new(values, real.shape)
+    }
+
+    /// Returns the real and imaginary parts of complex tensor data.
+    ///
+    /// # Panics
+    ///
+    /// If the data type isn't complex.
+    pub fn into_complex_parts(self) -> (TensorData, TensorData) {
+        match self.dtype {
+            DType::Complex32 => self.complex_into_parts::<f32>(),
+            DType::Complex64 => self.complex_into_parts::<f64>(),
+            dtype => panic!("Expected complex data, got {dtype:?}"),
+        }
+    }
+
+    fn complex_into_parts<E: Element>(self) -> (TensorData, TensorData) {
+        let (real, imag) = self
+            .iter::<ComplexElem<E>>()
+            .map(|value| (value.re, value.im))
+            .unzip();
+
+        (
+            TensorData::new(real, self.shape.clone()),
+            TensorData::new(imag, self.shape),
+        )
+    }
+
     // Check that the input vector contains a correct number of elements
     fn check_data_len<E: Element>(data: &[E], shape: &Vec<usize>) {
         let expected_data_len = Self::numel(shape);
@@ -238,6 +292,16 @@ impl TensorData {
                         .iter()
                         .map(|e: &f16| e.elem::<E>()),
                 ),
+                DType::E4M3 => Box::new(
+                    bytemuck::checked::cast_slice(&self.bytes)
+                        .iter()
+                        .map(|e: &e4m3| e.elem::<E>()),
+                ),
+                DType::E5M2 => Box::new(
+                    bytemuck::checked::cast_slice(&self.bytes)
+                        .iter()
+                        .map(|e: &e5m2| e.elem::<E>()),
+                ),
                 DType::F32 | DType::Flex32 => Box::new(
                     bytemuck::checked::cast_slice(&self.bytes)
                         .iter()
@@ -248,16 +312,25 @@ impl TensorData {
                         .iter()
                         .map(|e: &f64| e.elem::<E>()),
                 ),
+                DType::Complex32 => Box::new(
+                    bytemuck::checked::cast_slice(&self.bytes)
+                        .iter()
+                        .map(|e: &Complex32| e.elem::<E>()),
+                ),
+                DType::Complex64 => Box::new(
+                    bytemuck::checked::cast_slice(&self.bytes)
+                        .iter()
+                        .map(|e: &Complex64| e.elem::<E>()),
+                ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool => Box::new(self.bytes.iter().map(|e| e.elem::<E>())),
                 DType::QFloat(scheme) => match scheme {
                     QuantScheme {
-                        level: QuantLevel::Tensor | QuantLevel::Block(_),
-                        mode: QuantMode::Symmetric,
-                        q_type: QuantInputType::QInt8,
+                        q_type:
+                            QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4,
                         ..
                     } => {
-                        // Quantized int8 values
+                        // Quantized integer values
                         let q_bytes = QuantizedBytes {
                             bytes: self.bytes.clone(),
                             scheme,
@@ -273,6 +346,32 @@ impl TensorData {
                                 .into_iter(),
                         )
                     }
+                    QuantScheme {
+                        q_type: q_type @ (QuantInputType::E4M3 | QuantInputType::E5M2),
+                        ..
+                    } => {
+                        // Quantized fp8 values, stored as their bits
+                        let q_bytes = QuantizedBytes {
+                            bytes: self.bytes.clone(),
+                            scheme,
+                            num_elements: self.num_elements(),
+                        };
+                        let (values, _) = q_bytes.into_vec_i8();
+                        let bits: Vec<u8> = bytemuck::allocation::cast_vec(values);
+
+                        let values: Vec<E> = match q_type {
+                            QuantInputType::E4M3 => bits
+                                .into_iter()
+                                .map(|e| e4m3::from_bits(e).elem::<E>())
+                                .collect(),
+                            _ => bits
+                                .into_iter()
+                                .map(|e| e5m2::from_bits(e).elem::<E>())
+                                .collect(),
+                        };
+
+                        Box::new(values.into_iter())
+                    }
                 },
             }
         }
@@ -354,12 +453,16 @@ impl TensorData {
         } else if dtype.size() == self.dtype.size()
             && !matches!(self.dtype, DType::Bool | DType::QFloat(_))
             && !matches!(dtype, DType::Bool | DType::QFloat(_))
+            && !self.dtype.is_complex()
+            && !dtype.is_complex()
         {
             match self.dtype {
                 DType::F64 => self.convert_inplace_dtype::<f64>(dtype),
                 DType::F32 | DType::Flex32 => self.convert_inplace_dtype::<f32>(dtype),
                 DType::F16 => self.convert_inplace_dtype::<f16>(dtype),
                 DType::BF16 => self.convert_inplace_dtype::<bf16>(dtype),
+                DType::E4M3 => self.convert_inplace_dtype::<e4m3>(dtype),
+                DType::E5M2 => self.convert_inplace_dtype::<e5m2>(dtype),
                 DType::I64 => self.convert_inplace_dtype::<i64>(dtype),
                 DType::I32 => self.convert_inplace_dtype::<i32>(dtype),
                 DType::I16 => self.convert_inplace_dtype::<i16>(dtype),
@@ -368,7 +471,9 @@ impl TensorData {
                 DType::U32 => self.convert_inplace_dtype::<u32>(dtype),
                 DType::U16 => self.convert_inplace_dtype::<u16>(dtype),
                 DType::U8 => self.convert_inplace_dtype::<u8>(dtype),
-                DType::Bool | DType::QFloat(_) => unreachable!(),
+                DType::Bool | DType::Complex32 | DType::Complex64 | DType::QFloat(_) => {
+                    unreachable!()
+                }
             }
         } else {
             match self.dtype {
@@ -376,6 +481,8 @@ impl TensorData {
                 DType::F32 | DType::Flex32 => self.convert_clone_dtype::<f32>(dtype),
                 DType::F16 => self.convert_clone_dtype::<f16>(dtype),
                 DType::BF16 => self.convert_clone_dtype::<bf16>(dtype),
+                DType::E4M3 => self.convert_clone_dtype::<e4m3>(dtype),
+                DType::E5M2 => self.convert_clone_dtype::<e5m2>(dtype),
                 DType::I64 => self.convert_clone_dtype::<i64>(dtype),
                 DType::I32 => self.convert_clone_dtype::<i32>(dtype),
                 DType::I16 => self.convert_clone_dtype::<i16>(dtype),
@@ -385,6 +492,8 @@ impl TensorData {
                 DType::U16 => self.convert_clone_dtype::<u16>(dtype),
                 DType::U8 => self.convert_clone_dtype::<u8>(dtype),
                 DType::Bool => self.convert_clone_dtype::<bool>(dtype),
+                DType::Complex32 => self.convert_clone_dtype::<Complex32>(dtype),
+                DType::Complex64 => self.convert_clone_dtype::<Complex64>(dtype),
                 DType::QFloat(_) => unreachable!(),
             }
         }
@@ -396,6 +505,8 @@ impl TensorData {
             DType::F32 | DType::Flex32 => self.convert_inplace::<Current, f32>(),
             DType::F16 => self.convert_inplace::<Current, f16>(),
             DType::BF16 => self.convert_inplace::<Current, bf16>(),
+            DType::E4M3 => self.convert_inplace::<Current, e4m3>(),
+            DType::E5M2 => self.convert_inplace::<Current, e5m2>(),
             DType::I64 => self.convert_inplace::<Current, i64>(),
             DType::I32 => self.convert_inplace::<Current, i32>(),
             DType::I16 => self.convert_inplace::<Current, i16>(),
@@ -404,7 +515,9 @@ impl TensorData {
             DType::U32 => self.convert_inplace::<Current, u32>(),
             DType::U16 => self.convert_inplace::<Current, u16>(),
             DType::U8 => self.convert_inplace::<Current, u8>(),
-            DType::Bool | DType::QFloat(_) => unreachable!(),
+            DType::Bool | DType::Complex32 | DType::Complex64 | DType::QFloat(_) => {
+                unreachable!()
+            }
         }
     }
 
@@ -428,6 +541,8 @@ impl TensorData {
             DType::F32 | DType::Flex32 => self.convert_clone::<Current, f32>(),
             DType::F16 => self.convert_clone::<Current, f16>(),
             DType::BF16 => self.convert_clone::<Current, bf16>(),
+            DType::E4M3 => self.convert_clone::<Current, e4m3>(),
+            DType::E5M2 => self.convert_clone::<Current, e5m2>(),
             DType::I64 => self.convert_clone::<Current, i64>(),
             DType::I32 => self.convert_clone::<Current, i32>(),
             DType::I16 => self.convert_clone::<Current, i16>(),
@@ -437,6 +552,8 @@ impl TensorData {
             DType::U16 => self.convert_clone::<Current, u16>(),
             DType::U8 => self.convert_clone::<Current, u8>(),
             DType::Bool => self.convert_clone::<Current, bool>(),
+            DType::Complex32 => self.convert_clone::<Current, Complex32>(),
+            DType::Complex64 => self.convert_clone::<Current, Complex64>(),
             DType::QFloat(_) => unreachable!(),
         }
     }
@@ -489,7 +606,7 @@ impl TensorData {
                 num_elements,
             };
 
-            let values = q_bytes.dequantize().0;
+            let values = q_bytes.dequantize(&self.shape).0;
             Ok(Self::new(values, self.shape))
         } else {
             Err(DataError::TypeMismatch(format!(
@@ -525,6 +642,8 @@ impl TensorData {
             DType::F32 | DType::Flex32 => self.assert_eq_elem::<f32>(other),
             DType::F16 => self.assert_eq_elem::<f16>(other),
             DType::BF16 => self.assert_eq_elem::<bf16>(other),
+            DType::E4M3 => self.assert_eq_elem::<e4m3>(other),
+            DType::E5M2 => self.assert_eq_elem::<e5m2>(other),
             DType::I64 => self.assert_eq_elem::<i64>(other),
             DType::I32 => self.assert_eq_elem::<i32>(other),
             DType::I16 => self.assert_eq_elem::<i16>(other),
@@ -534,6 +653,8 @@ impl TensorData {
             DType::U16 => self.assert_eq_elem::<u16>(other),
             DType::U8 => self.assert_eq_elem::<u8>(other),
             DType::Bool => self.assert_eq_elem::<bool>(other),
+            DType::Complex32 => self.assert_eq_elem::<Complex32>(other),
+            DType::Complex64 => self.assert_eq_elem::<Complex64>(other),
             DType::QFloat(q) => {
                 // Strict or not, it doesn't make sense to compare quantized data to not quantized data for equality
                 let q_other = if let DType::QFloat(q_other) = other.dtype {
@@ -544,7 +665,14 @@ impl TensorData {
 
                 // Data equality mostly depends on input quantization type, but we also check level
                 if q.q_type == q_other.q_type && q.level == q_other.level {
-                    self.assert_eq_elem::<i8>(other)
+                    match q.q_type {
+                        QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+                            self.assert_eq_elem::<i8>(other)
+                        }
+                        QuantInputType::E4M3 | QuantInputType::E5M2 => {
+                            self.assert_eq_elem::<f32>(other)
+                        }
+                    }
                 } else {
                     panic!("Quantization schemes differ ({q:?} != {q_other:?})")
                 }
@@ -596,6 +724,13 @@ impl TensorData {
     /// Panics if the data is not approximately equal.
     #[track_caller]
     pub fn assert_approx_eq<F: Float + Element>(&self, other: &Self, tolerance: Tolerance<F>) {
+        if self.dtype.is_complex() && other.dtype.is_complex() {
+            // Compare the real and imaginary parts as interleaved values.
+            return self
+                .complex_as_real()
+                .assert_approx_eq(&other.complex_as_real(), tolerance);
+        }
+
         let mut message = String::new();
         if self.shape != other.shape {
             message += format!(
@@ -649,6 +784,19 @@ impl TensorData {
         }
     }
 
+    /// Reinterprets complex data as real data with a trailing dimension of size 2 holding the real
+    /// and imaginary parts.
+    fn complex_as_real(&self) -> Self {
+        let mut data = self.clone();
+        data.dtype = match self.dtype {
+            DType::Complex32 => DType::F32,
+            DType::Complex64 => DType::F64,
+            _ => unreachable!(),
+        };
+        data.shape.push(2);
+        data
+    }
+
     /// Asserts each value is within a given range.
     ///
     /// # Arguments
@@ -802,6 +950,8 @@ impl core::fmt::Display for TensorData {
             DType::F32 | DType::Flex32 => format!("{:?}", self.as_slice::<f32>().unwrap()),
             DType::F16 => format!("{:?}", self.as_slice::<f16>().unwrap()),
             DType::BF16 => format!("{:?}", self.as_slice::<bf16>().unwrap()),
+            DType::E4M3 => format!("{:?}", self.as_slice::<e4m3>().unwrap()),
+            DType::E5M2 => format!("{:?}", self.as_slice::<e5m2>().unwrap()),
             DType::I64 => format!("{:?}", self.as_slice::<i64>().unwrap()),
             DType::I32 => format!("{:?}", self.as_slice::<i32>().unwrap()),
             DType::I16 => format!("{:?}", self.as_slice::<i16>().unwrap()),
@@ -811,15 +961,21 @@ impl core::fmt::Display for TensorData {
             DType::U16 => format!("{:?}", self.as_slice::<u16>().unwrap()),
             DType::U8 => format!("{:?}", self.as_slice::<u8>().unwrap()),
             DType::Bool => format!("{:?}", self.as_slice::<bool>().unwrap()),
+            DType::Complex32 => format!("{:?}", self.as_slice::<Complex32>().unwrap()),
+            DType::Complex64 => format!("{:?}", self.as_slice::<Complex64>().unwrap()),
             DType::QFloat(scheme) => match scheme {
                 QuantScheme {
-                    level: QuantLevel::Tensor | QuantLevel::Block(_),
-                    mode: QuantMode::Symmetric,
-                    q_type: QuantInputType::QInt8,
+                    q_type: QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4,
                     ..
                 } => {
                     format!("{:?} {scheme:?}", self.iter::<i8>().collect::<Vec<_>>())
                 }
+                QuantScheme {
+                    q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                    ..
+                } => {
+                    format!("{:?} {scheme:?}", self.iter::<f32>().collect::<Vec<_>>())
+                }
             },
         };
         f.write_str(fmt.as_str())
@@ -1023,7 +1179,10 @@ impl<F: Float> Tolerance<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Shape, quantization::SymmetricQuantization};
+    use crate::{
+        Shape,
+        quantization::{ScaledFloat8Quantization, SymmetricQuantization},
+    };
 
     use super::*;
     use alloc::vec;
@@ -1170,4 +1329,72 @@ mod tests {
             Tolerance::default(),
         );
     }
+
+    #[test]
+    fn should_support_complex_parts_round_trip() {
+        let real = TensorData::from([[1.0f32, -2.0], [0.5, 3.0]]);
+        let imag = TensorData::from([[0.0f32, 4.0], [-1.5, 2.0]]);
+
+        let data = TensorData::complex(real.clone(), imag.clone());
+        assert_eq!(data.dtype, DType::Complex32);
+        assert_eq!(data.shape, vec![2, 2]);
+        assert_eq!(
+            data.as_slice::<Complex32>().unwrap()[1],
+            Complex32::new(-2.0, 4.0)
+        );
+
+        let (output_real, output_imag) = data.into_complex_parts();
+        output_real.assert_eq(&real, true);
+        output_imag.assert_eq(&imag, true);
+    }
+
+    #[test]
+    fn should_convert_complex_precision() {
+        let data = TensorData::complex(
+            TensorData::from([1.0f32, -2.0]),
+            TensorData::from([3.0f32, 0.5]),
+        );
+
+        let data = data.convert::<Complex64>();
+        assert_eq!(data.dtype, DType::Complex64);
+        assert_eq!(
+            data.to_vec::<Complex64>().unwrap(),
+            vec![Complex64::new(1.0, 3.0), Complex64::new(-2.0, 0.5)]
+        );
+    }
+
+    #[test]
+    fn should_convert_fp8_with_saturation() {
+        let data = TensorData::from([1.07f32, -500.0, 0.5, 1e6]);
+
+        let e4m3_data = data.clone().convert::<e4m3>();
+        assert_eq!(e4m3_data.dtype, DType::E4M3);
+        assert_eq!(e4m3_data.bytes.len(), 4);
+        e4m3_data
+            .convert::<f32>()
+            .assert_eq(&TensorData::from([1.125f32, -448.0, 0.5, 448.0]), true);
+
+        let e5m2_data = data.convert_dtype(DType::E5M2);
+        assert_eq!(e5m2_data.dtype, DType::E5M2);
+        e5m2_data
+            .convert::<f32>()
+            .assert_eq(&TensorData::from([1.0f32, -512.0, 0.5, 57344.0]), true);
+    }
+
+    #[test]
+    fn should_support_fp8_dequantize() {
+        let data = TensorData::from([[-1.8f32, -1.0, 0.0], [0.5, 0.25, 1.8]]);
+        let strategy =
+            QuantizationStrategy::PerTensorScaledE4M3(ScaledFloat8Quantization::new(-1.8, 1.8));
+
+        let quantized = data.clone().with_quantization(strategy);
+        assert_eq!(
+            quantized.iter::<f32>().collect::<Vec<_>>(),
+            vec![-448.0, -256.0, 0.0, 128.0, 64.0, 448.0]
+        );
+
+        let output = quantized.dequantize().unwrap();
+
+        output.assert_approx_eq::<f32>(&data, Tolerance::absolute(5e-2));
+    }
 }
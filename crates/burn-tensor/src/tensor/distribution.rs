@@ -1,4 +1,8 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
 use rand::{Rng, RngCore, distr::StandardUniform};
+use rand_distr::StandardNormal;
 
 use crate::{Element, ElementConversion};
 
@@ -16,6 +20,29 @@ pub enum Distribution {
 
     /// Normal distribution with the given mean and standard deviation.
     Normal(f64, f64),
+
+    /// Normal distribution with the given mean and standard deviation, truncated to the interval
+    /// `[low, high]`.
+    TruncatedNormal(f64, f64, f64, f64),
+
+    /// Exponential distribution with the given rate.
+    Exponential(f64),
+
+    /// Gamma distribution with the given shape and scale.
+    Gamma(f64, f64),
+
+    /// Beta distribution with the given alpha and beta shape parameters.
+    Beta(f64, f64),
+
+    /// Poisson distribution with the given rate.
+    Poisson(f64),
+
+    /// Cauchy distribution with the given median and scale.
+    Cauchy(f64, f64),
+
+    /// Log-normal distribution, whose logarithm follows a normal distribution with the given mean
+    /// and standard deviation.
+    LogNormal(f64, f64),
 }
 
 /// Distribution sampler for random value of a tensor.
@@ -47,6 +74,27 @@ where
 
     /// Normal distribution.
     Normal(rand_distr::Normal<f64>),
+
+    /// Truncated normal distribution.
+    TruncatedNormal(TruncatedNormal),
+
+    /// Exponential distribution.
+    Exponential(rand_distr::Exp<f64>),
+
+    /// Gamma distribution.
+    Gamma(rand_distr::Gamma<f64>),
+
+    /// Beta distribution.
+    Beta(rand_distr::Beta<f64>),
+
+    /// Poisson distribution.
+    Poisson(rand_distr::Poisson<f64>),
+
+    /// Cauchy distribution.
+    Cauchy(rand_distr::Cauchy<f64>),
+
+    /// Log-normal distribution.
+    LogNormal(rand_distr::LogNormal<f64>),
 }
 
 impl<E, R> DistributionSampler<'_, E, R>
@@ -69,6 +117,19 @@ where
                 }
             }
             DistributionSamplerKind::Normal(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::TruncatedNormal(distribution) => {
+                self.rng.sample(distribution).elem()
+            }
+            DistributionSamplerKind::Exponential(distribution) => {
+                self.rng.sample(distribution).elem()
+            }
+            DistributionSamplerKind::Gamma(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Beta(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Poisson(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Cauchy(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::LogNormal(distribution) => {
+                self.rng.sample(distribution).elem()
+            }
         }
     }
 }
@@ -102,8 +163,124 @@ impl Distribution {
             Distribution::Normal(mean, std) => {
                 DistributionSamplerKind::Normal(rand_distr::Normal::new(mean, std).unwrap())
             }
+            Distribution::TruncatedNormal(mean, std, low, high) => {
+                DistributionSamplerKind::TruncatedNormal(TruncatedNormal::new(mean, std, low, high))
+            }
+            Distribution::Exponential(rate) => {
+                DistributionSamplerKind::Exponential(rand_distr::Exp::<f64>::new(rate).unwrap())
+            }
+            Distribution::Gamma(shape, scale) => {
+                DistributionSamplerKind::Gamma(rand_distr::Gamma::<f64>::new(shape, scale).unwrap())
+            }
+            Distribution::Beta(alpha, beta) => {
+                DistributionSamplerKind::Beta(rand_distr::Beta::<f64>::new(alpha, beta).unwrap())
+            }
+            Distribution::Poisson(rate) => {
+                DistributionSamplerKind::Poisson(rand_distr::Poisson::<f64>::new(rate).unwrap())
+            }
+            Distribution::Cauchy(median, scale) => DistributionSamplerKind::Cauchy(
+                rand_distr::Cauchy::<f64>::new(median, scale).unwrap(),
+            ),
+            Distribution::LogNormal(mean, std) => DistributionSamplerKind::LogNormal(
+                rand_distr::LogNormal::<f64>::new(mean, std).unwrap(),
+            ),
         };
 
         DistributionSampler::new(kind, rng)
     }
 }
+
+/// Normal distribution truncated to an interval, sampled by rejection.
+///
+/// The proposal depends on where the interval lies, so that the acceptance rate stays high even
+/// far in the tails:
+/// - a normal proposal when the interval contains the mean and is wide;
+/// - a uniform proposal when the interval is narrow;
+/// - an exponential proposal when the interval lies in one of the tails.
+#[derive(Debug, Clone, Copy)]
+pub struct TruncatedNormal {
+    mean: f64,
+    std: f64,
+    low: f64,
+    high: f64,
+}
+
+impl TruncatedNormal {
+    /// Creates a truncated normal distribution with the given mean and standard deviation,
+    /// truncated to `[low, high]`.
+    ///
+    /// # Panics
+    ///
+    /// If the standard deviation isn't positive or if `low` isn't lower than `high`.
+    pub fn new(mean: f64, std: f64, low: f64, high: f64) -> Self {
+        assert!(
+            std > 0.0,
+            "The standard deviation must be positive, got {std}"
+        );
+        assert!(
+            low < high,
+            "The lower bound must be lower than the upper bound, got [{low}, {high}]"
+        );
+
+        // The bounds are kept for the standard normal distribution.
+        Self {
+            mean,
+            std,
+            low: (low - mean) / std,
+            high: (high - mean) / std,
+        }
+    }
+
+    fn sample_standard<R: Rng + ?Sized>(low: f64, high: f64, rng: &mut R) -> f64 {
+        if low > 0.0 {
+            Self::sample_tail(low, high, rng)
+        } else if high < 0.0 {
+            -Self::sample_tail(-high, -low, rng)
+        } else if high - low >= 1.0 {
+            loop {
+                let z: f64 = rng.sample(StandardNormal);
+                if z >= low && z <= high {
+                    return z;
+                }
+            }
+        } else {
+            Self::sample_uniform(low, high, 0.0, rng)
+        }
+    }
+
+    /// Samples from the interval `[low, high]` with `low > 0`.
+    fn sample_tail<R: Rng + ?Sized>(low: f64, high: f64, rng: &mut R) -> f64 {
+        let alpha = (low + (low * low + 4.0).sqrt()) / 2.0;
+
+        if (high - low) * alpha < 1.0 {
+            return Self::sample_uniform(low, high, low, rng);
+        }
+
+        loop {
+            let z = low - (1.0 - rng.random::<f64>()).ln() / alpha;
+            if z <= high && rng.random::<f64>() <= (-(z - alpha) * (z - alpha) / 2.0).exp() {
+                return z;
+            }
+        }
+    }
+
+    /// Samples with a uniform proposal, where `closest` is the point of the interval closest to 0.
+    fn sample_uniform<R: Rng + ?Sized>(low: f64, high: f64, closest: f64, rng: &mut R) -> f64 {
+        loop {
+            let z = low + (high - low) * rng.random::<f64>();
+            if rng.random::<f64>() <= ((closest * closest - z * z) / 2.0).exp() {
+                return z;
+            }
+        }
+    }
+}
+
+impl rand::distr::Distribution<f64> for TruncatedNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z = Self::sample_standard(self.low, self.high, rng);
+        (self.mean + self.std * z).clamp(
+            self.mean + self.std * self.low,
+            self.mean + self.std * self.high,
+        )
+    }
+}
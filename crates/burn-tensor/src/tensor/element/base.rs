@@ -5,6 +5,8 @@ use crate::{
     cast::ToElement,
     quantization::{QuantInputType, QuantScheme},
 };
+
+use super::{Complex32, Complex64, e4m3, e5m2};
 #[cfg(feature = "cubecl")]
 use cubecl::flex32;
 use half::{bf16, f16};
@@ -307,6 +309,8 @@ pub enum DType {
     Flex32,
     F16,
     BF16,
+    E4M3,
+    E5M2,
     I64,
     I32,
     I16,
@@ -316,6 +320,8 @@ pub enum DType {
     U16,
     U8,
     Bool,
+    Complex32,
+    Complex64,
     QFloat(QuantScheme),
 }
 
@@ -329,12 +335,12 @@ impl From<cubecl::ir::Elem> for DType {
                 cubecl::ir::FloatKind::Flex32 => DType::Flex32,
                 cubecl::ir::FloatKind::F32 => DType::F32,
                 cubecl::ir::FloatKind::F64 => DType::F64,
+                cubecl::ir::FloatKind::E4M3 => DType::E4M3,
+                cubecl::ir::FloatKind::E5M2 => DType::E5M2,
                 cubecl::ir::FloatKind::TF32 => panic!("Not a valid DType for tensors."),
                 cubecl::ir::FloatKind::E2M1
                 | cubecl::ir::FloatKind::E2M3
                 | cubecl::ir::FloatKind::E3M2
-                | cubecl::ir::FloatKind::E4M3
-                | cubecl::ir::FloatKind::E5M2
                 | cubecl::ir::FloatKind::UE8M0 => {
                     unimplemented!("Not yet supported, will be used for quantization")
                 }
@@ -365,6 +371,8 @@ impl DType {
             DType::Flex32 => core::mem::size_of::<f32>(),
             DType::F16 => core::mem::size_of::<f16>(),
             DType::BF16 => core::mem::size_of::<bf16>(),
+            DType::E4M3 => core::mem::size_of::<e4m3>(),
+            DType::E5M2 => core::mem::size_of::<e5m2>(),
             DType::I64 => core::mem::size_of::<i64>(),
             DType::I32 => core::mem::size_of::<i32>(),
             DType::I16 => core::mem::size_of::<i16>(),
@@ -374,14 +382,24 @@ impl DType {
             DType::U16 => core::mem::size_of::<u16>(),
             DType::U8 => core::mem::size_of::<u8>(),
             DType::Bool => core::mem::size_of::<bool>(),
+            DType::Complex32 => core::mem::size_of::<Complex32>(),
+            DType::Complex64 => core::mem::size_of::<Complex64>(),
             DType::QFloat(scheme) => match scheme.q_type {
-                QuantInputType::QInt8 => core::mem::size_of::<i8>(),
+                // The 4-bit values occupy a full byte when unpacked
+                QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+                    core::mem::size_of::<i8>()
+                }
+                QuantInputType::E4M3 => core::mem::size_of::<e4m3>(),
+                QuantInputType::E5M2 => core::mem::size_of::<e5m2>(),
             },
         }
     }
     /// Returns true if the data type is a floating point type.
     pub fn is_float(&self) -> bool {
-        matches!(self, DType::F64 | DType::F32 | DType::F16 | DType::BF16)
+        matches!(
+            self,
+            DType::F64 | DType::F32 | DType::F16 | DType::BF16 | DType::E4M3 | DType::E5M2
+        )
     }
     /// Returns true if the data type is a signed integer type.
     pub fn is_int(&self) -> bool {
@@ -393,6 +411,11 @@ impl DType {
         matches!(self, DType::Bool)
     }
 
+    /// Returns true if the data type is a complex type.
+    pub fn is_complex(&self) -> bool {
+        matches!(self, DType::Complex32 | DType::Complex64)
+    }
+
     /// Returns the data type name.
     pub fn name(&self) -> &'static str {
         match self {
@@ -401,6 +424,8 @@ impl DType {
             DType::Flex32 => "flex32",
             DType::F16 => "f16",
             DType::BF16 => "bf16",
+            DType::E4M3 => "e4m3",
+            DType::E5M2 => "e5m2",
             DType::I64 => "i64",
             DType::I32 => "i32",
             DType::I16 => "i16",
@@ -410,6 +435,8 @@ impl DType {
             DType::U16 => "u16",
             DType::U8 => "u8",
             DType::Bool => "bool",
+            DType::Complex32 => "complex32",
+            DType::Complex64 => "complex64",
             DType::QFloat(_) => "qfloat",
         }
     }
@@ -422,6 +449,8 @@ pub enum FloatDType {
     F32,
     F16,
     BF16,
+    E4M3,
+    E5M2,
 }
 
 impl From<DType> for FloatDType {
@@ -431,6 +460,8 @@ impl From<DType> for FloatDType {
             DType::F32 => FloatDType::F32,
             DType::F16 => FloatDType::F16,
             DType::BF16 => FloatDType::BF16,
+            DType::E4M3 => FloatDType::E4M3,
+            DType::E5M2 => FloatDType::E5M2,
             _ => panic!("Expected float data type, got {value:?}"),
         }
     }
@@ -443,6 +474,8 @@ impl From<FloatDType> for DType {
             FloatDType::F32 => DType::F32,
             FloatDType::F16 => DType::F16,
             FloatDType::BF16 => DType::BF16,
+            FloatDType::E4M3 => DType::E4M3,
+            FloatDType::E5M2 => DType::E5M2,
         }
     }
 }
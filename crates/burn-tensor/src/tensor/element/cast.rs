@@ -2,6 +2,8 @@ use core::mem::size_of;
 
 use half::{bf16, f16};
 
+use super::Complex64;
+
 /// A generic trait for converting a value to a number.
 /// Adapted from [num_traits::ToPrimitive] to support [bool].
 ///
@@ -131,6 +133,16 @@ pub trait ToElement {
     fn to_bool(&self) -> bool {
         ToElement::to_bool(&self.to_u64())
     }
+
+    /// Converts the value of `self` to a `Complex64`.
+    ///
+    /// The default implementation converts through `to_f64()` with a zero
+    /// imaginary part. Complex types should override this method to keep
+    /// their imaginary part.
+    #[inline]
+    fn to_complex64(&self) -> Complex64 {
+        Complex64::new(self.to_f64(), 0.0)
+    }
 }
 
 macro_rules! impl_to_element_int_to_int {
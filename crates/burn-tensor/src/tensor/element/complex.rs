@@ -0,0 +1,175 @@
+use core::cmp::Ordering;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DType, Distribution, Element, ElementComparison, ElementConversion, ElementLimits,
+    ElementPrecision, ElementRandom, Precision, cast::ToElement,
+};
+
+/// A complex number with real and imaginary parts of type `E`.
+///
+/// Complex numbers are stored with the real part first, so a buffer of complex values has the same
+/// layout as an interleaved buffer of `[re, im]` pairs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[repr(C)]
+pub struct ComplexElem<E> {
+    /// The real part.
+    pub re: E,
+    /// The imaginary part.
+    pub im: E,
+}
+
+/// Single precision complex number.
+pub type Complex32 = ComplexElem<f32>;
+
+/// Double precision complex number.
+pub type Complex64 = ComplexElem<f64>;
+
+impl<E> ComplexElem<E> {
+    /// Creates a new complex number from its real and imaginary parts.
+    pub const fn new(re: E, im: E) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<E: Element> ComplexElem<E> {
+    /// Returns the complex conjugate.
+    pub fn conj(self) -> Self {
+        Self::new(self.re, E::from_elem(-self.im.to_f64()))
+    }
+
+    /// Returns the modulus `sqrt(re² + im²)`.
+    pub fn abs(self) -> f64 {
+        self.re.to_f64().hypot(self.im.to_f64())
+    }
+
+    /// Returns the argument `atan2(im, re)`, in `[-π, π]`.
+    pub fn arg(self) -> f64 {
+        self.im.to_f64().atan2(self.re.to_f64())
+    }
+}
+
+impl<E: Element> core::fmt::Display for ComplexElem<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.im.to_f64().is_sign_negative() {
+            write!(f, "{}-{}i", self.re, E::from_elem(-self.im.to_f64()))
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+// SAFETY: Both fields have the same type, so `repr(C)` doesn't introduce any padding.
+unsafe impl<E: bytemuck::Zeroable> bytemuck::Zeroable for ComplexElem<E> {}
+// SAFETY: Both fields have the same type, so `repr(C)` doesn't introduce any padding.
+unsafe impl<E: bytemuck::NoUninit> bytemuck::NoUninit for ComplexElem<E> {}
+
+/// The bit pattern of a complex number, used to validate casts from raw bytes.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ComplexBits<B> {
+    re: B,
+    im: B,
+}
+
+// SAFETY: Both fields have the same type, so `repr(C)` doesn't introduce any padding.
+unsafe impl<B: bytemuck::Zeroable> bytemuck::Zeroable for ComplexBits<B> {}
+// SAFETY: Any bit pattern is valid for both fields.
+unsafe impl<B: bytemuck::AnyBitPattern> bytemuck::AnyBitPattern for ComplexBits<B> {}
+
+// SAFETY: `ComplexBits<E::Bits>` has the same layout as `ComplexElem<E>`, and a complex number is
+// valid when both of its parts are.
+unsafe impl<E: bytemuck::CheckedBitPattern> bytemuck::CheckedBitPattern for ComplexElem<E> {
+    type Bits = ComplexBits<E::Bits>;
+
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        E::is_valid_bit_pattern(&bits.re) && E::is_valid_bit_pattern(&bits.im)
+    }
+}
+
+/// Conversions to real types keep the real part and discard the imaginary part.
+impl<E: Element> ToElement for ComplexElem<E> {
+    fn to_i64(&self) -> i64 {
+        self.re.to_i64()
+    }
+
+    fn to_u64(&self) -> u64 {
+        self.re.to_u64()
+    }
+
+    fn to_f16(&self) -> half::f16 {
+        self.re.to_f16()
+    }
+
+    fn to_bf16(&self) -> half::bf16 {
+        self.re.to_bf16()
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.re.to_f32()
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.re.to_f64()
+    }
+
+    fn to_bool(&self) -> bool {
+        self.re.to_bool() || self.im.to_bool()
+    }
+
+    fn to_complex64(&self) -> Complex64 {
+        Complex64::new(self.re.to_f64(), self.im.to_f64())
+    }
+}
+
+impl<E: Element> ElementConversion for ComplexElem<E> {
+    /// Converts a value to a complex number, where real values have a zero imaginary part.
+    fn from_elem<T: ToElement>(elem: T) -> Self {
+        let value = elem.to_complex64();
+        Self::new(E::from_elem(value.re), E::from_elem(value.im))
+    }
+
+    fn elem<T: Element>(self) -> T {
+        T::from_elem(self)
+    }
+}
+
+impl<E: Element> ElementPrecision for ComplexElem<E> {
+    fn precision() -> Precision {
+        E::precision()
+    }
+}
+
+impl<E: Element> ElementRandom for ComplexElem<E> {
+    /// Samples the real and imaginary parts independently.
+    fn random<R: RngCore>(distribution: Distribution, rng: &mut R) -> Self {
+        Self::new(E::random(distribution, rng), E::random(distribution, rng))
+    }
+}
+
+impl<E: Element> ElementComparison for ComplexElem<E> {
+    /// Complex numbers have no natural ordering, so they are compared lexicographically.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.re.cmp(&other.re).then(self.im.cmp(&other.im))
+    }
+}
+
+impl<E: Element> ElementLimits for ComplexElem<E> {
+    const MIN: Self = Self::new(E::MIN, E::MIN);
+    const MAX: Self = Self::new(E::MAX, E::MAX);
+}
+
+impl<E: Element> Element for ComplexElem<E> {
+    fn dtype() -> DType {
+        match E::dtype() {
+            DType::F64 => DType::Complex64,
+            DType::F32 | DType::Flex32 => DType::Complex32,
+            dtype => unimplemented!("Complex numbers with {dtype:?} parts are not supported"),
+        }
+    }
+}
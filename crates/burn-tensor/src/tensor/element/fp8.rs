@@ -0,0 +1,375 @@
+use core::cmp::Ordering;
+
+use half::{bf16, f16};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DType, Distribution, Element, ElementComparison, ElementConversion, ElementLimits,
+    ElementPrecision, ElementRandom, Precision, cast::ToElement,
+};
+
+/// The layout of an 8-bit floating point format.
+struct Float8Format {
+    /// The number of mantissa bits.
+    mantissa_bits: u32,
+    /// The exponent bias.
+    bias: i32,
+    /// The bits of the largest finite magnitude.
+    max_bits: u8,
+    /// The bits of the infinite magnitude, if the format has infinities.
+    inf_bits: Option<u8>,
+    /// The bits of the NaN magnitude.
+    nan_bits: u8,
+}
+
+/// The `E4M3` format, without infinities and with a single NaN magnitude (`S.1111.111`).
+const E4M3_FORMAT: Float8Format = Float8Format {
+    mantissa_bits: 3,
+    bias: 7,
+    max_bits: 0x7E,
+    inf_bits: None,
+    nan_bits: 0x7F,
+};
+
+/// The `E5M2` format, which follows the IEEE 754 conventions for infinities and NaNs.
+const E5M2_FORMAT: Float8Format = Float8Format {
+    mantissa_bits: 2,
+    bias: 15,
+    max_bits: 0x7B,
+    inf_bits: Some(0x7C),
+    nan_bits: 0x7F,
+};
+
+impl Float8Format {
+    /// Encodes the value with round to nearest, ties to even.
+    ///
+    /// Finite values out of range saturate to the largest finite magnitude, as well as the
+    /// infinities of formats without infinities.
+    fn encode(&self, value: f32) -> u8 {
+        let bits = value.to_bits();
+        let sign = ((bits >> 24) & 0x80) as u8;
+
+        if value.is_nan() {
+            return sign | self.nan_bits;
+        }
+        if value.is_infinite() {
+            return sign | self.inf_bits.unwrap_or(self.max_bits);
+        }
+
+        let exponent = ((bits >> 23) & 0xFF) as i32;
+        let mantissa = bits & 0x7F_FFFF;
+
+        // Zeros and f32 subnormals are far below the smallest 8-bit subnormal.
+        if exponent == 0 {
+            return sign;
+        }
+
+        let exponent = exponent - 127 + self.bias;
+        let shift = 23 - self.mantissa_bits;
+
+        let magnitude = if exponent > 0 {
+            // A mantissa rounded up to the next power of two carries into the exponent.
+            ((exponent as u32) << self.mantissa_bits) + round_shift(mantissa, shift)
+        } else {
+            // Subnormal values are scaled by the implicit leading bit, and round up to the
+            // smallest normal value when the mantissa carries.
+            let shift = shift + (1 - exponent) as u32;
+            round_shift(mantissa | 0x80_0000, shift)
+        };
+
+        if magnitude > self.max_bits as u32 {
+            sign | self.max_bits
+        } else {
+            sign | magnitude as u8
+        }
+    }
+
+    /// Decodes the bits to the exact `f32` value.
+    fn decode(&self, bits: u8) -> f32 {
+        let sign = if bits & 0x80 != 0 { -1.0 } else { 1.0 };
+        let magnitude = bits & 0x7F;
+
+        if magnitude == self.nan_bits || (self.inf_bits.is_some() && magnitude > self.max_bits) {
+            return if Some(magnitude) == self.inf_bits {
+                sign * f32::INFINITY
+            } else {
+                f32::NAN
+            };
+        }
+
+        let exponent = (magnitude >> self.mantissa_bits) as i32;
+        let mantissa = (magnitude & ((1 << self.mantissa_bits) - 1)) as u32;
+
+        let value = if exponent == 0 {
+            // Subnormal values don't have the implicit leading bit.
+            mantissa as f32 * pow2(1 - self.bias - self.mantissa_bits as i32)
+        } else {
+            f32::from_bits(
+                (((exponent - self.bias + 127) as u32) << 23)
+                    | (mantissa << (23 - self.mantissa_bits)),
+            )
+        };
+
+        sign * value
+    }
+}
+
+/// Shifts the value to the right, rounding to nearest with ties to even.
+fn round_shift(value: u32, shift: u32) -> u32 {
+    if shift >= 32 {
+        return 0;
+    }
+
+    let truncated = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+
+    if remainder > half || (remainder == half && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Returns `2^exponent` for exponents in the range of normal `f32` values.
+fn pow2(exponent: i32) -> f32 {
+    f32::from_bits(((exponent + 127) as u32) << 23)
+}
+
+macro_rules! float8 {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $format:expr, $dtype:expr
+    ) => {
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+        #[repr(transparent)]
+        pub struct $name(u8);
+
+        impl $name {
+            /// Creates a value from its bits.
+            pub const fn from_bits(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the bits of the value.
+            pub const fn to_bits(self) -> u8 {
+                self.0
+            }
+
+            /// Converts an `f32` value, rounding to the nearest representable value and
+            /// saturating to the largest finite magnitude.
+            pub fn from_f32(value: f32) -> Self {
+                Self($format.encode(value))
+            }
+
+            /// Converts an `f64` value, rounding to the nearest representable value and
+            /// saturating to the largest finite magnitude.
+            pub fn from_f64(value: f64) -> Self {
+                // Rounding through f32 is exact, since f32 has more than twice the precision.
+                Self::from_f32(value as f32)
+            }
+
+            /// Converts the value to `f32` without loss.
+            pub fn to_f32(self) -> f32 {
+                $format.decode(self.0)
+            }
+
+            /// Converts the value to `f64` without loss.
+            pub fn to_f64(self) -> f64 {
+                self.to_f32() as f64
+            }
+
+            /// Returns true if the value is NaN.
+            pub fn is_nan(self) -> bool {
+                self.to_f32().is_nan()
+            }
+        }
+
+        // SAFETY: The type is a transparent wrapper of `u8`.
+        unsafe impl bytemuck::Zeroable for $name {}
+        // SAFETY: The type is a transparent wrapper of `u8`, for which any bit pattern is valid.
+        unsafe impl bytemuck::Pod for $name {}
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl ToElement for $name {
+            #[inline]
+            fn to_i64(&self) -> i64 {
+                Self::to_f32(*self).to_i64()
+            }
+            #[inline]
+            fn to_u64(&self) -> u64 {
+                Self::to_f32(*self).to_u64()
+            }
+            #[inline]
+            fn to_f16(&self) -> f16 {
+                f16::from_f32(Self::to_f32(*self))
+            }
+            #[inline]
+            fn to_bf16(&self) -> bf16 {
+                bf16::from_f32(Self::to_f32(*self))
+            }
+            #[inline]
+            fn to_f32(&self) -> f32 {
+                Self::to_f32(*self)
+            }
+            #[inline]
+            fn to_f64(&self) -> f64 {
+                Self::to_f64(*self)
+            }
+            #[inline]
+            fn to_bool(&self) -> bool {
+                self.0 & 0x7F != 0
+            }
+        }
+
+        impl ElementConversion for $name {
+            #[inline]
+            fn from_elem<E: ToElement>(elem: E) -> Self {
+                Self::from_f32(elem.to_f32())
+            }
+            #[inline]
+            fn elem<E: Element>(self) -> E {
+                E::from_elem(self)
+            }
+        }
+
+        impl ElementPrecision for $name {
+            fn precision() -> Precision {
+                Precision::Other
+            }
+        }
+
+        impl ElementRandom for $name {
+            fn random<R: RngCore>(distribution: Distribution, rng: &mut R) -> Self {
+                let sample: f32 = distribution.sampler(rng).sample();
+                Self::from_f32(sample)
+            }
+        }
+
+        impl ElementComparison for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.to_f32().total_cmp(&other.to_f32())
+            }
+        }
+
+        impl ElementLimits for $name {
+            const MIN: Self = Self(0x80 | $format.max_bits);
+            const MAX: Self = Self($format.max_bits);
+        }
+
+        impl Element for $name {
+            fn dtype() -> DType {
+                $dtype
+            }
+        }
+    };
+}
+
+float8!(
+    /// An 8-bit floating point number with 4 exponent bits and 3 mantissa bits.
+    ///
+    /// The format doesn't have infinities, which extends the range of finite values to `±448`.
+    e4m3,
+    E4M3_FORMAT,
+    DType::E4M3
+);
+
+float8!(
+    /// An 8-bit floating point number with 5 exponent bits and 2 mantissa bits.
+    ///
+    /// The format follows the IEEE 754 conventions, with a range of finite values of `±57344`.
+    e5m2,
+    E5M2_FORMAT,
+    DType::E5M2
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_e4m3_exactly() {
+        for bits in 0..=u8::MAX {
+            let value = e4m3::from_bits(bits);
+
+            if value.is_nan() {
+                assert_eq!(bits & 0x7F, 0x7F);
+            } else {
+                assert_eq!(e4m3::from_f32(value.to_f32()).to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn should_convert_e5m2_exactly() {
+        for bits in 0..=u8::MAX {
+            let value = e5m2::from_bits(bits);
+
+            if value.is_nan() {
+                assert!(bits & 0x7F > 0x7C);
+            } else {
+                assert_eq!(e5m2::from_f32(value.to_f32()).to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn should_round_to_nearest_even() {
+        // Representable neighbors are 1.0 and 1.125 for e4m3, 1.0 and 1.25 for e5m2.
+        assert_eq!(e4m3::from_f32(1.0625).to_f32(), 1.0);
+        assert_eq!(e4m3::from_f32(1.07).to_f32(), 1.125);
+        assert_eq!(e4m3::from_f32(1.1875).to_f32(), 1.25);
+        assert_eq!(e5m2::from_f32(1.125).to_f32(), 1.0);
+        assert_eq!(e5m2::from_f32(1.375).to_f32(), 1.5);
+        assert_eq!(e4m3::from_f32(-1.07).to_f32(), -1.125);
+    }
+
+    #[test]
+    fn should_round_subnormals() {
+        // The smallest subnormal values are 2^-9 for e4m3 and 2^-16 for e5m2.
+        assert_eq!(e4m3::from_f32(0.001953125).to_bits(), 0x01);
+        assert_eq!(e4m3::from_f32(0.0009765625).to_bits(), 0x00);
+        assert_eq!(e4m3::from_f32(0.0011).to_bits(), 0x01);
+        assert_eq!(e4m3::from_f32(0.0155).to_f32(), 0.015625);
+        assert_eq!(e5m2::from_f32(2f32.powi(-16)).to_bits(), 0x01);
+        assert_eq!(e5m2::from_f32(1e-9).to_bits(), 0x00);
+        assert_eq!(e5m2::from_f32(-1e-9).to_bits(), 0x80);
+    }
+
+    #[test]
+    fn should_saturate() {
+        assert_eq!(e4m3::from_f32(448.0).to_f32(), 448.0);
+        assert_eq!(e4m3::from_f32(500.0).to_f32(), 448.0);
+        assert_eq!(e4m3::from_f32(-1e10).to_f32(), -448.0);
+        assert_eq!(e4m3::from_f32(f32::INFINITY).to_f32(), 448.0);
+        assert_eq!(e5m2::from_f32(1e6).to_f32(), 57344.0);
+        assert_eq!(
+            e5m2::from_f32(f32::NEG_INFINITY).to_f32(),
+            f32::NEG_INFINITY
+        );
+        assert!(e4m3::from_f32(f32::NAN).is_nan());
+        assert!(e5m2::from_f32(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn should_have_limits() {
+        assert_eq!(e4m3::MAX.to_f32(), 448.0);
+        assert_eq!(e4m3::MIN.to_f32(), -448.0);
+        assert_eq!(e5m2::MAX.to_f32(), 57344.0);
+        assert_eq!(e5m2::MIN.to_f32(), -57344.0);
+    }
+}
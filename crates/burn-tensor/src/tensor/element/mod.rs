@@ -1,6 +1,10 @@
 mod base;
+mod complex;
+mod fp8;
 
 /// Tensor element casting.
 pub mod cast;
 
 pub use base::*;
+pub use complex::*;
+pub use fp8::*;
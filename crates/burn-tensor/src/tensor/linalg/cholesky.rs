@@ -0,0 +1,24 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+/// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.cholesky.html
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`. Only the lower triangle is read.
+///
+/// # Returns
+///
+/// The lower triangular factor `L` of shape `[..., n, n]`, such that `A = L Lᵀ`. The factor
+/// contains `NaN` values when a matrix isn't positive-definite.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Cholesky", &tensor.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::cholesky(
+        tensor.into_primitive().tensor(),
+    )))
+}
@@ -0,0 +1,52 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+/// Computes the determinant of a batch of square matrices.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.det.html
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// The determinants of shape `[..., 1, 1]`.
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Det", &tensor.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::det(
+        tensor.into_primitive().tensor(),
+    )))
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of a
+/// batch of square matrices.
+///
+/// This is more numerically stable than [det] for large matrices, whose determinant may overflow
+/// or underflow.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.slogdet.html
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tuple `(sign, logabsdet)` of tensors of shape `[..., 1, 1]`, such that
+/// `det = sign * exp(logabsdet)`. Singular matrices have a sign of zero and a `logabsdet` of
+/// negative infinity. Only `logabsdet` is differentiable.
+pub fn slogdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_square::<D>("Slogdet", &tensor.shape()));
+
+    let (sign, logabsdet) = B::slogdet(tensor.into_primitive().tensor());
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(sign)),
+        Tensor::from_primitive(TensorPrimitive::Float(logabsdet)),
+    )
+}
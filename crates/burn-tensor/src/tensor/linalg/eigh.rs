@@ -0,0 +1,33 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+/// Computes the eigendecomposition of a batch of symmetric matrices.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.eigh.html
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`. Only the lower triangle is read.
+///
+/// # Returns
+///
+/// A tuple `(L, V)`, where `L` of shape `[..., 1, n]` holds the eigenvalues in ascending order
+/// and `V` of shape `[..., n, n]` holds the orthonormal eigenvectors as columns, such that
+/// `A = V diag(L) Vᵀ`, which can be computed with `v.clone().mul(l).matmul(v.transpose())`.
+///
+/// # Notes
+///
+/// The eigenvectors are only unique up to a sign, and the gradient is undefined when
+/// eigenvalues are repeated.
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_square::<D>("Eigh", &tensor.shape()));
+
+    let (values, vectors) = B::eigh(tensor.into_primitive().tensor());
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(values)),
+        Tensor::from_primitive(TensorPrimitive::Float(vectors)),
+    )
+}
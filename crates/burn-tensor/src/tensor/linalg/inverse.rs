@@ -0,0 +1,28 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+/// Computes the inverse of a batch of square matrices.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.inv.html
+///
+/// # Arguments
+///
+/// * `tensor` - The invertible matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// The inverse matrices of shape `[..., n, n]`.
+///
+/// # Notes
+///
+/// Prefer [solve](super::solve) to multiplying by the inverse, which is faster and more
+/// numerically stable.
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Inverse", &tensor.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::inverse(
+        tensor.into_primitive().tensor(),
+    )))
+}
@@ -0,0 +1,43 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, check};
+
+use super::{qr, solve_triangular};
+
+/// Computes the least squares solution of a batch of systems of linear equations `A X = B`.
+///
+/// For overdetermined systems (`m >= n`), the solution minimizes `‖A X - B‖`. For
+/// underdetermined systems (`m < n`), the solution is the one with the minimum norm. The solution
+/// is computed with a QR decomposition, so `a` must have full rank.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.lstsq.html
+///
+/// # Arguments
+///
+/// * `a` - The full rank matrices of shape `[..., m, n]`.
+/// * `b` - The right-hand sides of shape `[..., m, r]`, with the same batch dimensions as `a`.
+///
+/// # Returns
+///
+/// The solutions `X` of shape `[..., n, r]`.
+pub fn lstsq<B: Backend, const D: usize>(a: Tensor<B, D>, b: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_system::<D>(
+        "Lstsq",
+        &a.shape(),
+        &b.shape(),
+        false
+    ));
+
+    let [m, n] = [a.dims()[D - 2], a.dims()[D - 1]];
+
+    if m >= n {
+        // A = QR, so X = R⁻¹ Qᵀ B.
+        let (q, r) = qr(a);
+        solve_triangular(r, q.transpose().matmul(b), true)
+    } else {
+        // Aᵀ = QR, so the minimum norm solution is X = Q R⁻ᵀ B.
+        let (q, r) = qr(a.transpose());
+        q.matmul(solve_triangular(r.transpose(), b, false))
+    }
+}
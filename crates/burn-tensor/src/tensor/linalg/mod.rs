@@ -1,5 +1,21 @@
+mod cholesky;
 mod cosine_similarity;
+mod det;
+mod eigh;
+mod inverse;
+mod lstsq;
+mod qr;
+mod solve;
+mod svd;
 mod vector_norm;
 
+pub use cholesky::*;
 pub use cosine_similarity::*;
+pub use det::*;
+pub use eigh::*;
+pub use inverse::*;
+pub use lstsq::*;
+pub use qr::*;
+pub use solve::*;
+pub use svd::*;
 pub use vector_norm::*;
@@ -0,0 +1,27 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+/// Computes the reduced QR decomposition of a batch of matrices.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.qr.html
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(Q, R)`, where `Q` of shape `[..., m, k]` has orthonormal columns and `R` of shape
+/// `[..., k, n]` is upper triangular with a non-negative diagonal, with `k = min(m, n)`.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("QR"));
+
+    let (q, r) = B::qr(tensor.into_primitive().tensor());
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(q)),
+        Tensor::from_primitive(TensorPrimitive::Float(r)),
+    )
+}
@@ -0,0 +1,64 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+/// Solves a batch of square systems of linear equations `A X = B`.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.solve.html
+///
+/// # Arguments
+///
+/// * `a` - The invertible matrices of shape `[..., n, n]`.
+/// * `b` - The right-hand sides of shape `[..., n, r]`, with the same batch dimensions as `a`.
+///
+/// # Returns
+///
+/// The solutions `X` of shape `[..., n, r]`.
+pub fn solve<B: Backend, const D: usize>(a: Tensor<B, D>, b: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_system::<D>(
+        "Solve",
+        &a.shape(),
+        &b.shape(),
+        true
+    ));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::solve(
+        a.into_primitive().tensor(),
+        b.into_primitive().tensor(),
+    )))
+}
+
+/// Solves a batch of triangular systems of linear equations `A X = B`.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.solve_triangular.html
+///
+/// # Arguments
+///
+/// * `a` - The triangular matrices of shape `[..., n, n]`.
+/// * `b` - The right-hand sides of shape `[..., n, r]`, with the same batch dimensions as `a`.
+/// * `upper` - Whether `a` is upper triangular, otherwise it is lower triangular. Only the
+///   corresponding triangle of `a` is read.
+///
+/// # Returns
+///
+/// The solutions `X` of shape `[..., n, r]`.
+pub fn solve_triangular<B: Backend, const D: usize>(
+    a: Tensor<B, D>,
+    b: Tensor<B, D>,
+    upper: bool,
+) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_system::<D>(
+        "SolveTriangular",
+        &a.shape(),
+        &b.shape(),
+        true
+    ));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::solve_triangular(
+        a.into_primitive().tensor(),
+        b.into_primitive().tensor(),
+        upper,
+    )))
+}
@@ -0,0 +1,40 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+/// Computes the reduced singular value decomposition of a batch of matrices.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.linalg.svd.html
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(U, S, Vh)` with `k = min(m, n)`, where:
+/// - `U` of shape `[..., m, k]` holds the left singular vectors as columns.
+/// - `S` of shape `[..., 1, k]` holds the singular values in descending order.
+/// - `Vh` of shape `[..., k, n]` holds the right singular vectors as rows.
+///
+/// The singular values keep the matrix dimensions so the input can be reconstructed with
+/// `u.mul(s).matmul(vh)`.
+///
+/// # Notes
+///
+/// The singular vectors are only unique up to a sign, and the gradient is undefined when
+/// singular values are repeated.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("SVD"));
+
+    let (u, s, vh) = B::svd(tensor.into_primitive().tensor());
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(u)),
+        Tensor::from_primitive(TensorPrimitive::Float(s)),
+        Tensor::from_primitive(TensorPrimitive::Float(vh)),
+    )
+}
@@ -1,5 +1,7 @@
 pub(crate) mod stats;
 
+pub use stats::QuantileInterpolation;
+
 mod api;
 mod bytes;
 mod data;
@@ -46,6 +48,9 @@ pub mod ops;
 /// Tensor quantization module.
 pub mod quantization;
 
+/// The signal processing module.
+pub mod signal;
+
 #[cfg(feature = "std")]
 pub use report::*;
 
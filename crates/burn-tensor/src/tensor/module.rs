@@ -1,9 +1,15 @@
+use alloc::vec;
+
 use crate::{
     Int, Tensor, TensorPrimitive,
     backend::Backend,
     check,
     check::TensorCheck,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
+    ops::{
+        ConvOptions, ConvTransposeOptions, GridSampleMode, GridSampleOptions, InterpolateOptions,
+        QuantizedTensor, UnfoldOptions,
+    },
+    quantization::{QuantScheme, QuantizationParameters},
 };
 
 use super::ops::DeformConvOptions;
@@ -358,6 +364,176 @@ where
     )))
 }
 
+/// Samples the input at the locations of a [2D grid](crate::ops::ModuleOps::grid_sample).
+///
+/// The grid holds `(x, y)` coordinates normalized in `[-1, 1]`, where `x` indexes the width and
+/// `y` the height of the input.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, height, width]`,
+/// grid: `[batch_size, height_out, width_out, 2]`,
+/// output: `[batch_size, channels, height_out, width_out]`.
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grid_sample(
+        "grid_sample_2d",
+        x.dims(),
+        grid.dims(),
+        options.mode == GridSampleMode::Bicubic,
+    ));
+    Tensor::new(TensorPrimitive::Float(B::grid_sample(
+        x.primitive.tensor(),
+        grid.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Samples the input at the locations of a [3D grid](crate::ops::ModuleOps::grid_sample).
+///
+/// The grid holds `(x, y, z)` coordinates normalized in `[-1, 1]`, where `x` indexes the width,
+/// `y` the height and `z` the depth of the input. Bicubic sampling isn't supported.
+///
+/// # Shapes
+///
+/// x: `[batch_size, channels, depth, height, width]`,
+/// grid: `[batch_size, depth_out, height_out, width_out, 3]`,
+/// output: `[batch_size, channels, depth_out, height_out, width_out]`.
+pub fn grid_sample_3d<B>(
+    x: Tensor<B, 5>,
+    grid: Tensor<B, 5>,
+    options: GridSampleOptions,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grid_sample(
+        "grid_sample_3d",
+        x.dims(),
+        grid.dims(),
+        options.mode == GridSampleMode::Bicubic,
+    ));
+    Tensor::new(TensorPrimitive::Float(B::grid_sample(
+        x.primitive.tensor(),
+        grid.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Generates the 2D sampling grid of a batch of affine transformations, to be used with
+/// [grid_sample_2d].
+///
+/// # Arguments
+///
+/// * `theta` - The affine matrices, `[batch_size, 2, 3]`.
+/// * `size` - The size of the output image, `[batch_size, channels, height, width]`.
+/// * `align_corners` - Must match the option used to sample the grid.
+///
+/// # Returns
+///
+/// The grid, `[batch_size, height, width, 2]`.
+pub fn affine_grid_2d<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::affine_grid(
+        "affine_grid_2d",
+        theta.dims(),
+        size
+    ));
+    let [batch_size, _, height, width] = size;
+    let device = theta.device();
+
+    let xs = affine_grid_steps::<B>(width, align_corners, &device)
+        .reshape([1, width, 1])
+        .expand([height, width, 1]);
+    let ys = affine_grid_steps::<B>(height, align_corners, &device)
+        .reshape([height, 1, 1])
+        .expand([height, width, 1]);
+    let ones = Tensor::ones([height, width, 1], &device);
+
+    Tensor::cat(vec![xs, ys, ones], 2)
+        .reshape([1, height * width, 3])
+        .expand([batch_size, height * width, 3])
+        .matmul(theta.swap_dims(1, 2))
+        .reshape([batch_size, height, width, 2])
+}
+
+/// Generates the 3D sampling grid of a batch of affine transformations, to be used with
+/// [grid_sample_3d].
+///
+/// # Arguments
+///
+/// * `theta` - The affine matrices, `[batch_size, 3, 4]`.
+/// * `size` - The size of the output volume, `[batch_size, channels, depth, height, width]`.
+/// * `align_corners` - Must match the option used to sample the grid.
+///
+/// # Returns
+///
+/// The grid, `[batch_size, depth, height, width, 3]`.
+pub fn affine_grid_3d<B>(theta: Tensor<B, 3>, size: [usize; 5], align_corners: bool) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    check!(TensorCheck::affine_grid(
+        "affine_grid_3d",
+        theta.dims(),
+        size
+    ));
+    let [batch_size, _, depth, height, width] = size;
+    let device = theta.device();
+    let shape = [depth, height, width, 1];
+
+    let xs = affine_grid_steps::<B>(width, align_corners, &device)
+        .reshape([1, 1, width, 1])
+        .expand(shape);
+    let ys = affine_grid_steps::<B>(height, align_corners, &device)
+        .reshape([1, height, 1, 1])
+        .expand(shape);
+    let zs = affine_grid_steps::<B>(depth, align_corners, &device)
+        .reshape([depth, 1, 1, 1])
+        .expand(shape);
+    let ones = Tensor::ones(shape, &device);
+    let num_points = depth * height * width;
+
+    Tensor::cat(vec![xs, ys, zs, ones], 3)
+        .reshape([1, num_points, 4])
+        .expand([batch_size, num_points, 4])
+        .matmul(theta.swap_dims(1, 2))
+        .reshape([batch_size, depth, height, width, 3])
+}
+
+/// The normalized coordinates of the elements along a dimension of an affine grid.
+fn affine_grid_steps<B: Backend>(
+    size: usize,
+    align_corners: bool,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    if size <= 1 {
+        return Tensor::zeros([size], device);
+    }
+
+    let mut step = 2.0 / (size as f64 - 1.0);
+    let mut start = -1.0;
+    if !align_corners {
+        // The extrema are the corners of the corner elements instead of their centers.
+        let scale = (size as f64 - 1.0) / size as f64;
+        step *= scale;
+        start *= scale;
+    }
+
+    Tensor::<B, 1, Int>::arange(0..size as i64, device)
+        .float()
+        .mul_scalar(step)
+        .add_scalar(start)
+}
+
 /// Applies a [linear transformation](crate::ops::ModuleOps::linear) to the input tensor using the given weight and bias.
 ///
 /// ```math
@@ -394,3 +570,63 @@ pub fn linear<B: Backend, const D: usize>(
         bias.map(|b| b.primitive.tensor()),
     )))
 }
+
+/// Applies a [linear](linear) transformation to a quantized input with quantized weights, where
+/// the output is requantized with the given static quantization parameters.
+///
+/// # Panics
+///
+/// If the input or the weight is not quantized.
+pub fn quantized_linear<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    weight: Tensor<B, 2>,
+    bias: Option<Tensor<B, 1>>,
+    scheme: &QuantScheme,
+    qparams: QuantizationParameters<B>,
+) -> Tensor<B, D> {
+    check!(TensorCheck::linear("linear", input.dims(), weight.dims()));
+    Tensor::new(TensorPrimitive::QFloat(B::q_linear(
+        expect_quantized(input.primitive, "input"),
+        expect_quantized(weight.primitive, "weight"),
+        bias.map(|b| b.primitive.tensor()),
+        scheme,
+        qparams.into(),
+    )))
+}
+
+/// Applies a [2D convolution](conv2d) to a quantized input with quantized weights, where the output
+/// is requantized with the given static quantization parameters.
+///
+/// # Panics
+///
+/// If the input or the weight is not quantized.
+pub fn quantized_conv2d<B: Backend>(
+    x: Tensor<B, 4>,
+    weight: Tensor<B, 4>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<2>,
+    scheme: &QuantScheme,
+    qparams: QuantizationParameters<B>,
+) -> Tensor<B, 4> {
+    check!(TensorCheck::conv(
+        "conv2d",
+        x.dims(),
+        weight.dims(),
+        options.groups,
+    ));
+    Tensor::new(TensorPrimitive::QFloat(B::q_conv2d(
+        expect_quantized(x.primitive, "input"),
+        expect_quantized(weight.primitive, "weight"),
+        bias.map(|b| b.primitive.tensor()),
+        options,
+        scheme,
+        qparams.into(),
+    )))
+}
+
+fn expect_quantized<B: Backend>(tensor: TensorPrimitive<B>, name: &str) -> QuantizedTensor<B> {
+    match tensor {
+        TensorPrimitive::QFloat(tensor) => tensor,
+        TensorPrimitive::Float(_) => panic!("Expected a quantized {name} tensor"),
+    }
+}
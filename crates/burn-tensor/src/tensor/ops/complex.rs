@@ -0,0 +1,101 @@
+use crate::{
+    backend::Backend,
+    ops::{ComplexTensor, FloatTensor},
+};
+
+/// Complex tensor operations.
+///
+/// Complex tensors are represented by two float tensors of the same shape holding the real and
+/// imaginary parts, so the default implementations are written with float tensor operations.
+/// Backends can override them with fused kernels.
+///
+/// # Gradients
+///
+/// Since the parts are float tensors, gradients follow from the real and imaginary parts as
+/// independent variables. With Wirtinger calculus, the gradient of a real loss `L` with respect
+/// to `z = x + iy` is reported as `∂L/∂x + i ∂L/∂y = 2 ∂L/∂z̄`, the conjugate Wirtinger
+/// derivative scaled by two, which is the direction of steepest ascent. This is the same
+/// convention as PyTorch.
+pub trait ComplexOps<B: Backend> {
+    /// Adds two complex tensors.
+    fn complex_add(lhs: ComplexTensor<B>, rhs: ComplexTensor<B>) -> ComplexTensor<B> {
+        ComplexTensor::new(
+            B::float_add(lhs.real, rhs.real),
+            B::float_add(lhs.imag, rhs.imag),
+        )
+    }
+
+    /// Subtracts two complex tensors.
+    fn complex_sub(lhs: ComplexTensor<B>, rhs: ComplexTensor<B>) -> ComplexTensor<B> {
+        ComplexTensor::new(
+            B::float_sub(lhs.real, rhs.real),
+            B::float_sub(lhs.imag, rhs.imag),
+        )
+    }
+
+    /// Multiplies two complex tensors elementwise.
+    ///
+    /// `(a + bi)(c + di) = (ac - bd) + (ad + bc)i`
+    fn complex_mul(lhs: ComplexTensor<B>, rhs: ComplexTensor<B>) -> ComplexTensor<B> {
+        let ac = B::float_mul(lhs.real.clone(), rhs.real.clone());
+        let bd = B::float_mul(lhs.imag.clone(), rhs.imag.clone());
+        let ad = B::float_mul(lhs.real, rhs.imag);
+        let bc = B::float_mul(lhs.imag, rhs.real);
+
+        ComplexTensor::new(B::float_sub(ac, bd), B::float_add(ad, bc))
+    }
+
+    /// Divides two complex tensors elementwise.
+    ///
+    /// `(a + bi) / (c + di) = ((ac + bd) + (bc - ad)i) / (c² + d²)`
+    fn complex_div(lhs: ComplexTensor<B>, rhs: ComplexTensor<B>) -> ComplexTensor<B> {
+        let denominator = B::float_add(
+            B::float_mul(rhs.real.clone(), rhs.real.clone()),
+            B::float_mul(rhs.imag.clone(), rhs.imag.clone()),
+        );
+        let ac = B::float_mul(lhs.real.clone(), rhs.real.clone());
+        let bd = B::float_mul(lhs.imag.clone(), rhs.imag.clone());
+        let bc = B::float_mul(lhs.imag, rhs.real);
+        let ad = B::float_mul(lhs.real, rhs.imag);
+
+        ComplexTensor::new(
+            B::float_div(B::float_add(ac, bd), denominator.clone()),
+            B::float_div(B::float_sub(bc, ad), denominator),
+        )
+    }
+
+    /// Negates a complex tensor.
+    fn complex_neg(tensor: ComplexTensor<B>) -> ComplexTensor<B> {
+        ComplexTensor::new(B::float_neg(tensor.real), B::float_neg(tensor.imag))
+    }
+
+    /// Returns the complex conjugate `a - bi` of each element `a + bi`.
+    fn complex_conj(tensor: ComplexTensor<B>) -> ComplexTensor<B> {
+        ComplexTensor::new(tensor.real, B::float_neg(tensor.imag))
+    }
+
+    /// Returns the modulus `sqrt(a² + b²)` of each element `a + bi`.
+    fn complex_abs(tensor: ComplexTensor<B>) -> FloatTensor<B> {
+        B::float_sqrt(B::float_add(
+            B::float_mul(tensor.real.clone(), tensor.real),
+            B::float_mul(tensor.imag.clone(), tensor.imag),
+        ))
+    }
+
+    /// Returns the argument `atan2(b, a)` of each element `a + bi`, in `[-π, π]`.
+    fn complex_angle(tensor: ComplexTensor<B>) -> FloatTensor<B> {
+        B::float_atan2(tensor.imag, tensor.real)
+    }
+
+    /// Computes the matrix product of two complex tensors.
+    ///
+    /// `(A + Bi)(C + Di) = (AC - BD) + (AD + BC)i`
+    fn complex_matmul(lhs: ComplexTensor<B>, rhs: ComplexTensor<B>) -> ComplexTensor<B> {
+        let ac = B::float_matmul(lhs.real.clone(), rhs.real.clone());
+        let bd = B::float_matmul(lhs.imag.clone(), rhs.imag.clone());
+        let ad = B::float_matmul(lhs.real, rhs.imag);
+        let bc = B::float_matmul(lhs.imag, rhs.real);
+
+        ComplexTensor::new(B::float_sub(ac, bd), B::float_add(ad, bc))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::backend::Backend;
+
+use super::{FloatTensor, modules::fft};
+
+#[doc(hidden)]
+pub use super::modules::fft::fft_in_place;
+
+/// Fast Fourier transform operations.
+///
+/// Complex tensors are represented by two float tensors of the same shape holding the real and
+/// imaginary parts.
+///
+/// The default implementation reads the tensors on the host, so backends are encouraged to
+/// override it for better performance.
+pub trait FftOps<B: Backend> {
+    /// Computes the unnormalized discrete Fourier transform of complex signals along a dimension.
+    ///
+    /// `X[k] = sum_n x[n] exp(-2πi kn / N)`, or `exp(2πi kn / N)` for the inverse transform.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the signals.
+    /// * `imag` - The imaginary part of the signals, with the same shape as `real`.
+    /// * `dim` - The dimension along which the transform is computed.
+    /// * `inverse` - Whether to compute the inverse transform. No normalization is applied in
+    ///   either direction.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the transformed signals, with the same shape as the inputs.
+    fn fft(
+        real: FloatTensor<B>,
+        imag: FloatTensor<B>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<B>, FloatTensor<B>) {
+        fft::fft::<B>(real, imag, dim, inverse)
+    }
+}
@@ -1,4 +1,6 @@
 use super::cat::cat_with_slice_assign;
+use super::cumulative::{cumulative_extremum_with_slice, cumulative_with_slice};
+use super::gather_nd::{gather_nd, scatter_nd};
 use super::repeat_dim::repeat_with_slice_assign;
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::{Distribution, ElementConversion, Int, TensorData, backend::Backend, tensor::Shape};
@@ -201,6 +203,43 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B>,
     ) -> IntTensor<B>;
 
+    /// Gather the slices of the tensor addressed by the index tuples along the last dimension
+    /// of the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to gather from.
+    /// * `indices` - The index tuples, with shape `[..., k]` where `k` is at most the tensor rank.
+    ///
+    /// # Returns
+    ///
+    /// The gathered slices, with shape `indices.shape[..-1] ++ tensor.shape[k..]`.
+    fn int_gather_nd(tensor: IntTensor<B>, indices: IntTensor<B>) -> IntTensor<B> {
+        gather_nd::<B, Int>(tensor, indices)
+    }
+
+    /// Update the slices of the tensor addressed by the index tuples along the last dimension
+    /// of the indices with the given values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to update.
+    /// * `indices` - The index tuples, with shape `[..., k]` where `k` is at most the tensor rank.
+    /// * `values` - The values, with shape `indices.shape[..-1] ++ tensor.shape[k..]`.
+    /// * `accumulate` - Whether the values are summed to the tensor instead of replacing it.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the updated slices.
+    fn int_scatter_nd(
+        tensor: IntTensor<B>,
+        indices: IntTensor<B>,
+        values: IntTensor<B>,
+        accumulate: bool,
+    ) -> IntTensor<B> {
+        scatter_nd::<B, Int>(tensor, indices, values, accumulate)
+    }
+
     /// Repeats the tensor along the given dimension the given number of times.
     ///
     /// # Arguments
@@ -699,6 +738,66 @@ pub trait IntTensorOps<B: Backend> {
     /// The product of all elements in the tensor along the dimension.
     fn int_prod_dim(tensor: IntTensor<B>, dim: usize) -> IntTensor<B>;
 
+    /// Computes the cumulative sum of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the sum of all elements
+    /// up to and including its position along `dim`.
+    fn int_cumsum(tensor: IntTensor<B>, dim: usize) -> IntTensor<B> {
+        cumulative_with_slice::<B, Int>(tensor, dim, B::int_add)
+    }
+
+    /// Computes the cumulative product of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the product of all elements
+    /// up to and including its position along `dim`.
+    fn int_cumprod(tensor: IntTensor<B>, dim: usize) -> IntTensor<B> {
+        cumulative_with_slice::<B, Int>(tensor, dim, B::int_mul)
+    }
+
+    /// Computes the cumulative maximum of the elements along a dimension and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running maximum of `tensor` along `dim` and the indices of the maximum
+    /// elements. When the maximum is found multiple times, the index of the last occurrence is returned.
+    fn int_cummax(tensor: IntTensor<B>, dim: usize) -> (IntTensor<B>, IntTensor<B>) {
+        cumulative_extremum_with_slice::<B, Int>(tensor, dim, true)
+    }
+
+    /// Computes the cumulative minimum of the elements along a dimension and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running minimum of `tensor` along `dim` and the indices of the minimum
+    /// elements. When the minimum is found multiple times, the index of the last occurrence is returned.
+    fn int_cummin(tensor: IntTensor<B>, dim: usize) -> (IntTensor<B>, IntTensor<B>) {
+        cumulative_extremum_with_slice::<B, Int>(tensor, dim, false)
+    }
+
     /// Computes the mean of all elements in the tensor.
     ///
     /// # Arguments
@@ -0,0 +1,139 @@
+use crate::backend::Backend;
+
+use super::{FloatTensor, modules::linalg};
+
+/// Linear algebra operations.
+///
+/// All operations are batched over the leading dimensions of their inputs, and work on the
+/// matrices formed by the last two dimensions.
+///
+/// The default implementations read the tensors on the host, so backends are encouraged to
+/// override them for better performance.
+pub trait LinalgOps<B: Backend> {
+    /// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`. Only the lower triangle is read.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular factor `L` of shape `[..., n, n]`, such that `A = L Lᵀ`.
+    fn cholesky(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        linalg::cholesky::<B>(tensor)
+    }
+
+    /// Computes the reduced QR decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The orthonormal factor `Q` of shape `[..., m, k]` and the upper triangular factor `R` of
+    /// shape `[..., k, n]` with a non-negative diagonal, where `k = min(m, n)`.
+    fn qr(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>) {
+        linalg::qr::<B>(tensor)
+    }
+
+    /// Computes the reduced singular value decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The left singular vectors `U` of shape `[..., m, k]`, the singular values `S` in
+    /// descending order of shape `[..., 1, k]` and the right singular vectors `Vh` of shape
+    /// `[..., k, n]`, where `k = min(m, n)`.
+    fn svd(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>) {
+        linalg::svd::<B>(tensor)
+    }
+
+    /// Computes the eigendecomposition of symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`. Only the lower triangle is read.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues in ascending order of shape `[..., 1, n]` and the orthonormal
+    /// eigenvectors as the columns of a tensor of shape `[..., n, n]`.
+    fn eigh(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>) {
+        linalg::eigh::<B>(tensor)
+    }
+
+    /// Solves triangular systems of linear equations `A X = B`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The triangular matrices of shape `[..., n, n]`.
+    /// * `b` - The right-hand sides of shape `[..., n, r]`.
+    /// * `upper` - Whether `A` is upper triangular, otherwise it is lower triangular. Only the
+    ///   corresponding triangle is read.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X` of shape `[..., n, r]`.
+    fn solve_triangular(a: FloatTensor<B>, b: FloatTensor<B>, upper: bool) -> FloatTensor<B> {
+        linalg::solve_triangular::<B>(a, b, upper)
+    }
+
+    /// Solves square systems of linear equations `A X = B`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The invertible matrices of shape `[..., n, n]`.
+    /// * `b` - The right-hand sides of shape `[..., n, r]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X` of shape `[..., n, r]`.
+    fn solve(a: FloatTensor<B>, b: FloatTensor<B>) -> FloatTensor<B> {
+        linalg::solve::<B>(a, b)
+    }
+
+    /// Computes the inverse of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The invertible matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The inverse matrices of shape `[..., n, n]`.
+    fn inverse(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        linalg::inverse::<B>(tensor)
+    }
+
+    /// Computes the determinant of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The determinants of shape `[..., 1, 1]`.
+    fn det(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        linalg::det::<B>(tensor)
+    }
+
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant of
+    /// square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The signs and the log absolute determinants, both of shape `[..., 1, 1]`. Singular
+    /// matrices have a sign of zero and a log absolute determinant of negative infinity.
+    fn slogdet(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>) {
+        linalg::slogdet::<B>(tensor)
+    }
+}
@@ -2,7 +2,10 @@ mod activation;
 mod alias;
 mod binary;
 mod bool_tensor;
+mod complex;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod modules;
 mod qtensor;
 mod tensor;
@@ -12,7 +15,10 @@ pub use activation::*;
 pub use alias::*;
 pub use binary::*;
 pub use bool_tensor::*;
+pub use complex::*;
+pub use fft::*;
 pub use int_tensor::*;
+pub use linalg::*;
 pub use modules::*;
 pub use qtensor::*;
 pub use tensor::*;
@@ -1,7 +1,7 @@
 use alloc::vec;
 use core::num::NonZeroUsize;
 
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{conv, grid_sample, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     Shape, TensorMetadata,
     backend::Backend,
@@ -258,6 +258,66 @@ pub struct InterpolateBackward<B: Backend> {
     pub x_grad: FloatTensor<B>,
 }
 
+/// Algorithm used to sample the input at the grid locations.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum GridSampleMode {
+    /// Value of the nearest input element.
+    Nearest,
+
+    /// Bilinear interpolation, or trilinear for volumetric inputs.
+    Bilinear,
+
+    /// Bicubic interpolation, only supported for 2D inputs.
+    Bicubic,
+}
+
+/// How the grid locations outside of the input are sampled.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum GridSamplePaddingMode {
+    /// Use zeros for the out of bound locations.
+    Zeros,
+
+    /// Use the border values for the out of bound locations.
+    Border,
+
+    /// Reflect the out of bound locations at the border until they are in bound.
+    Reflection,
+}
+
+/// Grid sampling options.
+#[derive(new, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct GridSampleOptions {
+    /// Algorithm used to sample the input.
+    pub mode: GridSampleMode,
+
+    /// How the locations outside of the input are sampled.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If true, the extrema `-1` and `1` refer to the center of the corner elements of the
+    /// input. Otherwise, they refer to the corners of the corner elements.
+    pub align_corners: bool,
+}
+
+impl Default for GridSampleOptions {
+    fn default() -> Self {
+        Self {
+            mode: GridSampleMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Zeros,
+            align_corners: false,
+        }
+    }
+}
+
+/// Gradient computed during the backward pass for each tensor used by [grid_sample](ModuleOps::grid_sample).
+#[derive(new)]
+pub struct GridSampleBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B>,
+
+    /// Grid gradient.
+    pub grid_grad: FloatTensor<B>,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -766,6 +826,37 @@ pub trait ModuleOps<B: Backend> {
         options: InterpolateOptions,
     ) -> FloatTensor<B>;
 
+    /// Samples the input at the locations of the grid.
+    ///
+    /// The grid holds normalized coordinates, where `-1` and `1` are the extrema of the input
+    /// spatial dimensions. The last grid dimension holds the coordinates in the reverse order of
+    /// the spatial dimensions, i.e. `(x, y)` for `[height, width]` inputs.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]` or `[batch_size, channels, depth, height, width]`,
+    /// grid: `[batch_size, height_out, width_out, 2]` or
+    /// `[batch_size, depth_out, height_out, width_out, 3]`,
+    /// output: `[batch_size, channels, height_out, width_out]` or
+    /// `[batch_size, channels, depth_out, height_out, width_out]`.
+    fn grid_sample(
+        x: FloatTensor<B>,
+        grid: FloatTensor<B>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B> {
+        grid_sample::grid_sample::<B>(x, grid, options)
+    }
+
+    /// Backward pass for the [grid_sample](ModuleOps::grid_sample) operation.
+    fn grid_sample_backward(
+        x: FloatTensor<B>,
+        grid: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        options: GridSampleOptions,
+    ) -> GridSampleBackward<B> {
+        grid_sample::grid_sample_backward::<B>(x, grid, output_grad, options)
+    }
+
     /// Applies a linear transformation to the input tensor using the given weight and bias.
     ///
     /// ```math
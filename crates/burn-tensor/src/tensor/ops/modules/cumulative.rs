@@ -0,0 +1,87 @@
+use crate::{
+    BasicOps, Element, ElementConversion, Numeric, TensorKind, TensorMetadata, backend::Backend,
+    ops::IntTensor,
+};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+fn slice_ranges(dims: &[usize], dim: usize, index: usize) -> Vec<Range<usize>> {
+    let mut ranges = dims.iter().map(|d| 0..*d).collect::<Vec<_>>();
+    ranges[dim] = index..index + 1;
+    ranges
+}
+
+/// Computes an inclusive scan of the tensor along `dim` with the given combine function.
+///
+/// Each step slices the next element along `dim`, combines it with the running accumulator and
+/// the accumulated slices are concatenated back together, so the operation stays on the device.
+pub(crate) fn cumulative_with_slice<B, K>(
+    tensor: K::Primitive,
+    dim: usize,
+    combine: impl Fn(K::Primitive, K::Primitive) -> K::Primitive,
+) -> K::Primitive
+where
+    B: Backend,
+    K: TensorKind<B> + BasicOps<B>,
+{
+    let shape = tensor.shape();
+    let size = shape.dims[dim];
+
+    let mut acc = K::slice(tensor.clone(), &slice_ranges(&shape.dims, dim, 0));
+    let mut outputs = Vec::with_capacity(size);
+    outputs.push(acc.clone());
+
+    for index in 1..size {
+        let item = K::slice(tensor.clone(), &slice_ranges(&shape.dims, dim, index));
+        acc = combine(acc, item);
+        outputs.push(acc.clone());
+    }
+
+    K::cat(outputs, dim)
+}
+
+/// Computes the cumulative maximum (or minimum) of the tensor along `dim` with the indices of the
+/// selected elements.
+///
+/// When multiple elements are equal to the running extremum, the index of the last occurrence is
+/// returned.
+pub(crate) fn cumulative_extremum_with_slice<B, K>(
+    tensor: K::Primitive,
+    dim: usize,
+    maximum: bool,
+) -> (K::Primitive, IntTensor<B>)
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    let shape = tensor.shape();
+    let size = shape.dims[dim];
+    let device = K::device(&tensor);
+
+    let mut shape_item = shape.clone();
+    shape_item.dims[dim] = 1;
+
+    let mut acc = K::slice(tensor.clone(), &slice_ranges(&shape.dims, dim, 0));
+    let mut acc_indices = B::int_zeros(shape_item.clone(), &device);
+    let mut outputs = Vec::with_capacity(size);
+    let mut indices = Vec::with_capacity(size);
+    outputs.push(acc.clone());
+    indices.push(acc_indices.clone());
+
+    for index in 1..size {
+        let item = K::slice(tensor.clone(), &slice_ranges(&shape.dims, dim, index));
+        let mask = match maximum {
+            true => K::greater_equal(item.clone(), acc.clone()),
+            false => K::lower_equal(item.clone(), acc.clone()),
+        };
+        let item_indices = B::int_full(shape_item.clone(), (index as i64).elem(), &device);
+
+        acc = K::mask_where(acc, mask.clone(), item);
+        acc_indices = B::int_mask_where(acc_indices, mask, item_indices);
+        outputs.push(acc.clone());
+        indices.push(acc_indices.clone());
+    }
+
+    (K::cat(outputs, dim), B::int_cat(indices, dim))
+}
@@ -0,0 +1,170 @@
+use crate::{TensorData, TensorMetadata, backend::Backend, ops::FloatTensor};
+use alloc::{vec, vec::Vec};
+use burn_common::reader::try_read_sync;
+use core::f64::consts::PI;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+fn read<B: Backend>(tensor: FloatTensor<B>) -> TensorData {
+    try_read_sync(B::float_into_data(tensor)).expect(
+        "Failed to synchronously read tensor data. This operation is not supported until this \
+         backend has a fast Fourier transform implementation.",
+    )
+}
+
+/// Computes the in-place radix-2 fast Fourier transform of a signal whose length is a power of
+/// two, with the exponent sign given by `sign`.
+fn radix2(real: &mut [f64], imag: &mut [f64], sign: f64) {
+    let n = real.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+
+        if i < j {
+            real.swap(i, j);
+            imag.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = sign * 2.0 * PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (s, c) = (step * k as f64).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let tr = real[b] * c - imag[b] * s;
+                let ti = real[b] * s + imag[b] * c;
+
+                real[b] = real[a] - tr;
+                imag[b] = imag[a] - ti;
+                real[a] += tr;
+                imag[a] += ti;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Computes the in-place unnormalized discrete Fourier transform of a signal of arbitrary length.
+///
+/// Lengths that aren't a power of two are handled with Bluestein's algorithm, which rewrites the
+/// transform as a circular convolution computed with power of two transforms.
+///
+/// Exported for the backends computing the transform on the host.
+#[doc(hidden)]
+pub fn fft_in_place(real: &mut [f64], imag: &mut [f64], inverse: bool) {
+    let n = real.len();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    if n <= 1 {
+        return;
+    }
+
+    if n.is_power_of_two() {
+        radix2(real, imag, sign);
+        return;
+    }
+
+    // Chirp `c[k] = exp(sign * i * pi * k^2 / n)`, where `k^2` is reduced modulo `2n` to keep the
+    // angles small.
+    let chirp = (0..n)
+        .map(|k| {
+            let angle = sign * PI * ((k * k) % (2 * n)) as f64 / n as f64;
+            let (s, c) = angle.sin_cos();
+            (c, s)
+        })
+        .collect::<Vec<_>>();
+
+    let m = (2 * n - 1).next_power_of_two();
+    let mut a_real = vec![0.0; m];
+    let mut a_imag = vec![0.0; m];
+    let mut b_real = vec![0.0; m];
+    let mut b_imag = vec![0.0; m];
+
+    for k in 0..n {
+        let (c, s) = chirp[k];
+        a_real[k] = real[k] * c - imag[k] * s;
+        a_imag[k] = real[k] * s + imag[k] * c;
+
+        b_real[k] = c;
+        b_imag[k] = -s;
+        if k > 0 {
+            b_real[m - k] = c;
+            b_imag[m - k] = -s;
+        }
+    }
+
+    radix2(&mut a_real, &mut a_imag, -1.0);
+    radix2(&mut b_real, &mut b_imag, -1.0);
+
+    for k in 0..m {
+        let (ar, ai) = (a_real[k], a_imag[k]);
+        a_real[k] = ar * b_real[k] - ai * b_imag[k];
+        a_imag[k] = ar * b_imag[k] + ai * b_real[k];
+    }
+
+    radix2(&mut a_real, &mut a_imag, 1.0);
+
+    for k in 0..n {
+        let (c, s) = chirp[k];
+        let (cr, ci) = (a_real[k] / m as f64, a_imag[k] / m as f64);
+        real[k] = cr * c - ci * s;
+        imag[k] = cr * s + ci * c;
+    }
+}
+
+/// Computes the unnormalized discrete Fourier transform of complex signals along `dim` on the
+/// host.
+pub(crate) fn fft<B: Backend>(
+    real: FloatTensor<B>,
+    imag: FloatTensor<B>,
+    dim: usize,
+    inverse: bool,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let device = B::float_device(&real);
+    let dtype = real.dtype();
+    let shape = real.shape();
+
+    let mut values_real = read::<B>(real).iter::<f64>().collect::<Vec<_>>();
+    let mut values_imag = read::<B>(imag).iter::<f64>().collect::<Vec<_>>();
+
+    let size = shape.dims[dim];
+    let stride = shape.dims[dim + 1..].iter().product::<usize>();
+    let num_lines = shape.num_elements() / size.max(1);
+
+    let mut line_real = vec![0.0; size];
+    let mut line_imag = vec![0.0; size];
+
+    for line in 0..num_lines {
+        let offset = (line / stride) * stride * size + line % stride;
+
+        for i in 0..size {
+            line_real[i] = values_real[offset + i * stride];
+            line_imag[i] = values_imag[offset + i * stride];
+        }
+
+        fft_in_place(&mut line_real, &mut line_imag, inverse);
+
+        for i in 0..size {
+            values_real[offset + i * stride] = line_real[i];
+            values_imag[offset + i * stride] = line_imag[i];
+        }
+    }
+
+    let output = |values: Vec<f64>| {
+        B::float_from_data(
+            TensorData::new(values, shape.clone()).convert_dtype(dtype),
+            &device,
+        )
+    };
+
+    (output(values_real), output(values_imag))
+}
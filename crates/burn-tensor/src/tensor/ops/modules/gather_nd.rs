@@ -0,0 +1,100 @@
+use crate::{
+    Int, Numeric, Shape, Tensor, TensorData, TensorMetadata, backend::Backend, ops::IntTensor,
+};
+
+/// Flattens the index tuples of shape `[..., k]` into linear indices over the first `k`
+/// dimensions of a tensor with the given dims.
+///
+/// Returns the linear indices with shape `[M]` where `M` is the number of index tuples.
+fn linear_indices<B: Backend>(dims: &[usize], indices: IntTensor<B>) -> Tensor<B, 1, Int> {
+    let indices_dims = indices.shape().dims;
+    let k = indices_dims[indices_dims.len() - 1];
+    let num_tuples = indices_dims[..indices_dims.len() - 1]
+        .iter()
+        .product::<usize>();
+
+    let mut strides = alloc::vec![1i64; k];
+    for i in (0..k.saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * dims[i + 1] as i64;
+    }
+
+    let indices = Tensor::<B, 2, Int>::new(B::int_reshape(indices, Shape::new([num_tuples, k])));
+    let strides =
+        Tensor::<B, 2, Int>::from_data(TensorData::new(strides, [1, k]), &indices.device());
+
+    indices.mul(strides).sum_dim(1).reshape([num_tuples])
+}
+
+/// Gathers the slices of the tensor addressed by the index tuples of the last indices dimension.
+///
+/// The tensor is flattened to `[P, R]`, `P` being the number of positions addressed by the index
+/// tuples and `R` the size of each slice, so the operation is a single
+/// [select](Tensor::select) on the first dimension.
+pub(crate) fn gather_nd<B: Backend, K: Numeric<B>>(
+    tensor: K::Primitive,
+    indices: IntTensor<B>,
+) -> K::Primitive {
+    let dims = tensor.shape().dims;
+    let indices_dims = indices.shape().dims;
+    let k = indices_dims[indices_dims.len() - 1];
+
+    let num_positions = dims[..k].iter().product::<usize>();
+    let slice_size = dims[k..].iter().product::<usize>();
+
+    let mut output_dims = indices_dims[..indices_dims.len() - 1].to_vec();
+    output_dims.extend_from_slice(&dims[k..]);
+
+    let linear = linear_indices::<B>(&dims, indices);
+    let tensor =
+        Tensor::<B, 2, K>::new(K::reshape(tensor, Shape::new([num_positions, slice_size])));
+
+    K::reshape(
+        tensor.select(0, linear).into_primitive(),
+        Shape::from(output_dims),
+    )
+}
+
+/// Updates the slices of the tensor addressed by the index tuples of the last indices dimension
+/// with the values, which are summed to the tensor when `accumulate` is true.
+///
+/// When the values replace the tensor slices, the positions hit by at least one index tuple are
+/// found by scattering ones, and the scattered values are selected at those positions.
+pub(crate) fn scatter_nd<B: Backend, K: Numeric<B>>(
+    tensor: K::Primitive,
+    indices: IntTensor<B>,
+    values: K::Primitive,
+    accumulate: bool,
+) -> K::Primitive {
+    let shape = tensor.shape();
+    let indices_dims = indices.shape().dims;
+    let k = indices_dims[indices_dims.len() - 1];
+
+    let num_positions = shape.dims[..k].iter().product::<usize>();
+    let slice_size = shape.dims[k..].iter().product::<usize>();
+    let num_tuples = indices_dims[..indices_dims.len() - 1]
+        .iter()
+        .product::<usize>();
+
+    let linear = linear_indices::<B>(&shape.dims, indices);
+    let tensor =
+        Tensor::<B, 2, K>::new(K::reshape(tensor, Shape::new([num_positions, slice_size])));
+    let values = Tensor::<B, 2, K>::new(K::reshape(values, Shape::new([num_tuples, slice_size])));
+
+    let output = if accumulate {
+        tensor.select_assign(0, linear, values)
+    } else {
+        let device = tensor.device();
+        let hits = Tensor::<B, 2, Int>::zeros([num_positions, slice_size], &device)
+            .select_assign(
+                0,
+                linear.clone(),
+                Tensor::ones([num_tuples, slice_size], &device),
+            )
+            .greater_elem(0);
+        let updates = tensor.zeros_like().select_assign(0, linear, values);
+
+        tensor.mask_where(hits, updates)
+    };
+
+    K::reshape(output.into_primitive(), shape)
+}
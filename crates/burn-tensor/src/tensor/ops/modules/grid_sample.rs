@@ -0,0 +1,427 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    Shape, Tensor, TensorMetadata, TensorPrimitive,
+    backend::Backend,
+    ops::{
+        FloatTensor, GridSampleBackward, GridSampleMode, GridSampleOptions, GridSamplePaddingMode,
+    },
+};
+
+/// The coefficient of the cubic convolution, same as PyTorch.
+const CUBIC_A: f64 = -0.75;
+
+/// Shapes of a grid sampling operation.
+struct Layout {
+    batch_size: usize,
+    channels: usize,
+    spatial: Vec<usize>,
+    output_spatial: Vec<usize>,
+}
+
+impl Layout {
+    fn new(x: &Shape, grid: &Shape, options: &GridSampleOptions) -> Self {
+        let rank = grid.num_dims();
+        let num_spatial = rank - 2;
+
+        assert!(
+            num_spatial == 2 || num_spatial == 3,
+            "Grid sample only supports 2D and 3D inputs, got a grid of rank {rank}"
+        );
+        assert_eq!(
+            x.num_dims(),
+            rank,
+            "The input and the grid of grid sample must have the same rank"
+        );
+        assert_eq!(
+            grid.dims[rank - 1],
+            num_spatial,
+            "The last dimension of the grid must hold {num_spatial} coordinates"
+        );
+        assert_eq!(
+            x.dims[0], grid.dims[0],
+            "The input and the grid of grid sample must have the same batch size"
+        );
+        assert!(
+            num_spatial == 2 || options.mode != GridSampleMode::Bicubic,
+            "Bicubic grid sample only supports 2D inputs"
+        );
+
+        Self {
+            batch_size: x.dims[0],
+            channels: x.dims[1],
+            spatial: x.dims[2..].to_vec(),
+            output_spatial: grid.dims[1..rank - 1].to_vec(),
+        }
+    }
+
+    fn num_elements(&self) -> usize {
+        self.spatial.iter().product()
+    }
+
+    fn num_locations(&self) -> usize {
+        self.output_spatial.iter().product()
+    }
+}
+
+/// A location of the input used to sample the grid points along a spatial dimension.
+struct Tap<B: Backend> {
+    /// The input index, always in bound.
+    index: Tensor<B, 2>,
+    /// The weight of the input value, zero when the index was out of bound.
+    weight: Tensor<B, 2>,
+    /// The derivative of the weight with respect to the grid coordinate.
+    weight_grad: Tensor<B, 2>,
+}
+
+/// The weighted input locations used to sample each grid point, combined over all the spatial
+/// dimensions.
+struct Sample<B: Backend> {
+    /// The flat input index.
+    index: Tensor<B, 2>,
+    weight: Tensor<B, 2>,
+    /// The derivative of the weight with respect to each grid coordinate.
+    weight_grads: Vec<Tensor<B, 2>>,
+}
+
+pub(crate) fn grid_sample<B: Backend>(
+    x: FloatTensor<B>,
+    grid: FloatTensor<B>,
+    options: GridSampleOptions,
+) -> FloatTensor<B> {
+    let layout = Layout::new(&x.shape(), &grid.shape(), &options);
+    let x = flatten_input::<B>(x, &layout);
+    let shape = [layout.batch_size, layout.channels, layout.num_locations()];
+
+    let output = samples::<B>(grid, &layout, &options)
+        .into_iter()
+        .map(|sample| {
+            let index = sample.index.int().unsqueeze_dim::<3>(1).expand(shape);
+            x.clone()
+                .gather(2, index)
+                .mul(sample.weight.unsqueeze_dim(1))
+        })
+        .reduce(|acc, value| acc.add(value))
+        .unwrap();
+
+    let mut output_shape = vec![layout.batch_size, layout.channels];
+    output_shape.extend(&layout.output_spatial);
+    B::float_reshape(output.into_primitive().tensor(), Shape::from(output_shape))
+}
+
+pub(crate) fn grid_sample_backward<B: Backend>(
+    x: FloatTensor<B>,
+    grid: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    options: GridSampleOptions,
+) -> GridSampleBackward<B> {
+    let layout = Layout::new(&x.shape(), &grid.shape(), &options);
+    let grid_shape = grid.shape();
+    let x_shape = x.shape();
+    let x = flatten_input::<B>(x, &layout);
+    let shape = [layout.batch_size, layout.channels, layout.num_locations()];
+    let output_grad = Tensor::<B, 3>::new(TensorPrimitive::Float(B::float_reshape(
+        output_grad,
+        Shape::new(shape),
+    )));
+
+    let mut x_grad = x.zeros_like();
+    let mut grid_grads: Vec<Option<Tensor<B, 2>>> = vec![None; layout.spatial.len()];
+
+    for sample in samples::<B>(grid, &layout, &options) {
+        let index = sample.index.int().unsqueeze_dim::<3>(1).expand(shape);
+        let values = x.clone().gather(2, index.clone());
+
+        x_grad = x_grad.scatter(
+            2,
+            index,
+            output_grad.clone().mul(sample.weight.unsqueeze_dim(1)),
+        );
+
+        let values_grad = output_grad.clone().mul(values).sum_dim(1).squeeze::<2>(1);
+        for (grid_grad, weight_grad) in grid_grads.iter_mut().zip(sample.weight_grads) {
+            let value = values_grad.clone().mul(weight_grad);
+            *grid_grad = Some(match grid_grad.take() {
+                Some(grid_grad) => grid_grad.add(value),
+                None => value,
+            });
+        }
+    }
+
+    let grid_grad = Tensor::stack::<3>(grid_grads.into_iter().map(Option::unwrap).collect(), 2);
+
+    GridSampleBackward::new(
+        B::float_reshape(x_grad.into_primitive().tensor(), x_shape),
+        B::float_reshape(grid_grad.into_primitive().tensor(), grid_shape),
+    )
+}
+
+/// Reshapes the input to `[batch_size, channels, num_elements]`.
+fn flatten_input<B: Backend>(x: FloatTensor<B>, layout: &Layout) -> Tensor<B, 3> {
+    Tensor::new(TensorPrimitive::Float(B::float_reshape(
+        x,
+        Shape::new([layout.batch_size, layout.channels, layout.num_elements()]),
+    )))
+}
+
+/// Computes all the weighted input locations used to sample each grid point.
+fn samples<B: Backend>(
+    grid: FloatTensor<B>,
+    layout: &Layout,
+    options: &GridSampleOptions,
+) -> Vec<Sample<B>> {
+    let num_spatial = layout.spatial.len();
+    let grid = Tensor::<B, 3>::new(TensorPrimitive::Float(B::float_reshape(
+        grid,
+        Shape::new([layout.batch_size, layout.num_locations(), num_spatial]),
+    )));
+
+    let ones = Tensor::<B, 2>::ones([layout.batch_size, layout.num_locations()], &grid.device());
+    let mut samples = vec![Sample {
+        index: ones.zeros_like(),
+        weight: ones,
+        weight_grads: Vec::new(),
+    }];
+
+    // The grid coordinates are in the reverse order of the spatial dimensions.
+    for axis in 0..num_spatial {
+        let dim = num_spatial - 1 - axis;
+        let size = layout.spatial[dim];
+        let stride = layout.spatial[dim + 1..].iter().product::<usize>();
+        let coord = grid.clone().narrow(2, axis, 1).squeeze::<2>(2);
+        let taps = axis_taps(coord, size, options);
+
+        samples = samples
+            .into_iter()
+            .flat_map(|sample| {
+                taps.iter().map(move |tap| {
+                    let mut weight_grads = sample
+                        .weight_grads
+                        .iter()
+                        .map(|grad| grad.clone().mul(tap.weight.clone()))
+                        .collect::<Vec<_>>();
+                    weight_grads.push(sample.weight.clone().mul(tap.weight_grad.clone()));
+
+                    Sample {
+                        index: sample
+                            .index
+                            .clone()
+                            .add(tap.index.clone().mul_scalar(stride as f64)),
+                        weight: sample.weight.clone().mul(tap.weight.clone()),
+                        weight_grads,
+                    }
+                })
+            })
+            .collect();
+    }
+
+    samples
+}
+
+/// Computes the input locations used to sample the grid coordinates along a spatial dimension.
+fn axis_taps<B: Backend>(
+    coord: Tensor<B, 2>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> Vec<Tap<B>> {
+    // Unnormalize the coordinates from [-1, 1] to the input indices.
+    let scale = match options.align_corners {
+        true => (size as f64 - 1.0) / 2.0,
+        false => size as f64 / 2.0,
+    };
+    let coord = coord
+        .mul_scalar(scale)
+        .add_scalar((size as f64 - 1.0) / 2.0);
+
+    let taps = match options.mode {
+        GridSampleMode::Nearest => {
+            let (coord, _) = pad_coordinate(coord, size, options);
+            let ones = coord.ones_like();
+
+            vec![Tap {
+                index: coord.round(),
+                weight_grad: ones.zeros_like(),
+                weight: ones,
+            }]
+        }
+        GridSampleMode::Bilinear => {
+            let (coord, coord_grad) = pad_coordinate(coord, size, options);
+            let index = coord.clone().floor();
+            let t = coord.sub(index.clone());
+            let weight_grad = coord_grad.mul_scalar(scale);
+
+            vec![
+                Tap {
+                    index: index.clone(),
+                    weight: t.clone().neg().add_scalar(1.0),
+                    weight_grad: weight_grad.clone().neg(),
+                },
+                Tap {
+                    index: index.add_scalar(1.0),
+                    weight: t,
+                    weight_grad,
+                },
+            ]
+        }
+        GridSampleMode::Bicubic => {
+            // The padding is applied to each tap instead of the coordinate.
+            let index = coord.clone().floor();
+            let t = coord.sub(index.clone());
+
+            (0..4)
+                .map(|i| {
+                    let index = pad_index(index.clone().add_scalar(i as f64 - 1.0), size, options);
+                    let (weight, weight_grad) = cubic_weight(t.clone(), i);
+
+                    Tap {
+                        index,
+                        weight,
+                        weight_grad: weight_grad.mul_scalar(scale),
+                    }
+                })
+                .collect()
+        }
+    };
+
+    taps.into_iter()
+        .map(|tap| {
+            let max = size as f64 - 1.0;
+            let valid = tap
+                .index
+                .clone()
+                .greater_equal_elem(0.0)
+                .bool_and(tap.index.clone().lower_equal_elem(max))
+                .float();
+
+            Tap {
+                index: tap.index.clamp(0.0, max),
+                weight: tap.weight.mul(valid.clone()),
+                weight_grad: tap.weight_grad.mul(valid),
+            }
+        })
+        .collect()
+}
+
+/// Applies the padding to an unnormalized coordinate, also returning the derivative of the
+/// padded coordinate.
+fn pad_coordinate<B: Backend>(
+    coord: Tensor<B, 2>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => {
+            let ones = coord.ones_like();
+            (coord, ones)
+        }
+        GridSamplePaddingMode::Border => clip(coord, size),
+        GridSamplePaddingMode::Reflection => {
+            let (coord, reflect_grad) = match options.align_corners {
+                true => reflect(coord, 0.0, 2.0 * (size as f64 - 1.0)),
+                false => reflect(coord, -1.0, 2.0 * size as f64 - 1.0),
+            };
+            let (coord, clip_grad) = clip(coord, size);
+
+            (coord, reflect_grad.mul(clip_grad))
+        }
+    }
+}
+
+/// Applies the padding to the index of a tap.
+fn pad_index<B: Backend>(
+    index: Tensor<B, 2>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> Tensor<B, 2> {
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => index,
+        _ => pad_coordinate(index, size, options).0,
+    }
+}
+
+/// Clips the coordinate to the input, with the derivative of the clipping.
+fn clip<B: Backend>(coord: Tensor<B, 2>, size: usize) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let max = size as f64 - 1.0;
+    let grad = coord
+        .clone()
+        .greater_elem(0.0)
+        .bool_and(coord.clone().lower_elem(max))
+        .float();
+
+    (coord.clamp(0.0, max), grad)
+}
+
+/// Reflects the coordinate at the bounds `twice_low / 2` and `twice_high / 2` until it is in
+/// bound, with the derivative of the reflection.
+fn reflect<B: Backend>(
+    coord: Tensor<B, 2>,
+    twice_low: f64,
+    twice_high: f64,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    if twice_low == twice_high {
+        let zeros = coord.zeros_like();
+        return (zeros.clone(), zeros);
+    }
+
+    let min = twice_low / 2.0;
+    let span = (twice_high - twice_low) / 2.0;
+
+    let coord = coord.sub_scalar(min);
+    let sign = coord
+        .clone()
+        .lower_elem(0.0)
+        .float()
+        .mul_scalar(-2.0)
+        .add_scalar(1.0);
+    let coord = coord.abs();
+    let flips = coord.clone().div_scalar(span).floor();
+    let extra = coord.sub(flips.clone().mul_scalar(span));
+    // 1 when the number of flips is odd, in which case the direction is reversed.
+    let odd = flips
+        .clone()
+        .sub(flips.div_scalar(2.0).floor().mul_scalar(2.0));
+    let direction = odd.clone().mul_scalar(-2.0).add_scalar(1.0);
+
+    let reflected = extra
+        .clone()
+        .add(odd.mul(extra.mul_scalar(-2.0).add_scalar(span)))
+        .add_scalar(min);
+
+    (reflected, sign.mul(direction))
+}
+
+/// Computes the cubic convolution weight of the `i`-th tap, with its derivative with respect to
+/// `t`, the distance between the coordinate and the second tap.
+fn cubic_weight<B: Backend>(t: Tensor<B, 2>, i: usize) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    const A: f64 = CUBIC_A;
+    // Coefficients of the polynomials for distances in [0, 1] and [1, 2], highest degree first.
+    let near = [A + 2.0, -(A + 3.0), 0.0, 1.0];
+    let far = [A, -5.0 * A, 8.0 * A, -4.0 * A];
+
+    let (distance, coefficients, sign) = match i {
+        0 => (t.add_scalar(1.0), far, 1.0),
+        1 => (t, near, 1.0),
+        2 => (t.neg().add_scalar(1.0), near, -1.0),
+        _ => (t.neg().add_scalar(2.0), far, -1.0),
+    };
+    let [c3, c2, c1, c0] = coefficients;
+
+    let weight = distance
+        .clone()
+        .mul_scalar(c3)
+        .add_scalar(c2)
+        .mul(distance.clone())
+        .add_scalar(c1)
+        .mul(distance.clone())
+        .add_scalar(c0);
+    let weight_grad = distance
+        .clone()
+        .mul_scalar(3.0 * c3)
+        .add_scalar(2.0 * c2)
+        .mul(distance)
+        .add_scalar(c1)
+        .mul_scalar(sign);
+
+    (weight, weight_grad)
+}
@@ -0,0 +1,618 @@
+use crate::{DType, Device, TensorData, TensorMetadata, backend::Backend, ops::FloatTensor};
+use alloc::{vec, vec::Vec};
+use burn_common::reader::try_read_sync;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Maximum number of sweeps performed by the Jacobi algorithms.
+const MAX_SWEEPS: usize = 100;
+
+/// A batch of row-major matrices read from a float tensor.
+struct Matrices {
+    values: Vec<f64>,
+    batch_dims: Vec<usize>,
+    rows: usize,
+    cols: usize,
+    dtype: DType,
+}
+
+impl Matrices {
+    fn read<B: Backend>(tensor: FloatTensor<B>) -> (Self, Device<B>) {
+        let device = B::float_device(&tensor);
+        let dtype = tensor.dtype();
+        let data = try_read_sync(B::float_into_data(tensor)).expect(
+            "Failed to synchronously read tensor data. This operation is not supported until this \
+             backend has a linear algebra implementation.",
+        );
+
+        let dims = &data.shape;
+        let rank = dims.len();
+        let matrices = Self {
+            batch_dims: dims[..rank - 2].to_vec(),
+            rows: dims[rank - 2],
+            cols: dims[rank - 1],
+            values: data.iter::<f64>().collect(),
+            dtype,
+        };
+
+        (matrices, device)
+    }
+
+    fn batch_size(&self) -> usize {
+        self.batch_dims.iter().product()
+    }
+
+    fn matrix(&self, index: usize) -> &[f64] {
+        let size = self.rows * self.cols;
+        &self.values[index * size..(index + 1) * size]
+    }
+}
+
+/// Writes the concatenated `[rows, cols]` matrices back into a float tensor.
+fn write<B: Backend>(
+    values: Vec<f64>,
+    batch_dims: &[usize],
+    rows: usize,
+    cols: usize,
+    dtype: DType,
+    device: &Device<B>,
+) -> FloatTensor<B> {
+    let mut shape = batch_dims.to_vec();
+    shape.push(rows);
+    shape.push(cols);
+
+    B::float_from_data(TensorData::new(values, shape).convert_dtype(dtype), device)
+}
+
+fn identity(n: usize) -> Vec<f64> {
+    let mut values = vec![0.0; n * n];
+    for i in 0..n {
+        values[i * n + i] = 1.0;
+    }
+    values
+}
+
+fn transpose(values: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    let mut output = vec![0.0; rows * cols];
+    for i in 0..rows {
+        for j in 0..cols {
+            output[j * rows + i] = values[i * cols + j];
+        }
+    }
+    output
+}
+
+/// The sign of the value, where zero is considered positive.
+fn sign(value: f64) -> f64 {
+    if value < 0.0 { -1.0 } else { 1.0 }
+}
+
+/// Computes the lower triangular Cholesky factor of a `[n, n]` matrix.
+///
+/// Only the lower triangle of the matrix is read. The factor contains `NaN` values when the
+/// matrix isn't positive-definite.
+fn cholesky_matrix(a: &[f64], n: usize) -> Vec<f64> {
+    let mut l = vec![0.0; n * n];
+
+    for i in 0..n {
+        for j in 0..=i {
+            let mut sum = a[i * n + j];
+            for k in 0..j {
+                sum -= l[i * n + k] * l[j * n + k];
+            }
+
+            l[i * n + j] = match i == j {
+                true => sum.sqrt(),
+                false => sum / l[j * n + j],
+            };
+        }
+    }
+
+    l
+}
+
+/// Computes the reduced QR decomposition of a `[m, n]` matrix with Householder reflections.
+///
+/// Returns `Q` of shape `[m, k]` and `R` of shape `[k, n]` with `k = min(m, n)`, where the
+/// diagonal of `R` is non-negative.
+fn qr_matrix(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let k = m.min(n);
+    let mut r = a.to_vec();
+    let mut q = identity(m);
+
+    for j in 0..k {
+        let mut v = (j..m).map(|i| r[i * n + j]).collect::<Vec<_>>();
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+
+        v[0] += sign(v[0]) * norm;
+        let v_norm2 = v.iter().map(|x| x * x).sum::<f64>();
+
+        // R = (I - 2vvᵀ/vᵀv) R
+        for col in 0..n {
+            let dot = (j..m).map(|i| v[i - j] * r[i * n + col]).sum::<f64>();
+            let scale = 2.0 * dot / v_norm2;
+            for i in j..m {
+                r[i * n + col] -= scale * v[i - j];
+            }
+        }
+
+        // Q = Q (I - 2vvᵀ/vᵀv)
+        for row in 0..m {
+            let dot = (j..m).map(|i| q[row * m + i] * v[i - j]).sum::<f64>();
+            let scale = 2.0 * dot / v_norm2;
+            for i in j..m {
+                q[row * m + i] -= scale * v[i - j];
+            }
+        }
+    }
+
+    let mut q_reduced = vec![0.0; m * k];
+    let mut r_reduced = vec![0.0; k * n];
+
+    for i in 0..k {
+        let flip = sign(r[i * n + i]);
+        for col in i..n {
+            r_reduced[i * n + col] = flip * r[i * n + col];
+        }
+        for row in 0..m {
+            q_reduced[row * k + i] = flip * q[row * m + i];
+        }
+    }
+
+    (q_reduced, r_reduced)
+}
+
+/// Computes the reduced singular value decomposition of a `[m, n]` matrix with `m >= n` using
+/// one-sided Jacobi rotations.
+///
+/// Returns `U` of shape `[m, n]`, the singular values in descending order and `V` of shape
+/// `[n, n]`.
+fn svd_tall(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut u = a.to_vec();
+    let mut v = identity(n);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let mut alpha = 0.0;
+                let mut beta = 0.0;
+                let mut gamma = 0.0;
+                for i in 0..m {
+                    alpha += u[i * n + p] * u[i * n + p];
+                    beta += u[i * n + q] * u[i * n + q];
+                    gamma += u[i * n + p] * u[i * n + q];
+                }
+
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = sign(zeta) / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+
+                rotate_columns(&mut u, m, n, p, q, c, s);
+                rotate_columns(&mut v, n, n, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms = (0..n)
+        .map(|j| (0..m).map(|i| u[i * n + j].powi(2)).sum::<f64>().sqrt())
+        .collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|a, b| norms[*b].total_cmp(&norms[*a]));
+
+    let tolerance = norms.iter().copied().fold(0.0, f64::max) * f64::EPSILON * m as f64;
+    let mut u_sorted = vec![0.0; m * n];
+    let mut v_sorted = vec![0.0; n * n];
+    let mut s_sorted = vec![0.0; n];
+
+    for (j, &index) in order.iter().enumerate() {
+        let norm = norms[index];
+        s_sorted[j] = norm;
+        for i in 0..n {
+            v_sorted[i * n + j] = v[i * n + index];
+        }
+
+        if norm > tolerance {
+            for i in 0..m {
+                u_sorted[i * n + j] = u[i * n + index] / norm;
+            }
+        } else {
+            complete_column(&mut u_sorted, m, n, j);
+        }
+    }
+
+    (u_sorted, s_sorted, v_sorted)
+}
+
+/// Applies a Jacobi rotation to the columns `p` and `q` of a `[rows, cols]` matrix.
+fn rotate_columns(x: &mut [f64], rows: usize, cols: usize, p: usize, q: usize, c: f64, s: f64) {
+    for i in 0..rows {
+        let xp = x[i * cols + p];
+        let xq = x[i * cols + q];
+        x[i * cols + p] = c * xp - s * xq;
+        x[i * cols + q] = s * xp + c * xq;
+    }
+}
+
+/// Fills the column `j` of a `[rows, cols]` matrix with a unit vector orthogonal to the previous
+/// columns, used for the singular vectors associated with zero singular values.
+fn complete_column(x: &mut [f64], rows: usize, cols: usize, j: usize) {
+    for basis in 0..rows {
+        let mut column = vec![0.0; rows];
+        column[basis] = 1.0;
+
+        for k in 0..j {
+            let dot = (0..rows).map(|i| x[i * cols + k] * column[i]).sum::<f64>();
+            for (i, value) in column.iter_mut().enumerate() {
+                *value -= dot * x[i * cols + k];
+            }
+        }
+
+        let norm = column.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm > 0.5 {
+            for (i, value) in column.iter().enumerate() {
+                x[i * cols + j] = value / norm;
+            }
+            return;
+        }
+    }
+}
+
+/// Computes the reduced singular value decomposition of a `[m, n]` matrix.
+///
+/// Returns `U` of shape `[m, k]`, the singular values in descending order and `Vh` of shape
+/// `[k, n]` with `k = min(m, n)`.
+fn svd_matrix(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    if m >= n {
+        let (u, s, v) = svd_tall(a, m, n);
+        (u, s, transpose(&v, n, n))
+    } else {
+        // Aᵀ = U S Vᵀ, so A = V S Uᵀ.
+        let (u, s, v) = svd_tall(&transpose(a, m, n), n, m);
+        (v, s, transpose(&u, n, m))
+    }
+}
+
+/// Computes the eigenvalues in ascending order and the eigenvectors of the symmetric `[n, n]`
+/// matrix defined by the lower triangle of `a`, using cyclic Jacobi rotations.
+fn eigh_matrix(a: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut x = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..=i {
+            x[i * n + j] = a[i * n + j];
+            x[j * n + i] = a[i * n + j];
+        }
+    }
+    let mut v = identity(n);
+    let norm = x.iter().map(|x| x * x).sum::<f64>();
+
+    for _ in 0..MAX_SWEEPS {
+        let off = (0..n)
+            .flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+            .map(|(p, q)| x[p * n + q].powi(2))
+            .sum::<f64>();
+        if off <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = x[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+
+                let theta = (x[q * n + q] - x[p * n + p]) / (2.0 * apq);
+                let t = sign(theta) / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                // X = Jᵀ X J
+                rotate_columns(&mut x, n, n, p, q, c, s);
+                for k in 0..n {
+                    let xp = x[p * n + k];
+                    let xq = x[q * n + k];
+                    x[p * n + k] = c * xp - s * xq;
+                    x[q * n + k] = s * xp + c * xq;
+                }
+                rotate_columns(&mut v, n, n, p, q, c, s);
+            }
+        }
+    }
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|a, b| x[*a * n + *a].total_cmp(&x[*b * n + *b]));
+
+    let values = order.iter().map(|i| x[i * n + i]).collect();
+    let mut vectors = vec![0.0; n * n];
+    for (j, &index) in order.iter().enumerate() {
+        for i in 0..n {
+            vectors[i * n + j] = v[i * n + index];
+        }
+    }
+
+    (values, vectors)
+}
+
+/// LU decomposition with partial pivoting of a `[n, n]` matrix.
+struct Lu {
+    /// The strictly lower part holds `L` (with an implicit unit diagonal) and the upper part `U`.
+    lu: Vec<f64>,
+    /// The row of the input matrix at each row of the decomposition.
+    permutation: Vec<usize>,
+    /// The sign of the permutation.
+    sign: f64,
+}
+
+impl Lu {
+    fn new(a: &[f64], n: usize) -> Self {
+        let mut lu = a.to_vec();
+        let mut permutation = (0..n).collect::<Vec<_>>();
+        let mut sign = 1.0;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|i, j| lu[i * n + k].abs().total_cmp(&lu[j * n + k].abs()))
+                .unwrap();
+
+            if pivot != k {
+                for col in 0..n {
+                    lu.swap(k * n + col, pivot * n + col);
+                }
+                permutation.swap(k, pivot);
+                sign = -sign;
+            }
+
+            let diag = lu[k * n + k];
+            if diag == 0.0 {
+                continue;
+            }
+
+            for i in k + 1..n {
+                let factor = lu[i * n + k] / diag;
+                lu[i * n + k] = factor;
+                for col in k + 1..n {
+                    lu[i * n + col] -= factor * lu[k * n + col];
+                }
+            }
+        }
+
+        Self {
+            lu,
+            permutation,
+            sign,
+        }
+    }
+
+    /// Solves `A X = B` for `B` of shape `[n, r]`.
+    fn solve(&self, b: &[f64], n: usize, r: usize) -> Vec<f64> {
+        let mut x = vec![0.0; n * r];
+        for (i, &row) in self.permutation.iter().enumerate() {
+            x[i * r..(i + 1) * r].copy_from_slice(&b[row * r..(row + 1) * r]);
+        }
+
+        for col in 0..r {
+            for i in 0..n {
+                let sum = (0..i)
+                    .map(|k| self.lu[i * n + k] * x[k * r + col])
+                    .sum::<f64>();
+                x[i * r + col] -= sum;
+            }
+            for i in (0..n).rev() {
+                let sum = (i + 1..n)
+                    .map(|k| self.lu[i * n + k] * x[k * r + col])
+                    .sum::<f64>();
+                x[i * r + col] = (x[i * r + col] - sum) / self.lu[i * n + i];
+            }
+        }
+
+        x
+    }
+
+    /// Returns the determinant.
+    fn det(&self, n: usize) -> f64 {
+        (0..n).map(|i| self.lu[i * n + i]).product::<f64>() * self.sign
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant.
+    fn slogdet(&self, n: usize) -> (f64, f64) {
+        let mut sign = self.sign;
+        let mut logabsdet = 0.0;
+
+        for i in 0..n {
+            let diag = self.lu[i * n + i];
+            if diag == 0.0 {
+                return (0.0, f64::NEG_INFINITY);
+            }
+            sign *= diag.signum();
+            logabsdet += diag.abs().ln();
+        }
+
+        (sign, logabsdet)
+    }
+}
+
+/// Solves `A X = B` for a triangular `[n, n]` matrix `A` and `B` of shape `[n, r]`, reading only
+/// the upper or lower triangle of `A`.
+fn solve_triangular_matrix(a: &[f64], b: &[f64], n: usize, r: usize, upper: bool) -> Vec<f64> {
+    let mut x = b.to_vec();
+
+    for col in 0..r {
+        if upper {
+            for i in (0..n).rev() {
+                let sum = (i + 1..n)
+                    .map(|k| a[i * n + k] * x[k * r + col])
+                    .sum::<f64>();
+                x[i * r + col] = (x[i * r + col] - sum) / a[i * n + i];
+            }
+        } else {
+            for i in 0..n {
+                let sum = (0..i).map(|k| a[i * n + k] * x[k * r + col]).sum::<f64>();
+                x[i * r + col] = (x[i * r + col] - sum) / a[i * n + i];
+            }
+        }
+    }
+
+    x
+}
+
+/// Computes the Cholesky decomposition of a batch of matrices on the host.
+pub(crate) fn cholesky<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let (a, device) = Matrices::read::<B>(tensor);
+    let n = a.rows;
+
+    let values = (0..a.batch_size())
+        .flat_map(|index| cholesky_matrix(a.matrix(index), n))
+        .collect();
+
+    write::<B>(values, &a.batch_dims, n, n, a.dtype, &device)
+}
+
+/// Computes the reduced QR decomposition of a batch of matrices on the host.
+pub(crate) fn qr<B: Backend>(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>) {
+    let (a, device) = Matrices::read::<B>(tensor);
+    let (m, n) = (a.rows, a.cols);
+    let k = m.min(n);
+
+    let mut q = Vec::with_capacity(a.batch_size() * m * k);
+    let mut r = Vec::with_capacity(a.batch_size() * k * n);
+    for index in 0..a.batch_size() {
+        let (q_matrix, r_matrix) = qr_matrix(a.matrix(index), m, n);
+        q.extend(q_matrix);
+        r.extend(r_matrix);
+    }
+
+    (
+        write::<B>(q, &a.batch_dims, m, k, a.dtype, &device),
+        write::<B>(r, &a.batch_dims, k, n, a.dtype, &device),
+    )
+}
+
+/// Computes the reduced singular value decomposition of a batch of matrices on the host.
+pub(crate) fn svd<B: Backend>(
+    tensor: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>) {
+    let (a, device) = Matrices::read::<B>(tensor);
+    let (m, n) = (a.rows, a.cols);
+    let k = m.min(n);
+
+    let mut u = Vec::with_capacity(a.batch_size() * m * k);
+    let mut s = Vec::with_capacity(a.batch_size() * k);
+    let mut vh = Vec::with_capacity(a.batch_size() * k * n);
+    for index in 0..a.batch_size() {
+        let (u_matrix, s_values, vh_matrix) = svd_matrix(a.matrix(index), m, n);
+        u.extend(u_matrix);
+        s.extend(s_values);
+        vh.extend(vh_matrix);
+    }
+
+    (
+        write::<B>(u, &a.batch_dims, m, k, a.dtype, &device),
+        write::<B>(s, &a.batch_dims, 1, k, a.dtype, &device),
+        write::<B>(vh, &a.batch_dims, k, n, a.dtype, &device),
+    )
+}
+
+/// Computes the eigendecomposition of a batch of symmetric matrices on the host.
+pub(crate) fn eigh<B: Backend>(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>) {
+    let (a, device) = Matrices::read::<B>(tensor);
+    let n = a.rows;
+
+    let mut values = Vec::with_capacity(a.batch_size() * n);
+    let mut vectors = Vec::with_capacity(a.batch_size() * n * n);
+    for index in 0..a.batch_size() {
+        let (matrix_values, matrix_vectors) = eigh_matrix(a.matrix(index), n);
+        values.extend(matrix_values);
+        vectors.extend(matrix_vectors);
+    }
+
+    (
+        write::<B>(values, &a.batch_dims, 1, n, a.dtype, &device),
+        write::<B>(vectors, &a.batch_dims, n, n, a.dtype, &device),
+    )
+}
+
+/// Solves a batch of triangular systems on the host.
+pub(crate) fn solve_triangular<B: Backend>(
+    a: FloatTensor<B>,
+    b: FloatTensor<B>,
+    upper: bool,
+) -> FloatTensor<B> {
+    let (a, device) = Matrices::read::<B>(a);
+    let (b, _) = Matrices::read::<B>(b);
+    let (n, r) = (b.rows, b.cols);
+
+    let values = (0..a.batch_size())
+        .flat_map(|index| solve_triangular_matrix(a.matrix(index), b.matrix(index), n, r, upper))
+        .collect();
+
+    write::<B>(values, &b.batch_dims, n, r, b.dtype, &device)
+}
+
+/// Solves a batch of square systems on the host.
+pub(crate) fn solve<B: Backend>(a: FloatTensor<B>, b: FloatTensor<B>) -> FloatTensor<B> {
+    let (a, device) = Matrices::read::<B>(a);
+    let (b, _) = Matrices::read::<B>(b);
+    let (n, r) = (b.rows, b.cols);
+
+    let values = (0..a.batch_size())
+        .flat_map(|index| Lu::new(a.matrix(index), n).solve(b.matrix(index), n, r))
+        .collect();
+
+    write::<B>(values, &b.batch_dims, n, r, b.dtype, &device)
+}
+
+/// Computes the inverse of a batch of square matrices on the host.
+pub(crate) fn inverse<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let (a, device) = Matrices::read::<B>(tensor);
+    let n = a.rows;
+    let identity = identity(n);
+
+    let values = (0..a.batch_size())
+        .flat_map(|index| Lu::new(a.matrix(index), n).solve(&identity, n, n))
+        .collect();
+
+    write::<B>(values, &a.batch_dims, n, n, a.dtype, &device)
+}
+
+/// Computes the sign and the log absolute value of the determinant of a batch of square matrices
+/// on the host.
+pub(crate) fn slogdet<B: Backend>(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>) {
+    let (a, device) = Matrices::read::<B>(tensor);
+    let n = a.rows;
+
+    let (sign, logabsdet) = (0..a.batch_size())
+        .map(|index| Lu::new(a.matrix(index), n).slogdet(n))
+        .unzip();
+
+    (
+        write::<B>(sign, &a.batch_dims, 1, 1, a.dtype, &device),
+        write::<B>(logabsdet, &a.batch_dims, 1, 1, a.dtype, &device),
+    )
+}
+
+/// Computes the determinant of a batch of square matrices on the host.
+pub(crate) fn det<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let (a, device) = Matrices::read::<B>(tensor);
+    let n = a.rows;
+
+    let values = (0..a.batch_size())
+        .map(|index| Lu::new(a.matrix(index), n).det(n))
+        .collect();
+
+    write::<B>(values, &a.batch_dims, 1, 1, a.dtype, &device)
+}
@@ -3,8 +3,22 @@ pub mod conv;
 
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with cumulative operations
+pub(crate) mod cumulative;
+/// Module with fast Fourier transform operations
+pub(crate) mod fft;
+/// Module with N-dimensional gather and scatter operations
+pub(crate) mod gather_nd;
+/// Module with grid sampling operations
+pub(crate) mod grid_sample;
+/// Module with linear algebra operations
+pub(crate) mod linalg;
+/// Module with multinomial sampling
+pub(crate) mod multinomial;
 /// Module with repeat operation
 pub(crate) mod repeat_dim;
+/// Module with special mathematical functions
+pub(crate) mod special;
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
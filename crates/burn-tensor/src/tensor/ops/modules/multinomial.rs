@@ -0,0 +1,50 @@
+use crate::{
+    Distribution, Tensor, TensorPrimitive,
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+};
+
+/// Samples category indices from the weights of each row, using only tensor operations.
+///
+/// With replacement, the samples are drawn by inverse transform sampling on the cumulative
+/// weights. Without replacement, the categories with the largest perturbed log-weights are kept,
+/// which is equivalent to drawing them one after the other (Gumbel-top-k trick).
+pub(crate) fn multinomial<B: Backend>(
+    weights: FloatTensor<B>,
+    num_samples: usize,
+    replacement: bool,
+) -> IntTensor<B> {
+    let weights = Tensor::<B, 2>::new(TensorPrimitive::Float(weights));
+    let [batch_size, num_categories] = weights.dims();
+    let device = weights.device();
+
+    let samples = if replacement {
+        let cumulative = weights.cumsum(1);
+        let total = cumulative.clone().narrow(1, num_categories - 1, 1);
+        let values =
+            Tensor::<B, 2>::random([batch_size, num_samples], Distribution::Default, &device)
+                .mul(total);
+
+        // Categories with a zero weight have the same cumulative weight as the previous one, so
+        // they are never selected.
+        cumulative
+            .searchsorted(values, true)
+            .clamp_max(num_categories as i64 - 1)
+    } else {
+        let gumbel = weights
+            .random_like(Distribution::Default)
+            .log()
+            .neg()
+            .log()
+            .neg();
+
+        weights
+            .log()
+            .add(gumbel)
+            .sort_descending_with_indices(1)
+            .1
+            .narrow(1, 0, num_samples)
+    };
+
+    samples.into_primitive()
+}
@@ -0,0 +1,513 @@
+use core::f64::consts::{FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_4, PI};
+
+use crate::{
+    ElementConversion, Shape, TensorMetadata,
+    backend::Backend,
+    ops::{FloatTensor, binary_ops_shape},
+};
+
+const TAN_PI_8: f64 = 0.414_213_562_373_095_03;
+const TAN_3PI_8: f64 = 2.414_213_562_373_095;
+const HALF_LN_2PI: f64 = 0.918_938_533_204_672_8;
+
+/// Number of recurrence steps before Stirling's series of the log-gamma function is evaluated.
+const STIRLING_STEPS: usize = 6;
+
+/// Number of recurrence steps before the asymptotic expansions of the digamma and polygamma
+/// functions are evaluated.
+const RECURRENCE_STEPS: usize = 10;
+
+/// Bernoulli numbers `B_2, B_4, ..., B_12` used by the asymptotic expansion of the Hurwitz zeta
+/// function.
+const BERNOULLI: [f64; 6] = [
+    1.0 / 6.0,
+    -1.0 / 30.0,
+    1.0 / 42.0,
+    -1.0 / 30.0,
+    5.0 / 66.0,
+    -691.0 / 2730.0,
+];
+
+/// Evaluates a polynomial with Horner's method, where the coefficients are given from the highest
+/// degree to the constant term.
+fn polynomial<B: Backend>(tensor: FloatTensor<B>, coefficients: &[f64]) -> FloatTensor<B> {
+    let mut output = B::float_add_scalar(
+        B::float_mul_scalar(tensor.clone(), coefficients[0].elem()),
+        coefficients[1].elem(),
+    );
+    for coefficient in &coefficients[2..] {
+        output = B::float_add_scalar(B::float_mul(output, tensor.clone()), coefficient.elem());
+    }
+    output
+}
+
+/// Raises a tensor to a positive integer power with repeated multiplications, which is exact for
+/// negative bases.
+fn powi<B: Backend>(tensor: FloatTensor<B>, exponent: u32) -> FloatTensor<B> {
+    let mut output = tensor.clone();
+    for _ in 1..exponent {
+        output = B::float_mul(output, tensor.clone());
+    }
+    output
+}
+
+/// Computes `1 - x` element-wise.
+fn one_minus<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    B::float_add_scalar(B::float_neg(tensor), 1.0.elem())
+}
+
+/// Inverse tangent, using the range reduction and the polynomial of the Cephes library.
+pub(crate) fn atan<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let negative = B::float_lower_elem(tensor.clone(), 0.0.elem());
+    let abs = B::float_abs(tensor);
+    let large = B::float_greater_elem(abs.clone(), TAN_3PI_8.elem());
+    let medium = B::float_greater_elem(abs.clone(), TAN_PI_8.elem());
+
+    // Reduce the argument to [0, tan(pi/8)] with atan(x) = pi/4 + atan((x - 1) / (x + 1)) and
+    // atan(x) = pi/2 + atan(-1 / x).
+    let reduced = B::float_mask_where(
+        abs.clone(),
+        medium.clone(),
+        B::float_div(
+            B::float_sub_scalar(abs.clone(), 1.0.elem()),
+            B::float_add_scalar(abs.clone(), 1.0.elem()),
+        ),
+    );
+    let reduced = B::float_mask_where(
+        reduced,
+        large.clone(),
+        B::float_neg(B::float_recip(abs.clone())),
+    );
+    let offset = B::float_zeros(abs.shape(), &B::float_device(&abs));
+    let offset = B::float_mask_fill(offset, medium, FRAC_PI_4.elem());
+    let offset = B::float_mask_fill(offset, large, FRAC_PI_2.elem());
+
+    let squared = B::float_mul(reduced.clone(), reduced.clone());
+    let series = B::float_mul(
+        polynomial::<B>(
+            squared,
+            &[
+                8.053_744_495_38e-2,
+                -1.387_768_560_32e-1,
+                1.997_771_064_78e-1,
+                -3.333_294_915_39e-1,
+                0.0,
+            ],
+        ),
+        reduced.clone(),
+    );
+    let output = B::float_add(offset, B::float_add(series, reduced));
+
+    B::float_mask_where(output.clone(), negative, B::float_neg(output))
+}
+
+/// Four-quadrant inverse tangent of `lhs / rhs`.
+pub(crate) fn atan2<B: Backend>(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B> {
+    let shape = Shape::from(binary_ops_shape(&lhs.shape().dims, &rhs.shape().dims));
+    let y = B::float_expand(lhs, shape.clone());
+    let x = B::float_expand(rhs, shape);
+
+    // Half turn with the sign of y, used to move the angle to the left half-plane.
+    let half_turn = B::float_full(y.shape(), PI.elem(), &B::float_device(&y));
+    let half_turn = B::float_mask_fill(
+        half_turn,
+        B::float_lower_elem(y.clone(), 0.0.elem()),
+        (-PI).elem(),
+    );
+    let origin = B::float_equal_elem(
+        B::float_add(B::float_abs(x.clone()), B::float_abs(y.clone())),
+        0.0.elem(),
+    );
+
+    let output = B::float_atan(B::float_div(y, x.clone()));
+    let output = B::float_mask_where(
+        output.clone(),
+        B::float_lower_elem(x.clone(), 0.0.elem()),
+        B::float_add(output, half_turn.clone()),
+    );
+    let output = B::float_mask_where(
+        output,
+        B::float_equal_elem(x, 0.0.elem()),
+        B::float_div_scalar(half_turn, 2.0.elem()),
+    );
+
+    B::float_mask_fill(output, origin, 0.0.elem())
+}
+
+/// Inverse sine, computed as `atan2(x, sqrt(1 - x^2))`.
+pub(crate) fn asin<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let cos = complement_sqrt::<B>(tensor.clone());
+    B::float_atan2(tensor, cos)
+}
+
+/// Inverse cosine, computed as `atan2(sqrt(1 - x^2), x)`.
+pub(crate) fn acos<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let sin = complement_sqrt::<B>(tensor.clone());
+    B::float_atan2(sin, tensor)
+}
+
+/// Computes `sqrt((1 - x) (1 + x))`, which is more accurate than `sqrt(1 - x^2)` near one.
+fn complement_sqrt<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    B::float_sqrt(B::float_mul(
+        one_minus::<B>(tensor.clone()),
+        B::float_add_scalar(tensor, 1.0.elem()),
+    ))
+}
+
+/// Inverse hyperbolic sine, computed as `sign(x) log1p(|x| + x^2 / (1 + sqrt(1 + x^2)))` to stay
+/// accurate near zero.
+pub(crate) fn asinh<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let negative = B::float_lower_elem(tensor.clone(), 0.0.elem());
+    let abs = B::float_abs(tensor);
+    let squared = B::float_mul(abs.clone(), abs.clone());
+    let root = B::float_add_scalar(
+        B::float_sqrt(B::float_add_scalar(squared.clone(), 1.0.elem())),
+        1.0.elem(),
+    );
+    let output = B::float_log1p(B::float_add(abs, B::float_div(squared, root)));
+
+    B::float_mask_where(output.clone(), negative, B::float_neg(output))
+}
+
+/// Inverse hyperbolic cosine, computed as `log1p((x - 1) + sqrt((x - 1) (x + 1)))`.
+pub(crate) fn acosh<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let minus_one = B::float_sub_scalar(tensor.clone(), 1.0.elem());
+    let root = B::float_sqrt(B::float_mul(
+        minus_one.clone(),
+        B::float_add_scalar(tensor, 1.0.elem()),
+    ));
+    B::float_log1p(B::float_add(minus_one, root))
+}
+
+/// Inverse hyperbolic tangent, computed as `log1p(2x / (1 - x)) / 2`.
+pub(crate) fn atanh<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let ratio = B::float_div(
+        B::float_mul_scalar(tensor.clone(), 2.0.elem()),
+        one_minus::<B>(tensor),
+    );
+    B::float_mul_scalar(B::float_log1p(ratio), 0.5.elem())
+}
+
+/// Computes `exp(x) - 1`, using `2 tanh(x/2) / (1 - tanh(x/2))` near zero to avoid cancellation.
+pub(crate) fn expm1<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let small = B::float_lower_elem(B::float_abs(tensor.clone()), 0.5.elem());
+    let tanh = B::float_tanh(B::float_mul_scalar(tensor.clone(), 0.5.elem()));
+    let output_small = B::float_div(
+        B::float_mul_scalar(tanh.clone(), 2.0.elem()),
+        one_minus::<B>(tanh),
+    );
+    let output = B::float_sub_scalar(B::float_exp(tensor), 1.0.elem());
+
+    B::float_mask_where(output, small, output_small)
+}
+
+/// Natural logarithm of the absolute value of the gamma function, using the recurrence
+/// `lgamma(x) = lgamma(x + n) - ln(x (x + 1) ... (x + n - 1))` up to Stirling's series and the
+/// reflection formula `lgamma(x) = ln(pi / |sin(pi x)|) - lgamma(1 - x)` for `x < 0.5`.
+pub(crate) fn lgamma<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let reflect = B::float_lower_elem(tensor.clone(), 0.5.elem());
+    let z = B::float_mask_where(
+        tensor.clone(),
+        reflect.clone(),
+        one_minus::<B>(tensor.clone()),
+    );
+
+    // Only small values are shifted, the product is clamped to avoid overflows elsewhere.
+    let steps = STIRLING_STEPS as f64;
+    let shifted = B::float_lower_elem(z.clone(), steps.elem());
+    let y = B::float_mask_where(
+        z.clone(),
+        shifted.clone(),
+        B::float_add_scalar(z.clone(), steps.elem()),
+    );
+    let clamped = B::float_clamp_max(z, steps.elem());
+    let mut product = clamped.clone();
+    for k in 1..STIRLING_STEPS {
+        product = B::float_mul(
+            product,
+            B::float_add_scalar(clamped.clone(), (k as f64).elem()),
+        );
+    }
+    let output = stirling_lgamma::<B>(y);
+    let output = B::float_mask_where(
+        output.clone(),
+        shifted,
+        B::float_sub(output, B::float_log(product)),
+    );
+
+    let sin = B::float_abs(B::float_sin(B::float_mul_scalar(tensor, PI.elem())));
+    let reflected = B::float_sub(
+        B::float_neg(B::float_log(B::float_div_scalar(sin, PI.elem()))),
+        output.clone(),
+    );
+
+    B::float_mask_where(output, reflect, reflected)
+}
+
+/// Stirling's series of `lgamma(y)`, accurate for `y >= 6`.
+fn stirling_lgamma<B: Backend>(y: FloatTensor<B>) -> FloatTensor<B> {
+    let y_inv = B::float_recip(y.clone());
+    let y_inv2 = B::float_mul(y_inv.clone(), y_inv.clone());
+    let series = B::float_mul(
+        polynomial::<B>(
+            y_inv2,
+            &[-1.0 / 1680.0, 1.0 / 1260.0, -1.0 / 360.0, 1.0 / 12.0],
+        ),
+        y_inv,
+    );
+
+    // (y - 0.5) ln(y) - y + 0.5 ln(2 pi) + series
+    let output = B::float_sub(
+        B::float_mul(
+            B::float_sub_scalar(y.clone(), 0.5.elem()),
+            B::float_log(y.clone()),
+        ),
+        y,
+    );
+    B::float_add_scalar(B::float_add(output, series), HALF_LN_2PI.elem())
+}
+
+/// Digamma function, using the recurrence `psi(x) = psi(x + 1) - 1 / x` up to the asymptotic
+/// expansion and the reflection formula `psi(x) = psi(1 - x) - pi / tan(pi x)` for `x < 0.5`.
+pub(crate) fn digamma<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let reflect = B::float_lower_elem(tensor.clone(), 0.5.elem());
+    let z = B::float_mask_where(
+        tensor.clone(),
+        reflect.clone(),
+        one_minus::<B>(tensor.clone()),
+    );
+
+    let mut shift = B::float_recip(z.clone());
+    for k in 1..RECURRENCE_STEPS {
+        shift = B::float_add(
+            shift,
+            B::float_recip(B::float_add_scalar(z.clone(), (k as f64).elem())),
+        );
+    }
+
+    // ln(y) - 1 / (2y) - sum B_2k / (2k y^2k)
+    let y = B::float_add_scalar(z, (RECURRENCE_STEPS as f64).elem());
+    let y_inv = B::float_recip(y.clone());
+    let y_inv2 = B::float_mul(y_inv.clone(), y_inv.clone());
+    let series = B::float_mul(
+        polynomial::<B>(
+            y_inv2.clone(),
+            &[
+                -1.0 / 132.0,
+                1.0 / 240.0,
+                -1.0 / 252.0,
+                1.0 / 120.0,
+                -1.0 / 12.0,
+            ],
+        ),
+        y_inv2,
+    );
+    let output = B::float_sub(B::float_log(y), B::float_mul_scalar(y_inv, 0.5.elem()));
+    let output = B::float_sub(B::float_add(output, series), shift);
+
+    let angle = B::float_mul_scalar(tensor, PI.elem());
+    let cot = B::float_div(B::float_cos(angle.clone()), B::float_sin(angle));
+    let reflected = B::float_sub(output.clone(), B::float_mul_scalar(cot, PI.elem()));
+
+    B::float_mask_where(output, reflect, reflected)
+}
+
+/// Polygamma function of order `n >= 1`, computed from the Hurwitz zeta function with
+/// `psi_n(x) = (-1)^(n + 1) n! zeta(n + 1, x)`.
+///
+/// The zeta function is evaluated with the recurrence `zeta(s, x) = x^-s + zeta(s, x + 1)` up to
+/// its Euler-Maclaurin asymptotic expansion, which is accurate for `x > 0`.
+pub(crate) fn polygamma<B: Backend>(tensor: FloatTensor<B>, n: u32) -> FloatTensor<B> {
+    let s = n + 1;
+
+    let mut output = powi::<B>(B::float_recip(tensor.clone()), s);
+    for k in 1..RECURRENCE_STEPS {
+        let term = B::float_recip(B::float_add_scalar(tensor.clone(), (k as f64).elem()));
+        output = B::float_add(output, powi::<B>(term, s));
+    }
+
+    // y^-s (y / (s - 1) + 1 / 2 + sum B_2j / (2j)! s (s + 1) ... (s + 2j - 2) y^(1 - 2j))
+    let y = B::float_add_scalar(tensor, (RECURRENCE_STEPS as f64).elem());
+    let y_inv = B::float_recip(y.clone());
+    let y_inv2 = B::float_mul(y_inv.clone(), y_inv.clone());
+    let mut coefficients = [0.0; BERNOULLI.len()];
+    let mut factor = s as f64;
+    let mut factorial = 2.0;
+    for (j, bernoulli) in BERNOULLI.iter().enumerate() {
+        coefficients[BERNOULLI.len() - 1 - j] = bernoulli / factorial * factor;
+        let next = s as f64 + 2.0 * j as f64;
+        factor *= (next + 1.0) * (next + 2.0);
+        factorial *= (2.0 * j as f64 + 3.0) * (2.0 * j as f64 + 4.0);
+    }
+    let series = B::float_mul(polynomial::<B>(y_inv2, &coefficients), y_inv.clone());
+    let tail = B::float_add(
+        B::float_add_scalar(B::float_div_scalar(y, ((s - 1) as f64).elem()), 0.5.elem()),
+        series,
+    );
+    let tail = B::float_mul(tail, powi::<B>(y_inv, s));
+    let output = B::float_add(output, tail);
+
+    let sign = if n.is_multiple_of(2) { -1.0 } else { 1.0 };
+    let factorial = (1..=n).map(|k| k as f64).product::<f64>();
+    B::float_mul_scalar(output, (sign * factorial).elem())
+}
+
+/// Inverse error function, using the approximation from
+/// [Approximating the erfinv function](https://people.maths.ox.ac.uk/gilesm/files/gems_erfinv.pdf)
+/// refined with one step of Newton's method.
+pub(crate) fn erfinv<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let w = B::float_neg(B::float_log(B::float_mul(
+        one_minus::<B>(tensor.clone()),
+        B::float_add_scalar(tensor.clone(), 1.0.elem()),
+    )));
+    let central = B::float_lower_elem(w.clone(), 5.0.elem());
+
+    let central_output = polynomial::<B>(
+        B::float_sub_scalar(w.clone(), 2.5.elem()),
+        &[
+            2.810_226_36e-8,
+            3.432_739_39e-7,
+            -3.523_387_7e-6,
+            -4.391_506_54e-6,
+            2.185_808_7e-4,
+            -1.253_725_03e-3,
+            -4.177_681_64e-3,
+            2.466_407_27e-1,
+            1.501_409_41,
+        ],
+    );
+    let tail_output = polynomial::<B>(
+        B::float_sub_scalar(B::float_sqrt(w), 3.0.elem()),
+        &[
+            -2.002_142_57e-4,
+            1.009_505_58e-4,
+            1.349_343_22e-3,
+            -3.673_428_44e-3,
+            5.739_507_73e-3,
+            -7.622_461_3e-3,
+            9.438_870_47e-3,
+            1.001_674_06,
+            2.832_976_82,
+        ],
+    );
+    let output = B::float_mul(
+        B::float_mask_where(tail_output, central, central_output),
+        tensor.clone(),
+    );
+
+    // Newton step: r - (erf(r) - x) / (2 / sqrt(pi) exp(-r^2))
+    let derivative = B::float_mul_scalar(
+        B::float_exp(B::float_neg(B::float_mul(output.clone(), output.clone()))),
+        FRAC_2_SQRT_PI.elem(),
+    );
+    let error = B::float_sub(B::float_erf(output.clone()), tensor.clone());
+    let output = B::float_sub(output, B::float_div(error, derivative));
+
+    let output = B::float_mask_fill(
+        output,
+        B::float_equal_elem(tensor.clone(), 1.0.elem()),
+        f64::INFINITY.elem(),
+    );
+    B::float_mask_fill(
+        output,
+        B::float_equal_elem(tensor, (-1.0).elem()),
+        f64::NEG_INFINITY.elem(),
+    )
+}
+
+/// Modified Bessel function of the first kind of order zero, using the polynomial approximations
+/// of Abramowitz and Stegun (9.8.1 and 9.8.2).
+pub(crate) fn i0<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let abs = B::float_abs(tensor.clone());
+    let small = B::float_lower_equal_elem(abs.clone(), 3.75.elem());
+
+    let t = B::float_div_scalar(tensor, 3.75.elem());
+    let output_small = polynomial::<B>(
+        B::float_mul(t.clone(), t),
+        &[
+            0.004_581_3,
+            0.036_076_8,
+            0.265_973_2,
+            1.206_749_2,
+            3.089_942_4,
+            3.515_622_9,
+            1.0,
+        ],
+    );
+    let output_large = B::float_mul(
+        bessel_envelope::<B>(abs.clone()),
+        polynomial::<B>(
+            B::float_div(
+                B::float_full(abs.shape(), 3.75.elem(), &B::float_device(&abs)),
+                abs,
+            ),
+            &[
+                0.003_923_77,
+                -0.016_476_33,
+                0.026_355_37,
+                -0.020_577_06,
+                0.009_162_81,
+                -0.001_575_65,
+                0.002_253_19,
+                0.013_285_92,
+                0.398_942_28,
+            ],
+        ),
+    );
+
+    B::float_mask_where(output_large, small, output_small)
+}
+
+/// Modified Bessel function of the first kind of order one, using the polynomial approximations
+/// of Abramowitz and Stegun (9.8.3 and 9.8.4).
+pub(crate) fn i1<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let negative = B::float_lower_elem(tensor.clone(), 0.0.elem());
+    let abs = B::float_abs(tensor.clone());
+    let small = B::float_lower_equal_elem(abs.clone(), 3.75.elem());
+
+    let t = B::float_div_scalar(tensor.clone(), 3.75.elem());
+    let output_small = B::float_mul(
+        polynomial::<B>(
+            B::float_mul(t.clone(), t),
+            &[
+                0.000_324_11,
+                0.003_015_32,
+                0.026_587_33,
+                0.150_849_34,
+                0.514_988_69,
+                0.878_905_94,
+                0.5,
+            ],
+        ),
+        tensor,
+    );
+    let output_large = B::float_mul(
+        bessel_envelope::<B>(abs.clone()),
+        polynomial::<B>(
+            B::float_div(
+                B::float_full(abs.shape(), 3.75.elem(), &B::float_device(&abs)),
+                abs,
+            ),
+            &[
+                -0.004_200_59,
+                0.017_876_54,
+                -0.028_953_12,
+                0.022_829_67,
+                -0.010_315_55,
+                0.001_638_01,
+                -0.003_620_18,
+                -0.039_880_24,
+                0.398_942_28,
+            ],
+        ),
+    );
+    let output_large =
+        B::float_mask_where(output_large.clone(), negative, B::float_neg(output_large));
+
+    B::float_mask_where(output_large, small, output_small)
+}
+
+/// Computes `exp(x) / sqrt(x)`, the asymptotic growth of the modified Bessel functions.
+fn bessel_envelope<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    B::float_div(B::float_exp(tensor.clone()), B::float_sqrt(tensor))
+}
@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 use core::ops::Range;
 
 use crate::{
-    Device, Shape, TensorData, TensorMetadata, TensorPrimitive,
+    Device, ElementConversion, Shape, TensorData, TensorMetadata, TensorPrimitive,
     backend::Backend,
     quantization::{
         Calibration, QTensorPrimitive, QuantPropagation, QuantScheme,
@@ -10,7 +10,7 @@ use crate::{
     },
 };
 
-use super::{BoolTensor, FloatElem, FloatTensor, IntElem, IntTensor, QuantizedTensor};
+use super::{BoolTensor, ConvOptions, FloatElem, FloatTensor, IntElem, IntTensor, QuantizedTensor};
 
 /// Automatically applies `dequantization -> float operation -> quantization`.
 ///
@@ -143,6 +143,75 @@ pub trait QTensorOps<B: Backend> {
     /// Convert the tensor back to a higher precision data type.
     fn dequantize(tensor: QuantizedTensor<B>) -> FloatTensor<B>;
 
+    /// Applies a linear transformation to a quantized input with quantized weights, where the output
+    /// is requantized with the given (statically calibrated) quantization parameters.
+    ///
+    /// See [linear](crate::ops::ModuleOps::linear) for the shapes of the tensors.
+    ///
+    /// The default implementation dequantizes the tensors and computes the operation in floating
+    /// point; backends with integer kernels should override it.
+    fn q_linear(
+        input: QuantizedTensor<B>,
+        weight: QuantizedTensor<B>,
+        bias: Option<FloatTensor<B>>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> QuantizedTensor<B> {
+        let output = B::linear(Self::dequantize(input), Self::dequantize(weight), bias);
+        Self::quantize(output, scheme, qparams)
+    }
+
+    /// Applies a 2D convolution to a quantized input with quantized weights, where the output is
+    /// requantized with the given (statically calibrated) quantization parameters.
+    ///
+    /// See [conv2d](crate::ops::ModuleOps::conv2d) for the shapes of the tensors.
+    ///
+    /// The default implementation dequantizes the tensors and computes the operation in floating
+    /// point; backends with integer kernels should override it.
+    fn q_conv2d(
+        input: QuantizedTensor<B>,
+        weight: QuantizedTensor<B>,
+        bias: Option<FloatTensor<B>>,
+        options: ConvOptions<2>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> QuantizedTensor<B> {
+        let output = B::conv2d(
+            Self::dequantize(input),
+            Self::dequantize(weight),
+            bias,
+            options,
+        );
+        Self::quantize(output, scheme, qparams)
+    }
+
+    /// Simulate the quantization of a float tensor, where values are quantized and immediately
+    /// dequantized with the given scales and offsets.
+    ///
+    /// The scales and offsets must be broadcastable to the shape of the tensor. The output stays in
+    /// floating point precision, which is used to model quantization errors during training.
+    fn fake_quantize(
+        tensor: FloatTensor<B>,
+        scheme: &QuantScheme,
+        scales: FloatTensor<B>,
+        offsets: Option<FloatTensor<B>>,
+    ) -> FloatTensor<B> {
+        let (a, b) = scheme.q_type.range(scheme.mode);
+
+        let values = B::float_div(tensor, scales.clone());
+        let values = match &offsets {
+            Some(offsets) => B::float_add(values, offsets.clone()),
+            None => values,
+        };
+        let values = B::float_clamp(B::float_round(values), a.elem(), b.elem());
+        let values = match offsets {
+            Some(offsets) => B::float_sub(values, offsets),
+            None => values,
+        };
+
+        B::float_mul(values, scales)
+    }
+
     /// Gets the device of the tensor.
     ///
     /// # Arguments
@@ -1,5 +1,9 @@
 use super::cat::cat_with_slice_assign;
+use super::cumulative::{cumulative_extremum_with_slice, cumulative_with_slice};
+use super::gather_nd::{gather_nd, scatter_nd};
+use super::multinomial::multinomial;
 use super::repeat_dim::repeat_with_slice_assign;
+use super::special;
 use super::{BoolTensor, Device, FloatElem, FloatTensor, IntElem, IntTensor};
 use crate::{Distribution, ElementConversion, Float, TensorData, backend::Backend, tensor::Shape};
 use crate::{FloatDType, TensorMetadata, TensorPrimitive};
@@ -454,6 +458,49 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatTensor<B>,
     ) -> FloatTensor<B>;
 
+    /// Gather the slices of the tensor addressed by the index tuples along the last dimension
+    /// of the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to gather from.
+    /// * `indices` - The index tuples, with shape `[..., k]` where `k` is at most the tensor rank.
+    ///
+    /// # Returns
+    ///
+    /// The gathered slices, with shape `indices.shape[..-1] ++ tensor.shape[k..]`.
+    fn float_gather_nd(tensor: FloatTensor<B>, indices: IntTensor<B>) -> FloatTensor<B> {
+        gather_nd::<B, Float>(TensorPrimitive::Float(tensor), indices).tensor()
+    }
+
+    /// Update the slices of the tensor addressed by the index tuples along the last dimension
+    /// of the indices with the given values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to update.
+    /// * `indices` - The index tuples, with shape `[..., k]` where `k` is at most the tensor rank.
+    /// * `values` - The values, with shape `indices.shape[..-1] ++ tensor.shape[k..]`.
+    /// * `accumulate` - Whether the values are summed to the tensor instead of replacing it.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the updated slices.
+    fn float_scatter_nd(
+        tensor: FloatTensor<B>,
+        indices: IntTensor<B>,
+        values: FloatTensor<B>,
+        accumulate: bool,
+    ) -> FloatTensor<B> {
+        scatter_nd::<B, Float>(
+            TensorPrimitive::Float(tensor),
+            indices,
+            TensorPrimitive::Float(values),
+            accumulate,
+        )
+        .tensor()
+    }
+
     /// Select tensor elements corresponding for the given ranges.
     ///
     /// # Arguments
@@ -736,6 +783,103 @@ pub trait FloatTensorOps<B: Backend> {
         B::float_exp(B::float_sum_dim(B::float_log(tensor), dim))
     }
 
+    /// Computes the cumulative sum of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the sum of all elements
+    /// up to and including its position along `dim`.
+    fn float_cumsum(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        cumulative_with_slice::<B, Float>(TensorPrimitive::Float(tensor), dim, |acc, item| {
+            TensorPrimitive::Float(B::float_add(acc.tensor(), item.tensor()))
+        })
+        .tensor()
+    }
+
+    /// Computes the cumulative product of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the product of all elements
+    /// up to and including its position along `dim`.
+    fn float_cumprod(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        cumulative_with_slice::<B, Float>(TensorPrimitive::Float(tensor), dim, |acc, item| {
+            TensorPrimitive::Float(B::float_mul(acc.tensor(), item.tensor()))
+        })
+        .tensor()
+    }
+
+    /// Computes the cumulative maximum of the elements along a dimension and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running maximum of `tensor` along `dim` and the indices of the maximum
+    /// elements. When the maximum is found multiple times, the index of the last occurrence is returned.
+    fn float_cummax(tensor: FloatTensor<B>, dim: usize) -> (FloatTensor<B>, IntTensor<B>) {
+        let (values, indices) =
+            cumulative_extremum_with_slice::<B, Float>(TensorPrimitive::Float(tensor), dim, true);
+        (values.tensor(), indices)
+    }
+
+    /// Computes the cumulative minimum of the elements along a dimension and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running minimum of `tensor` along `dim` and the indices of the minimum
+    /// elements. When the minimum is found multiple times, the index of the last occurrence is returned.
+    fn float_cummin(tensor: FloatTensor<B>, dim: usize) -> (FloatTensor<B>, IntTensor<B>) {
+        let (values, indices) =
+            cumulative_extremum_with_slice::<B, Float>(TensorPrimitive::Float(tensor), dim, false);
+        (values.tensor(), indices)
+    }
+
+    /// Computes the logarithm of the cumulative sum of the exponentials of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is `log(sum(exp(x)))` of all
+    /// elements up to and including its position along `dim`.
+    fn float_logcumsumexp(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        cumulative_with_slice::<B, Float>(TensorPrimitive::Float(tensor), dim, |acc, item| {
+            // log(e^a + e^b) = max(a, b) + log(1 + e^(-|a - b|))
+            let (acc, item) = (acc.tensor(), item.tensor());
+            let mask = B::float_greater(item.clone(), acc.clone());
+            let max = B::float_mask_where(acc.clone(), mask, item.clone());
+            let diff = B::float_abs(B::float_sub(acc, item));
+            // Both operands are -inf, the difference is NaN but the result should stay -inf.
+            let nan_mask = B::float_not_equal(diff.clone(), diff.clone());
+            let diff = B::float_mask_fill(diff, nan_mask, f32::INFINITY.elem());
+            let correction = B::float_log1p(B::float_exp(B::float_neg(diff)));
+            TensorPrimitive::Float(B::float_add(max, correction))
+        })
+        .tensor()
+    }
+
     /// Mean of all elements in a tensor.
     ///
     /// # Arguments
@@ -762,6 +906,52 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the mean of all elements in `tensor` along `dim`.
     fn float_mean_dim(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B>;
 
+    /// Computes the logarithm of the sum of the exponentials of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `log(sum(exp(x)))` of the elements of `tensor` along `dim`, where the size of
+    /// `dim` is 1.
+    fn float_logsumexp(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        // log(sum(e^x)) = m + log(sum(e^(x - m))) with m the maximum, so no exponential overflows.
+        let max = B::float_max_dim(tensor.clone(), dim);
+        // The maximum is not finite when the elements are all -inf, contain +inf or NaN, shifting
+        // by it would produce NaN instead of the -inf, +inf or NaN result.
+        let diff = B::float_sub(max.clone(), max.clone());
+        let not_finite = B::float_not_equal_elem(diff, 0f32.elem());
+        let max = B::float_mask_fill(max, not_finite, 0f32.elem());
+
+        let sum = B::float_sum_dim(B::float_exp(B::float_sub(tensor, max.clone())), dim);
+        B::float_add(B::float_log(sum), max)
+    }
+
+    /// Sum of the elements of a tensor selected by a mask along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    /// * `mask` - The mask, with the same shape as `tensor`, where only the elements set to true
+    ///   are summed.
+    /// * `dim` - The dimension along which to sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the sum of the masked elements in `tensor` along `dim`.
+    fn float_sum_masked_dim(
+        tensor: FloatTensor<B>,
+        mask: BoolTensor<B>,
+        dim: usize,
+    ) -> FloatTensor<B> {
+        // Filling the unmasked elements also discards their NaN values.
+        let tensor = B::float_mask_fill(tensor, B::bool_not(mask), 0f32.elem());
+        B::float_sum_dim(tensor, dim)
+    }
+
     /// Converts a tensor to another floating point data type.
     ///
     /// # Arguments
@@ -1014,6 +1204,251 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf(tensor: FloatTensor<B>) -> FloatTensor<B>;
 
+    /// Returns a new tensor with inverse sine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse sine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse sine values.
+    fn float_asin(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::asin::<B>(tensor)
+    }
+
+    /// Returns a new tensor with inverse cosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse cosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse cosine values.
+    fn float_acos(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::acos::<B>(tensor)
+    }
+
+    /// Returns a new tensor with inverse tangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse tangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse tangent values.
+    fn float_atan(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::atan::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the four-quadrant inverse tangent of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The y-coordinates.
+    /// * `rhs` - The x-coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the four-quadrant inverse tangent of `lhs` and `rhs`, broadcast to the same shape.
+    fn float_atan2(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B> {
+        special::atan2::<B>(lhs, rhs)
+    }
+
+    /// Returns a new tensor with inverse hyperbolic sine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse hyperbolic sine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse hyperbolic sine values.
+    fn float_asinh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::asinh::<B>(tensor)
+    }
+
+    /// Returns a new tensor with inverse hyperbolic cosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse hyperbolic cosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse hyperbolic cosine values.
+    fn float_acosh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::acosh::<B>(tensor)
+    }
+
+    /// Returns a new tensor with inverse hyperbolic tangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse hyperbolic tangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse hyperbolic tangent values.
+    fn float_atanh(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::atanh::<B>(tensor)
+    }
+
+    /// Returns a new tensor with inverse error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse error function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse error function values.
+    fn float_erfinv(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::erfinv::<B>(tensor)
+    }
+
+    /// Returns a new tensor with complementary error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the complementary error function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with complementary error function values.
+    fn float_erfc(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        // erfc = 1 - erf
+        B::float_add_scalar(B::float_neg(B::float_erf(tensor)), 1.0.elem())
+    }
+
+    /// Returns a new tensor with exponential minus one values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the exponential minus one of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with exponential minus one values.
+    fn float_expm1(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::expm1::<B>(tensor)
+    }
+
+    /// Returns a new tensor with base 2 logarithm values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the base 2 logarithm of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with base 2 logarithm values.
+    fn float_log2(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        B::float_mul_scalar(B::float_log(tensor), core::f64::consts::LOG2_E.elem())
+    }
+
+    /// Returns a new tensor with base 10 logarithm values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the base 10 logarithm of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with base 10 logarithm values.
+    fn float_log10(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        B::float_mul_scalar(B::float_log(tensor), core::f64::consts::LOG10_E.elem())
+    }
+
+    /// Returns a new tensor with the hypotenuse of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The first tensor.
+    /// * `rhs` - The second tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the hypotenuse of `lhs` and `rhs`, broadcast to the same shape.
+    fn float_hypot(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B> {
+        // hypot = sqrt(lhs^2 + rhs^2)
+        let lhs = B::float_mul(lhs.clone(), lhs);
+        let rhs = B::float_mul(rhs.clone(), rhs);
+        B::float_sqrt(B::float_add(lhs, rhs))
+    }
+
+    /// Returns a new tensor with log-gamma values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the log-gamma of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with log-gamma values.
+    fn float_lgamma(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::lgamma::<B>(tensor)
+    }
+
+    /// Returns a new tensor with digamma values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the digamma of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with digamma values.
+    fn float_digamma(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::digamma::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the values of the polygamma function of order `n`, the `n`-th
+    /// derivative of the digamma function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the polygamma function of.
+    /// * `n` - The order of the derivative.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with polygamma values.
+    fn float_polygamma(tensor: FloatTensor<B>, n: u32) -> FloatTensor<B> {
+        match n {
+            0 => B::float_digamma(tensor),
+            n => special::polygamma::<B>(tensor, n),
+        }
+    }
+
+    /// Returns a new tensor with modified Bessel function of the first kind of order zero values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the modified Bessel function of the first kind of order zero of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with modified Bessel function of the first kind of order zero values.
+    fn float_i0(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::i0::<B>(tensor)
+    }
+
+    /// Returns a new tensor with modified Bessel function of the first kind of order one values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the modified Bessel function of the first kind of order one of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with modified Bessel function of the first kind of order one values.
+    fn float_i1(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::i1::<B>(tensor)
+    }
+
     /// Concatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1342,4 +1777,24 @@ pub trait FloatTensorOps<B: Backend> {
     fn float_argsort(tensor: FloatTensor<B>, dim: usize, descending: bool) -> IntTensor<B> {
         argsort::<B, Float>(TensorPrimitive::Float(tensor), dim, descending)
     }
+
+    /// Samples category indices from the non-negative weights of each row.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The 2D tensor of weights, with shape `[batch_size, num_categories]`. The weights
+    ///   don't need to sum to one.
+    /// * `num_samples` - The number of samples to draw for each row.
+    /// * `replacement` - Whether a category can be drawn more than once.
+    ///
+    /// # Returns
+    ///
+    /// The sampled category indices, with shape `[batch_size, num_samples]`.
+    fn float_multinomial(
+        tensor: FloatTensor<B>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B> {
+        multinomial::<B>(tensor, num_samples, replacement)
+    }
 }
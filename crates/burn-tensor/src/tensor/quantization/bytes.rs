@@ -4,16 +4,17 @@ use crate::{Bytes, Element};
 use alloc::vec::Vec;
 
 use super::{
-    QParams, QuantInputType, QuantLevel, QuantMode, QuantScheme, QuantizationStrategy,
-    SymmetricQuantization,
+    QParams, QuantInputType, QuantMode, QuantScheme, QuantizationStrategy, pack_i4s_to_u32s,
+    unpack_u32s_to_i4s,
 };
 
 /// Quantized data bytes representation.
 ///
 /// # Notes
 /// 1) The quantized values are packed into 32-bit unsigned integers. For example, int8
-///    quantized values pack 4 grouped values into a single `u32`. When unpacking these values,
-///    we make sure to retrieve only the meaningful values (and ignore the alignment padding).
+///    quantized values pack 4 grouped values into a single `u32`, and int4 quantized values
+///    pack 8 grouped values. When unpacking these values, we make sure to retrieve only the
+///    meaningful values (and ignore the alignment padding).
 /// 2) Quantization parameters are appended to the tensor data.
 ///    As such, the last bytes always correspond to the scale parameter.
 ///    If the quantization scheme includes an offset (zero-point) parameter, it is next to last.
@@ -33,34 +34,33 @@ impl QuantizedBytes {
         let num_elements = value.len();
         let scheme = strategy.scheme();
 
-        match strategy {
-            QuantizationStrategy::PerTensorSymmetricInt8(quant) => {
-                if TypeId::of::<E>() == TypeId::of::<i8>() {
-                    // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
-                    let i8s: Vec<i8> = bytemuck::allocation::cast_vec(value);
-                    bytes = Bytes::from_elems(i8s);
-                } else {
-                    panic!("Invalid quantized type");
-                }
-                let scale_bytes = bytemuck::bytes_of(&quant.scale);
-                bytes.extend_from_byte_slice_aligned(scale_bytes, align_of::<f32>());
-            }
-            QuantizationStrategy::PerBlockSymmetricInt8(quant, _block_size) => {
-                if TypeId::of::<E>() == TypeId::of::<i8>() {
-                    // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
-                    let i8s: Vec<i8> = bytemuck::allocation::cast_vec(value);
-                    bytes = Bytes::from_elems(i8s);
-                } else {
-                    panic!("Invalid quantized type");
+        if TypeId::of::<E>() == TypeId::of::<i8>() {
+            // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
+            let i8s: Vec<i8> = bytemuck::allocation::cast_vec(value);
+            bytes = match scheme.q_type {
+                QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+                    let packed = pack_i4s_to_u32s(&i8s);
+                    Bytes::from_elems(
+                        packed
+                            .into_iter()
+                            .flat_map(u32::to_le_bytes)
+                            .collect::<Vec<u8>>(),
+                    )
                 }
+                _ => Bytes::from_elems(i8s),
+            };
+        } else {
+            panic!("Invalid quantized type");
+        }
 
-                let mut scale_bytes = Vec::with_capacity(quant.len() * size_of::<f32>());
-                for q in quant {
-                    scale_bytes.extend_from_slice(bytemuck::bytes_of(&q.scale));
-                }
-                bytes.extend_from_byte_slice_aligned(scale_bytes.as_slice(), align_of::<f32>());
-            }
+        // The offsets are next to last, followed by the scales
+        let qparams = strategy.qparams();
+        let params = qparams.offsets.into_iter().flatten().chain(qparams.scales);
+        let mut params_bytes = Vec::new();
+        for param in params {
+            params_bytes.extend_from_slice(bytemuck::bytes_of(&param));
         }
+        bytes.extend_from_byte_slice_aligned(params_bytes.as_slice(), align_of::<f32>());
 
         Self {
             bytes,
@@ -69,39 +69,63 @@ impl QuantizedBytes {
         }
     }
 
-    /// Returns the int8 quantized values with the quantization parameters.
+    /// Returns the quantized values with the quantization parameters.
+    ///
+    /// The 4-bit values are unpacked to a single value per `i8`.
     pub fn into_vec_i8(self) -> (Vec<i8>, QParams<Vec<f32>>) {
-        let (values, (qparams, num_params)) = self.split_values_off();
+        let scheme = self.scheme;
+        let num_elements = self.num_elements;
+        let (values, qparams) = self.split_values_off();
 
         // Quantization parameters are added at the end of the tensor data.
-        // As such, the last bytes always correspond to the scale parameter(s).
+        // As such, the last bytes always correspond to the scale parameter(s), preceded by the
+        // offset parameter(s) for affine quantization.
         // For example, per-block quantization can have multiple parameters for a single tensor:
-        // [scale, scale, scale, ...]
-        let scale_size = core::mem::size_of::<f32>(); // scale is stored as f32
-        let qparams_bytes: &[u8] = bytemuck::cast_slice(&qparams);
-        let total_bytes = qparams_bytes.len();
-
-        let scales_size = scale_size * num_params;
+        // [offset, offset, offset, ..., scale, scale, scale, ...]
+        let qparams: &[f32] = bytemuck::cast_slice(&qparams);
+        let qparams = match scheme.mode {
+            QuantMode::Symmetric => QParams {
+                scales: qparams.to_vec(),
+                offsets: None,
+            },
+            QuantMode::Affine => {
+                let (offsets, scales) = qparams.split_at(qparams.len() / 2);
+                QParams {
+                    scales: scales.to_vec(),
+                    offsets: Some(offsets.to_vec()),
+                }
+            }
+        };
 
-        let scales = bytemuck::cast_slice(&qparams_bytes[total_bytes - scales_size..]).to_vec();
+        let values = match scheme.q_type {
+            QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+                let packed: Vec<u32> = bytemuck::cast_slice::<i8, u8>(&values)
+                    .chunks_exact(4)
+                    .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+                    .collect();
+                let signed = scheme.q_type == QuantInputType::QInt4;
+                unpack_u32s_to_i4s(&packed, num_elements, signed)
+            }
+            _ => values,
+        };
 
-        (values, QParams { scales })
+        (values, qparams)
     }
 
     /// Splits the quantized values of the tensor from the quantization parameters.
     ///
     /// Returns the packed values and a newly allocated vector containing the quantization parameters.
-    fn split_values_off(self) -> (Vec<i8>, (Vec<u32>, usize)) {
+    fn split_values_off(self) -> (Vec<i8>, Vec<u32>) {
         let mut values = self.bytes.try_into_vec::<i8>().unwrap();
 
-        let num_params = match self.scheme.level {
-            QuantLevel::Tensor => 1,
-            QuantLevel::Block(block_size) => self.num_elements / block_size,
+        // The 4-bit values are packed in u32, and the other values take a single byte
+        let values_end = match self.scheme.q_type {
+            QuantInputType::QInt4 | QuantInputType::QUInt4 => {
+                self.num_elements.div_ceil(8) * size_of::<u32>()
+            }
+            _ => self.num_elements,
         };
 
-        let scale_size = num_params * size_of::<f32>();
-        let values_end = values.len() - scale_size;
-
         let qparams = values.split_off(values_end);
 
         let qparams = if qparams.as_ptr() as usize % 4 == 0 {
@@ -125,43 +149,16 @@ impl QuantizedBytes {
             }
         };
 
-        (values, (qparams, num_params))
+        (values, qparams)
     }
 
-    /// Dequantizes the data according to its quantization scheme.
-    pub fn dequantize(self) -> (Vec<f32>, QParams<Vec<f32>>) {
-        match self.scheme {
-            QuantScheme {
-                level: QuantLevel::Tensor,
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => {
-                let (values, qparams) = self.into_vec_i8();
-                let strategy = QuantizationStrategy::PerTensorSymmetricInt8(
-                    SymmetricQuantization::init(qparams.scales[0]),
-                );
-                (strategy.dequantize(&values), qparams)
-            }
-            QuantScheme {
-                level: QuantLevel::Block(block_size),
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                ..
-            } => {
-                let (values, qparams) = self.into_vec_i8();
-                assert_eq!(values.len() / qparams.scales.len(), block_size);
-                let strategy = QuantizationStrategy::PerBlockSymmetricInt8(
-                    qparams
-                        .scales
-                        .iter()
-                        .map(|&s| SymmetricQuantization::init(s))
-                        .collect(),
-                    block_size,
-                );
-                (strategy.dequantize(&values), qparams)
-            }
-        }
+    /// Dequantizes the data of the given shape according to its quantization scheme.
+    pub fn dequantize(self, shape: &[usize]) -> (Vec<f32>, QParams<Vec<f32>>) {
+        let scheme = self.scheme;
+        let (values, qparams) = self.into_vec_i8();
+
+        let strategy = QuantizationStrategy::from_qparams(&scheme, &qparams, shape);
+        (strategy.dequantize(&values), qparams)
     }
 }
 
@@ -169,6 +166,7 @@ impl QuantizedBytes {
 mod tests {
 
     use super::*;
+    use crate::quantization::{IntQuantization, ScaledFloat8Quantization, SymmetricQuantization};
     use alloc::vec;
 
     #[test]
@@ -188,4 +186,72 @@ mod tests {
 
         assert_eq!(q_values, values);
     }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_per_block_e4m3() {
+        let scales = [0.5, 0.25];
+        let values = vec![0i8, 0x38, 0x40, 0x48, -0x38, 0x7E];
+
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerBlockScaledE4M3(
+                scales
+                    .iter()
+                    .map(|&s| ScaledFloat8Quantization::init(s))
+                    .collect(),
+                3,
+            ),
+        );
+
+        let (q_values, qparams) = q_bytes.dequantize(&[6]);
+
+        assert_eq!(qparams.scales, scales.to_vec());
+        assert_eq!(q_values, vec![0.0, 0.5, 1.0, 1.0, -1.0, 112.0]);
+    }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_per_channel_affine_int4() {
+        let values = vec![-8i8, 7, -1, 0, 1, 2, 3, 4, -3];
+
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerChannelInt(
+                vec![
+                    IntQuantization::affine(0.5, 1, QuantInputType::QInt4),
+                    IntQuantization::affine(0.25, -2, QuantInputType::QInt4),
+                    IntQuantization::affine(2.0, 0, QuantInputType::QInt4),
+                ],
+                0,
+                3,
+            ),
+        );
+
+        // 9 values packed in 2 u32, followed by 3 offsets and 3 scales
+        assert_eq!(q_bytes.bytes.len(), 8 + 12 + 12);
+
+        let (q_values, qparams) = q_bytes.into_vec_i8();
+
+        assert_eq!(qparams.scales, vec![0.5, 0.25, 2.0]);
+        assert_eq!(qparams.offsets, Some(vec![1.0, -2.0, 0.0]));
+        assert_eq!(q_values, values);
+    }
+
+    #[test]
+    fn should_dequantize_per_tensor_affine_uint4() {
+        let values = vec![0i8, 3, 12, 15];
+
+        let q_bytes = QuantizedBytes::new(
+            values,
+            QuantizationStrategy::PerTensorInt(IntQuantization::affine(
+                0.5,
+                3,
+                QuantInputType::QUInt4,
+            )),
+        );
+
+        let (q_values, qparams) = q_bytes.dequantize(&[2, 2]);
+
+        assert_eq!(qparams.offsets, Some(vec![3.0]));
+        assert_eq!(q_values, vec![-1.5, 0.0, 4.5, 6.0]);
+    }
 }
@@ -1,4 +1,13 @@
-use crate::{Tensor, backend::Backend};
+use alloc::{vec, vec::Vec};
+use burn_common::reader::try_read_sync;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{
+    Shape, Tensor, TensorData, TensorMetadata, TensorPrimitive, backend::Backend, ops::FloatTensor,
+};
+
+use super::{QuantLevel, QuantMode, QuantScheme, QuantizationParameters};
 
 /// The observed input calibration range.
 #[derive(Clone, Debug)]
@@ -10,7 +19,624 @@ pub struct CalibrationRange<B: Backend> {
 }
 
 /// Calibration method used to compute the quantization range mapping.
+#[derive(Clone, Debug, PartialEq)]
 pub enum Calibration {
     /// Computes quantization range mapping based on the min and max values.
     MinMax,
+    /// Clips the range to the given percentile (e.g., `99.99`) of the observed values.
+    Percentile(f64),
+    /// Selects the clipping threshold that minimizes the KL divergence between the observed
+    /// distribution of values and its quantized approximation.
+    Entropy,
+    /// Selects the range that minimizes the mean squared quantization error of the observed values.
+    Mse,
+}
+
+impl Calibration {
+    /// Returns true if the calibration method is computed from a histogram of the observed values.
+    pub fn is_histogram(&self) -> bool {
+        !matches!(self, Calibration::MinMax)
+    }
+}
+
+/// Number of bins used by the histogram-based calibration methods.
+const NUM_BINS: usize = 2048;
+
+/// Accumulates statistics over multiple tensors to compute the quantization range mapping.
+///
+/// The min-max calibration keeps track of the running min and max values on the device, while
+/// the histogram-based calibration methods accumulate a histogram of the observed values per
+/// quantization group (e.g., per block or per channel).
+pub struct CalibrationObserver<B: Backend> {
+    scheme: QuantScheme,
+    calibration: Calibration,
+    state: Option<ObserverState<B>>,
+}
+
+enum ObserverState<B: Backend> {
+    MinMax(CalibrationRange<B>),
+    Histogram {
+        histograms: Vec<Histogram>,
+        device: B::Device,
+    },
+}
+
+impl<B: Backend> CalibrationObserver<B> {
+    /// Create a new observer for the given quantization scheme and calibration method.
+    pub fn new(scheme: QuantScheme, calibration: Calibration) -> Self {
+        if let Calibration::Percentile(percentile) = calibration {
+            assert!(
+                percentile > 0.0 && percentile <= 100.0,
+                "Percentile must be in range (0, 100], got {percentile}"
+            );
+        }
+
+        Self {
+            scheme,
+            calibration,
+            state: None,
+        }
+    }
+
+    /// Observe the values of the given tensor.
+    pub fn observe<const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        match &tensor.primitive {
+            TensorPrimitive::Float(tensor) => self.observe_primitive(tensor.clone()),
+            TensorPrimitive::QFloat(_) => panic!("Cannot observe the values of a quantized tensor"),
+        }
+    }
+
+    pub(crate) fn observe_primitive(&mut self, tensor: FloatTensor<B>) {
+        if !self.calibration.is_histogram() {
+            let (min, max) = self
+                .scheme
+                .compute_range_primitive::<B>(tensor, &Calibration::MinMax);
+            let min = Tensor::from_primitive(TensorPrimitive::Float(min));
+            let max = Tensor::from_primitive(TensorPrimitive::Float(max));
+
+            self.state = Some(match self.state.take() {
+                Some(ObserverState::MinMax(range)) => ObserverState::MinMax(CalibrationRange {
+                    min: range.min.min_pair(min),
+                    max: range.max.max_pair(max),
+                }),
+                _ => ObserverState::MinMax(CalibrationRange { min, max }),
+            });
+            return;
+        }
+
+        let shape = tensor.shape();
+        let device = B::float_device(&tensor);
+        let data = try_read_sync(B::float_into_data(tensor))
+            .expect("Failed to synchronously read tensor data for histogram calibration.");
+        let groups = group_values(&self.scheme.level, &shape, &data);
+
+        if !matches!(self.state, Some(ObserverState::Histogram { .. })) {
+            self.state = Some(ObserverState::Histogram {
+                histograms: vec![Histogram::new(NUM_BINS); groups.len()],
+                device,
+            });
+        }
+        let Some(ObserverState::Histogram { histograms, .. }) = &mut self.state else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            histograms.len(),
+            groups.len(),
+            "Observed tensor {shape:?} has a different number of quantization groups"
+        );
+
+        for (histogram, values) in histograms.iter_mut().zip(groups) {
+            histogram.observe(&values);
+        }
+    }
+
+    /// Compute the quantization range mapping from the observed values.
+    pub fn range(&self) -> CalibrationRange<B> {
+        match &self.state {
+            Some(ObserverState::MinMax(range)) => range.clone(),
+            Some(ObserverState::Histogram { histograms, device }) => {
+                let num_levels = self.num_levels();
+                let (min, max): (Vec<f32>, Vec<f32>) = histograms
+                    .iter()
+                    .map(|histogram| match self.calibration {
+                        Calibration::Percentile(percentile) => {
+                            histogram.percentile_range(percentile)
+                        }
+                        Calibration::Entropy => histogram.entropy_range(num_levels / 2),
+                        Calibration::Mse => histogram
+                            .mse_range(num_levels, self.scheme.mode == QuantMode::Symmetric),
+                        Calibration::MinMax => unreachable!(),
+                    })
+                    .unzip();
+                let shape = [min.len()];
+
+                CalibrationRange {
+                    min: Tensor::from_data(TensorData::new(min, shape), device),
+                    max: Tensor::from_data(TensorData::new(max, shape), device),
+                }
+            }
+            None => panic!("No values were observed to compute the calibration range"),
+        }
+    }
+
+    /// Compute the quantization parameters from the observed values.
+    pub fn compute_q_params(&self) -> QuantizationParameters<B> {
+        self.scheme.compute_q_params(self.range())
+    }
+
+    /// Number of quantization levels, approximated by a uniform 8-bit grid for floating point types.
+    fn num_levels(&self) -> usize {
+        if self.scheme.q_type.is_int() {
+            let (a, b) = self.scheme.q_type.range(self.scheme.mode);
+            (b - a + 1) as usize
+        } else {
+            256
+        }
+    }
+}
+
+/// Split the values of a tensor into their quantization groups.
+fn group_values(level: &QuantLevel, shape: &Shape, data: &TensorData) -> Vec<Vec<f32>> {
+    let values = data.iter::<f32>();
+    let numel = shape.num_elements();
+
+    match level {
+        QuantLevel::Tensor => vec![values.collect()],
+        QuantLevel::Block(block_size) => {
+            assert_eq!(
+                numel % block_size,
+                0,
+                "Tensor {shape:?} must be evenly divisible by block size {block_size}"
+            );
+            let values = values.collect::<Vec<_>>();
+            values
+                .chunks(*block_size)
+                .map(|block| block.to_vec())
+                .collect()
+        }
+        QuantLevel::Channel(axis) => {
+            let num_channels = shape.dims[*axis];
+            let stride = shape.dims[axis + 1..].iter().product::<usize>();
+            let mut channels = vec![Vec::with_capacity(numel / num_channels); num_channels];
+            for (i, value) in values.enumerate() {
+                channels[(i / stride) % num_channels].push(value);
+            }
+            channels
+        }
+    }
+}
+
+/// Histogram of observed values with uniformly spaced bins.
+///
+/// The range of the histogram grows to include all observed values, in which case the existing
+/// counts are redistributed over the new bins assuming values are uniformly distributed within
+/// each bin. Non-finite values are ignored.
+#[derive(Clone, Debug)]
+pub struct Histogram {
+    counts: Vec<f64>,
+    range: Option<(f64, f64)>,
+}
+
+impl Histogram {
+    /// Create an empty histogram with the given number of bins.
+    pub fn new(num_bins: usize) -> Self {
+        assert!(num_bins > 0, "Histogram must have at least one bin");
+        Self {
+            counts: vec![0.0; num_bins],
+            range: None,
+        }
+    }
+
+    /// Add the given values to the histogram.
+    pub fn observe(&mut self, values: &[f32]) {
+        let (min, max) = values
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value as f64), max.max(value as f64))
+            });
+
+        if min > max {
+            return;
+        }
+
+        match self.range {
+            None => self.range = Some((min, max)),
+            Some((current_min, current_max)) if min < current_min || max > current_max => {
+                self.rebin(min.min(current_min), max.max(current_max))
+            }
+            _ => {}
+        }
+
+        for value in values.iter().filter(|value| value.is_finite()) {
+            let index = self.bin_index(*value as f64);
+            self.counts[index] += 1.0;
+        }
+    }
+
+    /// The counts of each bin.
+    pub fn counts(&self) -> &[f64] {
+        &self.counts
+    }
+
+    /// The min and max observed values, if any.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        self.range.map(|(min, max)| (min as f32, max as f32))
+    }
+
+    /// Compute the range that excludes the values below the `100 - percentile` and above the
+    /// `percentile` percentiles.
+    pub fn percentile_range(&self, percentile: f64) -> (f32, f32) {
+        let Some((min, max)) = self.range else {
+            return (0.0, 0.0);
+        };
+        let total = self.counts.iter().sum::<f64>();
+        let q = percentile / 100.0;
+
+        let lower = self.quantile((1.0 - q) * total).max(min);
+        let upper = self.quantile(q * total).min(max);
+
+        (lower as f32, upper as f32)
+    }
+
+    /// Compute the range `[-t, t]` (intersected with the observed range) where the threshold `t`
+    /// minimizes the KL divergence between the distribution of magnitudes clipped at `t` and
+    /// its quantized approximation with `num_quant_bins` levels.
+    pub fn entropy_range(&self, num_quant_bins: usize) -> (f32, f32) {
+        let Some((min, max)) = self.range else {
+            return (0.0, 0.0);
+        };
+        let magnitude = min.abs().max(max.abs());
+        let width = self.bin_width();
+        if width == 0.0 || magnitude == 0.0 {
+            return (min as f32, max as f32);
+        }
+
+        // Histogram of the magnitudes, using the same number of bins over `[0, magnitude]`
+        let num_bins = self.counts.len();
+        let abs_width = magnitude / num_bins as f64;
+        let mut magnitudes = vec![0.0; num_bins];
+        for (i, &count) in self.counts.iter().enumerate().filter(|(_, c)| **c > 0.0) {
+            let start = min + i as f64 * width;
+            let end = start + width;
+            if start >= 0.0 {
+                spread(&mut magnitudes, 0.0, abs_width, start, end, count);
+            } else if end <= 0.0 {
+                spread(&mut magnitudes, 0.0, abs_width, -end, -start, count);
+            } else {
+                let negative = count * -start / width;
+                spread(&mut magnitudes, 0.0, abs_width, 0.0, -start, negative);
+                spread(&mut magnitudes, 0.0, abs_width, 0.0, end, count - negative);
+            }
+        }
+
+        let num_quant_bins = num_quant_bins.clamp(1, num_bins);
+        let mut best = (f64::INFINITY, num_bins);
+
+        for threshold in num_quant_bins..=num_bins {
+            // Reference distribution, where the clipped outliers are accumulated in the last bin
+            let mut reference = magnitudes[..threshold].to_vec();
+            reference[threshold - 1] += magnitudes[threshold..].iter().sum::<f64>();
+
+            // Quantized distribution, where each quantized bin is expanded uniformly over the
+            // bins it covers that are non-empty in the reference distribution
+            let mut candidate = vec![0.0; threshold];
+            for q in 0..num_quant_bins {
+                let start = q * threshold / num_quant_bins;
+                let end = (q + 1) * threshold / num_quant_bins;
+                let non_empty = reference[start..end]
+                    .iter()
+                    .filter(|count| **count > 0.0)
+                    .count();
+                if non_empty > 0 {
+                    let value = magnitudes[start..end].iter().sum::<f64>() / non_empty as f64;
+                    for (count, bin) in candidate[start..end].iter_mut().zip(&reference[start..end])
+                    {
+                        if *bin > 0.0 {
+                            *count = value;
+                        }
+                    }
+                }
+            }
+
+            let divergence = kl_divergence(&reference, &candidate);
+            if divergence < best.0 {
+                best = (divergence, threshold);
+            }
+        }
+
+        let threshold = best.1 as f64 * abs_width;
+        (min.max(-threshold) as f32, max.min(threshold) as f32)
+    }
+
+    /// Compute the range that minimizes the mean squared error when quantizing the observed values
+    /// on a uniform grid with `num_levels` levels.
+    ///
+    /// The range is shrunk one bin at a time from the side holding the least values until the
+    /// quantization error stops decreasing.
+    pub fn mse_range(&self, num_levels: usize, symmetric: bool) -> (f32, f32) {
+        let Some((min, max)) = self.range else {
+            return (0.0, 0.0);
+        };
+        if self.bin_width() == 0.0 {
+            return (min as f32, max as f32);
+        }
+
+        let (mut start, mut end) = (0, self.counts.len() - 1);
+        let (mut dropped_start, mut dropped_end) = (0.0, 0.0);
+        let mut best = (
+            self.quantization_error(start, end, num_levels, symmetric),
+            start,
+            end,
+        );
+
+        while start < end {
+            if dropped_start + self.counts[start] < dropped_end + self.counts[end] {
+                dropped_start += self.counts[start];
+                start += 1;
+            } else {
+                dropped_end += self.counts[end];
+                end -= 1;
+            }
+
+            let error = self.quantization_error(start, end, num_levels, symmetric);
+            if error > best.0 {
+                break;
+            }
+            best = (error, start, end);
+        }
+
+        let (_, start, end) = best;
+        (self.bin_edge(start) as f32, self.bin_edge(end + 1) as f32)
+    }
+
+    /// Expected squared error when quantizing the observed values to the range covered by bins
+    /// `[start, end]`.
+    fn quantization_error(
+        &self,
+        start: usize,
+        end: usize,
+        num_levels: usize,
+        symmetric: bool,
+    ) -> f64 {
+        let (mut lower, mut upper) = (self.bin_edge(start), self.bin_edge(end + 1));
+        if symmetric {
+            let magnitude = lower.abs().max(upper.abs());
+            (lower, upper) = (-magnitude, magnitude);
+        }
+
+        // Values within the range have a uniformly distributed rounding error
+        let step = (upper - lower) / (num_levels.max(2) - 1) as f64;
+        let rounding_error = step * step / 12.0;
+
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0.0)
+            .map(|(i, count)| {
+                let center = self.bin_edge(i) + self.bin_width() / 2.0;
+                let error = if center < lower {
+                    (lower - center).powi(2)
+                } else if center > upper {
+                    (center - upper).powi(2)
+                } else {
+                    rounding_error
+                };
+                count * error
+            })
+            .sum()
+    }
+
+    /// Value at which the cumulative count reaches the target count.
+    fn quantile(&self, target: f64) -> f64 {
+        let width = self.bin_width();
+        let mut cumulative = 0.0;
+        for (i, &count) in self.counts.iter().enumerate() {
+            if count > 0.0 && cumulative + count >= target {
+                let fraction = ((target - cumulative) / count).clamp(0.0, 1.0);
+                return self.bin_edge(i) + fraction * width;
+            }
+            cumulative += count;
+        }
+        self.range.map(|(_, max)| max).unwrap_or(0.0)
+    }
+
+    fn bin_width(&self) -> f64 {
+        self.range
+            .map(|(min, max)| (max - min) / self.counts.len() as f64)
+            .unwrap_or(0.0)
+    }
+
+    fn bin_edge(&self, index: usize) -> f64 {
+        let min = self.range.map(|(min, _)| min).unwrap_or(0.0);
+        min + index as f64 * self.bin_width()
+    }
+
+    fn bin_index(&self, value: f64) -> usize {
+        bin_index(self.bin_edge(0), self.bin_width(), self.counts.len(), value)
+    }
+
+    /// Extend the histogram range and redistribute the existing counts over the new bins.
+    fn rebin(&mut self, min: f64, max: f64) {
+        let width = self.bin_width();
+        let origin = self.bin_edge(0);
+        let num_bins = self.counts.len();
+        let counts = core::mem::replace(&mut self.counts, vec![0.0; num_bins]);
+        self.range = Some((min, max));
+        let new_width = self.bin_width();
+
+        for (i, count) in counts.into_iter().enumerate().filter(|(_, c)| *c > 0.0) {
+            let start = origin + i as f64 * width;
+            spread(
+                &mut self.counts,
+                min,
+                new_width,
+                start,
+                start + width,
+                count,
+            );
+        }
+    }
+}
+
+fn bin_index(origin: f64, width: f64, num_bins: usize, value: f64) -> usize {
+    if width == 0.0 {
+        return 0;
+    }
+    (((value - origin) / width).max(0.0) as usize).min(num_bins - 1)
+}
+
+/// Add the count of the interval `[start, end)` to the bins it overlaps, proportionally to the
+/// overlap.
+fn spread(counts: &mut [f64], origin: f64, width: f64, start: f64, end: f64, count: f64) {
+    let num_bins = counts.len();
+    let first = bin_index(origin, width, num_bins, start);
+    if end <= start || width == 0.0 {
+        counts[first] += count;
+        return;
+    }
+    let last = bin_index(origin, width, num_bins, end);
+
+    let mut remaining = count;
+    for (index, bin) in counts.iter_mut().enumerate().take(last + 1).skip(first) {
+        let lower = start.max(origin + index as f64 * width);
+        let upper = if index == last {
+            end
+        } else {
+            end.min(origin + (index + 1) as f64 * width)
+        };
+        let value = (count * (upper - lower) / (end - start)).min(remaining);
+        *bin += value;
+        remaining -= value;
+    }
+
+    // Rounding leftovers are added to the last overlapped bin
+    counts[last] += remaining.max(0.0);
+}
+
+fn kl_divergence(reference: &[f64], candidate: &[f64]) -> f64 {
+    let (Some(reference), Some(candidate)) = (smooth(reference), smooth(candidate)) else {
+        return f64::INFINITY;
+    };
+
+    reference
+        .iter()
+        .zip(&candidate)
+        .filter(|(p, _)| **p > 0.0)
+        .map(|(p, q)| p * (p / q).ln())
+        .sum()
+}
+
+/// Normalize the distribution and move a small probability mass to the empty bins, so that the
+/// KL divergence is always defined.
+fn smooth(distribution: &[f64]) -> Option<Vec<f64>> {
+    const EPSILON: f64 = 1e-4;
+
+    let total = distribution.iter().sum::<f64>();
+    let num_empty = distribution.iter().filter(|count| **count == 0.0).count();
+    let num_non_empty = distribution.len() - num_empty;
+    if num_non_empty == 0 {
+        return None;
+    }
+
+    let removed = EPSILON * num_empty as f64 / num_non_empty as f64;
+    Some(
+        distribution
+            .iter()
+            .map(|count| {
+                if *count == 0.0 {
+                    EPSILON
+                } else {
+                    count / total - removed
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_should_rebin_when_range_grows() {
+        let mut histogram = Histogram::new(4);
+        histogram.observe(&[0.0, 1.0, 2.0, 4.0]);
+        histogram.observe(&[-4.0, 8.0]);
+
+        assert_eq!(histogram.range(), Some((-4.0, 8.0)));
+        assert_eq!(histogram.counts().iter().sum::<f64>(), 6.0);
+        assert_eq!(histogram.counts(), &[1.0, 2.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn histogram_should_ignore_non_finite_values() {
+        let mut histogram = Histogram::new(4);
+        histogram.observe(&[f32::NAN, 1.0, f32::INFINITY, 2.0]);
+
+        assert_eq!(histogram.range(), Some((1.0, 2.0)));
+        assert_eq!(histogram.counts().iter().sum::<f64>(), 2.0);
+    }
+
+    #[test]
+    fn percentile_range_should_clip_outliers() {
+        let mut histogram = Histogram::new(1000);
+        let mut values = (0..1000).map(|i| i as f32 / 1000.0).collect::<Vec<_>>();
+        values.push(100.0);
+        histogram.observe(&values);
+
+        let (min, max) = histogram.percentile_range(99.0);
+
+        // The 1st and 99th percentiles of the values, where the outlier is the 100th percentile
+        assert!((min - 0.01).abs() < 2e-3, "{min}");
+        assert!((max - 0.99).abs() < 2e-3, "{max}");
+    }
+
+    #[test]
+    fn entropy_range_should_clip_outliers() {
+        let mut histogram = Histogram::new(512);
+        let mut values = (0..10_000)
+            .map(|i| (i as f32 / 10_000.0 - 0.5) * 2.0)
+            .collect::<Vec<_>>();
+        values.extend([-50.0, 50.0]);
+        histogram.observe(&values);
+
+        let (min, max) = histogram.entropy_range(128);
+
+        // The outliers are clipped to the smallest threshold, which covers 128 of the 512 bins
+        assert_eq!((min, max), (-12.5, 12.5));
+    }
+
+    #[test]
+    fn mse_range_should_clip_tails() {
+        let mut histogram = Histogram::new(2048);
+        // Laplace distribution with unit scale, sampled from its quantile function
+        let values = (0..10_000)
+            .map(|i| {
+                let u = (i as f64 + 0.5) / 10_000.0 - 0.5;
+                (-u.signum() * (1.0 - 2.0 * u.abs()).ln()) as f32
+            })
+            .collect::<Vec<_>>();
+        histogram.observe(&values);
+
+        let (min, max) = histogram.mse_range(15, true);
+
+        // The mean squared error of the 4-bit quantization of the values is minimized by clipping
+        // them at about 4.79, while they range from -9.21 to 9.21
+        assert!((min + 4.79).abs() < 5e-2, "{min}");
+        assert!((max - 4.79).abs() < 5e-2, "{max}");
+    }
+
+    #[test]
+    fn mse_range_should_keep_range_without_outliers() {
+        let mut histogram = Histogram::new(64);
+        let values = (0..1000).map(|i| i as f32 / 1000.0).collect::<Vec<_>>();
+        histogram.observe(&values);
+
+        let (min, max) = histogram.mse_range(256, false);
+
+        assert_eq!(min, 0.0);
+        assert!((max - 0.999).abs() < 1e-6, "{max}");
+    }
 }
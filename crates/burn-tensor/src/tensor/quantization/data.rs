@@ -82,6 +82,43 @@ pub fn unpack_u32s_to_i8s(values: Vec<u32>, numel: usize) -> Vec<i8> {
     }
 }
 
+/// Pack 4-bit integer values, signed or unsigned, into a sequence of unsigned 32-bit integers.
+///
+/// The first value is stored in the least significant bits, and the last `u32` is padded with
+/// zeros when the number of values is not a factor of 8.
+pub fn pack_i4s_to_u32s(values: &[i8]) -> Vec<u32> {
+    values
+        .chunks(8)
+        .map(|x| {
+            x.iter()
+                .enumerate()
+                .fold(0u32, |acc, (i, x)| acc | ((*x as u32 & 0xF) << (i * 4)))
+        })
+        .collect()
+}
+
+/// Unpack 32-bit unsigned integer values into a sequence of 4-bit integers, sign-extended to
+/// 8 bits for signed values.
+pub fn unpack_u32s_to_i4s(values: &[u32], numel: usize, signed: bool) -> Vec<i8> {
+    values
+        .iter()
+        .enumerate()
+        .flat_map(|(i, packed)| {
+            // A single u32 could contain less than eight 4-bit values...
+            let n = core::cmp::min(8, numel - i * 8);
+            (0..n).map(move |i| {
+                let value = ((packed >> (i * 4)) & 0xF) as i8;
+                if signed {
+                    // Move the sign bit to the MSB, then shift back to restore the sign
+                    (value << 4) >> 4
+                } else {
+                    value
+                }
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,4 +153,20 @@ mod tests {
 
         assert_eq!(unpacked, vec![55]);
     }
+
+    #[test]
+    fn should_pack_unpack_i4s_padded() {
+        let values = vec![-8, 7, -1, 0, 1, 2, 3, 4, -3];
+        let packed = pack_i4s_to_u32s(&values);
+
+        assert_eq!(packed, vec![0x4321_0F78, 0xD]);
+        assert_eq!(unpack_u32s_to_i4s(&packed, values.len(), true), values);
+    }
+
+    #[test]
+    fn should_unpack_u32s_to_unsigned_i4s() {
+        let unpacked = unpack_u32s_to_i4s(&[0xF0A1], 4, false);
+
+        assert_eq!(unpacked, vec![1, 10, 0, 15]);
+    }
 }
@@ -9,6 +9,8 @@ pub type QuantizationParameters<B> = QParams<Tensor<B, 1>>;
 pub struct QParams<S> {
     /// The scaling factor.
     pub scales: S,
+    /// The zero-point offset, only used by affine quantization.
+    pub offsets: Option<S>,
 }
 
 /// The quantization parameters primitive.
@@ -23,12 +25,15 @@ pub struct QParams<S> {
 pub struct QuantizationParametersPrimitive<B: Backend> {
     /// The scaling factor.
     pub scales: B::FloatTensorPrimitive,
+    /// The zero-point offset, only used by affine quantization.
+    pub offsets: Option<B::FloatTensorPrimitive>,
 }
 
 impl<B: Backend> From<QuantizationParameters<B>> for QuantizationParametersPrimitive<B> {
     fn from(value: QuantizationParameters<B>) -> Self {
         QuantizationParametersPrimitive {
             scales: value.scales.primitive.tensor(),
+            offsets: value.offsets.map(|offsets| offsets.primitive.tensor()),
         }
     }
 }
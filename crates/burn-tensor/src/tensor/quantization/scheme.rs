@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Shape, Tensor, TensorMetadata, TensorPrimitive, backend::Backend};
+use crate::{
+    ElementLimits, Shape, Tensor, TensorMetadata, TensorPrimitive, backend::Backend, e4m3, e5m2,
+};
 
 use super::{
-    Calibration, CalibrationRange, QuantizationParameters, QuantizationParametersPrimitive,
+    Calibration, CalibrationObserver, CalibrationRange, QuantizationParameters,
+    QuantizationParametersPrimitive,
 };
 
 /// Describes a quantization scheme/configuration.
@@ -77,7 +80,8 @@ impl QuantScheme {
     /// Returns the size of the quantization input type in bits.
     pub fn bits_type(&self) -> usize {
         match self.q_type {
-            QuantInputType::QInt8 => 8,
+            QuantInputType::QInt8 | QuantInputType::E4M3 | QuantInputType::E5M2 => 8,
+            QuantInputType::QInt4 | QuantInputType::QUInt4 => 4,
         }
     }
 
@@ -97,6 +101,8 @@ pub enum QuantLevel {
     Tensor,
     /// Quantize a tensor using multiple 1D linear blocks.
     Block(usize),
+    /// Quantize a tensor using separate parameters for each channel along the given axis.
+    Channel(usize),
 }
 
 /// Data type used to represent quantized values.
@@ -104,6 +110,46 @@ pub enum QuantLevel {
 pub enum QuantInputType {
     /// 8-bit signed integer.
     QInt8,
+    /// 4-bit signed integer.
+    QInt4,
+    /// 4-bit unsigned integer.
+    QUInt4,
+    /// 8-bit floating point with 4 exponent bits and 3 mantissa bits.
+    E4M3,
+    /// 8-bit floating point with 5 exponent bits and 2 mantissa bits.
+    E5M2,
+}
+
+impl QuantInputType {
+    /// Returns the range `[a, b]` of the quantized integer values for the quantization mode.
+    ///
+    /// # Panics
+    ///
+    /// If the type is not an integer type, or if an unsigned type is used with symmetric
+    /// quantization.
+    pub fn range(&self, mode: QuantMode) -> (i32, i32) {
+        match (self, mode) {
+            (QuantInputType::QInt8, QuantMode::Symmetric) => (-(i8::MAX as i32), i8::MAX as i32),
+            (QuantInputType::QInt8, QuantMode::Affine) => (i8::MIN as i32, i8::MAX as i32),
+            (QuantInputType::QInt4, QuantMode::Symmetric) => (-7, 7),
+            (QuantInputType::QInt4, QuantMode::Affine) => (-8, 7),
+            (QuantInputType::QUInt4, QuantMode::Affine) => (0, 15),
+            (QuantInputType::QUInt4, QuantMode::Symmetric) => {
+                panic!("Symmetric quantization requires a signed type, got {self:?}")
+            }
+            (QuantInputType::E4M3 | QuantInputType::E5M2, _) => {
+                panic!("Expected an integer quantization type, got {self:?}")
+            }
+        }
+    }
+
+    /// Returns true if the type is an integer type.
+    pub fn is_int(&self) -> bool {
+        matches!(
+            self,
+            QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4
+        )
+    }
 }
 
 /// Data type used to stored quantized values.
@@ -122,6 +168,8 @@ pub enum QuantStoreType {
 pub enum QuantMode {
     /// Symmetric or scale quantization.
     Symmetric,
+    /// Affine or asymmetric quantization, with a scale and a zero-point offset.
+    Affine,
 }
 
 /// Quantization accumulator precision. This is the precision to used when accumulating values
@@ -193,7 +241,34 @@ impl QuantScheme {
                         B::float_reshape(B::float_max_dim(blocks, 1), Shape::new([num_blocks]));
                     (blocks_min, blocks_max)
                 }
+                QuantLevel::Channel(axis) => {
+                    let shape = tensor.shape();
+                    let num_channels = shape.dims[axis];
+                    let numel = shape.num_elements();
+
+                    // Move the channel axis first, so that each row contains the values of a channel
+                    let channels = B::float_reshape(
+                        B::float_swap_dims(tensor, 0, axis),
+                        Shape::new([num_channels, numel / num_channels]),
+                    );
+                    let channels_min = B::float_reshape(
+                        B::float_min_dim(channels.clone(), 1),
+                        Shape::new([num_channels]),
+                    );
+                    let channels_max =
+                        B::float_reshape(B::float_max_dim(channels, 1), Shape::new([num_channels]));
+                    (channels_min, channels_max)
+                }
             },
+            Calibration::Percentile(_) | Calibration::Entropy | Calibration::Mse => {
+                let mut observer = CalibrationObserver::<B>::new(*self, calibration.clone());
+                observer.observe_primitive(tensor);
+                let range = observer.range();
+                (
+                    range.min.into_primitive().tensor(),
+                    range.max.into_primitive().tensor(),
+                )
+            }
         }
     }
 
@@ -204,22 +279,82 @@ impl QuantScheme {
     ) -> QuantizationParameters<B> {
         match self {
             QuantScheme {
-                level: QuantLevel::Tensor | QuantLevel::Block(_),
                 mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
+                q_type: q_type @ (QuantInputType::QInt8 | QuantInputType::QInt4),
                 ..
             } => {
                 // Quantized range `[a, b]`
-                let b = i8::MAX as i32;
-                let a = -b;
+                let (a, b) = q_type.range(QuantMode::Symmetric);
 
                 // Compute scale to convert an input value in range `[-alpha, alpha]`
                 let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
 
                 QuantizationParameters {
                     scales: values_range.div_scalar(b - a),
+                    offsets: None,
+                }
+            }
+            QuantScheme {
+                mode: QuantMode::Affine,
+                q_type:
+                    q_type @ (QuantInputType::QInt8 | QuantInputType::QInt4 | QuantInputType::QUInt4),
+                ..
+            } => {
+                // Quantized range `[a, b]`
+                let (a, b) = q_type.range(QuantMode::Affine);
+
+                // The input range `[alpha, beta]` must include zero so that it is exactly representable
+                let alpha = range.min.clamp_max(0);
+                let beta = range.max.clamp_min(0);
+
+                // Compute scale to convert an input value in range `[alpha, beta]`, with an arbitrary
+                // scale for a range of zeros to avoid a division by zero
+                let scales = (beta - alpha.clone()).div_scalar(b - a);
+                let scales = scales.clone().mask_fill(scales.equal_elem(0), 0.1);
+
+                // Compute the offset to map `alpha` to `a`
+                let offsets = alpha
+                    .div(scales.clone())
+                    .neg()
+                    .add_scalar(a)
+                    .round()
+                    .clamp(a, b);
+
+                QuantizationParameters {
+                    scales,
+                    offsets: Some(offsets),
+                }
+            }
+            QuantScheme {
+                mode: QuantMode::Symmetric,
+                q_type: q_type @ (QuantInputType::E4M3 | QuantInputType::E5M2),
+                ..
+            } => {
+                // Compute scale to map the largest magnitude to the largest finite value
+                let max = match q_type {
+                    QuantInputType::E4M3 => e4m3::MAX.to_f32(),
+                    _ => e5m2::MAX.to_f32(),
+                };
+                let values_range = range.min.abs().max_pair(range.max.abs());
+
+                QuantizationParameters {
+                    scales: values_range.div_scalar(max),
+                    offsets: None,
                 }
             }
+            QuantScheme {
+                mode: QuantMode::Symmetric,
+                q_type: QuantInputType::QUInt4,
+                ..
+            } => panic!(
+                "Symmetric quantization requires a signed type, got {:?}",
+                self.q_type
+            ),
+            QuantScheme {
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::E4M3 | QuantInputType::E5M2,
+                ..
+            } => panic!("Affine quantization is not supported for {:?}", self.q_type),
         }
     }
 
@@ -1,11 +1,13 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::marker::PhantomData;
 use num_traits::{Float, PrimInt, Signed};
 use serde::{Deserialize, Serialize};
 
+use crate::{Element, e4m3, e5m2};
+
 use super::{
-    QuantAccPrecision, QuantInputType, QuantLevel, QuantMode, QuantPropagation, QuantScheme,
-    QuantStoreType,
+    QParams, QuantAccPrecision, QuantInputType, QuantLevel, QuantMode, QuantPropagation,
+    QuantScheme, QuantStoreType,
 };
 
 /// Quantization strategy.
@@ -15,26 +17,47 @@ pub enum QuantizationStrategy {
     PerTensorSymmetricInt8(SymmetricQuantization<f32, i8>),
     /// Per-block `int8` symmetric quantization.
     PerBlockSymmetricInt8(Vec<SymmetricQuantization<f32, i8>>, usize),
+    /// Per-tensor scaled `e4m3` quantization.
+    PerTensorScaledE4M3(ScaledFloat8Quantization<e4m3>),
+    /// Per-block scaled `e4m3` quantization.
+    PerBlockScaledE4M3(Vec<ScaledFloat8Quantization<e4m3>>, usize),
+    /// Per-tensor scaled `e5m2` quantization.
+    PerTensorScaledE5M2(ScaledFloat8Quantization<e5m2>),
+    /// Per-block scaled `e5m2` quantization.
+    PerBlockScaledE5M2(Vec<ScaledFloat8Quantization<e5m2>>, usize),
+    /// Per-tensor integer quantization, which supports affine quantization and 4-bit types.
+    PerTensorInt(IntQuantization),
+    /// Per-block integer quantization.
+    PerBlockInt(Vec<IntQuantization>, usize),
+    /// Per-channel integer quantization, with the channel axis and its stride (the number of
+    /// consecutive values in the same channel).
+    PerChannelInt(Vec<IntQuantization>, usize, usize),
 }
 
 impl QuantizationStrategy {
     /// Quantize the values to a lower precision data type.
+    ///
+    /// The 8-bit floating point values are returned as their bits.
     pub fn quantize(&self, values: &[f32]) -> Vec<i8> {
         match self {
             QuantizationStrategy::PerTensorSymmetricInt8(strategy) => strategy.quantize(values),
             QuantizationStrategy::PerBlockSymmetricInt8(strategy, block_size) => {
-                let num_blocks = strategy.len();
-                let numel = values.len();
-                assert_eq!(
-                    numel / block_size,
-                    num_blocks,
-                    "Invalid per-block quantization with num blocks {num_blocks} and {numel} values"
-                );
-                values
-                    .chunks(*block_size)
-                    .enumerate()
-                    .flat_map(|(block_id, block)| strategy[block_id].quantize(block))
-                    .collect()
+                per_block(strategy, *block_size, values, |s, block| s.quantize(block))
+            }
+            QuantizationStrategy::PerTensorScaledE4M3(strategy) => strategy.quantize(values),
+            QuantizationStrategy::PerBlockScaledE4M3(strategy, block_size) => {
+                per_block(strategy, *block_size, values, |s, block| s.quantize(block))
+            }
+            QuantizationStrategy::PerTensorScaledE5M2(strategy) => strategy.quantize(values),
+            QuantizationStrategy::PerBlockScaledE5M2(strategy, block_size) => {
+                per_block(strategy, *block_size, values, |s, block| s.quantize(block))
+            }
+            QuantizationStrategy::PerTensorInt(strategy) => strategy.quantize(values),
+            QuantizationStrategy::PerBlockInt(strategy, block_size) => {
+                per_block(strategy, *block_size, values, |s, block| s.quantize(block))
+            }
+            QuantizationStrategy::PerChannelInt(strategy, _axis, stride) => {
+                per_channel(strategy, *stride, values, |s, value| s.quantize_one(value))
             }
         }
     }
@@ -44,47 +67,270 @@ impl QuantizationStrategy {
         match self {
             QuantizationStrategy::PerTensorSymmetricInt8(strategy) => strategy.dequantize(values),
             QuantizationStrategy::PerBlockSymmetricInt8(strategy, block_size) => {
-                let num_blocks = strategy.len();
-                let numel = values.len();
-                assert_eq!(
-                    numel / block_size,
-                    num_blocks,
-                    "Invalid per-block quantization with block size {block_size}, num blocks {num_blocks} and {numel} values"
-                );
-                values
-                    .chunks(*block_size)
-                    .enumerate()
-                    .flat_map(|(block_id, block)| strategy[block_id].dequantize(block))
-                    .collect()
+                per_block(strategy, *block_size, values, |s, block| {
+                    s.dequantize(block)
+                })
+            }
+            QuantizationStrategy::PerTensorScaledE4M3(strategy) => strategy.dequantize(values),
+            QuantizationStrategy::PerBlockScaledE4M3(strategy, block_size) => {
+                per_block(strategy, *block_size, values, |s, block| {
+                    s.dequantize(block)
+                })
+            }
+            QuantizationStrategy::PerTensorScaledE5M2(strategy) => strategy.dequantize(values),
+            QuantizationStrategy::PerBlockScaledE5M2(strategy, block_size) => {
+                per_block(strategy, *block_size, values, |s, block| {
+                    s.dequantize(block)
+                })
+            }
+            QuantizationStrategy::PerTensorInt(strategy) => strategy.dequantize(values),
+            QuantizationStrategy::PerBlockInt(strategy, block_size) => {
+                per_block(strategy, *block_size, values, |s, block| {
+                    s.dequantize(block)
+                })
+            }
+            QuantizationStrategy::PerChannelInt(strategy, _axis, stride) => {
+                per_channel(strategy, *stride, values, |s, value| {
+                    s.dequantize_one(value)
+                })
             }
         }
     }
 }
 
+/// Applies the strategy of each block to the values of the block.
+fn per_block<S, I, O>(
+    strategy: &[S],
+    block_size: usize,
+    values: &[I],
+    func: impl Fn(&S, &[I]) -> Vec<O>,
+) -> Vec<O> {
+    let num_blocks = strategy.len();
+    let numel = values.len();
+    assert_eq!(
+        numel / block_size,
+        num_blocks,
+        "Invalid per-block quantization with block size {block_size}, num blocks {num_blocks} and {numel} values"
+    );
+    values
+        .chunks(block_size)
+        .enumerate()
+        .flat_map(|(block_id, block)| func(&strategy[block_id], block))
+        .collect()
+}
+
+/// Applies the strategy of each channel to the values of the channel, where the channel changes
+/// every `stride` values.
+fn per_channel<S, I: Copy, O>(
+    strategy: &[S],
+    stride: usize,
+    values: &[I],
+    func: impl Fn(&S, I) -> O,
+) -> Vec<O> {
+    let num_channels = strategy.len();
+    let numel = values.len();
+    assert!(
+        numel == 0 || numel % (num_channels * stride) == 0,
+        "Invalid per-channel quantization with stride {stride}, num channels {num_channels} and {numel} values"
+    );
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| func(&strategy[(i / stride) % num_channels], *value))
+        .collect()
+}
+
 impl QuantizationStrategy {
     /// Returns the corresponding quantization scheme.
     pub fn scheme(&self) -> QuantScheme {
-        match self {
-            QuantizationStrategy::PerTensorSymmetricInt8(_) => QuantScheme {
-                level: QuantLevel::Tensor,
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                acc_precision: QuantAccPrecision::Full,
-                propagation: QuantPropagation::Inhibit,
-                q_store_type: QuantStoreType::Native,
-            },
-            QuantizationStrategy::PerBlockSymmetricInt8(_blocks, block_size) => QuantScheme {
-                level: QuantLevel::Block(*block_size),
-                mode: QuantMode::Symmetric,
-                q_type: QuantInputType::QInt8,
-                acc_precision: QuantAccPrecision::Full,
-                propagation: QuantPropagation::Inhibit,
-                q_store_type: QuantStoreType::Native,
-            },
+        let (level, mode, q_type) = match self {
+            QuantizationStrategy::PerTensorSymmetricInt8(_) => (
+                QuantLevel::Tensor,
+                QuantMode::Symmetric,
+                QuantInputType::QInt8,
+            ),
+            QuantizationStrategy::PerBlockSymmetricInt8(_blocks, block_size) => (
+                QuantLevel::Block(*block_size),
+                QuantMode::Symmetric,
+                QuantInputType::QInt8,
+            ),
+            QuantizationStrategy::PerTensorScaledE4M3(_) => (
+                QuantLevel::Tensor,
+                QuantMode::Symmetric,
+                QuantInputType::E4M3,
+            ),
+            QuantizationStrategy::PerBlockScaledE4M3(_blocks, block_size) => (
+                QuantLevel::Block(*block_size),
+                QuantMode::Symmetric,
+                QuantInputType::E4M3,
+            ),
+            QuantizationStrategy::PerTensorScaledE5M2(_) => (
+                QuantLevel::Tensor,
+                QuantMode::Symmetric,
+                QuantInputType::E5M2,
+            ),
+            QuantizationStrategy::PerBlockScaledE5M2(_blocks, block_size) => (
+                QuantLevel::Block(*block_size),
+                QuantMode::Symmetric,
+                QuantInputType::E5M2,
+            ),
+            QuantizationStrategy::PerTensorInt(strategy) => {
+                (QuantLevel::Tensor, strategy.mode, strategy.q_type)
+            }
+            QuantizationStrategy::PerBlockInt(blocks, block_size) => (
+                QuantLevel::Block(*block_size),
+                blocks[0].mode,
+                blocks[0].q_type,
+            ),
+            QuantizationStrategy::PerChannelInt(channels, axis, _stride) => (
+                QuantLevel::Channel(*axis),
+                channels[0].mode,
+                channels[0].q_type,
+            ),
+        };
+
+        // The 4-bit types are always packed
+        let q_store_type = match q_type {
+            QuantInputType::QInt4 | QuantInputType::QUInt4 => QuantStoreType::U32,
+            _ => QuantStoreType::Native,
+        };
+
+        QuantScheme {
+            level,
+            mode,
+            q_type,
+            acc_precision: QuantAccPrecision::Full,
+            propagation: QuantPropagation::Inhibit,
+            q_store_type,
+        }
+    }
+
+    /// Creates the quantization strategy of the scheme from its parameters, for a tensor of the
+    /// given shape.
+    ///
+    /// Per-tensor quantization expects a single set of parameters, per-block quantization a set
+    /// for each block and per-channel quantization a set for each channel.
+    pub fn from_qparams(
+        scheme: &QuantScheme,
+        qparams: &QParams<Vec<f32>>,
+        shape: &[usize],
+    ) -> Self {
+        let scales = &qparams.scales;
+
+        match (scheme.level, scheme.mode, scheme.q_type) {
+            (QuantLevel::Tensor, QuantMode::Symmetric, QuantInputType::QInt8) => {
+                QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(scales[0]))
+            }
+            (QuantLevel::Block(block_size), QuantMode::Symmetric, QuantInputType::QInt8) => {
+                QuantizationStrategy::PerBlockSymmetricInt8(
+                    scales
+                        .iter()
+                        .map(|&s| SymmetricQuantization::init(s))
+                        .collect(),
+                    block_size,
+                )
+            }
+            (QuantLevel::Tensor, QuantMode::Symmetric, QuantInputType::E4M3) => {
+                QuantizationStrategy::PerTensorScaledE4M3(ScaledFloat8Quantization::init(scales[0]))
+            }
+            (QuantLevel::Block(block_size), QuantMode::Symmetric, QuantInputType::E4M3) => {
+                QuantizationStrategy::PerBlockScaledE4M3(
+                    scales
+                        .iter()
+                        .map(|&s| ScaledFloat8Quantization::init(s))
+                        .collect(),
+                    block_size,
+                )
+            }
+            (QuantLevel::Tensor, QuantMode::Symmetric, QuantInputType::E5M2) => {
+                QuantizationStrategy::PerTensorScaledE5M2(ScaledFloat8Quantization::init(scales[0]))
+            }
+            (QuantLevel::Block(block_size), QuantMode::Symmetric, QuantInputType::E5M2) => {
+                QuantizationStrategy::PerBlockScaledE5M2(
+                    scales
+                        .iter()
+                        .map(|&s| ScaledFloat8Quantization::init(s))
+                        .collect(),
+                    block_size,
+                )
+            }
+            (_, _, QuantInputType::E4M3 | QuantInputType::E5M2) => {
+                panic!("Unsupported quantization scheme for fp8 types: {scheme:?}")
+            }
+            (level, mode, q_type) => {
+                let mut params = scales
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &scale)| match mode {
+                        QuantMode::Symmetric => IntQuantization::symmetric(scale, q_type),
+                        QuantMode::Affine => {
+                            let offsets = qparams
+                                .offsets
+                                .as_ref()
+                                .expect("Affine quantization should have offsets");
+                            IntQuantization::affine(scale, offsets[i] as i32, q_type)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                match level {
+                    QuantLevel::Tensor => QuantizationStrategy::PerTensorInt(params.remove(0)),
+                    QuantLevel::Block(block_size) => {
+                        QuantizationStrategy::PerBlockInt(params, block_size)
+                    }
+                    QuantLevel::Channel(axis) => {
+                        let stride = shape[axis + 1..].iter().product();
+                        QuantizationStrategy::PerChannelInt(params, axis, stride)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the quantization parameters of each block or channel, or a single set of
+    /// parameters for per-tensor quantization.
+    pub fn qparams(&self) -> QParams<Vec<f32>> {
+        let scales = match self {
+            QuantizationStrategy::PerTensorSymmetricInt8(strategy) => vec![strategy.scale],
+            QuantizationStrategy::PerBlockSymmetricInt8(strategy, _) => {
+                strategy.iter().map(|s| s.scale).collect()
+            }
+            QuantizationStrategy::PerTensorScaledE4M3(strategy) => vec![strategy.scale],
+            QuantizationStrategy::PerBlockScaledE4M3(strategy, _) => {
+                strategy.iter().map(|s| s.scale).collect()
+            }
+            QuantizationStrategy::PerTensorScaledE5M2(strategy) => vec![strategy.scale],
+            QuantizationStrategy::PerBlockScaledE5M2(strategy, _) => {
+                strategy.iter().map(|s| s.scale).collect()
+            }
+            QuantizationStrategy::PerTensorInt(strategy) => {
+                return int_qparams(core::slice::from_ref(strategy));
+            }
+            QuantizationStrategy::PerBlockInt(strategy, _)
+            | QuantizationStrategy::PerChannelInt(strategy, _, _) => {
+                return int_qparams(strategy);
+            }
+        };
+
+        QParams {
+            scales,
+            offsets: None,
         }
     }
 }
 
+/// Returns the scales and, for affine quantization, the offsets of the integer quantization
+/// strategies.
+fn int_qparams(strategy: &[IntQuantization]) -> QParams<Vec<f32>> {
+    let scales = strategy.iter().map(|s| s.scale).collect();
+    let offsets = strategy
+        .iter()
+        .any(|s| s.mode == QuantMode::Affine)
+        .then(|| strategy.iter().map(|s| s.offset as f32).collect());
+
+    QParams { scales, offsets }
+}
+
 /// Quantization scheme to convert elements of a higher precision data type `E` to a lower precision
 /// data type `Q` and vice-versa.
 pub trait Quantization<E: Float + Send + Sync, Q: PrimInt + Send + Sync> {
@@ -186,10 +432,167 @@ impl<E: Float + Send + Sync, Q: PrimInt + Signed + Send + Sync> PartialEq
 
 impl<E: Float + Send + Sync, Q: PrimInt + Signed + Send + Sync> Eq for SymmetricQuantization<E, Q> {}
 
+/// Scaled 8-bit floating point quantization scheme.
+///
+/// The values are divided by the scale, then rounded to the nearest value of the 8-bit floating
+/// point type `F` and saturated to its largest finite magnitude.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScaledFloat8Quantization<F> {
+    /// The scaling factor.
+    pub scale: f32,
+    /// The quantized type.
+    _f: PhantomData<F>,
+}
+
+impl<F: Element + bytemuck::Pod> ScaledFloat8Quantization<F> {
+    /// Initialize a scaled quantization scheme with the given parameters.
+    pub fn init(scale: f32) -> Self {
+        Self {
+            scale: valid_scale(scale),
+            _f: PhantomData,
+        }
+    }
+
+    /// Create a new quantization scheme for an input range `[alpha, beta]`, which maps the largest
+    /// magnitude to the largest finite value of the quantized type.
+    pub fn new(alpha: f32, beta: f32) -> Self {
+        let alpha = alpha.abs().max(beta.abs());
+        Self::init(alpha / F::MAX.to_f32())
+    }
+
+    /// Convert the values to the bits of the quantized type.
+    pub fn quantize(&self, values: &[f32]) -> Vec<i8> {
+        values.iter().map(|x| self.quantize_one(*x)).collect()
+    }
+
+    /// Convert a single value to the bits of the quantized type.
+    pub fn quantize_one(&self, value: f32) -> i8 {
+        // x_q = saturate(round(x / scale))
+        bytemuck::cast(F::from_elem(value / self.scale))
+    }
+
+    /// Convert the bits of the quantized type back to a higher precision data type.
+    pub fn dequantize(&self, values: &[i8]) -> Vec<f32> {
+        values.iter().map(|x_q| self.dequantize_one(*x_q)).collect()
+    }
+
+    /// Convert the bits of a single quantized value back to a higher precision data type.
+    pub fn dequantize_one(&self, value: i8) -> f32 {
+        // x = scale * x_q
+        self.scale * bytemuck::cast::<i8, F>(value).to_f32()
+    }
+}
+
+impl<F> PartialEq for ScaledFloat8Quantization<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale
+    }
+}
+
+impl<F> Eq for ScaledFloat8Quantization<F> {}
+
+/// Integer quantization scheme, which supports both quantization modes and all integer types.
+///
+/// The values are divided by the scale, rounded, shifted by the zero-point offset and clamped to
+/// the range of the quantized type. The offset is always zero for symmetric quantization.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct IntQuantization {
+    /// The scaling factor.
+    pub scale: f32,
+    /// The zero-point offset.
+    pub offset: i32,
+    /// The quantization mode.
+    pub mode: QuantMode,
+    /// The quantized type.
+    pub q_type: QuantInputType,
+}
+
+impl IntQuantization {
+    /// Initialize a symmetric quantization scheme with the given scale.
+    pub fn symmetric(scale: f32, q_type: QuantInputType) -> Self {
+        Self::init(scale, 0, QuantMode::Symmetric, q_type)
+    }
+
+    /// Initialize an affine quantization scheme with the given scale and offset.
+    pub fn affine(scale: f32, offset: i32, q_type: QuantInputType) -> Self {
+        Self::init(scale, offset, QuantMode::Affine, q_type)
+    }
+
+    fn init(scale: f32, offset: i32, mode: QuantMode, q_type: QuantInputType) -> Self {
+        // Validate the type for the mode
+        q_type.range(mode);
+
+        Self {
+            scale: valid_scale(scale),
+            offset,
+            mode,
+            q_type,
+        }
+    }
+
+    /// Create a new quantization scheme for an input range `[alpha, beta]`.
+    pub fn new(alpha: f32, beta: f32, mode: QuantMode, q_type: QuantInputType) -> Self {
+        let (a, b) = q_type.range(mode);
+        let (a, b) = (a as f32, b as f32);
+
+        match mode {
+            QuantMode::Symmetric => {
+                // Compute scale to convert a value in range `[-alpha, alpha]` to the quantized range
+                let alpha = alpha.abs().max(beta.abs());
+                Self::symmetric((alpha + alpha) / (b - a), q_type)
+            }
+            QuantMode::Affine => {
+                // The input range must include zero so that it is exactly representable
+                let alpha = alpha.min(0.0);
+                let beta = beta.max(0.0);
+
+                // Compute the scale and the offset to map `[alpha, beta]` to the quantized range
+                let scale = valid_scale((beta - alpha) / (b - a));
+                let offset = (a - alpha / scale).round().clamp(a, b);
+                Self::affine(scale, offset as i32, q_type)
+            }
+        }
+    }
+
+    /// Convert the values to a lower precision data type.
+    pub fn quantize(&self, values: &[f32]) -> Vec<i8> {
+        values.iter().map(|x| self.quantize_one(*x)).collect()
+    }
+
+    /// Convert a single value to a lower precision data type.
+    pub fn quantize_one(&self, value: f32) -> i8 {
+        let (a, b) = self.q_type.range(self.mode);
+
+        // x_q = clamp(round(x / scale) + offset, a, b)
+        ((value / self.scale).round() + self.offset as f32).clamp(a as f32, b as f32) as i8
+    }
+
+    /// Convert the values back to a higher precision data type.
+    pub fn dequantize(&self, values: &[i8]) -> Vec<f32> {
+        values.iter().map(|x_q| self.dequantize_one(*x_q)).collect()
+    }
+
+    /// Convert a single value back to a higher precision data type.
+    pub fn dequantize_one(&self, value: i8) -> f32 {
+        // x = scale * (x_q - offset)
+        self.scale * (value as i32 - self.offset) as f32
+    }
+}
+
+impl PartialEq for IntQuantization {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale
+            && self.offset == other.offset
+            && self.mode == other.mode
+            && self.q_type == other.q_type
+    }
+}
+
+impl Eq for IntQuantization {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::vec;
 
     #[test]
     fn test_int8_symmetric_quantization() {
@@ -226,4 +629,99 @@ mod tests {
 
         assert_eq!(d, expected_d);
     }
+
+    #[test]
+    fn test_e4m3_scaled_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        // scale = 1.8 / 448, so the values are rounded to e4m3 in [-448, 124.44]
+        let expected_d = vec![-1.8, -1.0285714, 0.0, 0.5142857];
+
+        let scaled = ScaledFloat8Quantization::<e4m3>::new(-1.8, 0.5);
+
+        let q = scaled.quantize(&x);
+        assert_eq!(q[0] as u8, 0xFE);
+        assert_eq!(q[2], 0);
+
+        let d = scaled.dequantize(&q);
+
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_e5m2_scaled_quantization_per_block() {
+        let x: [f32; 8] = [-1.8, -1.0, 0.0, 0.5, -3.6, -2.0, 0.0, 1.0];
+        let strategy = QuantizationStrategy::PerBlockScaledE5M2(
+            vec![
+                ScaledFloat8Quantization::new(-1.8, 0.5),
+                ScaledFloat8Quantization::new(-3.6, 1.0),
+            ],
+            4,
+        );
+
+        let q = strategy.quantize(&x);
+        assert_eq!(&q[..4], &q[4..]);
+
+        let d = strategy.dequantize(&q);
+
+        assert_eq!(d[0], -1.8);
+        assert_eq!(d[4], -3.6);
+        assert_eq!(d[5], 2.0 * d[1]);
+    }
+
+    #[test]
+    fn test_int4_affine_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-8, -3, 4, 7];
+
+        let affine = IntQuantization::new(-1.8, 0.5, QuantMode::Affine, QuantInputType::QInt4);
+        assert_eq!(affine.offset, 4);
+
+        let q = affine.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = affine.dequantize(&q);
+
+        // Zero is exactly representable and the error is bounded by half the scale
+        assert_eq!(d[2], 0.0);
+        for (d, x) in d.iter().zip(x) {
+            assert!((d - x).abs() <= affine.scale / 2.0 + f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_int8_symmetric_quantization_per_channel() {
+        // Shape [2, 2, 2] quantized along axis 1
+        let x: [f32; 8] = [0.1, 0.2, 0.2, 0.4, -0.1, -0.2, -0.2, -0.4];
+        let expected_q = vec![1, 2, 1, 2, -1, -2, -1, -2];
+
+        let scheme = QuantScheme::default().set_level(QuantLevel::Channel(1));
+        let qparams = QParams {
+            scales: vec![0.1, 0.2],
+            offsets: None,
+        };
+        let strategy = QuantizationStrategy::from_qparams(&scheme, &qparams, &[2, 2, 2]);
+
+        assert_eq!(
+            strategy,
+            QuantizationStrategy::PerChannelInt(
+                vec![
+                    IntQuantization::symmetric(0.1, QuantInputType::QInt8),
+                    IntQuantization::symmetric(0.2, QuantInputType::QInt8),
+                ],
+                1,
+                2
+            )
+        );
+        assert_eq!(strategy.scheme().level, QuantLevel::Channel(1));
+
+        let q = strategy.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = strategy.dequantize(&q);
+        assert_eq!(d, x.to_vec());
+
+        let qparams = strategy.qparams();
+        assert_eq!(qparams.scales, vec![0.1, 0.2]);
+        assert!(qparams.offsets.is_none());
+    }
 }
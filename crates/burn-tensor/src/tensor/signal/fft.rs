@@ -0,0 +1,242 @@
+use alloc::vec;
+
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorPrimitive, check};
+
+fn transform<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+    inverse: bool,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (real, imag) = B::fft(
+        real.into_primitive().tensor(),
+        imag.into_primitive().tensor(),
+        dim,
+        inverse,
+    );
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(real)),
+        Tensor::from_primitive(TensorPrimitive::Float(imag)),
+    )
+}
+
+/// Computes the one-dimensional discrete Fourier transform of a complex signal.
+///
+/// `X[k] = sum_n x[n] exp(-2πi kn / N)`, where `N` is the size of the transformed dimension.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.fft.fft.html
+///
+/// # Arguments
+///
+/// * `real` - The real part of the signal.
+/// * `imag` - The imaginary part of the signal, with the same shape as `real`.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transformed signal, with the same shape as the inputs.
+pub fn fft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft::<D>(
+        "FFT",
+        dim,
+        &real.shape(),
+        &imag.shape()
+    ));
+
+    transform(real, imag, dim, false)
+}
+
+/// Computes the one-dimensional inverse discrete Fourier transform of a complex signal.
+///
+/// `x[n] = 1/N sum_k X[k] exp(2πi kn / N)`, where `N` is the size of the transformed dimension,
+/// so that `ifft(fft(x)) = x`.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.fft.ifft.html
+///
+/// # Arguments
+///
+/// * `real` - The real part of the spectrum.
+/// * `imag` - The imaginary part of the spectrum, with the same shape as `real`.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the signal, with the same shape as the inputs.
+pub fn ifft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft::<D>(
+        "IFFT",
+        dim,
+        &real.shape(),
+        &imag.shape()
+    ));
+
+    let size = real.dims()[dim].max(1) as f64;
+    let (real, imag) = transform(real, imag, dim, true);
+
+    (real.div_scalar(size), imag.div_scalar(size))
+}
+
+/// Computes the one-dimensional discrete Fourier transform of a real signal.
+///
+/// Since the spectrum of a real signal is Hermitian symmetric, only the `N / 2 + 1` non-negative
+/// frequencies are returned.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.fft.rfft.html
+///
+/// # Arguments
+///
+/// * `signal` - The real signal.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the spectrum, with size `N / 2 + 1` along `dim`.
+pub fn rfft<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft::<D>(
+        "RFFT",
+        dim,
+        &signal.shape(),
+        &signal.shape()
+    ));
+
+    let size = signal.dims()[dim];
+    let imag = signal.zeros_like();
+    let (real, imag) = transform(signal, imag, dim, false);
+
+    (
+        real.narrow(dim, 0, size / 2 + 1),
+        imag.narrow(dim, 0, size / 2 + 1),
+    )
+}
+
+/// Computes the inverse of [rfft], returning a real signal from the non-negative frequencies of
+/// its spectrum.
+///
+/// The negative frequencies are reconstructed by Hermitian symmetry, and the imaginary parts of
+/// the zero and Nyquist frequencies are ignored.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.fft.irfft.html
+///
+/// # Arguments
+///
+/// * `real` - The real part of the non-negative frequencies.
+/// * `imag` - The imaginary part of the non-negative frequencies, with the same shape as `real`.
+/// * `dim` - The dimension along which the transform is computed.
+/// * `size` - The length `N` of the signal. Defaults to `2 * (M - 1)`, where `M` is the number of
+///   frequencies. The spectrum is trimmed or zero-padded to `N / 2 + 1` frequencies.
+///
+/// # Returns
+///
+/// The real signal, with size `N` along `dim`.
+pub fn irfft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+    size: Option<usize>,
+) -> Tensor<B, D> {
+    check!(TensorCheck::fft::<D>(
+        "IRFFT",
+        dim,
+        &real.shape(),
+        &imag.shape()
+    ));
+
+    let num_freqs = real.dims()[dim];
+    let size = size.unwrap_or(2 * num_freqs.saturating_sub(1));
+    let half = size / 2 + 1;
+
+    let (real, imag) = match num_freqs.cmp(&half) {
+        core::cmp::Ordering::Less => {
+            let mut padding = real.dims();
+            padding[dim] = half - num_freqs;
+            let zeros = Tensor::zeros(padding, &real.device());
+            (
+                Tensor::cat(vec![real, zeros.clone()], dim),
+                Tensor::cat(vec![imag, zeros], dim),
+            )
+        }
+        _ => (real.narrow(dim, 0, half), imag.narrow(dim, 0, half)),
+    };
+
+    // X[N - k] = conj(X[k]) for the negative frequencies.
+    let num_negative = size - half;
+    let (real, imag) = match num_negative > 0 {
+        true => {
+            let axes = [dim as isize];
+            let real_negative = real.clone().narrow(dim, 1, num_negative).flip(axes);
+            let imag_negative = imag.clone().narrow(dim, 1, num_negative).flip(axes).neg();
+            (
+                Tensor::cat(vec![real, real_negative], dim),
+                Tensor::cat(vec![imag, imag_negative], dim),
+            )
+        }
+        false => (real, imag),
+    };
+
+    let (signal, _) = transform(real, imag, dim, true);
+    signal.div_scalar(size.max(1) as f64)
+}
+
+/// Computes the two-dimensional discrete Fourier transform of a complex signal.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.fft.fft2.html
+///
+/// # Arguments
+///
+/// * `real` - The real part of the signal.
+/// * `imag` - The imaginary part of the signal, with the same shape as `real`.
+/// * `dims` - The two dimensions along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transformed signal, with the same shape as the inputs.
+pub fn fft2<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (real, imag) = fft(real, imag, dims[0]);
+    fft(real, imag, dims[1])
+}
+
+/// Computes the two-dimensional inverse discrete Fourier transform of a complex signal.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.fft.ifft2.html
+///
+/// # Arguments
+///
+/// * `real` - The real part of the spectrum.
+/// * `imag` - The imaginary part of the spectrum, with the same shape as `real`.
+/// * `dims` - The two dimensions along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the signal, with the same shape as the inputs.
+pub fn ifft2<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (real, imag) = ifft(real, imag, dims[0]);
+    ifft(real, imag, dims[1])
+}
@@ -0,0 +1,88 @@
+use alloc::{vec, vec::Vec};
+
+use crate::backend::Backend;
+use crate::{Tensor, TensorData};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+fn hertz_to_mel(hertz: f64) -> f64 {
+    2595.0 * (1.0 + hertz / 700.0).log10()
+}
+
+fn mel_to_hertz(mel: f64) -> f64 {
+    700.0 * (10f64.powf(mel / 2595.0) - 1.0)
+}
+
+/// Generates a matrix mapping the non-negative frequencies of a discrete Fourier transform to
+/// triangular filters evenly spaced on the mel scale.
+///
+/// The filters follow the definition of the ONNX `MelWeightMatrix` operator: the edges of the
+/// filters are rounded down to frequency bins and each filter peaks at one on its center bin.
+/// Multiplying a magnitude spectrogram of shape `[..., frames, dft_length / 2 + 1]` with this
+/// matrix gives a mel spectrogram of shape `[..., frames, num_mel_bins]`.
+///
+/// See:
+/// - https://onnx.ai/onnx/operators/onnx__MelWeightMatrix.html
+///
+/// # Arguments
+///
+/// * `num_mel_bins` - The number of mel filters.
+/// * `dft_length` - The size of the Fourier transform.
+/// * `sample_rate` - The sample rate of the signal, in hertz.
+/// * `lower_edge_hertz` - The lowest frequency covered by the filters.
+/// * `upper_edge_hertz` - The highest frequency covered by the filters.
+/// * `device` - The device on which the matrix is created.
+///
+/// # Returns
+///
+/// The weights of shape `[dft_length / 2 + 1, num_mel_bins]`.
+pub fn mel_weight_matrix<B: Backend>(
+    num_mel_bins: usize,
+    dft_length: usize,
+    sample_rate: usize,
+    lower_edge_hertz: f64,
+    upper_edge_hertz: f64,
+    device: &B::Device,
+) -> Tensor<B, 2> {
+    let num_bins = dft_length / 2 + 1;
+    let lower_mel = hertz_to_mel(lower_edge_hertz);
+    let upper_mel = hertz_to_mel(upper_edge_hertz);
+    let step = (upper_mel - lower_mel) / (num_mel_bins + 2) as f64;
+
+    // The edges of the filters, as frequency bins.
+    let edges = (0..num_mel_bins + 2)
+        .map(|i| {
+            let hertz = mel_to_hertz(lower_mel + i as f64 * step);
+            ((dft_length + 1) as f64 * hertz / sample_rate as f64).floor() as usize
+        })
+        .collect::<Vec<_>>();
+
+    let mut values = vec![0.0; num_bins * num_mel_bins];
+    let mut set = |bin: usize, filter: usize, value: f64| {
+        if bin < num_bins {
+            values[bin * num_mel_bins + filter] = value;
+        }
+    };
+
+    for filter in 0..num_mel_bins {
+        let (lower, center, upper) = (edges[filter], edges[filter + 1], edges[filter + 2]);
+
+        match center - lower {
+            0 => set(center, filter, 1.0),
+            width => {
+                for bin in lower..=center {
+                    set(bin, filter, (bin - lower) as f64 / width as f64);
+                }
+            }
+        }
+
+        let width = upper - center;
+        for bin in center..upper {
+            set(bin, filter, (upper - bin) as f64 / width as f64);
+        }
+    }
+
+    Tensor::from_data(TensorData::new(values, [num_bins, num_mel_bins]), device)
+}
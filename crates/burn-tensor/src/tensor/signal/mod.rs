@@ -0,0 +1,9 @@
+mod fft;
+mod mel;
+mod stft;
+mod window;
+
+pub use fft::*;
+pub use mel::*;
+pub use stft::*;
+pub use window::*;
@@ -0,0 +1,222 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{fft, ifft, irfft, rfft};
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{Int, Tensor, TensorData, check};
+
+/// Options for the [short-time Fourier transform](stft) and its [inverse](istft).
+#[derive(new, Debug, Clone)]
+pub struct StftOptions {
+    /// The size of the Fourier transform of each frame.
+    pub n_fft: usize,
+    /// The number of samples between the starts of neighboring frames.
+    pub hop_length: usize,
+    /// Whether the signal is padded on both sides by `n_fft / 2` samples with its reflection, so
+    /// that frame `t` is centered on sample `t * hop_length`.
+    pub center: bool,
+    /// Whether only the `n_fft / 2 + 1` non-negative frequencies are returned.
+    pub onesided: bool,
+}
+
+/// The positions in the signal of the samples of each frame, flattened frame by frame.
+fn frame_indices<B: Backend>(
+    num_frames: usize,
+    options: &StftOptions,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let indices = (0..num_frames)
+        .flat_map(|frame| (0..options.n_fft).map(move |i| (frame * options.hop_length + i) as i64))
+        .collect::<Vec<_>>();
+
+    Tensor::from_data(
+        TensorData::new(indices, [num_frames * options.n_fft]),
+        device,
+    )
+}
+
+/// Returns the window zero-padded on both sides to `n_fft` samples, or a rectangular window.
+fn padded_window<B: Backend>(
+    window: Option<Tensor<B, 1>>,
+    n_fft: usize,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    let Some(window) = window else {
+        return Tensor::ones([n_fft], device);
+    };
+
+    let [length] = window.dims();
+    if length == n_fft {
+        return window;
+    }
+
+    let left = (n_fft - length) / 2;
+    Tensor::cat(
+        vec![
+            Tensor::zeros([left], device),
+            window,
+            Tensor::zeros([n_fft - length - left], device),
+        ],
+        0,
+    )
+}
+
+/// Computes the short-time Fourier transform of a batch of real signals.
+///
+/// The signals are split into overlapping frames of `n_fft` samples, which are multiplied by the
+/// window before their discrete Fourier transform is computed.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.stft.html
+///
+/// # Arguments
+///
+/// * `signal` - The signals of shape `[batch_size, length]`.
+/// * `window` - The window of at most `n_fft` samples, zero-padded on both sides to `n_fft`
+///   samples. Defaults to a rectangular window.
+/// * `options` - The framing options.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the spectrogram, of shape `[batch_size, num_freqs, num_frames]`
+/// where `num_freqs` is `n_fft / 2 + 1` for one-sided transforms and `n_fft` otherwise.
+pub fn stft<B: Backend>(
+    signal: Tensor<B, 2>,
+    window: Option<Tensor<B, 1>>,
+    options: StftOptions,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    let device = signal.device();
+    let window_length = window
+        .as_ref()
+        .map_or(options.n_fft, |window| window.dims()[0]);
+
+    let signal = match options.center {
+        true => {
+            let [_, length] = signal.dims();
+            let padding = options.n_fft / 2;
+            assert!(
+                padding < length,
+                "STFT: the signal length {length} must be greater than the reflection padding {padding}."
+            );
+
+            let left = signal.clone().narrow(1, 1, padding).flip([1]);
+            let right = signal
+                .clone()
+                .narrow(1, length - 1 - padding, padding)
+                .flip([1]);
+            Tensor::cat(vec![left, signal, right], 1)
+        }
+        false => signal,
+    };
+
+    let [batch_size, length] = signal.dims();
+    check!(TensorCheck::stft(
+        "STFT",
+        options.n_fft,
+        options.hop_length,
+        window_length,
+        length
+    ));
+
+    let num_frames = 1 + (length - options.n_fft) / options.hop_length;
+    let frames = signal
+        .select(1, frame_indices::<B>(num_frames, &options, &device))
+        .reshape([batch_size, num_frames, options.n_fft]);
+    let frames = frames * padded_window(window, options.n_fft, &device).unsqueeze::<3>();
+
+    let (real, imag) = match options.onesided {
+        true => rfft(frames, 2),
+        false => {
+            let imag = frames.zeros_like();
+            fft(frames, imag, 2)
+        }
+    };
+
+    (real.swap_dims(1, 2), imag.swap_dims(1, 2))
+}
+
+/// Computes the inverse of the [short-time Fourier transform](stft).
+///
+/// The frames are recovered with the inverse discrete Fourier transform, multiplied by the window
+/// and overlap-added, before being normalized by the overlap-added squared window. The window
+/// must not vanish on the overlapping frames for the signal to be recovered.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.istft.html
+///
+/// # Arguments
+///
+/// * `real` - The real part of the spectrogram of shape `[batch_size, num_freqs, num_frames]`.
+/// * `imag` - The imaginary part of the spectrogram, with the same shape as `real`.
+/// * `window` - The window used by the forward transform. Defaults to a rectangular window.
+/// * `options` - The framing options used by the forward transform.
+/// * `length` - The length of the recovered signals. Defaults to the length covered by the frames,
+///   and the signals are zero-padded when longer.
+///
+/// # Returns
+///
+/// The signals of shape `[batch_size, length]`.
+pub fn istft<B: Backend>(
+    real: Tensor<B, 3>,
+    imag: Tensor<B, 3>,
+    window: Option<Tensor<B, 1>>,
+    options: StftOptions,
+    length: Option<usize>,
+) -> Tensor<B, 2> {
+    let device = real.device();
+    let [batch_size, _, num_frames] = real.dims();
+    let n_fft = options.n_fft;
+    let window_length = window.as_ref().map_or(n_fft, |window| window.dims()[0]);
+    let total = n_fft + options.hop_length * num_frames.saturating_sub(1);
+
+    check!(TensorCheck::stft(
+        "ISTFT",
+        n_fft,
+        options.hop_length,
+        window_length,
+        total
+    ));
+
+    let real = real.swap_dims(1, 2);
+    let imag = imag.swap_dims(1, 2);
+    let frames = match options.onesided {
+        true => irfft(real, imag, 2, Some(n_fft)),
+        false => ifft(real, imag, 2).0,
+    };
+
+    let window = padded_window(window, n_fft, &device);
+    let frames = frames * window.clone().unsqueeze::<3>();
+
+    let indices = frame_indices::<B>(num_frames, &options, &device);
+    let signal = Tensor::zeros([batch_size, total], &device).select_assign(
+        1,
+        indices.clone(),
+        frames.reshape([batch_size, num_frames * n_fft]),
+    );
+    let envelope = Tensor::zeros([total], &device).select_assign(
+        0,
+        indices,
+        window
+            .powi_scalar(2)
+            .unsqueeze::<2>()
+            .repeat_dim(0, num_frames)
+            .reshape([num_frames * n_fft]),
+    );
+    let signal = signal / envelope.unsqueeze::<2>();
+
+    let start = if options.center { n_fft / 2 } else { 0 };
+    let available = total - 2 * start;
+    let length = length.unwrap_or(available);
+
+    match length <= available {
+        true => signal.narrow(1, start, length),
+        false => Tensor::cat(
+            vec![
+                signal.narrow(1, start, available),
+                Tensor::zeros([batch_size, length - available], &device),
+            ],
+            1,
+        ),
+    }
+}
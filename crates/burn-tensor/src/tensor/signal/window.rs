@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use crate::backend::Backend;
+use crate::{Tensor, TensorData};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// Generates a generalized cosine window.
+///
+/// `w[n] = sum_k (-1)^k a[k] cos(2πkn / N)`, where `N` is `size` for periodic windows and
+/// `size - 1` for symmetric windows.
+///
+/// See:
+/// - https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.general_cosine.html
+///
+/// # Arguments
+///
+/// * `size` - The number of samples of the window.
+/// * `periodic` - Whether the window is periodic, as used for spectral analysis, or symmetric, as
+///   used for filter design.
+/// * `coefficients` - The weights `a[k]` of the cosine terms.
+/// * `device` - The device on which the window is created.
+///
+/// # Returns
+///
+/// The window of shape `[size]`.
+pub fn cosine_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    coefficients: &[f64],
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    let denominator = match periodic {
+        true => size,
+        false => size.saturating_sub(1),
+    };
+
+    let values = (0..size)
+        .map(|n| match denominator {
+            0 => 1.0,
+            _ => coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, a)| {
+                    let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+                    sign * a * (2.0 * PI * (k * n) as f64 / denominator as f64).cos()
+                })
+                .sum(),
+        })
+        .collect::<Vec<f64>>();
+
+    Tensor::from_data(TensorData::new(values, [size]), device)
+}
+
+/// Generates a Hann window.
+///
+/// `w[n] = 0.5 - 0.5 cos(2πn / N)`, where `N` is `size` for periodic windows and `size - 1` for
+/// symmetric windows.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.hann_window.html
+///
+/// # Arguments
+///
+/// * `size` - The number of samples of the window.
+/// * `periodic` - Whether the window is periodic or symmetric.
+/// * `device` - The device on which the window is created.
+///
+/// # Returns
+///
+/// The window of shape `[size]`.
+pub fn hann_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.5, 0.5], device)
+}
+
+/// Generates a Hamming window.
+///
+/// `w[n] = 0.54 - 0.46 cos(2πn / N)`, where `N` is `size` for periodic windows and `size - 1`
+/// for symmetric windows.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.hamming_window.html
+///
+/// # Arguments
+///
+/// * `size` - The number of samples of the window.
+/// * `periodic` - Whether the window is periodic or symmetric.
+/// * `device` - The device on which the window is created.
+///
+/// # Returns
+///
+/// The window of shape `[size]`.
+pub fn hamming_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.54, 0.46], device)
+}
+
+/// Generates a Blackman window.
+///
+/// `w[n] = 0.42 - 0.5 cos(2πn / N) + 0.08 cos(4πn / N)`, where `N` is `size` for periodic
+/// windows and `size - 1` for symmetric windows.
+///
+/// See:
+/// - https://pytorch.org/docs/stable/generated/torch.blackman_window.html
+///
+/// # Arguments
+///
+/// * `size` - The number of samples of the window.
+/// * `periodic` - Whether the window is periodic or symmetric.
+/// * `device` - The device on which the window is created.
+///
+/// # Returns
+///
+/// The window of shape `[size]`.
+pub fn blackman_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.42, 0.5, 0.08], device)
+}
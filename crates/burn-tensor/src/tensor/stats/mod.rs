@@ -1,4 +1,6 @@
-use crate::{Tensor, backend::Backend};
+use alloc::vec;
+
+use crate::{Int, Numeric, Tensor, backend::Backend};
 
 pub fn var<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     let mean = tensor.clone().mean_dim(dim);
@@ -40,3 +42,129 @@ pub fn var_with_mean_n<B: Backend, const D: usize>(
         .sum_dim(dim)
         .div_scalar(n as f32)
 }
+
+/// The interpolation method used to compute a [quantile](Tensor::quantile) that lies between two
+/// data points `lower` and `higher`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuantileInterpolation {
+    /// `lower + (higher - lower) * fraction`, where `fraction` is the fractional part of the
+    /// quantile position.
+    #[default]
+    Linear,
+    /// `lower`.
+    Lower,
+    /// `higher`.
+    Higher,
+    /// `lower` or `higher`, whichever is nearest, rounding half to even.
+    Nearest,
+    /// `(lower + higher) / 2`.
+    Midpoint,
+}
+
+pub fn quantile<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    q: f64,
+    dim: usize,
+    interpolation: QuantileInterpolation,
+) -> Tensor<B, D> {
+    let n = tensor.dims()[dim];
+    let position = q * (n - 1) as f64;
+    let lower = position as usize;
+    let fraction = position - lower as f64;
+    let higher = if fraction > 0.0 { lower + 1 } else { lower };
+
+    let sorted = tensor.sort(dim);
+    let value = |index| sorted.clone().narrow(dim, index, 1);
+
+    match interpolation {
+        QuantileInterpolation::Linear => {
+            let lower = value(lower);
+            lower.clone() + (value(higher) - lower).mul_scalar(fraction)
+        }
+        QuantileInterpolation::Lower => value(lower),
+        QuantileInterpolation::Higher => value(higher),
+        QuantileInterpolation::Nearest if fraction == 0.5 => value(lower + lower % 2),
+        QuantileInterpolation::Nearest if fraction < 0.5 => value(lower),
+        QuantileInterpolation::Nearest => value(higher),
+        QuantileInterpolation::Midpoint => (value(lower) + value(higher)).div_scalar(2),
+    }
+}
+
+pub fn kthvalue_with_indices<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    k: usize,
+    dim: usize,
+) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+    let indices = tensor.clone().argsort(dim).narrow(dim, k - 1, 1);
+
+    // Gathering from the input tensor keeps the values differentiable.
+    (tensor.gather(dim, indices.clone()), indices)
+}
+
+pub fn median_with_indices<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+    let n = tensor.dims()[dim];
+    kthvalue_with_indices(tensor, n.div_ceil(2), dim)
+}
+
+pub fn nanmedian_with_indices<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D, Int>) {
+    let nan_mask = tensor.clone().is_nan();
+    let count = nan_mask.clone().bool_not().int().sum_dim(dim);
+
+    // NaNs are sorted after all other values, so the median is the lower median of the first
+    // `count` sorted values.
+    let tensor = tensor.mask_fill(nan_mask, f64::INFINITY);
+    let position = count
+        .clone()
+        .float()
+        .sub_scalar(1)
+        .clamp_min(0)
+        .div_scalar(2)
+        .floor()
+        .int();
+    let indices = tensor.clone().argsort(dim).gather(dim, position);
+    let values = tensor.gather(dim, indices.clone());
+
+    (values.mask_fill(count.equal_elem(0), f64::NAN), indices)
+}
+
+pub fn mode_with_indices<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+    let device = tensor.device();
+    let mut dims = tensor.dims();
+    let n = dims[dim];
+
+    let (sorted, sorted_indices) = tensor.clone().sort_with_indices(dim);
+
+    // Each value is the last element of a run of equal values in the sorted tensor, and the run
+    // length is the distance to the start of the run, found with a cumulative maximum over the
+    // positions where the value changes.
+    let mut shape = [1; D];
+    shape[dim] = n;
+    let positions = Tensor::<B, 1, Int>::arange(0..n as i64, &device).reshape(shape);
+
+    dims[dim] = 1;
+    let mut run_starts = Tensor::<B, D, Int>::zeros(dims, &device);
+    if n > 1 {
+        let changes = sorted
+            .clone()
+            .narrow(dim, 1, n - 1)
+            .not_equal(sorted.narrow(dim, 0, n - 1));
+        run_starts = Tensor::cat(vec![run_starts, changes.int()], dim);
+    }
+    let run_starts = (run_starts * positions.clone()).cummax(dim);
+    let run_lengths = positions.clone() - run_starts + 1;
+
+    // Breaks ties between runs of the same length in favor of the smallest value.
+    let scores = run_lengths.mul_scalar(n as i64) - positions;
+    let indices = sorted_indices.gather(dim, scores.argmax(dim));
+
+    (tensor.gather(dim, indices.clone()), indices)
+}
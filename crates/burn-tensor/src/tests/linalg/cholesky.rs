@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_cholesky() {
+        let tensor = TestTensor::<2>::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let output = linalg::cholesky(tensor);
+        let expected = TensorData::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_cholesky_batched() {
+        let tensor = TestTensor::<3>::from([[[4.0, 2.0], [2.0, 5.0]], [[9.0, 3.0], [3.0, 2.0]]]);
+
+        let output = linalg::cholesky(tensor);
+        let expected = TensorData::from([[[2.0, 0.0], [1.0, 2.0]], [[3.0, 0.0], [1.0, 1.0]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_cholesky_reads_lower_triangle() {
+        let tensor = TestTensor::<2>::from([[4.0, 100.0], [2.0, 5.0]]);
+
+        let output = linalg::cholesky(tensor);
+        let expected = TensorData::from([[2.0, 0.0], [1.0, 2.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_matrix_is_not_square() {
+        let tensor = TestTensor::<2>::from([[4.0, 2.0, 1.0], [2.0, 5.0, 1.0]]);
+
+        let _output = linalg::cholesky(tensor);
+    }
+}
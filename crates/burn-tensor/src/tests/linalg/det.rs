@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(det)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_det() {
+        let tensor = TestTensor::<2>::from([[6.0, 1.0, 1.0], [4.0, -2.0, 5.0], [2.0, 8.0, 7.0]]);
+
+        let output = linalg::det(tensor);
+        let expected = TensorData::from([[-306.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::rel_abs(1e-4, 1e-3));
+    }
+
+    #[test]
+    fn test_det_batched() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 2.0], [3.0, 4.0]],
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[1.0, 2.0], [2.0, 4.0]],
+        ]);
+
+        let output = linalg::det(tensor);
+        let expected = TensorData::from([[[-2.0]], [[-1.0]], [[0.0]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_slogdet() {
+        let tensor = TestTensor::<3>::from([
+            [[6.0, 1.0, 1.0], [4.0, -2.0, 5.0], [2.0, 8.0, 7.0]],
+            [[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 4.0]],
+        ]);
+
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+
+        sign.into_data()
+            .assert_eq(&TensorData::from([[[-1.0]], [[1.0]]]), false);
+        logabsdet.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[306.0f64.ln()]], [[24.0f64.ln()]]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_slogdet_singular() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [2.0, 4.0]]);
+
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+
+        sign.into_data()
+            .assert_eq(&TensorData::from([[0.0]]), false);
+        logabsdet
+            .into_data()
+            .assert_eq(&TensorData::from([[f32::NEG_INFINITY]]), false);
+    }
+}
@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_eigh() {
+        let tensor = TestTensor::<2>::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+        let sqrt_2 = core::f64::consts::SQRT_2;
+
+        values.clone().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[2.0 - sqrt_2, 2.0, 2.0 + sqrt_2]]),
+            Tolerance::default(),
+        );
+        vectors
+            .clone()
+            .transpose()
+            .matmul(vectors.clone())
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+                Tolerance::default(),
+            );
+        vectors
+            .clone()
+            .mul(values)
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq::<FT>(&tensor.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_eigh_batched_reads_lower_triangle() {
+        let tensor = TestTensor::<3>::from([[[2.0, 100.0], [1.0, 2.0]], [[3.0, 0.0], [0.0, -1.0]]]);
+
+        let (values, vectors) = linalg::eigh(tensor);
+
+        values.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[1.0, 3.0]], [[-1.0, 3.0]]]),
+            Tolerance::default(),
+        );
+        vectors.abs().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                [
+                    [
+                        core::f64::consts::FRAC_1_SQRT_2,
+                        core::f64::consts::FRAC_1_SQRT_2,
+                    ],
+                    [
+                        core::f64::consts::FRAC_1_SQRT_2,
+                        core::f64::consts::FRAC_1_SQRT_2,
+                    ],
+                ],
+                [[0.0, 1.0], [1.0, 0.0]],
+            ]),
+            Tolerance::default(),
+        );
+    }
+}
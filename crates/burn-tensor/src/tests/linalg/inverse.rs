@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(inverse)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_inverse() {
+        let tensor = TestTensor::<2>::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::inverse(tensor);
+        let expected = TensorData::from([[0.6, -0.7], [-0.2, 0.4]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_inverse_batched_with_pivoting() {
+        let tensor = TestTensor::<3>::from([
+            [[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]],
+            [[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 0.5]],
+        ]);
+
+        let output = linalg::inverse(tensor);
+        let expected = TensorData::from([
+            [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            [[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, 2.0]],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
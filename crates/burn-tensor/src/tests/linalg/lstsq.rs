@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(lstsq)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_lstsq_overdetermined() {
+        let a = TestTensor::<2>::from([[1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = TestTensor::<2>::from([[6.0], [0.0], [0.0]]);
+
+        let output = linalg::lstsq(a, b);
+        let expected = TensorData::from([[8.0], [-3.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::rel_abs(1e-4, 1e-4));
+    }
+
+    #[test]
+    fn test_lstsq_underdetermined_minimum_norm() {
+        let a = TestTensor::<3>::from([[[1.0, 1.0]], [[0.0, 2.0]]]);
+        let b = TestTensor::<3>::from([[[2.0]], [[4.0]]]);
+
+        let output = linalg::lstsq(a, b);
+        let expected = TensorData::from([[[1.0], [1.0]], [[0.0], [2.0]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
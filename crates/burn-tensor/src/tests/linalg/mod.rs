@@ -1,2 +1,10 @@
+pub(crate) mod cholesky;
 pub(crate) mod cosine_similarity;
+pub(crate) mod det;
+pub(crate) mod eigh;
+pub(crate) mod inverse;
+pub(crate) mod lstsq;
+pub(crate) mod qr;
+pub(crate) mod solve;
+pub(crate) mod svd;
 pub(crate) mod vector_norm;
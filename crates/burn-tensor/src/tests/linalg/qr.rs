@@ -0,0 +1,70 @@
+#[burn_tensor_testgen::testgen(qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_qr_square() {
+        let tensor =
+            TestTensor::<2>::from([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+
+        let (q, r) = linalg::qr(tensor);
+        let expected_q = TensorData::from([
+            [6.0 / 7.0, -69.0 / 175.0, -58.0 / 175.0],
+            [3.0 / 7.0, 158.0 / 175.0, 6.0 / 175.0],
+            [-2.0 / 7.0, 6.0 / 35.0, -33.0 / 35.0],
+        ]);
+        let expected_r =
+            TensorData::from([[14.0, 21.0, -14.0], [0.0, 175.0, -70.0], [0.0, 0.0, 35.0]]);
+
+        q.into_data()
+            .assert_approx_eq::<FT>(&expected_q, Tolerance::rel_abs(1e-4, 1e-4));
+        r.into_data()
+            .assert_approx_eq::<FT>(&expected_r, Tolerance::rel_abs(1e-4, 1e-3));
+    }
+
+    #[test]
+    fn test_qr_tall() {
+        let tensor = TestTensor::<2>::from([[12.0, -51.0], [6.0, 167.0], [-4.0, 24.0]]);
+
+        let (q, r) = linalg::qr(tensor);
+        let expected_q = TensorData::from([
+            [6.0 / 7.0, -69.0 / 175.0],
+            [3.0 / 7.0, 158.0 / 175.0],
+            [-2.0 / 7.0, 6.0 / 35.0],
+        ]);
+        let expected_r = TensorData::from([[14.0, 21.0], [0.0, 175.0]]);
+
+        q.into_data()
+            .assert_approx_eq::<FT>(&expected_q, Tolerance::rel_abs(1e-4, 1e-4));
+        r.into_data()
+            .assert_approx_eq::<FT>(&expected_r, Tolerance::rel_abs(1e-4, 1e-3));
+    }
+
+    #[test]
+    fn test_qr_wide_reconstructs_input() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[0.0, -1.0, 2.0], [3.0, 1.0, -2.0]],
+        ]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 2, 2]);
+        assert_eq!(r.dims(), [2, 2, 3]);
+        q.clone()
+            .transpose()
+            .matmul(q.clone())
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[[1.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]]]),
+                Tolerance::default(),
+            );
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq::<FT>(&tensor.into_data(), Tolerance::default());
+    }
+}
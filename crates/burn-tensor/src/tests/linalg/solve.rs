@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_solve() {
+        let a = TestTensor::<2>::from([[3.0, 1.0], [1.0, 2.0]]);
+        let b = TestTensor::<2>::from([[9.0, 1.0], [8.0, 2.0]]);
+
+        let output = linalg::solve(a, b);
+        let expected = TensorData::from([[2.0, 0.0], [3.0, 1.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_solve_batched_with_pivoting() {
+        let a = TestTensor::<3>::from([
+            [[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]],
+            [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 4.0]],
+        ]);
+        let b = TestTensor::<3>::from([[[7.0], [3.0], [11.0]], [[1.0], [1.0], [1.0]]]);
+
+        let output = linalg::solve(a, b);
+        let expected = TensorData::from([[[1.0], [2.0], [3.0]], [[1.0], [0.5], [0.25]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_solve_triangular_lower() {
+        // The upper triangle is ignored.
+        let a = TestTensor::<2>::from([[2.0, 100.0], [3.0, 1.0]]);
+        let b = TestTensor::<2>::from([[4.0], [7.0]]);
+
+        let output = linalg::solve_triangular(a, b, false);
+        let expected = TensorData::from([[2.0], [1.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_solve_triangular_upper() {
+        // The lower triangle is ignored.
+        let a = TestTensor::<2>::from([[2.0, 3.0], [100.0, 1.0]]);
+        let b = TestTensor::<2>::from([[8.0], [2.0]]);
+
+        let output = linalg::solve_triangular(a, b, true);
+        let expected = TensorData::from([[1.0], [2.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_rhs_rows_dont_match() {
+        let a = TestTensor::<2>::from([[3.0, 1.0], [1.0, 2.0]]);
+        let b = TestTensor::<2>::from([[9.0], [8.0], [1.0]]);
+
+        let _output = linalg::solve(a, b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_batch_dims_dont_match() {
+        let a = TestTensor::<3>::from([[[3.0, 1.0], [1.0, 2.0]]]);
+        let b = TestTensor::<3>::from([[[9.0], [8.0]], [[1.0], [1.0]]]);
+
+        let _output = linalg::solve(a, b);
+    }
+}
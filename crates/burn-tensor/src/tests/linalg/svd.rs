@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::linalg;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_svd_wide() {
+        let tensor = TestTensor::<2>::from([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(vh.dims(), [2, 3]);
+        s.clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[5.0, 3.0]]), Tolerance::default());
+        u.clone()
+            .transpose()
+            .matmul(u.clone())
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[1.0, 0.0], [0.0, 1.0]]),
+                Tolerance::default(),
+            );
+        vh.clone()
+            .matmul(vh.clone().transpose())
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[1.0, 0.0], [0.0, 1.0]]),
+                Tolerance::default(),
+            );
+        u.mul(s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq::<FT>(&tensor.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_svd_batched_tall() {
+        let tensor = TestTensor::<3>::from([
+            [[2.0, 0.0], [0.0, -3.0], [0.0, 0.0]],
+            [[1.0, 1.0], [1.0, 1.0], [0.0, 0.0]],
+        ]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        s.clone().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[3.0, 2.0]], [[2.0, 0.0]]]),
+            Tolerance::default(),
+        );
+        u.clone()
+            .transpose()
+            .matmul(u.clone())
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([[[1.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]]]),
+                Tolerance::default(),
+            );
+        u.mul(s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq::<FT>(&tensor.into_data(), Tolerance::default());
+    }
+}
@@ -8,6 +8,7 @@ mod multi_threads;
 mod ops;
 mod primitive;
 mod quantization;
+mod signal;
 mod stats;
 
 pub use cubecl::prelude::{Float, Int, Numeric};
@@ -114,6 +115,7 @@ macro_rules! testgen_quantization {
         burn_tensor::testgen_q_mask!();
         burn_tensor::testgen_q_matmul!();
         burn_tensor::testgen_q_maxmin!();
+        burn_tensor::testgen_q_module!();
         burn_tensor::testgen_q_mul!();
         burn_tensor::testgen_q_narrow!();
         burn_tensor::testgen_q_neg!();
@@ -164,6 +166,20 @@ macro_rules! testgen_with_float_param {
         // test linalg
         burn_tensor::testgen_vector_norm!();
         burn_tensor::testgen_cosine_similarity!();
+        burn_tensor::testgen_cholesky!();
+        burn_tensor::testgen_qr!();
+        burn_tensor::testgen_svd!();
+        burn_tensor::testgen_eigh!();
+        burn_tensor::testgen_inverse!();
+        burn_tensor::testgen_det!();
+        burn_tensor::testgen_solve!();
+        burn_tensor::testgen_lstsq!();
+
+        // test signal
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_stft!();
+        burn_tensor::testgen_window!();
+        burn_tensor::testgen_mel_weight_matrix!();
 
         // test module
         burn_tensor::testgen_module_conv1d!();
@@ -184,10 +200,12 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
         burn_tensor::testgen_module_linear!();
 
         // test ops
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_gather_nd!();
         burn_tensor::testgen_narrow!();
         burn_tensor::testgen_add!();
         burn_tensor::testgen_aggregation!();
@@ -201,9 +219,16 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_close!();
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_cosh!();
+        burn_tensor::testgen_complex!();
         burn_tensor::testgen_create_like!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_erf!();
+        burn_tensor::testgen_inverse_trig!();
+        burn_tensor::testgen_inverse_hyperbolic!();
+        burn_tensor::testgen_gamma!();
+        burn_tensor::testgen_bessel!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
@@ -211,6 +236,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_iter_dim!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
+        burn_tensor::testgen_logsumexp!();
         burn_tensor::testgen_map_comparison!();
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
@@ -220,6 +246,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_one_hot!();
         burn_tensor::testgen_powf_scalar!();
         burn_tensor::testgen_random!();
+        burn_tensor::testgen_multinomial!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat_dim!();
         burn_tensor::testgen_repeat!();
@@ -250,9 +277,13 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_tri_mask!();
         burn_tensor::testgen_sort_argsort!();
         burn_tensor::testgen_topk!();
+        burn_tensor::testgen_unique!();
+        burn_tensor::testgen_searchsorted!();
+        burn_tensor::testgen_bincount!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
         burn_tensor::testgen_nan!();
+        burn_tensor::testgen_nan_reduce!();
         burn_tensor::testgen_inf!();
         burn_tensor::testgen_finite!();
         burn_tensor::testgen_round!();
@@ -261,11 +292,17 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_select!();
         burn_tensor::testgen_split!();
         burn_tensor::testgen_prod!();
+        burn_tensor::testgen_tensor_dyn!();
 
         // test stats
         burn_tensor::testgen_var!();
         burn_tensor::testgen_cov!();
         burn_tensor::testgen_eye!();
+        burn_tensor::testgen_quantile!();
+        burn_tensor::testgen_median!();
+        burn_tensor::testgen_mode!();
+        burn_tensor::testgen_kthvalue!();
+        burn_tensor::testgen_histc!();
 
         // test padding
         burn_tensor::testgen_padding!();
@@ -283,6 +320,7 @@ macro_rules! testgen_with_int_param {
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_bool!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_expand!();
         burn_tensor::testgen_flip!();
@@ -0,0 +1,248 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, affine_grid_3d, grid_sample_2d, grid_sample_3d};
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_grid_sample_bilinear_zeros() {
+        let output = grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[0.0000, 6.1000, 1.6800], [1.0000, 0.0000, 9.6000]],
+                [[-0.6000, -0.9500, -0.5600], [0.0500, 0.0000, -1.0875]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_bilinear_border_align_corners() {
+        let output = grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            true,
+        );
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[0.0750, 5.8500, 3.6500], [9.0000, 8.0000, 8.2500]],
+                [[-1.8500, -1.3500, -0.1000], [1.0000, -1.0000, -0.8625]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_bilinear_reflection() {
+        let output = grid_sample(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            false,
+        );
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[0.0000, 6.1000, 3.0000], [10.0000, 8.3000, 9.6000]],
+                [[-2.0000, -0.9500, -1.0000], [0.5000, -0.4000, -1.0875]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_nearest_zeros() {
+        let output = grid_sample(GridSampleMode::Nearest, GridSamplePaddingMode::Zeros, false);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[0.0000, 5.0000, 3.0000], [0.0000, 0.0000, 10.0000]],
+                [[-2.0000, -2.0000, -1.0000], [0.0000, 0.0000, -2.0000]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_nearest_reflection_align_corners() {
+        let output = grid_sample(
+            GridSampleMode::Nearest,
+            GridSamplePaddingMode::Reflection,
+            true,
+        );
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[0.0000, 5.0000, 3.0000], [11.0000, 9.0000, 10.0000]],
+                [[-2.0000, -2.0000, -1.0000], [0.0000, 1.0000, -2.0000]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_bicubic_zeros() {
+        let output = grid_sample(GridSampleMode::Bicubic, GridSamplePaddingMode::Zeros, false);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[-0.2835, 6.8728, 1.5963], [0.9418, -0.3200, 9.7551]],
+                [[-0.9428, -1.1904, -1.1272], [0.0619, 0.0499, -1.3062]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_bicubic_border_align_corners() {
+        let output = grid_sample(GridSampleMode::Bicubic, GridSamplePaddingMode::Border, true);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[0.0564, 6.1594, 3.4947], [9.4852, 8.1710, 8.5230]],
+                [[-1.8873, -1.7124, -0.1229], [1.5534, -1.2874, -1.2617]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_bicubic_reflection() {
+        let output = grid_sample(
+            GridSampleMode::Bicubic,
+            GridSamplePaddingMode::Reflection,
+            false,
+        );
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[-0.9300, 6.4142, 2.6775], [10.3019, 8.9142, 9.5830]],
+                [[-3.0913, -1.2434, -1.9270], [0.7055, -0.3632, -1.2832]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_affine_grid_2d() {
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 3], false);
+
+        grid.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[-0.6667, -0.5], [0.0, -0.5], [0.6667, -0.5]],
+                [[-0.6667, 0.5], [0.0, 0.5], [0.6667, 0.5]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_affine_grid_2d_rotation_align_corners() {
+        // Rotation by 90 degrees, (x, y) -> (-y, x).
+        let theta = TestTensor::<3>::from([[[0.0, -1.0, 0.0], [1.0, 0.0, 0.0]]]);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 3], true);
+
+        grid.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[
+                [[1.0, -1.0], [1.0, 0.0], [1.0, 1.0]],
+                [[-1.0, -1.0], [-1.0, 0.0], [-1.0, 1.0]],
+            ]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_sample_identity_affine_grid_2d() {
+        let x = input();
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        for align_corners in [false, true] {
+            let grid = affine_grid_2d(theta.clone(), x.dims(), align_corners);
+            let options = GridSampleOptions {
+                align_corners,
+                ..Default::default()
+            };
+
+            let output = grid_sample_2d(x.clone(), grid, options);
+
+            output
+                .into_data()
+                .assert_approx_eq::<FT>(&x.to_data(), Tolerance::absolute(1e-3));
+        }
+    }
+
+    #[test]
+    fn should_sample_shifted_affine_grid_3d() {
+        let x = TestTensorInt::<1>::arange(0..12, &Default::default())
+            .float()
+            .reshape([1, 1, 2, 2, 3]);
+        // Shifts the width by one element.
+        let theta = TestTensor::<3>::from([[
+            [1.0, 0.0, 0.0, 2.0 / 3.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ]]);
+
+        let grid = affine_grid_3d(theta, x.dims(), false);
+        let output = grid_sample_3d(x, grid, GridSampleOptions::default());
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[
+                [[1.0, 2.0, 0.0], [4.0, 5.0, 0.0]],
+                [[7.0, 8.0, 0.0], [10.0, 11.0, 0.0]],
+            ]]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn should_grid_sample_3d_nearest() {
+        let x = TestTensorInt::<1>::arange(0..8, &Default::default())
+            .float()
+            .reshape([1, 1, 2, 2, 2]);
+        let grid = TestTensor::<5>::from([[[[[-0.9, 0.8, 0.7], [0.6, -0.7, -0.8]]]]]);
+        let options =
+            GridSampleOptions::new(GridSampleMode::Nearest, GridSamplePaddingMode::Zeros, true);
+
+        let output = grid_sample_3d(x, grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[[[[6.0, 1.0]]]]]), Tolerance::default());
+    }
+
+    fn input() -> TestTensor<4> {
+        let values = TestTensorInt::<1>::arange(0..12, &Default::default()).float();
+        let other = values
+            .clone()
+            .mul_scalar(7)
+            .remainder_scalar(5)
+            .sub_scalar(2);
+
+        TestTensor::stack::<2>(vec![values, other], 0).reshape([1, 2, 3, 4])
+    }
+
+    fn grid_sample(
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> TestTensor<4> {
+        let grid = TestTensor::<4>::from([[
+            [[-0.95, -1.0], [-0.3, 0.2], [0.9, -0.8]],
+            [[1.2, 0.5], [-1.4, 1.1], [0.1, 0.65]],
+        ]]);
+        let options = GridSampleOptions::new(mode, padding_mode, align_corners);
+
+        grid_sample_2d(input(), grid, options)
+    }
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(bessel)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_support_i0_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-2.0, 0.0, 0.5], [1.0, 3.75, 5.0]], &Default::default());
+
+        let output = tensor.i0();
+        let expected = TensorData::from([
+            [2.2795854, 1.0, 1.0634834],
+            [1.2660659, 9.118946, 27.239872],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_i1_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-2.0, 0.0, 0.5], [1.0, 3.75, 5.0]], &Default::default());
+
+        let output = tensor.i1();
+        let expected = TensorData::from([
+            [-1.5906368, 0.0, 0.2578943],
+            [0.5651591, 7.780015, 24.335642],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(bincount)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_support_bincount() {
+        let tensor = TestTensorInt::<1>::from([1, 3, 1, 0, 1]);
+
+        let output = tensor.bincount(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 0, 1]), false);
+    }
+
+    #[test]
+    fn should_support_bincount_minlength() {
+        let tensor = TestTensorInt::<1>::from([2, 0]);
+
+        let output = tensor.bincount(5);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 0, 1, 0, 0]), false);
+    }
+
+    #[test]
+    fn should_support_bincount_weighted() {
+        let tensor = TestTensorInt::<1>::from([1, 3, 1, 0]);
+        let weights = TestTensor::<1>::from([0.5, 1.0, 2.0, 0.25]);
+
+        let output = tensor.bincount_weighted(weights, 0);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.25, 2.5, 0.0, 1.0]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_bincount_negative_values() {
+        let tensor = TestTensorInt::<1>::from([1, -1]);
+
+        let _output = tensor.bincount(0);
+    }
+}
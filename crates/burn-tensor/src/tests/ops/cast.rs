@@ -53,4 +53,33 @@ mod tests {
             .into_data()
             .assert_approx_eq::<FT>(&data, Tolerance::default());
     }
+
+    #[test]
+    fn cast_float_to_e4m3() {
+        let tensor = TestTensor::<1>::from([1.07, -300.0, 0.3, 110.0]);
+
+        let output = tensor.cast(DType::E4M3);
+
+        assert_eq!(output.dtype(), DType::E4M3);
+        // The values are rounded to the nearest 8-bit float
+        let expected = TensorData::from([1.125, -288.0, 0.3125, 112.0]);
+        output
+            .cast(DType::F32)
+            .into_data()
+            .assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn cast_float_to_e5m2() {
+        let tensor = TestTensor::<1>::from([1.07, -300.0, 0.3, 110.0]);
+
+        let output = tensor.cast(DType::E5M2);
+
+        assert_eq!(output.dtype(), DType::E5M2);
+        let expected = TensorData::from([1.0, -320.0, 0.3125, 112.0]);
+        output
+            .cast(DType::F32)
+            .into_data()
+            .assert_eq(&expected, false);
+    }
 }
@@ -0,0 +1,168 @@
+#[burn_tensor_testgen::testgen(complex)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Tensor, TensorData};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn complex<const D: usize>(
+        real: TestTensor<D>,
+        imag: TestTensor<D>,
+    ) -> Tensor<TestBackend, D, Complex> {
+        Tensor::from_parts(real, imag)
+    }
+
+    #[test]
+    fn should_support_from_data_and_into_parts() {
+        let data = TensorData::complex(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0]]),
+            TensorData::from([[-1.0, 0.5], [0.0, 2.0]]),
+        );
+        let tensor =
+            Tensor::<TestBackend, 2, Complex>::from_data(data.clone(), &Default::default());
+
+        tensor
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&data, Tolerance::default());
+
+        let (real, imag) = tensor.into_parts();
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 2.0], [3.0, 4.0]]),
+            Tolerance::default(),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[-1.0, 0.5], [0.0, 2.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_support_add_sub_neg() {
+        let lhs = complex::<1>(TestTensor::from([1.0, 2.0]), TestTensor::from([3.0, -1.0]));
+        let rhs = complex::<1>(TestTensor::from([0.5, -2.0]), TestTensor::from([1.0, 1.0]));
+
+        let added = lhs.clone().add(rhs.clone()).into_parts();
+        added
+            .0
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.5, 0.0]), Tolerance::default());
+        added
+            .1
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([4.0, 0.0]), Tolerance::default());
+
+        let subtracted = lhs.sub(rhs).neg().into_parts();
+        subtracted
+            .0
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([-0.5, -4.0]), Tolerance::default());
+        subtracted
+            .1
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([-2.0, 2.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_mul_div() {
+        let lhs = complex::<1>(TestTensor::from([1.0, 3.0]), TestTensor::from([2.0, -1.0]));
+        let rhs = complex::<1>(TestTensor::from([3.0, 0.0]), TestTensor::from([-1.0, 2.0]));
+
+        // (1 + 2i)(3 - i) = 5 + 5i, (3 - i)(2i) = 2 + 6i
+        let (real, imag) = lhs.clone().mul(rhs.clone()).into_parts();
+        real.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([5.0, 2.0]), Tolerance::default());
+        imag.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([5.0, 6.0]), Tolerance::default());
+
+        // (1 + 2i) / (3 - i) = 0.1 + 0.7i, (3 - i) / (2i) = -0.5 - 1.5i
+        let (real, imag) = lhs.div(rhs).into_parts();
+        real.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.1, -0.5]), Tolerance::default());
+        imag.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.7, -1.5]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_conj_abs_angle() {
+        let tensor = complex::<1>(
+            TestTensor::from([3.0, 0.0, -1.0, 0.0]),
+            TestTensor::from([4.0, 2.0, 0.0, 0.0]),
+        );
+
+        let conj = tensor.clone().conj().imag();
+        conj.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([-4.0, -2.0, 0.0, 0.0]),
+            Tolerance::default(),
+        );
+
+        let abs = tensor.clone().abs();
+        abs.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([5.0, 2.0, 1.0, 0.0]),
+            Tolerance::default(),
+        );
+
+        let angle = tensor.angle();
+        angle.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                0.927_295_2,
+                core::f32::consts::FRAC_PI_2,
+                core::f32::consts::PI,
+                0.0,
+            ]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_support_matmul() {
+        let lhs = complex::<2>(
+            TestTensor::from([[1.0, 0.0], [0.0, 1.0]]),
+            TestTensor::from([[0.0, 1.0], [1.0, 0.0]]),
+        );
+        let rhs = complex::<2>(
+            TestTensor::from([[1.0, 2.0], [3.0, 4.0]]),
+            TestTensor::from([[1.0, 0.0], [0.0, -1.0]]),
+        );
+
+        // [[1, i], [i, 1]] x [[1 + i, 2], [3, 4 - i]]
+        let (real, imag) = lhs.matmul(rhs).into_parts();
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 3.0], [2.0, 4.0]]),
+            Tolerance::default(),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[4.0, 4.0], [1.0, 1.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_support_reshape_and_slice() {
+        let tensor = complex::<1>(
+            TestTensor::from([1.0, 2.0, 3.0, 4.0]),
+            TestTensor::from([-1.0, -2.0, -3.0, -4.0]),
+        );
+
+        let output = tensor.reshape([2, 2]).slice([1..2, 0..2]);
+        let expected = TensorData::complex(
+            TensorData::from([[3.0, 4.0]]),
+            TensorData::from([[-3.0, -4.0]]),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_from_real() {
+        let tensor = Tensor::<TestBackend, 1, Complex>::from_real(TestTensor::from([1.0, -2.0]));
+        let expected =
+            TensorData::complex(TensorData::from([1.0, -2.0]), TensorData::from([0.0, 0.0]));
+
+        tensor
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
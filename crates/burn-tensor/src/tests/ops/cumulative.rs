@@ -0,0 +1,138 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_cumsum_float_dim_0() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum(0);
+        let expected = TensorData::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cumsum_float_dim_1() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum(1);
+        let expected = TensorData::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cumsum_int() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        let output = tensor.cumsum(1);
+        let expected = TensorData::from([[0, 1, 3], [3, 7, 12]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cumsum_3d_middle_dim() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+
+        let output = tensor.cumsum(1);
+        let expected = TensorData::from([[[1.0, 2.0], [4.0, 6.0]], [[5.0, 6.0], [12.0, 14.0]]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cumprod_float() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]]);
+
+        let output = tensor.cumprod(1);
+        let expected = TensorData::from([[1.0, 2.0, 6.0], [-1.0, -0.5, -2.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cumprod_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [2, 0, 4]]);
+
+        let output = tensor.cumprod(1);
+        let expected = TensorData::from([[1, 2, 6], [2, 0, 0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cummax_float() {
+        let tensor = TestTensor::<2>::from([[1.0, 3.0, 2.0, 3.0], [4.0, 1.0, 5.0, 0.0]]);
+
+        let (output, indices) = tensor.cummax_with_indices(1);
+        let expected = TensorData::from([[1.0, 3.0, 3.0, 3.0], [4.0, 4.0, 5.0, 5.0]]);
+        let expected_indices = TensorData::from([[0, 1, 1, 3], [0, 0, 2, 2]]);
+
+        output.into_data().assert_eq(&expected, false);
+        indices.into_data().assert_eq(&expected_indices, false);
+    }
+
+    #[test]
+    fn test_cummin_float() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0, 2.0, 1.0], [4.0, 5.0, 0.0, 6.0]]);
+
+        let (output, indices) = tensor.cummin_with_indices(1);
+        let expected = TensorData::from([[3.0, 1.0, 1.0, 1.0], [4.0, 4.0, 0.0, 0.0]]);
+        let expected_indices = TensorData::from([[0, 1, 1, 3], [0, 0, 2, 2]]);
+
+        output.into_data().assert_eq(&expected, false);
+        indices.into_data().assert_eq(&expected_indices, false);
+    }
+
+    #[test]
+    fn test_cummax_int_dim_0() {
+        let tensor = TestTensorInt::<2>::from([[1, 5], [3, 2], [2, 7]]);
+
+        let (output, indices) = tensor.cummax_with_indices(0);
+        let expected = TensorData::from([[1, 5], [3, 5], [3, 7]]);
+        let expected_indices = TensorData::from([[0, 0], [1, 0], [1, 2]]);
+
+        output.into_data().assert_eq(&expected, false);
+        indices.into_data().assert_eq(&expected_indices, false);
+    }
+
+    #[test]
+    fn test_cummin_int() {
+        let tensor = TestTensorInt::<1>::from([4, 2, 3, 1, 5]);
+
+        let output = tensor.cummin(0);
+        let expected = TensorData::from([4, 2, 2, 1, 1]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_logcumsumexp() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [-1.0, 0.0, 3.0]]);
+
+        let output = tensor.logcumsumexp(1);
+        let expected =
+            TensorData::from([[0.0, 1.3132616, 2.4076060], [-1.0, 0.3132616, 3.0658839]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_logcumsumexp_neg_inf() {
+        let tensor = TestTensor::<1>::from([f32::NEG_INFINITY, f32::NEG_INFINITY, 0.0]);
+
+        let output = tensor.logcumsumexp(0);
+        let expected = TensorData::from([f32::NEG_INFINITY, f32::NEG_INFINITY, 0.0]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
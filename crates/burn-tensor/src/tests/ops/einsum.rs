@@ -0,0 +1,234 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData, einsum};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_einsum_matmul() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, -1.0], [0.5, 2.0], [-2.0, 1.0]]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("ij,jk->ik", &[lhs, rhs]);
+        let expected = TensorData::from([[-4.0, 6.0], [-5.5, 12.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_implicit_output() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, -1.0], [0.5, 2.0], [-2.0, 1.0]]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("ij,jk", &[lhs, rhs]);
+        let expected = TensorData::from([[-4.0, 6.0], [-5.5, 12.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_attention_scores() {
+        let q = TestTensor::<4>::from([[
+            [[0.1, 0.2, 0.3], [0.4, 0.5, 0.6]],
+            [[-0.1, 0.0, 0.1], [1.0, -1.0, 0.5]],
+        ]]);
+        let k = TestTensor::<4>::from([[
+            [[1.0, 0.0, -1.0], [0.5, 0.5, 0.5], [2.0, 1.0, 0.0]],
+            [[0.0, 1.0, 0.0], [-1.0, 2.0, 1.0], [0.3, 0.3, 0.3]],
+        ]]);
+
+        let output: Tensor<TestBackend, 4> = Tensor::einsum("bhqd,bhkd->bhqk", &[q, k]);
+        let expected = TensorData::from([[
+            [[-0.2, 0.3, 0.4], [-0.2, 0.75, 1.3]],
+            [[0.0, 0.2, 0.0], [-1.0, -2.5, 0.15]],
+        ]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_transpose() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("ij->ji", &[tensor]);
+        let expected = TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_einsum_sum_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output: Tensor<TestBackend, 1> = Tensor::einsum("ij->i", &[tensor]);
+        let expected = TensorData::from([6.0, 15.0]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_diagonal() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let output: Tensor<TestBackend, 1> = Tensor::einsum("ii->i", &[tensor]);
+        let expected = TensorData::from([1.0, 5.0, 9.0]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_batched_diagonal() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [-1.0, 0.5]]]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("bii->bi", &[tensor]);
+        let expected = TensorData::from([[1.0, 4.0], [0.0, 0.5]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_outer_product() {
+        let lhs = TestTensor::<1>::from([1.0, 2.0]);
+        let rhs = TestTensor::<1>::from([3.0, 4.0, 5.0]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("i,j->ij", &[lhs, rhs]);
+        let expected = TensorData::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_elementwise_product() {
+        let lhs = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let rhs = TestTensor::<1>::from([4.0, 5.0, 6.0]);
+
+        let output: Tensor<TestBackend, 1> = Tensor::einsum("i,i->i", &[lhs, rhs]);
+        let expected = TensorData::from([4.0, 10.0, 18.0]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_row_wise_dot_product() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 1.0, 1.0], [0.5, 0.5, 0.5]]);
+
+        let output: Tensor<TestBackend, 1> = Tensor::einsum("ij,ij->i", &[lhs, rhs]);
+        let expected = TensorData::from([6.0, 7.5]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_three_operands() {
+        let a = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = TestTensor::<2>::from([[1.0, -1.0], [0.5, 2.0], [-2.0, 1.0]]);
+        let c = TestTensor::<2>::from([[1.0, 0.0], [0.0, 2.0]]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("ij,jk,kl->il", &[a, b, c]);
+        let expected = TensorData::from([[-4.0, 12.0], [-5.5, 24.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_broadcast_batch() {
+        let lhs = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [-1.0, 0.5]]]);
+        let rhs = TestTensor::<3>::from([[[2.0, 0.0], [1.0, 1.0]]]);
+
+        let output: Tensor<TestBackend, 3> = Tensor::einsum("bij,bjk->bik", &[lhs, rhs]);
+        let expected = TensorData::from([[[4.0, 2.0], [10.0, 4.0]], [[1.0, 1.0], [-1.5, 0.5]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_ellipsis() {
+        let lhs = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [-1.0, 0.5]]]);
+        let rhs = TestTensor::<3>::from([[[2.0, 0.0], [1.0, 1.0]]]);
+
+        let output: Tensor<TestBackend, 3> = Tensor::einsum("...ij,...jk", &[lhs, rhs]);
+        let expected = TensorData::from([[[4.0, 2.0], [10.0, 4.0]], [[1.0, 1.0], [-1.5, 0.5]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_matrix_vector() {
+        let matrix = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let vector = TestTensor::<1>::from([1.0, -1.0, 0.5]);
+
+        let output: Tensor<TestBackend, 1> = einsum("ij,j->i", (matrix, vector));
+        let expected = TensorData::from([0.5, 2.0]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_einsum_dynamic_rank_operands() {
+        let lhs = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [0.5, 2.0]]]);
+        let rhs = TestTensor::<1>::from([2.0, -1.0]);
+
+        let output: Tensor<TestBackend, 2> =
+            einsum("bij,j->bi", vec![lhs.into_dyn(), rhs.into_dyn()]);
+        let expected = TensorData::from([[0.0, 2.0], [-2.0, -1.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_subscripts_dont_match_rank() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output: Tensor<TestBackend, 2> = Tensor::einsum("ijk,jk->ik", &[lhs, rhs]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sizes_are_incompatible() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output: Tensor<TestBackend, 2> = Tensor::einsum("ij,jk->ik", &[lhs, rhs]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_output_rank_is_invalid() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output: Tensor<TestBackend, 3> = Tensor::einsum("ij,jk->ik", &[lhs, rhs]);
+    }
+}
@@ -35,4 +35,51 @@ mod tests {
             Tolerance::default().set_half_precision_absolute(3e-3),
         );
     }
+
+    #[test]
+    fn should_support_erfc_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-1.0, 0.0, 0.5], [1.0, 2.0, 3.0]], &Default::default());
+
+        let output = tensor.erfc();
+        let expected = TensorData::from([
+            [1.8427008, 1.0, 0.47950011],
+            [0.15729921, 0.004677735, 0.000022090497],
+        ]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_absolute(2e-3),
+        );
+    }
+
+    #[test]
+    fn should_support_erfinv_ops() {
+        let tensor = TestTensor::<2>::from_data(
+            [[-0.9, -0.5, 0.0], [0.3, 0.99, 0.999]],
+            &Default::default(),
+        );
+
+        let output = tensor.erfinv();
+        let expected = TensorData::from([
+            [-1.1630871, -0.47693628, 0.0],
+            [0.27246272, 1.8213864, 2.3267538],
+        ]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_relative(2e-2),
+        );
+    }
+
+    #[test]
+    fn should_support_erfinv_ops_at_bounds() {
+        let tensor = TestTensor::<1>::from_data([-1.0, 1.0], &Default::default());
+
+        let output = tensor.erfinv();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([f32::NEG_INFINITY, f32::INFINITY]), false);
+    }
 }
@@ -17,4 +17,20 @@ mod tests {
             .into_data()
             .assert_approx_eq::<FT>(&expected, Tolerance::default());
     }
+
+    #[test]
+    fn should_support_expm1_ops() {
+        let data = TensorData::from([[-1.0, -1e-4, 0.0], [1e-5, 0.3, 2.0]]);
+        let tensor = TestTensor::<2>::from_data(data, &Default::default());
+
+        let output = tensor.expm1();
+        let expected = TensorData::from([
+            [-0.63212055, -0.000099995, 0.0],
+            [0.00001000005, 0.3498588, 6.389056],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::relative(1e-4));
+    }
 }
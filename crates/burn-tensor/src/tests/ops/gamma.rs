@@ -0,0 +1,106 @@
+#[burn_tensor_testgen::testgen(gamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_support_lgamma_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[0.5, 1.0, 1.5], [2.0, 3.5, 10.0]], &Default::default());
+
+        let output = tensor.lgamma();
+        let expected =
+            TensorData::from([[0.5723649, 0.0, -0.12078224], [0.0, 1.2009736, 12.801827]]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_absolute(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_support_lgamma_ops_with_negative_number() {
+        let tensor = TestTensor::<1>::from_data([-0.5, -1.5, -2.3, 0.1], &Default::default());
+
+        let output = tensor.lgamma();
+        let expected = TensorData::from([1.2655121, 0.860047, 0.36956667, 2.2527127]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_absolute(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_support_digamma_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[0.5, 1.0, 1.5], [2.0, 3.5, 10.0]], &Default::default());
+
+        let output = tensor.digamma();
+        let expected = TensorData::from([
+            [-1.96351, -0.5772157, 0.036489975],
+            [0.42278433, 1.1031567, 2.2517526],
+        ]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_absolute(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_support_digamma_ops_with_negative_number() {
+        let tensor = TestTensor::<1>::from_data([-0.5, -1.5, -2.3, 0.1], &Default::default());
+
+        let output = tensor.digamma();
+        let expected = TensorData::from([0.036489975, 0.70315665, 3.3173232, -10.423755]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::default().set_half_precision_relative(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_support_polygamma_ops() {
+        let tensor = TestTensor::<1>::from_data([0.5, 1.0, 2.0, 3.5, 10.0], &Default::default());
+
+        let output = tensor.clone().polygamma(1);
+        let expected = TensorData::from([4.934802, 1.644934, 0.644934, 0.33035776, 0.105166335]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+
+        let output = tensor.clone().polygamma(2);
+        let expected = TensorData::from([
+            -16.828796,
+            -2.4041138,
+            -0.4041138,
+            -0.10820405,
+            -0.011049835,
+        ]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+
+        let output = tensor.polygamma(3);
+        let expected = TensorData::from([97.40909, 6.4939394, 0.4939394, 0.07030585, 0.0023199013]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_polygamma_ops_of_order_zero() {
+        let tensor = TestTensor::<1>::from_data([0.5, 2.0, 10.0], &Default::default());
+
+        let output = tensor.clone().polygamma(0);
+        let expected = tensor.digamma();
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+}
@@ -0,0 +1,142 @@
+#[burn_tensor_testgen::testgen(gather_nd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_gather_nd_elements() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0], [2.0, 3.0]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[0, 0], [1, 1], [1, 0]], &device);
+
+        let output: TestTensor<1> = tensor.gather_nd(indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 3.0, 2.0]), false);
+    }
+
+    #[test]
+    fn should_gather_nd_slices() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]],
+            &device,
+        );
+        let indices = TestTensorInt::<3>::from_ints([[[0, 1]], [[1, 0]]], &device);
+
+        let output: TestTensor<3> = tensor.gather_nd(indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[2.0, 3.0]], [[4.0, 5.0]]]), false);
+    }
+
+    #[test]
+    fn should_gather_nd_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2], [3, 4, 5]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[1], [0], [1]], &device);
+
+        let output: TestTensorInt<2> = tensor.gather_nd(indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 4, 5], [0, 1, 2], [3, 4, 5]]), false);
+    }
+
+    #[test]
+    fn should_scatter_nd_replace() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[1, 2], [0, 0]], &device);
+        let values = TestTensor::<1>::from_floats([10.0, 20.0], &device);
+
+        let output = tensor.scatter_nd(indices, values, false);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[20.0, 1.0, 2.0], [3.0, 4.0, 10.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_scatter_nd_accumulate() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[1], [1]], &device);
+        let values = TestTensor::<2>::from_floats([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]], &device);
+
+        let output = tensor.scatter_nd(indices, values, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 1.0, 2.0], [6.0, 7.0, 8.0]]), false);
+    }
+
+    #[test]
+    fn should_scatter_nd_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<3>::zeros([2, 2, 2], &device);
+        let indices = TestTensorInt::<2>::from_ints([[0, 1], [1, 0]], &device);
+        let values = TestTensorInt::<2>::from_ints([[1, 2], [3, 4]], &device);
+
+        let output = tensor.scatter_nd(indices, values, false);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[0, 0], [1, 2]], [[3, 4], [0, 0]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_index_put() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+        let rows = TestTensorInt::<1>::from_ints([0, 1, 0], &device);
+        let cols = TestTensorInt::<1>::from_ints([2, 0, 1], &device);
+        let values = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let output = tensor.index_put(vec![rows, cols], values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 3.0, 1.0], [2.0, 0.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_index_put_accumulate() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::ones([2, 3], &device);
+        let rows = TestTensorInt::<1>::from_ints([0, 1, 0], &device);
+        let cols = TestTensorInt::<1>::from_ints([2, 0, 2], &device);
+        let values = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let output = tensor.index_put(vec![rows, cols], values, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0, 5.0], [3.0, 1.0, 1.0]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_gather_nd_wrong_output_rank() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0], [2.0, 3.0]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[0, 0]], &device);
+
+        let _output: TestTensor<2> = tensor.gather_nd(indices);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_scatter_nd_wrong_values_shape() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+        let indices = TestTensorInt::<2>::from_ints([[0], [1]], &device);
+        let values = TestTensor::<2>::ones([2, 2], &device);
+
+        let _output = tensor.scatter_nd(indices, values, false);
+    }
+}
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(inverse_hyperbolic)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_support_asinh_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-2.0, -0.5, 0.0], [1e-3, 1.0, 10.0]], &Default::default());
+
+        let output = tensor.asinh();
+        let expected = TensorData::from([
+            [-1.4436355, -0.4812118, 0.0],
+            [0.0009999998, 0.8813736, 2.998223],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_acosh_ops() {
+        let tensor = TestTensor::<1>::from_data([1.0, 1.5, 2.0, 10.0], &Default::default());
+
+        let output = tensor.acosh();
+        let expected = TensorData::from([0.0, 0.9624237, 1.3169579, 2.9932228]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_atanh_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-0.9, -0.5, 0.0], [1e-3, 0.5, 0.9]], &Default::default());
+
+        let output = tensor.atanh();
+        let expected = TensorData::from([
+            [-1.4722195, -0.54930615, 0.0],
+            [0.0010000003, 0.54930615, 1.4722195],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
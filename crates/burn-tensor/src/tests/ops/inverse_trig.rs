@@ -0,0 +1,100 @@
+#[burn_tensor_testgen::testgen(inverse_trig)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_support_asin_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-1.0, -0.5, 0.0], [0.3, 0.9, 1.0]], &Default::default());
+
+        let output = tensor.asin();
+        let expected = TensorData::from([
+            [-1.5707964, -0.5235988, 0.0],
+            [0.30469266, 1.1197695, 1.5707964],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_acos_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-1.0, -0.5, 0.0], [0.3, 0.9, 1.0]], &Default::default());
+
+        let output = tensor.acos();
+        let expected = TensorData::from([
+            [3.1415927, 2.0943952, 1.5707964],
+            [1.2661037, 0.4510268, 0.0],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_atan_ops() {
+        let tensor =
+            TestTensor::<2>::from_data([[-10.0, -1.0, -0.3], [0.0, 0.5, 2.0]], &Default::default());
+
+        let output = tensor.atan();
+        let expected = TensorData::from([
+            [-1.4711277, -0.7853982, -0.2914568],
+            [0.0, 0.4636476, 1.1071488],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_atan2_ops() {
+        let device = Default::default();
+        let y = TestTensor::<2>::from_data([[1.0, 1.0, -1.0], [-1.0, 0.0, 2.0]], &device);
+        let x = TestTensor::<2>::from_data([[1.0, -1.0, -1.0], [1.0, -1.0, 0.0]], &device);
+
+        let output = y.atan2(x);
+        let expected = TensorData::from([
+            [0.7853982, 2.3561945, -2.3561945],
+            [-0.7853982, 3.1415927, 1.5707964],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_atan2_ops_with_broadcast() {
+        let device = Default::default();
+        let y = TestTensor::<2>::from_data([[0.0], [1.0]], &device);
+        let x = TestTensor::<2>::from_data([[0.0, -1.0]], &device);
+
+        let output = y.atan2(x);
+        let expected = TensorData::from([[0.0, 3.1415927], [1.5707964, 2.3561945]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_hypot_ops() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_data([[3.0, -5.0], [0.0, 1.0]], &device);
+        let rhs = TestTensor::<2>::from_data([[4.0, 12.0], [0.0, 1.0]], &device);
+
+        let output = lhs.hypot(rhs);
+        let expected = TensorData::from([[5.0, 13.0], [0.0, 1.4142135]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
@@ -21,4 +21,30 @@ mod tests {
             Tolerance::default().set_half_precision_relative(1e-3),
         );
     }
+
+    #[test]
+    fn should_support_log2_ops() {
+        let data = TensorData::from([0.5, 1.0, 2.0, 10.0, 1024.0]);
+        let tensor = TestTensor::<1>::from_data(data, &Default::default());
+
+        let output = tensor.log2();
+        let expected = TensorData::from([-1.0, 0.0, 1.0, 3.321928, 10.0]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_log10_ops() {
+        let data = TensorData::from([0.5, 1.0, 2.0, 10.0, 1000.0]);
+        let tensor = TestTensor::<1>::from_data(data, &Default::default());
+
+        let output = tensor.log10();
+        let expected = TensorData::from([-0.30103, 0.0, 0.30103, 1.0, 3.0]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
 }
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(logsumexp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_logsumexp() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [-1.0, 0.5, 3.0]]);
+
+        let output = tensor.logsumexp(1);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[2.407606], [3.0989265]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_logsumexp_dim_0() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0], [0.0, 1.0]]);
+
+        let output = tensor.logsumexp(0);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.6931472, 1.6931472]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_logsumexp_large_values() {
+        let tensor = TestTensor::<2>::from([[1000.0, 1000.0], [-1000.0, -1000.0]]);
+
+        let output = tensor.logsumexp(1);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1000.6931], [-999.3069]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_logsumexp_infinite_values() {
+        let tensor = TestTensor::<2>::from([
+            [f32::NEG_INFINITY, f32::NEG_INFINITY],
+            [f32::NEG_INFINITY, 0.0],
+            [f32::INFINITY, 1.0],
+        ]);
+
+        let output = tensor.logsumexp(1);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[f32::NEG_INFINITY], [0.0], [f32::INFINITY]]),
+            Tolerance::default(),
+        );
+    }
+}
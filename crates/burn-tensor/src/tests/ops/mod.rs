@@ -7,6 +7,8 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod bessel;
+mod bincount;
 mod bitwise;
 mod bool;
 mod cartesian_grid;
@@ -16,10 +18,13 @@ mod ceil;
 mod chunk;
 mod clamp;
 mod close;
+mod complex;
 mod cos;
 mod cosh;
 mod create_like;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
@@ -28,19 +33,26 @@ mod flatten;
 mod flip;
 mod floor;
 mod full;
+mod gamma;
+mod gather_nd;
 mod gather_scatter;
 mod inf;
 mod init;
+mod inverse_hyperbolic;
+mod inverse_trig;
 mod iter_dim;
 mod log;
 mod log1p;
+mod logsumexp;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
 mod movedim;
 mod mul;
+mod multinomial;
 mod nan;
+mod nan_reduce;
 mod narrow;
 mod neg;
 mod one_hot;
@@ -57,6 +69,7 @@ mod repeat_dim;
 mod reshape;
 mod roll;
 mod round;
+mod searchsorted;
 mod select;
 mod sign;
 mod sin;
@@ -70,7 +83,9 @@ mod stack;
 mod sub;
 mod tan;
 mod tanh;
+mod tensor_dyn;
 mod topk;
 mod transpose;
 mod tri;
 mod tri_mask;
+mod unique;
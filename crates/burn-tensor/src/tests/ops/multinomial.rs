@@ -0,0 +1,69 @@
+#[burn_tensor_testgen::testgen(multinomial)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, cast::ToElement};
+
+    #[test]
+    fn should_sample_one_hot_probabilities() {
+        let probs = TestTensor::<2>::from([[0.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+
+        let samples = probs.multinomial(4, true);
+
+        samples
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 1, 1, 1], [2, 2, 2, 2]]), false);
+    }
+
+    #[test]
+    fn should_never_sample_zero_weights() {
+        let probs = TestTensor::<1>::from([0.0, 0.3, 0.0, 0.7, 0.0]);
+
+        let samples = probs.clone().multinomial(1000, true);
+        let drawn = probs.select(0, samples).min().into_scalar().to_f64();
+
+        assert!(drawn > 0.);
+    }
+
+    #[test]
+    fn should_sample_with_replacement_frequencies() {
+        let probs = TestTensor::<1>::from([1.0, 3.0]);
+
+        let samples = probs.multinomial(10_000, true);
+        let ones = samples.sum().into_scalar().to_f64();
+
+        assert!((ones / 10_000. - 0.75).abs() < 0.03);
+    }
+
+    #[test]
+    fn should_sample_without_replacement() {
+        let probs = TestTensor::<2>::from([[0.1, 0.2, 0.3, 0.4], [1.0, 0.0, 1.0, 1.0]]);
+
+        let samples = probs.multinomial(3, false);
+        let [batch_size, num_samples] = samples.dims();
+        assert_eq!([batch_size, num_samples], [2, 3]);
+
+        let data = samples
+            .into_data()
+            .convert::<i64>()
+            .to_vec::<i64>()
+            .unwrap();
+        let mut first = data[..3].to_vec();
+        first.sort();
+        first.dedup();
+        assert_eq!(first.len(), 3);
+
+        // The category with a zero weight is drawn last.
+        let mut second = data[3..].to_vec();
+        second.sort();
+        assert_eq!(second, [0, 2, 3]);
+    }
+
+    #[test]
+    fn should_keep_leading_dims() {
+        let probs = TestTensor::<3>::ones([2, 3, 4], &Default::default());
+
+        let samples = probs.multinomial(5, true);
+
+        assert_eq!(samples.dims(), [2, 3, 5]);
+    }
+}
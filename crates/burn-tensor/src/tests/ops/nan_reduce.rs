@@ -0,0 +1,148 @@
+#[burn_tensor_testgen::testgen(nan_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn tensor_with_nan() -> TestTensor<2> {
+        TestTensor::from([
+            [1.0, f32::NAN, 3.0],
+            [f32::NAN, f32::NAN, f32::NAN],
+            [5.0, -2.0, 6.0],
+        ])
+    }
+
+    #[test]
+    fn test_nansum() {
+        let output = tensor_with_nan().nansum();
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([13.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_nansum_dim() {
+        let output = tensor_with_nan().nansum_dim(1);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[4.0], [0.0], [9.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_nanmean() {
+        let output = tensor_with_nan().nanmean();
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([2.6]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_nanmean_dim() {
+        let output = tensor_with_nan().nanmean_dim(1);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[2.0], [f32::NAN], [3.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_nanmax() {
+        let output = tensor_with_nan().nanmax();
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([6.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_nanmax_dim() {
+        let output = tensor_with_nan().nanmax_dim(1);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[3.0], [f32::NAN], [6.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_nanmin_dim() {
+        let output = tensor_with_nan().nanmin_dim(0);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[1.0, -2.0, 3.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_nanmin_all_nan() {
+        let tensor = TestTensor::<1>::from([f32::NAN, f32::NAN]);
+
+        let output = tensor.nanmin();
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([f32::NAN]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_sum_masked() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, f32::NAN, 6.0]]);
+        let mask = TestTensorBool::<2>::from([[true, false, true], [false, false, true]]);
+
+        let output = tensor.sum_masked(mask);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([10.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_sum_masked_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, f32::NAN, 6.0]]);
+        let mask = TestTensorBool::<2>::from([[true, false, true], [false, false, true]]);
+
+        let output = tensor.sum_masked_dim(mask, 0);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[1.0, 0.0, 9.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_mean_masked_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+        let mask = TestTensorBool::<2>::from([[true, false, true], [false, true, false]]);
+
+        let output = tensor.mean_masked_dim(mask, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.0], [9.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_mean_masked() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+        let mask = TestTensorBool::<2>::from([[true, false, true], [false, true, false]]);
+
+        let output = tensor.mean_masked(mask);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([13.0 / 3.0]), Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_masked_dim_shape_mismatch() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+        let mask = TestTensorBool::<2>::from([[true, false], [false, true]]);
+
+        let _output = tensor.sum_masked_dim(mask, 1);
+    }
+}
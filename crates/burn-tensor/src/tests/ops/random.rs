@@ -60,4 +60,106 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [FloatType::new(1f32); 20].into());
     }
+
+    fn sample_mean(tensor: TestTensor<1>) -> f64 {
+        tensor.mean().into_scalar().to_f64()
+    }
+
+    #[test]
+    fn rand_truncated_normal() {
+        let tensor = TestTensor::<1>::random(
+            [10_000],
+            Distribution::TruncatedNormal(0., 1., 1.5, 3.),
+            &Default::default(),
+        );
+
+        tensor
+            .into_data()
+            .assert_within_range_inclusive(1.5.elem::<FloatType>()..=3.elem::<FloatType>());
+    }
+
+    #[test]
+    fn rand_exponential() {
+        let tensor =
+            TestTensor::<1>::random([10_000], Distribution::Exponential(2.), &Default::default());
+
+        assert!(tensor.clone().min().into_scalar().to_f64() >= 0.);
+        assert!((sample_mean(tensor) - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn rand_gamma() {
+        let tensor =
+            TestTensor::<1>::random([10_000], Distribution::Gamma(2., 3.), &Default::default());
+
+        assert!(tensor.clone().min().into_scalar().to_f64() >= 0.);
+        assert!((sample_mean(tensor) - 6.).abs() < 0.3);
+    }
+
+    #[test]
+    fn rand_gamma_small_shape() {
+        let tensor =
+            TestTensor::<1>::random([10_000], Distribution::Gamma(0.5, 1.), &Default::default());
+
+        assert!(tensor.clone().min().into_scalar().to_f64() >= 0.);
+        assert!((sample_mean(tensor) - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn rand_beta() {
+        let tensor =
+            TestTensor::<1>::random([10_000], Distribution::Beta(2., 5.), &Default::default());
+
+        tensor
+            .clone()
+            .into_data()
+            .assert_within_range_inclusive(0.elem::<FloatType>()..=1.elem::<FloatType>());
+        assert!((sample_mean(tensor) - 2. / 7.).abs() < 0.02);
+    }
+
+    #[test]
+    fn rand_poisson() {
+        for rate in [4., 50.] {
+            let tensor =
+                TestTensor::<1>::random([10_000], Distribution::Poisson(rate), &Default::default());
+
+            assert!(tensor.clone().min().into_scalar().to_f64() >= 0.);
+            assert_eq!(
+                tensor.clone().round().into_data(),
+                tensor.clone().into_data()
+            );
+            assert!((sample_mean(tensor) - rate).abs() < rate.sqrt() * 0.1);
+        }
+    }
+
+    #[test]
+    fn rand_poisson_int() {
+        let tensor =
+            TestTensorInt::<1>::random([10_000], Distribution::Poisson(3.), &Default::default());
+
+        let mean = tensor.float().mean().into_scalar().to_f64();
+        assert!((mean - 3.).abs() < 0.2);
+    }
+
+    #[test]
+    fn rand_cauchy() {
+        let tensor =
+            TestTensor::<1>::random([10_000], Distribution::Cauchy(2., 1.), &Default::default());
+
+        // The mean is undefined, but half of the samples are below the median.
+        let below = tensor.lower_elem(2.).int().sum().into_scalar().to_f64();
+        assert!((below / 10_000. - 0.5).abs() < 0.03);
+    }
+
+    #[test]
+    fn rand_log_normal() {
+        let tensor = TestTensor::<1>::random(
+            [10_000],
+            Distribution::LogNormal(1., 0.5),
+            &Default::default(),
+        );
+
+        assert!(tensor.clone().min().into_scalar().to_f64() > 0.);
+        assert!((sample_mean(tensor.log()) - 1.).abs() < 0.05);
+    }
 }
@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(searchsorted)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_searchsorted_left() {
+        let sorted = TestTensor::<2>::from([[1.0, 3.0, 5.0, 7.0, 9.0], [2.0, 4.0, 6.0, 8.0, 10.0]]);
+        let values = TestTensor::<2>::from([[3.0, 6.0, 9.0], [1.0, 4.0, 12.0]]);
+
+        let output = sorted.searchsorted(values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3, 4], [0, 1, 5]]), false);
+    }
+
+    #[test]
+    fn should_support_searchsorted_right() {
+        let sorted = TestTensor::<2>::from([[1.0, 3.0, 5.0, 7.0, 9.0], [2.0, 4.0, 6.0, 8.0, 10.0]]);
+        let values = TestTensor::<2>::from([[3.0, 6.0, 9.0], [1.0, 4.0, 12.0]]);
+
+        let output = sorted.searchsorted(values, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 3, 5], [0, 2, 5]]), false);
+    }
+
+    #[test]
+    fn should_support_searchsorted_with_duplicates() {
+        let sorted = TestTensorInt::<1>::from([1, 2, 2, 2, 3]);
+        let values = TestTensorInt::<1>::from([2, 0, 4]);
+
+        sorted
+            .clone()
+            .searchsorted(values.clone(), false)
+            .into_data()
+            .assert_eq(&TensorData::from([1, 0, 5]), false);
+        sorted
+            .searchsorted(values, true)
+            .into_data()
+            .assert_eq(&TensorData::from([4, 0, 5]), false);
+    }
+
+    #[test]
+    fn should_support_bucketize() {
+        let tensor = TestTensor::<2>::from([[0.5, 3.0], [7.0, 4.0]]);
+        let boundaries = TestTensor::<1>::from([1.0, 3.0, 5.0]);
+
+        boundaries
+            .clone()
+            .unsqueeze::<2>()
+            .repeat_dim(0, 2)
+            .searchsorted(tensor.clone(), false)
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1], [3, 2]]), false);
+
+        tensor
+            .clone()
+            .bucketize(boundaries.clone(), false)
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1], [3, 2]]), false);
+        tensor
+            .bucketize(boundaries, true)
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 2], [3, 2]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_searchsorted_batch_dims_differ() {
+        let sorted = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let values = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let _output = sorted.searchsorted(values, false);
+    }
+}
@@ -0,0 +1,127 @@
+#[burn_tensor_testgen::testgen(tensor_dyn)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Shape, TensorData, TensorDyn};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_convert_to_and_from_static_rank() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_dyn = tensor.clone().into_dyn();
+        assert_eq!(tensor_dyn.rank(), 2);
+        assert_eq!(tensor_dyn.dims(), vec![2, 2]);
+
+        let output = tensor_dyn.into_tensor::<2>();
+        output.into_data().assert_eq(&tensor.into_data(), true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_converting_to_wrong_rank() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]).into_dyn();
+
+        let _output = tensor.into_tensor::<3>();
+    }
+
+    #[test]
+    fn should_change_rank_at_runtime() {
+        let device = Default::default();
+        let tensor = TensorDyn::<TestBackend>::from_data(
+            TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]),
+            &device,
+        );
+
+        let output = tensor.clone().unsqueeze_dim(0);
+        assert_eq!(output.dims(), vec![1, 2, 3]);
+
+        let output = output.squeeze(0).flatten(0, 1);
+        assert_eq!(output.dims(), vec![6]);
+
+        let output = tensor.reshape([3, 1, 2]).permute(&[-1, 0, 1]);
+        assert_eq!(output.dims(), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn should_support_numeric_ops() {
+        let device = Default::default();
+        let lhs = TensorDyn::<TestBackend>::from_data(
+            TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]),
+            &device,
+        );
+        let rhs = TensorDyn::<TestBackend>::ones([2, 3], &device);
+
+        let output = (lhs.clone() + rhs) * 2.0 - lhs.exp().log();
+        let expected = TensorData::from([[2.0, 3.0, 4.0], [5.0, 6.0, 7.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_matmul_and_slice() {
+        let device = Default::default();
+        let lhs = TensorDyn::<TestBackend>::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0]]),
+            &device,
+        );
+        let rhs = TensorDyn::<TestBackend>::from_data(
+            TensorData::from([[1.0, 0.0], [0.0, 1.0]]),
+            &device,
+        );
+
+        let output = lhs.matmul(rhs).slice([0..2, 1..2]);
+        let expected = TensorData::from([[2.0], [4.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_cat_and_stack() {
+        let device = Default::default();
+        let tensor = TensorDyn::<TestBackend, Int>::from_data(TensorData::from([1, 2]), &device);
+
+        let output = TensorDyn::cat(vec![tensor.clone(), tensor.clone()], 0);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 1, 2]), false);
+
+        let output = TensorDyn::stack(vec![tensor.clone(), tensor], 0);
+        assert_eq!(output.shape(), Shape::new([2, 2]));
+    }
+
+    #[test]
+    fn should_support_comparison_ops() {
+        let device = Default::default();
+        let tensor = TensorDyn::<TestBackend, Int>::from_data(TensorData::from([1, 5, 3]), &device);
+
+        let output = tensor.greater_elem(2).int();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 1]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_on_binary_ops_with_different_ranks() {
+        let device = Default::default();
+        let lhs = TensorDyn::<TestBackend>::ones([2, 3], &device);
+        let rhs = TensorDyn::<TestBackend>::ones([2, 3, 1], &device);
+
+        let _output = lhs + rhs;
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_on_out_of_bounds_dim() {
+        let device = Default::default();
+        let tensor = TensorDyn::<TestBackend>::ones([2, 3], &device);
+
+        let _output = tensor.repeat_dim(2, 2);
+    }
+}
@@ -0,0 +1,88 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_unique_int() {
+        let tensor = TestTensorInt::<2>::from([[3, 1, 3], [2, 1, 5]]);
+
+        let output = tensor.unique();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3, 5]), false);
+    }
+
+    #[test]
+    fn should_support_unique_float() {
+        let tensor = TestTensor::<1>::from([2.5, -1.0, 2.5, 0.0, -1.0]);
+
+        let output = tensor.unique();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0, 0.0, 2.5]), false);
+    }
+
+    #[test]
+    fn should_support_unique_with_inverse_and_counts() {
+        let tensor = TestTensorInt::<2>::from([[3, 1, 3], [2, 1, 5]]);
+
+        let (unique, inverse, counts) = tensor.unique_with_inverse_and_counts();
+
+        unique
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3, 5]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 0, 2], [1, 0, 3]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 1, 2, 1]), false);
+    }
+
+    #[test]
+    fn should_support_unique_single_value() {
+        let tensor = TestTensorInt::<1>::from([4, 4, 4]);
+
+        let (unique, inverse, counts) = tensor.unique_with_inverse_and_counts();
+
+        unique.into_data().assert_eq(&TensorData::from([4]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 0]), false);
+        counts.into_data().assert_eq(&TensorData::from([3]), false);
+    }
+
+    #[test]
+    fn should_support_unique_consecutive() {
+        let tensor = TestTensorInt::<1>::from([1, 1, 2, 2, 3, 1, 1]);
+
+        let (unique, inverse, counts) = tensor.unique_consecutive_with_inverse_and_counts();
+
+        unique
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3, 1]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 1, 1, 2, 3, 3]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 2, 1, 2]), false);
+    }
+
+    #[test]
+    fn should_support_unique_consecutive_2d() {
+        let tensor = TestTensorInt::<2>::from([[1, 1, 2], [2, 3, 3]]);
+
+        let (unique, inverse, _counts) = tensor.unique_consecutive_with_inverse_and_counts();
+
+        unique
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 1], [1, 2, 2]]), false);
+    }
+}
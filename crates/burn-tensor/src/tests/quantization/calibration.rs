@@ -1,10 +1,12 @@
 #[burn_tensor_testgen::testgen(calibration)]
 mod tests {
     use super::*;
+    use burn_tensor::ops::FloatElem;
     use burn_tensor::{
-        Tensor, TensorData,
-        quantization::{Calibration, QuantLevel, QuantScheme},
+        Tensor, TensorData, Tolerance,
+        quantization::{Calibration, CalibrationObserver, QuantInputType, QuantLevel, QuantScheme},
     };
+    type FT = FloatElem<TestBackend>;
 
     // NOTE: The scheme variant fields are not important for calibration, only the "main" variant (e.g., per-tensor)
     #[test]
@@ -48,4 +50,101 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([0.5, 1.8, 0.04, -0.01]), false);
     }
+
+    #[test]
+    fn percentile_calibration_range_per_tensor() {
+        let values = (0..100).map(|i| i as f32 / 100.0).collect::<Vec<_>>();
+        let tensor = TestTensor::<1>::from_floats(values.as_slice(), &Default::default());
+        let scheme = QuantScheme::default();
+
+        let range = scheme.compute_range(&tensor, &Calibration::Percentile(90.0));
+
+        range
+            .min
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.09]), Tolerance::absolute(1e-2));
+        range
+            .max
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.89]), Tolerance::absolute(1e-2));
+    }
+
+    /// Deterministic sample of a Laplace distribution with unit scale, from its quantile function.
+    fn laplace_sample(num_values: usize) -> Vec<f32> {
+        (0..num_values)
+            .map(|i| {
+                let u = (i as f64 + 0.5) / num_values as f64 - 0.5;
+                (-u.signum() * (1.0 - 2.0 * u.abs()).ln()) as f32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn mse_calibration_range_should_minimize_quantization_error() {
+        let values = laplace_sample(10_000);
+        let tensor = TestTensor::<1>::from_floats(values.as_slice(), &Default::default());
+        let scheme = QuantScheme::default().set_q_type(QuantInputType::QInt4);
+
+        let range = scheme.compute_range(&tensor, &Calibration::Mse);
+
+        // The values range from -9.21 to 9.21, but the mean squared error of the 4-bit
+        // quantization is minimized by clipping them at about 4.79
+        range
+            .min
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([-4.79]), Tolerance::absolute(5e-2));
+        range
+            .max
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([4.79]), Tolerance::absolute(5e-2));
+    }
+
+    #[test]
+    fn entropy_calibration_range_should_clip_sparse_tail() {
+        let values = laplace_sample(10_000);
+        let tensor = TestTensor::<1>::from_floats(values.as_slice(), &Default::default());
+        let scheme = QuantScheme::default();
+
+        let range = scheme.compute_range(&tensor, &Calibration::Entropy);
+
+        // The KL divergence is minimized by clipping the values at about 7.68, beyond which the
+        // histogram only holds a few values
+        range
+            .min
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([-7.68]), Tolerance::absolute(5e-2));
+        range
+            .max
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([7.68]), Tolerance::absolute(5e-2));
+    }
+
+    #[test]
+    fn calibration_observer_should_accumulate_batches() {
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_level(QuantLevel::Block(2));
+
+        for calibration in [Calibration::MinMax, Calibration::Percentile(100.0)] {
+            let mut observer = CalibrationObserver::new(scheme, calibration);
+            observer.observe(&TestTensor::<1>::from_floats(
+                [-1.0, 0.5, 0.2, 0.3],
+                &device,
+            ));
+            observer.observe(&TestTensor::<1>::from_floats(
+                [0.0, 1.5, -0.2, 0.1],
+                &device,
+            ));
+
+            let range = observer.range();
+
+            range
+                .min
+                .into_data()
+                .assert_approx_eq::<FT>(&TensorData::from([-1.0, -0.2]), Tolerance::default());
+            range
+                .max
+                .into_data()
+                .assert_approx_eq::<FT>(&TensorData::from([1.5, 0.3]), Tolerance::default());
+        }
+    }
 }
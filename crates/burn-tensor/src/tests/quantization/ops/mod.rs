@@ -23,6 +23,7 @@ mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
+mod module;
 mod mul;
 mod narrow;
 mod neg;
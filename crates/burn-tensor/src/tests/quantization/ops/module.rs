@@ -0,0 +1,135 @@
+#[burn_tensor_testgen::testgen(q_module)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{conv2d, linear, quantized_conv2d, quantized_linear};
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::quantization::{QuantLevel, QuantScheme, QuantizationParameters};
+    use burn_tensor::{Tensor, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn qparams(scale: f32) -> QuantizationParameters<TestBackend> {
+        QuantizationParameters {
+            scales: Tensor::from_floats([scale], &Default::default()),
+            offsets: None,
+        }
+    }
+
+    #[test]
+    fn should_support_quantized_linear_per_tensor() {
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let input = TestTensor::<2>::from_floats([[1.0, -2.0, 0.5], [0.25, 1.5, -1.0]], &device)
+            .quantize(&scheme, qparams(0.02));
+        let weight =
+            TestTensor::<2>::from_floats([[0.5, -1.0], [0.25, 0.75], [-0.5, 1.0]], &device)
+                .quantize(&scheme, qparams(0.01));
+        let bias = TestTensor::<1>::from_floats([0.1, -0.2], &device);
+
+        let expected = linear(
+            input.clone().dequantize(),
+            weight.clone().dequantize(),
+            Some(bias.clone()),
+        );
+        let output = quantized_linear(input, weight, Some(bias), &scheme, qparams(0.05));
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(0.05));
+    }
+
+    #[test]
+    fn should_support_quantized_linear_per_channel_weight() {
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let input = TestTensor::<2>::from_floats([[1.0, -2.0, 0.5]], &device)
+            .quantize(&scheme, qparams(0.02));
+        let weight_scheme = scheme.set_level(QuantLevel::Channel(1));
+        let weight =
+            TestTensor::<2>::from_floats([[0.5, -10.0], [0.25, 7.5], [-0.5, 10.0]], &device)
+                .quantize_dynamic(&weight_scheme);
+
+        let expected = linear(
+            input.clone().dequantize(),
+            weight.clone().dequantize(),
+            None,
+        );
+        let output = quantized_linear(input, weight, None, &scheme, qparams(0.25));
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(0.25));
+    }
+
+    #[test]
+    fn should_support_quantized_conv2d() {
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let input = TestTensor::<4>::from_floats(
+            [[
+                [[0.0, 0.5, 1.0], [1.5, 2.0, 2.5], [-1.0, -0.5, 0.25]],
+                [[1.0, -1.0, 0.5], [0.0, 0.75, -2.0], [2.0, 1.0, 0.0]],
+            ]],
+            &device,
+        )
+        .quantize(&scheme, qparams(0.02));
+        let weight = TestTensor::<4>::from_floats(
+            [
+                [[[0.5, -0.25], [0.75, 1.0]], [[-1.0, 0.5], [0.25, 0.0]]],
+                [[[0.1, 0.2], [0.3, 0.4]], [[-0.4, -0.3], [-0.2, -0.1]]],
+            ],
+            &device,
+        )
+        .quantize(&scheme, qparams(0.01));
+        let bias = TestTensor::<1>::from_floats([0.5, -0.5], &device);
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+
+        let expected = conv2d(
+            input.clone().dequantize(),
+            weight.clone().dequantize(),
+            Some(bias.clone()),
+            options.clone(),
+        );
+        let output = quantized_conv2d(input, weight, Some(bias), options, &scheme, qparams(0.05));
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(0.05));
+    }
+
+    #[test]
+    fn should_support_quantized_conv2d_groups_per_channel_weight() {
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let input = TestTensor::<4>::from_floats(
+            [[
+                [[0.0, 0.5, 1.0], [1.5, 2.0, 2.5], [-1.0, -0.5, 0.25]],
+                [[1.0, -1.0, 0.5], [0.0, 0.75, -2.0], [2.0, 1.0, 0.0]],
+            ]],
+            &device,
+        )
+        .quantize(&scheme, qparams(0.02));
+        let weight_scheme = scheme.set_level(QuantLevel::Channel(0));
+        let weight = TestTensor::<4>::from_floats(
+            [[[[0.5, -0.25], [0.75, 1.0]]], [[[-2.0, 1.0], [0.5, 1.5]]]],
+            &device,
+        )
+        .quantize_dynamic(&weight_scheme);
+        let options = ConvOptions::new([2, 1], [1, 1], [1, 1], 2);
+
+        let expected = conv2d(
+            input.clone().dequantize(),
+            weight.clone().dequantize(),
+            None,
+            options.clone(),
+        );
+        let output = quantized_conv2d(input, weight, None, options, &scheme, qparams(0.1));
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(0.1));
+    }
+}
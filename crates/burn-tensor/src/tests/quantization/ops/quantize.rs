@@ -4,10 +4,10 @@ mod tests {
     use super::*;
     use alloc::{vec, vec::Vec};
     use burn_tensor::quantization::{
-        QParams, QuantScheme, QuantizationParameters, QuantizationStrategy, QuantizedBytes,
-        SymmetricQuantization,
+        QParams, QuantInputType, QuantMode, QuantScheme, QuantStoreType, QuantizationParameters,
+        QuantizationStrategy, QuantizedBytes, ScaledFloat8Quantization, SymmetricQuantization,
     };
-    use burn_tensor::{DType, Tensor, TensorData};
+    use burn_tensor::{DType, Tensor, TensorData, e4m3};
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
 
@@ -33,6 +33,7 @@ mod tests {
         let scheme = QuantScheme::default();
         let qparams = QuantizationParameters {
             scales: Tensor::from_floats([0.014_173_228], &device),
+            offsets: None,
         };
 
         let x_q = tensor.clone().quantize(&scheme, qparams);
@@ -83,4 +84,79 @@ mod tests {
 
         x_q.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_quantize_scaled_e4m3() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantScheme::default()
+            .set_q_type(QuantInputType::E4M3)
+            .set_q_store_type(QuantStoreType::Native);
+        let qparams = QuantizationParameters {
+            scales: Tensor::from_floats([0.004_017_857], &device),
+            offsets: None,
+        };
+
+        let x_q = tensor.clone().quantize(&scheme, qparams);
+
+        let x_q_data = x_q.to_data();
+        // The values are divided by the scale and rounded to the nearest e4m3 value
+        let values = [-448.0, -256.0, 0.0, 128.0]
+            .iter()
+            .map(|&x| e4m3::from_f32(x).to_bits() as i8)
+            .collect::<Vec<_>>();
+        let expected = TensorData::quantized(
+            values,
+            [4],
+            QuantizationStrategy::PerTensorScaledE4M3(ScaledFloat8Quantization::init(
+                0.004_017_857,
+            )),
+        );
+
+        // Values equality
+        x_q_data.assert_eq(&expected, false);
+
+        // Dequantize
+        let x = x_q.dequantize();
+
+        x.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([-1.8, -1.0285714, 0.0, 0.5142857]),
+            Tolerance::absolute(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_support_quantize_dynamic_e5m2() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([57344., 0., 1024., -3584.], &device);
+        let scheme = QuantScheme::default()
+            .set_q_type(QuantInputType::E5M2)
+            .set_q_store_type(QuantStoreType::Native);
+
+        let x_q = tensor.clone().quantize_dynamic(&scheme);
+
+        // The scale is 1, since the largest magnitude is the largest finite e5m2 value
+        x_q.dequantize()
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn should_fake_quantize_per_channel_affine() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[-1.0, 0.2, 2.0], [0.5, 0.52, -0.04]], &device);
+        let scheme = QuantScheme::default()
+            .set_mode(QuantMode::Affine)
+            .set_q_type(QuantInputType::QUInt4);
+        let scales = TestTensor::<2>::from_floats([[0.1], [0.04]], &device);
+        let offsets = TestTensor::<2>::from_floats([[5.0], [1.0]], &device);
+
+        let output = tensor.fake_quantize(&scheme, scales, Some(offsets));
+
+        // Values are rounded to the closest multiple of the scale, clamped to the quantized range
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[-0.5, 0.2, 1.0], [0.52, 0.52, -0.04]]),
+            Tolerance::default(),
+        );
+    }
 }
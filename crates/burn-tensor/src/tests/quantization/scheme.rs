@@ -4,8 +4,8 @@ mod tests {
     use burn_tensor::{
         DType, Element, Tensor, TensorData,
         quantization::{
-            CalibrationRange, QuantAccPrecision, QuantInputType, QuantLevel, QuantMode,
-            QuantPropagation, QuantScheme,
+            Calibration, CalibrationRange, QuantAccPrecision, QuantInputType, QuantLevel,
+            QuantMode, QuantPropagation, QuantScheme,
         },
     };
     use burn_tensor::{Tolerance, ops::FloatElem};
@@ -45,6 +45,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn per_tensor_affine_int8() {
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_mode(QuantMode::Affine);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8], &device),
+            max: TestTensor::<1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.009_019_608]), Tolerance::default());
+        qparams
+            .offsets
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([72.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn per_channel_affine_uint4() {
+        let device = Default::default();
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(0))
+            .set_mode(QuantMode::Affine)
+            .set_q_type(QuantInputType::QUInt4);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.5, 0.5], &device),
+            max: TestTensor::<1>::from_floats([0.0, 1.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        // The range always includes zero
+        qparams
+            .scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.1, 0.1]), Tolerance::default());
+        qparams
+            .offsets
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([15.0, 0.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn per_channel_range_symmetric_int4() {
+        let device = Default::default();
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Channel(1))
+            .set_q_type(QuantInputType::QInt4);
+        let tensor = TestTensor::<2>::from_floats([[0.7, -1.4], [-0.35, 0.2]], &device);
+
+        let range = scheme.compute_range(&tensor, &Calibration::MinMax);
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.1, 0.2]), Tolerance::default());
+        assert!(qparams.offsets.is_none());
+    }
+
+    #[test]
+    fn per_tensor_scaled_e4m3() {
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_q_type(QuantInputType::E4M3);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8], &device),
+            max: TestTensor::<1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.004_017_857]), Tolerance::default());
+    }
+
+    #[test]
+    fn per_block_scaled_e5m2() {
+        let device = Default::default();
+        let scheme = QuantScheme::default()
+            .set_level(QuantLevel::Block(4))
+            .set_q_type(QuantInputType::E5M2);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-57344.0, -0.5], &device),
+            max: TestTensor::<1>::from_floats([0.5, 1146.88], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scales
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0, 0.02]), Tolerance::default());
+    }
+
     #[test]
     fn quant_scheme_should_propagate() {
         let device = Default::default();
@@ -0,0 +1,140 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::signal;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_fft() {
+        let real = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0]);
+        let imag = TestTensor::<1>::from([0.0, 1.0, 0.0, -1.0]);
+
+        let (real, imag) = signal::fft(real, imag, 0);
+
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([10.0, 0.0, -2.0, -4.0]),
+            Tolerance::default(),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 2.0, 0.0, -2.0]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_fft_non_power_of_two() {
+        let real = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0], [0.5, -1.0, 2.0, 0.0, 1.0]]);
+        let imag = TestTensor::<2>::from([[0.0, 0.0, 0.0, 0.0, 0.0], [1.0, 2.0, 3.0, 4.0, 5.0]]);
+
+        let (real, imag) = signal::fft(real, imag, 1);
+
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                [15.0, -2.5, -2.5, -2.5, -2.5],
+                [2.5, -4.559, 0.3057, 1.9303, 2.3229],
+            ]),
+            Tolerance::absolute(1e-3),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                [0.0, 3.441, 0.8123, -0.8123, -3.441],
+                [15.0, -1.7735, 0.5777, -5.5777, -3.2265],
+            ]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn test_fft_first_dim() {
+        let real = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let imag = real.zeros_like();
+
+        let (real, imag) = signal::fft(real, imag, 0);
+
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[9.0, 12.0], [-3.0, -3.0], [-3.0, -3.0]]),
+            Tolerance::absolute(1e-3),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.0, 0.0], [1.7321, 1.7321], [-1.7321, -1.7321]]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn test_ifft_inverts_fft() {
+        let real = TestTensor::<2>::from([[1.0, -2.0, 3.0], [0.5, 4.0, -1.0]]);
+        let imag = TestTensor::<2>::from([[2.0, 0.0, -1.0], [1.0, 1.5, 0.0]]);
+
+        let (output_real, output_imag) = signal::fft(real.clone(), imag.clone(), 1);
+        let (output_real, output_imag) = signal::ifft(output_real, output_imag, 1);
+
+        output_real
+            .into_data()
+            .assert_approx_eq::<FT>(&real.into_data(), Tolerance::absolute(1e-4));
+        output_imag
+            .into_data()
+            .assert_approx_eq::<FT>(&imag.into_data(), Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    fn test_rfft() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let (real, imag) = signal::rfft(tensor, 0);
+
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([21.0, -3.0, -3.0, -3.0]),
+            Tolerance::absolute(1e-3),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 5.1962, 1.7321, 0.0]),
+            Tolerance::absolute(1e-3),
+        );
+    }
+
+    #[test]
+    fn test_irfft() {
+        let even = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]]);
+        let odd = TestTensor::<2>::from([[1.0, -2.0, 3.0, 0.5, 2.0]]);
+
+        let (real, imag) = signal::rfft(even.clone(), 1);
+        let output = signal::irfft(real, imag, 1, None);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&even.into_data(), Tolerance::absolute(1e-4));
+
+        let (real, imag) = signal::rfft(odd.clone(), 1);
+        let output = signal::irfft(real, imag, 1, Some(5));
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&odd.into_data(), Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    fn test_fft2() {
+        let real = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let imag = real.zeros_like();
+
+        let (output_real, output_imag) = signal::fft2(real.clone(), imag.clone(), [0, 1]);
+
+        output_real.clone().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[21.0, -3.0, -3.0], [-9.0, 0.0, 0.0]]),
+            Tolerance::absolute(1e-3),
+        );
+        output_imag.clone().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.0, 1.7321, -1.7321], [0.0, 0.0, 0.0]]),
+            Tolerance::absolute(1e-3),
+        );
+
+        let (output_real, output_imag) = signal::ifft2(output_real, output_imag, [0, 1]);
+        output_real
+            .into_data()
+            .assert_approx_eq::<FT>(&real.into_data(), Tolerance::absolute(1e-4));
+        output_imag
+            .into_data()
+            .assert_approx_eq::<FT>(&imag.into_data(), Tolerance::absolute(1e-4));
+    }
+}
@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(mel_weight_matrix)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::signal;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_mel_weight_matrix() {
+        let device = Default::default();
+
+        let output = signal::mel_weight_matrix::<TestBackend>(3, 16, 8000, 0.0, 4000.0, &device);
+        let expected = TensorData::from([
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.5, 0.5],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.5],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+        ]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod fft;
+pub(crate) mod mel;
+pub(crate) mod stft;
+pub(crate) mod window;
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::signal::{self, StftOptions};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_stft() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]]);
+
+        let (real, imag) = signal::stft(tensor, None, StftOptions::new(4, 2, false, true));
+
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[10.0, 18.0, 26.0], [-2.0, -2.0, -2.0], [-2.0, -2.0, -2.0]]]),
+            Tolerance::default(),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[0.0, 0.0, 0.0], [2.0, 2.0, 2.0], [0.0, 0.0, 0.0]]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_stft_window() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from([[1.0, 0.0, -1.0, 2.0, 3.0, -2.0]]);
+        let window = signal::hann_window(4, true, &device);
+
+        let (real, imag) = signal::stft(tensor, Some(window), StftOptions::new(4, 2, false, true));
+
+        real.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[0.0, 3.0], [1.0, -3.0], [-2.0, 3.0]]]),
+            Tolerance::default(),
+        );
+        imag.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[0.0, 0.0], [1.0, -2.0], [0.0, 0.0]]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_istft_inverts_stft() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from([
+            [1.0, 0.0, -1.0, 2.0, 3.0, -2.0, 0.5, 1.5, -0.5, 4.0],
+            [2.0, 1.0, 0.0, -1.0, -2.0, 3.0, 1.0, 0.0, 2.5, -1.0],
+        ]);
+        let window = signal::hann_window(4, true, &device);
+
+        for onesided in [true, false] {
+            let options = StftOptions::new(4, 1, true, onesided);
+            let (real, imag) = signal::stft(tensor.clone(), Some(window.clone()), options.clone());
+            let output = signal::istft(real, imag, Some(window.clone()), options, Some(10));
+
+            output
+                .into_data()
+                .assert_approx_eq::<FT>(&tensor.to_data(), Tolerance::absolute(1e-4));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(window)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::signal;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_hann_window() {
+        let device = Default::default();
+
+        let periodic = signal::hann_window::<TestBackend>(5, true, &device);
+        let symmetric = signal::hann_window::<TestBackend>(5, false, &device);
+
+        periodic.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 0.345492, 0.904508, 0.904508, 0.345492]),
+            Tolerance::default(),
+        );
+        symmetric.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 0.5, 1.0, 0.5, 0.0]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_hamming_window() {
+        let device = Default::default();
+
+        let window = signal::hamming_window::<TestBackend>(4, true, &device);
+
+        window.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.08, 0.54, 1.0, 0.54]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_blackman_window() {
+        let device = Default::default();
+
+        let window = signal::blackman_window::<TestBackend>(5, false, &device);
+
+        window.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 0.34, 1.0, 0.34, 0.0]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_window_single_sample() {
+        let device = Default::default();
+
+        let window = signal::hann_window::<TestBackend>(1, false, &device);
+
+        window
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0]), Tolerance::default());
+    }
+}
@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(histc)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_support_histc() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 1.0, 4.0, 5.0, -1.0]);
+
+        let output = tensor.histc(4, 0.0, 4.0);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.0, 2.0, 1.0, 1.0]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_support_histc_data_range() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 1.5], [2.0, 3.5, 4.0]]);
+
+        let output = tensor.histc(4, 0.0, 0.0);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([1.0, 2.0, 1.0, 2.0]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn should_support_histc_constant_tensor() {
+        let tensor = TestTensor::<1>::from([2.0, 2.0, 2.0]);
+
+        let output = tensor.histc(2, 0.0, 0.0);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0, 3.0]), Tolerance::default());
+    }
+}
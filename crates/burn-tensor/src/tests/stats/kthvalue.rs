@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(kthvalue)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_kthvalue() {
+        let tensor = TestTensor::<2>::from([[12.0, -2.0, 3.0, 8.0], [5.0, 3.0, 6.0, 1.0]]);
+
+        let (values, indices) = tensor.clone().kthvalue_with_indices(3, 1);
+
+        values
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[8.0], [5.0]]), Tolerance::default());
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[3], [0]]), false);
+
+        let output = tensor.kthvalue(1, 0);
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[5.0, -2.0, 3.0, 1.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_kthvalue_int() {
+        let tensor = TestTensorInt::<1>::from([7, -2, 4, 9, 1]);
+
+        let (values, indices) = tensor.kthvalue_with_indices(5, 0);
+
+        values.into_data().assert_eq(&TensorData::from([9]), false);
+        indices.into_data().assert_eq(&TensorData::from([3]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kthvalue_zero() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0]);
+        let _output = tensor.kthvalue(0, 0);
+    }
+}
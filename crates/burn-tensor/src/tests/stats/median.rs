@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(median)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_median_odd() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0, 2.0], [-1.0, 5.0, 0.0]]);
+
+        let (values, indices) = tensor.median_with_indices(1);
+
+        values
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.0], [0.0]]), Tolerance::default());
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[2], [2]]), false);
+    }
+
+    #[test]
+    fn test_median_even_returns_lower() {
+        let tensor = TestTensor::<2>::from([[4.0, 1.0], [2.0, 3.0], [1.0, 8.0], [3.0, 6.0]]);
+
+        let output = tensor.median(0);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.0, 3.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_median_int() {
+        let tensor = TestTensorInt::<1>::from([7, -2, 4, 9, 1]);
+
+        let output = tensor.median(0);
+
+        output.into_data().assert_eq(&TensorData::from([4]), false);
+    }
+
+    #[test]
+    fn test_nanmedian() {
+        let tensor = TestTensor::<2>::from([
+            [1.0, f32::NAN, 3.0, 2.0],
+            [f32::NAN, 4.0, f32::NAN, -1.0],
+            [f32::NAN, f32::NAN, f32::NAN, f32::NAN],
+        ]);
+
+        let (values, indices) = tensor.nanmedian_with_indices(1);
+
+        values
+            .clone()
+            .slice([0..2])
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.0], [-1.0]]), Tolerance::default());
+        assert!(
+            values
+                .slice([2..3])
+                .into_data()
+                .iter::<f32>()
+                .all(|v| v.is_nan())
+        );
+        indices
+            .slice([0..2])
+            .into_data()
+            .assert_eq(&TensorData::from([[3], [3]]), false);
+    }
+}
@@ -1,4 +1,9 @@
 mod cov;
 mod display;
 mod eye;
+mod histc;
+mod kthvalue;
+mod median;
+mod mode;
+mod quantile;
 mod var;
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(mode)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_mode() {
+        let tensor = TestTensor::<2>::from([
+            [1.0, 3.0, 2.0, 3.0, 1.0, 3.0],
+            [0.5, 4.0, 0.5, -1.0, 4.0, 2.0],
+        ]);
+
+        let (values, indices) = tensor.mode_with_indices(1);
+
+        // Ties are resolved in favor of the smallest value.
+        values
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[3.0], [0.5]]), Tolerance::default());
+
+        let indices = indices.into_data().iter::<i64>().collect::<Vec<_>>();
+        assert!([1, 3, 5].contains(&indices[0]));
+        assert!([0, 2].contains(&indices[1]));
+    }
+
+    #[test]
+    fn test_mode_int() {
+        let tensor = TestTensorInt::<2>::from([[4, 2, 4, 7], [1, 2, 3, 5]]);
+
+        let output = tensor.mode(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[4], [1]]), false);
+    }
+
+    #[test]
+    fn test_mode_single_element() {
+        let tensor = TestTensor::<2>::from([[2.0], [-3.0]]);
+
+        let (values, indices) = tensor.mode_with_indices(1);
+
+        values
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.0], [-3.0]]), Tolerance::default());
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0], [0]]), false);
+    }
+}
@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(quantile)]
+mod tests {
+    use super::*;
+    use burn_tensor::{QuantileInterpolation, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn tensor() -> TestTensor<2> {
+        TestTensor::from([[1.0, 4.0, 2.0, 3.0, 5.0], [0.5, -1.0, 7.0, 2.0, 3.0]])
+    }
+
+    #[test]
+    fn test_quantile_linear() {
+        let output = tensor().quantile(0.3, 1, QuantileInterpolation::Linear);
+        let expected = TensorData::from([[2.2], [0.8]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_quantile_lower_higher() {
+        let lower = tensor().quantile(0.3, 1, QuantileInterpolation::Lower);
+        let higher = tensor().quantile(0.3, 1, QuantileInterpolation::Higher);
+
+        lower
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.0], [0.5]]), Tolerance::default());
+        higher
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[3.0], [2.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_quantile_nearest_midpoint() {
+        let nearest = tensor().quantile(0.3, 1, QuantileInterpolation::Nearest);
+        let midpoint = tensor().quantile(0.3, 1, QuantileInterpolation::Midpoint);
+
+        nearest
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.0], [0.5]]), Tolerance::default());
+        midpoint
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[2.5], [1.25]]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_quantile_nearest_rounds_half_to_even() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        // The positions are 0.5 and 1.5, which round to 0 and 2.
+        let first = tensor
+            .clone()
+            .quantile(0.125, 0, QuantileInterpolation::Nearest);
+        let second = tensor.quantile(0.375, 0, QuantileInterpolation::Nearest);
+
+        first
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0]), Tolerance::default());
+        second
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([3.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_quantile_bounds() {
+        let min = tensor().quantile(0.0, 0, QuantileInterpolation::Linear);
+        let max = tensor().quantile(1.0, 0, QuantileInterpolation::Linear);
+
+        min.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.5, -1.0, 2.0, 2.0, 3.0]]),
+            Tolerance::default(),
+        );
+        max.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 4.0, 7.0, 3.0, 5.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_quantile_out_of_range() {
+        let _output = tensor().quantile(1.5, 1, QuantileInterpolation::Linear);
+    }
+}
@@ -74,7 +74,7 @@ pub fn morph<B: Backend, K: BasicOps<B>>(
         DType::F32 | DType::Flex32 => {
             morph_typed::<B, K, f32>(data, shape, kernel, op, iter, btype, bvalue, &device)
         }
-        DType::F16 | DType::BF16 => morph_typed::<B, K, f32>(
+        DType::F16 | DType::BF16 | DType::E4M3 | DType::E5M2 => morph_typed::<B, K, f32>(
             data.convert::<f32>(),
             shape,
             kernel,
@@ -105,7 +105,7 @@ pub fn morph<B: Backend, K: BasicOps<B>>(
         }
         DType::U8 => morph_typed::<B, K, u8>(data, shape, kernel, op, iter, btype, bvalue, &device),
         DType::Bool => morph_bool::<B, K>(data, shape, kernel, op, iter, btype, bvalue, &device),
-        DType::QFloat(_) => unimplemented!(),
+        DType::Complex32 | DType::Complex64 | DType::QFloat(_) => unimplemented!(),
     }
 }
 
@@ -19,15 +19,21 @@ use super::rank_inference::rank_inference;
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 18] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 25] = [
     NodeType::BatchNormalization,
+    NodeType::BlackmanWindow,
     NodeType::InstanceNormalization,
     NodeType::GroupNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::CumSum,
+    NodeType::DFT,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::HammingWindow,
+    NodeType::HannWindow,
+    NodeType::MelWeightMatrix,
     NodeType::OneHot,
     NodeType::ReduceSum,
     NodeType::Reshape,
@@ -35,6 +41,7 @@ const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 18] = [
     NodeType::Slice,
     NodeType::Split,
     NodeType::Squeeze,
+    NodeType::STFT,
     NodeType::TopK,
     NodeType::Trilu,
     NodeType::Unsqueeze,
@@ -277,7 +284,23 @@ impl OnnxGraphBuilder {
             );
         } else if self.constants_types.contains(&node.node_type) {
             log::debug!("checking node {} for constants", &node.name);
-            for input in node.inputs.iter_mut().skip(1) {
+            let node_type = node.node_type.clone();
+            let is_lifted = |index: usize| match node_type {
+                // The generators only take scalar inputs, which all configure the output.
+                NodeType::BlackmanWindow
+                | NodeType::HammingWindow
+                | NodeType::HannWindow
+                | NodeType::MelWeightMatrix => true,
+                // The window of STFT is used as a tensor in the forward pass.
+                NodeType::STFT => index != 0 && index != 2,
+                _ => index != 0,
+            };
+            for (_, input) in node
+                .inputs
+                .iter_mut()
+                .enumerate()
+                .filter(|(index, _)| is_lifted(*index))
+            {
                 log::debug!("checking input {input:?} for const");
                 if let Some(const_idx) = self.constants_map.get(&input.name) {
                     let constant = &graph_data.processed_nodes[*const_idx];
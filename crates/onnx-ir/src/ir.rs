@@ -585,6 +585,7 @@ impl Data {
             Data::Float64(elem) => elem,
             Data::Int32(elem) => elem as f64,
             Data::Int64(elem) => elem as f64,
+            Data::Float32s(elem) if elem.len() == 1 => elem[0] as f64,
             Data::Float64s(elem) if elem.len() == 1 => elem[0],
             _ => panic!("Cannot convert {self:?} to f64"),
         }
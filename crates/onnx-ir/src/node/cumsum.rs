@@ -0,0 +1,117 @@
+use crate::ir::{ArgType, Data, Node};
+
+/// Configuration for the CumSum operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CumSumConfig {
+    /// The axis along which to accumulate.
+    pub axis: usize,
+    /// Whether each output excludes its own input element.
+    pub exclusive: bool,
+    /// Whether to accumulate from the end of the axis.
+    pub reverse: bool,
+}
+
+impl CumSumConfig {
+    /// Creates a new CumSumConfig.
+    pub fn new(axis: usize, exclusive: bool, reverse: bool) -> Self {
+        Self {
+            axis,
+            exclusive,
+            reverse,
+        }
+    }
+}
+
+/// Creates a CumSumConfig from the node attributes and inputs.
+pub fn cumsum_config(node: &Node) -> CumSumConfig {
+    let rank = match &node.inputs.first().unwrap().ty {
+        ArgType::Tensor(tensor) => tensor.rank,
+        _ => panic!("CumSum: only tensor input is valid"),
+    };
+
+    // The second input of the CumSum node is the axis, coming from a constant node
+    let mut axis = match node.inputs.get(1).and_then(|axis| axis.value.as_ref()) {
+        Some(value) => match &value.data {
+            Data::Int32(axis) => *axis as i64,
+            Data::Int64(axis) => *axis,
+            data => data.clone().into_i64s()[0],
+        },
+        None => panic!("CumSum: only constant 'axis' tensor is currently supported"),
+    };
+
+    // If axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += rank as i64;
+    }
+
+    let mut exclusive = false;
+    let mut reverse = false;
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "exclusive" => exclusive = value.clone().into_i64() != 0,
+            "reverse" => reverse = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    CumSumConfig::new(axis as usize, exclusive, reverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(axis: i64, exclusive: Option<i64>, reverse: Option<i64>) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::CumSum, "test_cumsum")
+            .input_tensor_f32("X", 3, None)
+            .input_scalar_tensor_i64("axis", axis)
+            .output_tensor_f32("Y", 3, None);
+
+        if let Some(exclusive) = exclusive {
+            builder = builder.attr_int("exclusive", exclusive);
+        }
+        if let Some(reverse) = reverse {
+            builder = builder.attr_int("reverse", reverse);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_cumsum_config_default() {
+        let node = create_test_node(1, None, None);
+
+        let config = cumsum_config(&node);
+
+        assert_eq!(config, CumSumConfig::new(1, false, false));
+    }
+
+    #[test]
+    fn test_cumsum_config_negative_axis() {
+        let node = create_test_node(-1, None, None);
+
+        let config = cumsum_config(&node);
+
+        assert_eq!(config, CumSumConfig::new(2, false, false));
+    }
+
+    #[test]
+    fn test_cumsum_config_exclusive_reverse() {
+        let node = create_test_node(0, Some(1), Some(1));
+
+        let config = cumsum_config(&node);
+
+        assert_eq!(config, CumSumConfig::new(0, true, true));
+    }
+
+    #[test]
+    #[should_panic(expected = "CumSum: only constant 'axis' tensor is currently supported")]
+    fn test_cumsum_config_runtime_axis() {
+        let mut node = create_test_node(0, None, None);
+        node.inputs[1].value = None;
+
+        let _ = cumsum_config(&node);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Update output rank for Det, which removes the two matrix dimensions of the input.
+///
+/// The determinant of a single matrix is represented as a tensor of rank 1.
+pub fn det_update_outputs(node: &mut Node) {
+    log::debug!("Det rank inference for node {}", node.name);
+
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Det: only tensor input is valid"),
+    };
+    assert!(
+        tensor.rank >= 2,
+        "Det: expected an input of rank 2 or more, found {}",
+        tensor.rank
+    );
+
+    let rank = (tensor.rank - 2).max(1);
+    log::debug!("Det output rank for {}: {}", node.name, rank);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type,
+        rank,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{ElementType, NodeType};
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(rank: usize) -> Node {
+        NodeBuilder::new(NodeType::Det, "test_det")
+            .input_tensor_f32("data", rank, None)
+            .output_tensor_f32("output", 0, None) // Rank will be updated
+            .build()
+    }
+
+    #[test]
+    fn test_det_update_outputs_batched() {
+        let mut node = create_test_node(4);
+        det_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float32);
+                assert_eq!(tensor.rank, 2);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    fn test_det_update_outputs_matrix() {
+        let mut node = create_test_node(2);
+        det_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.rank, 1),
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Det: expected an input of rank 2 or more")]
+    fn test_det_invalid_rank() {
+        let mut node = create_test_node(1);
+        det_update_outputs(&mut node);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Configuration for the DFT operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DftConfig {
+    /// The axis along which the transform is computed.
+    pub axis: usize,
+    /// Whether the inverse transform is computed.
+    pub inverse: bool,
+    /// Whether only the non-negative frequencies are returned.
+    pub onesided: bool,
+}
+
+impl DftConfig {
+    /// Creates a new DftConfig.
+    pub fn new(axis: usize, inverse: bool, onesided: bool) -> Self {
+        Self {
+            axis,
+            inverse,
+            onesided,
+        }
+    }
+}
+
+/// Creates a DftConfig from the node attributes and inputs.
+pub fn dft_config(node: &Node) -> DftConfig {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.rank,
+        _ => panic!("DFT: only tensor input is valid"),
+    };
+
+    if let Some(dft_length) = node.inputs.get(1).filter(|arg| !arg.name.is_empty()) {
+        panic!(
+            "DFT: the dft_length input ({}) is not supported, the transform length must be the size of the axis",
+            dft_length.name
+        );
+    }
+
+    // The axis is an attribute up to opset 17, and an optional input since opset 20.
+    let axis = match node.inputs.get(2).filter(|arg| !arg.name.is_empty()) {
+        Some(axis) => axis
+            .value
+            .clone()
+            .expect("DFT: only constant axis is currently supported")
+            .data
+            .into_i64(),
+        None => node
+            .attrs
+            .get("axis")
+            .map(|val| val.clone().into_i64())
+            .unwrap_or(1),
+    };
+
+    let inverse = node
+        .attrs
+        .get("inverse")
+        .is_some_and(|val| val.clone().into_i64() != 0);
+    let onesided = node
+        .attrs
+        .get("onesided")
+        .is_some_and(|val| val.clone().into_i64() != 0);
+
+    // The last dimension holds the real and imaginary parts, so it can't be transformed.
+    let axis = if axis < 0 { axis + rank as i64 } else { axis };
+    assert!(
+        axis >= 0 && (axis as usize) < rank - 1,
+        "DFT: axis {axis} is out of bounds for an input of rank {rank}"
+    );
+    assert!(
+        !(inverse && onesided),
+        "DFT: the inverse of a one-sided transform is not supported"
+    );
+
+    DftConfig::new(axis as usize, inverse, onesided)
+}
+
+/// Update output rank for DFT (same as input rank).
+pub fn dft_update_outputs(node: &mut Node) {
+    log::debug!("DFT rank inference for node {}", node.name);
+
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("DFT: only tensor input is valid"),
+    };
+    assert!(
+        tensor.rank >= 2,
+        "DFT: expected an input of rank 2 or more, found {}",
+        tensor.rank
+    );
+    log::debug!("DFT output rank for {}: {}", node.name, tensor.rank);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type,
+        rank: tensor.rank,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(axis: Option<i64>, inverse: i64, onesided: i64) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::DFT, "test_dft")
+            .input_tensor_f32("input", 3, None)
+            .output_tensor_f32("output", 0, None) // Rank will be updated
+            .attr_int("inverse", inverse)
+            .attr_int("onesided", onesided);
+
+        if let Some(axis) = axis {
+            builder = builder.attr_int("axis", axis);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_dft_config_default_axis() {
+        let node = create_test_node(None, 0, 1);
+        let config = dft_config(&node);
+
+        assert_eq!(config, DftConfig::new(1, false, true));
+    }
+
+    #[test]
+    fn test_dft_config_negative_axis() {
+        let node = create_test_node(Some(-3), 1, 0);
+        let config = dft_config(&node);
+
+        assert_eq!(config, DftConfig::new(0, true, false));
+    }
+
+    #[test]
+    fn test_dft_config_axis_input() {
+        let mut node = NodeBuilder::new(NodeType::DFT, "test_dft")
+            .input_tensor_f32("input", 4, None)
+            .input_tensor_i64("dft_length", 0, None)
+            .input_scalar_tensor_i64("axis", -2)
+            .output_tensor_f32("output", 4, None)
+            .build();
+        // An omitted optional input has an empty name.
+        node.inputs[1].name = String::new();
+
+        assert_eq!(dft_config(&node).axis, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "DFT: axis 2 is out of bounds")]
+    fn test_dft_config_complex_axis() {
+        let node = create_test_node(Some(2), 0, 0);
+        let _ = dft_config(&node);
+    }
+
+    #[test]
+    fn test_dft_update_outputs() {
+        let mut node = create_test_node(None, 0, 0);
+        dft_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.rank, 3),
+            _ => panic!("Expected tensor output"),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Configuration for the Einsum operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EinsumConfig {
+    /// The einsum equation, without whitespaces.
+    pub equation: String,
+}
+
+impl EinsumConfig {
+    /// Creates a new EinsumConfig.
+    pub fn new(equation: String) -> Self {
+        Self { equation }
+    }
+}
+
+/// Creates an EinsumConfig from the node attributes.
+pub fn einsum_config(node: &Node) -> EinsumConfig {
+    let equation = node
+        .attrs
+        .get("equation")
+        .cloned()
+        .expect("Einsum: equation attribute not found")
+        .into_string();
+
+    EinsumConfig::new(equation.chars().filter(|c| !c.is_whitespace()).collect())
+}
+
+/// Update output rank for Einsum based on the equation and the input ranks.
+pub fn einsum_update_outputs(node: &mut Node) {
+    log::debug!("Einsum rank inference for node {}", node.name);
+
+    let tensors = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.clone(),
+            _ => panic!("Einsum: only tensor inputs are valid"),
+        })
+        .collect::<Vec<_>>();
+    let ranks = tensors.iter().map(|tensor| tensor.rank).collect::<Vec<_>>();
+
+    let config = einsum_config(node);
+    let rank = einsum_output_rank(&config.equation, &ranks);
+
+    log::debug!("Einsum output rank for {}: {}", node.name, rank);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensors[0].elem_type.clone(),
+        rank,
+        static_shape: None,
+    });
+}
+
+/// Computes the rank of the einsum output for inputs of the given ranks.
+///
+/// The dimensions covered by an ellipsis are broadcasted across the inputs, so the ellipsis spans
+/// the largest number of dimensions it covers in any input term.
+pub fn einsum_output_rank(equation: &str, ranks: &[usize]) -> usize {
+    let (lhs, rhs) = match equation.split_once("->") {
+        Some((lhs, rhs)) => (lhs, Some(rhs)),
+        None => (equation, None),
+    };
+    let terms = lhs.split(',').collect::<Vec<_>>();
+    assert_eq!(
+        terms.len(),
+        ranks.len(),
+        "Einsum: the equation has {} input terms, but the node has {} inputs",
+        terms.len(),
+        ranks.len()
+    );
+
+    let num_labels = |term: &str| term.chars().filter(char::is_ascii_alphabetic).count();
+    let ellipsis_rank = terms
+        .iter()
+        .zip(ranks)
+        .filter(|(term, _)| term.contains("..."))
+        .map(|(term, rank)| rank - num_labels(term))
+        .max()
+        .unwrap_or(0);
+
+    match rhs {
+        Some(rhs) if rhs.contains("...") => ellipsis_rank + num_labels(rhs),
+        Some(rhs) => num_labels(rhs),
+        None => {
+            let labels = lhs.chars().filter(char::is_ascii_alphabetic);
+            let unique = labels
+                .clone()
+                .filter(|label| labels.clone().filter(|l| l == label).count() == 1)
+                .count();
+
+            ellipsis_rank + unique
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{ElementType, NodeType};
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(equation: &str, ranks: &[usize]) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::Einsum, "test_einsum");
+        for (i, rank) in ranks.iter().enumerate() {
+            builder = builder.input_tensor_f32(&format!("input{i}"), *rank, None);
+        }
+
+        builder
+            .output_tensor_f32("output", 0, None) // Rank will be updated
+            .attr_string("equation", equation)
+            .build()
+    }
+
+    #[test]
+    fn test_einsum_config() {
+        let node = create_test_node("bhqd, bhkd -> bhqk", &[4, 4]);
+        let config = einsum_config(&node);
+
+        assert_eq!(config.equation, "bhqd,bhkd->bhqk");
+    }
+
+    #[test]
+    fn test_einsum_update_outputs() {
+        let mut node = create_test_node("bhqd,bhkd->bhqk", &[4, 4]);
+        einsum_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float32);
+                assert_eq!(tensor.rank, 4);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    fn test_einsum_output_rank_reduction() {
+        assert_eq!(einsum_output_rank("ij->i", &[2]), 1);
+        assert_eq!(einsum_output_rank("ii->i", &[2]), 1);
+    }
+
+    #[test]
+    fn test_einsum_output_rank_implicit() {
+        assert_eq!(einsum_output_rank("ij,jk", &[2, 2]), 2);
+        assert_eq!(einsum_output_rank("i,i", &[1, 1]), 0);
+    }
+
+    #[test]
+    fn test_einsum_output_rank_ellipsis() {
+        assert_eq!(einsum_output_rank("...ij,jk->...ik", &[4, 2]), 4);
+        assert_eq!(einsum_output_rank("...ij,...jk", &[3, 4]), 4);
+        assert_eq!(einsum_output_rank("...ii->...i", &[3]), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Einsum: the equation has 2 input terms")]
+    fn test_einsum_invalid_number_of_inputs() {
+        let mut node = create_test_node("ij,jk->ik", &[2]);
+        einsum_update_outputs(&mut node);
+    }
+}
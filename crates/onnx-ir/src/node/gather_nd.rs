@@ -0,0 +1,145 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Validates the attributes of the GatherND node.
+///
+/// Only `batch_dims = 0` is supported, the index tuples address the data from its first dimension.
+pub fn gather_nd_config(node: &Node) {
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "batch_dims" => {
+                let batch_dims = value.clone().into_i64();
+                if batch_dims != 0 {
+                    panic!("GatherND: only batch_dims = 0 is supported, got {batch_dims}");
+                }
+            }
+            _ => panic!("Unexpected attribute for GatherND: {key}"),
+        }
+    }
+}
+
+/// Update output type for GatherND.
+///
+/// The data has rank `r` and the indices have shape `[..., k]` with rank `q`, the output has rank
+/// `q - 1 + r - k`. The last dimension of the indices must therefore be known statically.
+pub fn gather_nd_update_output(node: &mut Node) {
+    log::debug!("GatherND rank inference for node {}", node.name);
+
+    let data = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("GatherND: only tensor data is valid"),
+    };
+    let indices_rank = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.rank,
+        _ => panic!("GatherND: only tensor indices are valid"),
+    };
+    let tuple_size =
+        last_dim(node, 1).expect("GatherND: the indices shape must be known statically");
+
+    assert!(
+        tuple_size >= 1 && tuple_size <= data.rank,
+        "GatherND: the index tuples size ({tuple_size}) must be between 1 and the data rank ({})",
+        data.rank
+    );
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: data.elem_type,
+        rank: indices_rank - 1 + data.rank - tuple_size,
+        static_shape: None,
+    });
+}
+
+/// Returns the size of the last dimension of the input at the given position, when known.
+fn last_dim(node: &Node, input: usize) -> Option<usize> {
+    let argument = &node.inputs[input];
+
+    if let Some(value) = &argument.value {
+        return value.shape.last().copied();
+    }
+
+    match &argument.ty {
+        ArgType::Tensor(tensor) => tensor
+            .static_shape
+            .as_ref()
+            .and_then(|shape| shape.last().copied()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElementType;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(data_rank: usize, indices_shape: Vec<usize>) -> Node {
+        NodeBuilder::new(NodeType::GatherND, "test_gather_nd")
+            .input_tensor_f32("data", data_rank, None)
+            .input_tensor_i64("indices", indices_shape.len(), Some(indices_shape))
+            .output_tensor_f32("output", 0, None)
+            .build()
+    }
+
+    #[test]
+    fn test_update_output_elements() {
+        let mut node = create_test_node(2, vec![3, 2]);
+        gather_nd_update_output(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float32);
+                assert_eq!(tensor.rank, 1);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    fn test_update_output_slices() {
+        let mut node = create_test_node(3, vec![2, 2, 1]);
+        gather_nd_update_output(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.rank, 4),
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    fn test_update_output_constant_indices() {
+        let mut node = NodeBuilder::new(NodeType::GatherND, "test_gather_nd")
+            .input_tensor_f32("data", 3, None)
+            .input_tensor_i64_data("indices", vec![0, 1, 1, 0], vec![2, 2])
+            .output_tensor_f32("output", 0, None)
+            .build();
+        gather_nd_update_output(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.rank, 2),
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the indices shape must be known statically")]
+    fn test_update_output_unknown_indices_shape() {
+        let mut node = NodeBuilder::new(NodeType::GatherND, "test_gather_nd")
+            .input_tensor_f32("data", 2, None)
+            .input_tensor_i64("indices", 2, None)
+            .output_tensor_f32("output", 0, None)
+            .build();
+        gather_nd_update_output(&mut node);
+    }
+
+    #[test]
+    #[should_panic(expected = "only batch_dims = 0 is supported")]
+    fn test_config_batch_dims() {
+        let node = NodeBuilder::new(NodeType::GatherND, "test_gather_nd")
+            .input_tensor_f32("data", 3, None)
+            .input_tensor_i64("indices", 2, Some(vec![3, 1]))
+            .output_tensor_f32("output", 0, None)
+            .attr_int("batch_dims", 1)
+            .build();
+        gather_nd_config(&node);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Interpolation mode of the GridSample operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSampleMode {
+    Nearest,
+    Linear,
+    Cubic,
+}
+
+impl From<&str> for GridSampleMode {
+    fn from(val: &str) -> Self {
+        // Opset 20 renamed `bilinear` and `bicubic` to `linear` and `cubic`.
+        match val {
+            "nearest" => Self::Nearest,
+            "linear" | "bilinear" => Self::Linear,
+            "cubic" | "bicubic" => Self::Cubic,
+            _ => panic!("Unexpected value for GridSample mode: {val}"),
+        }
+    }
+}
+
+/// Padding mode of the GridSample operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePaddingMode {
+    Zeros,
+    Border,
+    Reflection,
+}
+
+impl From<&str> for GridSamplePaddingMode {
+    fn from(val: &str) -> Self {
+        match val {
+            "zeros" => Self::Zeros,
+            "border" => Self::Border,
+            "reflection" => Self::Reflection,
+            _ => panic!("Unexpected value for GridSample padding_mode: {val}"),
+        }
+    }
+}
+
+/// Configuration for the GridSample operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridSampleConfig {
+    pub mode: GridSampleMode,
+    pub padding_mode: GridSamplePaddingMode,
+    pub align_corners: bool,
+}
+
+impl GridSampleConfig {
+    /// Create a new GridSampleConfig
+    pub fn new(
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> Self {
+        Self {
+            mode,
+            padding_mode,
+            align_corners,
+        }
+    }
+}
+
+/// Creates a GridSampleConfig from the node attributes.
+pub fn grid_sample_config(node: &Node) -> GridSampleConfig {
+    let mut mode = GridSampleMode::Linear;
+    let mut padding_mode = GridSamplePaddingMode::Zeros;
+    let mut align_corners = false;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "mode" => mode = value.clone().into_string().as_str().into(),
+            "padding_mode" => padding_mode = value.clone().into_string().as_str().into(),
+            "align_corners" => align_corners = value.clone().into_i64() != 0,
+            _ => panic!("Unexpected attribute for GridSample: {key}"),
+        }
+    }
+
+    GridSampleConfig {
+        mode,
+        padding_mode,
+        align_corners,
+    }
+}
+
+/// Update output type for GridSample.
+///
+/// The input has shape `[N, C, D1, ..., Dr]` and the grid `[N, D1_out, ..., Dr_out, r]`, the
+/// output has shape `[N, C, D1_out, ..., Dr_out]`.
+pub fn grid_sample_update_output(node: &mut Node) {
+    log::debug!("GridSample rank inference for node {}", node.name);
+
+    let input = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("GridSample: only tensor input is valid"),
+    };
+    let grid_rank = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.rank,
+        _ => panic!("GridSample: only tensor grid is valid"),
+    };
+
+    assert!(
+        input.rank == 4 || input.rank == 5,
+        "GridSample: only 4D and 5D inputs are supported, got rank {}",
+        input.rank
+    );
+    assert_eq!(
+        input.rank, grid_rank,
+        "GridSample: the input and the grid must have the same rank"
+    );
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: input.elem_type,
+        rank: input.rank,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElementType;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(rank: usize, attrs: &[(&str, &str)], align_corners: Option<i64>) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::GridSample, "test_grid_sample")
+            .input_tensor_f32("input", rank, None)
+            .input_tensor_f32("grid", rank, None)
+            .output_tensor_f32("output", 0, None);
+
+        for (key, value) in attrs {
+            builder = builder.attr_string(key, value);
+        }
+        if let Some(align_corners) = align_corners {
+            builder = builder.attr_int("align_corners", align_corners);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_default_config() {
+        let node = create_test_node(4, &[], None);
+        let config = grid_sample_config(&node);
+
+        assert_eq!(
+            config,
+            GridSampleConfig::new(GridSampleMode::Linear, GridSamplePaddingMode::Zeros, false)
+        );
+    }
+
+    #[test]
+    fn test_config() {
+        let node = create_test_node(
+            4,
+            &[("mode", "bicubic"), ("padding_mode", "reflection")],
+            Some(1),
+        );
+        let config = grid_sample_config(&node);
+
+        assert_eq!(
+            config,
+            GridSampleConfig::new(
+                GridSampleMode::Cubic,
+                GridSamplePaddingMode::Reflection,
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn test_opset_20_mode_names() {
+        let node = create_test_node(4, &[("mode", "linear"), ("padding_mode", "border")], None);
+        let config = grid_sample_config(&node);
+
+        assert_eq!(config.mode, GridSampleMode::Linear);
+        assert_eq!(config.padding_mode, GridSamplePaddingMode::Border);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected value for GridSample mode")]
+    fn test_invalid_mode() {
+        let node = create_test_node(4, &[("mode", "area")], None);
+        let _ = grid_sample_config(&node);
+    }
+
+    #[test]
+    fn test_update_output() {
+        let mut node = create_test_node(5, &[], None);
+        grid_sample_update_output(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float32);
+                assert_eq!(tensor.rank, 5);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the input and the grid must have the same rank")]
+    fn test_update_output_rank_mismatch() {
+        let mut node = NodeBuilder::new(NodeType::GridSample, "test_grid_sample")
+            .input_tensor_f32("input", 4, None)
+            .input_tensor_f32("grid", 5, None)
+            .output_tensor_f32("output", 0, None)
+            .build();
+        grid_sample_update_output(&mut node);
+    }
+}
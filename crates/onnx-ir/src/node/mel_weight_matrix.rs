@@ -0,0 +1,123 @@
+use crate::ir::{ArgType, ElementType, Node, TensorType};
+use crate::protos::tensor_proto::DataType;
+use protobuf::Enum;
+
+/// Configuration for the MelWeightMatrix operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MelWeightMatrixConfig {
+    /// The number of bands in the mel spectrum.
+    pub num_mel_bins: usize,
+    /// The size of the discrete Fourier transform.
+    pub dft_length: usize,
+    /// The sample rate of the signal, in Hertz.
+    pub sample_rate: usize,
+    /// The lowest frequency of the mel spectrum, in Hertz.
+    pub lower_edge_hertz: f64,
+    /// The highest frequency of the mel spectrum, in Hertz.
+    pub upper_edge_hertz: f64,
+}
+
+impl MelWeightMatrixConfig {
+    /// Creates a new MelWeightMatrixConfig.
+    pub fn new(
+        num_mel_bins: usize,
+        dft_length: usize,
+        sample_rate: usize,
+        lower_edge_hertz: f64,
+        upper_edge_hertz: f64,
+    ) -> Self {
+        Self {
+            num_mel_bins,
+            dft_length,
+            sample_rate,
+            lower_edge_hertz,
+            upper_edge_hertz,
+        }
+    }
+}
+
+/// Creates a MelWeightMatrixConfig from the node inputs.
+pub fn mel_weight_matrix_config(node: &Node) -> MelWeightMatrixConfig {
+    let input = |index: usize, name: &str| {
+        node.inputs[index]
+            .value
+            .clone()
+            .unwrap_or_else(|| {
+                panic!("MelWeightMatrix: only constant {name} is currently supported")
+            })
+            .data
+    };
+
+    MelWeightMatrixConfig::new(
+        input(0, "num_mel_bins").into_i64() as usize,
+        input(1, "dft_length").into_i64() as usize,
+        input(2, "sample_rate").into_i64() as usize,
+        input(3, "lower_edge_hertz").into_f64(),
+        input(4, "upper_edge_hertz").into_f64(),
+    )
+}
+
+/// Update output rank for MelWeightMatrix, which generates a `[dft_length / 2 + 1, num_mel_bins]`
+/// matrix with the type given by the `output_datatype` attribute.
+pub fn mel_weight_matrix_update_outputs(node: &mut Node) {
+    log::debug!("MelWeightMatrix rank inference for node {}", node.name);
+
+    let dtype = node
+        .attrs
+        .get("output_datatype")
+        .map(|val| DataType::from_i32(val.clone().into_i32()).unwrap())
+        .unwrap_or(DataType::FLOAT);
+
+    let elem_type = match dtype {
+        DataType::FLOAT => ElementType::Float32,
+        DataType::DOUBLE => ElementType::Float64,
+        DataType::FLOAT16 => ElementType::Float16,
+        _ => panic!("MelWeightMatrix: tensor with type {dtype:?} not supported for output"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        rank: 2,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node() -> Node {
+        NodeBuilder::new(NodeType::MelWeightMatrix, "test_mel_weight_matrix")
+            .input_scalar_tensor_i64("num_mel_bins", 8)
+            .input_scalar_tensor_i64("dft_length", 16)
+            .input_scalar_tensor_i64("sample_rate", 8000)
+            .input_scalar_tensor_f32("lower_edge_hertz", Some(0.0))
+            .input_scalar_tensor_f32("upper_edge_hertz", Some(4000.0))
+            .output_tensor_f32("output", 0, None)
+            .build()
+    }
+
+    #[test]
+    fn test_mel_weight_matrix_config() {
+        let node = create_test_node();
+        let config = mel_weight_matrix_config(&node);
+
+        assert_eq!(config, MelWeightMatrixConfig::new(8, 16, 8000, 0.0, 4000.0));
+    }
+
+    #[test]
+    fn test_mel_weight_matrix_update_outputs() {
+        let mut node = create_test_node();
+        mel_weight_matrix_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float32);
+                assert_eq!(tensor.rank, 2);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+}
@@ -30,12 +30,18 @@ pub mod conv3d;
 pub mod conv_transpose1d;
 pub mod conv_transpose2d;
 pub mod conv_transpose3d;
+pub mod cumsum;
 pub mod depth_to_space;
+pub mod det;
+pub mod dft;
 pub mod dropout;
+pub mod einsum;
 pub mod expand;
 pub mod flatten;
 pub mod gather;
+pub mod gather_nd;
 pub mod gemm;
+pub mod grid_sample;
 pub mod group_norm;
 pub mod hard_sigmoid;
 pub mod instance_norm;
@@ -47,12 +53,15 @@ pub mod log_softmax;
 pub mod matmul;
 pub mod max_pool1d;
 pub mod max_pool2d;
+pub mod mel_weight_matrix;
+pub mod multinomial;
 pub mod one_hot;
 pub mod pad;
 pub mod padding;
 pub mod random;
 pub mod random_like;
 pub mod range;
+pub mod reduce;
 pub mod reduce_max;
 pub mod reduce_mean;
 pub mod reduce_min;
@@ -60,6 +69,8 @@ pub mod reduce_prod;
 pub mod reduce_sum;
 pub mod reshape;
 pub mod resize;
+pub mod scatter_elements;
+pub mod scatter_nd;
 pub mod shape;
 pub mod size;
 pub mod slice;
@@ -67,9 +78,12 @@ pub mod softmax;
 pub mod space_to_depth;
 pub mod split;
 pub mod squeeze;
+pub mod stft;
 pub mod tile;
 pub mod topk;
 pub mod transpose;
 pub mod trilu;
+pub mod unique;
 pub mod unsqueeze;
 pub mod where_op;
+pub mod window;
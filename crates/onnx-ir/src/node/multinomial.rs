@@ -0,0 +1,146 @@
+use crate::ir::{ArgType, ElementType, Node, TensorType};
+use crate::protos::tensor_proto::DataType;
+use protobuf::Enum;
+
+/// Configuration for the Multinomial operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultinomialConfig {
+    /// The number of samples drawn for each batch.
+    pub sample_size: usize,
+}
+
+/// Creates a MultinomialConfig from the node attributes.
+///
+/// The `seed` attribute is ignored, the backend generator is used instead.
+pub fn multinomial_config(node: &Node) -> MultinomialConfig {
+    let sample_size = node
+        .attrs
+        .get("sample_size")
+        .map_or(1, |value| value.clone().into_i64());
+
+    if sample_size <= 0 {
+        panic!("Multinomial: sample_size must be positive, got {sample_size}");
+    }
+
+    MultinomialConfig {
+        sample_size: sample_size as usize,
+    }
+}
+
+/// Update output type for Multinomial.
+///
+/// The input has shape `[batch_size, class_size]` and the output `[batch_size, sample_size]`,
+/// with the integer type given by the `dtype` attribute (Int32 by default).
+pub fn multinomial_update_output(node: &mut Node) {
+    log::debug!("Multinomial rank inference for node {}", node.name);
+
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) if tensor.rank == 2 => {}
+        ArgType::Tensor(tensor) => panic!(
+            "Multinomial: input must be 2D [batch_size, class_size], got rank {}",
+            tensor.rank
+        ),
+        _ => panic!("Multinomial: only tensor input is valid"),
+    }
+
+    let dtype = node
+        .attrs
+        .get("dtype")
+        .map(|val| DataType::from_i32(val.clone().into_i32()).unwrap());
+
+    let elem_type = match dtype {
+        None | Some(DataType::INT32) => ElementType::Int32,
+        Some(DataType::INT64) => ElementType::Int64,
+        Some(dtype) => panic!("Multinomial: output type {dtype:?} not supported"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        rank: 2,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(sample_size: Option<i64>, dtype: Option<i32>) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::Multinomial, "test_multinomial")
+            .input_tensor_f32("input", 2, None)
+            .output_tensor_i64("output", 0, None);
+
+        if let Some(sample_size) = sample_size {
+            builder = builder.attr_int("sample_size", sample_size);
+        }
+        if let Some(dtype) = dtype {
+            builder = builder.attr_int("dtype", dtype as i64);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_multinomial_config_default() {
+        let node = create_test_node(None, None);
+
+        let config = multinomial_config(&node);
+
+        assert_eq!(config, MultinomialConfig { sample_size: 1 });
+    }
+
+    #[test]
+    fn test_multinomial_config() {
+        let node = create_test_node(Some(5), None);
+
+        let config = multinomial_config(&node);
+
+        assert_eq!(config, MultinomialConfig { sample_size: 5 });
+    }
+
+    #[test]
+    #[should_panic(expected = "sample_size must be positive")]
+    fn test_multinomial_config_invalid_sample_size() {
+        let node = create_test_node(Some(0), None);
+        let _ = multinomial_config(&node);
+    }
+
+    #[test]
+    fn test_multinomial_update_output() {
+        let mut node = create_test_node(Some(3), Some(DataType::INT64.value()));
+        multinomial_update_output(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Int64);
+                assert_eq!(tensor.rank, 2);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    fn test_multinomial_update_output_default_dtype() {
+        let mut node = create_test_node(None, None);
+        multinomial_update_output(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.elem_type, ElementType::Int32),
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "input must be 2D")]
+    fn test_multinomial_invalid_rank() {
+        let mut node = create_test_node(None, None);
+        node.inputs[0].ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            rank: 3,
+            static_shape: None,
+        });
+        multinomial_update_output(&mut node);
+    }
+}
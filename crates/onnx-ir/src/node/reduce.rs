@@ -0,0 +1,184 @@
+//! Shared configuration and rank inference of the reduce operations that are mapped onto a
+//! composition of Burn ops: ReduceL1, ReduceL2, ReduceLogSum, ReduceLogSumExp and ReduceSumSquare.
+
+use crate::ir::{ArgType, AttributeValue, Data, Node, TensorType};
+
+/// Create the reduced dimension of a reduce operation from the attributes of the node, `None`
+/// meaning that all the dimensions are reduced.
+pub fn reduce_config(node: &Node) -> Option<usize> {
+    let op = &node.node_type;
+    let mut axes = Vec::new();
+    let mut keepdims = 1;
+
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{op}: only tensor input is valid"),
+    };
+
+    // Extract the attributes
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "keepdims" => keepdims = value.clone().into_i64(),
+            "axes" => axes = value.clone().into_i64s(),
+            // TODO: handle noop_with_empty_axes
+            _ => {}
+        }
+    }
+
+    // Since opset 18, the axes are an input instead of an attribute
+    if let Some(value) = node
+        .inputs
+        .get(1)
+        .and_then(|argument| argument.value.as_ref())
+    {
+        axes = value.clone().data.into_i64s();
+    }
+
+    if axes.len() > 1 {
+        panic!("{op}: reducing on multiple dimensions is not supported")
+    }
+
+    if axes.is_empty() && keepdims == 1 {
+        panic!("{op}: axes must be provided with keepdims")
+    }
+
+    if !axes.is_empty() && keepdims == 0 {
+        // Not supported in Burn
+        panic!("{op}: the reduce operation must preserve the reduced dimension")
+    }
+
+    if axes.is_empty() {
+        None
+    } else {
+        let mut dim = axes[0];
+
+        if dim < 0 {
+            // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
+            dim += tensor.rank as i64;
+        }
+        Some(dim as usize)
+    }
+}
+
+/// Update output rank of a reduce operation based on axes.
+pub fn reduce_update_outputs(node: &mut Node) {
+    log::debug!("{} rank inference for node {}", node.node_type, node.name);
+
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{}: only tensor input is valid", node.node_type),
+    };
+
+    let dim_only = match node.attrs.get("axes") {
+        Some(value) => match &value {
+            AttributeValue::Int64(_) => true,
+            AttributeValue::Int64s(ints) => ints.len() == 1,
+            _ => false,
+        },
+        None => false,
+    } || match node.inputs.get(1).and_then(|arg| arg.value.as_ref()) {
+        Some(value) => match &value.data {
+            Data::Int64(_) => true,
+            Data::Int64s(ints) => ints.len() == 1,
+            _ => false,
+        },
+        None => false,
+    };
+
+    let output_rank = if dim_only { tensor.rank } else { 1 };
+    log::debug!(
+        "{} output rank for {}: {}",
+        node.node_type,
+        node.name,
+        output_rank
+    );
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type.clone(),
+        rank: output_rank,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(
+        node_type: NodeType,
+        axes: Option<Vec<i64>>,
+        keepdims: Option<i64>,
+        with_axes_input: bool,
+    ) -> Node {
+        let mut builder = NodeBuilder::new(node_type, "test_reduce")
+            .input_tensor_f32("data", 3, None)
+            .output_tensor_f32("reduced", 3, None);
+
+        if let Some(axes) = axes {
+            if with_axes_input {
+                builder = builder.input_tensor_i64_data("axes", axes.clone(), vec![axes.len()]);
+            } else {
+                builder = builder.attr_ints("axes", axes);
+            }
+        }
+
+        if let Some(kd) = keepdims {
+            builder = builder.attr_int("keepdims", kd);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_reduce_config_basic() {
+        let node = create_test_node(NodeType::ReduceLogSumExp, Some(vec![1]), Some(1), false);
+        assert_eq!(reduce_config(&node), Some(1));
+    }
+
+    #[test]
+    fn test_reduce_config_with_input_axes() {
+        let node = create_test_node(NodeType::ReduceL2, Some(vec![-1]), None, true);
+        assert_eq!(reduce_config(&node), Some(2));
+    }
+
+    #[test]
+    fn test_reduce_config_all_dims() {
+        let node = create_test_node(NodeType::ReduceSumSquare, None, Some(0), false);
+        assert_eq!(reduce_config(&node), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "ReduceL1: reducing on multiple dimensions is not supported")]
+    fn test_reduce_config_multiple_axes() {
+        let node = create_test_node(NodeType::ReduceL1, Some(vec![0, 1]), Some(1), false);
+        let _ = reduce_config(&node);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "ReduceLogSum: the reduce operation must preserve the reduced dimension"
+    )]
+    fn test_reduce_config_no_keepdims() {
+        let node = create_test_node(NodeType::ReduceLogSum, Some(vec![1]), Some(0), false);
+        let _ = reduce_config(&node);
+    }
+
+    #[test]
+    fn test_reduce_update_outputs() {
+        let mut node = create_test_node(NodeType::ReduceLogSumExp, Some(vec![1]), None, true);
+        reduce_update_outputs(&mut node);
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.rank, 3),
+            _ => panic!("Expected tensor output"),
+        }
+
+        let mut node = create_test_node(NodeType::ReduceLogSumExp, None, Some(0), false);
+        reduce_update_outputs(&mut node);
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.rank, 1),
+            _ => panic!("Expected tensor output"),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::ir::{ArgType, Node};
+use crate::node::scatter_nd::ScatterReduction;
+
+/// Configuration for the ScatterElements operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScatterElementsConfig {
+    pub axis: usize,
+    pub reduction: ScatterReduction,
+}
+
+impl ScatterElementsConfig {
+    /// Create a new ScatterElementsConfig
+    pub fn new(axis: usize, reduction: ScatterReduction) -> Self {
+        Self { axis, reduction }
+    }
+}
+
+/// Creates a ScatterElementsConfig from the node attributes.
+pub fn scatter_elements_config(node: &Node) -> ScatterElementsConfig {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.rank as i64,
+        _ => panic!("ScatterElements: only tensor data is valid"),
+    };
+
+    let mut axis = 0;
+    let mut reduction = ScatterReduction::None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "reduction" => reduction = value.clone().into_string().as_str().into(),
+            _ => panic!("Unexpected attribute for ScatterElements: {key}"),
+        }
+    }
+
+    // If axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += rank;
+    }
+
+    ScatterElementsConfig::new(axis as usize, reduction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(axis: Option<i64>, reduction: Option<&str>) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::ScatterElements, "test_scatter_elements")
+            .input_tensor_f32("data", 3, None)
+            .input_tensor_i64("indices", 3, None)
+            .input_tensor_f32("updates", 3, None)
+            .output_tensor_f32("output", 3, None);
+
+        if let Some(axis) = axis {
+            builder = builder.attr_int("axis", axis);
+        }
+        if let Some(reduction) = reduction {
+            builder = builder.attr_string("reduction", reduction);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_default_config() {
+        let node = create_test_node(None, None);
+        assert_eq!(
+            scatter_elements_config(&node),
+            ScatterElementsConfig::new(0, ScatterReduction::None)
+        );
+    }
+
+    #[test]
+    fn test_config() {
+        let node = create_test_node(Some(1), Some("add"));
+        assert_eq!(
+            scatter_elements_config(&node),
+            ScatterElementsConfig::new(1, ScatterReduction::Add)
+        );
+    }
+
+    #[test]
+    fn test_negative_axis() {
+        let node = create_test_node(Some(-1), None);
+        assert_eq!(scatter_elements_config(&node).axis, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported scatter reduction: max")]
+    fn test_unsupported_reduction() {
+        let node = create_test_node(None, Some("max"));
+        let _ = scatter_elements_config(&node);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::ir::Node;
+
+/// Reduction applied when the updates are written to the data of the scatter operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterReduction {
+    /// The updates replace the data.
+    None,
+    /// The updates are summed to the data.
+    Add,
+}
+
+impl From<&str> for ScatterReduction {
+    fn from(val: &str) -> Self {
+        match val {
+            "none" => Self::None,
+            "add" => Self::Add,
+            _ => panic!("Unsupported scatter reduction: {val}"),
+        }
+    }
+}
+
+/// Creates the ScatterND reduction from the node attributes.
+pub fn scatter_nd_config(node: &Node) -> ScatterReduction {
+    let mut reduction = ScatterReduction::None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "reduction" => reduction = value.clone().into_string().as_str().into(),
+            _ => panic!("Unexpected attribute for ScatterND: {key}"),
+        }
+    }
+
+    reduction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(reduction: Option<&str>) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::ScatterND, "test_scatter_nd")
+            .input_tensor_f32("data", 3, None)
+            .input_tensor_i64("indices", 2, None)
+            .input_tensor_f32("updates", 2, None)
+            .output_tensor_f32("output", 3, None);
+
+        if let Some(reduction) = reduction {
+            builder = builder.attr_string("reduction", reduction);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_default_config() {
+        let node = create_test_node(None);
+        assert_eq!(scatter_nd_config(&node), ScatterReduction::None);
+    }
+
+    #[test]
+    fn test_add_config() {
+        let node = create_test_node(Some("add"));
+        assert_eq!(scatter_nd_config(&node), ScatterReduction::Add);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported scatter reduction: mul")]
+    fn test_unsupported_reduction() {
+        let node = create_test_node(Some("mul"));
+        let _ = scatter_nd_config(&node);
+    }
+}
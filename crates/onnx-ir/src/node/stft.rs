@@ -0,0 +1,142 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Configuration for the STFT operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StftConfig {
+    /// The number of samples between the starts of neighboring frames.
+    pub frame_step: usize,
+    /// The number of samples of each frame, defaulting to the length of the window.
+    pub frame_length: Option<usize>,
+    /// Whether only the non-negative frequencies are returned.
+    pub onesided: bool,
+}
+
+impl StftConfig {
+    /// Creates a new StftConfig.
+    pub fn new(frame_step: usize, frame_length: Option<usize>, onesided: bool) -> Self {
+        Self {
+            frame_step,
+            frame_length,
+            onesided,
+        }
+    }
+}
+
+/// Creates a StftConfig from the node attributes and inputs.
+pub fn stft_config(node: &Node) -> StftConfig {
+    let frame_step = node.inputs[1]
+        .value
+        .clone()
+        .expect("STFT: only constant frame_step is currently supported")
+        .data
+        .into_i64();
+
+    let frame_length = node
+        .inputs
+        .get(3)
+        .filter(|arg| !arg.name.is_empty())
+        .map(|arg| {
+            arg.value
+                .clone()
+                .expect("STFT: only constant frame_length is currently supported")
+                .data
+                .into_i64() as usize
+        });
+
+    let has_window = node.inputs.get(2).is_some_and(|arg| !arg.name.is_empty());
+    assert!(
+        has_window || frame_length.is_some(),
+        "STFT: either the window or the frame_length input is required"
+    );
+
+    let onesided = node
+        .attrs
+        .get("onesided")
+        .map(|val| val.clone().into_i64() != 0)
+        .unwrap_or(true);
+
+    StftConfig::new(frame_step as usize, frame_length, onesided)
+}
+
+/// Update output rank for STFT, which returns the `[batch_size, frames, dft_unique_bins, 2]`
+/// spectrogram of a `[batch_size, signal_length, 1]` signal.
+pub fn stft_update_outputs(node: &mut Node) {
+    log::debug!("STFT rank inference for node {}", node.name);
+
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("STFT: only tensor input is valid"),
+    };
+    assert_eq!(
+        tensor.rank, 3,
+        "STFT: expected a signal of rank 3, found {}",
+        tensor.rank
+    );
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type,
+        rank: 4,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(frame_length: Option<i64>, onesided: Option<i64>) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::STFT, "test_stft")
+            .input_tensor_f32("signal", 3, None)
+            .input_scalar_tensor_i64("frame_step", 4)
+            .input_tensor_f32("window", 1, None);
+
+        if let Some(frame_length) = frame_length {
+            builder = builder.input_scalar_tensor_i64("frame_length", frame_length);
+        }
+        if let Some(onesided) = onesided {
+            builder = builder.attr_int("onesided", onesided);
+        }
+
+        builder.output_tensor_f32("output", 0, None).build()
+    }
+
+    #[test]
+    fn test_stft_config() {
+        let node = create_test_node(Some(16), None);
+        let config = stft_config(&node);
+
+        assert_eq!(config, StftConfig::new(4, Some(16), true));
+    }
+
+    #[test]
+    fn test_stft_config_window_length() {
+        let node = create_test_node(None, Some(0));
+        let config = stft_config(&node);
+
+        assert_eq!(config, StftConfig::new(4, None, false));
+    }
+
+    #[test]
+    fn test_stft_update_outputs() {
+        let mut node = create_test_node(None, None);
+        stft_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => assert_eq!(tensor.rank, 4),
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "STFT: expected a signal of rank 3")]
+    fn test_stft_invalid_rank() {
+        let mut node = NodeBuilder::new(NodeType::STFT, "test_stft")
+            .input_tensor_f32("signal", 2, None)
+            .input_scalar_tensor_i64("frame_step", 4)
+            .output_tensor_f32("output", 0, None)
+            .build();
+        stft_update_outputs(&mut node);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::ir::{ArgType, ElementType, Node, TensorType};
+
+/// Update output ranks for Unique.
+///
+/// Without an axis, the input is flattened, so all the outputs are 1D. The first output has the
+/// element type of the input, and the optional `indices`, `inverse_indices` and `counts` outputs
+/// are Int64.
+pub fn unique_update_outputs(node: &mut Node) {
+    log::debug!("Unique rank inference for node {}", node.name);
+
+    if node.attrs.contains_key("axis") {
+        unimplemented!("Unique: only the flattened input is supported, 'axis' must not be set")
+    }
+
+    let sorted = node
+        .attrs
+        .get("sorted")
+        .is_none_or(|sorted| sorted.clone().into_i64() == 1);
+    if !sorted {
+        unimplemented!("Unique: only sorted unique elements are supported")
+    }
+
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Unique: invalid input type"),
+    };
+
+    for (i, output) in node.outputs.iter_mut().enumerate() {
+        output.ty = ArgType::Tensor(TensorType {
+            elem_type: if i == 0 {
+                elem_type.clone()
+            } else {
+                ElementType::Int64
+            },
+            rank: 1,
+            static_shape: None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{AttributeValue, NodeType};
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(input_rank: usize, num_outputs: usize) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::Unique, "test_unique")
+            .input_tensor_f32("X", input_rank, None)
+            .output_tensor_f32("Y", 0, None);
+
+        for name in ["indices", "inverse_indices", "counts"]
+            .iter()
+            .take(num_outputs - 1)
+        {
+            builder = builder.output_tensor_i64(name, 0, None);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_unique_update_outputs() {
+        let mut node = create_test_node(3, 4);
+
+        unique_update_outputs(&mut node);
+
+        for (i, output) in node.outputs.iter().enumerate() {
+            match &output.ty {
+                ArgType::Tensor(tensor) => {
+                    let expected = match i {
+                        0 => ElementType::Float32,
+                        _ => ElementType::Int64,
+                    };
+                    assert_eq!(tensor.elem_type, expected);
+                    assert_eq!(tensor.rank, 1);
+                }
+                _ => panic!("Expected tensor output"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unique_update_outputs_sorted() {
+        let mut node = create_test_node(2, 1);
+        node.attrs
+            .insert("sorted".to_string(), AttributeValue::Int64(1));
+
+        unique_update_outputs(&mut node);
+
+        assert_eq!(node.outputs.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "only sorted unique elements are supported")]
+    fn test_unique_unsorted() {
+        let mut node = create_test_node(2, 1);
+        node.attrs
+            .insert("sorted".to_string(), AttributeValue::Int64(0));
+
+        unique_update_outputs(&mut node);
+    }
+
+    #[test]
+    #[should_panic(expected = "'axis' must not be set")]
+    fn test_unique_axis() {
+        let mut node = create_test_node(2, 1);
+        node.attrs
+            .insert("axis".to_string(), AttributeValue::Int64(0));
+
+        unique_update_outputs(&mut node);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::ir::{ArgType, ElementType, Node, TensorType};
+use crate::protos::tensor_proto::DataType;
+use protobuf::Enum;
+
+/// Configuration for the HannWindow, HammingWindow and BlackmanWindow operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowConfig {
+    /// The length of the window.
+    pub size: usize,
+    /// Whether the window is periodic, as used for spectral analysis, or symmetric, as used for
+    /// filter design.
+    pub periodic: bool,
+}
+
+impl WindowConfig {
+    /// Creates a new WindowConfig.
+    pub fn new(size: usize, periodic: bool) -> Self {
+        Self { size, periodic }
+    }
+}
+
+/// Creates a WindowConfig from the node attributes and inputs.
+pub fn window_config(node: &Node) -> WindowConfig {
+    let size = node.inputs[0]
+        .value
+        .clone()
+        .unwrap_or_else(|| {
+            panic!(
+                "{}: only constant size is currently supported",
+                node.node_type
+            )
+        })
+        .data
+        .into_i64();
+
+    let periodic = node
+        .attrs
+        .get("periodic")
+        .map(|val| val.clone().into_i64() != 0)
+        .unwrap_or(true);
+
+    WindowConfig::new(size as usize, periodic)
+}
+
+/// Update output rank for the window operations, which generate a tensor of rank 1 with the type
+/// given by the `output_datatype` attribute.
+pub fn window_update_outputs(node: &mut Node) {
+    log::debug!("{} rank inference for node {}", node.node_type, node.name);
+
+    let dtype = node
+        .attrs
+        .get("output_datatype")
+        .map(|val| DataType::from_i32(val.clone().into_i32()).unwrap())
+        .unwrap_or(DataType::FLOAT);
+
+    let elem_type = match dtype {
+        DataType::FLOAT => ElementType::Float32,
+        DataType::DOUBLE => ElementType::Float64,
+        DataType::FLOAT16 => ElementType::Float16,
+        _ => panic!(
+            "{}: tensor with type {dtype:?} not supported for window output",
+            node.node_type
+        ),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        rank: 1,
+        static_shape: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::NodeType;
+    use crate::node::test_utils::NodeBuilder;
+
+    fn create_test_node(periodic: Option<i64>, dtype: Option<i32>) -> Node {
+        let mut builder = NodeBuilder::new(NodeType::HannWindow, "test_hann_window")
+            .input_scalar_tensor_i64("size", 10)
+            .output_tensor_f32("output", 0, None);
+
+        if let Some(periodic) = periodic {
+            builder = builder.attr_int("periodic", periodic);
+        }
+        if let Some(dtype) = dtype {
+            builder = builder.attr_int("output_datatype", dtype as i64);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_window_config_default() {
+        let node = create_test_node(None, None);
+        assert_eq!(window_config(&node), WindowConfig::new(10, true));
+    }
+
+    #[test]
+    fn test_window_config_symmetric() {
+        let node = create_test_node(Some(0), None);
+        assert_eq!(window_config(&node), WindowConfig::new(10, false));
+    }
+
+    #[test]
+    fn test_window_update_outputs() {
+        let mut node = create_test_node(None, Some(DataType::DOUBLE.value()));
+        window_update_outputs(&mut node);
+
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(tensor.elem_type, ElementType::Float64);
+                assert_eq!(tensor.rank, 1);
+            }
+            _ => panic!("Expected tensor output"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not supported for window output")]
+    fn test_window_update_outputs_int() {
+        let mut node = create_test_node(None, Some(DataType::INT64.value()));
+        window_update_outputs(&mut node);
+    }
+}
@@ -6,18 +6,23 @@ use crate::{
         cast::cast_update_outputs, comparison::elementwise_comparison_outputs,
         concat::concat_update_outputs, constant::constant_update_outputs,
         constant_of_shape::constant_of_shape_update_output,
-        depth_to_space::depth_to_space_update_outputs, expand::expand_update_outputs,
-        flatten::flatten_update_outputs, gather::gather_update_outputs, gemm::gemm_output_shape,
-        linear::linear_update_outputs, matmul::matmul_update_outputs,
-        one_hot::one_hot_output_shape, random::random_update_output,
-        random_like::random_like_update_output, range::range_update_outputs,
+        depth_to_space::depth_to_space_update_outputs, det::det_update_outputs,
+        dft::dft_update_outputs, einsum::einsum_update_outputs, expand::expand_update_outputs,
+        flatten::flatten_update_outputs, gather::gather_update_outputs,
+        gather_nd::gather_nd_update_output, gemm::gemm_output_shape,
+        grid_sample::grid_sample_update_output, linear::linear_update_outputs,
+        matmul::matmul_update_outputs, mel_weight_matrix::mel_weight_matrix_update_outputs,
+        multinomial::multinomial_update_output, one_hot::one_hot_output_shape,
+        random::random_update_output, random_like::random_like_update_output,
+        range::range_update_outputs, reduce::reduce_update_outputs,
         reduce_max::reduce_max_update_outputs, reduce_mean::reduce_mean_update_outputs,
         reduce_min::reduce_min_update_outputs, reduce_prod::reduce_prod_update_outputs,
         reduce_sum::reduce_sum_update_outputs, reshape::reshape_update_outputs,
         shape::shape_update_outputs, size::size_update_outputs, slice::slice_update_output_rank,
         space_to_depth::space_to_depth_update_outputs, split::split_update_outputs,
-        squeeze::squeeze_update_output, topk::top_k_update_output,
-        unsqueeze::unsqueeze_update_output, where_op::where_update_outputs,
+        squeeze::squeeze_update_output, stft::stft_update_outputs, topk::top_k_update_output,
+        unique::unique_update_outputs, unsqueeze::unsqueeze_update_output,
+        where_op::where_update_outputs, window::window_update_outputs,
     },
     util::{same_as_input, same_as_input_broadcast, temporary_pass_through_stub},
 };
@@ -27,9 +32,15 @@ pub fn rank_inference(node: &mut Node) {
     log::debug!("Inferring rank for node: {}", node.name);
 
     match node.node_type {
+        NodeType::Acos => same_as_input(node),
+        NodeType::Acosh => same_as_input(node),
         NodeType::Add => same_as_input_broadcast(node),
         NodeType::ArgMax => argmax_update_outputs(node),
         NodeType::ArgMin => argmin_update_outputs(node),
+        NodeType::Asin => same_as_input(node),
+        NodeType::Asinh => same_as_input(node),
+        NodeType::Atan => same_as_input(node),
+        NodeType::Atanh => same_as_input(node),
         NodeType::Attention => attention_update_output(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
@@ -40,6 +51,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::BitwiseOr => same_as_input_broadcast(node),
         NodeType::BitwiseXor => same_as_input_broadcast(node),
         NodeType::Bernoulli => bernoulli_update_output(node),
+        NodeType::BlackmanWindow => window_update_outputs(node),
         NodeType::Cast => cast_update_outputs(node),
         NodeType::Ceil => same_as_input(node),
         NodeType::Clip => same_as_input(node),
@@ -50,8 +62,12 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Conv2d => same_as_input(node),
         NodeType::Cos => same_as_input(node),
         NodeType::Cosh => same_as_input(node),
+        NodeType::CumSum => same_as_input(node),
+        NodeType::Det => det_update_outputs(node),
+        NodeType::DFT => dft_update_outputs(node),
         NodeType::Div => same_as_input_broadcast(node),
         NodeType::Dropout => same_as_input(node),
+        NodeType::Einsum => einsum_update_outputs(node),
         NodeType::Equal => elementwise_comparison_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
@@ -61,9 +77,13 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Gelu => same_as_input(node),
         NodeType::Gather => gather_update_outputs(node),
         NodeType::GatherElements => same_as_input(node),
+        NodeType::GatherND => gather_nd_update_output(node),
         NodeType::Gemm => gemm_output_shape(node),
         NodeType::Greater => elementwise_comparison_outputs(node),
         NodeType::GreaterOrEqual => elementwise_comparison_outputs(node),
+        NodeType::GridSample => grid_sample_update_output(node),
+        NodeType::HammingWindow => window_update_outputs(node),
+        NodeType::HannWindow => window_update_outputs(node),
         NodeType::HardSigmoid => same_as_input(node),
         NodeType::GlobalAveragePool => same_as_input(node),
         NodeType::ConvTranspose1d => same_as_input(node),
@@ -84,8 +104,10 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Max => same_as_input_broadcast(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
+        NodeType::MelWeightMatrix => mel_weight_matrix_update_outputs(node),
         NodeType::Min => same_as_input_broadcast(node),
         NodeType::Mul => same_as_input(node),
+        NodeType::Multinomial => multinomial_update_output(node),
         NodeType::Neg => same_as_input(node),
         NodeType::Not => same_as_input(node),
         NodeType::And => same_as_input(node),
@@ -101,15 +123,22 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::RandomUniformLike => random_like_update_output(node),
         NodeType::Range => range_update_outputs(node),
         NodeType::Reciprocal => same_as_input(node),
+        NodeType::ReduceL1 => reduce_update_outputs(node),
+        NodeType::ReduceL2 => reduce_update_outputs(node),
+        NodeType::ReduceLogSum => reduce_update_outputs(node),
+        NodeType::ReduceLogSumExp => reduce_update_outputs(node),
         NodeType::ReduceMax => reduce_max_update_outputs(node),
         NodeType::ReduceMin => reduce_min_update_outputs(node),
         NodeType::ReduceMean => reduce_mean_update_outputs(node),
         NodeType::ReduceProd => reduce_prod_update_outputs(node),
         NodeType::ReduceSum => reduce_sum_update_outputs(node),
+        NodeType::ReduceSumSquare => reduce_update_outputs(node),
         NodeType::Relu => same_as_input(node),
         NodeType::Reshape => reshape_update_outputs(node),
         NodeType::Resize => same_as_input(node),
         NodeType::Round => same_as_input(node),
+        NodeType::ScatterElements => same_as_input(node),
+        NodeType::ScatterND => same_as_input(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
         NodeType::Sign => same_as_input(node),
@@ -122,6 +151,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Split => split_update_outputs(node),
         NodeType::Squeeze => squeeze_update_output(node),
         NodeType::Sqrt => same_as_input(node),
+        NodeType::STFT => stft_update_outputs(node),
         NodeType::Sub => same_as_input_broadcast(node),
         NodeType::Sum => same_as_input_broadcast(node),
         NodeType::Tan => same_as_input(node),
@@ -129,6 +159,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::TopK => top_k_update_output(node),
         NodeType::Transpose => same_as_input(node),
         NodeType::Trilu => same_as_input(node),
+        NodeType::Unique => unique_update_outputs(node),
         NodeType::Unsqueeze => unsqueeze_update_output(node),
         NodeType::Where => where_update_outputs(node),
         _ => temporary_pass_through_stub(node),
@@ -173,6 +173,15 @@ pub(crate) fn handle_command(
                         "std all features",
                     )?;
 
+                    // burn-autodiff
+                    helpers::custom_crates_tests(
+                        vec!["burn-autodiff"],
+                        handle_test_args(&["--features", "async"], args.release),
+                        None,
+                        None,
+                        "std async",
+                    )?;
+
                     // burn-core
                     helpers::custom_crates_tests(
                         vec!["burn-core"],
@@ -193,6 +202,15 @@ pub(crate) fn handle_command(
                         None,
                         "std cpu",
                     )?;
+
+                    // burn-sparse
+                    helpers::custom_crates_tests(
+                        vec!["burn-sparse"],
+                        handle_test_args(&["--features", "test-cpu"], args.release),
+                        None,
+                        None,
+                        "std cpu",
+                    )?;
                 }
                 CiTestType::GcpCudaRunner => (),
                 CiTestType::GcpVulkanRunner | CiTestType::GcpWgpuRunner => (), // handled in tests above